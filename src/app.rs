@@ -16,163 +16,369 @@
 
 use core::f32;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    io::Write,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, RwLock},
     time::Duration,
 };
 
 use crate::{
-    autosave::{autosave_exists, restore_autosave},
+    autosave::{AutosaveRestore, restore_autosave},
+    charts::all_charts,
     constants::{
-        FURTHERANCE_VERSION, INSPECTOR_ALIGNMENT, INSPECTOR_PADDING, INSPECTOR_SPACING,
-        INSPECTOR_WIDTH, OFFICIAL_SERVER, SETTINGS_SPACING,
+        CSV_IMPORT_ERRORS_SHOWN, CURRENCY_INPUT_ID, DAY_NOTES_CSV_HEADER, DEFAULT_WINDOW_HEIGHT,
+        DEFAULT_WINDOW_WIDTH, DELETE_EVERYTHING_CONFIRMATION_WORD, DEVICE_NAME_INPUT_ID,
+        FIND_INPUT_ID, FURTHERANCE_PURPLE, FURTHERANCE_VERSION, GOAL_PROJECT_INPUT_ID,
+        HISTORY_FILTER_CHIP_COUNT, HISTORY_SCROLLABLE_ID, INSPECTOR_ALIGNMENT,
+        INSPECTOR_ESTIMATE_INPUT_ID, INSPECTOR_NAME_INPUT_ID, INSPECTOR_PADDING,
+        INSPECTOR_PROJECT_INPUT_ID, INSPECTOR_RATE_INPUT_ID, INSPECTOR_ROW_WIDTH,
+        INSPECTOR_SPACING, INSPECTOR_TAGS_INPUT_ID, INSPECTOR_WIDTH, INVOICE_CLIENT_INPUT_ID,
+        INVOICE_FROM_INPUT_ID, INVOICE_NOTES_INPUT_ID, INVOICE_NUMBER_INPUT_ID,
+        MAX_SECONDARY_TIMERS, OFFICIAL_SERVER, PROFILE_NAME_INPUT_ID, PROFILE_PATH_INPUT_ID,
+        REPLACE_INPUT_ID, REPORT_VALUES_SHOWN, ROUNDING_INCREMENTS,
+        SCHEDULED_SUMMARY_CHECK_SECONDS, SEARCH_INPUT_ID, SETTINGS_SPACING, SHORTCUT_BUTTON_WIDTH,
+        SHORTCUT_FOCUS_RING_WIDTH, SHORTCUTS_SCROLLABLE_ID, SHORTCUTS_SPACING, SIDEBAR_WIDTH,
+        SYNC_EMAIL_INPUT_ID, SYNC_KEY_INPUT_ID, SYNC_SERVER_INPUT_ID, SYSTEM_THEME_CHECK_SECONDS,
+        TASK_INPUT_ID, TRASH_ITEMS_SHOWN, WINDOW_GEOMETRY_SAVE_SECONDS,
     },
     database::*,
     helpers::{
-        color_utils::{FromHex, ToSrgb},
+        auto_stop::{auto_stop_time, parse_auto_stop_time},
+        clock_skew::sync_timestamp,
+        color_utils::{FromHex, ToIcedColor, ToSrgb, is_dark_color},
+        daily_target::{DailyTarget, TargetProgress},
+        device::{hostname, set_current_device_name},
+        earnings::{
+            group_earnings, group_is_excluded_from_earnings, is_excluded_from_earnings,
+            net_earnings, net_earnings_deduction, task_earnings,
+        },
+        error_log::{log_error, unseen_error_count},
+        formatting::{
+            format_byte_size, format_currency, format_time, set_default_currency,
+            set_use_24_hour_time,
+        },
+        keyboard_nav::NavDirection,
         midnight_subscription::MidnightSubscription,
-        tasks,
+        notification_actions::NotificationActionSubscription,
+        power::PowerSubscription,
+        project_defaults::{added_default_tags, default_tags_for},
+        search, tasks,
+        trash::{DeletedItem, DeletedItemKind, count_of_kind, purge_cutoff},
+        year_in_review::{YearInReview, review_lines},
     },
     localization::Localization,
     models::{
+        csv_import::CsvRowError,
         export_settings::ExportSettings,
+        find_replace::FindReplace,
+        fur_day_note::{DayNoteToEdit, FurDayNote},
+        fur_days_off::FurDaysOff,
+        fur_goal::GoalToAdd,
         fur_idle::FurIdle,
-        fur_pomodoro::FurPomodoro,
-        fur_report::FurReport,
-        fur_settings::FurSettings,
-        fur_shortcut::FurShortcut,
+        fur_invoice::InvoiceToGenerate,
+        fur_onboarding::FurOnboarding,
+        fur_pomodoro::{FurPomodoro, FurPomodoroStats},
+        fur_report::{FurReport, percent_change},
+        fur_running_timer::FurRunningTimer,
+        fur_settings::{FurSettings, SettingsRecovery},
+        fur_shortcut::{
+            FurShortcut, ShortcutMove, ShortcutRateChange, ShortcutUsage, sort_shortcuts,
+        },
+        fur_sync_record::FurSyncRecord,
+        fur_task::FurTask,
         fur_task_group::FurTaskGroup,
         fur_todo::{FurTodo, TodoToAdd, TodoToEdit},
         fur_user::{FurUser, FurUserFields},
         group_to_edit::GroupToEdit,
+        history_selection::HistorySelection,
+        profile_to_add::ProfileToAdd,
+        recent_tasks::RecentTasks,
         shortcut_to_add::ShortcutToAdd,
         shortcut_to_edit::ShortcutToEdit,
         task_to_add::TaskToAdd,
         task_to_edit::TaskToEdit,
     },
+    server::login::is_unencrypted_server,
+    single_instance::RaiseRequestSubscription,
+    status_file::get_status_file_path,
+    status_server::{StatusServer, StatusSnapshot},
     style::{self, FurTheme},
     ui::todos,
     update::{
         messages::Message,
         msg_helper_functions::{
-            chain_tasks, get_timer_text, seconds_to_formatted_duration, split_task_input,
+            active_planned_seconds, chain_tasks, check_notification_support,
+            check_scheduled_summary, clock_skew_warning, format_duration_input, get_day_notes,
+            get_days_off, get_timer_text, is_task_switch, refresh_history_sections,
+            refresh_project_lists, refresh_recent_tasks, restart_status_server, round_seconds,
+            seconds_to_formatted_duration, set_todo_list, split_logged_duration, split_task_input,
+            sync_retry_delay, task_input_is_billable, task_input_planned_seconds, timer_now,
+            update_status_file, window_title,
         },
     },
     view_enums::*,
 };
-use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeDelta};
-use csv::Writer;
+use chrono::{
+    DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeDelta, TimeZone, offset::LocalResult,
+};
+use csv::WriterBuilder;
 use fluent::FluentValue;
 use iced::{
-    Alignment, Color, Element, Length, Padding, Renderer, Subscription, Task, Theme,
+    Alignment, Color, Element, Length, Padding, Point, Renderer, Subscription, Task, Theme,
     advanced::subscription,
-    alignment, font, keyboard,
+    alignment, event, font, keyboard, mouse,
     widget::{
         Button, Column, Container, Row, Scrollable, button, center, checkbox, column, container,
-        opaque, pick_list, row, rule, space, stack, text, text_input, toggler,
+        opaque, pick_list, progress_bar, radio, row, rule, space, stack, text, text_editor,
+        text_input, toggler, tooltip,
     },
+    window,
 };
 use iced_aw::{
-    Card, ContextMenu, TabBarPosition, TabLabel, Tabs, TimePicker, color_picker, date_picker,
-    number_input, time_picker,
+    Card, ContextMenu, Spinner, TabBarPosition, TabLabel, Tabs, TimePicker, color_picker,
+    date_picker, number_input, time_picker,
 };
 use iced_fonts::bootstrap::{self, advanced_text};
 use itertools::Itertools;
 use palette::Srgb;
-use palette::color_difference::Wcag21RelativeContrast;
 use tokio::time;
 
 #[cfg(target_os = "macos")]
 use notify_rust::set_application;
 
 pub struct Furtherance {
+    pub active_history_filters: HashSet<FilterChip>,
+    pub all_projects: Vec<String>,
+    pub archived_todos: BTreeMap<NaiveDate, Vec<FurTodo>>,
+    pub clock_skew: Option<TimeDelta>,
+    pub csv_import_errors: Vec<CsvRowError>,
     pub current_view: FurView,
+    pub database_merge: Option<UpgradeProgress>,
+    pub day_notes: BTreeMap<NaiveDate, FurDayNote>,
+    pub day_note_to_edit: Option<DayNoteToEdit>,
+    pub days_off: FurDaysOff,
+    pub delete_everything_confirmation: String,
     pub delete_tasks_from_context: Option<Vec<String>>,
     pub delete_shortcut_from_context: Option<String>,
     pub delete_todo_uid: Option<String>,
+    pub discard_changes_then: Option<Message>,
     pub displayed_alert: Option<FurAlert>,
     pub displayed_task_start_time: time_picker::Time,
+    pub dropped_database: Option<PathBuf>,
+    pub duplicates_found: Option<DuplicateUids>,
+    pub earnings_exclusions_input: String,
+    pub errors: VecDeque<(DateTime<Local>, String)>,
+    pub errors_viewed_at: Option<DateTime<Local>>,
     pub export_settings: ExportSettings,
+    pub find_replace: FindReplace,
     pub fur_settings: FurSettings,
     pub fur_user: Option<FurUser>,
     pub fur_user_fields: FurUserFields,
+    pub goal_to_add: GoalToAdd,
     pub group_to_edit: Option<GroupToEdit>,
+    pub history_days_loaded: i64,
+    pub history_end_reached: bool,
+    pub history_filter_chips: Vec<FilterChip>,
+    pub history_focus: Option<usize>,
+    pub history_focus_actions: bool,
+    pub history_loading_older: bool,
+    pub history_sections: BTreeMap<tasks::PeriodKey, Vec<FurTaskGroup>>,
+    pub history_selection: Option<HistorySelection>,
     pub idle: FurIdle,
+    pub idle_backend_status: Option<Result<FurIdleBackend, String>>,
     pub inspector_view: Option<FurInspectorView>,
+    pub invoice_to_generate: Option<InvoiceToGenerate>,
+    pub keyboard_modifiers: keyboard::Modifiers,
+    pub legacy_upgrade: Option<UpgradeProgress>,
     pub localization: Arc<Localization>,
+    pub logged_task: Option<FurTask>,
     pub login_message: Result<String, Box<dyn std::error::Error>>,
+    pub long_task_to_confirm: Option<FurTask>,
+    pub notification_status: Option<Result<(), String>>,
+    pub onboarding: Option<FurOnboarding>,
+    pub pending_sync_changes: usize,
+    pub planned_time_notified: Option<i64>,
     pub pomodoro: FurPomodoro,
+    pub profile_to_add: ProfileToAdd,
+    pub project_color_to_edit: Option<String>,
+    pub project_colors: HashMap<String, Srgb>,
+    pub project_default_tags: HashMap<String, String>,
+    pub recent_tasks: RecentTasks,
     pub report: FurReport,
+    pub report_export_message: Result<String, Box<dyn std::error::Error>>,
+    pub running_task_input: String,
+    pub running_task_notes: text_editor::Content,
+    pub scheduled_summary_failed: Option<NaiveDate>,
+    pub scheduled_summary_in_progress: bool,
+    pub search_end_reached: bool,
+    pub search_query: String,
+    pub search_results: Vec<FurTask>,
+    pub secondary_timer_input: String,
+    pub secondary_timers: Vec<FurRunningTimer>,
+    pub secondary_timers_autosaved_at: DateTime<Local>,
     pub settings_active_tab: TabId,
     pub settings_csv_message: Result<String, Box<dyn std::error::Error>>,
     pub settings_database_message: Result<String, Box<dyn std::error::Error>>,
+    pub settings_find_replace_message: Result<String, Box<dyn std::error::Error>>,
     pub settings_more_message: Result<String, Box<dyn std::error::Error>>,
+    pub settings_profiles_message: Result<String, Box<dyn std::error::Error>>,
+    pub settings_recovery: Option<SettingsRecovery>,
     pub settings_server_choice: Option<ServerChoices>,
+    pub shortcut_focus: Option<usize>,
+    pub shortcut_focus_actions: bool,
+    pub shortcut_usage: HashMap<String, ShortcutUsage>,
     pub shortcuts: Vec<FurShortcut>,
     pub shortcut_to_add: Option<ShortcutToAdd>,
     pub shortcut_to_edit: Option<ShortcutToEdit>,
+    pub shortcut_to_switch_to: Option<FurShortcut>,
+    pub show_archived_todos: bool,
+    pub show_running_task_notes: bool,
     pub show_sidebar: bool,
     pub show_timer_start_picker: bool,
+    pub show_auto_stop_time_picker: bool,
+    pub status_server: Option<StatusServer>,
+    pub status_snapshot: Arc<RwLock<StatusSnapshot>>,
+    pub stopwatch_tick_id: u64,
+    pub summary_file_pending: bool,
+    pub summary_file_written_at: Option<DateTime<Local>>,
+    pub sync_history: Vec<FurSyncRecord>,
+    pub sync_retry_attempt: Option<u32>,
+    pub sync_started_at: Option<DateTime<Local>>,
+    pub sync_suspended: bool,
+    pub system_theme: FurTheme,
     pub task_history: BTreeMap<NaiveDate, Vec<FurTaskGroup>>,
     pub task_input: String,
+    pub task_input_focused: bool,
+    pub focused_input: Option<&'static str>,
     pub task_to_add: Option<TaskToAdd>,
     pub task_to_edit: Option<TaskToEdit>,
     pub timer_is_running: bool,
+    pub timer_notice: Option<String>,
+    pub timer_paused: bool,
+    pub timer_paused_at: DateTime<Local>,
+    pub timer_start_error: Option<String>,
+    /// The start that was chosen and the stop of the task it overlaps
+    pub timer_start_overlap: Option<(DateTime<Local>, DateTime<Local>)>,
     pub timer_start_time: DateTime<Local>,
     pub timer_text: String,
     pub todo_to_add: Option<TodoToAdd>,
     pub todo_to_edit: Option<TodoToEdit>,
     pub todos: BTreeMap<NaiveDate, Vec<FurTodo>>,
+    pub trash: Vec<DeletedItem>,
+    pub window_geometry_changed: bool,
+    pub window_height: f32,
+    pub window_maximized: bool,
+    pub window_minimized: bool,
+    pub window_position: Option<Point>,
+    pub window_width: f32,
+    pub year_in_review: Option<YearInReview>,
 }
 
 impl Furtherance {
-    pub fn new() -> (Self, iced::Task<Message>) {
-        // Load settings
-        let mut settings = match FurSettings::new() {
-            Ok(loaded_settings) => loaded_settings,
-            Err(e) => {
-                eprintln!("Error loading settings: {}", e);
-                FurSettings::default()
-            }
-        };
+    /// Settings are loaded in main before the window is built so it can open at its saved size
+    pub fn new(
+        mut settings: FurSettings,
+        settings_recovery: Option<SettingsRecovery>,
+    ) -> (Self, iced::Task<Message>) {
+        set_current_device_name(&settings.device_name);
+        set_default_currency(&settings.default_currency);
+        set_use_24_hour_time(settings.use_24_hour_time);
+        let mut startup_errors = VecDeque::new();
         // Load or create database
         if let Err(e) = db_init() {
             if let Err(e) = settings.reset_to_default_db_location() {
-                eprintln!("Error loading database. Can't load or save data: {}", e);
+                log_error(
+                    &mut startup_errors,
+                    format!("Error loading database. Can't load or save data: {}", e),
+                );
             }
-            eprintln!(
-                "Error loading database. Reverting to default location: {}",
-                e
+            log_error(
+                &mut startup_errors,
+                format!(
+                    "Error loading database. Reverting to default location: {}",
+                    e
+                ),
             );
         }
+        if !settings.tags_normalized {
+            match db_normalize_all_tags() {
+                Ok(_) => {
+                    if let Err(e) = settings.change_tags_normalized(true) {
+                        log_error(
+                            &mut startup_errors,
+                            format!("Failed to change tags_normalized in settings: {}", e),
+                        );
+                    }
+                }
+                Err(e) => log_error(
+                    &mut startup_errors,
+                    format!("Error normalizing saved tags: {}", e),
+                ),
+            }
+        }
 
         // Load user credentials from database
         let saved_user = match db_retrieve_credentials() {
             Ok(optional_user) => optional_user,
             Err(e) => {
-                eprintln!("Error retrieving user credentials from database: {}", e);
+                log_error(
+                    &mut startup_errors,
+                    format!("Error retrieving user credentials from database: {}", e),
+                );
                 None
             }
         };
 
+        // Empty the trash of anything past the retention period
+        if let Err(e) = db_purge_deleted_before(purge_cutoff(
+            sync_timestamp(),
+            settings.trash_retention_days,
+            saved_user.as_ref().map(|_| settings.last_sync),
+        )) {
+            log_error(
+                &mut startup_errors,
+                format!("Error purging old deleted items: {}", e),
+            );
+        }
+
         // Set application identifier for notifications
         #[cfg(target_os = "macos")]
         if let Err(e) = set_application("io.unobserved.furtherance") {
-            eprintln!(
-                "Failed to set application identifier for notifications: {}",
-                e
+            log_error(
+                &mut startup_errors,
+                format!(
+                    "Failed to set application identifier for notifications: {}",
+                    e
+                ),
             );
         }
 
         let mut furtherance = Furtherance {
+            active_history_filters: HashSet::new(),
+            all_projects: vec![],
+            archived_todos: BTreeMap::<chrono::NaiveDate, Vec<FurTodo>>::new(),
+            clock_skew: None,
+            csv_import_errors: vec![],
             current_view: settings.default_view,
+            database_merge: None,
+            day_notes: get_day_notes(),
+            day_note_to_edit: None,
+            days_off: FurDaysOff::new(get_days_off(), settings.report_weekends_off),
+            delete_everything_confirmation: String::new(),
             delete_tasks_from_context: None,
             delete_shortcut_from_context: None,
             delete_todo_uid: None,
+            discard_changes_then: None,
             displayed_alert: None,
             displayed_task_start_time: time_picker::Time::now_hm(true),
+            dropped_database: None,
+            duplicates_found: None,
+            earnings_exclusions_input: String::new(),
+            errors: VecDeque::new(),
+            errors_viewed_at: None,
             export_settings: ExportSettings::new(),
+            find_replace: FindReplace::new(),
             fur_settings: settings,
             fur_user: saved_user.clone(),
             fur_user_fields: match &saved_user {
@@ -183,17 +389,55 @@ impl Furtherance {
                 },
                 None => FurUserFields::default(),
             },
+            goal_to_add: GoalToAdd::new(),
             group_to_edit: None,
+            history_days_loaded: 0,
+            history_end_reached: false,
+            history_filter_chips: vec![],
+            history_focus: None,
+            history_focus_actions: false,
+            history_loading_older: false,
+            history_sections: BTreeMap::new(),
+            history_selection: None,
             idle: FurIdle::new(),
+            idle_backend_status: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             localization: Arc::new(Localization::new()),
+            logged_task: None,
             login_message: Ok(String::new()),
+            long_task_to_confirm: None,
+            notification_status: None,
+            onboarding: None,
+            pending_sync_changes: 0,
+            planned_time_notified: None,
             pomodoro: FurPomodoro::new(),
+            profile_to_add: ProfileToAdd::new(),
+            project_color_to_edit: None,
+            project_colors: HashMap::new(),
+            project_default_tags: HashMap::new(),
             inspector_view: None,
+            invoice_to_generate: None,
+            legacy_upgrade: None,
+            recent_tasks: RecentTasks::new(),
             report: FurReport::new(),
+            report_export_message: Ok(String::new()),
+            running_task_input: String::new(),
+            running_task_notes: text_editor::Content::new(),
+            scheduled_summary_failed: None,
+            scheduled_summary_in_progress: false,
+            search_end_reached: true,
+            search_query: String::new(),
+            search_results: vec![],
+            secondary_timer_input: String::new(),
+            secondary_timers: vec![],
+            secondary_timers_autosaved_at: Local::now(),
             settings_active_tab: TabId::General,
             settings_csv_message: Ok(String::new()),
             settings_database_message: Ok(String::new()),
+            settings_find_replace_message: Ok(String::new()),
             settings_more_message: Ok(String::new()),
+            settings_profiles_message: Ok(String::new()),
+            settings_recovery,
             settings_server_choice: if saved_user
                 .as_ref()
                 .map_or(false, |user| user.server != OFFICIAL_SERVER)
@@ -202,56 +446,166 @@ impl Furtherance {
             } else {
                 Some(ServerChoices::Official)
             },
+            shortcut_focus: None,
+            shortcut_focus_actions: false,
+            shortcut_usage: match db_retrieve_shortcut_usage() {
+                Ok(usage) => usage,
+                Err(e) => {
+                    log_error(
+                        &mut startup_errors,
+                        format!("Error reading shortcut usage from database: {}", e),
+                    );
+                    HashMap::new()
+                }
+            },
             shortcuts: match db_retrieve_existing_shortcuts() {
                 Ok(shortcuts) => shortcuts,
                 Err(e) => {
-                    eprintln!("Error reading shortcuts from database: {}", e);
+                    log_error(
+                        &mut startup_errors,
+                        format!("Error reading shortcuts from database: {}", e),
+                    );
                     vec![]
                 }
             },
             shortcut_to_add: None,
             shortcut_to_edit: None,
+            shortcut_to_switch_to: None,
+            show_archived_todos: false,
+            show_running_task_notes: false,
             show_sidebar: true,
             show_timer_start_picker: false,
+            show_auto_stop_time_picker: false,
+            status_server: None,
+            status_snapshot: Arc::new(RwLock::new(StatusSnapshot::default())),
+            stopwatch_tick_id: 0,
+            summary_file_pending: false,
+            summary_file_written_at: None,
+            sync_history: Vec::new(),
+            sync_retry_attempt: None,
+            sync_started_at: None,
+            sync_suspended: false,
+            system_theme: FurTheme::detect(),
             task_history: BTreeMap::<chrono::NaiveDate, Vec<FurTaskGroup>>::new(),
             task_input: "".to_string(),
+            task_input_focused: false,
+            focused_input: None,
             task_to_add: None,
             task_to_edit: None,
             timer_is_running: false,
+            timer_notice: None,
+            timer_paused: false,
+            timer_paused_at: Local::now(),
+            timer_start_error: None,
+            timer_start_overlap: None,
             timer_start_time: Local::now(),
             timer_text: "0:00:00".to_string(),
             todo_to_add: None,
             todo_to_edit: None,
             todos: BTreeMap::<chrono::NaiveDate, Vec<FurTodo>>::new(),
+            trash: Vec::new(),
+            window_geometry_changed: false,
+            window_height: DEFAULT_WINDOW_HEIGHT,
+            window_maximized: false,
+            window_minimized: false,
+            window_position: None,
+            window_width: DEFAULT_WINDOW_WIDTH,
+            year_in_review: None,
         };
+        furtherance.errors = startup_errors;
+        if let Some((width, height)) = furtherance.fur_settings.saved_window_size() {
+            furtherance.window_width = width;
+            furtherance.window_height = height;
+        }
+        furtherance.window_maximized = furtherance.fur_settings.window_maximized;
+        furtherance.earnings_exclusions_input =
+            furtherance.fur_settings.earnings_exclusions.join(", ");
 
         furtherance.timer_text = get_timer_text(&furtherance, 0);
+        all_charts::set_chart_theme(furtherance.resolved_theme());
+        all_charts::set_chart_net_earnings(net_earnings_deduction(&furtherance.fur_settings));
+        // Replace any status file left behind by an unclean exit
+        update_status_file(&furtherance);
+
+        if furtherance.settings_recovery.is_some() {
+            furtherance.displayed_alert = Some(FurAlert::SettingsRecovered);
+        }
 
-        if autosave_exists() {
-            restore_autosave();
-            if furtherance.displayed_alert == None {
-                furtherance.displayed_alert = Some(FurAlert::AutosaveRestored);
+        match restore_autosave(auto_stop_time(&furtherance.fur_settings)) {
+            Ok(AutosaveRestore::Restored) => {
+                if furtherance.displayed_alert == None {
+                    furtherance.displayed_alert = Some(FurAlert::AutosaveRestored);
+                }
+            }
+            Ok(AutosaveRestore::NotPresent) => {}
+            Err(e) => {
+                log_error(&mut furtherance.errors, e.to_string());
+                if furtherance.displayed_alert == None {
+                    furtherance.displayed_alert = Some(FurAlert::AutosaveCorrupt);
+                }
             }
         }
 
-        // Ask user to import old Furtherance database on first run
+        // Walk new users through the basics until they finish or skip the onboarding
         if furtherance.fur_settings.first_run {
             #[cfg(target_os = "macos")]
-            {
-                furtherance.displayed_alert = db_check_for_existing_mac_db();
-            }
+            let offer_old_db_import = db_check_for_existing_mac_db();
+            #[cfg(not(target_os = "macos"))]
+            let offer_old_db_import = false;
 
-            let _ = furtherance.fur_settings.change_first_run(false);
-            let _ = furtherance.fur_settings.change_notify_of_sync(false);
+            furtherance.onboarding = Some(FurOnboarding::new(offer_old_db_import));
+            furtherance.displayed_alert = Some(FurAlert::Onboarding);
         } else if furtherance.fur_settings.notify_of_sync {
             furtherance.displayed_alert = Some(FurAlert::NotifyOfSync)
         }
 
-        furtherance.task_history = tasks::get_task_history(furtherance.fur_settings.days_to_show);
-        furtherance.todos = todos::get_all_todos();
-        furtherance.export_settings.get_all_projects();
+        furtherance.history_days_loaded = furtherance.fur_settings.days_to_show;
+        furtherance.task_history = tasks::get_task_history(furtherance.history_days_loaded);
+        furtherance.history_filter_chips =
+            tasks::most_used_filter_chips(&furtherance.task_history, HISTORY_FILTER_CHIP_COUNT);
+        refresh_history_sections(&mut furtherance);
+        set_todo_list(&mut furtherance, todos::get_all_todos());
+        furtherance.pomodoro.stats = FurPomodoroStats::load(Local::now().date_naive());
+        refresh_project_lists(&mut furtherance);
+        refresh_recent_tasks(&mut furtherance);
+        furtherance
+            .report
+            .set_archived_projects(furtherance.fur_settings.archived_projects.clone());
+        furtherance
+            .report
+            .set_monthly_earnings_target(furtherance.fur_settings.monthly_earnings_target);
+        furtherance
+            .report
+            .set_earnings_exclusions(furtherance.fur_settings.earnings_exclusions.clone());
+        furtherance
+            .report
+            .set_days_off(furtherance.days_off.clone());
+        furtherance
+            .report
+            .set_show_heatmap(furtherance.fur_settings.show_chart_heatmap);
+        furtherance
+            .report
+            .set_show_todo_chart(furtherance.fur_settings.show_chart_todos);
+        restart_status_server(&mut furtherance);
+
+        let mut tasks: Vec<Task<Message>> = vec![
+            furtherance.report.refresh(),
+            // Catch up on a summary that came due while Furtherance was closed
+            check_scheduled_summary(&mut furtherance),
+            // Checked once so a missing notification daemon isn't retried at every reminder
+            Task::perform(
+                async { check_notification_support() },
+                Message::NotificationSupportChecked,
+            ),
+        ];
 
-        let mut tasks: Vec<Task<Message>> = vec![];
+        // A remembered position may be off screen after a monitor change
+        if furtherance.fur_settings.saved_window_size().is_some() {
+            tasks.push(window::latest().and_then(|id| {
+                window::monitor_size(id)
+                    .map(move |monitor_size| Message::WindowMonitorSizeChecked(id, monitor_size))
+            }));
+        }
 
         if furtherance.fur_user.is_some() {
             tasks.push(Task::perform(
@@ -267,18 +621,24 @@ impl Furtherance {
     }
 
     pub fn title(&self) -> String {
-        "Furtherance".to_owned()
+        if self.timer_is_running && !self.timer_paused && self.fur_settings.show_timer_in_title {
+            let (name, _, _, _) = split_task_input(&self.task_input);
+            window_title(&self.timer_text, &name)
+        } else {
+            "Furtherance".to_owned()
+        }
     }
 
     pub fn theme(&self) -> Theme {
-        match dark_light::detect() {
-            Ok(mode) => match mode {
-                dark_light::Mode::Light | dark_light::Mode::Unspecified => {
-                    FurTheme::Light.to_theme()
-                }
-                dark_light::Mode::Dark => FurTheme::Dark.to_theme(),
-            },
-            Err(_) => FurTheme::Light.to_theme(),
+        self.resolved_theme().to_theme()
+    }
+
+    /// The theme chosen in settings, or the system's when set to follow it
+    pub fn resolved_theme(&self) -> FurTheme {
+        match self.fur_settings.theme_preference {
+            FurThemePreference::System => self.system_theme,
+            FurThemePreference::Light => FurTheme::Light,
+            FurThemePreference::Dark => FurTheme::Dark,
         }
     }
 
@@ -294,7 +654,22 @@ impl Furtherance {
             None
         };
 
+        fn handle_window_event((id, event): (window::Id, window::Event)) -> Option<Message> {
+            match event {
+                window::Event::Focused | window::Event::Unfocused | window::Event::Resized(_) => {
+                    Some(Message::WindowStateChanged(id))
+                }
+                window::Event::Moved(position) => Some(Message::WindowMoved(position)),
+                window::Event::FileDropped(path) => Some(Message::FileDropped(path)),
+                _ => None,
+            }
+        }
+
         fn handle_hotkey(event: keyboard::Event) -> Option<Message> {
+            // Text inputs capture Enter, so Ctrl+Enter is told apart by the held modifiers
+            if let keyboard::Event::ModifiersChanged(modifiers) = event {
+                return Some(Message::KeyboardModifiersChanged(modifiers));
+            }
             let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
                 return None;
             };
@@ -303,25 +678,151 @@ impl Furtherance {
                 (keyboard::Key::Named(keyboard::key::Named::Tab), _) => Some(Message::TabPressed {
                     shift: modifiers.shift(),
                 }),
+                (keyboard::Key::Character(c), modifiers)
+                    if modifiers.command() && c.as_str().eq_ignore_ascii_case("f") =>
+                {
+                    Some(Message::SearchShortcutPressed)
+                }
+                (keyboard::Key::Named(keyboard::key::Named::ArrowUp), _) => {
+                    Some(Message::KeyboardNavigate(NavDirection::Up))
+                }
+                (keyboard::Key::Named(keyboard::key::Named::ArrowDown), _) => {
+                    Some(Message::KeyboardNavigate(NavDirection::Down))
+                }
+                (keyboard::Key::Named(keyboard::key::Named::ArrowLeft), _) => {
+                    Some(Message::KeyboardNavigate(NavDirection::Left))
+                }
+                (keyboard::Key::Named(keyboard::key::Named::ArrowRight), _) => {
+                    Some(Message::KeyboardNavigate(NavDirection::Right))
+                }
+                // Keys typed into a text input never get here, so j and k are free to use
+                (keyboard::Key::Character(c), modifiers)
+                    if modifiers.is_empty() && c.as_str() == "k" =>
+                {
+                    Some(Message::KeyboardNavigate(NavDirection::Up))
+                }
+                (keyboard::Key::Character(c), modifiers)
+                    if modifiers.is_empty() && c.as_str() == "j" =>
+                {
+                    Some(Message::KeyboardNavigate(NavDirection::Down))
+                }
+                (keyboard::Key::Named(keyboard::key::Named::Enter), _) => {
+                    Some(Message::KeyboardActivate)
+                }
+                (keyboard::Key::Named(keyboard::key::Named::Delete), _) => {
+                    Some(Message::KeyboardDelete)
+                }
+                (keyboard::Key::Named(keyboard::key::Named::ContextMenu), _) => {
+                    Some(Message::KeyboardContextMenu)
+                }
+                (keyboard::Key::Named(keyboard::key::Named::F10), modifiers)
+                    if modifiers.shift() =>
+                {
+                    Some(Message::KeyboardContextMenu)
+                }
+                (keyboard::Key::Named(keyboard::key::Named::Escape), _) => {
+                    Some(Message::KeyboardFocusCleared)
+                }
                 _ => None,
             }
         }
 
-        let timed_sync = if self.fur_user.is_some() {
-            let sync_interval = 900; // 15 mins in secs
+        // An outdated client stops syncing until it's updated or the user retries
+        let timed_sync =
+            match FurSyncInterval::from_minutes(self.fur_settings.sync_interval_minutes) {
+                FurSyncInterval::Minutes(minutes)
+                    if self.fur_user.is_some() && !self.sync_suspended =>
+                {
+                    Some(
+                        iced::time::every(Duration::from_secs(u64::from(minutes) * 60))
+                            .map(|_| Message::SyncTimerFired),
+                    )
+                }
+                _ => None,
+            };
+
+        // Retry sooner than the regular interval while the server is unreachable
+        let retry_sync = match self.sync_retry_attempt {
+            Some(attempt) if self.fur_user.is_some() && !self.sync_suspended => {
+                Some(iced::time::every(sync_retry_delay(attempt)).map(|_| Message::SyncWithServer))
+            }
+            _ => None,
+        };
+
+        // Only follow the system theme when the user hasn't picked one
+        let system_theme_check = match self.fur_settings.theme_preference {
+            FurThemePreference::System => Some(
+                iced::time::every(Duration::from_secs(SYSTEM_THEME_CHECK_SECONDS))
+                    .map(|_| Message::CheckSystemTheme),
+            ),
+            FurThemePreference::Light | FurThemePreference::Dark => None,
+        };
+
+        let scheduled_summary_check = match self.fur_settings.scheduled_summary {
+            FurSummarySchedule::Off => None,
+            FurSummarySchedule::Daily | FurSummarySchedule::Weekly => Some(
+                iced::time::every(Duration::from_secs(SCHEDULED_SUMMARY_CHECK_SECONDS))
+                    .map(|_| Message::ScheduledSummaryCheck),
+            ),
+        };
+
+        // Resizing and moving send a stream of events, so only save once they settle
+        let save_window_geometry = if self.window_geometry_changed {
             Some(
-                iced::time::every(Duration::from_secs(sync_interval))
-                    .map(|_| Message::SyncWithServer),
+                iced::time::every(Duration::from_secs(WINDOW_GEOMETRY_SAVE_SECONDS))
+                    .map(|_| Message::SaveWindowGeometry),
             )
         } else {
             None
         };
 
+        // The main timer schedules its own ticks, but secondary timers share one
+        let secondary_timer_tick = if self.secondary_timers.is_empty() {
+            None
+        } else {
+            Some(iced::time::every(Duration::from_secs(1)).map(|_| Message::SecondaryTimerTick))
+        };
+
+        // Clicking anywhere that isn't a widget, or pressing Escape even while
+        // the task input has focus, closes the recent tasks menu
+        let dismiss_recent_tasks = if self.recent_tasks.is_open || self.task_input_focused {
+            Some(event::listen_with(|app_event, status, _| {
+                match (app_event, status) {
+                    (
+                        iced::Event::Mouse(mouse::Event::ButtonPressed(_)),
+                        event::Status::Ignored,
+                    ) => Some(Message::RecentTasksDismissed),
+                    (
+                        iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                            ..
+                        }),
+                        _,
+                    ) => Some(Message::RecentTasksDismissed),
+                    _ => None,
+                }
+            }))
+        } else {
+            None
+        };
+
         Subscription::batch([
             keyboard::listen().filter_map(handle_hotkey),
+            window::close_requests().map(Message::WindowCloseRequested),
+            window::events().filter_map(handle_window_event),
+            window::resize_events().map(|(_, size)| Message::WindowResized(size)),
             subscription::from_recipe(MidnightSubscription),
+            subscription::from_recipe(NotificationActionSubscription),
+            subscription::from_recipe(PowerSubscription),
+            subscription::from_recipe(RaiseRequestSubscription),
             show_reminder_notification.unwrap_or(Subscription::none()),
             timed_sync.unwrap_or(Subscription::none()),
+            retry_sync.unwrap_or(Subscription::none()),
+            system_theme_check.unwrap_or(Subscription::none()),
+            scheduled_summary_check.unwrap_or(Subscription::none()),
+            secondary_timer_tick.unwrap_or(Subscription::none()),
+            save_window_geometry.unwrap_or(Subscription::none()),
+            dismiss_recent_tasks.unwrap_or(Subscription::none()),
         ])
     }
 
@@ -364,6 +865,15 @@ impl Furtherance {
         } else {
             None
         };
+        let sidebar_button_in_search = if !self.show_sidebar {
+            Some(
+                button(bootstrap::layout_sidebar_inset())
+                    .on_press(Message::ToggleSidebar)
+                    .style(button::text),
+            )
+        } else {
+            None
+        };
         let sidebar_button_in_report = if !self.show_sidebar {
             Some(
                 row![
@@ -402,33 +912,71 @@ impl Furtherance {
                             FurView::Report,
                             self.current_view == FurView::Report
                         ),
+                        nav_button(
+                            self.localization.get_message("search", None),
+                            FurView::Search,
+                            self.current_view == FurView::Search
+                        ),
                         space::vertical().height(Length::Fill),
                         if self.timer_is_running && self.current_view != FurView::Timer {
-                            text(convert_timer_text_to_vertical_hms(
-                                &self.timer_text,
-                                &self.localization,
-                            ))
-                            .size(50)
-                            .style(|theme| {
-                                if self.pomodoro.on_break {
-                                    style::red_text(theme)
-                                } else {
-                                    text::Style::default()
-                                }
-                            })
+                            Some(
+                                column![
+                                    if self.timer_paused {
+                                        Some(bootstrap::pause_fill().size(30))
+                                    } else {
+                                        None
+                                    },
+                                    text(convert_timer_text_to_vertical_hms(
+                                        &self.timer_text,
+                                        &self.localization,
+                                    ))
+                                    .size(50)
+                                    .style(|theme| {
+                                        if self.pomodoro.on_break {
+                                            style::red_text(theme)
+                                        } else {
+                                            text::Style::default()
+                                        }
+                                    }),
+                                    if self.secondary_timers.is_empty() {
+                                        None
+                                    } else {
+                                        Some(
+                                            Container::new(text(format!(
+                                                "+{}",
+                                                self.secondary_timers.len()
+                                            )))
+                                            .align_x(alignment::Horizontal::Center)
+                                            .width(30)
+                                            .style(style::group_count_circle),
+                                        )
+                                    },
+                                ]
+                                .spacing(5),
+                            )
                         } else {
-                            text("")
+                            None
                         },
                         nav_button(
                             self.localization.get_message("settings", None),
                             FurView::Settings,
                             self.current_view == FurView::Settings
                         ),
+                        self.fur_user.as_ref().map(|_| sync_status_button(
+                            self.fur_settings.last_successful_sync,
+                            self.sync_retry_attempt.is_some(),
+                            self.sync_suspended,
+                            &self.localization,
+                        )),
+                        error_log_badge(
+                            unseen_error_count(&self.errors, self.errors_viewed_at),
+                            &self.localization,
+                        ),
                     ]
                     .spacing(12)
                     .align_x(Alignment::Start),
                 )
-                .width(175)
+                .width(SIDEBAR_WIDTH)
                 .padding(10)
                 .clip(true)
                 .style(style::gray_background),
@@ -438,23 +986,64 @@ impl Furtherance {
         };
 
         // MARK: Shortcuts
-        let mut shortcuts_row = Row::new().spacing(20.0);
-        for shortcut in &self.shortcuts {
-            shortcuts_row = shortcuts_row.push(shortcut_button(
+        let mut shortcuts_row = Row::new().spacing(SHORTCUTS_SPACING);
+        // Moving shortcuts only makes sense in the order they're saved in
+        let can_reorder = self.fur_settings.shortcut_sort == FurShortcutSort::Manual;
+        for (index, shortcut) in sort_shortcuts(
+            &self.shortcuts,
+            &self.shortcut_usage,
+            self.fur_settings.shortcut_sort,
+        )
+        .into_iter()
+        .enumerate()
+        {
+            let is_focused = self.shortcut_focus == Some(index);
+            let use_count = if self.fur_settings.show_shortcut_stats {
+                Some(
+                    self.shortcut_usage
+                        .get(&shortcut.uid)
+                        .map_or(0, |usage| usage.count),
+                )
+            } else {
+                None
+            };
+            let mut shortcut_cell = column![shortcut_button(
                 shortcut,
-                self.timer_is_running,
+                self.timer_is_running && self.pomodoro.on_break,
+                use_count,
+                can_reorder,
                 &self.localization,
-            ));
+            )]
+            .spacing(5);
+            if is_focused && self.shortcut_focus_actions {
+                shortcut_cell = shortcut_cell.push(
+                    Container::new(shortcut_actions(shortcut, can_reorder, &self.localization))
+                        .max_width(150)
+                        .style(style::task_row),
+                );
+            }
+            shortcuts_row = shortcuts_row.push(
+                container(shortcut_cell)
+                    .padding(SHORTCUT_FOCUS_RING_WIDTH)
+                    .style(move |theme| style::keyboard_focus_ring(theme, is_focused)),
+            );
         }
 
         let new_shortcut_row = if self.inspector_view.is_none() {
             row![
                 sidebar_button_in_shortcuts,
                 space::horizontal(),
+                pick_list(
+                    &FurShortcutSort::ALL[..],
+                    Some(self.fur_settings.shortcut_sort),
+                    Message::ShortcutSortSelected,
+                ),
                 button(bootstrap::plus_lg())
                     .on_press(Message::AddNewShortcutPressed)
                     .style(button::text),
             ]
+            .spacing(10)
+            .align_y(Alignment::Center)
             .padding([10, 20])
         } else {
             row![
@@ -468,6 +1057,7 @@ impl Furtherance {
         let shortcuts_view = column![
             new_shortcut_row,
             Scrollable::new(column![shortcuts_row.width(Length::Fill).wrap()].padding(20))
+                .id(SHORTCUTS_SCROLLABLE_ID)
         ];
 
         // MARK: TIMER
@@ -478,39 +1068,122 @@ impl Furtherance {
                 bottom: 0.0,
                 left: 20.0,
             });
-        for (date, task_groups) in self.task_history.iter().rev() {
-            let (total_time, total_earnings) = task_groups.iter().fold(
-                (0i64, 0f32),
-                |(accumulated_time, accumulated_earnings), group| {
-                    let group_time = group.total_time;
-                    let group_earnings = (group_time as f32 / 3600.0) * group.rate;
-
+        // The running task isn't filtered, so only add it to unfiltered totals
+        let running_timer_in = |includes_timer_start: bool| -> Option<(bool, i64, f32)> {
+            if includes_timer_start && self.active_history_filters.is_empty() {
+                let (_, project, tags, rate) = split_task_input(&self.task_input);
+                let billable_rate = if task_input_is_billable(&self.task_input, rate)
+                    && !is_excluded_from_earnings(
+                        &tags,
+                        &project,
+                        &self.fur_settings.earnings_exclusions,
+                    ) {
+                    rate
+                } else {
+                    0.0
+                };
+                let seconds_elapsed = timer_now(self)
+                    .signed_duration_since(self.timer_start_time)
+                    .num_seconds();
+                Some((self.timer_is_running, seconds_elapsed, billable_rate))
+            } else {
+                None
+            }
+        };
+        let history_grouping = self.fur_settings.history_grouping;
+        let history_sections: Vec<(Element<'_, Message>, Vec<&FurTaskGroup>)> = if history_grouping
+            == FurHistoryGrouping::Day
+        {
+            self.task_history
+                .iter()
+                .rev()
+                .map(|(date, task_groups)| {
                     (
-                        accumulated_time + group_time,
-                        accumulated_earnings + group_earnings,
+                        date,
+                        filtered_history_groups(task_groups, &self.active_history_filters),
                     )
-                },
-            );
-            all_history_rows = all_history_rows.push(history_title_row(
-                date,
-                total_time,
-                total_earnings,
-                &self.fur_settings,
-                if self.timer_start_time.date_naive() == *date {
-                    let (_, _, _, rate) = split_task_input(&self.task_input);
-                    Some((self.timer_is_running, &self.timer_text, rate))
-                } else {
-                    None
-                },
-                &self.localization,
-            ));
+                })
+                .filter(|(_, task_groups)| !task_groups.is_empty())
+                .map(|(date, task_groups)| {
+                    let (total_time, total_earnings) =
+                        history_day_totals(task_groups.iter().copied(), &self.fur_settings);
+                    let title_row: Element<'_, Message> = history_title_row(
+                        date,
+                        total_time,
+                        total_earnings,
+                        self.day_notes.get(date),
+                        &self.days_off,
+                        &self.fur_settings,
+                        running_timer_in(self.timer_start_time.date_naive() == *date),
+                        &self.localization,
+                    )
+                    .into();
+                    (title_row, task_groups)
+                })
+                .collect()
+        } else {
+            let timer_period =
+                tasks::PeriodKey::containing(self.timer_start_time.date_naive(), history_grouping);
+            self.history_sections
+                .iter()
+                .rev()
+                .map(|(period, task_groups)| {
+                    (
+                        period,
+                        filtered_history_groups(task_groups, &self.active_history_filters),
+                    )
+                })
+                .filter(|(_, task_groups)| !task_groups.is_empty())
+                .map(|(period, task_groups)| {
+                    let (total_time, total_earnings) =
+                        history_day_totals(task_groups.iter().copied(), &self.fur_settings);
+                    let title_row = history_period_title_row(
+                        format_history_period(period, history_grouping, &self.localization),
+                        total_time,
+                        total_earnings,
+                        &self.fur_settings,
+                        running_timer_in(*period == timer_period),
+                        &self.localization,
+                    );
+                    let title_row: Element<'_, Message> =
+                        if history_grouping == FurHistoryGrouping::Week {
+                            weekly_summary_menu(title_row, period.start, &self.localization).into()
+                        } else {
+                            title_row.into()
+                        };
+                    (title_row, task_groups)
+                })
+                .collect()
+        };
+        let mut visible_index = 0;
+        for (title_row, task_groups) in history_sections {
+            all_history_rows = all_history_rows.push(title_row);
             for task_group in task_groups {
+                // Archived projects still count toward the daily totals above
+                if self.fur_settings.hide_archived_in_history
+                    && self.fur_settings.is_project_archived(&task_group.project)
+                {
+                    continue;
+                }
+                // Counted in the same order as tasks::visible_history_groups
+                let is_focused = self.history_focus == Some(visible_index);
+                visible_index += 1;
                 all_history_rows = all_history_rows.push(history_group_row(
                     task_group,
+                    self.project_colors.get(&task_group.project).copied(),
                     self.timer_is_running,
+                    self.history_selection.as_ref(),
+                    is_focused,
                     &self.fur_settings,
                     &self.localization,
-                ))
+                ));
+                if is_focused && self.history_focus_actions {
+                    all_history_rows = all_history_rows.push(
+                        Container::new(history_group_actions(task_group, &self.localization))
+                            .max_width(150)
+                            .style(style::task_row),
+                    );
+                }
             }
         }
 
@@ -519,10 +1192,28 @@ impl Furtherance {
             row![
                 sidebar_button_in_timer,
                 space::horizontal(),
+                pick_list(
+                    &FurHistoryGrouping::ALL[..],
+                    Some(self.fur_settings.history_grouping),
+                    Message::HistoryGroupingSelected,
+                ),
+                button(if self.history_selection.is_some() {
+                    bootstrap::x_lg()
+                } else {
+                    bootstrap::check_square()
+                })
+                .on_press_maybe(if self.task_history.is_empty() {
+                    None
+                } else {
+                    Some(Message::ToggleHistorySelection)
+                })
+                .style(button::text),
                 button(bootstrap::plus_lg())
                     .on_press(Message::AddNewTaskPressed)
                     .style(button::text),
             ]
+            .spacing(10)
+            .align_y(Alignment::Center)
             .padding([10, 20])
         } else {
             row![
@@ -546,6 +1237,45 @@ impl Furtherance {
                 text::Style::default()
             }
         }));
+        if let Some(notice) = &self.timer_notice {
+            timer_view = timer_view.push(text(notice).size(14).style(style::red_text));
+        }
+        let planned_seconds = active_planned_seconds(self);
+        if self.timer_is_running && planned_seconds > 0 {
+            let tracked_seconds = (timer_now(self) - self.timer_start_time).num_seconds();
+            timer_view = timer_view.push(
+                text(self.localization.get_message(
+                    "planned-progress",
+                    Some(&HashMap::from([
+                        ("tracked", FluentValue::from(tracked_seconds / 60)),
+                        ("planned", FluentValue::from(planned_seconds / 60)),
+                    ])),
+                ))
+                .size(14),
+            );
+        }
+        if self.fur_settings.pomodoro {
+            timer_view = timer_view.push(
+                text(self.localization.get_message(
+                    "pomodoro-stats",
+                    Some(&HashMap::from([
+                        ("today", FluentValue::from(self.pomodoro.stats.today)),
+                        ("streak", FluentValue::from(self.pomodoro.stats.streak)),
+                        ("week", FluentValue::from(self.pomodoro.stats.this_week)),
+                    ])),
+                ))
+                .size(14),
+            );
+            if task_input_planned_seconds(&self.task_input) > 0 {
+                timer_view = timer_view.push(
+                    text(
+                        self.localization
+                            .get_message("planned-time-ignored-during-pomodoro", None),
+                    )
+                    .size(12),
+                );
+            }
+        }
         timer_view = timer_view.push(
             column![
                 row![
@@ -555,9 +1285,17 @@ impl Furtherance {
                             .get_message("task-input-placeholder", None),
                         &self.task_input
                     )
+                    .id(TASK_INPUT_ID)
                     .on_input(Message::TaskInputChanged)
                     .on_submit(Message::EnterPressedInTaskInput)
                     .size(20),
+                    button(bootstrap::clock_history().size(20))
+                        .on_press_maybe(if self.timer_is_running {
+                            None
+                        } else {
+                            Some(Message::RecentTasksToggled)
+                        })
+                        .style(button::text),
                     button(row![
                         space::horizontal().width(Length::Fixed(5.0)),
                         if self.timer_is_running {
@@ -573,8 +1311,125 @@ impl Furtherance {
                         Some(Message::StartStopPressed)
                     })
                     .style(style::primary_button_style),
+                    if self.timer_is_running
+                        && !self.pomodoro.on_break
+                        && is_task_switch(&self.running_task_input, &self.task_input)
+                    {
+                        Some(tooltip(
+                            button(text(self.localization.get_message("switch-task", None)))
+                                .on_press(Message::SwitchTask)
+                                .style(style::primary_button_style),
+                            container(text(self.localization.get_message(
+                                "switch-task-description",
+                                Some(&HashMap::from([(
+                                    "task",
+                                    FluentValue::from(self.running_task_input.clone()),
+                                )])),
+                            )))
+                            .padding(10)
+                            .style(container::rounded_box),
+                            tooltip::Position::Bottom,
+                        ))
+                    } else {
+                        None
+                    },
+                    if self.timer_is_running {
+                        Some(
+                            button(row![
+                                space::horizontal().width(Length::Fixed(5.0)),
+                                if self.timer_paused {
+                                    bootstrap::play_fill().size(20)
+                                } else {
+                                    bootstrap::pause_fill().size(20)
+                                },
+                                space::horizontal().width(Length::Fixed(5.0)),
+                            ])
+                            .on_press(Message::PauseResumePressed)
+                            .style(style::primary_button_style),
+                        )
+                    } else {
+                        None
+                    },
+                    if self.timer_is_running && !self.pomodoro.on_break {
+                        Some(tooltip(
+                            button(if self.running_task_notes.text().trim().is_empty() {
+                                bootstrap::pencil().size(20)
+                            } else {
+                                bootstrap::pencil_fill().size(20)
+                            })
+                            .on_press(Message::ToggleRunningTaskNotes)
+                            .style(button::text),
+                            container(text(self.localization.get_message("task-notes", None)))
+                                .padding(10)
+                                .style(container::rounded_box),
+                            tooltip::Position::Bottom,
+                        ))
+                    } else {
+                        None
+                    },
                 ]
+                .align_y(Alignment::Center)
                 .spacing(10),
+                if self.recent_tasks.is_open {
+                    Some(recent_tasks_menu(&self.recent_tasks, &self.localization))
+                } else {
+                    None
+                },
+                project_default_tags_hint(
+                    &self.task_input,
+                    &self.project_default_tags,
+                    &self.localization
+                ),
+                if self.timer_is_running {
+                    None
+                } else {
+                    split_logged_duration(&self.task_input).map(|(_, seconds)| {
+                        text(self.localization.get_message(
+                            "log-duration-hint",
+                            Some(&HashMap::from([(
+                                "duration",
+                                FluentValue::from(format_duration_input(seconds)),
+                            )])),
+                        ))
+                        .size(12)
+                    })
+                },
+                self.logged_task.as_ref().map(|task| {
+                    row![
+                        text(self.localization.get_message(
+                            "task-logged",
+                            Some(&HashMap::from([
+                                ("task", FluentValue::from(task.name.clone())),
+                                (
+                                    "duration",
+                                    FluentValue::from(format_duration_input(
+                                        task.total_time_in_seconds()
+                                    )),
+                                ),
+                            ])),
+                        ))
+                        .size(14),
+                        button(text(self.localization.get_message("undo", None)).size(14))
+                            .on_press(Message::UndoLoggedTask)
+                            .style(button::text),
+                    ]
+                    .align_y(Alignment::Center)
+                    .spacing(5)
+                }),
+                if self.timer_is_running && !self.pomodoro.on_break && self.show_running_task_notes
+                {
+                    Some(
+                        text_editor(&self.running_task_notes)
+                            .placeholder(
+                                self.localization
+                                    .get_message("task-notes-placeholder", None),
+                            )
+                            .on_action(Message::RunningTaskNotesEdited)
+                            .height(100),
+                    )
+                } else {
+                    None
+                },
                 if self.timer_is_running {
                     row![
                         TimePicker::new(
@@ -584,9 +1439,10 @@ impl Furtherance {
                                 "started-at",
                                 Some(&HashMap::from([(
                                     "time",
-                                    FluentValue::from(
-                                        self.timer_start_time.format("%H:%M").to_string()
-                                    )
+                                    FluentValue::from(format_time(
+                                        self.timer_start_time.time(),
+                                        false
+                                    ))
                                 )]))
                             )))
                             .on_press(Message::ChooseCurrentTaskStartTime)
@@ -594,7 +1450,12 @@ impl Furtherance {
                             Message::CancelCurrentTaskStartTime,
                             Message::SubmitCurrentTaskStartTime,
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time),
+                        if let Some(error) = &self.timer_start_error {
+                            Some(text(error).size(14).style(style::red_text))
+                        } else {
+                            None
+                        },
                     ]
                     .align_y(Alignment::Center)
                     .spacing(10)
@@ -607,6 +1468,13 @@ impl Furtherance {
                 } else {
                     row![]
                 },
+                secondary_timers_column(
+                    &self.secondary_timers,
+                    &self.secondary_timer_input,
+                    self.timer_is_running,
+                    self.fur_settings.show_seconds,
+                    &self.localization,
+                ),
             ]
             .align_x(Alignment::Center)
             .spacing(15)
@@ -618,12 +1486,50 @@ impl Furtherance {
             }),
         );
 
+        // Hidden while the inspector is open to leave room for the history
+        if self.inspector_view.is_none() && !self.history_filter_chips.is_empty() {
+            timer_view = timer_view.push(history_filter_chip_bar(
+                &self.history_filter_chips,
+                &self.active_history_filters,
+                &self.localization,
+            ));
+        }
+
+        if !self.task_history.is_empty() {
+            let history_end: Element<'_, Message> = if self.history_loading_older {
+                text(self.localization.get_message("loading-history", None)).into()
+            } else if self.history_end_reached {
+                text(self.localization.get_message("end-of-history", None)).into()
+            } else {
+                button(text(
+                    self.localization.get_message("load-older-history", None),
+                ))
+                .on_press(Message::LoadOlderHistory)
+                .style(button::text)
+                .into()
+            };
+            all_history_rows = all_history_rows.push(
+                container(history_end)
+                    .center_x(Length::Fill)
+                    .padding([10, 0]),
+            );
+        }
+
         timer_view = timer_view.push(if self.task_history.is_empty() {
             Some(Scrollable::new(column![]).height(Length::Fill))
         } else {
-            Some(Scrollable::new(all_history_rows).height(Length::Fill))
+            Some(
+                Scrollable::new(all_history_rows)
+                    .id(HISTORY_SCROLLABLE_ID)
+                    .on_scroll(|viewport| Message::HistoryScrolled(viewport.relative_offset().y))
+                    .height(Length::Fill),
+            )
         });
 
+        if let Some(selection) = &self.history_selection {
+            timer_view = timer_view.push(bulk_action_bar(selection, &self.localization));
+        }
+
         // MARK: TODOS
         let mut all_todo_rows: Column<'_, Message, Theme, Renderer> = Column::new()
             .spacing(8)
@@ -635,6 +1541,17 @@ impl Furtherance {
             })
             .width(Length::Fill);
 
+        // Completed todos show the time tracked that day on matching tasks
+        let tracked_todo_time = |date: &NaiveDate, todo: &FurTodo| -> Option<i64> {
+            if todo.is_completed && self.fur_settings.show_todo_estimates {
+                self.task_history
+                    .get(date)
+                    .and_then(|task_groups| todo.tracked_time(task_groups))
+            } else {
+                None
+            }
+        };
+
         // First, check for today
         if let Some((date, todos)) = self
             .todos
@@ -642,10 +1559,17 @@ impl Furtherance {
             .find(|(date, _)| date == &&Local::now().date_naive())
         {
             all_todo_rows = all_todo_rows.push(todos::todo_title_row(&date, &self.localization));
+            all_todo_rows = all_todo_rows.push(todos::todo_summary_row(
+                todos,
+                &self.fur_settings,
+                &self.localization,
+            ));
             let mut today_column: Column<'_, Message, Theme, Renderer> = column![].spacing(8);
             for todo in todos.iter().sorted_by_key(|todo| todo.is_completed) {
                 today_column = today_column.push(todos::todo_row(
                     todo,
+                    self.project_colors.get(&todo.project).copied(),
+                    tracked_todo_time(date, todo),
                     self.timer_is_running,
                     &self.fur_settings,
                     &self.localization,
@@ -664,9 +1588,16 @@ impl Furtherance {
             .find(|(date, _)| date == &&(Local::now().date_naive() + TimeDelta::days(1)))
         {
             all_todo_rows = all_todo_rows.push(todos::todo_title_row(&date, &self.localization));
+            all_todo_rows = all_todo_rows.push(todos::todo_summary_row(
+                todos,
+                &self.fur_settings,
+                &self.localization,
+            ));
             for todo in todos.iter().sorted_by_key(|todo| todo.is_completed) {
                 all_todo_rows = all_todo_rows.push(todos::todo_row(
                     todo,
+                    self.project_colors.get(&todo.project).copied(),
+                    tracked_todo_time(date, todo),
                     self.timer_is_running,
                     &self.fur_settings,
                     &self.localization,
@@ -680,9 +1611,16 @@ impl Furtherance {
             {
                 all_todo_rows =
                     all_todo_rows.push(todos::todo_title_row(&date, &self.localization));
+                all_todo_rows = all_todo_rows.push(todos::todo_summary_row(
+                    todos,
+                    &self.fur_settings,
+                    &self.localization,
+                ));
                 for todo in todos.iter().sorted_by_key(|todo| todo.is_completed) {
                     all_todo_rows = all_todo_rows.push(todos::todo_row(
                         todo,
+                        self.project_colors.get(&todo.project).copied(),
+                        tracked_todo_time(date, todo),
                         self.timer_is_running,
                         &self.fur_settings,
                         &self.localization,
@@ -711,28 +1649,201 @@ impl Furtherance {
             .padding([10, 20])
         });
 
+        // Completed todos past the archive threshold stay out of the way until asked for
+        if !self.archived_todos.is_empty() {
+            all_todo_rows = all_todo_rows.push(
+                row![
+                    button(text(self.localization.get_message(
+                        if self.show_archived_todos {
+                            "hide-archived"
+                        } else {
+                            "show-archived"
+                        },
+                        Some(&HashMap::from([(
+                            "count",
+                            FluentValue::from(
+                                self.archived_todos.values().map(Vec::len).sum::<usize>()
+                            ),
+                        )])),
+                    )))
+                    .on_press(Message::ToggleArchivedTodos)
+                    .style(button::text),
+                    space::horizontal(),
+                    if self.show_archived_todos {
+                        Some(
+                            button(text(
+                                self.localization
+                                    .get_message("delete-all-archived-todos", None),
+                            ))
+                            .on_press(Message::DeleteArchivedTodosPressed)
+                            .style(button::danger),
+                        )
+                    } else {
+                        None
+                    },
+                ]
+                .align_y(Alignment::Center),
+            );
+            if self.show_archived_todos {
+                for (date, todos) in self.archived_todos.iter().rev() {
+                    all_todo_rows =
+                        all_todo_rows.push(todos::todo_title_row(&date, &self.localization));
+                    for todo in todos {
+                        all_todo_rows = all_todo_rows.push(todos::todo_row(
+                            todo,
+                            self.project_colors.get(&todo.project).copied(),
+                            tracked_todo_time(date, todo),
+                            self.timer_is_running,
+                            &self.fur_settings,
+                            &self.localization,
+                        ))
+                    }
+                }
+            }
+        }
+
         todo_view = todo_view.push(Scrollable::new(all_todo_rows).height(Length::Fill));
 
+        // MARK: SEARCH
+        let search_terms = search::search_terms(&self.search_query);
+        let mut search_results_column: Column<'_, Message, Theme, Renderer> =
+            Column::new().spacing(8).padding(Padding {
+                top: 0.0,
+                right: 20.0,
+                bottom: 20.0,
+                left: 20.0,
+            });
+        if search_terms.is_empty() {
+            search_results_column = search_results_column.push(text(
+                self.localization.get_message("search-history-prompt", None),
+            ));
+        } else if self.search_results.is_empty() {
+            search_results_column = search_results_column.push(text(
+                self.localization.get_message("no-search-results", None),
+            ));
+        }
+        for (date, tasks) in search::group_results_by_date(&self.search_results) {
+            search_results_column = search_results_column.push(
+                text(format_history_date(&date, &self.localization)).font(font::Font {
+                    weight: iced::font::Weight::Bold,
+                    ..Default::default()
+                }),
+            );
+            for task in tasks {
+                search_results_column = search_results_column.push(search_result_row(
+                    task,
+                    &search_terms,
+                    &self.fur_settings,
+                ));
+            }
+        }
+        if !self.search_end_reached {
+            search_results_column = search_results_column.push(
+                button(text(self.localization.get_message("load-more", None)))
+                    .on_press(Message::SearchLoadMore)
+                    .style(button::secondary),
+            );
+        }
+
+        let search_view = column![
+            row![
+                sidebar_button_in_search,
+                text_input(
+                    &self.localization.get_message("search-history", None),
+                    &self.search_query
+                )
+                .id(SEARCH_INPUT_ID)
+                .on_input(Message::SearchQueryChanged),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .padding([10, 20]),
+            Scrollable::new(search_results_column.width(Length::Fill)).height(Length::Fill),
+        ];
+
         // MARK: REPORT
         let mut charts_column = Column::new().align_x(Alignment::Center);
 
+        let (report_total_time, report_total_earned) = if self.fur_settings.rounding_enabled {
+            rounded_task_totals(&self.report.data.tasks_in_range, &self.fur_settings)
+        } else {
+            (self.report.data.total_time, self.report.data.total_earned)
+        };
+        let previous_totals = self.report.comparison.as_ref().map(|comparison| {
+            if self.fur_settings.rounding_enabled {
+                rounded_task_totals(&comparison.previous.tasks_in_range, &self.fur_settings)
+            } else {
+                (
+                    comparison.previous.total_time,
+                    comparison.previous.total_earned,
+                )
+            }
+        });
+        let net_deduction = net_earnings_deduction(&self.fur_settings);
+        let net_earned_text = |gross: f32, deduction_percent: f32| {
+            text(self.localization.get_message(
+                "net-earned",
+                Some(&HashMap::from([
+                    (
+                        "amount",
+                        FluentValue::from(format_currency(
+                            net_earnings(gross, deduction_percent),
+                            "",
+                        )),
+                    ),
+                    ("percent", FluentValue::from(deduction_percent.to_string())),
+                ])),
+            ))
+            .size(14)
+        };
         let mut timer_earnings_boxes_widgets: Vec<Element<'_, Message, Theme, Renderer>> =
             Vec::new();
-        if self.fur_settings.show_chart_total_time_box && self.report.total_time > 0 {
+        if self.fur_settings.show_chart_total_time_box && report_total_time > 0 {
             timer_earnings_boxes_widgets.push(
                 column![
-                    text(seconds_to_formatted_duration(self.report.total_time, true)).size(50),
-                    text(self.localization.get_message("total-time", None)),
+                    text(seconds_to_formatted_duration(report_total_time, true)).size(50),
+                    text(total_label(
+                        "total-time",
+                        &self.fur_settings,
+                        &self.localization
+                    )),
+                    previous_totals.map(|(previous_time, _)| {
+                        text(comparison_delta_text(
+                            format!(
+                                "{:+.1} h",
+                                (report_total_time - previous_time) as f64 / 3600.0
+                            ),
+                            percent_change(report_total_time as f64, previous_time as f64),
+                        ))
+                        .size(14)
+                    }),
                 ]
                 .align_x(Alignment::Center)
                 .into(),
             );
         }
-        if self.fur_settings.show_chart_total_earnings_box && self.report.total_earned > 0.0 {
+        if self.fur_settings.show_chart_total_earnings_box && report_total_earned > 0.0 {
             timer_earnings_boxes_widgets.push(
                 column![
-                    text!("${:.2}", self.report.total_earned).size(50),
-                    text(self.localization.get_message("earned", None)),
+                    text(format_currency(report_total_earned, "")).size(50),
+                    text(total_label(
+                        "earned",
+                        &self.fur_settings,
+                        &self.localization
+                    )),
+                    previous_totals.map(|(_, previous_earned)| {
+                        let change = report_total_earned - previous_earned;
+                        text(comparison_delta_text(
+                            format!(
+                                "{}{}",
+                                if change < 0.0 { "-" } else { "+" },
+                                format_currency(change.abs(), "")
+                            ),
+                            percent_change(report_total_earned as f64, previous_earned as f64),
+                        ))
+                        .size(14)
+                    }),
+                    net_deduction.map(|percent| net_earned_text(report_total_earned, percent)),
                 ]
                 .align_x(Alignment::Center)
                 .into(),
@@ -758,27 +1869,196 @@ impl Furtherance {
             );
         }
 
+        let workday_stats = if self.fur_settings.show_chart_breakdown_by_selection {
+            self.report.workday_stats()
+        } else {
+            self.report.data.workday_stats.as_ref()
+        };
+        if self.fur_settings.show_chart_workday_stats
+            && let Some(stats) = workday_stats
+        {
+            let stat_column = |value: String, label: &str| {
+                column![
+                    text(value).size(30),
+                    text(self.localization.get_message(label, None)),
+                ]
+                .align_x(Alignment::Center)
+                .width(Length::Fill)
+            };
+            charts_column = charts_column.push(
+                column![
+                    text(self.localization.get_message("workday", None)).size(40),
+                    row![
+                        stat_column(
+                            format_seconds_after_midnight(stats.average_start),
+                            "average-workday-start"
+                        ),
+                        stat_column(
+                            format_seconds_after_midnight(stats.average_end),
+                            "average-workday-end"
+                        ),
+                        stat_column(
+                            seconds_to_formatted_duration(stats.average_tracked, false),
+                            "average-tracked-per-day"
+                        ),
+                        stat_column(stats.active_days.to_string(), "active-days"),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .align_x(Alignment::Center)
+                .padding(Padding {
+                    top: 0.0,
+                    right: 0.0,
+                    bottom: 20.0,
+                    left: 0.0,
+                }),
+            );
+        }
+
+        let daily_target = DailyTarget::from_settings(&self.fur_settings);
+        if daily_target.is_enabled() {
+            let (days_met, days_counted) = self.report.days_meeting_target(&daily_target);
+            charts_column = charts_column.push(
+                column![
+                    text(self.localization.get_message("daily-target", None)).size(40),
+                    text(self.localization.get_message(
+                        "days-met-target",
+                        Some(&HashMap::from([
+                            ("met", FluentValue::from(days_met)),
+                            ("days", FluentValue::from(days_counted)),
+                        ])),
+                    )),
+                ]
+                .spacing(10)
+                .align_x(Alignment::Center)
+                .padding(Padding {
+                    top: 0.0,
+                    right: 0.0,
+                    bottom: 20.0,
+                    left: 0.0,
+                }),
+            );
+        }
+
+        if !self.report.weekly_goal_progress.is_empty() {
+            let running_project = if self.timer_is_running && self.fur_settings.dynamic_total {
+                let (_, project, _, _) = split_task_input(&self.task_input);
+                Some(project)
+            } else {
+                None
+            };
+            let mut goals_column =
+                column![text(self.localization.get_message("weekly-goals", None)).size(40)]
+                    .spacing(10)
+                    .align_x(Alignment::Center);
+            for (goal, tracked_seconds) in &self.report.weekly_goal_progress {
+                let mut seconds = *tracked_seconds;
+                if let Some(project) = &running_project
+                    && project.eq_ignore_ascii_case(&goal.project)
+                {
+                    seconds += (timer_now(self) - self.timer_start_time).num_seconds();
+                }
+                let goal_seconds = goal.weekly_minutes * 60;
+                goals_column = goals_column.push(
+                    column![
+                        row![
+                            text(format!("@{}", goal.project)),
+                            space::horizontal().width(Length::Fill),
+                            text(format!(
+                                "{} / {}",
+                                seconds_to_formatted_duration(seconds, false),
+                                seconds_to_formatted_duration(goal_seconds, false)
+                            )),
+                        ],
+                        progress_bar(
+                            0.0..=goal_seconds.max(1) as f32,
+                            seconds.min(goal_seconds) as f32
+                        ),
+                    ]
+                    .spacing(5),
+                );
+            }
+            charts_column = charts_column.push(goals_column.padding(Padding {
+                top: 0.0,
+                right: 0.0,
+                bottom: 20.0,
+                left: 0.0,
+            }));
+        }
+
         if self.fur_settings.show_chart_time_recorded {
-            charts_column = charts_column.push(self.report.time_recorded_chart.view());
+            charts_column = charts_column.push(match &self.report.comparison {
+                Some(comparison) => comparison.time_recorded_chart.view(),
+                None => match &self.report.running_time_chart {
+                    Some(running_time_chart) => running_time_chart.view(),
+                    None => self.report.data.time_recorded_chart.view(),
+                },
+            });
+        }
+        if self.fur_settings.show_chart_earnings && self.report.data.total_earned > 0.0 {
+            charts_column = charts_column.push(match &self.report.comparison {
+                Some(comparison) => comparison.earnings_chart.view(),
+                None => self.report.data.earnings_chart.view(),
+            });
         }
-        if self.fur_settings.show_chart_earnings && self.report.total_earned > 0.0 {
-            charts_column = charts_column.push(self.report.earnings_chart.view());
+        if self.fur_settings.show_chart_cumulative_earnings && self.report.data.total_earned > 0.0 {
+            charts_column = charts_column.push(self.report.data.cumulative_earnings_chart.view());
         }
         if self.fur_settings.show_chart_average_time {
-            charts_column = charts_column.push(self.report.average_time_chart.view());
+            charts_column = charts_column.push(self.report.data.average_time_chart.view());
+        }
+        if self.fur_settings.show_chart_average_earnings && self.report.data.total_earned > 0.0 {
+            charts_column = charts_column.push(self.report.data.average_earnings_chart.view());
+        }
+        if (self.fur_settings.show_chart_average_time
+            || self.fur_settings.show_chart_average_earnings)
+            && self.report.data.zero_length_tasks > 0
+        {
+            charts_column = charts_column.push(
+                text(self.localization.get_message(
+                    "zero-length-tasks-footnote",
+                    Some(&HashMap::from([(
+                        "count",
+                        FluentValue::from(self.report.data.zero_length_tasks),
+                    )])),
+                ))
+                .size(12),
+            );
+        }
+        if self.fur_settings.show_chart_time_of_day {
+            charts_column = charts_column.push(self.report.data.time_of_day_chart.view());
         }
-        if self.fur_settings.show_chart_average_earnings && self.report.total_earned > 0.0 {
-            charts_column = charts_column.push(self.report.average_earnings_chart.view());
+        if self.fur_settings.show_chart_heatmap {
+            charts_column = charts_column.push(self.report.heatmap_chart.view());
+        }
+        if self.fur_settings.show_chart_todos {
+            if let Some(completion_rate) = self.report.todo_completion_chart.completion_rate() {
+                charts_column = charts_column.push(
+                    column![
+                        text(format!("{:.0}%", completion_rate)).size(50),
+                        text(self.localization.get_message("todo-completion-rate", None)),
+                    ]
+                    .align_x(Alignment::Center)
+                    .padding(Padding {
+                        top: 10.0,
+                        right: 0.0,
+                        bottom: 0.0,
+                        left: 0.0,
+                    }),
+                );
+            }
+            charts_column = charts_column.push(self.report.todo_completion_chart.view());
         }
 
         // Breakdown by Selection Picker & Charts
         let mut selection_timer_earnings_boxes_widgets: Vec<Element<'_, Message, Theme, Renderer>> =
             Vec::new();
-        if self.fur_settings.show_chart_total_time_box && self.report.selection_total_time > 0 {
+        if self.fur_settings.show_chart_total_time_box && self.report.selection.total_time > 0 {
             selection_timer_earnings_boxes_widgets.push(
                 column![
                     text(seconds_to_formatted_duration(
-                        self.report.selection_total_time,
+                        self.report.selection.total_time,
                         true
                     ))
                     .size(50),
@@ -789,12 +2069,16 @@ impl Furtherance {
             );
         }
         if self.fur_settings.show_chart_total_earnings_box
-            && self.report.selection_total_earned > 0.0
+            && self.report.selection.total_earned > 0.0
         {
             selection_timer_earnings_boxes_widgets.push(
                 column![
-                    text!("${:.2}", self.report.selection_total_earned).size(50),
+                    text(format_currency(self.report.selection.total_earned, "")).size(50),
                     text(self.localization.get_message("earned", None)),
+                    net_deduction.map(|percent| net_earned_text(
+                        self.report.selection.total_earned,
+                        percent
+                    )),
                 ]
                 .align_x(Alignment::Center)
                 .into(),
@@ -802,7 +2086,7 @@ impl Furtherance {
         }
 
         let mut charts_breakdown_by_selection_column = Column::new().align_x(Alignment::Center);
-        if !self.report.tasks_in_range.is_empty()
+        if !self.report.data.tasks_in_range.is_empty()
             && self.fur_settings.show_chart_breakdown_by_selection
         {
             charts_breakdown_by_selection_column = charts_breakdown_by_selection_column.push(
@@ -812,6 +2096,8 @@ impl Furtherance {
                 )
                 .size(40),
             );
+            // Tags can be combined, so they're picked from chips instead of a single value
+            let picking_tags = self.report.picked_task_property_key == Some(FurTaskProperty::Tags);
             charts_breakdown_by_selection_column = charts_breakdown_by_selection_column.push(
                 row![
                     pick_list(
@@ -820,16 +2106,47 @@ impl Furtherance {
                         Message::ChartTaskPropertyKeySelected,
                     )
                     .width(Length::Fill),
-                    pick_list(
-                        &self.report.task_property_value_keys[..],
-                        self.report.picked_task_property_value.clone(),
-                        Message::ChartTaskPropertyValueSelected,
-                    )
-                    .width(Length::Fill),
+                    if picking_tags {
+                        None
+                    } else {
+                        Some(
+                            button(
+                                row![
+                                    text(
+                                        self.report
+                                            .picked_task_property_value
+                                            .clone()
+                                            .unwrap_or_default()
+                                    )
+                                    .width(Length::Fill),
+                                    text(if self.report.show_task_property_value_picker {
+                                        "\u{25B4}"
+                                    } else {
+                                        "\u{25BE}"
+                                    }),
+                                ]
+                                .align_y(Alignment::Center),
+                            )
+                            .on_press(Message::ChartTaskPropertyValuePickerToggled)
+                            .style(button::secondary)
+                            .width(Length::Fill),
+                        )
+                    },
                 ]
                 .spacing(10)
                 .width(Length::Fill),
             );
+            if !picking_tags && self.report.show_task_property_value_picker {
+                charts_breakdown_by_selection_column = charts_breakdown_by_selection_column
+                    .push(task_property_value_picker(&self.report, &self.localization));
+            }
+            if picking_tags {
+                charts_breakdown_by_selection_column =
+                    charts_breakdown_by_selection_column.push(chart_tag_chip_bar(
+                        &self.report.task_property_value_keys,
+                        &self.report.picked_tags,
+                    ));
+            }
             charts_breakdown_by_selection_column =
                 charts_breakdown_by_selection_column.push(rule::horizontal(20));
 
@@ -857,23 +2174,123 @@ impl Furtherance {
 
             if self.fur_settings.show_chart_selection_time {
                 charts_breakdown_by_selection_column = charts_breakdown_by_selection_column
-                    .push(self.report.selection_time_recorded_chart.view());
+                    .push(self.report.selection.time_recorded_chart.view());
             }
             if self.fur_settings.show_chart_selection_earnings {
                 charts_breakdown_by_selection_column = charts_breakdown_by_selection_column
-                    .push(self.report.selection_earnings_recorded_chart.view());
+                    .push(self.report.selection.earnings_recorded_chart.view());
+            }
+            if self.fur_settings.show_chart_time_of_day {
+                charts_breakdown_by_selection_column = charts_breakdown_by_selection_column
+                    .push(self.report.selection.time_of_day_chart.view());
+            }
+
+            // The tasks behind the selection, grouped like the task history
+            let mut selection_history_column = column![].spacing(8).padding(Padding {
+                top: 20.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 0.0,
+            });
+            for (date, task_groups) in self.report.selection.history.iter().rev() {
+                let (total_time, total_earnings) =
+                    history_day_totals(task_groups, &self.fur_settings);
+                selection_history_column = selection_history_column.push(history_title_row(
+                    date,
+                    total_time,
+                    total_earnings,
+                    self.day_notes.get(date),
+                    &self.days_off,
+                    &self.fur_settings,
+                    None,
+                    &self.localization,
+                ));
+                for task_group in task_groups {
+                    selection_history_column = selection_history_column.push(history_group_row(
+                        task_group,
+                        self.project_colors.get(&task_group.project).copied(),
+                        self.timer_is_running,
+                        None,
+                        false,
+                        &self.fur_settings,
+                        &self.localization,
+                    ));
+                }
             }
+            charts_breakdown_by_selection_column =
+                charts_breakdown_by_selection_column.push(selection_history_column);
         };
 
         let charts_view = column![
             sidebar_button_in_report,
             column![
-                pick_list(
-                    &FurDateRange::ALL[..],
-                    self.report.picked_date_range,
-                    Message::DateRangeSelected,
-                )
-                .width(Length::Fill),
+                row![
+                    pick_list(
+                        &FurDateRange::ALL[..],
+                        self.report.picked_date_range,
+                        Message::DateRangeSelected,
+                    )
+                    .width(Length::Fill),
+                    pick_list(
+                        &FurBillableFilter::ALL[..],
+                        Some(self.report.billable_filter),
+                        Message::BillableFilterSelected,
+                    ),
+                    button(text(
+                        self.localization.get_message("generate-invoice", None)
+                    ))
+                    .on_press_maybe(if self.report.data.tasks_in_range.is_empty() {
+                        None
+                    } else {
+                        Some(Message::GenerateInvoicePressed)
+                    })
+                    .style(style::primary_button_style),
+                    button(text(
+                        self.localization.get_message("export-this-selection", None)
+                    ))
+                    .on_press_maybe(if self.report.data.tasks_in_range.is_empty() {
+                        None
+                    } else {
+                        Some(Message::ExportReportSelectionPressed)
+                    })
+                    .style(style::primary_button_style),
+                    button(text(self.localization.get_message("year-in-review", None)))
+                        .on_press(Message::YearInReviewPressed)
+                        .style(style::primary_button_style),
+                ]
+                .spacing(10),
+                match &self.report_export_message {
+                    Ok(msg) => {
+                        if msg.is_empty() {
+                            None
+                        } else {
+                            Some(text(msg).size(14).style(style::green_text))
+                        }
+                    }
+                    Err(e) => Some(text!("{}", e).size(14).style(style::red_text)),
+                },
+                row![
+                    checkbox(
+                        self.report.compare_with_previous
+                            && self.report.picked_date_range != Some(FurDateRange::AllTime)
+                    )
+                    .label(self.localization.get_message("compare-with", None))
+                    .on_toggle_maybe(
+                        if self.report.picked_date_range == Some(FurDateRange::AllTime) {
+                            None
+                        } else {
+                            Some(Message::CompareWithPreviousToggled)
+                        }
+                    )
+                    .style(style::fur_checkbox_style),
+                    pick_list(
+                        &FurComparisonPeriod::ALL[..],
+                        Some(self.report.comparison_period),
+                        Message::ComparisonPeriodSelected,
+                    ),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
                 if self.report.picked_date_range == Some(FurDateRange::Range) {
                     row![
                         space::horizontal().width(Length::Fill),
@@ -922,6 +2339,21 @@ impl Furtherance {
                 bottom: 10.0,
                 left: 20.0,
             }),
+            // The previous results stay visible until the new ones are ready
+            if self.report.is_computing() {
+                Some(
+                    row![
+                        space::horizontal(),
+                        Spinner::new()
+                            .width(Length::Fixed(24.0))
+                            .height(Length::Fixed(24.0)),
+                        space::horizontal(),
+                    ]
+                    .padding(10),
+                )
+            } else {
+                None
+            },
             Scrollable::new(
                 column![charts_column, charts_breakdown_by_selection_column]
                     .align_x(Alignment::Center)
@@ -968,7 +2400,8 @@ impl Furtherance {
         server_choice_col = server_choice_col.push(
             if self.settings_server_choice == Some(ServerChoices::Custom) {
                 Some(
-                    text_input("", &self.fur_user_fields.server)
+                    text_input("https://", &self.fur_user_fields.server)
+                        .id(SYNC_SERVER_INPUT_ID)
                         .on_input(Message::UserServerChanged)
                         .on_submit(Message::EnterPressedInSyncFields),
                 )
@@ -976,6 +2409,18 @@ impl Furtherance {
                 None
             },
         );
+        if self.settings_server_choice == Some(ServerChoices::Custom)
+            && is_unencrypted_server(&self.fur_user_fields.server)
+        {
+            server_choice_col = server_choice_col.push(
+                text(
+                    self.localization
+                        .get_message("server-unencrypted-warning", None),
+                )
+                .size(12)
+                .style(style::red_text),
+            );
+        }
 
         let mut sync_server_col = column![
             row![
@@ -987,6 +2432,7 @@ impl Furtherance {
                 text(self.localization.get_message("email", None)),
                 column![
                     text_input("", &self.fur_user_fields.email)
+                        .id(SYNC_EMAIL_INPUT_ID)
                         .on_input(Message::UserEmailChanged)
                         .on_submit(Message::EnterPressedInSyncFields)
                 ]
@@ -998,6 +2444,7 @@ impl Furtherance {
                 column![
                     text_input("", &self.fur_user_fields.encryption_key)
                         .secure(true)
+                        .id(SYNC_KEY_INPUT_ID)
                         .on_input(Message::UserEncryptionKeyChanged)
                         .on_submit(Message::EnterPressedInSyncFields)
                 ]
@@ -1034,7 +2481,13 @@ impl Furtherance {
             }),
         ]
         .spacing(10);
-        sync_button_row = sync_button_row.push(if self.fur_user.is_some() {
+        sync_button_row = sync_button_row.push(if self.fur_user.is_some() && self.sync_suspended {
+            Some(
+                button(text(self.localization.get_message("retry", None)))
+                    .on_press(Message::RetrySyncPressed)
+                    .style(style::primary_button_style),
+            )
+        } else if self.fur_user.is_some() {
             Some(
                 button(text(self.localization.get_message("sync", None)))
                     .on_press_maybe(match self.fur_user {
@@ -1055,6 +2508,49 @@ impl Furtherance {
             None
         });
         sync_server_col = sync_server_col.push(sync_button_row);
+        if self.fur_user.is_some() {
+            sync_server_col = sync_server_col.push(
+                row![
+                    text(sync_summary_text(
+                        self.sync_history.first(),
+                        self.pending_sync_changes,
+                        &self.localization,
+                    )),
+                    button(text(self.localization.get_message("sync-details", None)))
+                        .on_press(Message::ShowAlert(FurAlert::SyncHistory))
+                        .style(button::secondary),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+            sync_server_col = sync_server_col.push(
+                row![
+                    text(self.localization.get_message("sync-frequency", None)),
+                    pick_list(
+                        &FurSyncInterval::ALL[..],
+                        Some(FurSyncInterval::from_minutes(
+                            self.fur_settings.sync_interval_minutes
+                        )),
+                        Message::SettingsSyncIntervalSelected,
+                    ),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        if self.sync_suspended {
+            sync_server_col = sync_server_col.push(
+                text(
+                    self.localization
+                        .get_message("sync-incompatible-version", None),
+                )
+                .style(style::red_text),
+            );
+        }
+        if let Some(skew) = self.clock_skew {
+            sync_server_col = sync_server_col
+                .push(text(clock_skew_warning(&self.localization, skew)).style(style::red_text));
+        }
         sync_server_col = sync_server_col.push(match &self.login_message {
             Ok(msg) => {
                 if msg.is_empty() {
@@ -1066,6 +2562,8 @@ impl Furtherance {
             Err(e) => Some(text!("{}", e).style(style::red_text)),
         });
 
+        // Switching databases mid-upgrade or mid-merge would leave either half done
+        let database_busy = self.legacy_upgrade.is_some() || self.database_merge.is_some();
         let mut database_location_col = column![
             text(self.localization.get_message("database-location", None)),
             text_input(
@@ -1074,14 +2572,25 @@ impl Furtherance {
             ),
             row![
                 button(text(self.localization.get_message("create-new", None)))
-                    .on_press(Message::SettingsChangeDatabaseLocationPressed(
-                        ChangeDB::New
-                    ))
+                    .on_press_maybe(if database_busy {
+                        None
+                    } else {
+                        Some(Message::SettingsChangeDatabaseLocationPressed(
+                            ChangeDB::New,
+                        ))
+                    })
                     .style(style::primary_button_style),
                 button(text(self.localization.get_message("open-existing", None)))
-                    .on_press(Message::SettingsChangeDatabaseLocationPressed(
-                        ChangeDB::Open
-                    ))
+                    .on_press_maybe(if database_busy {
+                        None
+                    } else {
+                        Some(Message::SettingsChangeDatabaseLocationPressed(
+                            ChangeDB::Open,
+                        ))
+                    })
+                    .style(style::primary_button_style),
+                button(text(self.localization.get_message("merge-database", None)))
+                    .on_press_maybe((!database_busy).then_some(Message::MergeDatabasePressed))
                     .style(style::primary_button_style),
                 button(text(self.localization.get_message("backup-database", None)))
                     .on_press(Message::BackupDatabase)
@@ -1091,6 +2600,36 @@ impl Furtherance {
             .wrap(),
         ]
         .spacing(10);
+        if let Some(progress) = self.legacy_upgrade {
+            database_location_col = database_location_col.push(
+                column![
+                    text(self.localization.get_message(
+                        "upgrading-database",
+                        Some(&HashMap::from([
+                            ("migrated", FluentValue::from(progress.migrated)),
+                            ("total", FluentValue::from(progress.total)),
+                        ]))
+                    )),
+                    progress_bar(0.0..=1.0, progress.fraction()),
+                ]
+                .spacing(5),
+            );
+        }
+        if let Some(progress) = self.database_merge {
+            database_location_col = database_location_col.push(
+                column![
+                    text(self.localization.get_message(
+                        "merging-database",
+                        Some(&HashMap::from([
+                            ("merged", FluentValue::from(progress.migrated)),
+                            ("total", FluentValue::from(progress.total)),
+                        ]))
+                    )),
+                    progress_bar(0.0..=1.0, progress.fraction()),
+                ]
+                .spacing(5),
+            );
+        }
         database_location_col = database_location_col.push(match &self.settings_database_message {
             Ok(msg) => {
                 if msg.is_empty() {
@@ -1102,6 +2641,113 @@ impl Furtherance {
             Err(e) => Some(text!("{}", e).style(style::red_text)),
         });
 
+        let active_profile = self.fur_settings.active_profile();
+        let profile_picker: Element<'_, Message> =
+            if self.timer_is_running || !self.secondary_timers.is_empty() {
+                tooltip(
+                    button(text(
+                        active_profile.map_or(String::new(), |profile| profile.name.clone()),
+                    ))
+                    .style(button::secondary),
+                    container(text(
+                        self.localization
+                            .get_message("stop-timer-to-switch-profile", None),
+                    ))
+                    .padding(5)
+                    .style(container::rounded_box),
+                    tooltip::Position::Top,
+                )
+                .into()
+            } else {
+                pick_list(
+                    &self.fur_settings.profiles[..],
+                    active_profile.cloned(),
+                    |profile| Message::SwitchProfile(profile.name),
+                )
+                .placeholder(self.localization.get_message("choose-profile", None))
+                .into()
+            };
+        let mut profiles_col = column![
+            text(self.localization.get_message("profiles-description", None)).size(12),
+            row![
+                text(self.localization.get_message("active-profile", None)),
+                profile_picker,
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(10);
+        for profile in &self.fur_settings.profiles {
+            let is_active = active_profile == Some(profile);
+            profiles_col = profiles_col.push(
+                row![
+                    column![
+                        text(&profile.name).font(font::Font {
+                            weight: iced::font::Weight::Bold,
+                            ..Default::default()
+                        }),
+                        text(&profile.database_url).size(12),
+                    ]
+                    .width(Length::Fill),
+                    button(text(self.localization.get_message("rename", None)))
+                        .on_press_maybe(if self.profile_to_add.name.trim().is_empty() {
+                            None
+                        } else {
+                            Some(Message::RenameProfile(profile.name.clone()))
+                        })
+                        .style(style::primary_button_style),
+                    button(text(self.localization.get_message("delete", None)))
+                        .on_press_maybe(if is_active {
+                            None
+                        } else {
+                            Some(Message::DeleteProfile(profile.name.clone()))
+                        })
+                        .style(button::danger),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        profiles_col = profiles_col.push(
+            row![
+                text_input(
+                    &self.localization.get_message("profile-name", None),
+                    &self.profile_to_add.name,
+                )
+                .id(PROFILE_NAME_INPUT_ID)
+                .on_input(Message::ProfileNameChanged)
+                .width(150),
+                text_input(
+                    &self.localization.get_message("profile-database-path", None),
+                    &self.profile_to_add.database_url,
+                )
+                .id(PROFILE_PATH_INPUT_ID)
+                .on_input(Message::ProfilePathChanged),
+                button(text(self.localization.get_message("browse", None)))
+                    .on_press(Message::ProfileBrowsePressed)
+                    .style(style::primary_button_style),
+                button(text(self.localization.get_message("add-profile", None)))
+                    .on_press_maybe(if self.profile_to_add.can_add() {
+                        Some(Message::AddProfilePressed)
+                    } else {
+                        None
+                    })
+                    .style(style::primary_button_style),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        );
+        profiles_col = profiles_col.push(match &self.settings_profiles_message {
+            Ok(msg) => {
+                if msg.is_empty() {
+                    None
+                } else {
+                    Some(text(msg).style(style::green_text))
+                }
+            }
+            Err(e) => Some(text!("{}", e).style(style::red_text)),
+        });
+
         let mut csv_col = column![
             text(self.localization.get_message("export-options", None)),
             row![
@@ -1121,6 +2767,9 @@ impl Furtherance {
                     checkbox(self.export_settings.total_time)
                         .label(self.localization.get_message("total-time", None))
                         .on_toggle(Message::ExportTotalTimeColumnToggled),
+                    checkbox(self.export_settings.device)
+                        .label(self.localization.get_message("device", None))
+                        .on_toggle(Message::ExportDeviceColumnToggled),
                 ]
                 .spacing(6),
                 column![
@@ -1136,10 +2785,16 @@ impl Furtherance {
                     checkbox(self.export_settings.total_earnings)
                         .label(self.localization.get_message("total-earnings-text", None))
                         .on_toggle(Message::ExportTotalEarningsColumnToggled),
+                    checkbox(self.export_settings.billable)
+                        .label(self.localization.get_message("billable", None))
+                        .on_toggle(Message::ExportBillableColumnToggled),
                 ]
                 .spacing(6),
             ]
             .spacing(30),
+            checkbox(self.export_settings.day_notes)
+                .label(self.localization.get_message("day-notes", None))
+                .on_toggle(Message::ExportDayNotesToggled),
             row![
                 checkbox(self.export_settings.filter_by_date)
                     .label(self.localization.get_message("filter-by-date", None))
@@ -1226,8 +2881,135 @@ impl Furtherance {
             }
             Err(e) => Some(text!("{}", e).style(style::red_text)),
         });
+        if !self.csv_import_errors.is_empty() {
+            csv_col = csv_col.push(
+                button(text(
+                    self.localization.get_message("csv-import-details", None),
+                ))
+                .on_press(Message::ShowAlert(FurAlert::CsvImportErrors))
+                .style(style::primary_button_style),
+            );
+        }
+
+        let mut scheduled_summary_col = column![
+            row![
+                column![
+                    text(
+                        self.localization
+                            .get_message("write-scheduled-summary", None)
+                    ),
+                    text(
+                        self.localization
+                            .get_message("scheduled-summary-description", None)
+                    )
+                    .size(12),
+                ],
+                pick_list(
+                    &FurSummarySchedule::ALL[..],
+                    Some(self.fur_settings.scheduled_summary),
+                    Message::SettingsScheduledSummarySelected,
+                ),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(10);
+        if self.fur_settings.scheduled_summary != FurSummarySchedule::Off {
+            scheduled_summary_col = scheduled_summary_col
+                .push(
+                    row![
+                        text(
+                            self.localization
+                                .get_message("scheduled-summary-hour", None)
+                        ),
+                        number_input(
+                            &self.fur_settings.scheduled_summary_hour,
+                            0..=23,
+                            Message::SettingsScheduledSummaryHourChanged
+                        )
+                        .style(style::fur_number_input_style),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                )
+                .push(
+                    row![
+                        text(
+                            self.localization
+                                .get_message("scheduled-summary-earnings", None)
+                        ),
+                        toggler(self.fur_settings.scheduled_summary_earnings)
+                            .on_toggle(Message::SettingsScheduledSummaryEarningsToggled)
+                            .width(Length::Shrink)
+                            .style(style::fur_toggler_style),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                )
+                .push(text_input(
+                    &self.fur_settings.scheduled_summary_folder,
+                    &self.fur_settings.scheduled_summary_folder,
+                ))
+                .push(
+                    button(text(self.localization.get_message("browse", None)))
+                        .on_press(Message::ScheduledSummaryFolderBrowsePressed)
+                        .style(style::primary_button_style),
+                );
+        }
+
+        let summary_file_col = column![
+            row![
+                column![
+                    text(self.localization.get_message("write-summary-file", None)),
+                    text(
+                        self.localization
+                            .get_message("summary-file-description", None)
+                    )
+                    .size(12),
+                ],
+                toggler(self.fur_settings.write_summary_file)
+                    .on_toggle(Message::SettingsWriteSummaryFileToggled)
+                    .width(Length::Shrink)
+                    .style(style::fur_toggler_style)
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text_input(
+                &self.fur_settings.summary_file_path,
+                &self.fur_settings.summary_file_path,
+            ),
+            button(text(self.localization.get_message("browse", None)))
+                .on_press(Message::SummaryFileBrowsePressed)
+                .style(style::primary_button_style),
+        ]
+        .spacing(10);
 
         let mut backup_col = column![
+            row![
+                button(text(self.localization.get_message("export-settings", None)))
+                    .on_press(Message::ExportSettingsPressed)
+                    .style(style::primary_button_style),
+                button(text(self.localization.get_message("import-settings", None)))
+                    .on_press(Message::ImportSettingsPressed)
+                    .style(style::primary_button_style),
+            ]
+            .spacing(10),
+            row![
+                button(text(
+                    self.localization.get_message("export-shortcuts", None)
+                ))
+                .on_press(Message::ExportShortcutsPressed)
+                .style(style::primary_button_style),
+                button(text(
+                    self.localization.get_message("import-shortcuts", None)
+                ))
+                .on_press(Message::ImportShortcutsPressed)
+                .style(style::primary_button_style),
+            ]
+            .spacing(10),
+            button(text(self.localization.get_message("find-duplicates", None)))
+                .on_press(Message::FindDuplicatesPressed)
+                .style(style::primary_button_style),
             button(text(
                 self.localization.get_message("delete-everything", None)
             ))
@@ -1246,152 +3028,731 @@ impl Furtherance {
             Err(e) => Some(text!("{}", e).style(style::red_text)),
         });
 
-        let settings_view: Column<'_, Message, Theme, Renderer> = column![
-            Tabs::new(Message::SettingsTabSelected)
-                .tab_icon_position(iced_aw::tabs::Position::Top)
-                .push(
-                    TabId::General,
-                    TabLabel::IconText(
-                        advanced_text::gear_fill().0.chars().next().unwrap_or(' '),
-                        self.localization.get_message("general", None)
-                    ),
-                    Scrollable::new(
-                        column![
-                            settings_heading(self.localization.get_message("interface", None)),
-                            row![
-                                text(self.localization.get_message("default-view", None)),
-                                pick_list(
-                                    &FurView::ALL[..],
-                                    Some(self.fur_settings.default_view),
-                                    Message::SettingsDefaultViewSelected,
-                                ),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(
-                                    self.localization
-                                        .get_message("show-delete-confirmation", None)
-                                ),
-                                toggler(self.fur_settings.show_delete_confirmation)
-                                    .on_toggle(Message::SettingsDeleteConfirmationToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            settings_heading(self.localization.get_message("task-history", None)),
-                            row![
-                                text(self.localization.get_message("show-project", None)),
-                                toggler(self.fur_settings.show_task_project)
-                                    .on_toggle(Message::SettingsShowTaskProjectToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-tags", None)),
-                                toggler(self.fur_settings.show_task_tags)
-                                    .on_toggle(Message::SettingsShowTaskTagsToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-earnings", None)),
-                                toggler(self.fur_settings.show_task_earnings)
-                                    .on_toggle(Message::SettingsShowEarningsToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-seconds", None)),
-                                toggler(self.fur_settings.show_seconds)
-                                    .on_toggle(Message::SettingsShowSecondsToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-daily-time-total", None)),
-                                toggler(self.fur_settings.show_daily_time_total)
-                                    .on_toggle(Message::SettingsShowDailyTimeTotalToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            settings_heading(self.localization.get_message("todos", None)),
-                            row![
-                                text(self.localization.get_message("show-project", None)),
-                                toggler(self.fur_settings.show_todo_project)
-                                    .on_toggle(Message::SettingsShowTodoProjectToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-tags", None)),
-                                toggler(self.fur_settings.show_todo_tags)
-                                    .on_toggle(Message::SettingsShowTodoTagsToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                            row![
-                                text(self.localization.get_message("show-rate", None)),
-                                toggler(self.fur_settings.show_todo_rate)
-                                    .on_toggle(Message::SettingsShowTodoRateToggled)
-                                    .width(Length::Shrink)
-                                    .style(style::fur_toggler_style),
-                            ]
-                            .spacing(10)
-                            .align_y(Alignment::Center),
-                        ]
-                        .spacing(SETTINGS_SPACING)
-                        .padding(10)
-                    ),
+        let mut find_replace_col = column![
+            text(
+                self.localization
+                    .get_message("find-replace-description", None)
+            ),
+            row![
+                pick_list(
+                    &FurRenameField::ALL[..],
+                    Some(self.find_replace.field),
+                    Message::FindReplaceFieldSelected,
+                ),
+                text_input(
+                    &self.localization.get_message("find", None),
+                    &self.find_replace.find
                 )
-                .push(
-                    TabId::Advanced,
-                    TabLabel::IconText(
-                        advanced_text::gear_wide_connected()
-                            .0
-                            .chars()
-                            .next()
-                            .unwrap_or(' '),
-                        self.localization.get_message("advanced", None)
-                    ),
-                    Scrollable::new(
+                .id(FIND_INPUT_ID)
+                .on_input(Message::FindReplaceFindChanged)
+                .on_submit_maybe(if self.find_replace.can_preview() {
+                    Some(Message::FindReplacePreviewPressed)
+                } else {
+                    None
+                })
+                .width(200),
+                text_input(
+                    &self.localization.get_message("replace-with", None),
+                    &self.find_replace.replace
+                )
+                .id(REPLACE_INPUT_ID)
+                .on_input(Message::FindReplaceReplaceChanged)
+                .on_submit_maybe(if self.find_replace.can_preview() {
+                    Some(Message::FindReplacePreviewPressed)
+                } else {
+                    None
+                })
+                .width(200),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            row![
+                button(text(self.localization.get_message("preview", None)))
+                    .on_press_maybe(if self.find_replace.can_preview() {
+                        Some(Message::FindReplacePreviewPressed)
+                    } else {
+                        None
+                    })
+                    .style(style::primary_button_style),
+                button(text(self.localization.get_message("replace-all", None)))
+                    .on_press_maybe(if self.find_replace.can_apply() {
+                        Some(Message::FindReplaceApplyPressed)
+                    } else {
+                        None
+                    })
+                    .style(style::primary_button_style),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+        find_replace_col = find_replace_col.push(match &self.settings_find_replace_message {
+            Ok(msg) => {
+                if msg.is_empty() {
+                    None
+                } else {
+                    Some(text(msg).style(style::green_text))
+                }
+            }
+            Err(e) => Some(text!("{}", e).style(style::red_text)),
+        });
+
+        let mut trash_col = column![
+            text(self.localization.get_message(
+                "trash-description",
+                Some(&HashMap::from([(
+                    "days",
+                    FluentValue::from(self.fur_settings.trash_retention_days)
+                )]))
+            ))
+            .size(12),
+            row![
+                text(
+                    self.localization
+                        .get_message("keep-deleted-items-for", None)
+                ),
+                number_input(
+                    &self.fur_settings.trash_retention_days,
+                    1..=365,
+                    Message::SettingsTrashRetentionChanged
+                )
+                .style(style::fur_number_input_style),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text(self.localization.get_message(
+                "trash-counts",
+                Some(&HashMap::from([
+                    (
+                        "tasks",
+                        FluentValue::from(count_of_kind(&self.trash, DeletedItemKind::Task))
+                    ),
+                    (
+                        "todos",
+                        FluentValue::from(count_of_kind(&self.trash, DeletedItemKind::Todo))
+                    ),
+                    (
+                        "shortcuts",
+                        FluentValue::from(count_of_kind(&self.trash, DeletedItemKind::Shortcut))
+                    ),
+                ]))
+            )),
+        ]
+        .spacing(10);
+        for item in self.trash.iter().take(TRASH_ITEMS_SHOWN) {
+            let mut details = vec![item.kind.label(&self.localization)];
+            if !item.project.is_empty() {
+                details.push(format!("@{}", item.project));
+            }
+            if let Some(deleted_at) = DateTime::from_timestamp(item.deleted_at, 0) {
+                details.push(format_date_and_time(deleted_at.with_timezone(&Local)));
+            }
+            trash_col = trash_col.push(
+                row![
+                    column![
+                        text(&item.name).font(font::Font {
+                            weight: iced::font::Weight::Bold,
+                            ..Default::default()
+                        }),
+                        text(details.join(" · ")).size(12),
+                    ]
+                    .width(Length::Fill),
+                    button(text(self.localization.get_message("restore", None)))
+                        .on_press(Message::RestoreDeletedItem(item.clone()))
+                        .style(style::primary_button_style),
+                    button(text(
+                        self.localization.get_message("delete-permanently", None)
+                    ))
+                    .on_press(Message::PurgeDeletedItem(item.clone()))
+                    .style(button::danger),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        if self.trash.len() > TRASH_ITEMS_SHOWN {
+            trash_col = trash_col.push(
+                text(self.localization.get_message(
+                    "trash-more",
+                    Some(&HashMap::from([(
+                        "count",
+                        FluentValue::from(self.trash.len() - TRASH_ITEMS_SHOWN),
+                    )])),
+                ))
+                .size(12),
+            );
+        }
+
+        let mut goals_col = column![].spacing(10);
+        for (goal, _) in &self.report.weekly_goal_progress {
+            let uid = goal.uid.clone();
+            goals_col = goals_col.push(
+                row![
+                    text(format!("@{}", goal.project)),
+                    number_input(&(goal.weekly_minutes / 60), 1..=168, move |hours| {
+                        Message::GoalHoursChanged(uid.clone(), hours)
+                    })
+                    .style(style::fur_number_input_style),
+                    text(self.localization.get_message("hours-per-week", None)),
+                    button(bootstrap::trash_fill())
+                        .on_press(Message::DeleteGoalPressed(goal.uid.clone()))
+                        .style(button::text),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        goals_col = goals_col.push(
+            row![
+                text_input(
+                    &self.localization.get_message("project", None),
+                    &self.goal_to_add.project
+                )
+                .id(GOAL_PROJECT_INPUT_ID)
+                .on_input(Message::GoalToAddProjectChanged)
+                .on_submit(Message::AddGoalPressed)
+                .width(200),
+                number_input(
+                    &self.goal_to_add.weekly_hours,
+                    1..=168,
+                    Message::GoalToAddHoursChanged
+                )
+                .style(style::fur_number_input_style),
+                text(self.localization.get_message("hours-per-week", None)),
+                button(text(self.localization.get_message("add-goal", None)))
+                    .on_press_maybe(if self.goal_to_add.project.trim().is_empty() {
+                        None
+                    } else {
+                        Some(Message::AddGoalPressed)
+                    })
+                    .style(style::primary_button_style),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        );
+        if !self.goal_to_add.invalid_input_error_message.is_empty() {
+            goals_col = goals_col
+                .push(text(&self.goal_to_add.invalid_input_error_message).style(style::red_text));
+        }
+
+        let mut projects_col = column![
+            row![
+                text(
+                    self.localization
+                        .get_message("hide-archived-in-history", None)
+                ),
+                toggler(self.fur_settings.hide_archived_in_history)
+                    .on_toggle(Message::SettingsHideArchivedInHistoryToggled)
+                    .width(Length::Shrink)
+                    .style(style::fur_toggler_style),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(10);
+        if self.all_projects.is_empty() {
+            projects_col =
+                projects_col.push(text(self.localization.get_message("no-projects", None)));
+        }
+        for project in &self.all_projects {
+            let project_name = project.clone();
+            let color_project_name = project.clone();
+            let tags_project_name = project.clone();
+            let project_color = self
+                .project_colors
+                .get(project)
+                .copied()
+                .unwrap_or(FURTHERANCE_PURPLE);
+            projects_col = projects_col.push(
+                row![
+                    project_label(project, self.project_colors.get(project).copied()),
+                    space::horizontal().width(Length::Fill),
+                    color_picker(
+                        self.project_color_to_edit.as_ref() == Some(project),
+                        project_color.to_iced_color(),
+                        button(text(self.localization.get_message("color", None)).style(
+                            move |_| {
+                                text::Style {
+                                    color: Some(if is_dark_color(project_color) {
+                                        Color::WHITE
+                                    } else {
+                                        Color::BLACK
+                                    }),
+                                }
+                            }
+                        ))
+                        .on_press(Message::ChooseProjectColor(project.clone()))
+                        .style(move |theme, status| {
+                            style::shortcut_button_style(theme, status, project_color)
+                        }),
+                        Message::CancelProjectColor,
+                        move |color| Message::SubmitProjectColor(color_project_name.clone(), color),
+                    ),
+                    text_input(
+                        &self.localization.get_message("default-tags", None),
+                        self.project_default_tags
+                            .get(project)
+                            .map_or("", String::as_str),
+                    )
+                    .on_input(move |tags| {
+                        Message::ProjectDefaultTagsChanged(tags_project_name.clone(), tags)
+                    })
+                    .width(Length::Fixed(180.0)),
+                    text(self.localization.get_message("archived", None)),
+                    toggler(self.fur_settings.is_project_archived(project))
+                        .on_toggle(move |archive| {
+                            Message::ProjectArchiveToggled(project_name.clone(), archive)
+                        })
+                        .width(Length::Shrink)
+                        .style(style::fur_toggler_style),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+
+        let auto_stop_at =
+            parse_auto_stop_time(&self.fur_settings.auto_stop_time).unwrap_or_default();
+        let auto_stop_row = row![
+            column![
+                text(self.localization.get_message("auto-stop-timer", None)),
+                text(
+                    self.localization
+                        .get_message("auto-stop-timer-description", None)
+                )
+                .size(12),
+            ],
+            toggler(self.fur_settings.auto_stop_enabled)
+                .on_toggle(Message::SettingsAutoStopToggled)
+                .width(Length::Shrink)
+                .style(style::fur_toggler_style),
+            TimePicker::new(
+                self.show_auto_stop_time_picker,
+                time_picker::Time::from(auto_stop_at),
+                Button::new(text(format_time(auto_stop_at, false)))
+                    .on_press_maybe(
+                        self.fur_settings
+                            .auto_stop_enabled
+                            .then_some(Message::ChooseAutoStopTime)
+                    )
+                    .style(style::primary_button_style),
+                Message::CancelAutoStopTime,
+                Message::SubmitAutoStopTime,
+            )
+            .clock_format(self.fur_settings.use_24_hour_time),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let settings_view: Column<'_, Message, Theme, Renderer> = column![
+            Tabs::new(Message::SettingsTabSelected)
+                .tab_icon_position(iced_aw::tabs::Position::Top)
+                .push(
+                    TabId::General,
+                    TabLabel::IconText(
+                        advanced_text::gear_fill().0.chars().next().unwrap_or(' '),
+                        self.localization.get_message("general", None)
+                    ),
+                    Scrollable::new(
+                        column![
+                            settings_heading(self.localization.get_message("interface", None)),
+                            row![
+                                text(self.localization.get_message("default-view", None)),
+                                pick_list(
+                                    &FurView::ALL[..],
+                                    Some(self.fur_settings.default_view),
+                                    Message::SettingsDefaultViewSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("theme", None)),
+                                pick_list(
+                                    &FurThemePreference::ALL[..],
+                                    Some(self.fur_settings.theme_preference),
+                                    Message::SettingsThemePreferenceSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("default-currency", None)),
+                                text_input("USD", &self.fur_settings.default_currency)
+                                    .id(CURRENCY_INPUT_ID)
+                                    .on_input(Message::SettingsDefaultCurrencyChanged)
+                                    .width(80),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("use-24-hour-time", None)),
+                                toggler(self.fur_settings.use_24_hour_time)
+                                    .on_toggle(Message::SettingsUse24HourTimeToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(
+                                    self.localization
+                                        .get_message("show-delete-confirmation", None)
+                                ),
+                                toggler(self.fur_settings.show_delete_confirmation)
+                                    .on_toggle(Message::SettingsDeleteConfirmationToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-timer-in-title", None)),
+                                toggler(self.fur_settings.show_timer_in_title)
+                                    .on_toggle(Message::SettingsShowTimerInTitleToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(self.localization.get_message("window-size", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("reset-window-size-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                button(text(
+                                    self.localization.get_message("reset-window-size", None)
+                                ))
+                                .on_press_maybe(
+                                    if self.fur_settings.saved_window_size().is_some()
+                                        || self.fur_settings.window_maximized
+                                    {
+                                        Some(Message::SettingsResetWindowSizePressed)
+                                    } else {
+                                        None
+                                    }
+                                )
+                                .style(style::primary_button_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("task-history", None)),
+                            row![
+                                text(self.localization.get_message("show-project", None)),
+                                toggler(self.fur_settings.show_task_project)
+                                    .on_toggle(Message::SettingsShowTaskProjectToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-tags", None)),
+                                toggler(self.fur_settings.show_task_tags)
+                                    .on_toggle(Message::SettingsShowTaskTagsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-planned-time", None)),
+                                toggler(self.fur_settings.show_task_planned_time)
+                                    .on_toggle(Message::SettingsShowTaskPlannedTimeToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-earnings", None)),
+                                toggler(self.fur_settings.show_task_earnings)
+                                    .on_toggle(Message::SettingsShowEarningsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-seconds", None)),
+                                toggler(self.fur_settings.show_seconds)
+                                    .on_toggle(Message::SettingsShowSecondsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-daily-time-total", None)),
+                                toggler(self.fur_settings.show_daily_time_total)
+                                    .on_toggle(Message::SettingsShowDailyTimeTotalToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("shortcuts", None)),
+                            row![
+                                text(self.localization.get_message("show-shortcut-stats", None)),
+                                toggler(self.fur_settings.show_shortcut_stats)
+                                    .on_toggle(Message::SettingsShowShortcutStatsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("todos", None)),
+                            row![
+                                text(self.localization.get_message("show-project", None)),
+                                toggler(self.fur_settings.show_todo_project)
+                                    .on_toggle(Message::SettingsShowTodoProjectToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-tags", None)),
+                                toggler(self.fur_settings.show_todo_tags)
+                                    .on_toggle(Message::SettingsShowTodoTagsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-rate", None)),
+                                toggler(self.fur_settings.show_todo_rate)
+                                    .on_toggle(Message::SettingsShowTodoRateToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("show-estimates", None)),
+                                toggler(self.fur_settings.show_todo_estimates)
+                                    .on_toggle(Message::SettingsShowTodoEstimatesToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(
+                                        self.localization
+                                            .get_message("hide-completed-todos-after", None)
+                                    ),
+                                    text(self.localization.get_message(
+                                        "hide-completed-todos-after-description",
+                                        None
+                                    ))
+                                    .size(12),
+                                ],
+                                number_input(
+                                    &self.fur_settings.auto_hide_completed_after_days,
+                                    0..=365,
+                                    Message::SettingsAutoHideCompletedAfterDaysChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("sounds", None)),
+                            row![
+                                text(self.localization.get_message("play-sounds", None)),
+                                toggler(self.fur_settings.sounds_enabled)
+                                    .on_toggle(Message::SettingsSoundsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("sound-on-start-stop", None)),
+                                toggler(self.fur_settings.sound_on_start_stop)
+                                    .on_toggle_maybe(
+                                        self.fur_settings
+                                            .sounds_enabled
+                                            .then_some(Message::SettingsSoundOnStartStopToggled)
+                                    )
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("sound-on-idle", None)),
+                                toggler(self.fur_settings.sound_on_idle)
+                                    .on_toggle_maybe(
+                                        self.fur_settings
+                                            .sounds_enabled
+                                            .then_some(Message::SettingsSoundOnIdleToggled)
+                                    )
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                        ]
+                        .spacing(SETTINGS_SPACING)
+                        .padding(10)
+                    ),
+                )
+                .push(
+                    TabId::Advanced,
+                    TabLabel::IconText(
+                        advanced_text::gear_wide_connected()
+                            .0
+                            .chars()
+                            .next()
+                            .unwrap_or(' '),
+                        self.localization.get_message("advanced", None)
+                    ),
+                    Scrollable::new(
                         column![
                             settings_heading(self.localization.get_message("idle", None)),
                             row![
-                                text(self.localization.get_message("idle-detection", None)),
-                                toggler(self.fur_settings.notify_on_idle)
-                                    .on_toggle(Message::SettingsIdleToggled)
+                                text(self.localization.get_message("idle-detection", None)),
+                                toggler(self.fur_settings.notify_on_idle)
+                                    .on_toggle(Message::SettingsIdleToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(self.localization.get_message("idle-backend", None)),
+                                    match &self.idle_backend_status {
+                                        Some(Ok(backend)) => text(self.localization.get_message(
+                                            "idle-backend-active",
+                                            Some(&HashMap::from([(
+                                                "backend",
+                                                FluentValue::from(backend.to_string())
+                                            )]))
+                                        ))
+                                        .size(12),
+                                        Some(Err(e)) => text(self.localization.get_message(
+                                            "idle-detection-unavailable",
+                                            Some(&HashMap::from([(
+                                                "error",
+                                                FluentValue::from(e.clone())
+                                            )]))
+                                        ))
+                                        .size(12)
+                                        .style(style::red_text),
+                                        None => text("").size(12),
+                                    },
+                                ],
+                                pick_list(
+                                    &FurIdleBackend::ALL[..],
+                                    Some(self.fur_settings.idle_backend),
+                                    Message::SettingsIdleBackendSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("minutes-until-idle", None)),
+                                number_input(
+                                    &self.fur_settings.chosen_idle_time,
+                                    1..999,
+                                    Message::SettingsIdleTimeChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("device", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("device-name", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("device-name-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                text_input(&hostname(), &self.fur_settings.device_name)
+                                    .id(DEVICE_NAME_INPUT_ID)
+                                    .on_input(Message::SettingsDeviceNameChanged)
+                                    .width(200),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(
+                                self.localization.get_message("task-length-checks", None)
+                            ),
+                            row![
+                                column![
+                                    text(
+                                        self.localization
+                                            .get_message("warn-if-longer-than-hours", None)
+                                    ),
+                                    text(self.localization.get_message("zero-turns-off", None))
+                                        .size(12),
+                                ],
+                                number_input(
+                                    &self.fur_settings.warn_if_longer_than_hours,
+                                    0..=168,
+                                    Message::SettingsWarnIfLongerThanChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(self.localization.get_message("confirm-on-stop", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("confirm-on-stop-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.confirm_on_stop)
+                                    .on_toggle(Message::SettingsConfirmOnStopToggled)
                                     .width(Length::Shrink)
-                                    .style(style::fur_toggler_style)
+                                    .style(style::fur_toggler_style),
                             ]
                             .spacing(10)
                             .align_y(Alignment::Center),
                             row![
-                                text(self.localization.get_message("minutes-until-idle", None)),
+                                text(
+                                    self.localization
+                                        .get_message("confirm-shortcut-switch", None)
+                                ),
+                                toggler(self.fur_settings.confirm_shortcut_switch)
+                                    .on_toggle(Message::SettingsConfirmShortcutSwitchToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(
+                                        self.localization
+                                            .get_message("discard-if-shorter-than-seconds", None)
+                                    ),
+                                    text(self.localization.get_message("zero-turns-off", None))
+                                        .size(12),
+                                ],
                                 number_input(
-                                    &self.fur_settings.chosen_idle_time,
-                                    1..999,
-                                    Message::SettingsIdleTimeChanged
+                                    &self.fur_settings.discard_if_shorter_than_seconds,
+                                    0..=3600,
+                                    Message::SettingsDiscardIfShorterThanChanged
                                 )
                                 .style(style::fur_number_input_style)
                             ]
                             .spacing(10)
                             .align_y(Alignment::Center),
+                            auto_stop_row,
                             settings_heading(self.localization.get_message("task-history", None)),
                             row![
                                 column![
@@ -1420,6 +3781,43 @@ impl Furtherance {
                             ]
                             .spacing(10)
                             .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("rounding", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("round-durations", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("round-durations-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.rounding_enabled)
+                                    .on_toggle(Message::SettingsRoundingToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("round-to-minutes", None)),
+                                pick_list(
+                                    &ROUNDING_INCREMENTS[..],
+                                    Some(self.fur_settings.rounding_minutes),
+                                    Message::SettingsRoundingMinutesSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("round-direction", None)),
+                                pick_list(
+                                    &FurRoundingDirection::ALL[..],
+                                    Some(self.fur_settings.rounding_direction),
+                                    Message::SettingsRoundingDirectionSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
                             settings_heading(
                                 self.localization.get_message("reminder-notification", None)
                             ),
@@ -1444,6 +3842,37 @@ impl Furtherance {
                             ]
                             .spacing(10)
                             .align_y(Alignment::Center),
+                            if let Some(Err(_)) = &self.notification_status {
+                                Some(
+                                    text(
+                                        self.localization
+                                            .get_message("notifications-unavailable-hint", None),
+                                    )
+                                    .size(12)
+                                    .style(style::red_text),
+                                )
+                            } else {
+                                None
+                            },
+                            row![
+                                button(text(
+                                    self.localization.get_message("test-notification", None)
+                                ))
+                                .on_press(Message::TestNotificationPressed)
+                                .style(style::primary_button_style),
+                                match &self.settings_notification_message {
+                                    Ok(msg) => {
+                                        if msg.is_empty() {
+                                            None
+                                        } else {
+                                            Some(text(msg).style(style::green_text))
+                                        }
+                                    }
+                                    Err(e) => Some(text!("{}", e).style(style::red_text)),
+                                },
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
                             row![
                                 text(self.localization.get_message("reminder-interval", None)),
                                 number_input(
@@ -1455,6 +3884,121 @@ impl Furtherance {
                             ]
                             .spacing(10)
                             .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(
+                                        self.localization
+                                            .get_message("planned-time-notifications", None)
+                                    ),
+                                    text(self.localization.get_message(
+                                        "planned-time-notifications-description",
+                                        None
+                                    ))
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.notify_on_planned_time)
+                                    .on_toggle(Message::SettingsNotifyOnPlannedTimeToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("status-file", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("write-status-file", None)),
+                                    text(
+                                        self.localization.get_message(
+                                            "status-file-description",
+                                            Some(&HashMap::from([(
+                                                "path",
+                                                FluentValue::from(
+                                                    get_status_file_path()
+                                                        .to_string_lossy()
+                                                        .into_owned()
+                                                )
+                                            )]))
+                                        )
+                                    )
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.status_file)
+                                    .on_toggle(Message::SettingsStatusFileToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("status-server", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("serve-status", None)),
+                                    text(self.localization.get_message(
+                                        "status-server-description",
+                                        Some(&HashMap::from([(
+                                            "port",
+                                            FluentValue::from(self.fur_settings.status_server_port)
+                                        )]))
+                                    ))
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.status_server)
+                                    .on_toggle(Message::SettingsStatusServerToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("port", None)),
+                                number_input(
+                                    &self.fur_settings.status_server_port,
+                                    1024..=65535,
+                                    Message::SettingsStatusServerPortChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            self.fur_settings.status_server.then(|| row![
+                                text(self.localization.get_message("api-key", None)),
+                                text(&self.fur_settings.status_server_api_key)
+                                    .font(font::Font::MONOSPACE),
+                                button(text(self.localization.get_message("copy", None)))
+                                    .on_press(Message::CopyStatusServerKey)
+                                    .style(style::primary_button_style),
+                                button(text(self.localization.get_message("regenerate", None)))
+                                    .on_press(Message::RegenerateStatusServerKey)
+                                    .style(style::primary_button_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center)),
+                            (self.fur_settings.status_server && self.status_server.is_none()).then(
+                                || text(
+                                    self.localization
+                                        .get_message("status-server-not-running", None)
+                                )
+                                .style(style::red_text)
+                            ),
+                            settings_heading(self.localization.get_message("error-log", None)),
+                            row![
+                                text(self.localization.get_message(
+                                    "errors-this-session",
+                                    Some(&HashMap::from([(
+                                        "count",
+                                        FluentValue::from(self.errors.len())
+                                    )]))
+                                )),
+                                button(text(self.localization.get_message("view", None)))
+                                    .on_press_maybe(if self.errors.is_empty() {
+                                        None
+                                    } else {
+                                        Some(Message::ShowAlert(FurAlert::ErrorLog))
+                                    })
+                                    .style(style::primary_button_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
                             row![
                                 text(format!("Furtherance version {}", FURTHERANCE_VERSION)).font(
                                     font::Font {
@@ -1604,6 +4148,10 @@ impl Furtherance {
                                 .label(self.localization.get_message("total-earnings-box", None))
                                 .on_toggle(Message::SettingsShowChartTotalEarningsBoxToggled)
                                 .style(style::fur_checkbox_style),
+                            checkbox(self.fur_settings.show_chart_workday_stats)
+                                .label(self.localization.get_message("workday-stats", None))
+                                .on_toggle(Message::SettingsShowChartWorkdayStatsToggled)
+                                .style(style::fur_checkbox_style),
                             checkbox(self.fur_settings.show_chart_time_recorded)
                                 .label(self.localization.get_message("time-recorded", None))
                                 .on_toggle(Message::SettingsShowChartTimeRecordedToggled)
@@ -1612,6 +4160,10 @@ impl Furtherance {
                                 .label(self.localization.get_message("earnings", None))
                                 .on_toggle(Message::SettingsShowChartEarningsToggled)
                                 .style(style::fur_checkbox_style),
+                            checkbox(self.fur_settings.show_chart_cumulative_earnings)
+                                .label(self.localization.get_message("cumulative-earnings", None))
+                                .on_toggle(Message::SettingsShowChartCumulativeEarningsToggled)
+                                .style(style::fur_checkbox_style),
                             checkbox(self.fur_settings.show_chart_average_time)
                                 .label(self.localization.get_message("average-time-per-task", None))
                                 .on_toggle(Message::SettingsShowChartAverageTimeToggled)
@@ -1623,6 +4175,21 @@ impl Furtherance {
                                 )
                                 .on_toggle(Message::SettingsShowChartAverageEarningsToggled)
                                 .style(style::fur_checkbox_style),
+                            checkbox(self.fur_settings.show_chart_heatmap)
+                                .label(self.localization.get_message("activity-heatmap", None))
+                                .on_toggle(Message::SettingsShowChartHeatmapToggled)
+                                .style(style::fur_checkbox_style),
+                            checkbox(self.fur_settings.show_chart_time_of_day)
+                                .label(self.localization.get_message("time-of-day", None))
+                                .on_toggle(Message::SettingsShowChartTimeOfDayToggled)
+                                .style(style::fur_checkbox_style),
+                            checkbox(self.fur_settings.show_chart_todos)
+                                .label(
+                                    self.localization
+                                        .get_message("todos-planned-vs-completed", None)
+                                )
+                                .on_toggle(Message::SettingsShowChartTodosToggled)
+                                .style(style::fur_checkbox_style),
                             checkbox(self.fur_settings.show_chart_breakdown_by_selection)
                                 .label(
                                     self.localization
@@ -1656,6 +4223,147 @@ impl Furtherance {
                                     }
                                 )
                                 .style(style::fur_checkbox_style),
+                            settings_heading(
+                                self.localization
+                                    .get_message("monthly-earnings-target", None)
+                            ),
+                            row![
+                                column![
+                                    text(self.localization.get_message("target-per-month", None)),
+                                    text(
+                                        self.localization.get_message(
+                                            "monthly-earnings-target-description",
+                                            None
+                                        )
+                                    )
+                                    .size(12),
+                                ],
+                                number_input(
+                                    &self.fur_settings.monthly_earnings_target,
+                                    0.0..=f32::MAX,
+                                    Message::SettingsMonthlyEarningsTargetChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                column![
+                                    text(
+                                        self.localization
+                                            .get_message("weekly-summary-format", None)
+                                    ),
+                                    text(
+                                        self.localization
+                                            .get_message("weekly-summary-format-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                pick_list(
+                                    &FurSummaryFormat::ALL[..],
+                                    Some(self.fur_settings.summary_format),
+                                    Message::SettingsSummaryFormatSelected,
+                                ),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            scheduled_summary_col,
+                            settings_heading(self.localization.get_message("net-earnings", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("show-net-earnings", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("show-net-earnings-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.show_net_earnings)
+                                    .on_toggle(Message::SettingsShowNetEarningsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            row![
+                                text(self.localization.get_message("deduction-percent", None)),
+                                number_input(
+                                    &self.fur_settings.deduction_percent,
+                                    0.0..=100.0,
+                                    Message::SettingsDeductionPercentChanged
+                                )
+                                .step(0.5)
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(
+                                self.localization
+                                    .get_message("excluded-from-earnings", None)
+                            ),
+                            column![
+                                text(
+                                    self.localization
+                                        .get_message("excluded-from-earnings-description", None)
+                                )
+                                .size(12),
+                                text_input("#nonbill, @internal", &self.earnings_exclusions_input)
+                                    .on_input(Message::SettingsEarningsExclusionsChanged),
+                                earnings_exclusion_chips(&self.fur_settings.earnings_exclusions),
+                            ]
+                            .spacing(10),
+                            settings_heading(self.localization.get_message("daily-target", None)),
+                            row![
+                                column![
+                                    text(
+                                        self.localization.get_message("daily-target-minutes", None)
+                                    ),
+                                    text(
+                                        self.localization
+                                            .get_message("daily-target-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                number_input(
+                                    &self.fur_settings.daily_target_minutes,
+                                    0..=1440,
+                                    Message::SettingsDailyTargetChanged
+                                )
+                                .style(style::fur_number_input_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            (self.fur_settings.daily_target_minutes > 0).then(|| row![
+                                text(
+                                    self.localization
+                                        .get_message("target-applies-to-weekends", None)
+                                ),
+                                toggler(self.fur_settings.target_applies_to_weekends)
+                                    .on_toggle(Message::SettingsTargetAppliesToWeekendsToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style),
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center)),
+                            settings_heading(self.localization.get_message("days-off", None)),
+                            row![
+                                column![
+                                    text(self.localization.get_message("weekends-off", None)),
+                                    text(
+                                        self.localization
+                                            .get_message("weekends-off-description", None)
+                                    )
+                                    .size(12),
+                                ],
+                                toggler(self.fur_settings.report_weekends_off)
+                                    .on_toggle(Message::SettingsReportWeekendsOffToggled)
+                                    .width(Length::Shrink)
+                                    .style(style::fur_toggler_style)
+                            ]
+                            .spacing(10)
+                            .align_y(Alignment::Center),
+                            settings_heading(self.localization.get_message("weekly-goals", None)),
+                            goals_col,
                         ]
                         .spacing(SETTINGS_SPACING)
                         .padding(10),
@@ -1674,10 +4382,26 @@ impl Furtherance {
                             sync_server_col,
                             settings_heading(self.localization.get_message("local-database", None)),
                             database_location_col,
+                            settings_heading(self.localization.get_message("profiles", None)),
+                            profiles_col,
                             settings_heading("CSV".to_string()),
                             csv_col,
+                            settings_heading(self.localization.get_message("projects", None)),
+                            projects_col,
+                            settings_heading(self.localization.get_message("find-replace", None)),
+                            find_replace_col,
+                            settings_heading(self.localization.get_message("summary-file", None)),
+                            summary_file_col,
                             settings_heading(self.localization.get_message("more", None)),
                             backup_col,
+                            settings_heading(self.localization.get_message(
+                                "recently-deleted",
+                                Some(&HashMap::from([(
+                                    "count",
+                                    FluentValue::from(self.trash.len())
+                                )]))
+                            )),
+                            trash_col,
                         ]
                         .spacing(SETTINGS_SPACING)
                         .padding(10),
@@ -1696,6 +4420,7 @@ impl Furtherance {
                         &self.localization.get_message("task-name", None),
                         &task_to_add.name
                     )
+                    .id(INSPECTOR_NAME_INPUT_ID)
                     .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Name))
                     .on_submit_maybe(if task_to_add.name.trim().is_empty() {
                         None
@@ -1706,6 +4431,7 @@ impl Furtherance {
                         &self.localization.get_message("project", None),
                         &task_to_add.project
                     )
+                    .id(INSPECTOR_PROJECT_INPUT_ID)
                     .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Project))
                     .on_submit_maybe(if task_to_add.name.trim().is_empty() {
                         None
@@ -1716,6 +4442,7 @@ impl Furtherance {
                         &self.localization.get_message("hashtag-tags", None),
                         &task_to_add.tags
                     )
+                    .id(INSPECTOR_TAGS_INPUT_ID)
                     .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Tags))
                     .on_submit_maybe(if task_to_add.name.trim().is_empty() {
                         None
@@ -1723,12 +4450,22 @@ impl Furtherance {
                         Some(Message::SaveTaskEdit)
                     }),
                     text_input("0.00", &task_to_add.new_rate)
+                        .id(INSPECTOR_RATE_INPUT_ID)
                         .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Rate))
                         .on_submit_maybe(if task_to_add.name.trim().is_empty() {
                             None
                         } else {
                             Some(Message::SaveTaskEdit)
                         }),
+                    row![
+                        text(self.localization.get_message("billable", None)),
+                        toggler(task_to_add.is_billable)
+                            .on_toggle(Message::EditTaskBillableToggled)
+                            .width(Length::Shrink)
+                            .style(style::fur_toggler_style),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
                     row![
                         text(self.localization.get_message("start-colon", None)),
                         date_picker(
@@ -1746,7 +4483,7 @@ impl Furtherance {
                             task_to_add.show_start_time_picker,
                             task_to_add.displayed_start_time,
                             button(
-                                text(format_iced_time_as_hm(task_to_add.displayed_start_time))
+                                text(format_iced_time_as_hms(task_to_add.displayed_start_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StartTime))
@@ -1755,7 +4492,8 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StartTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StartTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
@@ -1776,7 +4514,7 @@ impl Furtherance {
                             task_to_add.show_stop_time_picker,
                             task_to_add.displayed_stop_time,
                             button(
-                                text(format_iced_time_as_hm(task_to_add.displayed_stop_time))
+                                text(format_iced_time_as_hms(task_to_add.displayed_stop_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StopTime))
@@ -1785,7 +4523,8 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StopTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StopTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
@@ -1802,11 +4541,14 @@ impl Furtherance {
                                 .align_x(alignment::Horizontal::Center)
                         )
                         .style(button::primary)
-                        .on_press_maybe(if task_to_add.name.trim().is_empty() {
-                            None
-                        } else {
-                            Some(Message::SaveTaskEdit)
-                        })
+                        .on_press_maybe(
+                            if task_to_add.name.trim().is_empty() || !task_to_add.times_are_valid()
+                            {
+                                None
+                            } else {
+                                Some(Message::SaveTaskEdit)
+                            }
+                        )
                         .width(Length::Fill)
                         .style(style::primary_button_style),
                     ]
@@ -1836,6 +4578,7 @@ impl Furtherance {
                         &self.localization.get_message("task", None),
                         &todo_to_add.name
                     )
+                    .id(INSPECTOR_NAME_INPUT_ID)
                     .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Task))
                     .on_submit_maybe(if todo_to_add.name.trim().is_empty() {
                         None
@@ -1846,6 +4589,7 @@ impl Furtherance {
                         &self.localization.get_message("project", None),
                         &todo_to_add.project
                     )
+                    .id(INSPECTOR_PROJECT_INPUT_ID)
                     .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Project))
                     .on_submit_maybe(if todo_to_add.name.trim().is_empty() {
                         None
@@ -1856,6 +4600,7 @@ impl Furtherance {
                         &self.localization.get_message("hashtag-tags", None),
                         &todo_to_add.tags
                     )
+                    .id(INSPECTOR_TAGS_INPUT_ID)
                     .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Tags))
                     .on_submit_maybe(if todo_to_add.name.trim().is_empty() {
                         None
@@ -1863,12 +4608,24 @@ impl Furtherance {
                         Some(Message::SaveTodoEdit)
                     }),
                     text_input("0.00", &todo_to_add.rate)
+                        .id(INSPECTOR_RATE_INPUT_ID)
                         .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Rate))
                         .on_submit_maybe(if todo_to_add.name.trim().is_empty() {
                             None
                         } else {
                             Some(Message::SaveTodoEdit)
                         }),
+                    text_input(
+                        &self.localization.get_message("estimate-placeholder", None),
+                        &todo_to_add.estimate
+                    )
+                    .id(INSPECTOR_ESTIMATE_INPUT_ID)
+                    .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Estimate))
+                    .on_submit_maybe(if todo_to_add.name.trim().is_empty() {
+                        None
+                    } else {
+                        Some(Message::SaveTodoEdit)
+                    }),
                     row![
                         text(self.localization.get_message("date-colon", None)),
                         date_picker(
@@ -1930,6 +4687,7 @@ impl Furtherance {
                         &self.localization.get_message("task-name", None),
                         &shortcut_to_add.name
                     )
+                    .id(INSPECTOR_NAME_INPUT_ID)
                     .on_input(|s| Message::EditShortcutTextChanged(s, EditTaskProperty::Name))
                     .on_submit_maybe(
                         if shortcut_to_add.name.trim().is_empty() {
@@ -1942,6 +4700,7 @@ impl Furtherance {
                         &self.localization.get_message("project", None),
                         &shortcut_to_add.project
                     )
+                    .id(INSPECTOR_PROJECT_INPUT_ID)
                     .on_input(|s| {
                         Message::EditShortcutTextChanged(s, EditTaskProperty::Project)
                     })
@@ -1956,6 +4715,7 @@ impl Furtherance {
                         &self.localization.get_message("hashtag-tags", None),
                         &shortcut_to_add.tags
                     )
+                    .id(INSPECTOR_TAGS_INPUT_ID)
                     .on_input(|s| Message::EditShortcutTextChanged(s, EditTaskProperty::Tags))
                     .on_submit_maybe(
                         if shortcut_to_add.name.trim().is_empty() {
@@ -1967,6 +4727,7 @@ impl Furtherance {
                     row![
                         text("$"),
                         text_input("0.00", &shortcut_to_add.new_rate)
+                            .id(INSPECTOR_RATE_INPUT_ID)
                             .on_input(|s| {
                                 Message::EditShortcutTextChanged(s, EditTaskProperty::Rate)
                             })
@@ -2065,7 +4826,7 @@ impl Furtherance {
                             task_to_add.show_start_time_picker,
                             task_to_add.displayed_start_time,
                             button(
-                                text(format_iced_time_as_hm(task_to_add.displayed_start_time))
+                                text(format_iced_time_as_hms(task_to_add.displayed_start_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StartTime))
@@ -2074,7 +4835,8 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StartTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StartTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
@@ -2090,7 +4852,7 @@ impl Furtherance {
                             task_to_add.show_stop_time_picker,
                             task_to_add.displayed_stop_time,
                             button(
-                                text(format_iced_time_as_hm(task_to_add.displayed_stop_time))
+                                text(format_iced_time_as_hms(task_to_add.displayed_stop_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StopTime))
@@ -2099,10 +4861,30 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StopTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StopTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
+                    time_picker(
+                        task_to_edit.show_split_time_picker,
+                        task_to_edit.displayed_split_time,
+                        button(
+                            text(self.localization.get_message("split", None))
+                                .align_x(alignment::Horizontal::Center)
+                        )
+                        // Splitting works on the saved task, so save other edits first
+                        .on_press_maybe(if task_to_edit.is_changed() {
+                            None
+                        } else {
+                            Some(Message::ChooseTaskSplitTime)
+                        })
+                        .width(Length::Fill)
+                        .style(button::secondary),
+                        Message::CancelTaskSplitTime,
+                        Message::SplitTask,
+                    )
+                    .clock_format(self.fur_settings.use_24_hour_time),
                     row![
                         button(
                             text(self.localization.get_message("cancel", None))
@@ -2146,6 +4928,7 @@ impl Furtherance {
                         &self.localization.get_message("task-name", None),
                         &shortcut_to_edit.new_name
                     )
+                    .id(INSPECTOR_NAME_INPUT_ID)
                     .on_input(|s| Message::EditShortcutTextChanged(s, EditTaskProperty::Name))
                     .on_submit_maybe(
                         if shortcut_to_edit.new_name.trim().is_empty()
@@ -2160,6 +4943,7 @@ impl Furtherance {
                         &self.localization.get_message("project", None),
                         &shortcut_to_edit.new_project
                     )
+                    .id(INSPECTOR_PROJECT_INPUT_ID)
                     .on_input(|s| {
                         Message::EditShortcutTextChanged(s, EditTaskProperty::Project)
                     })
@@ -2176,6 +4960,7 @@ impl Furtherance {
                         &self.localization.get_message("hashtag-tags", None),
                         &shortcut_to_edit.new_tags
                     )
+                    .id(INSPECTOR_TAGS_INPUT_ID)
                     .on_input(|s| Message::EditShortcutTextChanged(s, EditTaskProperty::Tags))
                     .on_submit_maybe(
                         if shortcut_to_edit.new_name.trim().is_empty()
@@ -2189,6 +4974,7 @@ impl Furtherance {
                     row![
                         text("$"),
                         text_input("0.00", &shortcut_to_edit.new_rate)
+                            .id(INSPECTOR_RATE_INPUT_ID)
                             .on_input(|s| {
                                 Message::EditShortcutTextChanged(s, EditTaskProperty::Rate)
                             })
@@ -2266,6 +5052,7 @@ impl Furtherance {
                     })
                     .spacing(10),
                     text(&shortcut_to_edit.invalid_input_error_message).style(style::red_text),
+                    shortcut_rate_history(&shortcut_to_edit.rate_history, &self.localization),
                 ]
                 .spacing(INSPECTOR_SPACING)
                 .padding(INSPECTOR_PADDING)
@@ -2286,7 +5073,9 @@ impl Furtherance {
                             .style(button::text),
                         space::horizontal(),
                         button(bootstrap::trash_fill())
-                            .on_press(if self.fur_settings.show_delete_confirmation {
+                            .on_press(if task_to_edit.is_unsaved {
+                                Message::ShowAlert(FurAlert::DiscardStoppedTaskConfirmation)
+                            } else if self.fur_settings.show_delete_confirmation {
                                 Message::ShowAlert(FurAlert::DeleteTaskConfirmation)
                             } else {
                                 Message::DeleteTasks
@@ -2294,9 +5083,12 @@ impl Furtherance {
                             .style(button::text),
                     ],
                     text_input(&task_to_edit.name, &task_to_edit.new_name)
+                        .id(INSPECTOR_NAME_INPUT_ID)
                         .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Name))
                         .on_submit_maybe(
-                            if task_to_edit.is_changed() && !task_to_edit.new_name.trim().is_empty()
+                            if task_to_edit.is_changed()
+                                && task_to_edit.times_are_valid()
+                                && !task_to_edit.new_name.trim().is_empty()
                             {
                                 Some(Message::SaveTaskEdit)
                             } else {
@@ -2304,9 +5096,12 @@ impl Furtherance {
                             }
                         ),
                     text_input(&task_to_edit.project, &task_to_edit.new_project)
+                        .id(INSPECTOR_PROJECT_INPUT_ID)
                         .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Project))
                         .on_submit_maybe(
-                            if task_to_edit.is_changed() && !task_to_edit.new_name.trim().is_empty()
+                            if task_to_edit.is_changed()
+                                && task_to_edit.times_are_valid()
+                                && !task_to_edit.new_name.trim().is_empty()
                             {
                                 Some(Message::SaveTaskEdit)
                             } else {
@@ -2314,9 +5109,12 @@ impl Furtherance {
                             }
                         ),
                     text_input(&task_to_edit.tags, &task_to_edit.new_tags)
+                        .id(INSPECTOR_TAGS_INPUT_ID)
                         .on_input(|s| Message::EditTaskTextChanged(s, EditTaskProperty::Tags))
                         .on_submit_maybe(
-                            if task_to_edit.is_changed() && !task_to_edit.new_name.trim().is_empty()
+                            if task_to_edit.is_changed()
+                                && task_to_edit.times_are_valid()
+                                && !task_to_edit.new_name.trim().is_empty()
                             {
                                 Some(Message::SaveTaskEdit)
                             } else {
@@ -2329,9 +5127,12 @@ impl Furtherance {
                             &format!("{:.2}", &task_to_edit.rate),
                             &task_to_edit.new_rate
                         )
+                        .id(INSPECTOR_RATE_INPUT_ID)
                         .on_input(|s| { Message::EditTaskTextChanged(s, EditTaskProperty::Rate) })
                         .on_submit_maybe(
-                            if task_to_edit.is_changed() && !task_to_edit.new_name.trim().is_empty()
+                            if task_to_edit.is_changed()
+                                && task_to_edit.times_are_valid()
+                                && !task_to_edit.new_name.trim().is_empty()
                             {
                                 Some(Message::SaveTaskEdit)
                             } else {
@@ -2341,6 +5142,15 @@ impl Furtherance {
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
+                    row![
+                        text(self.localization.get_message("billable", None)),
+                        toggler(task_to_edit.new_is_billable)
+                            .on_toggle(Message::EditTaskBillableToggled)
+                            .width(Length::Shrink)
+                            .style(style::fur_toggler_style),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
                     row![
                         text(self.localization.get_message("start-colon", None)),
                         date_picker(
@@ -2358,7 +5168,7 @@ impl Furtherance {
                             task_to_edit.show_displayed_start_time_picker,
                             task_to_edit.displayed_start_time,
                             Button::new(
-                                text(format_iced_time_as_hm(task_to_edit.displayed_start_time))
+                                text(format_iced_time_as_hms(task_to_edit.displayed_start_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StartTime))
@@ -2367,7 +5177,8 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StartTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StartTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
@@ -2388,7 +5199,7 @@ impl Furtherance {
                             task_to_edit.show_displayed_stop_time_picker,
                             task_to_edit.displayed_stop_time,
                             button(
-                                text(format_iced_time_as_hm(task_to_edit.displayed_stop_time))
+                                text(format_iced_time_as_hms(task_to_edit.displayed_stop_time))
                                     .center()
                             )
                             .on_press(Message::ChooseTaskEditDateTime(EditTaskProperty::StopTime))
@@ -2397,7 +5208,8 @@ impl Furtherance {
                             Message::CancelTaskEditDateTime(EditTaskProperty::StopTime),
                             |time| Message::SubmitTaskEditTime(time, EditTaskProperty::StopTime),
                         )
-                        .use_24h(),
+                        .clock_format(self.fur_settings.use_24_hour_time)
+                        .show_seconds(),
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
@@ -2415,7 +5227,9 @@ impl Furtherance {
                         )
                         .style(style::primary_button_style)
                         .on_press_maybe(
-                            if task_to_edit.is_changed() && !task_to_edit.new_name.trim().is_empty()
+                            if task_to_edit.is_changed()
+                                && task_to_edit.times_are_valid()
+                                && !task_to_edit.new_name.trim().is_empty()
                             {
                                 Some(Message::SaveTaskEdit)
                             } else {
@@ -2432,6 +5246,16 @@ impl Furtherance {
                     })
                     .spacing(10),
                     text(&task_to_edit.invalid_input_error_message).style(style::red_text),
+                    (!task_to_edit.device_name.is_empty()).then(|| {
+                        text(self.localization.get_message(
+                            "created-on-device",
+                            Some(&HashMap::from([(
+                                "device",
+                                FluentValue::from(task_to_edit.device_name.clone()),
+                            )])),
+                        ))
+                        .size(12)
+                    }),
                 ]
                 .spacing(INSPECTOR_SPACING)
                 .padding(INSPECTOR_PADDING)
@@ -2460,8 +5284,9 @@ impl Furtherance {
                     }
                     if group_to_edit.rate != 0.0 {
                         group_info_column =
-                            group_info_column.push(text!("${}", &group_to_edit.rate));
+                            group_info_column.push(text(format_currency(group_to_edit.rate, "")));
                     }
+                    let show_seconds = self.fur_settings.show_seconds;
                     let tasks_column: Scrollable<'_, Message, Theme, Renderer> =
                         Scrollable::new(group_to_edit.tasks.iter().fold(
                             Column::new().spacing(5),
@@ -2470,29 +5295,25 @@ impl Furtherance {
                                     .push(
                                         button(
                                             Container::new(column![
-                                                text(
-                                                    self.localization.get_message(
-                                                        "start-to-stop",
-                                                        Some(&HashMap::from([
-                                                            (
-                                                                "start",
-                                                                FluentValue::from(
-                                                                    task.start_time
-                                                                        .format("%H:%M")
-                                                                        .to_string()
-                                                                )
-                                                            ),
-                                                            (
-                                                                "stop",
-                                                                FluentValue::from(
-                                                                    task.stop_time
-                                                                        .format("%H:%M")
-                                                                        .to_string()
-                                                                )
-                                                            )
-                                                        ]))
-                                                    )
-                                                )
+                                                text(self.localization.get_message(
+                                                    "start-to-stop",
+                                                    Some(&HashMap::from([
+                                                        (
+                                                            "start",
+                                                            FluentValue::from(format_time(
+                                                                task.start_time.time(),
+                                                                show_seconds
+                                                            ))
+                                                        ),
+                                                        (
+                                                            "stop",
+                                                            FluentValue::from(format_time(
+                                                                task.stop_time.time(),
+                                                                show_seconds
+                                                            ))
+                                                        )
+                                                    ]))
+                                                ))
                                                 .font(font::Font {
                                                     weight: iced::font::Weight::Bold,
                                                     ..Default::default()
@@ -2563,29 +5384,76 @@ impl Furtherance {
                         // .spacing(5),
                         match group_to_edit.is_in_edit_mode {
                             true => column![
-                                text_input(&group_to_edit.name, &group_to_edit.new_name).on_input(
-                                    |s| Message::EditTaskTextChanged(s, EditTaskProperty::Name)
-                                ),
+                                text_input(&group_to_edit.name, &group_to_edit.new_name)
+                                    .id(INSPECTOR_NAME_INPUT_ID)
+                                    .on_input(|s| Message::EditTaskTextChanged(
+                                        s,
+                                        EditTaskProperty::Name
+                                    )),
                                 text_input(&group_to_edit.project, &group_to_edit.new_project)
+                                    .id(INSPECTOR_PROJECT_INPUT_ID)
                                     .on_input(|s| Message::EditTaskTextChanged(
                                         s,
                                         EditTaskProperty::Project
                                     )),
-                                text_input(&group_to_edit.tags, &group_to_edit.new_tags).on_input(
-                                    |s| Message::EditTaskTextChanged(s, EditTaskProperty::Tags)
-                                ),
+                                text_input(&group_to_edit.tags, &group_to_edit.new_tags)
+                                    .id(INSPECTOR_TAGS_INPUT_ID)
+                                    .on_input(|s| Message::EditTaskTextChanged(
+                                        s,
+                                        EditTaskProperty::Tags
+                                    )),
                                 row![
                                     text("$"),
                                     text_input(
                                         &format!("{:.2}", &group_to_edit.rate),
                                         &group_to_edit.new_rate
                                     )
+                                    .id(INSPECTOR_RATE_INPUT_ID)
                                     .on_input(|s| {
                                         Message::EditTaskTextChanged(s, EditTaskProperty::Rate)
-                                    }),
+                                    })
+                                    .on_submit_maybe(
+                                        if group_to_edit.is_changed()
+                                            && !group_to_edit.new_name.trim().is_empty()
+                                        {
+                                            Some(Message::SaveGroupEdit)
+                                        } else {
+                                            None
+                                        }
+                                    ),
                                 ]
                                 .align_y(Alignment::Center)
                                 .spacing(5),
+                                if group_to_edit.rate_is_changed() {
+                                    column![
+                                        radio(
+                                            self.localization
+                                                .get_message("rate-future-tasks-only", None),
+                                            false,
+                                            Some(group_to_edit.update_past_rates),
+                                            Message::GroupRateScopeSelected
+                                        )
+                                        .size(16)
+                                        .text_size(14),
+                                        radio(
+                                            self.localization.get_message(
+                                                "rate-update-past-tasks",
+                                                Some(&HashMap::from([(
+                                                    "count",
+                                                    FluentValue::from(group_to_edit.tasks.len())
+                                                )]))
+                                            ),
+                                            true,
+                                            Some(group_to_edit.update_past_rates),
+                                            Message::GroupRateScopeSelected
+                                        )
+                                        .size(16)
+                                        .text_size(14),
+                                    ]
+                                    .spacing(5)
+                                } else {
+                                    column![]
+                                },
                                 row![
                                     button(
                                         text(self.localization.get_message("cancel", None))
@@ -2634,6 +5502,53 @@ impl Furtherance {
                 .padding(20)
                 .align_x(Alignment::Start),
             },
+            // MARK: Edit Day Note
+            Some(FurInspectorView::EditDayNote) => match &self.day_note_to_edit {
+                Some(day_note_to_edit) => column![
+                    text(format_history_date(
+                        &day_note_to_edit.date,
+                        &self.localization
+                    ))
+                    .size(24),
+                    text_editor(&day_note_to_edit.content)
+                        .placeholder(self.localization.get_message("day-note-placeholder", None))
+                        .on_action(Message::DayNoteEdited)
+                        .height(200),
+                    row![
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                        )
+                        .style(button::secondary)
+                        .on_press(Message::CancelDayNoteEdit)
+                        .width(Length::Fill),
+                        button(
+                            text(self.localization.get_message("save", None))
+                                .align_x(alignment::Horizontal::Center)
+                        )
+                        .style(button::primary)
+                        .on_press_maybe(if day_note_to_edit.is_changed() {
+                            Some(Message::SaveDayNote)
+                        } else {
+                            None
+                        })
+                        .width(Length::Fill)
+                        .style(style::primary_button_style),
+                    ]
+                    .padding(Padding {
+                        top: 20.0,
+                        right: 0.0,
+                        bottom: 0.0,
+                        left: 0.0,
+                    })
+                    .spacing(10),
+                ]
+                .spacing(INSPECTOR_SPACING)
+                .padding(INSPECTOR_PADDING)
+                .width(INSPECTOR_WIDTH)
+                .align_x(INSPECTOR_ALIGNMENT),
+                None => column![].width(INSPECTOR_WIDTH),
+            },
             // MARK: Edit Todo
             Some(FurInspectorView::EditTodo) => match &self.todo_to_edit {
                 Some(todo_to_edit) => column![
@@ -2644,6 +5559,7 @@ impl Furtherance {
                             .style(button::text),
                     ],
                     text_input(&todo_to_edit.name, &todo_to_edit.new_name)
+                        .id(INSPECTOR_NAME_INPUT_ID)
                         .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Task))
                         .on_submit_maybe(
                             if todo_to_edit.name.trim().is_empty() || !todo_to_edit.is_changed() {
@@ -2653,6 +5569,7 @@ impl Furtherance {
                             }
                         ),
                     text_input(&todo_to_edit.project, &todo_to_edit.new_project)
+                        .id(INSPECTOR_PROJECT_INPUT_ID)
                         .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Project))
                         .on_submit_maybe(
                             if todo_to_edit.name.trim().is_empty() || !todo_to_edit.is_changed() {
@@ -2662,6 +5579,7 @@ impl Furtherance {
                             }
                         ),
                     text_input(&todo_to_edit.tags, &todo_to_edit.new_tags)
+                        .id(INSPECTOR_TAGS_INPUT_ID)
                         .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Tags))
                         .on_submit_maybe(
                             if todo_to_edit.name.trim().is_empty() || !todo_to_edit.is_changed() {
@@ -2676,6 +5594,7 @@ impl Furtherance {
                             &format!("{:.2}", &todo_to_edit.rate),
                             &todo_to_edit.new_rate
                         )
+                        .id(INSPECTOR_RATE_INPUT_ID)
                         .on_input(|s| { Message::EditTodoTextChanged(s, EditTodoProperty::Rate) })
                         .on_submit_maybe(
                             if todo_to_edit.name.trim().is_empty() || !todo_to_edit.is_changed() {
@@ -2687,6 +5606,19 @@ impl Furtherance {
                     ]
                     .align_y(Alignment::Center)
                     .spacing(5),
+                    text_input(
+                        &self.localization.get_message("estimate-placeholder", None),
+                        &todo_to_edit.new_estimate
+                    )
+                    .id(INSPECTOR_ESTIMATE_INPUT_ID)
+                    .on_input(|s| Message::EditTodoTextChanged(s, EditTodoProperty::Estimate))
+                    .on_submit_maybe(
+                        if todo_to_edit.name.trim().is_empty() || !todo_to_edit.is_changed() {
+                            None
+                        } else {
+                            Some(Message::SaveTodoEdit)
+                        }
+                    ),
                     row![
                         text(self.localization.get_message("date-colon", None)),
                         date_picker(
@@ -2742,11 +5674,80 @@ impl Furtherance {
                     .width(250)
                     .align_x(Alignment::Start),
             },
+            // MARK: Generate Invoice
+            Some(FurInspectorView::GenerateInvoice) => match &self.invoice_to_generate {
+                Some(invoice_to_generate) => column![
+                    text(self.localization.get_message("generate-invoice", None)).size(24),
+                    pick_list(
+                        &invoice_to_generate.projects[..],
+                        Some(invoice_to_generate.project.clone()),
+                        Message::InvoiceProjectSelected,
+                    )
+                    .width(Length::Fill),
+                    text_input(
+                        &self.localization.get_message("invoice-number", None),
+                        &invoice_to_generate.header.number
+                    )
+                    .id(INVOICE_NUMBER_INPUT_ID)
+                    .on_input(|s| Message::InvoiceTextChanged(s, InvoiceField::Number)),
+                    text_input(
+                        &self.localization.get_message("invoice-from", None),
+                        &invoice_to_generate.header.from
+                    )
+                    .id(INVOICE_FROM_INPUT_ID)
+                    .on_input(|s| Message::InvoiceTextChanged(s, InvoiceField::From)),
+                    text_input(
+                        &self.localization.get_message("bill-to", None),
+                        &invoice_to_generate.header.client
+                    )
+                    .id(INVOICE_CLIENT_INPUT_ID)
+                    .on_input(|s| Message::InvoiceTextChanged(s, InvoiceField::Client)),
+                    text_input(
+                        &self.localization.get_message("invoice-notes", None),
+                        &invoice_to_generate.header.notes
+                    )
+                    .id(INVOICE_NOTES_INPUT_ID)
+                    .on_input(|s| Message::InvoiceTextChanged(s, InvoiceField::Notes))
+                    .on_submit(Message::SaveInvoice),
+                    row![
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                        )
+                        .style(button::secondary)
+                        .on_press(Message::CancelInvoice)
+                        .width(Length::Fill),
+                        button(
+                            text(self.localization.get_message("save", None))
+                                .align_x(alignment::Horizontal::Center)
+                        )
+                        .style(style::primary_button_style)
+                        .on_press(Message::SaveInvoice)
+                        .width(Length::Fill),
+                    ]
+                    .padding(Padding {
+                        top: 20.0,
+                        right: 0.0,
+                        bottom: 0.0,
+                        left: 0.0,
+                    })
+                    .spacing(10),
+                    match &invoice_to_generate.message {
+                        Ok(msg) => text(msg).style(style::green_text),
+                        Err(e) => text(e.to_string()).style(style::red_text),
+                    },
+                ]
+                .spacing(INSPECTOR_SPACING)
+                .padding(INSPECTOR_PADDING)
+                .width(INSPECTOR_WIDTH)
+                .align_x(INSPECTOR_ALIGNMENT),
+                None => column![].width(INSPECTOR_WIDTH),
+            },
             _ => column![],
         };
 
         let inspector_row = if self.inspector_view.is_some() {
-            Some(row![rule::vertical(1), inspector].width(260))
+            Some(row![rule::vertical(1), inspector].width(INSPECTOR_ROW_WIDTH))
         } else {
             None
         };
@@ -2759,6 +5760,7 @@ impl Furtherance {
                 FurView::Timer => timer_view,
                 FurView::Todo => todo_view,
                 FurView::Report => charts_view,
+                FurView::Search => search_view,
                 FurView::Settings => settings_view,
             },
             inspector_row,
@@ -2771,6 +5773,7 @@ impl Furtherance {
             let close_button: Option<Button<'_, Message, Theme, Renderer>>;
             let mut confirmation_button: Option<Button<'_, Message, Theme, Renderer>> = None;
             let mut snooze_button: Option<Button<'_, Message, Theme, Renderer>> = None;
+            let mut alert_content: Option<Element<'_, Message>> = None;
 
             match self.displayed_alert.as_ref().unwrap() {
                 FurAlert::AutosaveRestored => {
@@ -2780,21 +5783,229 @@ impl Furtherance {
                         .get_message("autosave-restored-description", None);
                     close_button = Some(
                         button(
-                            text(self.localization.get_message("ok", None))
+                            text(self.localization.get_message("ok", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::primary),
+                    );
+                }
+                FurAlert::AutosaveCorrupt => {
+                    alert_text = self.localization.get_message("autosave-corrupt", None);
+                    alert_description = self
+                        .localization
+                        .get_message("autosave-corrupt-description", None);
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("ok", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::primary),
+                    );
+                }
+                FurAlert::CsvImportErrors => {
+                    alert_text = self.localization.get_message("csv-import-problems", None);
+                    let mut problems = self
+                        .csv_import_errors
+                        .iter()
+                        .take(CSV_IMPORT_ERRORS_SHOWN)
+                        .map(|error| {
+                            self.localization.get_message(
+                                "csv-row-error",
+                                Some(&HashMap::from([
+                                    ("row", FluentValue::from(error.row)),
+                                    ("problem", FluentValue::from(error.problem.as_str())),
+                                ])),
+                            )
+                        })
+                        .collect::<Vec<String>>();
+                    if self.csv_import_errors.len() > CSV_IMPORT_ERRORS_SHOWN {
+                        problems.push(self.localization.get_message(
+                            "csv-more-problems",
+                            Some(&HashMap::from([(
+                                "count",
+                                FluentValue::from(
+                                    self.csv_import_errors.len() - CSV_IMPORT_ERRORS_SHOWN,
+                                ),
+                            )])),
+                        ));
+                    }
+                    alert_description = problems.join("\n");
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("ok", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::primary),
+                    );
+                }
+                FurAlert::DeleteArchivedTodosConfirmation => {
+                    alert_text = self
+                        .localization
+                        .get_message("delete-archived-todos-question", None);
+                    alert_description = self
+                        .localization
+                        .get_message("delete-archived-todos-description", None);
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("delete", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::DeleteArchivedTodos)
+                        .style(button::danger),
+                    );
+                }
+                FurAlert::DeleteEverythingConfirmation => {
+                    alert_text = self
+                        .localization
+                        .get_message("delete-everything-question", None);
+                    let is_synced = self.fur_user.is_some();
+                    alert_description = if is_synced {
+                        self.localization
+                            .get_message("delete-everything-synced-description", None)
+                    } else {
+                        self.localization
+                            .get_message("delete-everything-description", None)
+                    };
+                    if is_synced {
+                        alert_content = Some(
+                            column![
+                                text(self.localization.get_message(
+                                    "type-to-confirm",
+                                    Some(&HashMap::from([(
+                                        "word",
+                                        FluentValue::from(DELETE_EVERYTHING_CONFIRMATION_WORD),
+                                    )])),
+                                )),
+                                text_input(
+                                    DELETE_EVERYTHING_CONFIRMATION_WORD,
+                                    &self.delete_everything_confirmation,
+                                )
+                                .on_input(Message::DeleteEverythingConfirmationChanged),
+                                button(
+                                    text(
+                                        self.localization
+                                            .get_message("delete-local-data-only", None)
+                                    )
+                                    .align_x(alignment::Horizontal::Center)
+                                    .width(Length::Fill),
+                                )
+                                .on_press(Message::DeleteLocalData)
+                                .style(button::secondary)
+                                .width(Length::Fill),
+                            ]
+                            .spacing(10)
+                            .into(),
+                        );
+                    }
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("delete-everything", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press_maybe(
+                            if !is_synced
+                                || self.delete_everything_confirmation
+                                    == DELETE_EVERYTHING_CONFIRMATION_WORD
+                            {
+                                Some(Message::DeleteEverything)
+                            } else {
+                                None
+                            },
+                        )
+                        .style(button::danger),
+                    );
+                }
+                FurAlert::DeleteGroupConfirmation => {
+                    alert_text = self.localization.get_message("delete-all-question", None);
+                    alert_description =
+                        match (&self.history_selection, &self.delete_tasks_from_context) {
+                            (Some(_), Some(task_ids)) => self.localization.get_message(
+                                "delete-selected-description",
+                                Some(&HashMap::from([(
+                                    "count",
+                                    FluentValue::from(task_ids.len()),
+                                )])),
+                            ),
+                            _ => self
+                                .localization
+                                .get_message("delete-all-description", None),
+                        };
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("delete-all", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::DeleteTasks)
+                        .style(button::danger),
+                    );
+                }
+                FurAlert::DeleteShortcutConfirmation => {
+                    alert_text = self
+                        .localization
+                        .get_message("delete-shortcut-question", None);
+                    alert_description = self
+                        .localization
+                        .get_message("delete-shortcut-description", None);
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("delete", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::AlertClose)
-                        .style(button::primary),
+                        .on_press(Message::DeleteShortcut)
+                        .style(button::danger),
                     );
                 }
-                FurAlert::DeleteEverythingConfirmation => {
-                    alert_text = self
-                        .localization
-                        .get_message("delete-everything-question", None);
+                FurAlert::DeleteTaskConfirmation => {
+                    alert_text = self.localization.get_message("delete-task-question", None);
                     alert_description = self
                         .localization
-                        .get_message("delete-everything-description", None);
+                        .get_message("delete-task-description", None);
                     close_button = Some(
                         button(
                             text(self.localization.get_message("cancel", None))
@@ -2806,19 +6017,19 @@ impl Furtherance {
                     );
                     confirmation_button = Some(
                         button(
-                            text(self.localization.get_message("delete-everything", None))
+                            text(self.localization.get_message("delete", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::DeleteEverything)
+                        .on_press(Message::DeleteTasks)
                         .style(button::danger),
                     );
                 }
-                FurAlert::DeleteGroupConfirmation => {
-                    alert_text = self.localization.get_message("delete-all-question", None);
+                FurAlert::DeleteTodoConfirmation => {
+                    alert_text = self.localization.get_message("delete-todo-question", None);
                     alert_description = self
                         .localization
-                        .get_message("delete-all-description", None);
+                        .get_message("delete-todo-description", None);
                     close_button = Some(
                         button(
                             text(self.localization.get_message("cancel", None))
@@ -2830,24 +6041,24 @@ impl Furtherance {
                     );
                     confirmation_button = Some(
                         button(
-                            text(self.localization.get_message("delete-all", None))
+                            text(self.localization.get_message("delete", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::DeleteTasks)
+                        .on_press(Message::DeleteTodo)
                         .style(button::danger),
                     );
                 }
-                FurAlert::DeleteShortcutConfirmation => {
+                FurAlert::DiscardChangesConfirmation => {
                     alert_text = self
                         .localization
-                        .get_message("delete-shortcut-question", None);
+                        .get_message("discard-changes-question", None);
                     alert_description = self
                         .localization
-                        .get_message("delete-shortcut-description", None);
+                        .get_message("discard-changes-description", None);
                     close_button = Some(
                         button(
-                            text(self.localization.get_message("cancel", None))
+                            text(self.localization.get_message("keep-editing", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
@@ -2856,22 +6067,24 @@ impl Furtherance {
                     );
                     confirmation_button = Some(
                         button(
-                            text(self.localization.get_message("delete", None))
+                            text(self.localization.get_message("discard", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::DeleteShortcut)
+                        .on_press(Message::DiscardChanges)
                         .style(button::danger),
                     );
                 }
-                FurAlert::DeleteTaskConfirmation => {
-                    alert_text = self.localization.get_message("delete-task-question", None);
+                FurAlert::DiscardStoppedTaskConfirmation => {
+                    alert_text = self
+                        .localization
+                        .get_message("discard-stopped-task-question", None);
                     alert_description = self
                         .localization
-                        .get_message("delete-task-description", None);
+                        .get_message("discard-stopped-task-description", None);
                     close_button = Some(
                         button(
-                            text(self.localization.get_message("cancel", None))
+                            text(self.localization.get_message("keep-editing", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
@@ -2880,20 +6093,29 @@ impl Furtherance {
                     );
                     confirmation_button = Some(
                         button(
-                            text(self.localization.get_message("delete", None))
+                            text(self.localization.get_message("discard", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::DeleteTasks)
+                        .on_press(Message::DiscardStoppedTask)
                         .style(button::danger),
                     );
                 }
-                FurAlert::DeleteTodoConfirmation => {
-                    alert_text = self.localization.get_message("delete-todo-question", None);
-                    alert_description = self
+                FurAlert::DroppedDatabase => {
+                    alert_text = self
                         .localization
-                        .get_message("delete-todo-description", None);
-                    close_button = Some(
+                        .get_message("dropped-database-question", None);
+                    let file_name = self
+                        .dropped_database
+                        .as_ref()
+                        .and_then(|path| path.file_name())
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    alert_description = self.localization.get_message(
+                        "dropped-database-description",
+                        Some(&HashMap::from([("file", FluentValue::from(file_name))])),
+                    );
+                    snooze_button = Some(
                         button(
                             text(self.localization.get_message("cancel", None))
                                 .align_x(alignment::Horizontal::Center)
@@ -2902,14 +6124,23 @@ impl Furtherance {
                         .on_press(Message::AlertClose)
                         .style(button::secondary),
                     );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("merge-databases", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::DroppedDatabaseMergePressed)
+                        .style(button::secondary),
+                    );
                     confirmation_button = Some(
                         button(
-                            text(self.localization.get_message("delete", None))
+                            text(self.localization.get_message("open-as-database", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
-                        .on_press(Message::DeleteTodo)
-                        .style(button::danger),
+                        .on_press(Message::DroppedDatabaseOpenPressed)
+                        .style(style::primary_button_style),
                     );
                 }
                 FurAlert::Idle => {
@@ -2942,6 +6173,76 @@ impl Furtherance {
                         .style(button::danger),
                     );
                 }
+                FurAlert::LongTaskConfirmation => {
+                    let hours = self
+                        .long_task_to_confirm
+                        .as_ref()
+                        .map_or(0, |task| task.total_time_in_seconds() / 3600);
+                    alert_text = self.localization.get_message("long-task-title", None);
+                    alert_description = self.localization.get_message(
+                        "long-task-description",
+                        Some(&HashMap::from([("hours", FluentValue::from(hours))])),
+                    );
+                    snooze_button = Some(
+                        button(
+                            text(self.localization.get_message("discard", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Shrink),
+                        )
+                        .on_press(Message::LongTaskDiscard)
+                        .style(button::danger),
+                    );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("edit-stop-time", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::LongTaskEdit)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("save-as-is", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::LongTaskKeep)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::MergeDuplicatesConfirmation => {
+                    alert_text = self
+                        .localization
+                        .get_message("merge-duplicates-question", None);
+                    let duplicates = self.duplicates_found.clone().unwrap_or_default();
+                    alert_description = self.localization.get_message(
+                        "merge-duplicates-description",
+                        Some(&HashMap::from([
+                            ("tasks", FluentValue::from(duplicates.tasks.len())),
+                            ("shortcuts", FluentValue::from(duplicates.shortcuts.len())),
+                            ("todos", FluentValue::from(duplicates.todos.len())),
+                        ])),
+                    );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("merge-duplicates", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::MergeDuplicates)
+                        .style(button::danger),
+                    );
+                }
                 FurAlert::ImportMacDatabase => {
                     alert_text = self.localization.get_message("import-old-database", None);
                     alert_description = self
@@ -2988,6 +6289,207 @@ impl Furtherance {
                         .style(style::primary_button_style),
                     );
                 }
+                FurAlert::Onboarding => {
+                    let (step, step_number, total_steps, is_first_step, is_last_step) =
+                        match self.onboarding.as_ref() {
+                            Some(onboarding) => (
+                                onboarding.current_step(),
+                                onboarding.step_number(),
+                                onboarding.total_steps(),
+                                onboarding.is_first_step(),
+                                onboarding.is_last_step(),
+                            ),
+                            None => (OnboardingStep::Sync, 1, 1, true, true),
+                        };
+                    let step_title = match step {
+                        OnboardingStep::TaskSyntax => {
+                            alert_description = self
+                                .localization
+                                .get_message("onboarding-task-syntax-description", None);
+                            if let Some(onboarding) = self.onboarding.as_ref() {
+                                let (name, project, tags, rate) =
+                                    split_task_input(&onboarding.example_input);
+                                alert_content = Some(
+                                    column![
+                                        text_input(
+                                            &self
+                                                .localization
+                                                .get_message("task-input-placeholder", None),
+                                            &onboarding.example_input
+                                        )
+                                        .on_input(Message::OnboardingExampleChanged),
+                                        text(self.localization.get_message(
+                                            "onboarding-example-name",
+                                            Some(&HashMap::from([(
+                                                "name",
+                                                FluentValue::from(name)
+                                            )]))
+                                        )),
+                                        text(self.localization.get_message(
+                                            "onboarding-example-project",
+                                            Some(&HashMap::from([(
+                                                "project",
+                                                FluentValue::from(project)
+                                            )]))
+                                        )),
+                                        text(self.localization.get_message(
+                                            "onboarding-example-tags",
+                                            Some(&HashMap::from([(
+                                                "tags",
+                                                FluentValue::from(if tags.is_empty() {
+                                                    tags
+                                                } else {
+                                                    format!("#{}", tags)
+                                                })
+                                            )]))
+                                        )),
+                                        text(self.localization.get_message(
+                                            "onboarding-example-rate",
+                                            Some(&HashMap::from([(
+                                                "rate",
+                                                FluentValue::from(format!("{:.2}", rate))
+                                            )]))
+                                        )),
+                                    ]
+                                    .spacing(5)
+                                    .into(),
+                                );
+                            }
+                            self.localization
+                                .get_message("onboarding-task-syntax-title", None)
+                        }
+                        OnboardingStep::FirstShortcut => {
+                            alert_description = self
+                                .localization
+                                .get_message("onboarding-shortcut-description", None);
+                            if self
+                                .onboarding
+                                .as_ref()
+                                .map_or(false, |onboarding| onboarding.shortcut_created)
+                            {
+                                alert_content = Some(
+                                    text(
+                                        self.localization
+                                            .get_message("onboarding-shortcut-created", None),
+                                    )
+                                    .style(style::green_text)
+                                    .into(),
+                                );
+                            } else {
+                                alert_content = Some(
+                                    button(text(
+                                        self.localization.get_message("create-shortcut", None),
+                                    ))
+                                    .on_press(Message::OnboardingCreateShortcut)
+                                    .style(style::primary_button_style)
+                                    .into(),
+                                );
+                            }
+                            self.localization
+                                .get_message("onboarding-shortcut-title", None)
+                        }
+                        OnboardingStep::Settings => {
+                            alert_description = self
+                                .localization
+                                .get_message("onboarding-settings-description", None);
+                            self.localization
+                                .get_message("onboarding-settings-title", None)
+                        }
+                        OnboardingStep::IdleAndPomodoro => {
+                            alert_description = self
+                                .localization
+                                .get_message("onboarding-idle-pomodoro-description", None);
+                            alert_content = Some(
+                                column![
+                                    row![
+                                        text(self.localization.get_message("idle-detection", None)),
+                                        space::horizontal(),
+                                        toggler(self.fur_settings.notify_on_idle)
+                                            .on_toggle(Message::SettingsIdleToggled)
+                                            .width(Length::Shrink)
+                                            .style(style::fur_toggler_style),
+                                    ]
+                                    .align_y(Alignment::Center),
+                                    row![
+                                        text(self.localization.get_message("pomodoro-timer", None)),
+                                        space::horizontal(),
+                                        toggler(self.fur_settings.pomodoro)
+                                            .on_toggle_maybe(if self.timer_is_running {
+                                                None
+                                            } else {
+                                                Some(Message::SettingsPomodoroToggled)
+                                            })
+                                            .width(Length::Shrink)
+                                            .style(style::fur_toggler_style),
+                                    ]
+                                    .align_y(Alignment::Center),
+                                ]
+                                .spacing(10)
+                                .into(),
+                            );
+                            self.localization
+                                .get_message("onboarding-idle-pomodoro-title", None)
+                        }
+                        OnboardingStep::Sync => {
+                            alert_description = self
+                                .localization
+                                .get_message("onboarding-sync-description", None);
+                            alert_content = Some(
+                                button(text(self.localization.get_message("learn-more", None)))
+                                    .on_press(Message::OpenUrl(
+                                        "https://furtherance.app/sync".to_string(),
+                                    ))
+                                    .style(button::secondary)
+                                    .into(),
+                            );
+                            self.localization.get_message("onboarding-sync-title", None)
+                        }
+                    };
+                    alert_text = self.localization.get_message(
+                        "onboarding-title",
+                        Some(&HashMap::from([
+                            ("title", FluentValue::from(step_title)),
+                            ("step", FluentValue::from(step_number)),
+                            ("total", FluentValue::from(total_steps)),
+                        ])),
+                    );
+                    if !is_last_step {
+                        snooze_button = Some(
+                            button(
+                                text(self.localization.get_message("skip", None))
+                                    .align_x(alignment::Horizontal::Center)
+                                    .width(Length::Fill),
+                            )
+                            .on_press(Message::OnboardingSkip)
+                            .style(button::text),
+                        );
+                    }
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("back", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press_maybe(if is_first_step {
+                            None
+                        } else {
+                            Some(Message::OnboardingBack)
+                        })
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(
+                                self.localization
+                                    .get_message(if is_last_step { "done" } else { "next" }, None),
+                            )
+                            .align_x(alignment::Horizontal::Center)
+                            .width(Length::Fill),
+                        )
+                        .on_press(Message::OnboardingNext)
+                        .style(style::primary_button_style),
+                    );
+                }
                 FurAlert::PomodoroBreakOver => {
                     alert_text = self.localization.get_message("break-over-title", None);
                     alert_description = self
@@ -3061,6 +6563,139 @@ impl Furtherance {
                         .style(style::primary_button_style),
                     );
                 }
+                FurAlert::SettingsRecovered => {
+                    alert_text = self.localization.get_message("settings-recovered", None);
+                    let mut details = vec![if self
+                        .settings_recovery
+                        .as_ref()
+                        .map_or(false, |recovery| recovery.backed_up)
+                    {
+                        self.localization
+                            .get_message("settings-recovered-description", None)
+                    } else {
+                        self.localization
+                            .get_message("settings-recovered-not-backed-up", None)
+                    }];
+                    if let Some(recovery) = &self.settings_recovery {
+                        if !recovery.reset.is_empty() {
+                            details.push(self.localization.get_message(
+                                "settings-recovered-reset",
+                                Some(&HashMap::from([(
+                                    "settings",
+                                    FluentValue::from(recovery.reset.join(", ")),
+                                )])),
+                            ));
+                        }
+                        if recovery.reset.iter().any(|key| key == "database_url") {
+                            details.push(
+                                self.localization
+                                    .get_message("settings-recovered-database", None),
+                            );
+                        }
+                    }
+                    alert_description = details.join("\n\n");
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("ok", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::SwitchToShortcutConfirmation => {
+                    alert_text = self.localization.get_message("switch-task-question", None);
+                    alert_description = self.localization.get_message(
+                        "switch-to-shortcut-description",
+                        Some(&HashMap::from([
+                            (
+                                "current",
+                                FluentValue::from(self.running_task_input.clone()),
+                            ),
+                            (
+                                "next",
+                                FluentValue::from(
+                                    self.shortcut_to_switch_to
+                                        .as_ref()
+                                        .map_or(String::new(), |shortcut| shortcut.to_string()),
+                                ),
+                            ),
+                        ])),
+                    );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("cancel", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message("switch-task", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::SwitchToShortcut)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::SyncHistory => {
+                    alert_text = self.localization.get_message("sync-history", None);
+                    alert_description = if self.sync_history.is_empty() {
+                        self.localization.get_message("sync-history-empty", None)
+                    } else {
+                        self.localization.get_message(
+                            "sync-history-description",
+                            Some(&HashMap::from([(
+                                "pending",
+                                FluentValue::from(self.pending_sync_changes),
+                            )])),
+                        )
+                    };
+                    let mut sync_list = column![].spacing(8);
+                    for record in &self.sync_history {
+                        sync_list = sync_list.push(
+                            column![
+                                text(sync_time_text(record.synced_at)).size(12),
+                                text(self.localization.get_message(
+                                    "sync-history-entry",
+                                    Some(&HashMap::from([
+                                        ("uploaded", FluentValue::from(record.uploaded)),
+                                        ("downloaded", FluentValue::from(record.downloaded)),
+                                        (
+                                            "size",
+                                            FluentValue::from(format_byte_size(
+                                                record.payload_bytes
+                                            )),
+                                        ),
+                                        (
+                                            "seconds",
+                                            FluentValue::from(format!(
+                                                "{:.1}",
+                                                record.duration_millis as f64 / 1000.0
+                                            )),
+                                        ),
+                                    ])),
+                                ))
+                                .size(14),
+                            ]
+                            .spacing(2),
+                        );
+                    }
+                    alert_content = Some(sync_list.into());
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("close", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(style::primary_button_style),
+                    );
+                }
                 FurAlert::ShortcutExists => {
                     alert_text = self.localization.get_message("shortcut-exists", None);
                     alert_description = self
@@ -3068,12 +6703,160 @@ impl Furtherance {
                         .get_message("shortcut-exists-description", None);
                     close_button = Some(
                         button(
-                            text(self.localization.get_message("ok", None))
+                            text(self.localization.get_message("ok", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::ErrorLog => {
+                    alert_text = self.localization.get_message("error-log", None);
+                    alert_description =
+                        self.localization.get_message("error-log-description", None);
+                    let mut error_list = column![].spacing(8);
+                    for (at, message) in self.errors.iter().rev() {
+                        error_list = error_list.push(
+                            column![
+                                text(format_time(at.time(), true)).size(12),
+                                text(message.clone()).size(14),
+                            ]
+                            .spacing(2),
+                        );
+                    }
+                    alert_content = Some(
+                        Scrollable::new(error_list)
+                            .height(Length::Fixed(300.0))
+                            .into(),
+                    );
+                    snooze_button = Some(
+                        button(
+                            text(self.localization.get_message("copy", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::CopyErrorLog)
+                        .style(button::secondary),
+                    );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("close", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::AlertClose)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::TimerStartOverlap => {
+                    alert_text = self.localization.get_message("start-overlap-title", None);
+                    let previous_stop = self
+                        .timer_start_overlap
+                        .map(|(_, previous_stop)| format_time(previous_stop.time(), false))
+                        .unwrap_or_default();
+                    alert_description = self.localization.get_message(
+                        "start-overlap-description",
+                        Some(&HashMap::from([(
+                            "time",
+                            FluentValue::from(previous_stop.clone()),
+                        )])),
+                    );
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("keep-overlap", None))
+                                .align_x(alignment::Horizontal::Center)
+                                .width(Length::Fill),
+                        )
+                        .on_press(Message::TimerStartOverlapKeepPressed)
+                        .style(button::secondary),
+                    );
+                    confirmation_button = Some(
+                        button(
+                            text(self.localization.get_message(
+                                "start-at-time",
+                                Some(&HashMap::from([("time", FluentValue::from(previous_stop))])),
+                            ))
+                            .align_x(alignment::Horizontal::Center)
+                            .width(Length::Fill),
+                        )
+                        .on_press(Message::TimerStartOverlapClampPressed)
+                        .style(style::primary_button_style),
+                    );
+                }
+                FurAlert::YearInReview => {
+                    let review = self.year_in_review.clone().unwrap_or_default();
+                    alert_text = self.localization.get_message(
+                        "year-in-review-title",
+                        Some(&HashMap::from([(
+                            "year",
+                            FluentValue::from(review.year.to_string()),
+                        )])),
+                    );
+                    if review.task_count == 0 {
+                        alert_description = self.localization.get_message(
+                            "year-in-review-empty",
+                            Some(&HashMap::from([(
+                                "year",
+                                FluentValue::from(review.year.to_string()),
+                            )])),
+                        );
+                    } else {
+                        alert_description = String::new();
+                        let mut stat_cards = column![].spacing(10);
+                        for pair in review_lines(&review, &self.localization).chunks(2) {
+                            let mut stat_row = row![].spacing(10);
+                            for (heading, value) in pair {
+                                stat_row = stat_row.push(
+                                    container(
+                                        column![
+                                            text(heading.clone()).size(12),
+                                            text(value.clone()).size(18).font(font::Font {
+                                                weight: iced::font::Weight::Bold,
+                                                ..Default::default()
+                                            }),
+                                        ]
+                                        .spacing(5),
+                                    )
+                                    .padding(10)
+                                    .width(Length::Fill)
+                                    .style(container::rounded_box),
+                                );
+                            }
+                            stat_cards = stat_cards.push(stat_row);
+                        }
+                        alert_content = Some(
+                            Scrollable::new(stat_cards)
+                                .height(Length::Fixed(360.0))
+                                .into(),
+                        );
+                        snooze_button = Some(
+                            button(
+                                text(self.localization.get_message("copy", None))
+                                    .align_x(alignment::Horizontal::Center)
+                                    .width(Length::Fill),
+                            )
+                            .on_press(Message::CopyYearInReview)
+                            .style(button::secondary),
+                        );
+                        confirmation_button = Some(
+                            button(
+                                text(self.localization.get_message("save", None))
+                                    .align_x(alignment::Horizontal::Center)
+                                    .width(Length::Fill),
+                            )
+                            .on_press(Message::SaveYearInReview)
+                            .style(style::primary_button_style),
+                        );
+                    }
+                    close_button = Some(
+                        button(
+                            text(self.localization.get_message("close", None))
                                 .align_x(alignment::Horizontal::Center)
                                 .width(Length::Fill),
                         )
                         .on_press(Message::AlertClose)
-                        .style(style::primary_button_style),
+                        .style(button::secondary),
                     );
                 }
             }
@@ -3090,14 +6873,32 @@ impl Furtherance {
                 buttons = buttons.push(confirmation);
             }
 
+            let mut alert_body: Column<'_, Message, Theme, Renderer> =
+                column![text(alert_description)].spacing(15);
+            if let Some(content) = alert_content {
+                alert_body = alert_body.push(content);
+            }
+
             Some(
-                Card::new(text(alert_text), text(alert_description))
+                Card::new(text(alert_text), alert_body)
                     .foot(buttons)
-                    .max_width(if self.displayed_alert == Some(FurAlert::PomodoroOver) {
-                        400.0
-                    } else {
-                        300.0
-                    })
+                    .max_width(
+                        if matches!(
+                            self.displayed_alert,
+                            Some(
+                                FurAlert::DroppedDatabase
+                                    | FurAlert::ErrorLog
+                                    | FurAlert::LongTaskConfirmation
+                                    | FurAlert::Onboarding
+                                    | FurAlert::PomodoroOver
+                                    | FurAlert::YearInReview
+                            )
+                        ) {
+                            400.0
+                        } else {
+                            300.0
+                        },
+                    )
                     .style(style::fur_card),
             )
         } else {
@@ -3124,9 +6925,124 @@ fn nav_button<'a>(nav_text: String, destination: FurView, active: bool) -> Butto
         })
 }
 
+/// The time of a sync, with the date if it wasn't today
+fn sync_time_text(timestamp: i64) -> String {
+    match Local.timestamp_opt(timestamp, 0) {
+        LocalResult::Single(synced_at) if synced_at.date_naive() == Local::now().date_naive() => {
+            format_time(synced_at.time(), false)
+        }
+        LocalResult::Single(synced_at) => format!(
+            "{} {}",
+            synced_at.format("%b %d"),
+            format_time(synced_at.time(), false)
+        ),
+        _ => String::new(),
+    }
+}
+
+fn sync_summary_text(
+    last_sync: Option<&FurSyncRecord>,
+    pending: usize,
+    localization: &Localization,
+) -> String {
+    match last_sync {
+        Some(last_sync) => localization.get_message(
+            "last-sync-summary",
+            Some(&HashMap::from([
+                (
+                    "time",
+                    FluentValue::from(sync_time_text(last_sync.synced_at)),
+                ),
+                ("count", FluentValue::from(last_sync.items())),
+                ("pending", FluentValue::from(pending)),
+            ])),
+        ),
+        None => localization.get_message(
+            "no-sync-summary",
+            Some(&HashMap::from([("pending", FluentValue::from(pending))])),
+        ),
+    }
+}
+
+fn sync_status_button<'a>(
+    last_successful_sync: i64,
+    sync_pending: bool,
+    sync_suspended: bool,
+    localization: &Localization,
+) -> Button<'a, Message> {
+    let status_text = if sync_suspended {
+        localization.get_message("sync-update-required", None)
+    } else if sync_pending {
+        localization.get_message("sync-pending", None)
+    } else {
+        match Local.timestamp_opt(last_successful_sync, 0) {
+            LocalResult::Single(last_sync) if last_successful_sync > 0 => localization.get_message(
+                "last-synced",
+                Some(&HashMap::from([(
+                    "time",
+                    FluentValue::from(format!(
+                        "{} {}",
+                        last_sync.format("%b %d"),
+                        format_time(last_sync.time(), false)
+                    )),
+                )])),
+            ),
+            _ => localization.get_message("never-synced", None),
+        }
+    };
+
+    button(
+        row![
+            if sync_pending || sync_suspended {
+                bootstrap::cloud_slash()
+            } else {
+                bootstrap::cloud_check()
+            },
+            text(status_text).size(12),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center),
+    )
+    .padding([5, 15])
+    .on_press(if sync_suspended {
+        Message::RetrySyncPressed
+    } else {
+        Message::SyncWithServer
+    })
+    .style(button::text)
+}
+
+fn error_log_badge<'a>(unseen: usize, localization: &Localization) -> Option<Button<'a, Message>> {
+    if unseen == 0 {
+        return None;
+    }
+
+    Some(
+        button(
+            row![
+                text("\u{26A0}").style(style::red_text),
+                text(localization.get_message(
+                    "new-errors",
+                    Some(&HashMap::from([("count", FluentValue::from(unseen))])),
+                ))
+                .size(12)
+                .style(style::red_text),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        )
+        .padding([5, 15])
+        .on_press(Message::ShowAlert(FurAlert::ErrorLog))
+        .style(button::text),
+    )
+}
+
 fn history_group_row<'a, 'loc>(
     task_group: &'a FurTaskGroup,
+    project_color: Option<Srgb>,
     timer_is_running: bool,
+    selection: Option<&HistorySelection>,
+    is_focused: bool,
     settings: &'a FurSettings,
     localization: &'loc Localization,
 ) -> ContextMenu<'a, Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'loc>, Message> {
@@ -3137,7 +7053,8 @@ fn history_group_row<'a, 'loc>(
         }),]
         .width(Length::FillPortion(6));
     if settings.show_task_project && !task_group.project.is_empty() {
-        task_details_column = task_details_column.push(text!("@{}", task_group.project));
+        task_details_column =
+            task_details_column.push(project_label(&task_group.project, project_color));
     }
     if settings.show_task_tags && !task_group.tags.is_empty() {
         task_details_column = task_details_column.push(text!("#{}", task_group.tags));
@@ -3145,6 +7062,14 @@ fn history_group_row<'a, 'loc>(
 
     let mut task_row: Row<'_, Message, Theme, Renderer> =
         row![].align_y(Alignment::Center).spacing(5);
+    let is_selected = selection.map(|selection| selection.is_group_selected(task_group));
+    if let Some(is_selected) = is_selected {
+        let task_ids = task_group.all_task_ids();
+        task_row = task_row
+            .push(checkbox(is_selected).on_toggle(move |checked| {
+                Message::HistoryGroupSelected(task_ids.clone(), checked)
+            }));
+    }
     if task_group.tasks.len() > 1 {
         task_row = task_row.push(
             Container::new(text(task_group.tasks.len()))
@@ -3154,8 +7079,8 @@ fn history_group_row<'a, 'loc>(
         );
     }
 
-    let total_time_str =
-        seconds_to_formatted_duration(task_group.total_time, settings.show_seconds);
+    let group_time = round_seconds(task_group.total_time, settings);
+    let total_time_str = seconds_to_formatted_duration(group_time, settings.show_seconds);
     let mut totals_column: Column<'_, Message, Theme, Renderer> =
         column![text(total_time_str).font(font::Font {
             weight: iced::font::Weight::Bold,
@@ -3163,9 +7088,33 @@ fn history_group_row<'a, 'loc>(
         })]
         .align_x(Alignment::End);
 
-    if settings.show_task_earnings && task_group.rate > 0.0 {
-        let total_earnings = task_group.rate * (task_group.total_time as f32 / 3600.0);
-        totals_column = totals_column.push(text!("${:.2}", total_earnings));
+    let planned_seconds = task_group.planned_seconds();
+    if settings.show_task_planned_time && planned_seconds > 0 {
+        totals_column = totals_column.push(
+            text(localization.get_message(
+                "tracked-of-planned",
+                Some(&HashMap::from([(
+                    "planned",
+                    FluentValue::from(seconds_to_formatted_duration(planned_seconds, false)),
+                )])),
+            ))
+            .size(12),
+        );
+    }
+
+    if settings.show_task_earnings
+        && task_group.rate > 0.0
+        && task_group.is_billable
+        && !group_is_excluded_from_earnings(task_group, &settings.earnings_exclusions)
+    {
+        let currency = task_group
+            .tasks
+            .first()
+            .map_or("", |task| task.currency.as_str());
+        totals_column = totals_column.push(text(format_currency(
+            group_earnings(task_group, group_time, &settings.earnings_exclusions),
+            currency,
+        )));
     }
 
     let task_group_string = task_group.to_string();
@@ -3178,102 +7127,687 @@ fn history_group_row<'a, 'loc>(
             .on_press_maybe(if timer_is_running {
                 None
             } else {
-                Some(Message::RepeatLastTaskPressed(task_group_string.clone()))
+                Some(Message::RepeatLastTaskPressed(task_group_string))
             })
             .style(button::text),
     );
 
-    let history_row_button = button(
-        Container::new(task_row)
-            .padding([10, 15])
-            .width(Length::Fill)
-            .style(style::task_row),
+    let history_row_button = button(
+        Container::new(task_row)
+            .padding([10, 15])
+            .width(Length::Fill)
+            .style(if is_focused {
+                style::task_row_focused
+            } else {
+                style::task_row
+            }),
+    )
+    .on_press(match is_selected {
+        Some(is_selected) => Message::HistoryGroupSelected(task_group.all_task_ids(), !is_selected),
+        None => Message::EditGroup(task_group.clone()),
+    })
+    .style(button::text);
+
+    let task_group_clone = task_group.clone();
+
+    ContextMenu::new(
+        history_row_button,
+        Box::new(move || -> Element<'a, Message, Theme, Renderer> {
+            Container::new(history_group_actions(&task_group_clone, localization))
+                .max_width(150)
+                .into()
+        }),
+    )
+}
+
+/// The actions offered by a history row's context menu, also shown
+/// below the row when the menu is opened from the keyboard
+fn history_group_actions<'a>(
+    task_group: &FurTaskGroup,
+    localization: &Localization,
+) -> Column<'a, Message, Theme, Renderer> {
+    column![
+        iced::widget::button(text(localization.get_message("repeat", None)))
+            .on_press(Message::RepeatLastTaskPressed(task_group.to_string()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("edit", None)))
+            .on_press(Message::EditGroup(task_group.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("duplicate", None)))
+            .on_press(Message::DuplicateTaskGroup(task_group.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("duplicate-to-today", None)))
+            .on_press(Message::DuplicateTaskGroupToToday(task_group.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("create-shortcut", None)))
+            .on_press(Message::CreateShortcutFromTaskGroup(task_group.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("delete", None)))
+            .on_press(Message::DeleteTasksFromContext(task_group.all_task_ids()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+    ]
+}
+
+fn search_result_row<'a>(
+    task: &FurTask,
+    terms: &[String],
+    settings: &FurSettings,
+) -> Button<'a, Message> {
+    let mut details_column: Column<'a, Message, Theme, Renderer> =
+        column![highlighted_text(&task.name, terms, true)].width(Length::FillPortion(6));
+    if !task.project.is_empty() {
+        details_column = details_column.push(highlighted_text(
+            &format!("@{}", task.project),
+            terms,
+            false,
+        ));
+    }
+    if !task.tags.is_empty() {
+        details_column =
+            details_column.push(highlighted_text(&format!("#{}", task.tags), terms, false));
+    }
+
+    let times_column = column![
+        text(seconds_to_formatted_duration(
+            task.total_time_in_seconds(),
+            settings.show_seconds
+        ))
+        .font(font::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        }),
+        text!(
+            "{} - {}",
+            format_time(task.start_time.time(), false),
+            format_time(task.stop_time.time(), false)
+        )
+        .size(12),
+    ]
+    .align_x(Alignment::End);
+
+    button(
+        Container::new(
+            row![
+                details_column,
+                space::horizontal().width(Length::Fill),
+                times_column
+            ]
+            .align_y(Alignment::Center)
+            .spacing(5),
+        )
+        .padding([10, 15])
+        .width(Length::Fill)
+        .style(style::task_row),
+    )
+    .on_press(Message::SearchResultPressed(task.clone()))
+    .style(button::text)
+}
+
+/// Text with the parts matching the search drawn in the accent color
+fn highlighted_text<'a>(content: &str, terms: &[String], bold: bool) -> Row<'a, Message> {
+    let weight = if bold {
+        iced::font::Weight::Bold
+    } else {
+        iced::font::Weight::Normal
+    };
+    let mut highlighted = row![];
+    for (segment, is_match) in search::highlight_segments(content, terms) {
+        let segment = text(segment).font(font::Font {
+            weight,
+            ..Default::default()
+        });
+        highlighted = highlighted.push(if is_match {
+            segment.style(style::search_highlight)
+        } else {
+            segment
+        });
+    }
+    highlighted
+}
+
+/// "@project", shown on the project's color when it has one
+fn project_label<'a>(project: &str, color: Option<Srgb>) -> Element<'a, Message, Theme, Renderer> {
+    let label = text!("@{}", project);
+    match color {
+        Some(color) => Container::new(label)
+            .padding([0, 6])
+            .style(move |theme| style::project_badge(theme, color))
+            .into(),
+        None => label.into(),
+    }
+}
+
+/// Day totals built from each group's rounded duration so they match the rows above.
+fn filtered_history_groups<'g>(
+    task_groups: &'g [FurTaskGroup],
+    filters: &HashSet<FilterChip>,
+) -> Vec<&'g FurTaskGroup> {
+    task_groups
+        .iter()
+        .filter(|task_group| tasks::group_matches_filters(task_group, filters))
+        .collect()
+}
+
+fn history_day_totals<'g>(
+    task_groups: impl IntoIterator<Item = &'g FurTaskGroup>,
+    settings: &FurSettings,
+) -> (i64, f32) {
+    task_groups.into_iter().fold(
+        (0i64, 0f32),
+        |(accumulated_time, accumulated_earnings), group| {
+            let group_time = round_seconds(group.total_time, settings);
+            (
+                accumulated_time + group_time,
+                accumulated_earnings
+                    + group_earnings(group, group_time, &settings.earnings_exclusions),
+            )
+        },
+    )
+}
+
+fn rounded_task_totals(tasks: &[FurTask], settings: &FurSettings) -> (i64, f32) {
+    tasks.iter().fold(
+        (0i64, 0f32),
+        |(accumulated_time, accumulated_earnings), task| {
+            let task_time = round_seconds(task.total_time_in_seconds(), settings);
+            (
+                accumulated_time + task_time,
+                accumulated_earnings
+                    + task_earnings(task, task_time, &settings.earnings_exclusions),
+            )
+        },
+    )
+}
+
+fn total_label(message_id: &str, settings: &FurSettings, localization: &Localization) -> String {
+    if settings.rounding_enabled {
+        format!(
+            "{} ({})",
+            localization.get_message(message_id, None),
+            localization.get_message("rounded", None)
+        )
+    } else {
+        localization.get_message(message_id, None)
+    }
+}
+
+fn bulk_action_bar<'a>(
+    selection: &HistorySelection,
+    localization: &Localization,
+) -> Container<'a, Message> {
+    let has_selection = !selection.selected_task_ids.is_empty();
+    let when_selected = |message: Message, input: &str| {
+        if has_selection && !input.trim().is_empty() {
+            Some(message)
+        } else {
+            None
+        }
+    };
+
+    let mut bar = column![
+        row![
+            text(localization.get_message(
+                "tasks-selected",
+                Some(&HashMap::from([(
+                    "count",
+                    FluentValue::from(selection.selected_task_ids.len())
+                )]))
+            )),
+            space::horizontal(),
+            button(text(localization.get_message("delete", None)))
+                .on_press_maybe(if has_selection {
+                    Some(Message::BulkDeletePressed)
+                } else {
+                    None
+                })
+                .style(button::danger),
+        ]
+        .align_y(Alignment::Center),
+        row![
+            text_input(
+                &localization.get_message("project", None),
+                &selection.new_project
+            )
+            .on_input(|s| Message::BulkEditTextChanged(s, EditTaskProperty::Project)),
+            button(text(localization.get_message("change-project", None)))
+                .on_press_maybe(when_selected(
+                    Message::BulkChangeProjectPressed,
+                    &selection.new_project
+                ))
+                .style(style::primary_button_style),
+        ]
+        .spacing(10),
+        row![
+            text_input(&localization.get_message("tag", None), &selection.tag)
+                .on_input(|s| Message::BulkEditTextChanged(s, EditTaskProperty::Tags)),
+            button(text(localization.get_message("add-tag", None)))
+                .on_press_maybe(when_selected(Message::BulkAddTagPressed, &selection.tag))
+                .style(style::primary_button_style),
+            button(text(localization.get_message("remove-tag", None)))
+                .on_press_maybe(when_selected(Message::BulkRemoveTagPressed, &selection.tag))
+                .style(button::secondary),
+        ]
+        .spacing(10),
+        row![
+            text_input("0.00", &selection.new_rate)
+                .on_input(|s| Message::BulkEditTextChanged(s, EditTaskProperty::Rate)),
+            button(text(localization.get_message("set-rate", None)))
+                .on_press_maybe(when_selected(
+                    Message::BulkSetRatePressed,
+                    &selection.new_rate
+                ))
+                .style(style::primary_button_style),
+        ]
+        .spacing(10),
+    ]
+    .spacing(10);
+
+    if !selection.invalid_input_error_message.is_empty() {
+        bar = bar.push(text(selection.invalid_input_error_message.clone()).style(style::red_text));
+    }
+
+    Container::new(bar)
+        .padding([10, 20])
+        .width(Length::Fill)
+        .style(style::gray_background)
+}
+
+fn history_filter_chip_bar<'a>(
+    chips: &[FilterChip],
+    active_filters: &HashSet<FilterChip>,
+    localization: &Localization,
+) -> Element<'a, Message> {
+    let mut chip_row: Row<'a, Message> = row![].spacing(5).padding(Padding {
+        top: 10.0,
+        right: 20.0,
+        bottom: 0.0,
+        left: 20.0,
+    });
+
+    // Active filters stay visible even if they drop out of the most used
+    let inactive_chips = chips.iter().filter(|chip| !active_filters.contains(chip));
+    for chip in active_filters.iter().sorted_by_key(|chip| chip.to_string()) {
+        chip_row = chip_row.push(
+            button(text(chip.to_string()).size(12))
+                .on_press(Message::ToggleHistoryFilter(chip.clone()))
+                .style(style::primary_button_style),
+        );
+    }
+    for chip in inactive_chips {
+        chip_row = chip_row.push(
+            button(text(chip.to_string()).size(12))
+                .on_press(Message::ToggleHistoryFilter(chip.clone()))
+                .style(button::secondary),
+        );
+    }
+
+    if !active_filters.is_empty() {
+        chip_row = chip_row.push(
+            button(text(localization.get_message("clear-filters", None)).size(12))
+                .on_press(Message::ClearHistoryFilters)
+                .style(button::text),
+        );
+    }
+
+    chip_row.wrap().into()
+}
+
+fn task_property_value_picker<'a>(
+    report: &FurReport,
+    localization: &Localization,
+) -> Element<'a, Message> {
+    let mut value_list = column![].spacing(2);
+    for value in report.filtered_task_property_values(REPORT_VALUES_SHOWN) {
+        value_list = value_list.push(
+            button(text(value.clone()).size(14))
+                .on_press(Message::ChartTaskPropertyValueSelected(value.clone()))
+                .width(Length::Fill)
+                .style(
+                    if report.picked_task_property_value.as_ref() == Some(value) {
+                        style::primary_button_style
+                    } else {
+                        button::text
+                    },
+                ),
+        );
+    }
+
+    container(
+        column![
+            text_input(
+                &localization.get_message("filter-values", None),
+                &report.task_property_value_filter
+            )
+            .on_input(Message::ChartTaskPropertyValueFilterChanged),
+            Scrollable::new(value_list),
+        ]
+        .spacing(5),
+    )
+    .max_height(300)
+    .padding(5)
+    .style(container::rounded_box)
+    .into()
+}
+
+fn chart_tag_chip_bar<'a>(tags: &[String], picked_tags: &BTreeSet<String>) -> Element<'a, Message> {
+    let mut chip_row: Row<'a, Message> = row![].spacing(5).padding(Padding {
+        top: 10.0,
+        right: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+    });
+
+    for tag in tags {
+        chip_row = chip_row.push(
+            button(text(tag.clone()).size(12))
+                .on_press(Message::ChartTagToggled(tag.clone()))
+                .style(if picked_tags.contains(tag) {
+                    style::primary_button_style
+                } else {
+                    button::secondary
+                }),
+        );
+    }
+
+    chip_row.wrap().into()
+}
+
+/// The parsed exclusions under the text that they were typed in, so typos stand out
+fn earnings_exclusion_chips<'a>(exclusions: &[String]) -> Element<'a, Message> {
+    let mut chip_row: Row<'a, Message> = row![].spacing(5);
+
+    for exclusion in exclusions {
+        chip_row = chip_row.push(
+            container(text(exclusion.clone()).size(12))
+                .padding([3, 8])
+                .style(container::rounded_box),
+        );
+    }
+
+    chip_row.wrap().into()
+}
+
+fn history_totals_column<'a>(
+    total_time: i64,
+    total_earnings: f32,
+    settings: &FurSettings,
+    running_timer: Option<(bool, i64, f32)>,
+    localization: &Localization,
+) -> Column<'a, Message, Theme, Renderer> {
+    let mut total_time_column = column![].align_x(Alignment::End);
+
+    if settings.show_daily_time_total {
+        let total_time = if settings.dynamic_total
+            && let Some((true, seconds_elapsed, _)) = running_timer
+        {
+            seconds_to_formatted_duration(total_time + seconds_elapsed, settings.show_seconds)
+        } else {
+            seconds_to_formatted_duration(total_time, settings.show_seconds)
+        };
+        total_time_column = total_time_column.push(text(total_time).font(font::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        }));
+    }
+
+    if settings.show_task_earnings {
+        let total_earnings = if settings.dynamic_total
+            && let Some((true, seconds_elapsed, rate)) = running_timer
+        {
+            total_earnings + ((seconds_elapsed as f32 / 3600.0) * rate)
+        } else {
+            total_earnings
+        };
+        if total_earnings > 0.0 {
+            total_time_column = total_time_column.push(text(format_currency(total_earnings, "")));
+        }
+    }
+
+    if settings.rounding_enabled && (settings.show_daily_time_total || settings.show_task_earnings)
+    {
+        total_time_column =
+            total_time_column.push(text(localization.get_message("rounded", None)).size(12));
+    }
+
+    total_time_column
+}
+
+/// The header of a week or month section of the history
+fn history_period_title_row<'a>(
+    title: String,
+    total_time: i64,
+    total_earnings: f32,
+    settings: &FurSettings,
+    running_timer: Option<(bool, i64, f32)>,
+    localization: &Localization,
+) -> Row<'a, Message, Theme, Renderer> {
+    row![
+        text(title).font(font::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        }),
+        space::horizontal().width(Length::Fill),
+        history_totals_column(
+            total_time,
+            total_earnings,
+            settings,
+            running_timer,
+            localization
+        ),
+    ]
+    .align_y(Alignment::Center)
+}
+
+/// Names the tags the typed project will add, so they aren't a surprise
+fn project_default_tags_hint<'a>(
+    task_input: &str,
+    project_default_tags: &HashMap<String, String>,
+    localization: &Localization,
+) -> Option<Element<'a, Message>> {
+    let (_, project, tags, _) = split_task_input(task_input);
+    let added_tags = added_default_tags(&tags, default_tags_for(project_default_tags, &project));
+    if added_tags.is_empty() {
+        return None;
+    }
+
+    Some(
+        text(localization.get_message(
+            "project-default-tags-hint",
+            Some(&HashMap::from([
+                ("tags", FluentValue::from(format!("#{}", added_tags))),
+                ("project", FluentValue::from(project)),
+            ])),
+        ))
+        .size(12)
+        .into(),
     )
-    .on_press(Message::EditGroup(task_group.clone()))
-    .style(button::text);
+}
 
-    let task_group_ids = task_group.all_task_ids();
-    let task_group_clone = task_group.clone();
+fn shortcut_rate_history<'a>(
+    changes: &[ShortcutRateChange],
+    localization: &Localization,
+) -> Element<'a, Message> {
+    if changes.is_empty() {
+        return column![].into();
+    }
+
+    let mut history_col =
+        column![
+            text(localization.get_message("rate-history", None)).font(font::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            })
+        ]
+        .spacing(5);
+    for change in changes {
+        let changed_at = DateTime::from_timestamp(change.changed_at, 0)
+            .map(|changed_at| format_date_and_time(changed_at.with_timezone(&Local)))
+            .unwrap_or_default();
+        history_col = history_col.push(
+            text(localization.get_message(
+                "rate-changed",
+                Some(&HashMap::from([
+                    (
+                        "old",
+                        FluentValue::from(format_currency(change.old_rate, "")),
+                    ),
+                    (
+                        "new",
+                        FluentValue::from(format_currency(change.new_rate, "")),
+                    ),
+                    ("date", FluentValue::from(changed_at)),
+                ])),
+            ))
+            .size(12),
+        );
+    }
+
+    container(history_col)
+        .padding(Padding {
+            top: 20.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .into()
+}
 
+/// Right-clicking a week's title copies or saves a summary of it
+fn weekly_summary_menu<'a, 'loc>(
+    title_row: Row<'a, Message, Theme, Renderer>,
+    week_start: NaiveDate,
+    localization: &'loc Localization,
+) -> ContextMenu<'a, Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'loc>, Message> {
     ContextMenu::new(
-        history_row_button,
+        title_row,
         Box::new(move || -> Element<'a, Message, Theme, Renderer> {
             Container::new(column![
-                iced::widget::button(text(localization.get_message("repeat", None)))
-                    .on_press(Message::RepeatLastTaskPressed(task_group_string.clone()))
-                    .style(style::context_menu_button_style)
-                    .width(Length::Fill),
-                iced::widget::button(text(localization.get_message("edit", None)))
-                    .on_press(Message::EditGroup(task_group_clone.clone()))
-                    .style(style::context_menu_button_style)
-                    .width(Length::Fill),
-                iced::widget::button(text(localization.get_message("create-shortcut", None)))
-                    .on_press(Message::CreateShortcutFromTaskGroup(
-                        task_group_clone.clone(),
-                    ))
+                iced::widget::button(text(localization.get_message("copy-weekly-summary", None)))
+                    .on_press(Message::CopyWeeklySummary(week_start))
                     .style(style::context_menu_button_style)
                     .width(Length::Fill),
-                iced::widget::button(text(localization.get_message("delete", None)))
-                    .on_press(Message::DeleteTasksFromContext(task_group_ids.clone()))
+                iced::widget::button(text(localization.get_message("save-weekly-summary", None)))
+                    .on_press(Message::SaveWeeklySummary(week_start))
                     .style(style::context_menu_button_style)
                     .width(Length::Fill),
             ])
-            .max_width(150)
+            .max_width(200)
             .into()
         }),
     )
 }
 
-fn history_title_row<'a>(
+fn history_title_row<'a, 'loc>(
     date: &NaiveDate,
     total_time: i64,
     total_earnings: f32,
+    note: Option<&FurDayNote>,
+    days_off: &FurDaysOff,
     settings: &FurSettings,
-    running_timer: Option<(bool, &str, f32)>,
-    localization: &Localization,
-) -> Row<'a, Message> {
-    let mut total_time_column = column![].align_x(Alignment::End);
+    running_timer: Option<(bool, i64, f32)>,
+    localization: &'loc Localization,
+) -> ContextMenu<'a, Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'loc>, Message> {
+    let total_time_column = history_totals_column(
+        total_time,
+        total_earnings,
+        settings,
+        running_timer,
+        localization,
+    );
 
-    if settings.show_daily_time_total {
-        let total_time = if settings.dynamic_total
-            && let Some((true, timer_text, _)) = running_timer
-        {
-            seconds_to_formatted_duration(
-                combine_timer_with_seconds(timer_text, total_time),
-                settings.show_seconds,
+    let tracked_time = if settings.dynamic_total
+        && let Some((true, seconds_elapsed, _)) = running_timer
+    {
+        total_time + seconds_elapsed
+    } else {
+        total_time
+    };
+    let target_indicator = DailyTarget::from_settings(settings)
+        .progress(date, tracked_time, days_off)
+        .map(|progress| {
+            let (icon, label) = match progress {
+                TargetProgress::Met => (bootstrap::check_circle_fill(), "daily-target-met"),
+                TargetProgress::Partial => (bootstrap::circle_half(), "daily-target-partial"),
+                TargetProgress::Empty => (bootstrap::circle(), "daily-target-empty"),
+            };
+            tooltip(
+                container(
+                    icon.size(12)
+                        .style(move |theme| style::daily_target_indicator(theme, progress)),
+                )
+                .padding(Padding {
+                    top: 0.0,
+                    right: 5.0,
+                    bottom: 0.0,
+                    left: 0.0,
+                }),
+                container(text(localization.get_message(label, None)))
+                    .padding(10)
+                    .style(container::rounded_box),
+                tooltip::Position::Bottom,
             )
-        } else {
-            seconds_to_formatted_duration(total_time, settings.show_seconds)
-        };
-        total_time_column = total_time_column.push(text(total_time).font(font::Font {
-            weight: iced::font::Weight::Bold,
-            ..Default::default()
-        }));
-    }
+        });
 
-    if settings.show_task_earnings {
-        let total_earnings = if settings.dynamic_total
-            && let Some((true, timer_text, rate)) = running_timer
-        {
-            let timer_seconds = parse_timer_text_to_seconds(timer_text);
-            total_earnings + ((timer_seconds as f32 / 3600.0) * rate)
-        } else {
-            total_earnings
-        };
-        if total_earnings > 0.0 {
-            total_time_column = total_time_column.push(text!("${:.2}", total_earnings));
-        }
+    let mut date_column = column![
+        row![
+            target_indicator,
+            text(format_history_date(date, localization)).font(font::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }),
+            button(bootstrap::pencil().size(12))
+                .on_press(Message::EditDayNote(*date))
+                .style(button::text),
+            button(bootstrap::plus_lg().size(12))
+                .on_press(Message::AddNewTaskForDate(*date))
+                .style(button::text),
+        ]
+        .align_y(Alignment::Center)
+    ];
+    if let Some(note) = note
+        && !note.first_line().is_empty()
+    {
+        date_column = date_column.push(text(note.first_line().to_string()).size(12));
+    }
+    // History only lists days with tasks, so a day off here always has time on it
+    if days_off.is_day_off(date) {
+        date_column =
+            date_column.push(text(localization.get_message("day-off-has-tasks", None)).size(12));
     }
 
-    row![
-        text(format_history_date(date, localization)).font(font::Font {
-            weight: iced::font::Weight::Bold,
-            ..Default::default()
-        }),
+    let title_row = row![
+        date_column,
         space::horizontal().width(Length::Fill),
         total_time_column,
     ]
-    .align_y(Alignment::Center)
+    .align_y(Alignment::Center);
+
+    let date = *date;
+    let is_marked = days_off.is_marked(&date);
+    ContextMenu::new(
+        title_row,
+        Box::new(move || -> Element<'a, Message, Theme, Renderer> {
+            let label = if is_marked {
+                localization.get_message("unmark-day-off", None)
+            } else {
+                localization.get_message("mark-day-off", None)
+            };
+            Container::new(
+                iced::widget::button(text(label))
+                    .on_press(Message::ToggleDayOff(date))
+                    .style(style::context_menu_button_style)
+                    .width(Length::Fill),
+            )
+            .max_width(150)
+            .into()
+        }),
+    )
 }
 
 fn format_history_date(date: &NaiveDate, localization: &Localization) -> String {
@@ -3292,6 +7826,29 @@ fn format_history_date(date: &NaiveDate, localization: &Localization) -> String
     }
 }
 
+fn format_history_period(
+    period: &tasks::PeriodKey,
+    grouping: FurHistoryGrouping,
+    localization: &Localization,
+) -> String {
+    let current_year = Local::now().date_naive().year();
+    match grouping {
+        FurHistoryGrouping::Day => format_history_date(&period.start, localization),
+        FurHistoryGrouping::Week => localization.get_message(
+            "week-of",
+            Some(&HashMap::from([(
+                "date",
+                FluentValue::from(if period.start.year() == current_year {
+                    period.start.format("%b %-d").to_string()
+                } else {
+                    period.start.format("%b %-d, %Y").to_string()
+                }),
+            )])),
+        ),
+        FurHistoryGrouping::Month => period.start.format("%B %Y").to_string(),
+    }
+}
+
 fn shortcut_button_content<'a>(
     shortcut: &'a FurShortcut,
     text_color: Color,
@@ -3324,7 +7881,7 @@ fn shortcut_button_content<'a>(
         shortcut_text_column = shortcut_text_column.push(space::vertical());
         shortcut_text_column = shortcut_text_column.push(row![
             space::horizontal(),
-            text!("${:.2}", shortcut.rate).style(move |_| text::Style {
+            text(format_currency(shortcut.rate, &shortcut.currency)).style(move |_| text::Style {
                 color: Some(text_color)
             })
         ]);
@@ -3335,7 +7892,9 @@ fn shortcut_button_content<'a>(
 
 fn shortcut_button<'a, 'loc>(
     shortcut: &'a FurShortcut,
-    timer_is_running: bool,
+    is_disabled: bool,
+    use_count: Option<usize>,
+    can_reorder: bool,
     localization: &'loc Localization,
 ) -> ContextMenu<'a, Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'loc>, Message> {
     let shortcut_color = match Srgb::from_hex(&shortcut.color_hex) {
@@ -3348,14 +7907,31 @@ fn shortcut_button<'a, 'loc>(
         Color::BLACK
     };
 
-    let shortcut_button = button(shortcut_button_content(&shortcut, text_color))
-        .width(200)
+    let mut content = shortcut_button_content(&shortcut, text_color);
+    if let Some(count) = use_count {
+        content = content.push(space::vertical()).push(
+            text(localization.get_message(
+                "shortcut-used-times",
+                Some(&HashMap::from([("count", FluentValue::from(count))])),
+            ))
+            .size(11)
+            .style(move |_| text::Style {
+                color: Some(Color {
+                    a: 0.7,
+                    ..text_color
+                }),
+            }),
+        );
+    }
+
+    let shortcut_button = button(content)
+        .width(SHORTCUT_BUTTON_WIDTH)
         .padding(10)
         .height(170)
-        .on_press_maybe(if timer_is_running {
+        .on_press_maybe(if is_disabled {
             None
         } else {
-            Some(Message::ShortcutPressed(shortcut.to_string()))
+            Some(Message::ShortcutPressed(shortcut.clone()))
         })
         .style(move |theme, status| style::shortcut_button_style(theme, status, shortcut_color));
 
@@ -3364,26 +7940,151 @@ fn shortcut_button<'a, 'loc>(
     ContextMenu::new(
         shortcut_button,
         Box::new(move || -> Element<'a, Message, Theme, Renderer> {
-            Container::new(column![
-                iced::widget::button(text(localization.get_message("edit", None)))
-                    .on_press(Message::EditShortcutPressed(shortcut_clone.clone()))
-                    .style(style::context_menu_button_style)
-                    .width(Length::Fill),
-                iced::widget::button(text(localization.get_message("delete", None)))
-                    .on_press(Message::DeleteShortcutFromContext(
-                        shortcut_clone.uid.clone()
-                    ))
-                    .style(style::context_menu_button_style)
-                    .width(Length::Fill),
-            ])
-            .max_width(150)
-            .into()
+            Container::new(shortcut_actions(&shortcut_clone, can_reorder, localization))
+                .max_width(150)
+                .into()
         }),
     )
 }
 
-fn is_dark_color(color: Srgb) -> bool {
-    color.relative_luminance().luma < 0.6
+fn shortcut_actions<'a>(
+    shortcut: &FurShortcut,
+    can_reorder: bool,
+    localization: &Localization,
+) -> Column<'a, Message, Theme, Renderer> {
+    let mut actions = column![
+        iced::widget::button(text(localization.get_message("edit", None)))
+            .on_press(Message::EditShortcutPressed(shortcut.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+        iced::widget::button(text(localization.get_message("delete", None)))
+            .on_press(Message::DeleteShortcutFromContext(shortcut.uid.clone()))
+            .style(style::context_menu_button_style)
+            .width(Length::Fill),
+    ];
+    if can_reorder {
+        for (direction, label) in [
+            (
+                ShortcutMove::Front,
+                localization.get_message("move-to-front", None),
+            ),
+            (
+                ShortcutMove::Left,
+                localization.get_message("move-left", None),
+            ),
+            (
+                ShortcutMove::Right,
+                localization.get_message("move-right", None),
+            ),
+        ] {
+            actions = actions.push(
+                iced::widget::button(text(label))
+                    .on_press(Message::MoveShortcut(shortcut.uid.clone(), direction))
+                    .style(style::context_menu_button_style)
+                    .width(Length::Fill),
+            );
+        }
+    }
+    actions
+}
+
+/// The quick pick under the task input, with the entry keyboard focus is on highlighted
+fn recent_tasks_menu<'a>(
+    recent_tasks: &'a RecentTasks,
+    localization: &Localization,
+) -> Element<'a, Message> {
+    let mut menu = column![].spacing(2);
+    let mut index = 0;
+
+    for (heading_key, section) in [
+        ("recent-tasks", &recent_tasks.recent),
+        ("on-this-day-last-week", &recent_tasks.last_week),
+    ] {
+        if section.is_empty() {
+            continue;
+        }
+        menu = menu.push(
+            container(text(localization.get_message(heading_key, None)).size(12)).padding([5, 10]),
+        );
+        for task_input in section {
+            menu = menu.push(
+                button(text(task_input))
+                    .on_press(Message::RecentTaskPicked(task_input.clone()))
+                    .width(Length::Fill)
+                    .style(if recent_tasks.focus == Some(index) {
+                        style::active_nav_menu_button_style
+                    } else {
+                        style::context_menu_button_style
+                    }),
+            );
+            index += 1;
+        }
+    }
+
+    if recent_tasks.is_empty() {
+        menu = menu.push(
+            container(text(localization.get_message("no-recent-tasks", None))).padding([5, 10]),
+        );
+    }
+
+    container(menu)
+        .padding(5)
+        .width(Length::Fill)
+        .style(container::rounded_box)
+        .into()
+}
+
+fn secondary_timers_column<'a>(
+    timers: &'a [FurRunningTimer],
+    task_input: &'a str,
+    timer_is_running: bool,
+    show_seconds: bool,
+    localization: &Localization,
+) -> Column<'a, Message> {
+    let now = Local::now();
+    let mut secondary_timers = column![].spacing(10);
+
+    for (index, timer) in timers.iter().enumerate() {
+        secondary_timers = secondary_timers.push(
+            row![
+                text(&timer.task_input).width(Length::Fill),
+                text(seconds_to_formatted_duration(
+                    timer.seconds_elapsed(now),
+                    show_seconds
+                )),
+                button(bootstrap::stop_fill())
+                    .on_press(Message::StopSecondaryTimer(index))
+                    .style(style::primary_button_style),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(10),
+        );
+    }
+
+    // Extra timers run alongside the main one rather than instead of it
+    if timer_is_running && timers.len() < MAX_SECONDARY_TIMERS {
+        secondary_timers = secondary_timers.push(
+            row![
+                text_input(
+                    &localization.get_message("secondary-timer-placeholder", None),
+                    task_input
+                )
+                .on_input(Message::SecondaryTimerInputChanged)
+                .on_submit(Message::StartSecondaryTimer),
+                button(bootstrap::play_fill())
+                    .on_press_maybe(if task_input.trim().is_empty() {
+                        None
+                    } else {
+                        Some(Message::StartSecondaryTimer)
+                    })
+                    .style(style::primary_button_style),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(10),
+        );
+    }
+
+    secondary_timers
 }
 
 fn convert_timer_text_to_vertical_hms(timer_text: &str, localization: &Localization) -> String {
@@ -3428,9 +8129,15 @@ fn convert_timer_text_to_vertical_hms(timer_text: &str, localization: &Localizat
                 Some(&HashMap::from([("seconds", FluentValue::from("0"))])),
             ));
         }
+    } else if sidebar_timer_text.is_empty() {
+        // Timer text without seconds has nothing to show in the first minute
+        sidebar_timer_text.push_str(&localization.get_message(
+            "x-m",
+            Some(&HashMap::from([("minutes", FluentValue::from("0"))])),
+        ));
     }
 
-    sidebar_timer_text
+    sidebar_timer_text.trim_end().to_string()
 }
 
 fn settings_heading<'a>(heading: String) -> Column<'a, Message, Theme, Renderer> {
@@ -3449,88 +8156,42 @@ fn settings_heading<'a>(heading: String) -> Column<'a, Message, Theme, Renderer>
     })
 }
 
-fn combine_timer_with_seconds(timer: &str, seconds: i64) -> i64 {
-    let timer_seconds = parse_timer_text_to_seconds(timer);
-    timer_seconds + seconds
-}
-
-fn parse_timer_text_to_seconds(timer_text: &str) -> i64 {
-    let parts: Vec<&str> = timer_text.split(':').collect();
-    match parts.len() {
-        3 => {
-            let hours: i64 = parts[0].parse().unwrap_or(0);
-            let minutes: i64 = parts[1].parse().unwrap_or(0);
-            let seconds: i64 = parts[2].parse().unwrap_or(0);
-            hours * 3600 + minutes * 60 + seconds
-        }
-        2 => {
-            let minutes: i64 = parts[0].parse().unwrap_or(0);
-            let seconds: i64 = parts[1].parse().unwrap_or(0);
-            minutes * 60 + seconds
-        }
-        1 => parts[0].parse().unwrap_or(0),
-        _ => 0,
-    }
-}
-
 pub fn write_furtasks_to_csv(
     path: PathBuf,
     export_settings: &ExportSettings,
+    settings: &FurSettings,
     localization: &Localization,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match std::fs::File::create(path) {
         Ok(file) => {
             match db_retrieve_all_existing_tasks(SortBy::StopTime, export_settings.sort_order) {
                 Ok(tasks) => {
-                    let mut csv_writer = Writer::from_writer(file);
-                    let mut columns: Vec<String> = Vec::new();
-                    if export_settings.name {
-                        columns.push("Name".to_string());
-                    }
-                    if export_settings.start_time {
-                        columns.push("Start Time".to_string());
-                    }
-                    if export_settings.stop_time {
-                        columns.push("Stop Time".to_string());
-                    }
-                    if export_settings.tags {
-                        columns.push("Tags".to_string());
-                    }
-                    if export_settings.project {
-                        columns.push("Project".to_string());
-                    }
-                    if export_settings.rate {
-                        columns.push("Rate".to_string());
-                    }
-                    if export_settings.currency {
-                        columns.push("Currency".to_string());
-                    }
-                    if export_settings.total_time {
-                        columns.push("Total Time".to_string());
-                    }
-                    if export_settings.total_earnings {
-                        columns.push("Total Earnings".to_string());
-                    }
-
-                    csv_writer.write_record(&columns)?;
+                    // Flexible so the two-column day notes section can follow the tasks
+                    let mut csv_writer = WriterBuilder::new().flexible(true).from_writer(file);
+                    csv_writer.write_record(csv_columns(export_settings, settings))?;
 
                     let mut filtered_tasks = tasks.clone();
 
-                    if export_settings.filter_by_date {
-                        if let Some(start_date) = NaiveDate::from_ymd_opt(
+                    let date_filter = if export_settings.filter_by_date {
+                        NaiveDate::from_ymd_opt(
                             export_settings.picked_start_date.year,
                             export_settings.picked_start_date.month,
                             export_settings.picked_start_date.day,
-                        ) && let Some(end_date) = NaiveDate::from_ymd_opt(
+                        )
+                        .zip(NaiveDate::from_ymd_opt(
                             export_settings.picked_end_date.year,
                             export_settings.picked_end_date.month,
                             export_settings.picked_end_date.day,
-                        ) {
-                            filtered_tasks.retain(|t| {
-                                t.stop_time.date_naive() >= start_date
-                                    && t.stop_time.date_naive() <= end_date
-                            });
-                        }
+                        ))
+                    } else {
+                        None
+                    };
+
+                    if let Some((start_date, end_date)) = date_filter {
+                        filtered_tasks.retain(|t| {
+                            t.stop_time.date_naive() >= start_date
+                                && t.stop_time.date_naive() <= end_date
+                        });
                     }
 
                     if export_settings.filter_by_project {
@@ -3540,38 +8201,27 @@ pub fn write_furtasks_to_csv(
                     }
 
                     for task in filtered_tasks {
-                        let mut records: Vec<String> = Vec::new();
-                        if export_settings.name {
-                            records.push(task.name.clone());
-                        }
-                        if export_settings.start_time {
-                            records.push(task.start_time.to_rfc3339());
-                        }
-                        if export_settings.stop_time {
-                            records.push(task.stop_time.to_rfc3339());
-                        }
-                        if export_settings.tags {
-                            records.push(task.tags.clone());
-                        }
-                        if export_settings.project {
-                            records.push(task.project.clone());
-                        }
-                        if export_settings.rate {
-                            records.push(task.rate.to_string());
-                        }
-                        if export_settings.currency {
-                            records.push(task.currency.clone());
-                        }
-                        if export_settings.total_time {
-                            records.push(seconds_to_formatted_duration(
-                                task.total_time_in_seconds(),
-                                true,
-                            ));
+                        csv_writer.write_record(csv_task_record(
+                            &task,
+                            export_settings,
+                            settings,
+                        ))?;
+                    }
+
+                    if export_settings.day_notes {
+                        let mut notes = db_retrieve_all_day_notes().unwrap_or_default();
+                        if let Some((start_date, end_date)) = date_filter {
+                            notes.retain(|note| note.date >= start_date && note.date <= end_date);
                         }
-                        if export_settings.total_earnings {
-                            records.push(format!("${:.2}", task.total_earnings()));
+                        if !notes.is_empty() {
+                            csv_writer.write_record(DAY_NOTES_CSV_HEADER)?;
+                            for note in notes {
+                                csv_writer.write_record([
+                                    note.date.format("%Y-%m-%d").to_string(),
+                                    note.text,
+                                ])?;
+                            }
                         }
-                        csv_writer.write_record(&records)?;
                     }
 
                     csv_writer.flush()?;
@@ -3586,6 +8236,129 @@ pub fn write_furtasks_to_csv(
     }
 }
 
+/// Writes the report's tasks with the same columns as the full CSV export,
+/// after a comment line describing the range and selection they came from
+pub fn write_filtered_tasks_to_csv(
+    path: PathBuf,
+    tasks: &[&FurTask],
+    description: &str,
+    export_settings: &ExportSettings,
+    settings: &FurSettings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = std::fs::File::create(path)?;
+    writeln!(file, "# {}", description.replace('\n', " "))?;
+
+    let mut tasks = tasks.to_vec();
+    tasks.sort_by_key(|task| task.stop_time);
+    if export_settings.sort_order == SortOrder::Descending {
+        tasks.reverse();
+    }
+
+    let mut csv_writer = WriterBuilder::new().from_writer(file);
+    csv_writer.write_record(csv_columns(export_settings, settings))?;
+    for task in tasks {
+        csv_writer.write_record(csv_task_record(task, export_settings, settings))?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+fn csv_columns(export_settings: &ExportSettings, settings: &FurSettings) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    if export_settings.name {
+        columns.push("Name".to_string());
+    }
+    if export_settings.start_time {
+        columns.push("Start Time".to_string());
+    }
+    if export_settings.stop_time {
+        columns.push("Stop Time".to_string());
+    }
+    if export_settings.tags {
+        columns.push("Tags".to_string());
+    }
+    if export_settings.project {
+        columns.push("Project".to_string());
+    }
+    if export_settings.rate {
+        columns.push("Rate".to_string());
+    }
+    if export_settings.currency {
+        columns.push("Currency".to_string());
+    }
+    if export_settings.billable {
+        columns.push("Billable".to_string());
+    }
+    if export_settings.total_time {
+        columns.push("Total Time".to_string());
+    }
+    if export_settings.total_earnings {
+        columns.push("Total Earnings".to_string());
+    }
+    if settings.show_net_earnings {
+        columns.push("Net Earnings".to_string());
+    }
+    if export_settings.device {
+        columns.push("Device".to_string());
+    }
+    columns
+}
+
+fn csv_task_record(
+    task: &FurTask,
+    export_settings: &ExportSettings,
+    settings: &FurSettings,
+) -> Vec<String> {
+    let task_time = round_seconds(task.total_time_in_seconds(), settings);
+    let mut records: Vec<String> = Vec::new();
+    if export_settings.name {
+        records.push(task.name.clone());
+    }
+    if export_settings.start_time {
+        records.push(task.start_time.to_rfc3339());
+    }
+    if export_settings.stop_time {
+        records.push(task.stop_time.to_rfc3339());
+    }
+    if export_settings.tags {
+        records.push(task.tags.clone());
+    }
+    if export_settings.project {
+        records.push(task.project.clone());
+    }
+    if export_settings.rate {
+        records.push(task.rate.to_string());
+    }
+    if export_settings.currency {
+        records.push(task.currency.clone());
+    }
+    if export_settings.billable {
+        records.push(task.is_billable.to_string());
+    }
+    if export_settings.total_time {
+        records.push(seconds_to_formatted_duration(task_time, true));
+    }
+    if export_settings.total_earnings {
+        records.push(format_currency(
+            task_earnings(task, task_time, &settings.earnings_exclusions),
+            &task.currency,
+        ));
+    }
+    if let Some(deduction_percent) = net_earnings_deduction(settings) {
+        records.push(format_currency(
+            net_earnings(
+                task_earnings(task, task_time, &settings.earnings_exclusions),
+                deduction_percent,
+            ),
+            &task.currency,
+        ));
+    }
+    if export_settings.device {
+        records.push(task.device_name.clone());
+    }
+    records
+}
+
 fn modal<'a, Message>(
     base: impl Into<Element<'a, Message>>,
     alert: Container<'a, Message>,
@@ -3618,7 +8391,50 @@ where
     .into()
 }
 
+/// A change from the compared period, e.g. "+4.5 h, +12%"
+fn comparison_delta_text(change: String, percent: Option<f64>) -> String {
+    match percent {
+        Some(percent) => format!("{change}, {percent:+.0}%"),
+        None => change,
+    }
+}
+
+/// Wraps times past midnight, e.g. a workday averaging 25 hours ends at 01:00
+fn format_seconds_after_midnight(seconds: i64) -> String {
+    format_time(
+        NaiveTime::from_num_seconds_from_midnight_opt(seconds.rem_euclid(86_400) as u32, 0)
+            .unwrap_or_default(),
+        false,
+    )
+}
+
+fn format_date_and_time(date_time: DateTime<Local>) -> String {
+    format!(
+        "{} {}",
+        date_time.format("%Y-%m-%d"),
+        format_time(date_time.time(), false)
+    )
+}
+
 fn format_iced_time_as_hm(time: iced_aw::time_picker::Time) -> String {
-    let naive_time = NaiveTime::from(time);
-    naive_time.format("%H:%M").to_string()
+    format_time(NaiveTime::from(time), false)
+}
+
+fn format_iced_time_as_hms(time: iced_aw::time_picker::Time) -> String {
+    format_time(NaiveTime::from(time), true)
+}
+
+/// Lets time pickers follow the 12-hour or 24-hour clock from the settings
+trait ClockFormat {
+    fn clock_format(self, use_24_hour_time: bool) -> Self;
+}
+
+impl ClockFormat for TimePicker<'_, Message, Theme> {
+    fn clock_format(self, use_24_hour_time: bool) -> Self {
+        if use_24_hour_time {
+            self.use_24h()
+        } else {
+            self
+        }
+    }
 }
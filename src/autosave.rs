@@ -15,59 +15,184 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use std::{
-    fs::{File, remove_file},
-    io::{BufRead, BufReader, BufWriter, Result, Write},
-    path::PathBuf,
+    fs::{File, read, remove_file, rename},
+    io::{BufWriter, Result, Write},
+    path::{Path, PathBuf},
 };
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveTime};
 
 use crate::{
+    constants::MAX_SECONDARY_TIMERS,
     database::db_insert_task,
-    models::{fur_settings::get_data_path, fur_task::FurTask},
-    update::msg_helper_functions::split_task_input,
+    helpers::auto_stop::auto_stop_due,
+    models::{fur_running_timer::FurRunningTimer, fur_settings::get_data_path, fur_task::FurTask},
+    update::msg_helper_functions::{
+        split_task_input, task_input_is_billable, task_input_planned_seconds,
+    },
 };
 
-pub fn autosave_exists() -> bool {
-    let path = get_autosave_path();
-    path.exists()
+const AUTOSAVE_HEADER: &str = "furtherance-autosave v2";
+
+#[derive(Debug, PartialEq)]
+pub enum AutosaveRestore {
+    Restored,
+    NotPresent,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AutosaveError {
+    /// The autosave could not be read as a task and was moved to autosave.bad
+    Corrupt(String),
 }
 
-pub fn restore_autosave() {
-    let path = get_autosave_path();
-    if let Some(task) = task_from_autosave(&path) {
-        if let Err(e) = db_insert_task(&task) {
-            eprintln!("Error writing autosave to database: {e}");
+impl std::fmt::Display for AutosaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutosaveError::Corrupt(reason) => write!(f, "Corrupt autosave: {reason}"),
         }
+    }
+}
+
+impl std::error::Error for AutosaveError {}
 
-        delete_autosave();
+/// Restores the main timer's autosave and any secondary timers'. A corrupt file
+/// doesn't stop the others from being restored. With `auto_stop`, a task that ran
+/// past the auto-stop time ends there instead of when it was last saved.
+pub fn restore_autosave(
+    auto_stop: Option<NaiveTime>,
+) -> std::result::Result<AutosaveRestore, AutosaveError> {
+    let mut result = Ok(AutosaveRestore::NotPresent);
+    for path in std::iter::once(get_autosave_path())
+        .chain((0..MAX_SECONDARY_TIMERS).map(get_secondary_autosave_path))
+    {
+        match restore_autosave_file(&path, auto_stop) {
+            Ok(AutosaveRestore::Restored) if result.is_ok() => {
+                result = Ok(AutosaveRestore::Restored)
+            }
+            Err(e) => result = Err(e),
+            _ => {}
+        }
     }
+    result
 }
 
-pub fn write_autosave(task_input: &str, start_time: DateTime<Local>) -> Result<()> {
-    let start_time = start_time.to_rfc3339();
-    let stop_time = Local::now().to_rfc3339();
+fn restore_autosave_file(
+    path: &Path,
+    auto_stop: Option<NaiveTime>,
+) -> std::result::Result<AutosaveRestore, AutosaveError> {
+    if !path.exists() {
+        return Ok(AutosaveRestore::NotPresent);
+    }
 
-    let path = get_autosave_path();
-    let file = File::create(path)?;
-    let mut writer = BufWriter::new(file);
+    let parsed = match read(&path) {
+        Ok(bytes) => parse_autosave(&bytes),
+        Err(e) => Err(AutosaveError::Corrupt(e.to_string())),
+    };
+
+    match parsed {
+        Ok(mut task) => {
+            if let Some(stop_at) = auto_stop
+                && let Some(boundary) = auto_stop_due(task.start_time, task.stop_time, stop_at)
+            {
+                task.stop_time = boundary;
+            }
+            if let Err(e) = db_insert_task(&task) {
+                eprintln!("Error writing autosave to database: {e}");
+            }
+            delete_autosave_file(path);
+            Ok(AutosaveRestore::Restored)
+        }
+        Err(e) => {
+            // Keep the bad file for inspection but never try to restore it again
+            if let Err(e) = rename(path, path.with_extension("bad")) {
+                eprintln!("Error moving corrupt autosave aside: {e}");
+                delete_autosave_file(path);
+            }
+            Err(e)
+        }
+    }
+}
+
+pub fn write_autosave(
+    task_input: &str,
+    notes: &str,
+    start_time: DateTime<Local>,
+    stop_time: DateTime<Local>,
+) -> Result<()> {
+    write_autosave_file(
+        &get_autosave_path(),
+        task_input,
+        notes,
+        start_time,
+        stop_time,
+    )
+}
+
+/// Rewrites the secondary timers' autosaves, so stopped timers don't leave one behind
+pub fn write_secondary_autosaves(
+    timers: &[FurRunningTimer],
+    stop_time: DateTime<Local>,
+) -> Result<()> {
+    delete_secondary_autosaves();
+    for (index, timer) in timers.iter().enumerate() {
+        write_autosave_file(
+            &get_secondary_autosave_path(index),
+            &timer.task_input,
+            "",
+            timer.start_time,
+            stop_time,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_autosave_file(
+    path: &Path,
+    task_input: &str,
+    notes: &str,
+    start_time: DateTime<Local>,
+    stop_time: DateTime<Local>,
+) -> Result<()> {
+    let start_time = start_time.to_rfc3339();
+    let stop_time = stop_time.to_rfc3339();
 
     let (name, project, tags, rate) = split_task_input(task_input);
     let currency = String::new();
+    let is_billable = task_input_is_billable(task_input, rate);
+    let planned_seconds = task_input_planned_seconds(task_input);
+    let notes = escape_autosave_line(notes);
 
-    writeln!(writer, "{name}")?;
-    writeln!(writer, "{start_time}")?;
-    writeln!(writer, "{stop_time}")?;
-    writeln!(writer, "{tags}")?;
-    writeln!(writer, "{project}")?;
-    writeln!(writer, "{rate}")?;
-    writeln!(writer, "{currency}")?;
+    let contents = format!(
+        "{name}\n{start_time}\n{stop_time}\n{tags}\n{project}\n{rate}\n{currency}\n{is_billable}\n{planned_seconds}\n{notes}\n"
+    );
+    let checksum = blake3::hash(contents.as_bytes()).to_hex();
+
+    // Write beside the real file and rename so a crash never leaves half an autosave
+    let temp_path = path.with_extension("tmp");
+    let file = File::create(&temp_path)?;
+    let mut writer = BufWriter::new(file);
+    writeln!(writer, "{AUTOSAVE_HEADER}")?;
+    writeln!(writer, "{checksum}")?;
+    write!(writer, "{contents}")?;
+    writer.flush()?;
+    writer.get_ref().sync_all()?;
+    rename(temp_path, path)?;
 
     Ok(())
 }
 
 pub fn delete_autosave() {
-    let path = get_autosave_path();
+    delete_autosave_file(&get_autosave_path());
+}
+
+pub fn delete_secondary_autosaves() {
+    for index in 0..MAX_SECONDARY_TIMERS {
+        delete_autosave_file(&get_secondary_autosave_path(index));
+    }
+}
+
+fn delete_autosave_file(path: &Path) {
     if path.exists() {
         if let Err(e) = remove_file(path) {
             eprintln!("Error deleting autosave: {e}");
@@ -81,42 +206,97 @@ fn get_autosave_path() -> PathBuf {
     path
 }
 
-fn read_autosave(path: &PathBuf) -> Result<Vec<String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut vars = Vec::new();
+fn get_secondary_autosave_path(index: usize) -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&[format!("autosave-{}.txt", index + 2)]);
+    path
+}
 
-    for line in reader.lines() {
-        vars.push(line?);
-    }
+/// Parse autosave file contents, rejecting anything that is not a complete, sane task.
+/// Files without the version header are from older versions and are parsed as-is.
+pub fn parse_autosave(bytes: &[u8]) -> std::result::Result<FurTask, AutosaveError> {
+    let contents = std::str::from_utf8(bytes)
+        .map_err(|_| AutosaveError::Corrupt("not valid UTF-8".to_string()))?;
 
-    Ok(vars)
-}
-
-fn task_from_autosave(path: &PathBuf) -> Option<FurTask> {
-    if let Ok(autosave_lines) = read_autosave(path) {
-        if let Ok(start_time) = DateTime::parse_from_rfc3339(&autosave_lines[1]) {
-            if let Ok(stop_time) = DateTime::parse_from_rfc3339(&autosave_lines[2]) {
-                let currency: String;
-                if autosave_lines.len() < 7 {
-                    currency = String::new();
-                } else {
-                    currency = autosave_lines[6].clone()
-                }
-                return Some(FurTask::new(
-                    autosave_lines[0].clone(),
-                    DateTime::from(start_time),
-                    DateTime::from(stop_time),
-                    autosave_lines[3].clone(),
-                    autosave_lines[4].clone(),
-                    autosave_lines[5].parse().unwrap_or(0.0),
-                    currency,
-                ));
+    let contents = match contents.strip_prefix(AUTOSAVE_HEADER) {
+        Some(rest) => {
+            let rest = rest
+                .strip_prefix('\n')
+                .ok_or_else(|| AutosaveError::Corrupt("missing checksum".to_string()))?;
+            let (checksum, body) = rest
+                .split_once('\n')
+                .ok_or_else(|| AutosaveError::Corrupt("missing checksum".to_string()))?;
+            if blake3::hash(body.as_bytes()).to_hex().as_str() != checksum {
+                return Err(AutosaveError::Corrupt("checksum mismatch".to_string()));
             }
+            body
         }
+        None => contents,
+    };
+
+    let autosave_lines: Vec<&str> = contents.lines().collect();
+    if autosave_lines.len() < 6 {
+        return Err(AutosaveError::Corrupt("too few lines".to_string()));
+    }
 
-        return None;
-    } else {
-        return None;
+    let name = autosave_lines[0].trim();
+    if name.is_empty() {
+        return Err(AutosaveError::Corrupt("missing task name".to_string()));
+    }
+    let start_time = DateTime::parse_from_rfc3339(autosave_lines[1])
+        .map_err(|_| AutosaveError::Corrupt("invalid start time".to_string()))?;
+    let stop_time = DateTime::parse_from_rfc3339(autosave_lines[2])
+        .map_err(|_| AutosaveError::Corrupt("invalid stop time".to_string()))?;
+    if stop_time < start_time {
+        return Err(AutosaveError::Corrupt(
+            "stop time is before start time".to_string(),
+        ));
+    }
+    let rate: f32 = autosave_lines[5]
+        .parse()
+        .map_err(|_| AutosaveError::Corrupt("invalid rate".to_string()))?;
+    let currency = autosave_lines.get(6).unwrap_or(&"").to_string();
+
+    let mut task = FurTask::new(
+        name.to_string(),
+        DateTime::from(start_time),
+        DateTime::from(stop_time),
+        autosave_lines[3].to_string(),
+        autosave_lines[4].to_string(),
+        rate,
+        currency,
+    );
+    if let Some(is_billable) = autosave_lines.get(7).and_then(|s| s.parse().ok()) {
+        task.is_billable = is_billable;
+    }
+    if let Some(planned_seconds) = autosave_lines.get(8).and_then(|s| s.parse().ok()) {
+        task.planned_seconds = planned_seconds;
+    }
+    if let Some(notes) = autosave_lines.get(9) {
+        task.notes = unescape_autosave_line(notes);
+    }
+
+    Ok(task)
+}
+
+/// Keeps multiline text on one autosave line
+fn escape_autosave_line(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape_autosave_line(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => text.push('\n'),
+                Some(other) => text.push(other),
+                None => text.push('\\'),
+            }
+        } else {
+            text.push(c);
+        }
     }
+    text
 }
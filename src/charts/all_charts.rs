@@ -14,17 +14,207 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use plotters::style::{
-    RGBColor,
-    full_palette::{BLACK, WHITE},
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use chrono::{NaiveDate, TimeDelta, Timelike};
+
+use palette::Srgb;
+use plotters::{
+    chart::{ChartContext, SeriesLabelPosition},
+    coord::CoordTranslate,
+    prelude::{DrawingBackend, IntoFont},
+    style::{
+        RGBColor,
+        full_palette::{BLACK, WHITE},
+    },
 };
 
+use crate::{
+    constants::CHART_COLOR,
+    helpers::earnings::{net_earnings, task_earnings},
+    models::fur_task::FurTask,
+    style::FurTheme,
+};
+
+// Charts are drawn without access to the iced theme, so the app records
+// the resolved theme here whenever it changes
+static DARK_THEME: AtomicBool = AtomicBool::new(false);
+
+pub fn set_chart_theme(theme: FurTheme) {
+    DARK_THEME.store(theme == FurTheme::Dark, Ordering::Relaxed);
+}
+
+// The net earnings preview is recorded the same way, as the bits of the deduction percent
+static SHOW_NET_EARNINGS: AtomicBool = AtomicBool::new(false);
+static DEDUCTION_PERCENT: AtomicU32 = AtomicU32::new(0);
+
+pub fn set_chart_net_earnings(deduction_percent: Option<f32>) {
+    SHOW_NET_EARNINGS.store(deduction_percent.is_some(), Ordering::Relaxed);
+    DEDUCTION_PERCENT.store(
+        deduction_percent.unwrap_or(0.0).to_bits(),
+        Ordering::Relaxed,
+    );
+}
+
+/// Earnings per day after the deduction, if earnings charts should show a net line
+pub fn net_earnings_per_day(
+    earnings: &BTreeMap<NaiveDate, f32>,
+) -> Option<BTreeMap<NaiveDate, f32>> {
+    if !SHOW_NET_EARNINGS.load(Ordering::Relaxed) {
+        return None;
+    }
+    let deduction_percent = f32::from_bits(DEDUCTION_PERCENT.load(Ordering::Relaxed));
+    Some(
+        earnings
+            .iter()
+            .map(|(date, earned)| (*date, net_earnings(*earned, deduction_percent)))
+            .collect(),
+    )
+}
+
+/// Draws the legend telling the gross and net earnings lines apart
+pub fn draw_earnings_legend<'a, DB: DrawingBackend + 'a, CT: CoordTranslate>(
+    chart: &mut ChartContext<'a, DB, CT>,
+) {
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .label_font(("sans-serif", 12).into_font().color(&light_dark_color()))
+        .border_style(light_dark_color())
+        .draw()
+        .unwrap();
+}
+
 pub fn light_dark_color() -> RGBColor {
-    match dark_light::detect() {
-        Ok(mode) => match mode {
-            dark_light::Mode::Light | dark_light::Mode::Unspecified => BLACK,
-            dark_light::Mode::Dark => WHITE,
-        },
-        Err(_) => BLACK,
+    if DARK_THEME.load(Ordering::Relaxed) {
+        WHITE
+    } else {
+        BLACK
+    }
+}
+
+/// The color for a heatmap day, from an empty day at 0 up to the chart color
+pub fn heatmap_color(level: usize, levels: usize) -> RGBColor {
+    let background = if DARK_THEME.load(Ordering::Relaxed) {
+        RGBColor(0x2B, 0x2D, 0x31)
+    } else {
+        WHITE
+    };
+    // Empty days still need to stand out a little from the background
+    let amount = if level == 0 {
+        0.1
+    } else {
+        0.25 + 0.75 * level.min(levels) as f64 / levels.max(1) as f64
+    };
+    let target = if level == 0 {
+        light_dark_color()
+    } else {
+        CHART_COLOR
+    };
+    let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * amount).round() as u8;
+    RGBColor(
+        mix(background.0, target.0),
+        mix(background.1, target.1),
+        mix(background.2, target.2),
+    )
+}
+
+/// Total seconds tracked each day, keyed by the day a task started
+pub fn time_per_day(tasks: &[FurTask]) -> BTreeMap<NaiveDate, i64> {
+    let mut time_by_day = BTreeMap::new();
+    for task in tasks {
+        *time_by_day.entry(task.start_time.date_naive()).or_insert(0) +=
+            task.total_time_in_seconds();
+    }
+    time_by_day
+}
+
+/// Average seconds per task each day. Zero-length tasks (e.g. from a bad import)
+/// are left out so they don't drag the average down, and days with only those are skipped.
+pub fn average_time_per_task(tasks: &[&FurTask]) -> BTreeMap<NaiveDate, i64> {
+    average_per_task(tasks, |task| task.total_time_in_seconds() as f64)
+        .into_iter()
+        .map(|(date, average)| (date, average as i64))
+        .collect()
+}
+
+/// Average earnings per task each day, leaving out zero-length tasks like `average_time_per_task`
+pub fn average_earnings_per_task(
+    tasks: &[&FurTask],
+    exclusions: &[String],
+) -> BTreeMap<NaiveDate, f32> {
+    average_per_task(tasks, |task| {
+        task_earnings(task, task.total_time_in_seconds(), exclusions) as f64
+    })
+    .into_iter()
+    .map(|(date, average)| (date, average as f32))
+    .collect()
+}
+
+/// Tasks that stop when they start, which the averages leave out
+pub fn zero_length_task_count<'a>(tasks: impl IntoIterator<Item = &'a FurTask>) -> usize {
+    tasks
+        .into_iter()
+        .filter(|task| task.total_time_in_seconds() <= 0)
+        .count()
+}
+
+fn average_per_task(
+    tasks: &[&FurTask],
+    value: impl Fn(&FurTask) -> f64,
+) -> BTreeMap<NaiveDate, f64> {
+    tasks
+        .iter()
+        .filter(|task| task.total_time_in_seconds() > 0)
+        .fold(BTreeMap::new(), |mut accumulator, task| {
+            let entry = accumulator
+                .entry(task.start_time.date_naive())
+                .or_insert((0.0, 0));
+            entry.0 += value(task);
+            entry.1 += 1;
+            accumulator
+        })
+        .into_iter()
+        .map(|(date, (total, count))| (date, total / count as f64))
+        .collect()
+}
+
+/// Seconds tracked in each hour of the day. Tasks are split at hour boundaries,
+/// so 9:30-11:15 adds 30 minutes to 9, 60 to 10 and 15 to 11.
+pub fn time_per_hour(tasks: &[&FurTask]) -> [i64; 24] {
+    let mut time_by_hour = [0; 24];
+    for task in tasks {
+        let mut cursor = task.start_time;
+        while cursor < task.stop_time {
+            let into_hour = TimeDelta::seconds((cursor.minute() * 60 + cursor.second()) as i64)
+                + TimeDelta::nanoseconds(cursor.nanosecond() as i64);
+            let segment_end = (cursor - into_hour + TimeDelta::hours(1)).min(task.stop_time);
+            time_by_hour[cursor.hour() as usize] += (segment_end - cursor).num_seconds();
+            cursor = segment_end;
+        }
     }
+    time_by_hour
+}
+
+/// A project's color for chart series, or the default chart color
+pub fn series_color(color: Option<Srgb>) -> RGBColor {
+    match color {
+        Some(color) => {
+            let (r, g, b) = color.into_components();
+            RGBColor((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+        }
+        None => CHART_COLOR,
+    }
+}
+
+/// Moves every date in `values` by `offset`, e.g. to draw last month on this month's dates
+pub fn shift_dates<T: Copy>(
+    values: &BTreeMap<NaiveDate, T>,
+    offset: TimeDelta,
+) -> BTreeMap<NaiveDate, T> {
+    values
+        .iter()
+        .filter_map(|(date, value)| date.checked_add_signed(offset).map(|date| (date, *value)))
+        .collect()
 }
@@ -15,7 +15,8 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES},
+    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES, NET_EARNINGS_CHART_COLOR},
+    helpers::formatting::format_currency,
     localization::Localization,
     models::fur_task::FurTask,
     update::messages::Message,
@@ -35,9 +36,9 @@ pub struct AverageEarningsChart {
 }
 
 impl AverageEarningsChart {
-    pub fn new(tasks: &[FurTask]) -> Self {
+    pub fn new(tasks: &[&FurTask], exclusions: &[String]) -> Self {
         Self {
-            date_earned: earnings_per_day(tasks),
+            date_earned: all_charts::average_earnings_per_task(tasks, exclusions),
         }
     }
 
@@ -57,9 +58,11 @@ impl AverageEarningsChart {
 impl Chart<Message> for AverageEarningsChart {
     type State = ();
     fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let net_earned = all_charts::net_earnings_per_day(&self.date_earned);
         let min_earned = self
             .date_earned
             .values()
+            .chain(net_earned.iter().flat_map(|net| net.values()))
             .copied()
             .max_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal))
             .unwrap_or(0.0);
@@ -107,36 +110,43 @@ impl Chart<Message> for AverageEarningsChart {
                                 .color(&all_charts::light_dark_color())
                                 .transform(FontTransform::Rotate90),
                         )
-                        .y_label_formatter(&|y| format!("${:.2}", y))
+                        .y_label_formatter(&|y| format_currency(*y, ""))
                         .axis_style(
                             ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1),
                         )
                         .draw()
                         .unwrap();
 
-                    chart
+                    let gross_series = chart
                         .draw_series(LineSeries::new(
                             self.date_earned.iter().map(|(d, t)| (*d, *t)),
                             CHART_COLOR.filled(),
                         ))
                         .unwrap();
+
+                    if let Some(net_earned) = net_earned {
+                        gross_series
+                            .label(localization.get_message("gross-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(vec![(x, y), (x + 20, y)], CHART_COLOR)
+                            });
+                        chart
+                            .draw_series(LineSeries::new(
+                                net_earned.into_iter(),
+                                NET_EARNINGS_CHART_COLOR.filled(),
+                            ))
+                            .unwrap()
+                            .label(localization.get_message("net-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(
+                                    vec![(x, y), (x + 20, y)],
+                                    NET_EARNINGS_CHART_COLOR,
+                                )
+                            });
+                        all_charts::draw_earnings_legend(&mut chart);
+                    }
                 }
             }
         }
     }
 }
-
-fn earnings_per_day(tasks: &[FurTask]) -> BTreeMap<NaiveDate, f32> {
-    tasks
-        .iter()
-        .fold(BTreeMap::new(), |mut accumulator, task| {
-            let date = task.start_time.date_naive();
-            let entry = accumulator.entry(date).or_insert((0.0, 0));
-            entry.0 += task.total_earnings();
-            entry.1 += 1;
-            accumulator
-        })
-        .into_iter()
-        .map(|(date, (total_time, count))| (date, total_time / count as f32))
-        .collect()
-}
@@ -35,9 +35,9 @@ pub struct AverageTimeChart {
 }
 
 impl AverageTimeChart {
-    pub fn new(tasks: &[FurTask]) -> Self {
+    pub fn new(tasks: &[&FurTask]) -> Self {
         Self {
-            date_time: time_per_day(tasks),
+            date_time: all_charts::average_time_per_task(tasks),
         }
     }
 
@@ -116,21 +116,6 @@ impl Chart<Message> for AverageTimeChart {
     }
 }
 
-fn time_per_day(tasks: &[FurTask]) -> BTreeMap<NaiveDate, i64> {
-    tasks
-        .iter()
-        .fold(BTreeMap::new(), |mut accumulator, task| {
-            let date = task.start_time.date_naive();
-            let entry = accumulator.entry(date).or_insert((0, 0));
-            entry.0 += task.total_time_in_seconds();
-            entry.1 += 1;
-            accumulator
-        })
-        .into_iter()
-        .map(|(date, (total_time, count))| (date, total_time / count))
-        .collect()
-}
-
 fn seconds_to_hms(total_seconds: &i64) -> String {
     let h = total_seconds / 3600;
     let m = total_seconds % 3600 / 60;
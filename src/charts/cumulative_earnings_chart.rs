@@ -0,0 +1,193 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES, NET_EARNINGS_CHART_COLOR},
+    helpers::{earnings::task_earnings, formatting::format_currency},
+    localization::Localization,
+    models::fur_task::FurTask,
+    update::messages::Message,
+};
+use chrono::{Datelike, NaiveDate};
+use iced::{Element, Length, widget::Text};
+use plotters::prelude::*;
+use plotters_backend::DrawingBackend;
+use plotters_iced::{Chart, ChartWidget, plotters_backend};
+use std::collections::BTreeMap;
+
+use super::all_charts;
+
+/// Running earnings for the selected range. The running total starts over on the
+/// first of each month so every month can be compared against the monthly target.
+#[derive(Clone, Debug)]
+pub struct CumulativeEarningsChart {
+    cumulative_earned: BTreeMap<NaiveDate, f32>,
+    monthly_target: f32,
+}
+
+impl CumulativeEarningsChart {
+    pub fn new(tasks: &[FurTask], monthly_target: f32, exclusions: &[String]) -> Self {
+        Self {
+            cumulative_earned: cumulative_earnings_per_day(tasks, exclusions),
+            monthly_target,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if self.cumulative_earned.len() <= 1 {
+            Text::new("").into()
+        } else {
+            let chart = ChartWidget::new(self)
+                .width(Length::Fill)
+                .height(Length::Fixed(CHART_HEIGHT));
+
+            chart.into()
+        }
+    }
+}
+
+impl Chart<Message> for CumulativeEarningsChart {
+    type State = ();
+    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let localization = Localization::new();
+        let net_earned = all_charts::net_earnings_per_day(&self.cumulative_earned);
+
+        let max_earned = self
+            .cumulative_earned
+            .values()
+            .copied()
+            .fold(self.monthly_target, f32::max);
+        let max_plus_five_percent = max_earned + (max_earned * 0.05);
+
+        if let (Some(first_date), Some(last_date)) = (
+            self.cumulative_earned.first_key_value(),
+            self.cumulative_earned.last_key_value(),
+        ) {
+            let mut chart = chart
+                .margin(30)
+                .caption(
+                    localization.get_message("cumulative-earnings", None),
+                    ("sans-serif", 15)
+                        .into_font()
+                        .color(&all_charts::light_dark_color()),
+                )
+                .x_label_area_size(30)
+                .y_label_area_size(30)
+                .build_cartesian_2d(*first_date.0..*last_date.0, 0.0..max_plus_five_percent)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .label_style(&all_charts::light_dark_color())
+                .x_label_style(
+                    ("sans-serif", 12)
+                        .into_font()
+                        .color(&all_charts::light_dark_color()),
+                )
+                .x_labels(MAX_X_VALUES)
+                .y_label_style(
+                    ("sans-serif", 12)
+                        .into_font()
+                        .color(&all_charts::light_dark_color())
+                        .transform(FontTransform::Rotate90),
+                )
+                .y_label_formatter(&|y| format_currency(*y, ""))
+                .axis_style(ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1))
+                .draw()
+                .unwrap();
+
+            // Each month is its own series so the reset doesn't draw a line back down
+            for (index, days) in months(&self.cumulative_earned).into_values().enumerate() {
+                if self.monthly_target > 0.0 {
+                    if let (Some(first_day), Some(last_day)) = (days.first(), days.last()) {
+                        chart
+                            .draw_series(LineSeries::new(
+                                [
+                                    (first_day.0, self.monthly_target),
+                                    (last_day.0, self.monthly_target),
+                                ],
+                                RED.stroke_width(1),
+                            ))
+                            .unwrap();
+                    }
+                }
+
+                let gross_series = chart
+                    .draw_series(LineSeries::new(days, CHART_COLOR.filled()))
+                    .unwrap();
+                if index == 0 && net_earned.is_some() {
+                    gross_series
+                        .label(localization.get_message("gross-earnings-legend", None))
+                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], CHART_COLOR));
+                }
+            }
+
+            if let Some(net_earned) = net_earned {
+                for (index, days) in months(&net_earned).into_values().enumerate() {
+                    let net_series = chart
+                        .draw_series(LineSeries::new(days, NET_EARNINGS_CHART_COLOR.filled()))
+                        .unwrap();
+                    if index == 0 {
+                        net_series
+                            .label(localization.get_message("net-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(
+                                    vec![(x, y), (x + 20, y)],
+                                    NET_EARNINGS_CHART_COLOR,
+                                )
+                            });
+                    }
+                }
+                all_charts::draw_earnings_legend(&mut chart);
+            }
+        }
+    }
+}
+
+fn months(earned: &BTreeMap<NaiveDate, f32>) -> BTreeMap<(i32, u32), Vec<(NaiveDate, f32)>> {
+    let mut months: BTreeMap<(i32, u32), Vec<(NaiveDate, f32)>> = BTreeMap::new();
+    for (date, earned) in earned {
+        months
+            .entry((date.year(), date.month()))
+            .or_default()
+            .push((*date, *earned));
+    }
+    months
+}
+
+fn cumulative_earnings_per_day(
+    tasks: &[FurTask],
+    exclusions: &[String],
+) -> BTreeMap<NaiveDate, f32> {
+    let mut earnings_by_day: BTreeMap<NaiveDate, f32> = BTreeMap::new();
+    for task in tasks {
+        *earnings_by_day
+            .entry(task.start_time.date_naive())
+            .or_insert(0.0) += task_earnings(task, task.total_time_in_seconds(), exclusions);
+    }
+
+    let mut running_total = 0.0;
+    let mut current_month = None;
+    for (date, earned) in earnings_by_day.iter_mut() {
+        if current_month != Some((date.year(), date.month())) {
+            current_month = Some((date.year(), date.month()));
+            running_total = 0.0;
+        }
+        running_total += *earned;
+        *earned = running_total;
+    }
+    earnings_by_day
+}
@@ -15,12 +15,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES},
+    constants::{
+        CHART_COLOR, CHART_HEIGHT, CHART_PREVIOUS_PERIOD_OPACITY, MAX_X_VALUES,
+        NET_EARNINGS_CHART_COLOR,
+    },
+    helpers::{earnings::task_earnings, formatting::format_currency},
     localization::Localization,
     models::fur_task::FurTask,
     update::messages::Message,
 };
-use chrono::NaiveDate;
+use chrono::{NaiveDate, TimeDelta};
 use iced::{Element, Length, widget::Text};
 use plotters::prelude::*;
 use plotters_backend::DrawingBackend;
@@ -32,12 +36,23 @@ use super::all_charts;
 #[derive(Clone, Debug)]
 pub struct EarningsChart {
     date_earned: BTreeMap<NaiveDate, f32>,
+    /// A period being compared with, moved onto this chart's dates
+    previous: BTreeMap<NaiveDate, f32>,
 }
 
 impl EarningsChart {
-    pub fn new(tasks: &[FurTask]) -> Self {
+    pub fn new(tasks: &[FurTask], exclusions: &[String]) -> Self {
         Self {
-            date_earned: earnings_per_day(tasks),
+            date_earned: earnings_per_day(tasks, exclusions),
+            previous: BTreeMap::new(),
+        }
+    }
+
+    /// This chart with `previous` overlaid, its dates moved forward by `offset`
+    pub fn with_previous(&self, previous: &EarningsChart, offset: TimeDelta) -> Self {
+        Self {
+            date_earned: self.date_earned.clone(),
+            previous: all_charts::shift_dates(&previous.date_earned, offset),
         }
     }
 
@@ -58,10 +73,13 @@ impl Chart<Message> for EarningsChart {
     type State = ();
     fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
         let localization = Localization::new();
+        let net_earned = all_charts::net_earnings_per_day(&self.date_earned);
 
         let min_earned = self
             .date_earned
             .values()
+            .chain(self.previous.values())
+            .chain(net_earned.iter().flat_map(|net| net.values()))
             .copied()
             .max_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal))
             .unwrap_or(0.0);
@@ -69,6 +87,7 @@ impl Chart<Message> for EarningsChart {
         let max_earned = self
             .date_earned
             .values()
+            .chain(self.previous.values())
             .copied()
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
             .unwrap_or(0.0);
@@ -107,31 +126,64 @@ impl Chart<Message> for EarningsChart {
                                 .color(&all_charts::light_dark_color())
                                 .transform(FontTransform::Rotate90),
                         )
-                        .y_label_formatter(&|y| format!("${:.2}", y))
+                        .y_label_formatter(&|y| format_currency(*y, ""))
                         .axis_style(
                             ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1),
                         )
                         .draw()
                         .unwrap();
 
-                    chart
+                    if !self.previous.is_empty() {
+                        chart
+                            .draw_series(LineSeries::new(
+                                self.previous
+                                    .range(*first_date.0..=*last_date.0)
+                                    .map(|(d, t)| (*d, *t)),
+                                CHART_COLOR.mix(CHART_PREVIOUS_PERIOD_OPACITY),
+                            ))
+                            .unwrap();
+                    }
+
+                    let gross_series = chart
                         .draw_series(LineSeries::new(
                             self.date_earned.iter().map(|(d, t)| (*d, *t)),
                             CHART_COLOR.filled(),
                         ))
                         .unwrap();
+
+                    if let Some(net_earned) = net_earned {
+                        gross_series
+                            .label(localization.get_message("gross-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(vec![(x, y), (x + 20, y)], CHART_COLOR)
+                            });
+                        chart
+                            .draw_series(LineSeries::new(
+                                net_earned.into_iter(),
+                                NET_EARNINGS_CHART_COLOR.filled(),
+                            ))
+                            .unwrap()
+                            .label(localization.get_message("net-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(
+                                    vec![(x, y), (x + 20, y)],
+                                    NET_EARNINGS_CHART_COLOR,
+                                )
+                            });
+                        all_charts::draw_earnings_legend(&mut chart);
+                    }
                 }
             }
         }
     }
 }
 
-fn earnings_per_day(tasks: &[FurTask]) -> BTreeMap<NaiveDate, f32> {
+fn earnings_per_day(tasks: &[FurTask], exclusions: &[String]) -> BTreeMap<NaiveDate, f32> {
     let mut earnings_by_day = BTreeMap::new();
     for task in tasks {
         *earnings_by_day
             .entry(task.start_time.date_naive())
-            .or_insert(0.0) += task.total_earnings();
+            .or_insert(0.0) += task_earnings(task, task.total_time_in_seconds(), exclusions);
     }
     earnings_by_day
 }
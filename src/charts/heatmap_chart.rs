@@ -0,0 +1,185 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::{HEATMAP_HEIGHT, HEATMAP_LEVELS, HEATMAP_WEEKS},
+    database::db_retrieve_tasks_by_date_range,
+    localization::Localization,
+    models::fur_task::FurTask,
+    update::messages::Message,
+};
+use chrono::{Datelike, Days, NaiveDate};
+use iced::{
+    Alignment, Element, Length,
+    widget::{column, container, row, space, text},
+};
+use plotters::prelude::*;
+use plotters_backend::DrawingBackend;
+use plotters_iced::{Chart, ChartWidget, plotters_backend};
+use std::collections::BTreeMap;
+
+use super::all_charts;
+
+/// Time tracked each day of the last year, one column per week. Unlike the
+/// other charts it ignores the report range.
+#[derive(Clone, Debug)]
+pub struct HeatmapChart {
+    end: NaiveDate,
+    time_per_day: BTreeMap<NaiveDate, i64>,
+}
+
+impl HeatmapChart {
+    pub fn new(tasks: &[FurTask], end: NaiveDate) -> Self {
+        Self {
+            end,
+            time_per_day: all_charts::time_per_day(tasks),
+        }
+    }
+
+    /// Reads the tasks for the year ending on `end`. Runs in a Task like the report data.
+    pub fn load(end: NaiveDate) -> Self {
+        let tasks = match db_retrieve_tasks_by_date_range(first_day(end), end) {
+            Ok(tasks) => tasks,
+            Err(e) => {
+                eprintln!("Could not retrieve tasks for the heatmap: {}", e);
+                vec![]
+            }
+        };
+        HeatmapChart::new(&tasks, end)
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        first_day(self.end)
+    }
+
+    /// The week column and weekday row (Monday is 0) of a shown date
+    pub fn cell(&self, date: NaiveDate) -> Option<(u32, u32)> {
+        if date < self.first_day() || date > self.end {
+            return None;
+        }
+        let days = (date - self.first_day()).num_days() as u32;
+        Some((days / 7, days % 7))
+    }
+
+    /// How dark a day is drawn: 0 for nothing tracked, up to `HEATMAP_LEVELS`
+    /// for the busiest day shown
+    pub fn level(&self, date: NaiveDate) -> usize {
+        let seconds = self.time_per_day.get(&date).copied().unwrap_or(0);
+        let max = self
+            .time_per_day
+            .range(self.first_day()..=self.end)
+            .map(|(_, seconds)| *seconds)
+            .max()
+            .unwrap_or(0);
+        if seconds <= 0 || max <= 0 {
+            0
+        } else {
+            ((seconds as f64 / max as f64 * HEATMAP_LEVELS as f64).ceil() as usize)
+                .clamp(1, HEATMAP_LEVELS)
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if self.time_per_day.is_empty() {
+            return text("").into();
+        }
+
+        let localization = Localization::new();
+        let mut legend = row![text(localization.get_message("less", None)).size(12)]
+            .spacing(4)
+            .align_y(Alignment::Center);
+        for level in 0..=HEATMAP_LEVELS {
+            let RGBColor(r, g, b) = all_charts::heatmap_color(level, HEATMAP_LEVELS);
+            legend = legend.push(container(space::horizontal()).width(12).height(12).style(
+                move |_| container::Style {
+                    background: Some(iced::Color::from_rgb8(r, g, b).into()),
+                    ..Default::default()
+                },
+            ));
+        }
+        legend = legend.push(text(localization.get_message("more", None)).size(12));
+
+        column![
+            ChartWidget::new(self)
+                .width(Length::Fill)
+                .height(Length::Fixed(HEATMAP_HEIGHT)),
+            row![space::horizontal(), legend].padding([0, 30]),
+        ]
+        .into()
+    }
+}
+
+impl Chart<Message> for HeatmapChart {
+    type State = ();
+    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let localization = Localization::new();
+        let label_font = ("sans-serif", 12)
+            .into_font()
+            .color(&all_charts::light_dark_color());
+
+        // Seven weekday rows with Monday on top, plus a row for the month labels
+        let mut chart = chart
+            .margin(30)
+            .caption(
+                localization.get_message("activity-heatmap-title", None),
+                ("sans-serif", 15)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .build_cartesian_2d(0f64..HEATMAP_WEEKS as f64, 0f64..8f64)
+            .unwrap();
+
+        let days = self
+            .first_day()
+            .iter_days()
+            .take_while(|date| *date <= self.end)
+            .filter_map(|date| self.cell(date).map(|cell| (date, cell)));
+
+        chart
+            .draw_series(days.clone().map(|(date, (week, weekday))| {
+                let left = week as f64;
+                let top = 7.0 - weekday as f64;
+                Rectangle::new(
+                    [(left + 0.1, top - 0.1), (left + 0.9, top - 0.9)],
+                    all_charts::heatmap_color(self.level(date), HEATMAP_LEVELS).filled(),
+                )
+            }))
+            .unwrap();
+
+        chart
+            .draw_series(
+                days.filter(|(date, _)| date.day() == 1)
+                    .map(|(date, (week, _))| {
+                        Text::new(
+                            date.format("%b").to_string(),
+                            (week as f64, 7.9),
+                            label_font.clone(),
+                        )
+                    }),
+            )
+            .unwrap();
+    }
+}
+
+/// The Monday 52 weeks before the week `end` falls in, so the last column is the current week
+fn first_day(end: NaiveDate) -> NaiveDate {
+    let week_start = end - Days::new(end.weekday().num_days_from_monday() as u64);
+    week_start - Days::new(7 * (HEATMAP_WEEKS as u64 - 1))
+}
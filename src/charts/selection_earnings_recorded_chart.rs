@@ -15,7 +15,8 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES},
+    constants::{CHART_HEIGHT, MAX_X_VALUES, NET_EARNINGS_CHART_COLOR},
+    helpers::{earnings::task_earnings, formatting::format_currency},
     localization::Localization,
     models::fur_task::FurTask,
     update::messages::Message,
@@ -31,13 +32,15 @@ use super::all_charts;
 
 #[derive(Clone, Debug)]
 pub struct SelectionEarningsRecordedChart {
+    color: RGBColor,
     date_earned: BTreeMap<NaiveDate, f32>,
 }
 
 impl SelectionEarningsRecordedChart {
-    pub fn new(tasks: &[&FurTask]) -> Self {
+    pub fn new(tasks: &[&FurTask], color: RGBColor, exclusions: &[String]) -> Self {
         Self {
-            date_earned: earnings_per_day(tasks),
+            color,
+            date_earned: earnings_per_day(tasks, exclusions),
         }
     }
 
@@ -59,9 +62,11 @@ impl SelectionEarningsRecordedChart {
 impl Chart<Message> for SelectionEarningsRecordedChart {
     type State = ();
     fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let net_earned = all_charts::net_earnings_per_day(&self.date_earned);
         let min_earned = self
             .date_earned
             .values()
+            .chain(net_earned.iter().flat_map(|net| net.values()))
             .copied()
             .max_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal))
             .unwrap_or(0.0);
@@ -109,31 +114,54 @@ impl Chart<Message> for SelectionEarningsRecordedChart {
                                 .color(&all_charts::light_dark_color())
                                 .transform(FontTransform::Rotate90),
                         )
-                        .y_label_formatter(&|y| format!("${:.2}", y))
+                        .y_label_formatter(&|y| format_currency(*y, ""))
                         .axis_style(
                             ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1),
                         )
                         .draw()
                         .unwrap();
 
-                    chart
+                    let gross_series = chart
                         .draw_series(LineSeries::new(
                             self.date_earned.iter().map(|(d, t)| (*d, *t)),
-                            CHART_COLOR.filled(),
+                            self.color.filled(),
                         ))
                         .unwrap();
+
+                    if let Some(net_earned) = net_earned {
+                        let gross_color = self.color;
+                        gross_series
+                            .label(localization.get_message("gross-earnings-legend", None))
+                            .legend(move |(x, y)| {
+                                PathElement::new(vec![(x, y), (x + 20, y)], gross_color)
+                            });
+                        chart
+                            .draw_series(LineSeries::new(
+                                net_earned.into_iter(),
+                                NET_EARNINGS_CHART_COLOR.filled(),
+                            ))
+                            .unwrap()
+                            .label(localization.get_message("net-earnings-legend", None))
+                            .legend(|(x, y)| {
+                                PathElement::new(
+                                    vec![(x, y), (x + 20, y)],
+                                    NET_EARNINGS_CHART_COLOR,
+                                )
+                            });
+                        all_charts::draw_earnings_legend(&mut chart);
+                    }
                 }
             }
         }
     }
 }
 
-fn earnings_per_day(tasks: &[&FurTask]) -> BTreeMap<NaiveDate, f32> {
+fn earnings_per_day(tasks: &[&FurTask], exclusions: &[String]) -> BTreeMap<NaiveDate, f32> {
     let mut earnings_by_day = BTreeMap::new();
     for task in tasks {
         *earnings_by_day
             .entry(task.start_time.date_naive())
-            .or_insert(0.0) += task.total_earnings();
+            .or_insert(0.0) += task_earnings(task, task.total_time_in_seconds(), exclusions);
     }
     earnings_by_day
 }
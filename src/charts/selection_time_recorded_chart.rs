@@ -15,7 +15,7 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES},
+    constants::{CHART_HEIGHT, MAX_X_VALUES},
     localization::Localization,
     models::fur_task::FurTask,
     update::messages::Message,
@@ -31,12 +31,14 @@ use super::all_charts;
 
 #[derive(Clone, Debug)]
 pub struct SelectionTimeRecordedChart {
+    color: RGBColor,
     date_time: BTreeMap<NaiveDate, i64>,
 }
 
 impl SelectionTimeRecordedChart {
-    pub fn new(tasks: &[&FurTask]) -> Self {
+    pub fn new(tasks: &[&FurTask], color: RGBColor) -> Self {
         Self {
+            color,
             date_time: time_per_day(tasks),
         }
     }
@@ -108,7 +110,7 @@ impl Chart<Message> for SelectionTimeRecordedChart {
                     chart
                         .draw_series(LineSeries::new(
                             self.date_time.iter().map(|(d, t)| (*d, *t)),
-                            CHART_COLOR.filled(),
+                            self.color.filled(),
                         ))
                         .unwrap();
                 }
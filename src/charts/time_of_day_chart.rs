@@ -0,0 +1,123 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::CHART_HEIGHT, helpers::formatting::format_hour, localization::Localization,
+    models::fur_task::FurTask, update::messages::Message,
+};
+use iced::{Element, Length, widget::Text};
+use plotters::prelude::*;
+use plotters_backend::DrawingBackend;
+use plotters_iced::{Chart, ChartWidget, plotters_backend};
+
+use super::all_charts;
+
+#[derive(Clone, Debug)]
+pub struct TimeOfDayChart {
+    color: RGBColor,
+    time_per_hour: [i64; 24],
+}
+
+impl TimeOfDayChart {
+    pub fn new(tasks: &[&FurTask], color: RGBColor) -> Self {
+        Self {
+            color,
+            time_per_hour: all_charts::time_per_hour(tasks),
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if self.time_per_hour.iter().all(|&seconds| seconds == 0) {
+            Text::new("").into()
+        } else {
+            let chart = ChartWidget::new(self)
+                .width(Length::Fill)
+                .height(Length::Fixed(CHART_HEIGHT));
+
+            chart.into()
+        }
+    }
+}
+
+impl Chart<Message> for TimeOfDayChart {
+    type State = ();
+    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let max_time = self.time_per_hour.iter().copied().max().unwrap_or(0);
+        if max_time == 0 {
+            return;
+        }
+        let localization = Localization::new();
+
+        let mut chart = chart
+            .margin(30)
+            .caption(
+                localization.get_message("time-of-day-title", None),
+                ("sans-serif", 15)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_cartesian_2d((0u32..23u32).into_segmented(), 0..max_time)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .label_style(&all_charts::light_dark_color())
+            .x_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .x_labels(24)
+            .x_label_formatter(&|hour| match hour {
+                SegmentValue::CenterOf(hour) | SegmentValue::Exact(hour) => format_hour(*hour),
+                SegmentValue::Last => String::new(),
+            })
+            .y_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&all_charts::light_dark_color())
+                    .transform(FontTransform::Rotate90),
+            )
+            .y_label_formatter(&|y| seconds_to_hms(y))
+            .axis_style(ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1))
+            .draw()
+            .unwrap();
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(self.color.filled())
+                    .margin(2)
+                    .data(
+                        self.time_per_hour
+                            .iter()
+                            .enumerate()
+                            .map(|(hour, seconds)| (hour as u32, *seconds)),
+                    ),
+            )
+            .unwrap();
+    }
+}
+
+fn seconds_to_hms(total_seconds: &i64) -> String {
+    let h = total_seconds / 3600;
+    let m = total_seconds % 3600 / 60;
+    let s = total_seconds % 60;
+    format!("{}:{:02}:{:02}", h, m, s)
+}
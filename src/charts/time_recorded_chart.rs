@@ -15,12 +15,15 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES},
+    constants::{
+        CHART_COLOR, CHART_HEIGHT, CHART_PREVIOUS_PERIOD_OPACITY, CHART_PROVISIONAL_OPACITY,
+        MAX_X_VALUES,
+    },
     localization::Localization,
     models::fur_task::FurTask,
     update::messages::Message,
 };
-use chrono::NaiveDate;
+use chrono::{NaiveDate, TimeDelta};
 use iced::{
     Element, Length,
     widget::{Text, row, space},
@@ -35,17 +38,51 @@ use super::all_charts;
 #[derive(Clone, Debug)]
 pub struct TimeRecordedChart {
     date_time: BTreeMap<NaiveDate, i64>,
+    /// A period being compared with, moved onto this chart's dates
+    previous: BTreeMap<NaiveDate, i64>,
+    /// The day the running task started and that day's total including it
+    provisional: Option<(NaiveDate, i64)>,
 }
 
 impl TimeRecordedChart {
     pub fn new(tasks: &[FurTask]) -> Self {
         Self {
-            date_time: time_per_day(tasks),
+            date_time: all_charts::time_per_day(tasks),
+            previous: BTreeMap::new(),
+            provisional: None,
+        }
+    }
+
+    /// This chart with the running task's `seconds` added to `date` as a provisional point
+    pub fn with_running(&self, date: NaiveDate, seconds: i64) -> Self {
+        Self {
+            date_time: self.date_time.clone(),
+            previous: self.previous.clone(),
+            provisional: Some((
+                date,
+                self.date_time.get(&date).copied().unwrap_or(0) + seconds,
+            )),
+        }
+    }
+
+    fn date_count(&self) -> usize {
+        match self.provisional {
+            Some((date, _)) if !self.date_time.contains_key(&date) => self.date_time.len() + 1,
+            _ => self.date_time.len(),
+        }
+    }
+
+    /// This chart with `previous` overlaid, its dates moved forward by `offset`
+    pub fn with_previous(&self, previous: &TimeRecordedChart, offset: TimeDelta) -> Self {
+        Self {
+            date_time: self.date_time.clone(),
+            previous: all_charts::shift_dates(&previous.date_time, offset),
+            provisional: None,
         }
     }
 
     pub fn view(&self) -> Element<'_, Message> {
-        if self.date_time.len() <= 1 {
+        if self.date_count() <= 1 {
             let localization = Localization::new();
             row![
                 space::horizontal(),
@@ -66,13 +103,31 @@ impl TimeRecordedChart {
 impl Chart<Message> for TimeRecordedChart {
     type State = ();
     fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
-        let min_time = self.date_time.values().copied().min().unwrap_or(0);
+        let provisional_time = self.provisional.map(|(_, time)| time);
+        let min_time = self
+            .date_time
+            .values()
+            .chain(self.previous.values())
+            .copied()
+            .chain(provisional_time)
+            .min()
+            .unwrap_or(0);
         let min_minus_five_percent = min_time as f32 - (min_time as f32 * 0.05);
-        let max_time = self.date_time.values().copied().max().unwrap_or(0);
+        let max_time = self
+            .date_time
+            .values()
+            .chain(self.previous.values())
+            .copied()
+            .chain(provisional_time)
+            .max()
+            .unwrap_or(0);
+        let provisional_date = self.provisional.map(|(date, _)| date);
+        let first_date = self.date_time.keys().copied().chain(provisional_date).min();
+        let last_date = self.date_time.keys().copied().chain(provisional_date).max();
 
-        if self.date_time.len() > 1 {
-            if let Some(first_date) = self.date_time.first_key_value() {
-                if let Some(last_date) = self.date_time.last_key_value() {
+        if self.date_count() > 1 {
+            if let Some(first_date) = first_date {
+                if let Some(last_date) = last_date {
                     let localization = Localization::new();
 
                     let mut chart = chart
@@ -86,7 +141,7 @@ impl Chart<Message> for TimeRecordedChart {
                         .x_label_area_size(30)
                         .y_label_area_size(30)
                         .build_cartesian_2d(
-                            *first_date.0..*last_date.0,
+                            first_date..last_date,
                             min_minus_five_percent as i64..max_time,
                         )
                         .unwrap();
@@ -113,27 +168,51 @@ impl Chart<Message> for TimeRecordedChart {
                         .draw()
                         .unwrap();
 
+                    if !self.previous.is_empty() {
+                        chart
+                            .draw_series(LineSeries::new(
+                                self.previous
+                                    .range(first_date..=last_date)
+                                    .map(|(d, t)| (*d, *t)),
+                                CHART_COLOR.mix(CHART_PREVIOUS_PERIOD_OPACITY),
+                            ))
+                            .unwrap();
+                    }
+
                     chart
                         .draw_series(LineSeries::new(
                             self.date_time.iter().map(|(d, t)| (*d, *t)),
                             CHART_COLOR.filled(),
                         ))
                         .unwrap();
+
+                    // The running task isn't saved yet, so it's drawn lighter and hollow
+                    if let Some((date, time)) = self.provisional {
+                        let provisional_color = CHART_COLOR.mix(CHART_PROVISIONAL_OPACITY);
+                        if let Some((previous_date, previous_time)) =
+                            self.date_time.range(..date).next_back()
+                        {
+                            chart
+                                .draw_series(LineSeries::new(
+                                    [(*previous_date, *previous_time), (date, time)],
+                                    provisional_color.stroke_width(2),
+                                ))
+                                .unwrap();
+                        }
+                        chart
+                            .draw_series(std::iter::once(Circle::new(
+                                (date, time),
+                                4,
+                                provisional_color.stroke_width(2),
+                            )))
+                            .unwrap();
+                    }
                 }
             }
         }
     }
 }
 
-fn time_per_day(tasks: &[FurTask]) -> BTreeMap<NaiveDate, i64> {
-    let mut time_by_day = BTreeMap::new();
-    for task in tasks {
-        *time_by_day.entry(task.start_time.date_naive()).or_insert(0) +=
-            task.total_time_in_seconds();
-    }
-    time_by_day
-}
-
 fn seconds_to_hms(total_seconds: &i64) -> String {
     let h = total_seconds / 3600;
     let m = total_seconds % 3600 / 60;
@@ -0,0 +1,210 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    constants::{CHART_COLOR, CHART_HEIGHT, MAX_X_VALUES, TODO_PLANNED_BAR_OPACITY},
+    database::db_retrieve_todos_planned_or_completed_between,
+    localization::Localization,
+    models::fur_todo::FurTodo,
+    update::messages::Message,
+};
+use chrono::{NaiveDate, TimeDelta};
+use iced::{Element, Length, widget::Text};
+use plotters::prelude::*;
+use plotters_backend::DrawingBackend;
+use plotters_iced::{Chart, ChartWidget, plotters_backend};
+
+use super::all_charts;
+
+/// Todos planned for each day in a range next to the todos finished that day
+#[derive(Clone, Debug, Default)]
+pub struct TodoCompletionChart {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    planned: BTreeMap<NaiveDate, usize>,
+    completed: BTreeMap<NaiveDate, usize>,
+    /// Of the todos planned in range, how many are completed
+    planned_and_completed: usize,
+}
+
+impl TodoCompletionChart {
+    pub fn load(start: NaiveDate, end: NaiveDate) -> Result<Self, String> {
+        db_retrieve_todos_planned_or_completed_between(start, end)
+            .map(|todos| TodoCompletionChart::new(&todos, start, end))
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn new(todos: &[FurTodo], start: NaiveDate, end: NaiveDate) -> Self {
+        let in_range = |date: NaiveDate| start <= date && date <= end;
+        let mut planned = BTreeMap::new();
+        let mut completed = BTreeMap::new();
+        let mut planned_and_completed = 0;
+        for todo in todos.iter().filter(|todo| !todo.is_deleted) {
+            let planned_date = todo.date.date_naive();
+            if in_range(planned_date) {
+                *planned.entry(planned_date).or_insert(0) += 1;
+                if todo.is_completed {
+                    planned_and_completed += 1;
+                }
+            }
+            if todo.is_completed
+                && let Some(completed_date) = todo.completed_at.map(|at| at.date_naive())
+                && in_range(completed_date)
+            {
+                *completed.entry(completed_date).or_insert(0) += 1;
+            }
+        }
+
+        TodoCompletionChart {
+            start,
+            end,
+            planned,
+            completed,
+            planned_and_completed,
+        }
+    }
+
+    pub fn planned_on(&self, date: NaiveDate) -> usize {
+        self.planned.get(&date).copied().unwrap_or(0)
+    }
+
+    pub fn completed_on(&self, date: NaiveDate) -> usize {
+        self.completed.get(&date).copied().unwrap_or(0)
+    }
+
+    /// The percentage of todos planned in range that are completed, if any were planned
+    pub fn completion_rate(&self) -> Option<f32> {
+        let planned: usize = self.planned.values().sum();
+        (planned > 0).then(|| self.planned_and_completed as f32 / planned as f32 * 100.0)
+    }
+
+    /// The first and last days with a planned or completed todo
+    fn days_with_todos(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let days: BTreeSet<&NaiveDate> = self.planned.keys().chain(self.completed.keys()).collect();
+        Some((**days.first()?, **days.last()?))
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if self.days_with_todos().is_none() {
+            Text::new("").into()
+        } else {
+            let chart = ChartWidget::new(self)
+                .width(Length::Fill)
+                .height(Length::Fixed(CHART_HEIGHT));
+
+            chart.into()
+        }
+    }
+}
+
+impl Chart<Message> for TodoCompletionChart {
+    type State = ();
+    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut chart: ChartBuilder<DB>) {
+        let Some((first_day, last_day)) = self.days_with_todos() else {
+            return;
+        };
+        let day_count = (last_day - first_day).num_days() + 1;
+        let max_count = self
+            .planned
+            .values()
+            .chain(self.completed.values())
+            .copied()
+            .max()
+            .unwrap_or(0);
+        let localization = Localization::new();
+
+        let mut chart = chart
+            .margin(30)
+            .caption(
+                localization.get_message("todo-completion-title", None),
+                ("sans-serif", 15)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0.0..day_count as f64, 0.0..(max_count + 1) as f64)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .label_style(&all_charts::light_dark_color())
+            .x_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .x_labels(MAX_X_VALUES)
+            .x_label_formatter(&|x| {
+                (first_day + TimeDelta::days(x.floor() as i64))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            })
+            .y_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&all_charts::light_dark_color())
+                    .transform(FontTransform::Rotate90),
+            )
+            .y_label_formatter(&|y| format!("{}", y.round() as usize))
+            .axis_style(ShapeStyle::from(all_charts::light_dark_color()).stroke_width(1))
+            .draw()
+            .unwrap();
+
+        // Each day has a planned bar on the left and a completed bar on the right
+        let planned_color = CHART_COLOR.mix(TODO_PLANNED_BAR_OPACITY);
+        let days = first_day.iter_days().take(day_count as usize).enumerate();
+        chart
+            .draw_series(days.clone().map(|(index, date)| {
+                let x = index as f64;
+                Rectangle::new(
+                    [(x + 0.1, 0.0), (x + 0.5, self.planned_on(date) as f64)],
+                    planned_color.filled(),
+                )
+            }))
+            .unwrap()
+            .label(localization.get_message("todos-planned", None))
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 10, y + 5)], planned_color.filled())
+            });
+        chart
+            .draw_series(days.map(|(index, date)| {
+                let x = index as f64;
+                Rectangle::new(
+                    [(x + 0.5, 0.0), (x + 0.9, self.completed_on(date) as f64)],
+                    CHART_COLOR.filled(),
+                )
+            }))
+            .unwrap()
+            .label(localization.get_message("todos-completed", None))
+            .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], CHART_COLOR.filled()));
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .label_font(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&all_charts::light_dark_color()),
+            )
+            .border_style(all_charts::light_dark_color())
+            .draw()
+            .unwrap();
+    }
+}
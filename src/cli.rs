@@ -0,0 +1,252 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{Datelike, Local, NaiveDate};
+use iced_aw::date_picker::Date;
+
+use crate::{
+    app::write_furtasks_to_csv,
+    constants::NEW_INSTANCE_FLAG,
+    database::{SortBy, SortOrder, db_backup, db_init, db_retrieve_all_existing_tasks},
+    helpers::{device::set_current_device_name, formatting::set_default_currency},
+    localization::Localization,
+    models::{export_settings::ExportSettings, fur_settings::FurSettings},
+};
+
+const USAGE: &str = "Usage:
+  furtherance export --format csv|json --output FILE [--from YYYY-MM-DD] [--to YYYY-MM-DD]
+  furtherance backup --output FILE
+  furtherance --new-instance    Open another window even if Furtherance is already running";
+
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Backup {
+        output: PathBuf,
+    },
+    Export {
+        format: CliExportFormat,
+        output: PathBuf,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    },
+    Help,
+}
+
+/// Reads the command line, not including the program name.
+/// Returns None when there is no subcommand, so the GUI starts as usual.
+pub fn parse_args(args: &[String]) -> Option<Result<CliCommand, String>> {
+    let (command, options) = args.split_first()?;
+    match command.as_str() {
+        "backup" => Some(parse_backup(options)),
+        "export" => Some(parse_export(options)),
+        "help" | "--help" | "-h" => Some(Ok(CliCommand::Help)),
+        _ => None,
+    }
+}
+
+/// Whether to skip the single-instance check and open another window
+pub fn wants_new_instance(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == NEW_INSTANCE_FLAG)
+}
+
+fn parse_backup(options: &[String]) -> Result<CliCommand, String> {
+    let mut output = None;
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--output" | "-o" => output = Some(option_value(option, options.next())?),
+            _ => return Err(format!("Unknown option for backup: {}", option)),
+        }
+    }
+
+    Ok(CliCommand::Backup {
+        output: PathBuf::from(output.ok_or("Missing --output FILE")?),
+    })
+}
+
+fn parse_export(options: &[String]) -> Result<CliCommand, String> {
+    let mut format = None;
+    let mut output = None;
+    let mut from = None;
+    let mut to = None;
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--format" | "-f" => {
+                format = Some(match option_value(option, options.next())?.as_str() {
+                    "csv" => CliExportFormat::Csv,
+                    "json" => CliExportFormat::Json,
+                    other => {
+                        return Err(format!(
+                            "Unknown export format: {} (expected csv or json)",
+                            other
+                        ));
+                    }
+                })
+            }
+            "--output" | "-o" => output = Some(option_value(option, options.next())?),
+            "--from" => from = Some(parse_date(&option_value(option, options.next())?)?),
+            "--to" => to = Some(parse_date(&option_value(option, options.next())?)?),
+            _ => return Err(format!("Unknown option for export: {}", option)),
+        }
+    }
+
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        return Err(format!("--from {} is after --to {}", from, to));
+    }
+
+    Ok(CliCommand::Export {
+        format: format.ok_or("Missing --format csv|json")?,
+        output: PathBuf::from(output.ok_or("Missing --output FILE")?),
+        from,
+        to,
+    })
+}
+
+fn option_value(option: &str, value: Option<&String>) -> Result<String, String> {
+    value
+        .filter(|value| !value.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {} (expected YYYY-MM-DD)", value))
+}
+
+/// Runs the command without opening a window and returns the exit code
+pub fn run(command: Result<CliCommand, String>) -> i32 {
+    match command {
+        Ok(CliCommand::Help) => {
+            println!("{}", USAGE);
+            EXIT_SUCCESS
+        }
+        Ok(command) => match run_headless(command) {
+            Ok(message) => {
+                println!("{}", message);
+                EXIT_SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                EXIT_FAILURE
+            }
+        },
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            EXIT_USAGE
+        }
+    }
+}
+
+fn run_headless(command: CliCommand) -> Result<String, String> {
+    let settings = FurSettings::new().map_err(|e| format!("Unable to read the settings: {}", e))?;
+    set_current_device_name(&settings.device_name);
+    set_default_currency(&settings.default_currency);
+    db_init().map_err(|e| {
+        format!(
+            "Unable to open the database at {}: {}",
+            settings.database_url, e
+        )
+    })?;
+
+    match command {
+        CliCommand::Backup { output } => {
+            db_backup(output.clone())
+                .map_err(|e| format!("Unable to back up the database: {}", e))?;
+            Ok(format!("Backed up the database to {}", output.display()))
+        }
+        CliCommand::Export {
+            format,
+            output,
+            from,
+            to,
+        } => {
+            match format {
+                CliExportFormat::Csv => export_csv(&output, from, to, &settings)?,
+                CliExportFormat::Json => export_json(&output, from, to)?,
+            }
+            Ok(format!("Exported tasks to {}", output.display()))
+        }
+        CliCommand::Help => Ok(USAGE.to_string()),
+    }
+}
+
+fn export_csv(
+    output: &Path,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    settings: &FurSettings,
+) -> Result<(), String> {
+    let mut export_settings = ExportSettings::new();
+    export_settings.sort_order = SortOrder::Ascending;
+    if from.is_some() || to.is_some() {
+        let (from, to) = date_range(from, to);
+        export_settings.filter_by_date = true;
+        export_settings.picked_start_date = Date::from_ymd(from.year(), from.month(), from.day());
+        export_settings.picked_end_date = Date::from_ymd(to.year(), to.month(), to.day());
+    }
+
+    write_furtasks_to_csv(
+        output.to_path_buf(),
+        &export_settings,
+        settings,
+        &Localization::new(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn export_json(
+    output: &Path,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<(), String> {
+    let mut tasks = db_retrieve_all_existing_tasks(SortBy::StopTime, SortOrder::Ascending)
+        .map_err(|e| format!("Unable to read tasks from the database: {}", e))?;
+    if from.is_some() || to.is_some() {
+        let (from, to) = date_range(from, to);
+        tasks.retain(|task| {
+            let date = task.stop_time.date_naive();
+            date >= from && date <= to
+        });
+    }
+
+    let json = serde_json::to_string_pretty(&tasks)
+        .map_err(|e| format!("Unable to convert tasks to JSON: {}", e))?;
+    std::fs::write(output, json).map_err(|e| format!("Unable to write {}: {}", output.display(), e))
+}
+
+/// An open-ended range runs from the first task or up to today
+fn date_range(from: Option<NaiveDate>, to: Option<NaiveDate>) -> (NaiveDate, NaiveDate) {
+    (
+        from.unwrap_or(NaiveDate::MIN),
+        to.unwrap_or(Local::now().date_naive()),
+    )
+}
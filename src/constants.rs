@@ -24,20 +24,100 @@ pub const DEBUG_MODE: bool = cfg!(debug_assertions);
 pub const FURTHERANCE_PURPLE: Srgb = Srgb::new(0.694, 0.475, 0.945);
 pub const SETTINGS_SPACING: f32 = 15.0;
 pub const FURTHERANCE_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const NON_BILLABLE_MARKER: &str = "!";
+pub const ESCAPABLE_TASK_INPUT_CHARS: [char; 5] = ['@', '#', '$', '"', '\\'];
+pub const LITERAL_STAND_IN_START: u32 = 0xF0000;
+pub const DAY_NOTES_CSV_HEADER: [&str; 2] = ["Date", "Note"];
+pub const CSV_IMPORT_ERRORS_SHOWN: usize = 20;
+pub const HISTORY_FILTER_CHIP_COUNT: usize = 8;
+pub const HISTORY_LOAD_OLDER_OFFSET: f32 = 0.95;
+pub const HISTORY_SCROLLABLE_ID: &str = "history";
+pub const MAX_SECONDARY_TIMERS: usize = 2;
+pub const MAX_LOGGED_ERRORS: usize = 100;
+pub const RECENT_TASKS_SHOWN: usize = 10;
+pub const REPORT_VALUES_SHOWN: usize = 50;
+pub const SEARCH_INPUT_ID: &str = "search-input";
+pub const SEARCH_PAGE_SIZE: usize = 50;
+pub const SHORTCUT_BUTTON_WIDTH: f32 = 200.0;
+pub const SHORTCUT_FOCUS_RING_WIDTH: f32 = 3.0;
+pub const SHORTCUTS_SCROLLABLE_ID: &str = "shortcuts";
+pub const SHORTCUTS_SPACING: f32 = 20.0;
+pub const SIDEBAR_WIDTH: f32 = 175.0;
+pub const SUMMARY_FILE_DAYS: i64 = 90;
+pub const SUMMARY_FILE_MIN_SECONDS_BETWEEN_WRITES: u64 = 30;
+pub const TASK_INPUT_ID: &str = "task-input";
+pub const TITLE_TASK_NAME_MAX_CHARS: usize = 40;
+pub const UPGRADE_PROGRESS_ROWS: usize = 500;
+
+// Window
+pub const DEFAULT_WINDOW_HEIGHT: f32 = 600.0;
+pub const DEFAULT_WINDOW_WIDTH: f32 = 1024.0;
+pub const WINDOW_GEOMETRY_SAVE_SECONDS: u64 = 2;
 
 // Charts
 pub const CHART_HEIGHT: f32 = 400.0;
 pub const CHART_COLOR: RGBColor = RGBColor(177, 121, 241);
+pub const CHART_PREVIOUS_PERIOD_OPACITY: f64 = 0.35;
+pub const CHART_PROVISIONAL_OPACITY: f64 = 0.5;
+pub const HEATMAP_HEIGHT: f32 = 220.0;
+pub const NET_EARNINGS_CHART_COLOR: RGBColor = RGBColor(76, 175, 80);
+pub const TODO_PLANNED_BAR_OPACITY: f64 = 0.4;
+pub const HEATMAP_LEVELS: usize = 4;
+pub const HEATMAP_WEEKS: u32 = 53;
 pub const MAX_X_VALUES: usize = 7;
 
 // Inspector
 pub const INSPECTOR_PADDING: u16 = 10;
 pub const INSPECTOR_SPACING: u32 = 12;
 pub const INSPECTOR_WIDTH: u32 = 250;
+pub const INSPECTOR_ROW_WIDTH: f32 = 260.0;
 pub const INSPECTOR_ALIGNMENT: Alignment = Alignment::Start;
+pub const INSPECTOR_NAME_INPUT_ID: &str = "inspector-name";
+pub const INSPECTOR_PROJECT_INPUT_ID: &str = "inspector-project";
+pub const INSPECTOR_TAGS_INPUT_ID: &str = "inspector-tags";
+pub const INSPECTOR_RATE_INPUT_ID: &str = "inspector-rate";
+pub const INSPECTOR_ESTIMATE_INPUT_ID: &str = "inspector-estimate";
+pub const INVOICE_NUMBER_INPUT_ID: &str = "invoice-number";
+pub const INVOICE_FROM_INPUT_ID: &str = "invoice-from";
+pub const INVOICE_CLIENT_INPUT_ID: &str = "invoice-client";
+pub const INVOICE_NOTES_INPUT_ID: &str = "invoice-notes";
 
 // Settings
 pub const SETTINGS_MESSAGE_DURATION: u64 = 8;
+pub const DEFAULT_STATUS_SERVER_PORT: u16 = 47600;
+pub const ROUNDING_INCREMENTS: [i64; 6] = [1, 5, 6, 10, 15, 30];
+pub const SYSTEM_THEME_CHECK_SECONDS: u64 = 60;
+pub const SCHEDULED_SUMMARY_CHECK_SECONDS: u64 = 60;
+pub const CURRENCY_INPUT_ID: &str = "settings-currency";
+pub const DEVICE_NAME_INPUT_ID: &str = "settings-device-name";
+pub const DEFAULT_TRASH_RETENTION_DAYS: u16 = 30;
+pub const TRASH_ITEMS_SHOWN: usize = 50;
+pub const GOAL_PROJECT_INPUT_ID: &str = "settings-goal-project";
+pub const PROFILE_NAME_INPUT_ID: &str = "settings-profile-name";
+pub const PROFILE_PATH_INPUT_ID: &str = "settings-profile-path";
+pub const FIND_INPUT_ID: &str = "settings-find";
+pub const REPLACE_INPUT_ID: &str = "settings-replace";
+pub const AUTO_STOP_TIME_FORMAT: &str = "%H:%M";
+
+// Power
+pub const POWER_CHECK_SECONDS: u64 = 5;
+pub const SLEEP_GAP_TOLERANCE_SECONDS: u64 = 30;
+
+// Single instance
+pub const NEW_INSTANCE_FLAG: &str = "--new-instance";
+pub const RAISE_CONNECT_ATTEMPTS: u32 = 10;
+pub const RAISE_CONNECT_RETRY_MILLIS: u64 = 200;
 
 // Sync
 pub const OFFICIAL_SERVER: &str = "https://sync.furtherance.app";
+pub const DEFAULT_SYNC_INTERVAL_MINUTES: u16 = 15;
+pub const MIN_SECONDS_BETWEEN_SYNCS: i64 = 60;
+pub const SYNC_HISTORY_KEPT: usize = 5;
+pub const SYNC_RETRY_BASE_SECONDS: u64 = 30;
+pub const SYNC_RETRY_MAX_SECONDS: u64 = 900;
+pub const SYNC_PROTOCOL_VERSION: u32 = 1;
+pub const CLOCK_SKEW_WARNING_SECONDS: i64 = 120;
+pub const DELETE_EVERYTHING_CONFIRMATION_WORD: &str = "DELETE";
+pub const SYNC_SERVER_INPUT_ID: &str = "sync-server";
+pub const SYNC_EMAIL_INPUT_ID: &str = "sync-email";
+pub const SYNC_KEY_INPUT_ID: &str = "sync-encryption-key";
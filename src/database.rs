@@ -16,24 +16,44 @@
 
 use chrono::DateTime;
 use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
 use chrono::TimeDelta;
 use chrono::TimeZone;
+use chrono::Utc;
 use chrono::offset::LocalResult;
-use rusqlite::{Connection, Result, backup, params};
+use itertools::Itertools;
+use rusqlite::types::Value;
+use rusqlite::{
+    Connection, OpenFlags, OptionalExtension, Result, backup, params, params_from_iter,
+};
+use std::collections::{BTreeSet, HashMap};
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::constants::{SYNC_HISTORY_KEPT, UPGRADE_PROGRESS_ROWS};
+use crate::helpers::clock_skew::sync_timestamp;
+use crate::helpers::search::search_terms;
+use crate::helpers::trash::{DeletedItem, DeletedItemKind};
 use crate::models::fur_shortcut;
 use crate::models::fur_task;
 use crate::models::fur_todo::FurTodo;
 use crate::models::fur_user::FurUser;
 use crate::models::{
-    fur_settings::FurSettings, fur_shortcut::FurShortcut, fur_task::FurTask,
+    fur_day_note::FurDayNote,
+    fur_goal::FurGoal,
+    fur_pomodoro::FurPomodoroSession,
+    fur_settings::FurSettings,
+    fur_shortcut::{FurShortcut, ShortcutRateChange, ShortcutUsage},
+    fur_sync_record::FurSyncRecord,
+    fur_task::FurTask,
     group_to_edit::GroupToEdit,
 };
-#[cfg(target_os = "macos")]
-use crate::view_enums::FurAlert;
+use crate::update::msg_helper_functions::{
+    add_or_remove_tag, normalize_shortcut_tags, normalize_tags, replace_tag,
+    unescape_task_input_text,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SortOrder {
@@ -118,8 +138,42 @@ fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool> {
 }
 
 pub fn db_init() -> Result<()> {
-    let conn = Connection::open(db_get_directory())?;
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    db_create_tables(&tx)?;
+    db_upgrade_old(&tx, &mut |_| {})?;
+    tx.commit()
+}
+
+/// How many task rows a database upgrade has rewritten so far
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpgradeProgress {
+    pub migrated: usize,
+    pub total: usize,
+}
+
+impl UpgradeProgress {
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.migrated as f32 / self.total as f32
+        }
+    }
+}
+
+/// Bring a database from an older version of Furtherance up to date. Everything runs
+/// in one transaction, so a failure partway through leaves the file as it was.
+pub fn db_upgrade_legacy(path: &Path, mut on_progress: impl FnMut(UpgradeProgress)) -> Result<()> {
+    let mut conn = Connection::open(path)?;
+    let tx = conn.transaction()?;
+    db_create_tables(&tx)?;
+    db_upgrade_old(&tx, &mut on_progress)?;
+    db_repair_legacy_rows(&tx)?;
+    tx.commit()
+}
 
+fn db_create_tables(conn: &Connection) -> Result<()> {
     conn.execute(
         "CREATE TABLE IF NOT EXISTS tasks (
             id INTEGER PRIMARY KEY,
@@ -132,7 +186,11 @@ pub fn db_init() -> Result<()> {
             currency TEXT,
             uid TEXT,
             is_deleted BOOLEAN DEFAULT 0,
-            last_updated INTEGER DEFAULT 0
+            last_updated INTEGER DEFAULT 0,
+            is_billable BOOLEAN DEFAULT 1,
+            device_name TEXT DEFAULT '',
+            planned_seconds INTEGER DEFAULT 0,
+            notes TEXT DEFAULT ''
         );",
         [],
     )?;
@@ -148,7 +206,9 @@ pub fn db_init() -> Result<()> {
             color_hex TEXT,
             uid TEXT,
             is_deleted BOOLEAN DEFAULT 0,
-            last_updated INTEGER DEFAULT 0
+            last_updated INTEGER DEFAULT 0,
+            device_name TEXT DEFAULT '',
+            sort_order INTEGER DEFAULT 0
         );",
         [],
     )?;
@@ -177,38 +237,240 @@ pub fn db_init() -> Result<()> {
             uid TEXT,
             is_completed BOOLEAN DEFAULT 0,
             is_deleted BOOLEAN DEFAULT 0,
+            last_updated INTEGER DEFAULT 0,
+            estimate INTEGER DEFAULT 0,
+            device_name TEXT DEFAULT '',
+            completed_at TIMESTAMP
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS goals (
+            id INTEGER PRIMARY KEY,
+            project TEXT NOT NULL,
+            weekly_minutes INTEGER NOT NULL,
+            uid TEXT,
+            is_deleted BOOLEAN DEFAULT 0,
+            last_updated INTEGER DEFAULT 0
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS day_notes (
+            id INTEGER PRIMARY KEY,
+            date TEXT NOT NULL UNIQUE,
+            text TEXT NOT NULL,
+            uid TEXT,
             last_updated INTEGER DEFAULT 0
         )",
         [],
     )?;
 
-    db_upgrade_old()?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS days_off (
+            date TEXT PRIMARY KEY
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS project_colors (
+            project TEXT PRIMARY KEY,
+            color_hex TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS project_default_tags (
+            project TEXT PRIMARY KEY,
+            tags TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS pomodoro_sessions (
+            id INTEGER PRIMARY KEY,
+            completed_at TIMESTAMP NOT NULL,
+            task_name TEXT NOT NULL,
+            length INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    // Device-local, so it isn't part of the synced shortcuts table
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS shortcut_uses (
+            id INTEGER PRIMARY KEY,
+            shortcut_uid TEXT NOT NULL,
+            used_at INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS shortcut_rate_changes (
+            id INTEGER PRIMARY KEY,
+            shortcut_uid TEXT NOT NULL,
+            old_rate REAL NOT NULL,
+            new_rate REAL NOT NULL,
+            changed_at INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS sync_history (
+            id INTEGER PRIMARY KEY,
+            synced_at INTEGER NOT NULL,
+            duration_millis INTEGER NOT NULL,
+            uploaded INTEGER NOT NULL,
+            downloaded INTEGER NOT NULL,
+            payload_bytes INTEGER NOT NULL
+        )",
+        [],
+    )?;
 
     Ok(())
 }
 
-pub fn db_upgrade_old() -> Result<()> {
-    let conn = Connection::open(db_get_directory())?;
+fn db_upgrade_old(conn: &Connection, on_progress: &mut dyn FnMut(UpgradeProgress)) -> Result<()> {
+    // Only generating uids and converting times touch every row, so they're what takes long
+    let mut progress = UpgradeProgress::default();
+    if !column_exists(conn, "tasks", "uid")? {
+        progress.total +=
+            conn.query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get::<_, i64>(0))? as usize;
+    }
+    progress.total += conn.query_row(
+        "SELECT COUNT(*) FROM tasks
+        WHERE start_time NOT LIKE '%+00:00' OR stop_time NOT LIKE '%+00:00'",
+        [],
+        |row| row.get::<_, i64>(0),
+    )? as usize;
+    if progress.total > 0 {
+        on_progress(progress);
+    }
+    let mut row_migrated = || {
+        progress.migrated += 1;
+        if progress.migrated % UPGRADE_PROGRESS_ROWS == 0 || progress.migrated == progress.total {
+            on_progress(progress);
+        }
+    };
 
-    if !column_exists(&conn, "tasks", "tags")? {
-        db_add_tags_column(&conn)?;
+    if !column_exists(conn, "tasks", "tags")? {
+        db_add_tags_column(conn)?;
+    }
+    if !column_exists(conn, "tasks", "project")? {
+        db_add_project_column(conn)?;
     }
-    if !column_exists(&conn, "tasks", "project")? {
-        db_add_project_column(&conn)?;
+    if !column_exists(conn, "tasks", "rate")? {
+        db_add_rate_column(conn)?;
     }
-    if !column_exists(&conn, "tasks", "rate")? {
-        db_add_rate_column(&conn)?;
+    if !column_exists(conn, "tasks", "currency")? {
+        db_add_currency_column(conn)?;
     }
-    if !column_exists(&conn, "tasks", "currency")? {
-        db_add_currency_column(&conn)?;
+    if !column_exists(conn, "tasks", "uid")? {
+        db_add_sync_columns(conn, &mut row_migrated)?;
+    }
+    if !column_exists(conn, "tasks", "is_billable")? {
+        db_add_billable_column(conn)?;
+    }
+    if !column_exists(conn, "todos", "estimate")? {
+        db_add_todo_estimate_column(conn)?;
+    }
+    db_add_device_name_columns(conn)?;
+    if !column_exists(conn, "shortcuts", "sort_order")? {
+        db_add_shortcut_sort_order_column(conn)?;
     }
-    if !column_exists(&conn, "tasks", "uid")? {
-        db_add_sync_columns(&conn)?;
+    if !column_exists(conn, "tasks", "planned_seconds")? {
+        db_add_planned_seconds_column(conn)?;
+    }
+    if !column_exists(conn, "tasks", "notes")? {
+        db_add_task_notes_column(conn)?;
+    }
+    if !column_exists(conn, "todos", "completed_at")? {
+        db_add_todo_completed_at_column(conn)?;
+    }
+    db_convert_task_times_to_utc(conn, &mut row_migrated)?;
+    db_create_last_updated_indexes(conn)?;
+
+    Ok(())
+}
+
+/// Lets the pending sync count skip straight to the changed rows. Older databases only
+/// get a `last_updated` column during the upgrade, so this runs after it.
+fn db_create_last_updated_indexes(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE INDEX IF NOT EXISTS tasks_last_updated ON tasks (last_updated);
+        CREATE INDEX IF NOT EXISTS shortcuts_last_updated ON shortcuts (last_updated);
+        CREATE INDEX IF NOT EXISTS todos_last_updated ON todos (last_updated);",
+    )
+}
+
+/// Old versions could leave values empty that are now required, and record a task
+/// that stopped before it started when the clock changed mid-task
+fn db_repair_legacy_rows(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "UPDATE tasks SET task_name = '' WHERE task_name IS NULL;
+        UPDATE tasks SET tags = '' WHERE tags IS NULL;
+        UPDATE tasks SET project = '' WHERE project IS NULL;
+        UPDATE tasks SET rate = 0.0 WHERE rate IS NULL;
+        UPDATE tasks SET is_billable = (rate > 0) WHERE is_billable IS NULL;
+        UPDATE tasks SET currency = '' WHERE currency IS NULL;
+        UPDATE tasks SET start_time = stop_time, stop_time = start_time
+            WHERE stop_time < start_time;",
+    )
+}
+
+/// Task times used to be stored with the local offset they were recorded in,
+/// which breaks range queries after a timezone or DST change. Rewrite them as UTC.
+pub fn db_convert_task_times_to_utc(
+    conn: &Connection,
+    row_migrated: &mut dyn FnMut(),
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id, start_time, stop_time FROM tasks
+        WHERE start_time NOT LIKE '%+00:00' OR stop_time NOT LIKE '%+00:00'",
+    )?;
+    let tasks: Vec<(i64, DateTime<Utc>, DateTime<Utc>)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, start_time, stop_time) in tasks {
+        conn.execute(
+            "UPDATE tasks SET start_time = ?1, stop_time = ?2 WHERE id = ?3",
+            params![start_time.to_rfc3339(), stop_time.to_rfc3339(), id],
+        )?;
+        row_migrated();
     }
 
     Ok(())
 }
 
+/// Task times are stored as UTC so they sort and compare correctly across timezones
+fn db_timestamp(time: &DateTime<Local>) -> String {
+    time.with_timezone(&Utc).to_rfc3339()
+}
+
+/// The stored form of the instant a local date begins
+fn local_day_start_timestamp(date: NaiveDate) -> String {
+    let midnight = date.and_time(NaiveTime::MIN);
+    let day_start = match Local.from_local_datetime(&midnight) {
+        LocalResult::Single(time) | LocalResult::Ambiguous(time, _) => time.with_timezone(&Utc),
+        // Midnight was skipped by a DST change, so the day starts an hour later
+        LocalResult::None => Local
+            .from_local_datetime(&(midnight + TimeDelta::hours(1)))
+            .earliest()
+            .map_or(Utc.from_utc_datetime(&midnight), |time| {
+                time.with_timezone(&Utc)
+            }),
+    };
+    day_start.to_rfc3339()
+}
+
 pub fn db_add_tags_column(conn: &Connection) -> Result<()> {
     conn.execute("ALTER TABLE tasks ADD COLUMN tags TEXT DEFAULT ''", [])?;
     Ok(())
@@ -226,28 +488,87 @@ pub fn db_add_rate_column(conn: &Connection) -> Result<()> {
 
 pub fn db_add_currency_column(conn: &Connection) -> Result<()> {
     conn.execute_batch(
-        "BEGIN;
-        ALTER TABLE tasks ADD COLUMN currency Text DEFAULT ''
-        UPDATE tasks SET currency = '' WHERE currency IS NULL;
-        COMMIT;",
+        "ALTER TABLE tasks ADD COLUMN currency Text DEFAULT '';
+        UPDATE tasks SET currency = '' WHERE currency IS NULL;",
+    )?;
+    Ok(())
+}
+
+pub fn db_add_todo_estimate_column(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "ALTER TABLE todos ADD COLUMN estimate INTEGER DEFAULT 0",
+        [],
+    )?;
+    Ok(())
+}
+
+pub fn db_add_todo_completed_at_column(conn: &Connection) -> Result<()> {
+    conn.execute("ALTER TABLE todos ADD COLUMN completed_at TIMESTAMP", [])?;
+    Ok(())
+}
+
+pub fn db_add_device_name_columns(conn: &Connection) -> Result<()> {
+    for table in ["tasks", "shortcuts", "todos"] {
+        if !column_exists(conn, table, "device_name")? {
+            conn.execute(
+                &format!(
+                    "ALTER TABLE {} ADD COLUMN device_name TEXT DEFAULT ''",
+                    table
+                ),
+                [],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub fn db_add_shortcut_sort_order_column(conn: &Connection) -> Result<()> {
+    // Existing shortcuts all start at 0 and keep sorting by name until reordered
+    conn.execute(
+        "ALTER TABLE shortcuts ADD COLUMN sort_order INTEGER DEFAULT 0",
+        [],
+    )?;
+    Ok(())
+}
+
+pub fn db_add_planned_seconds_column(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "ALTER TABLE tasks ADD COLUMN planned_seconds INTEGER DEFAULT 0",
+        [],
+    )?;
+    Ok(())
+}
+
+pub fn db_add_task_notes_column(conn: &Connection) -> Result<()> {
+    conn.execute("ALTER TABLE tasks ADD COLUMN notes TEXT DEFAULT ''", [])?;
+    Ok(())
+}
+
+pub fn db_add_billable_column(conn: &Connection) -> Result<()> {
+    // Existing tasks are billable if they were tracked with a rate
+    conn.execute_batch(
+        "ALTER TABLE tasks ADD COLUMN is_billable BOOLEAN DEFAULT 1;
+        UPDATE tasks SET is_billable = (rate > 0);",
     )?;
     Ok(())
 }
 
-pub fn db_add_sync_columns(conn: &Connection) -> Result<()> {
+pub fn db_add_sync_columns(conn: &Connection, row_migrated: &mut dyn FnMut()) -> Result<()> {
     if !column_exists(conn, "tasks", "uid")? {
         conn.execute("ALTER TABLE tasks ADD COLUMN uid TEXT", [])?;
         let mut stmt = conn
             .prepare("SELECT id, task_name, start_time, stop_time FROM tasks WHERE uid IS NULL")?;
-        let tasks: Vec<(i64, String, DateTime<Local>, DateTime<Local>)> = stmt
+        let tasks: Vec<(i64, Option<String>, DateTime<Local>, DateTime<Local>)> = stmt
             .query_map([], |row| {
                 Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         for (id, name, start_time, stop_time) in tasks {
-            let uid = fur_task::generate_task_uid(&name, &start_time, &stop_time);
+            let uid =
+                fur_task::generate_task_uid(&name.unwrap_or_default(), &start_time, &stop_time);
             conn.execute("UPDATE tasks SET uid = ?1 WHERE id = ?2", params![uid, id])?;
+            row_migrated();
         }
     }
 
@@ -323,19 +644,27 @@ pub fn db_insert_task(task: &FurTask) -> Result<()> {
             currency,
             uid,
             is_deleted,
-            last_updated
-        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            last_updated,
+            is_billable,
+            device_name,
+            planned_seconds,
+            notes
+        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
         params![
             task.name,
-            task.start_time.to_rfc3339(),
-            task.stop_time.to_rfc3339(),
+            db_timestamp(&task.start_time),
+            db_timestamp(&task.stop_time),
             task.tags,
             task.project,
             task.rate,
             task.currency,
             task.uid,
             task.is_deleted,
-            task.last_updated
+            task.last_updated,
+            task.is_billable,
+            task.device_name,
+            task.planned_seconds,
+            task.notes
         ],
     )?;
 
@@ -359,22 +688,30 @@ pub fn db_insert_tasks(tasks: &[FurTask]) -> Result<()> {
                 currency,
                 uid,
                 is_deleted,
-                last_updated
-            ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                last_updated,
+                is_billable,
+                device_name,
+                planned_seconds,
+                notes
+            ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
         )?;
 
         for task in tasks {
             stmt.execute(params![
                 task.name,
-                task.start_time.to_rfc3339(),
-                task.stop_time.to_rfc3339(),
+                db_timestamp(&task.start_time),
+                db_timestamp(&task.stop_time),
                 task.tags,
                 task.project,
                 task.rate,
                 task.currency,
                 task.uid,
                 task.is_deleted,
-                task.last_updated
+                task.last_updated,
+                task.is_billable,
+                task.device_name,
+                task.planned_seconds,
+                task.notes
             ])?;
         }
     }
@@ -415,6 +752,10 @@ pub fn db_retrieve_all_tasks(
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         };
         tasks_vec.push(fur_task);
     }
@@ -453,6 +794,10 @@ pub fn db_retrieve_all_existing_tasks(
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         };
         tasks_vec.push(fur_task);
     }
@@ -460,15 +805,59 @@ pub fn db_retrieve_all_existing_tasks(
     Ok(tasks_vec)
 }
 
+/// Retrieve tasks that started on or between the given local dates
 pub fn db_retrieve_tasks_by_date_range(
-    start_date: String,
-    end_date: String,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
 ) -> Result<Vec<FurTask>> {
     let conn = Connection::open(db_get_directory())?;
     let mut stmt = conn.prepare(
-        "SELECT * FROM tasks WHERE start_time BETWEEN ?1 AND ?2 AND is_deleted = 0 ORDER BY start_time ASC",
+        "SELECT * FROM tasks WHERE start_time >= ?1 AND start_time < ?2 AND is_deleted = 0 ORDER BY start_time ASC",
     )?;
-    let mut rows = stmt.query(params![start_date, end_date])?;
+    let mut rows = stmt.query(params![
+        local_day_start_timestamp(start_date),
+        local_day_start_timestamp(end_date + TimeDelta::days(1)),
+    ])?;
+
+    let mut tasks_vec: Vec<FurTask> = Vec::new();
+
+    while let Some(row) = rows.next()? {
+        let fur_task = FurTask {
+            name: row.get(1)?,
+            start_time: row.get(2)?,
+            stop_time: row.get(3)?,
+            tags: row.get(4)?,
+            project: row.get(5)?,
+            rate: row.get(6)?,
+            currency: row.get(7).unwrap_or(String::new()),
+            uid: row.get(8)?,
+            is_deleted: row.get(9)?,
+            last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
+        };
+        tasks_vec.push(fur_task);
+    }
+
+    Ok(tasks_vec)
+}
+
+/// The latest task for each distinct name, project, tags and rate, most recent first
+pub fn db_retrieve_recent_distinct_tasks(limit: usize) -> Result<Vec<FurTask>> {
+    let conn = Connection::open(db_get_directory())?;
+    // SQLite fills a bare column from the row that MAX picked
+    let mut stmt = conn.prepare(
+        "SELECT * FROM tasks WHERE id IN (
+            SELECT id FROM (
+                SELECT id, MAX(start_time) FROM tasks WHERE is_deleted = 0
+                GROUP BY task_name, LOWER(project), tags, rate, is_billable
+            )
+        )
+        ORDER BY start_time DESC LIMIT ?1",
+    )?;
+    let mut rows = stmt.query(params![limit as i64])?;
 
     let mut tasks_vec: Vec<FurTask> = Vec::new();
 
@@ -484,6 +873,10 @@ pub fn db_retrieve_tasks_by_date_range(
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         };
         tasks_vec.push(fur_task);
     }
@@ -501,13 +894,14 @@ pub fn db_retrieve_tasks_with_day_limit(
 
     // Construct the query string dynamically
     let query = format!(
-        "SELECT * FROM tasks WHERE start_time >= date('now', ?) AND is_deleted = 0 ORDER BY {} {}",
+        "SELECT * FROM tasks WHERE start_time >= ? AND is_deleted = 0 ORDER BY {} {}",
         sort.to_sqlite(),
         order.to_sqlite()
     );
 
+    let first_day = Local::now().date_naive() - TimeDelta::days(days - 1);
     let mut stmt = conn.prepare(&query)?;
-    let mut rows = stmt.query(params![format!("-{} days", days - 1)])?;
+    let mut rows = stmt.query(params![local_day_start_timestamp(first_day)])?;
 
     let mut tasks_vec: Vec<FurTask> = Vec::new();
 
@@ -523,6 +917,10 @@ pub fn db_retrieve_tasks_with_day_limit(
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         };
         tasks_vec.push(fur_task);
     }
@@ -530,6 +928,20 @@ pub fn db_retrieve_tasks_with_day_limit(
     Ok(tasks_vec)
 }
 
+/// The start of the most recent task that started before `date`
+pub fn db_retrieve_latest_task_start_before(date: NaiveDate) -> Result<Option<DateTime<Local>>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT start_time FROM tasks WHERE start_time < ?1 AND is_deleted = 0 ORDER BY start_time DESC LIMIT 1",
+    )?;
+    let mut rows = stmt.query(params![local_day_start_timestamp(date)])?;
+
+    match rows.next()? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
 pub fn db_retrieve_task_by_id(uid: &String) -> Result<Option<FurTask>> {
     let conn = Connection::open(db_get_directory())?;
     let mut stmt = conn.prepare("SELECT * FROM tasks WHERE uid = ?")?;
@@ -545,6 +957,10 @@ pub fn db_retrieve_task_by_id(uid: &String) -> Result<Option<FurTask>> {
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         })
     })?;
 
@@ -555,6 +971,79 @@ pub fn db_retrieve_task_by_id(uid: &String) -> Result<Option<FurTask>> {
     }
 }
 
+/// Retrieve the distinct project names used by existing tasks
+pub fn db_retrieve_all_project_names() -> Result<Vec<String>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT TRIM(project) FROM tasks
+        WHERE is_deleted = 0 AND TRIM(project) != ''
+        ORDER BY TRIM(project) COLLATE NOCASE",
+    )?;
+    let projects = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok(projects
+        .into_iter()
+        .unique_by(|project| project.to_lowercase())
+        .collect())
+}
+
+/// Tasks from all of history where every word of the query appears in the
+/// name, project or tags, newest first
+pub fn db_search_tasks(query: &str, limit: usize, offset: usize) -> Result<Vec<FurTask>> {
+    let terms = search_terms(query);
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let conditions = (1..=terms.len())
+        .map(|i| {
+            format!(
+                "(task_name LIKE ?{i} ESCAPE '\\' OR project LIKE ?{i} ESCAPE '\\' OR tags LIKE ?{i} ESCAPE '\\')"
+            )
+        })
+        .join(" AND ");
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT * FROM tasks WHERE is_deleted = 0 AND {conditions}
+        ORDER BY start_time DESC LIMIT {limit} OFFSET {offset}"
+    ))?;
+    let patterns = terms.iter().map(|term| {
+        format!(
+            "%{}%",
+            term.replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        )
+    });
+    let mut rows = stmt.query(params_from_iter(patterns))?;
+
+    let mut tasks_vec: Vec<FurTask> = Vec::new();
+
+    while let Some(row) = rows.next()? {
+        let fur_task = FurTask {
+            name: row.get(1)?,
+            start_time: row.get(2)?,
+            stop_time: row.get(3)?,
+            tags: row.get(4)?,
+            project: row.get(5)?,
+            rate: row.get(6)?,
+            currency: row.get(7).unwrap_or(String::new()),
+            uid: row.get(8)?,
+            is_deleted: row.get(9)?,
+            last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
+        };
+        tasks_vec.push(fur_task);
+    }
+
+    Ok(tasks_vec)
+}
+
 pub fn db_update_task(task: &FurTask) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
 
@@ -568,18 +1057,26 @@ pub fn db_update_task(task: &FurTask) -> Result<()> {
             rate = ?6,
             currency = ?7,
             is_deleted = ?8,
-            last_updated = ?9
-        WHERE uid = ?10",
+            last_updated = ?9,
+            is_billable = ?10,
+            device_name = ?11,
+            planned_seconds = ?12,
+            notes = ?13
+        WHERE uid = ?14",
         params![
             task.name,
-            task.start_time.to_rfc3339(),
-            task.stop_time.to_rfc3339(),
+            db_timestamp(&task.start_time),
+            db_timestamp(&task.stop_time),
             task.tags,
             task.project,
             task.rate,
             task.currency,
             task.is_deleted,
             task.last_updated,
+            task.is_billable,
+            task.device_name,
+            task.planned_seconds,
+            task.notes,
             task.uid,
         ],
     )?;
@@ -587,6 +1084,59 @@ pub fn db_update_task(task: &FurTask) -> Result<()> {
     Ok(())
 }
 
+/// Shorten the original task and insert the rest of it as a new task, together
+pub fn db_split_task(first_part: &FurTask, second_part: &FurTask) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+
+    tx.execute(
+        "UPDATE tasks SET stop_time = ?1, last_updated = ?2 WHERE uid = ?3",
+        params![
+            db_timestamp(&first_part.stop_time),
+            first_part.last_updated,
+            first_part.uid,
+        ],
+    )?;
+    tx.execute(
+        "INSERT INTO tasks (
+            task_name,
+            start_time,
+            stop_time,
+            tags,
+            project,
+            rate,
+            currency,
+            uid,
+            is_deleted,
+            last_updated,
+            is_billable,
+            device_name,
+            planned_seconds,
+            notes
+        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            second_part.name,
+            db_timestamp(&second_part.start_time),
+            db_timestamp(&second_part.stop_time),
+            second_part.tags,
+            second_part.project,
+            second_part.rate,
+            second_part.currency,
+            second_part.uid,
+            second_part.is_deleted,
+            second_part.last_updated,
+            second_part.is_billable,
+            second_part.device_name,
+            second_part.planned_seconds,
+            second_part.notes
+        ],
+    )?;
+
+    tx.commit()?;
+
+    Ok(())
+}
+
 pub fn db_update_group_of_tasks(group: &GroupToEdit) -> Result<()> {
     let mut conn = Connection::open(db_get_directory())?;
     // Transaction ensures all updates succeed or none do.
@@ -604,17 +1154,11 @@ pub fn db_update_group_of_tasks(group: &GroupToEdit) -> Result<()> {
 
         for uid in group.all_task_ids().iter() {
             stmt.execute(params![
-                group.new_name.trim(),
-                group
-                    .new_tags
-                    .trim()
-                    .strip_prefix('#')
-                    .unwrap_or(&group.tags)
-                    .trim()
-                    .to_string(),
+                unescape_task_input_text(group.new_name.trim()),
+                normalize_tags(&group.new_tags),
                 group.new_project.trim(),
-                group.new_rate.trim().parse::<f32>().unwrap_or(0.0),
-                chrono::Utc::now().timestamp(),
+                group.rate_to_save(),
+                sync_timestamp(),
                 uid,
             ])?;
         }
@@ -626,10 +1170,64 @@ pub fn db_update_group_of_tasks(group: &GroupToEdit) -> Result<()> {
     Ok(())
 }
 
-pub fn db_task_exists(task: &FurTask) -> Result<bool> {
-    let conn = Connection::open(db_get_directory())?;
+pub fn db_update_tasks_project(uids: &[String], project: &str) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    {
+        let mut stmt =
+            tx.prepare("UPDATE tasks SET project = ?1, last_updated = ?2 WHERE uid = ?3")?;
+        let now = sync_timestamp();
+        for uid in uids {
+            stmt.execute(params![project.trim(), now, uid])?;
+        }
+    }
+    tx.commit()?;
 
-    let query = "
+    Ok(())
+}
+
+/// Add or remove a single tag on every task in `uids`
+pub fn db_update_tasks_tags(uids: &[String], tag: &str, remove: bool) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    {
+        let mut select_stmt = tx.prepare("SELECT tags FROM tasks WHERE uid = ?1")?;
+        let mut update_stmt =
+            tx.prepare("UPDATE tasks SET tags = ?1, last_updated = ?2 WHERE uid = ?3")?;
+        let now = sync_timestamp();
+        for uid in uids {
+            let tags: String = select_stmt.query_row(params![uid], |row| row.get(0))?;
+            let new_tags = add_or_remove_tag(&tags, tag, remove);
+            if new_tags != tags {
+                update_stmt.execute(params![new_tags, now, uid])?;
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+pub fn db_update_tasks_rate(uids: &[String], rate: f32) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    {
+        let mut stmt =
+            tx.prepare("UPDATE tasks SET rate = ?1, last_updated = ?2 WHERE uid = ?3")?;
+        let now = sync_timestamp();
+        for uid in uids {
+            stmt.execute(params![rate, now, uid])?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+pub fn db_task_exists(task: &FurTask) -> Result<bool> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let query = "
         SELECT 1 FROM tasks
         WHERE task_name = ?1
         AND start_time = ?2
@@ -646,8 +1244,8 @@ pub fn db_task_exists(task: &FurTask) -> Result<bool> {
 
     let exists = stmt.exists(params![
         task.name,
-        task.start_time.to_rfc3339(),
-        task.stop_time.to_rfc3339(),
+        db_timestamp(&task.start_time),
+        db_timestamp(&task.stop_time),
         task.tags,
         task.project,
         task.rate,
@@ -658,9 +1256,32 @@ pub fn db_task_exists(task: &FurTask) -> Result<bool> {
     Ok(exists)
 }
 
+/// Whether a non-deleted task with this name and these exact times exists
+pub fn db_task_with_times_exists(
+    name: &str,
+    start_time: &DateTime<Local>,
+    stop_time: &DateTime<Local>,
+) -> Result<bool> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT 1 FROM tasks
+        WHERE task_name = ?1
+        AND start_time = ?2
+        AND stop_time = ?3
+        AND is_deleted = 0
+        LIMIT 1",
+    )?;
+
+    stmt.exists(params![
+        name,
+        db_timestamp(start_time),
+        db_timestamp(stop_time),
+    ])
+}
+
 pub fn db_delete_tasks_by_ids(id_list: &[String]) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
+    let now = sync_timestamp();
 
     for id in id_list {
         conn.execute(
@@ -685,8 +1306,13 @@ pub fn db_insert_shortcut(shortcut: &FurShortcut) -> Result<()> {
             color_hex,
             uid,
             is_deleted,
-            last_updated
-        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            last_updated,
+            device_name,
+            sort_order
+        ) values (
+            ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10,
+            (SELECT COALESCE(MAX(sort_order), -1) + 1 FROM shortcuts)
+        )",
         params![
             shortcut.name,
             shortcut.tags,
@@ -697,6 +1323,7 @@ pub fn db_insert_shortcut(shortcut: &FurShortcut) -> Result<()> {
             shortcut.uid,
             shortcut.is_deleted,
             shortcut.last_updated,
+            shortcut.device_name,
         ],
     )?;
 
@@ -707,7 +1334,7 @@ pub fn db_insert_shortcut(shortcut: &FurShortcut) -> Result<()> {
 pub fn db_retrieve_all_shortcuts() -> Result<Vec<FurShortcut>, rusqlite::Error> {
     let conn = Connection::open(db_get_directory())?;
 
-    let mut stmt = conn.prepare("SELECT * FROM shortcuts ORDER BY name")?;
+    let mut stmt = conn.prepare("SELECT * FROM shortcuts ORDER BY sort_order, name")?;
     let mut rows = stmt.query(params![])?;
 
     let mut shortcuts: Vec<FurShortcut> = Vec::new();
@@ -723,6 +1350,8 @@ pub fn db_retrieve_all_shortcuts() -> Result<Vec<FurShortcut>, rusqlite::Error>
             uid: row.get(7)?,
             is_deleted: row.get(8)?,
             last_updated: row.get(9)?,
+            device_name: row.get(10).unwrap_or_default(),
+            sort_order: row.get(11).unwrap_or_default(),
         };
         shortcuts.push(fur_shortcut);
     }
@@ -734,7 +1363,8 @@ pub fn db_retrieve_all_shortcuts() -> Result<Vec<FurShortcut>, rusqlite::Error>
 pub fn db_retrieve_existing_shortcuts() -> Result<Vec<FurShortcut>, rusqlite::Error> {
     let conn = Connection::open(db_get_directory())?;
 
-    let mut stmt = conn.prepare("SELECT * FROM shortcuts WHERE is_deleted = 0 ORDER BY name")?;
+    let mut stmt =
+        conn.prepare("SELECT * FROM shortcuts WHERE is_deleted = 0 ORDER BY sort_order, name")?;
     let mut rows = stmt.query(params![])?;
 
     let mut shortcuts: Vec<FurShortcut> = Vec::new();
@@ -750,6 +1380,8 @@ pub fn db_retrieve_existing_shortcuts() -> Result<Vec<FurShortcut>, rusqlite::Er
             uid: row.get(7)?,
             is_deleted: row.get(8)?,
             last_updated: row.get(9)?,
+            device_name: row.get(10).unwrap_or_default(),
+            sort_order: row.get(11).unwrap_or_default(),
         };
         shortcuts.push(fur_shortcut);
     }
@@ -769,8 +1401,9 @@ pub fn db_update_shortcut(shortcut: &FurShortcut) -> Result<()> {
             currency = (?5),
             color_hex = (?6),
             is_deleted = (?7),
-            last_updated = (?8)
-        WHERE uid = (?9)",
+            last_updated = (?8),
+            device_name = (?9)
+        WHERE uid = (?10)",
         params![
             shortcut.name,
             shortcut.tags,
@@ -780,6 +1413,7 @@ pub fn db_update_shortcut(shortcut: &FurShortcut) -> Result<()> {
             shortcut.color_hex,
             shortcut.is_deleted,
             shortcut.last_updated,
+            shortcut.device_name,
             shortcut.uid,
         ],
     )?;
@@ -828,6 +1462,8 @@ pub fn db_retrieve_shortcut_by_id(uid: &String) -> Result<Option<FurShortcut>> {
             uid: row.get(7)?,
             is_deleted: row.get(8)?,
             last_updated: row.get(9)?,
+            device_name: row.get(10).unwrap_or_default(),
+            sort_order: row.get(11).unwrap_or_default(),
         })
     })?;
 
@@ -840,34 +1476,538 @@ pub fn db_retrieve_shortcut_by_id(uid: &String) -> Result<Option<FurShortcut>> {
 
 pub fn db_delete_shortcut_by_id(uid: &str) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
+    let now = sync_timestamp();
 
     conn.execute(
         "UPDATE shortcuts SET is_deleted = 1, last_updated = ?1 WHERE uid = ?2",
         params![now, uid],
     )?;
+    conn.execute(
+        "DELETE FROM shortcut_uses WHERE shortcut_uid = ?1",
+        params![uid],
+    )?;
 
     Ok(())
 }
 
+/// Save the manual shortcut order. Not synced, so it doesn't touch last_updated.
+pub fn db_update_shortcut_order(uids: &[String]) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare("UPDATE shortcuts SET sort_order = ?1 WHERE uid = ?2")?;
+        for (index, uid) in uids.iter().enumerate() {
+            stmt.execute(params![index as i64, uid])?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+pub fn db_record_shortcut_use(uid: &str) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "INSERT INTO shortcut_uses (shortcut_uid, used_at) VALUES (?1, ?2)",
+        params![uid, chrono::Utc::now().timestamp()],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_insert_shortcut_rate_change(uid: &str, change: &ShortcutRateChange) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "INSERT INTO shortcut_rate_changes (shortcut_uid, old_rate, new_rate, changed_at)
+        VALUES (?1, ?2, ?3, ?4)",
+        params![uid, change.old_rate, change.new_rate, change.changed_at],
+    )?;
+
+    Ok(())
+}
+
+/// A shortcut's rate changes on this device, newest first
+pub fn db_retrieve_shortcut_rate_changes(uid: &str) -> Result<Vec<ShortcutRateChange>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT old_rate, new_rate, changed_at FROM shortcut_rate_changes
+        WHERE shortcut_uid = ?1 ORDER BY changed_at DESC, id DESC",
+    )?;
+    let rows = stmt.query_map(params![uid], |row| {
+        Ok(ShortcutRateChange {
+            old_rate: row.get(0)?,
+            new_rate: row.get(1)?,
+            changed_at: row.get(2)?,
+        })
+    })?;
+
+    let mut changes = Vec::new();
+    for row in rows {
+        changes.push(row?);
+    }
+
+    Ok(changes)
+}
+
+/// How often and how recently each shortcut was used on this device, by shortcut uid
+pub fn db_retrieve_shortcut_usage() -> Result<HashMap<String, ShortcutUsage>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT shortcut_uid, COUNT(*), MAX(used_at) FROM shortcut_uses GROUP BY shortcut_uid",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            ShortcutUsage {
+                count: row.get::<_, i64>(1)? as usize,
+                last_used: row.get(2)?,
+            },
+        ))
+    })?;
+
+    rows.collect()
+}
+
+/// Marks every synced record as deleted so the deletion reaches the server and other devices.
+/// Runs in a single transaction so a failure can't leave only some tables cleared.
 pub fn db_delete_everything() -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let now = sync_timestamp();
+
+    let tx = conn.transaction()?;
+    tx.execute(
+        "UPDATE tasks SET is_deleted = 1, last_updated = ?1",
+        params![now],
+    )?;
+    tx.execute(
+        "UPDATE shortcuts SET is_deleted = 1, last_updated = ?1",
+        params![now],
+    )?;
+    tx.execute(
+        "UPDATE todos SET is_deleted = 1, last_updated = ?1",
+        params![now],
+    )?;
+    tx.execute(
+        "UPDATE goals SET is_deleted = 1, last_updated = ?1",
+        params![now],
+    )?;
+    tx.execute("DELETE FROM day_notes", [])?;
+    tx.execute("DELETE FROM days_off", [])?;
+    tx.execute("DELETE FROM project_colors", [])?;
+    tx.execute("DELETE FROM project_default_tags", [])?;
+    tx.execute("DELETE FROM pomodoro_sessions", [])?;
+    tx.execute("DELETE FROM shortcut_uses", [])?;
+    tx.execute("DELETE FROM shortcut_rate_changes", [])?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Removes all local records without marking them deleted, leaving the server copy untouched
+pub fn db_delete_local_data() -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM tasks", [])?;
+    tx.execute("DELETE FROM shortcuts", [])?;
+    tx.execute("DELETE FROM todos", [])?;
+    tx.execute("DELETE FROM goals", [])?;
+    tx.execute("DELETE FROM day_notes", [])?;
+    tx.execute("DELETE FROM days_off", [])?;
+    tx.execute("DELETE FROM project_colors", [])?;
+    tx.execute("DELETE FROM project_default_tags", [])?;
+    tx.execute("DELETE FROM pomodoro_sessions", [])?;
+    tx.execute("DELETE FROM shortcut_uses", [])?;
+    tx.execute("DELETE FROM shortcut_rate_changes", [])?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Records that are copies of an older record, usually left behind by a sync glitch
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateUids {
+    pub tasks: Vec<String>,
+    pub shortcuts: Vec<String>,
+    pub todos: Vec<String>,
+}
+
+impl DuplicateUids {
+    pub fn count(&self) -> usize {
+        self.tasks.len() + self.shortcuts.len() + self.todos.len()
+    }
+}
+
+/// The uids of non-deleted rows that match an older row on every one of `columns`.
+/// The oldest row of each set of duplicates is never returned, so it is kept.
+fn find_duplicate_uids(conn: &Connection, table: &str, columns: &[&str]) -> Result<Vec<String>> {
+    let matching = columns
+        .iter()
+        .map(|column| format!("older.{column} IS duplicate.{column}"))
+        .join(" AND ");
+    let mut stmt = conn.prepare(&format!(
+        "SELECT duplicate.uid FROM {table} AS duplicate
+        WHERE duplicate.is_deleted = 0
+        AND EXISTS (
+            SELECT 1 FROM {table} AS older
+            WHERE older.is_deleted = 0
+            AND older.id < duplicate.id
+            AND older.uid IS NOT duplicate.uid
+            AND {matching}
+        )"
+    ))?;
+
+    let uids = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<String>>>()?;
+    Ok(uids)
+}
+
+pub fn db_find_duplicate_tasks() -> Result<Vec<String>> {
+    let conn = Connection::open(db_get_directory())?;
+    find_duplicate_uids(
+        &conn,
+        "tasks",
+        &[
+            "task_name",
+            "project",
+            "tags",
+            "rate",
+            "start_time",
+            "stop_time",
+        ],
+    )
+}
+
+pub fn db_find_duplicate_shortcuts() -> Result<Vec<String>> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
-
-    conn.execute_batch(&format!(
-        "
-            BEGIN TRANSACTION;
-            UPDATE tasks SET is_deleted = 1, last_updated = {};
-            UPDATE shortcuts SET is_deleted = 1, last_updated = {};
-            UPDATE todos SET is_deleted = 1, last_updated = {};
-            COMMIT;
-        ",
-        now, now, now
+    find_duplicate_uids(
+        &conn,
+        "shortcuts",
+        &["name", "project", "tags", "rate", "currency", "color_hex"],
+    )
+}
+
+pub fn db_find_duplicate_todos() -> Result<Vec<String>> {
+    let conn = Connection::open(db_get_directory())?;
+    find_duplicate_uids(
+        &conn,
+        "todos",
+        &[
+            "name",
+            "project",
+            "tags",
+            "rate",
+            "date",
+            "is_completed",
+            "estimate",
+        ],
+    )
+}
+
+pub fn db_find_duplicates() -> Result<DuplicateUids> {
+    Ok(DuplicateUids {
+        tasks: db_find_duplicate_tasks()?,
+        shortcuts: db_find_duplicate_shortcuts()?,
+        todos: db_find_duplicate_todos()?,
+    })
+}
+
+/// Marks `uids` deleted with a new last_updated so the deletion syncs
+fn soft_delete_uids(tx: &rusqlite::Transaction, table: &str, uids: &[String]) -> Result<()> {
+    let mut stmt = tx.prepare(&format!(
+        "UPDATE {table} SET is_deleted = 1, last_updated = ?1 WHERE uid = ?2"
     ))?;
+    let now = sync_timestamp();
+    for uid in uids {
+        stmt.execute(params![now, uid])?;
+    }
+    Ok(())
+}
+
+/// Soft deletes every duplicate in a single transaction
+pub fn db_soft_delete_duplicates(duplicates: &DuplicateUids) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    soft_delete_uids(&tx, "tasks", &duplicates.tasks)?;
+    soft_delete_uids(&tx, "shortcuts", &duplicates.shortcuts)?;
+    soft_delete_uids(&tx, "todos", &duplicates.todos)?;
+    tx.commit()
+}
+
+/// Soft-deleted tasks, todos and shortcuts deleted at or after `since`, newest first
+pub fn db_retrieve_deleted_items(since: i64) -> Result<Vec<DeletedItem>> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let mut items = Vec::new();
+    for kind in DeletedItemKind::ALL {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT uid, COALESCE({name}, ''), COALESCE(project, ''), last_updated FROM {table}
+            WHERE is_deleted = 1 AND last_updated >= ?1 AND uid IS NOT NULL",
+            name = kind.name_column(),
+            table = kind.table(),
+        ))?;
+        let rows = stmt.query_map(params![since], |row| {
+            Ok(DeletedItem {
+                kind,
+                uid: row.get(0)?,
+                name: row.get(1)?,
+                project: row.get(2)?,
+                deleted_at: row.get(3)?,
+            })
+        })?;
+        for item in rows {
+            items.push(item?);
+        }
+    }
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+
+    Ok(items)
+}
+
+/// Undeletes an item with a new last_updated so the restoration syncs
+pub fn db_restore_deleted_item(item: &DeletedItem) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        &format!(
+            "UPDATE {} SET is_deleted = 0, last_updated = ?1 WHERE uid = ?2",
+            item.kind.table()
+        ),
+        params![sync_timestamp(), item.uid],
+    )?;
+
+    Ok(())
+}
+
+/// Removes a deleted item's row for good. This only affects this device: the server
+/// and other synced devices keep their deleted copy and may send it back in a full sync.
+pub fn db_purge_deleted_item(item: &DeletedItem) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        &format!(
+            "DELETE FROM {} WHERE uid = ?1 AND is_deleted = 1",
+            item.kind.table()
+        ),
+        params![item.uid],
+    )?;
 
     Ok(())
 }
 
+/// Removes every deleted task, todo and shortcut last updated before `before`
+pub fn db_purge_deleted_before(before: i64) -> Result<usize> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    let mut purged = 0;
+    for kind in DeletedItemKind::ALL {
+        purged += tx.execute(
+            &format!(
+                "DELETE FROM {} WHERE is_deleted = 1 AND last_updated < ?1",
+                kind.table()
+            ),
+            params![before],
+        )?;
+    }
+    tx.commit()?;
+
+    Ok(purged)
+}
+
+fn normalize_table_tags(
+    tx: &rusqlite::Transaction,
+    table: &str,
+    normalize: fn(&str) -> String,
+) -> Result<usize> {
+    let rows = {
+        let mut stmt = tx.prepare(&format!(
+            "SELECT uid, COALESCE(tags, '') FROM {table} WHERE is_deleted = 0 AND uid IS NOT NULL"
+        ))?;
+        stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>>>()?
+    };
+
+    let mut stmt = tx.prepare(&format!(
+        "UPDATE {table} SET tags = ?1, last_updated = ?2 WHERE uid = ?3"
+    ))?;
+    let now = sync_timestamp();
+    let mut changed = 0;
+    for (uid, tags) in rows {
+        let normalized = normalize(&tags);
+        if normalized != tags {
+            stmt.execute(params![normalized, now, uid])?;
+            changed += 1;
+        }
+    }
+    Ok(changed)
+}
+
+/// Rewrite tags saved before they were normalized so they match and sync consistently.
+/// Returns the number of rows that changed.
+pub fn db_normalize_all_tags() -> Result<usize> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    let changed = normalize_table_tags(&tx, "tasks", normalize_tags)?
+        + normalize_table_tags(&tx, "todos", normalize_tags)?
+        + normalize_table_tags(&tx, "shortcuts", normalize_shortcut_tags)?;
+    tx.commit()?;
+    Ok(changed)
+}
+
+/// How many tasks, todos and shortcuts a find and replace matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenameCounts {
+    pub tasks: usize,
+    pub todos: usize,
+    pub shortcuts: usize,
+}
+
+impl RenameCounts {
+    pub fn total(&self) -> usize {
+        self.tasks + self.todos + self.shortcuts
+    }
+}
+
+/// Non-deleted rows whose `column` equals `value`, ignoring case and surrounding spaces
+fn count_column_matches(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    value: &str,
+) -> Result<usize> {
+    conn.query_row(
+        &format!(
+            "SELECT COUNT(*) FROM {table}
+            WHERE is_deleted = 0 AND LOWER(TRIM({column})) = LOWER(TRIM(?1))"
+        ),
+        params![value],
+        |row| row.get(0),
+    )
+}
+
+fn rename_column_matches(
+    tx: &rusqlite::Transaction,
+    table: &str,
+    column: &str,
+    old_value: &str,
+    new_value: &str,
+) -> Result<usize> {
+    tx.execute(
+        &format!(
+            "UPDATE {table} SET {column} = ?1, last_updated = ?2
+            WHERE is_deleted = 0 AND LOWER(TRIM({column})) = LOWER(TRIM(?3))"
+        ),
+        params![new_value.trim(), sync_timestamp(), old_value],
+    )
+}
+
+/// The uids and tags of non-deleted rows that have `tag`
+fn rows_with_tag(conn: &Connection, table: &str, tag: &str) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT uid, COALESCE(tags, '') FROM {table} WHERE is_deleted = 0 AND uid IS NOT NULL"
+    ))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(rows
+        .into_iter()
+        .filter(|(_, tags)| replace_tag(tags, tag, tag).is_some())
+        .collect())
+}
+
+fn rename_tag_in_table(
+    tx: &rusqlite::Transaction,
+    table: &str,
+    old_tag: &str,
+    new_tag: &str,
+    normalize: fn(&str) -> String,
+) -> Result<usize> {
+    let rows = rows_with_tag(tx, table, old_tag)?;
+    let mut stmt = tx.prepare(&format!(
+        "UPDATE {table} SET tags = ?1, last_updated = ?2 WHERE uid = ?3"
+    ))?;
+    let now = sync_timestamp();
+    for (uid, tags) in &rows {
+        let new_tags = replace_tag(tags, old_tag, new_tag).unwrap_or_else(|| tags.clone());
+        stmt.execute(params![normalize(&new_tags), now, uid])?;
+    }
+    Ok(rows.len())
+}
+
+pub fn db_count_project_matches(project: &str) -> Result<RenameCounts> {
+    let conn = Connection::open(db_get_directory())?;
+    Ok(RenameCounts {
+        tasks: count_column_matches(&conn, "tasks", "project", project)?,
+        todos: count_column_matches(&conn, "todos", "project", project)?,
+        shortcuts: count_column_matches(&conn, "shortcuts", "project", project)?,
+    })
+}
+
+pub fn db_count_tag_matches(tag: &str) -> Result<RenameCounts> {
+    let conn = Connection::open(db_get_directory())?;
+    Ok(RenameCounts {
+        tasks: rows_with_tag(&conn, "tasks", tag)?.len(),
+        todos: rows_with_tag(&conn, "todos", tag)?.len(),
+        shortcuts: rows_with_tag(&conn, "shortcuts", tag)?.len(),
+    })
+}
+
+pub fn db_count_task_name_matches(name: &str) -> Result<RenameCounts> {
+    let conn = Connection::open(db_get_directory())?;
+    Ok(RenameCounts {
+        tasks: count_column_matches(&conn, "tasks", "task_name", name)?,
+        todos: count_column_matches(&conn, "todos", "name", name)?,
+        shortcuts: count_column_matches(&conn, "shortcuts", "name", name)?,
+    })
+}
+
+/// Rename a project everywhere it's used. Matching ignores case but not the rest of the name.
+pub fn db_rename_project(old_project: &str, new_project: &str) -> Result<RenameCounts> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    let counts = RenameCounts {
+        tasks: rename_column_matches(&tx, "tasks", "project", old_project, new_project)?,
+        todos: rename_column_matches(&tx, "todos", "project", old_project, new_project)?,
+        shortcuts: rename_column_matches(&tx, "shortcuts", "project", old_project, new_project)?,
+    };
+    tx.commit()?;
+    Ok(counts)
+}
+
+/// Swap one tag for another on every task, todo and shortcut that has it
+pub fn db_rename_tag(old_tag: &str, new_tag: &str) -> Result<RenameCounts> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    let counts = RenameCounts {
+        tasks: rename_tag_in_table(&tx, "tasks", old_tag, new_tag, normalize_tags)?,
+        todos: rename_tag_in_table(&tx, "todos", old_tag, new_tag, normalize_tags)?,
+        shortcuts: rename_tag_in_table(
+            &tx,
+            "shortcuts",
+            old_tag,
+            new_tag,
+            normalize_shortcut_tags,
+        )?,
+    };
+    tx.commit()?;
+    Ok(counts)
+}
+
+pub fn db_rename_task_name(old_name: &str, new_name: &str) -> Result<RenameCounts> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+    let counts = RenameCounts {
+        tasks: rename_column_matches(&tx, "tasks", "task_name", old_name, new_name)?,
+        todos: rename_column_matches(&tx, "todos", "name", old_name, new_name)?,
+        shortcuts: rename_column_matches(&tx, "shortcuts", "name", old_name, new_name)?,
+    };
+    tx.commit()?;
+    Ok(counts)
+}
+
 pub fn db_backup(backup_file: PathBuf) -> Result<()> {
     let mut bkup_conn = Connection::open(backup_file)?;
     let conn = Connection::open(db_get_directory())?;
@@ -896,6 +2036,10 @@ pub fn db_retrieve_orphaned_tasks(task_uids: Vec<String>) -> Result<Vec<FurTask>
                     uid: row.get(8)?,
                     is_deleted: row.get(9)?,
                     last_updated: row.get(10)?,
+                    is_billable: row.get(11)?,
+                    device_name: row.get(12).unwrap_or_default(),
+                    planned_seconds: row.get(13).unwrap_or(0),
+                    notes: row.get(14).unwrap_or_default(),
                 })
             })?;
 
@@ -930,6 +2074,8 @@ pub fn db_retrieve_orphaned_shortcuts(shortcut_uids: Vec<String>) -> Result<Vec<
                     uid: row.get(7)?,
                     is_deleted: row.get(8)?,
                     last_updated: row.get(9)?,
+                    device_name: row.get(10).unwrap_or_default(),
+                    sort_order: row.get(11).unwrap_or_default(),
                 })
             })?;
 
@@ -965,6 +2111,9 @@ pub fn db_retrieve_orphaned_todos(todo_uids: Vec<String>) -> Result<Vec<FurTodo>
                     is_completed: row.get(8)?,
                     is_deleted: row.get(9)?,
                     last_updated: row.get(10)?,
+                    estimate: row.get(11).unwrap_or(0),
+                    device_name: row.get(12).unwrap_or_default(),
+                    completed_at: row.get(13).unwrap_or(None),
                 })
             })?;
 
@@ -1000,6 +2149,10 @@ pub fn db_retrieve_tasks_since_timestamp(timestamp: i64) -> Result<Vec<FurTask>,
             uid: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            is_billable: row.get(11)?,
+            device_name: row.get(12).unwrap_or_default(),
+            planned_seconds: row.get(13).unwrap_or(0),
+            notes: row.get(14).unwrap_or_default(),
         };
         tasks_vec.push(fur_task);
     }
@@ -1029,6 +2182,8 @@ pub fn db_retrieve_shortcuts_since_timestamp(
             uid: row.get(7)?,
             is_deleted: row.get(8)?,
             last_updated: row.get(9)?,
+            device_name: row.get(10).unwrap_or_default(),
+            sort_order: row.get(11).unwrap_or_default(),
         };
         shortcuts_vec.push(fur_shortcut);
     }
@@ -1158,7 +2313,7 @@ pub fn db_is_valid_v1(path: &Path) -> Result<bool> {
 }
 
 #[cfg(target_os = "macos")]
-pub fn db_check_for_existing_mac_db() -> Option<FurAlert> {
+pub fn db_check_for_existing_mac_db() -> bool {
     if let Some(user_dirs) = directories::UserDirs::new() {
         let mut path = user_dirs.home_dir().to_path_buf();
         path.extend(&[
@@ -1172,17 +2327,10 @@ pub fn db_check_for_existing_mac_db() -> Option<FurAlert> {
             "Furtherance.sqlite",
         ]);
         if path.exists() {
-            match db_mac_core_data_db_is_valid(&path) {
-                Ok(is_valid) => {
-                    if is_valid {
-                        return Some(FurAlert::ImportMacDatabase);
-                    }
-                }
-                Err(_) => return None,
-            }
+            return db_mac_core_data_db_is_valid(&path).unwrap_or(false);
         }
     }
-    None
+    false
 }
 
 #[cfg(target_os = "macos")]
@@ -1303,6 +2451,227 @@ pub fn db_import_old_mac_db() -> Result<()> {
     Ok(())
 }
 
+/// What merging another database into this one changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatabaseMergeSummary {
+    pub tasks_added: usize,
+    pub todos_added: usize,
+    pub shortcuts_added: usize,
+    /// Rows both databases had, where the other one's copy was edited more recently
+    pub newer_applied: usize,
+    /// Rows edited at the same moment in both databases but different, which are left alone
+    pub conflicts_skipped: usize,
+}
+
+/// The synced tables a merge combines, each with every column but the row id
+const MERGED_TABLES: [(&str, &[&str]); 3] = [
+    (
+        "tasks",
+        &[
+            "task_name",
+            "start_time",
+            "stop_time",
+            "tags",
+            "project",
+            "rate",
+            "currency",
+            "uid",
+            "is_deleted",
+            "last_updated",
+            "is_billable",
+            "device_name",
+            "planned_seconds",
+            "notes",
+        ],
+    ),
+    (
+        "todos",
+        &[
+            "name",
+            "project",
+            "tags",
+            "rate",
+            "currency",
+            "date",
+            "uid",
+            "is_completed",
+            "is_deleted",
+            "last_updated",
+            "estimate",
+            "device_name",
+            "completed_at",
+        ],
+    ),
+    (
+        "shortcuts",
+        &[
+            "name",
+            "tags",
+            "project",
+            "rate",
+            "currency",
+            "color_hex",
+            "uid",
+            "is_deleted",
+            "last_updated",
+            "device_name",
+            "sort_order",
+        ],
+    ),
+];
+
+/// Combine the tasks, todos and shortcuts of the database at `source` with the one at
+/// `target`. Rows only `source` has are added, and where both have a row the one edited
+/// last wins. `source` is read into a temporary copy that's brought up to date there,
+/// so the file itself is never changed, even when it's from an older version.
+pub fn db_merge_database(
+    source: &Path,
+    target: &Path,
+    mut on_progress: impl FnMut(UpgradeProgress),
+) -> Result<DatabaseMergeSummary> {
+    let copy = std::env::temp_dir().join(format!(
+        "furtherance-merge-{}-{}.db",
+        std::process::id(),
+        Utc::now().timestamp_millis()
+    ));
+    let result = db_copy_read_only(source, &copy)
+        .and_then(|_| db_upgrade_legacy(&copy, |_| {}))
+        .and_then(|_| db_merge_rows(&copy, target, &mut on_progress));
+    let _ = std::fs::remove_file(&copy);
+    result
+}
+
+fn db_copy_read_only(source: &Path, copy: &Path) -> Result<()> {
+    let source_conn = Connection::open_with_flags(source, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let mut copy_conn = Connection::open(copy)?;
+    let backup = backup::Backup::new(&source_conn, &mut copy_conn)?;
+    backup.run_to_completion(100, Duration::from_millis(10), None)
+}
+
+fn db_merge_rows(
+    source: &Path,
+    target: &Path,
+    on_progress: &mut dyn FnMut(UpgradeProgress),
+) -> Result<DatabaseMergeSummary> {
+    let mut conn = Connection::open(target)?;
+    conn.execute(
+        "ATTACH DATABASE ?1 AS merge_source",
+        params![source.to_string_lossy()],
+    )?;
+
+    let mut progress = UpgradeProgress::default();
+    for (table, _) in MERGED_TABLES {
+        let count: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM merge_source.{}", table),
+            [],
+            |row| row.get(0),
+        )?;
+        progress.total += count as usize;
+    }
+    on_progress(progress);
+
+    let mut summary = DatabaseMergeSummary::default();
+    let tx = conn.transaction()?;
+    for (table, columns) in MERGED_TABLES {
+        let column_list = columns.join(", ");
+        let uid_index = columns.iter().position(|c| *c == "uid").unwrap_or_default();
+        let deleted_index = columns
+            .iter()
+            .position(|c| *c == "is_deleted")
+            .unwrap_or_default();
+        let updated_index = columns
+            .iter()
+            .position(|c| *c == "last_updated")
+            .unwrap_or_default();
+
+        let mut source_stmt = tx.prepare(&format!(
+            "SELECT {} FROM merge_source.{}",
+            column_list, table
+        ))?;
+        let mut local_stmt = tx.prepare(&format!(
+            "SELECT {} FROM main.{} WHERE uid = ?1 LIMIT 1",
+            column_list, table
+        ))?;
+        let mut insert_stmt = tx.prepare(&format!(
+            "INSERT INTO main.{} ({}) VALUES ({})",
+            table,
+            column_list,
+            (1..=columns.len()).map(|i| format!("?{}", i)).join(", ")
+        ))?;
+        let mut update_stmt = tx.prepare(&format!(
+            "UPDATE main.{} SET {} WHERE uid = ?{}",
+            table,
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| format!("{} = ?{}", column, i + 1))
+                .join(", "),
+            uid_index + 1
+        ))?;
+
+        let mut rows = source_stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let values = (0..columns.len())
+                .map(|i| row.get::<_, Value>(i))
+                .collect::<Result<Vec<Value>>>()?;
+            progress.migrated += 1;
+            if progress.migrated % UPGRADE_PROGRESS_ROWS == 0 || progress.migrated == progress.total
+            {
+                on_progress(progress);
+            }
+
+            let Value::Text(uid) = &values[uid_index] else {
+                continue;
+            };
+            let local_values = local_stmt
+                .query_row(params![uid], |local| {
+                    (0..columns.len())
+                        .map(|i| local.get::<_, Value>(i))
+                        .collect::<Result<Vec<Value>>>()
+                })
+                .optional()?;
+
+            match local_values {
+                None => {
+                    // Nothing to delete here, so there's no use copying the deletion
+                    if value_as_i64(&values[deleted_index]) != 0 {
+                        continue;
+                    }
+                    insert_stmt.execute(params_from_iter(values.iter()))?;
+                    match table {
+                        "tasks" => summary.tasks_added += 1,
+                        "todos" => summary.todos_added += 1,
+                        _ => summary.shortcuts_added += 1,
+                    }
+                }
+                Some(local_values) if local_values != values => {
+                    let source_updated = value_as_i64(&values[updated_index]);
+                    let local_updated = value_as_i64(&local_values[updated_index]);
+                    if source_updated > local_updated {
+                        update_stmt.execute(params_from_iter(values.iter()))?;
+                        summary.newer_applied += 1;
+                    } else if source_updated == local_updated {
+                        summary.conflicts_skipped += 1;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    tx.commit()?;
+    on_progress(progress);
+
+    Ok(summary)
+}
+
+fn value_as_i64(value: &Value) -> i64 {
+    match value {
+        Value::Integer(i) => *i,
+        Value::Real(r) => *r as i64,
+        _ => 0,
+    }
+}
+
 fn core_data_timestamp_to_datetime(timestamp: f64) -> Result<DateTime<Local>> {
     let seconds = timestamp.trunc() as i64;
     // Core Data reference date is January 1, 2001
@@ -1407,6 +2776,9 @@ pub fn db_retrieve_all_todos() -> Result<Vec<FurTodo>, rusqlite::Error> {
             is_completed: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            estimate: row.get(11).unwrap_or(0),
+            device_name: row.get(12).unwrap_or_default(),
+            completed_at: row.get(13).unwrap_or(None),
         };
         todos.push(fur_todo);
     }
@@ -1439,6 +2811,9 @@ pub fn db_retrieve_todos_between_dates(
             is_completed: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            estimate: row.get(11).unwrap_or(0),
+            device_name: row.get(12).unwrap_or_default(),
+            completed_at: row.get(13).unwrap_or(None),
         };
         todo_vec.push(fur_todo);
     }
@@ -1446,6 +2821,48 @@ pub fn db_retrieve_todos_between_dates(
     Ok(todo_vec)
 }
 
+/// Todos planned or completed around `start_date` to `end_date`. Todo times keep the
+/// offset they were saved with, so a day either side is included and callers should
+/// check the local dates themselves.
+pub fn db_retrieve_todos_planned_or_completed_between(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<FurTodo>> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let mut stmt = conn.prepare(
+        "SELECT * FROM todos WHERE is_deleted = 0
+        AND ((date >= ?1 AND date < ?2) OR (completed_at >= ?1 AND completed_at < ?2))",
+    )?;
+    let mut rows = stmt.query(params![
+        (start_date - TimeDelta::days(1)).to_string(),
+        (end_date + TimeDelta::days(2)).to_string(),
+    ])?;
+
+    let mut todos: Vec<FurTodo> = Vec::new();
+
+    while let Some(row) = rows.next()? {
+        let fur_todo = FurTodo {
+            name: row.get(1)?,
+            project: row.get(2)?,
+            tags: row.get(3)?,
+            rate: row.get(4)?,
+            currency: row.get(5).unwrap_or(String::new()),
+            date: row.get(6)?,
+            uid: row.get(7)?,
+            is_completed: row.get(8)?,
+            is_deleted: row.get(9)?,
+            last_updated: row.get(10)?,
+            estimate: row.get(11).unwrap_or(0),
+            device_name: row.get(12).unwrap_or_default(),
+            completed_at: row.get(13).unwrap_or(None),
+        };
+        todos.push(fur_todo);
+    }
+
+    Ok(todos)
+}
+
 pub fn db_retrieve_todos_since_timestamp(timestamp: i64) -> Result<Vec<FurTodo>, rusqlite::Error> {
     let conn = Connection::open(db_get_directory())?;
 
@@ -1467,6 +2884,9 @@ pub fn db_retrieve_todos_since_timestamp(timestamp: i64) -> Result<Vec<FurTodo>,
             is_completed: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            estimate: row.get(11).unwrap_or(0),
+            device_name: row.get(12).unwrap_or_default(),
+            completed_at: row.get(13).unwrap_or(None),
         };
         todos.push(fur_todo);
     }
@@ -1489,6 +2909,9 @@ pub fn db_retrieve_todo_by_id(uid: &String) -> Result<Option<FurTodo>> {
             is_completed: row.get(8)?,
             is_deleted: row.get(9)?,
             last_updated: row.get(10)?,
+            estimate: row.get(11).unwrap_or(0),
+            device_name: row.get(12).unwrap_or_default(),
+            completed_at: row.get(13).unwrap_or(None),
         })
     })?;
 
@@ -1512,8 +2935,11 @@ pub fn db_update_todo(todo: &FurTodo) -> Result<()> {
             date = ?6,
             is_completed = ?7,
             is_deleted = ?8,
-            last_updated = ?9
-        WHERE uid = ?10",
+            last_updated = ?9,
+            estimate = ?10,
+            device_name = ?11,
+            completed_at = ?12
+        WHERE uid = ?13",
         params![
             todo.name,
             todo.project,
@@ -1524,6 +2950,10 @@ pub fn db_update_todo(todo: &FurTodo) -> Result<()> {
             todo.is_completed,
             todo.is_deleted,
             todo.last_updated,
+            todo.estimate,
+            todo.device_name,
+            todo.completed_at
+                .map(|completed_at| completed_at.to_rfc3339()),
             todo.uid,
         ],
     )?;
@@ -1545,8 +2975,11 @@ pub fn db_insert_todo(todo: &FurTodo) -> Result<()> {
             uid,
             is_completed,
             is_deleted,
-            last_updated
-        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            last_updated,
+            estimate,
+            device_name,
+            completed_at
+        ) values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
         params![
             todo.name,
             todo.project,
@@ -1557,35 +2990,63 @@ pub fn db_insert_todo(todo: &FurTodo) -> Result<()> {
             todo.uid,
             todo.is_completed,
             todo.is_deleted,
-            todo.last_updated
+            todo.last_updated,
+            todo.estimate,
+            todo.device_name,
+            todo.completed_at
+                .map(|completed_at| completed_at.to_rfc3339()),
         ],
     )?;
 
     Ok(())
 }
 
-pub fn db_toggle_todo_completed(uid: &str) -> Result<()> {
+/// Flips whether a todo is completed. `completed_at` should be empty when it's
+/// being marked incomplete.
+pub fn db_toggle_todo_completed(uid: &str, completed_at: Option<DateTime<Local>>) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
+    let now = sync_timestamp();
 
     conn.execute(
         "UPDATE todos SET
             is_completed = NOT is_completed,
-            last_updated = ?1
-        WHERE uid = ?2",
-        params![now, uid],
+            completed_at = ?1,
+            last_updated = ?2
+        WHERE uid = ?3",
+        params![
+            completed_at.map(|completed_at| completed_at.to_rfc3339()),
+            now,
+            uid
+        ],
     )?;
 
     Ok(())
 }
 
-pub fn db_set_todo_completed(uid: &str) -> Result<()> {
+/// Marks a todo completed, keeping the original completion time if it already was
+pub fn db_set_todo_completed(uid: &str, completed_at: DateTime<Local>) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
+    let now = sync_timestamp();
 
     conn.execute(
         "UPDATE todos SET
+            completed_at = CASE WHEN is_completed THEN completed_at ELSE ?1 END,
             is_completed = true,
+            last_updated = ?2
+        WHERE uid = ?3",
+        params![completed_at.to_rfc3339(), now, uid],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_delete_todo_by_id(uid: &str) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    let now = sync_timestamp();
+
+    conn.execute(
+        "UPDATE todos SET
+            is_deleted = 1,
             last_updated = ?1
         WHERE uid = ?2",
         params![now, uid],
@@ -1594,12 +3055,125 @@ pub fn db_set_todo_completed(uid: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn db_delete_todo_by_id(uid: &str) -> Result<()> {
+pub fn db_delete_todos_by_ids(id_list: &[String]) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let now = sync_timestamp();
+
+    let tx = conn.transaction()?;
+    for id in id_list {
+        tx.execute(
+            "UPDATE todos SET is_deleted = 1, last_updated = ?1 WHERE uid = ?2",
+            params![now, id],
+        )?;
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Insert a goal into the database, reviving a deleted goal for the same project
+pub fn db_insert_goal(goal: &FurGoal) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let updated = conn.execute(
+        "UPDATE goals SET
+            project = ?1,
+            weekly_minutes = ?2,
+            is_deleted = ?3,
+            last_updated = ?4
+        WHERE uid = ?5",
+        params![
+            goal.project,
+            goal.weekly_minutes,
+            goal.is_deleted,
+            goal.last_updated,
+            goal.uid,
+        ],
+    )?;
+
+    if updated == 0 {
+        conn.execute(
+            "INSERT INTO goals (
+                project,
+                weekly_minutes,
+                uid,
+                is_deleted,
+                last_updated
+            ) values (?1, ?2, ?3, ?4, ?5)",
+            params![
+                goal.project,
+                goal.weekly_minutes,
+                goal.uid,
+                goal.is_deleted,
+                goal.last_updated,
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Retrieve all existing (not deleted) goals from the database
+pub fn db_retrieve_existing_goals() -> Result<Vec<FurGoal>> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let mut stmt =
+        conn.prepare("SELECT * FROM goals WHERE is_deleted = 0 ORDER BY project COLLATE NOCASE")?;
+    let mut rows = stmt.query(params![])?;
+
+    let mut goals: Vec<FurGoal> = Vec::new();
+
+    while let Some(row) = rows.next()? {
+        let fur_goal = FurGoal {
+            project: row.get(1)?,
+            weekly_minutes: row.get(2)?,
+            uid: row.get(3)?,
+            is_deleted: row.get(4)?,
+            last_updated: row.get(5)?,
+        };
+        goals.push(fur_goal);
+    }
+
+    Ok(goals)
+}
+
+pub fn db_goal_exists(project: &str) -> Result<bool> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let mut stmt = conn.prepare(
+        "SELECT 1 FROM goals WHERE project = ?1 COLLATE NOCASE AND is_deleted = 0 LIMIT 1",
+    )?;
+    stmt.exists(params![project])
+}
+
+pub fn db_update_goal(goal: &FurGoal) -> Result<()> {
     let conn = Connection::open(db_get_directory())?;
-    let now = chrono::Utc::now().timestamp();
 
     conn.execute(
-        "UPDATE todos SET
+        "UPDATE goals SET
+            project = ?1,
+            weekly_minutes = ?2,
+            is_deleted = ?3,
+            last_updated = ?4
+        WHERE uid = ?5",
+        params![
+            goal.project,
+            goal.weekly_minutes,
+            goal.is_deleted,
+            goal.last_updated,
+            goal.uid,
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_delete_goal_by_id(uid: &str) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    let now = sync_timestamp();
+
+    conn.execute(
+        "UPDATE goals SET
             is_deleted = 1,
             last_updated = ?1
         WHERE uid = ?2",
@@ -1608,3 +3182,290 @@ pub fn db_delete_todo_by_id(uid: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Save a day note, removing it instead when the text is empty
+pub fn db_save_day_note(note: &FurDayNote) -> Result<()> {
+    if note.text.trim().is_empty() {
+        return db_delete_day_note(&note.date);
+    }
+
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "INSERT INTO day_notes (date, text, uid, last_updated)
+        VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT(date) DO UPDATE SET
+            text = excluded.text,
+            last_updated = excluded.last_updated",
+        params![
+            note.date.format("%Y-%m-%d").to_string(),
+            note.text.trim(),
+            note.uid,
+            note.last_updated,
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_retrieve_day_note(date: &NaiveDate) -> Result<Option<FurDayNote>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt =
+        conn.prepare("SELECT date, text, uid, last_updated FROM day_notes WHERE date = ?1")?;
+    let mut rows = stmt.query(params![date.format("%Y-%m-%d").to_string()])?;
+
+    match rows.next()? {
+        Some(row) => Ok(Some(day_note_from_row(row)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn db_retrieve_all_day_notes() -> Result<Vec<FurDayNote>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt =
+        conn.prepare("SELECT date, text, uid, last_updated FROM day_notes ORDER BY date")?;
+    let mut rows = stmt.query(params![])?;
+
+    let mut notes: Vec<FurDayNote> = Vec::new();
+    while let Some(row) = rows.next()? {
+        notes.push(day_note_from_row(row)?);
+    }
+
+    Ok(notes)
+}
+
+pub fn db_delete_day_note(date: &NaiveDate) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "DELETE FROM day_notes WHERE date = ?1",
+        params![date.format("%Y-%m-%d").to_string()],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_insert_day_off(date: &NaiveDate) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "INSERT OR IGNORE INTO days_off (date) VALUES (?1)",
+        params![date.format("%Y-%m-%d").to_string()],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_delete_day_off(date: &NaiveDate) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "DELETE FROM days_off WHERE date = ?1",
+        params![date.format("%Y-%m-%d").to_string()],
+    )?;
+
+    Ok(())
+}
+
+pub fn db_retrieve_days_off() -> Result<BTreeSet<NaiveDate>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare("SELECT date FROM days_off")?;
+    let mut rows = stmt.query(params![])?;
+
+    let mut days_off = BTreeSet::new();
+    while let Some(row) = rows.next()? {
+        let date: String = row.get(0)?;
+        days_off.insert(NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })?);
+    }
+
+    Ok(days_off)
+}
+
+/// Retrieve every project's color as (project, hex) pairs
+pub fn db_retrieve_project_colors() -> Result<Vec<(String, String)>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare("SELECT project, color_hex FROM project_colors")?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    let mut colors = Vec::new();
+    for row in rows {
+        colors.push(row?);
+    }
+
+    Ok(colors)
+}
+
+pub fn db_set_project_color(project: &str, color_hex: &str) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    conn.execute(
+        "INSERT INTO project_colors (project, color_hex)
+        VALUES (?1, ?2)
+        ON CONFLICT(project) DO UPDATE SET color_hex = excluded.color_hex",
+        params![project, color_hex],
+    )?;
+
+    Ok(())
+}
+
+/// Retrieve every project's default tags as (project, tags) pairs
+pub fn db_retrieve_project_default_tags() -> Result<Vec<(String, String)>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare("SELECT project, tags FROM project_default_tags")?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    let mut default_tags = Vec::new();
+    for row in rows {
+        default_tags.push(row?);
+    }
+
+    Ok(default_tags)
+}
+
+/// Save a project's default tags. Empty tags remove the project's defaults.
+pub fn db_set_project_default_tags(project: &str, tags: &str) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+    if tags.trim().is_empty() {
+        conn.execute(
+            "DELETE FROM project_default_tags WHERE project = ?1",
+            params![project],
+        )?;
+    } else {
+        conn.execute(
+            "INSERT INTO project_default_tags (project, tags)
+            VALUES (?1, ?2)
+            ON CONFLICT(project) DO UPDATE SET tags = excluded.tags",
+            params![project, tags],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn day_note_from_row(row: &rusqlite::Row) -> Result<FurDayNote> {
+    let date: String = row.get(0)?;
+    Ok(FurDayNote {
+        date: NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })?,
+        text: row.get(1)?,
+        uid: row.get(2)?,
+        last_updated: row.get(3)?,
+    })
+}
+
+pub fn db_insert_pomodoro_session(session: &FurPomodoroSession) -> Result<()> {
+    let conn = Connection::open(db_get_directory())?;
+
+    conn.execute(
+        "INSERT INTO pomodoro_sessions (
+            completed_at,
+            task_name,
+            length
+        ) VALUES (?1, ?2, ?3)",
+        params![session.completed_at, session.task_name, session.length],
+    )?;
+
+    Ok(())
+}
+
+/// Counts the pomodoro sessions completed from the start of `start_date` to the end of `end_date`
+pub fn db_count_pomodoro_sessions(start_date: NaiveDate, end_date: NaiveDate) -> Result<usize> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pomodoro_sessions WHERE completed_at >= ?1 AND completed_at < ?2",
+        params![
+            local_day_start_timestamp(start_date),
+            local_day_start_timestamp(end_date + TimeDelta::days(1)),
+        ],
+        |row| row.get(0),
+    )?;
+
+    Ok(count as usize)
+}
+
+/// Every local date with at least one completed pomodoro session
+pub fn db_retrieve_pomodoro_session_days() -> Result<BTreeSet<NaiveDate>> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let mut stmt = conn.prepare("SELECT completed_at FROM pomodoro_sessions")?;
+    let mut rows = stmt.query(params![])?;
+
+    let mut days = BTreeSet::new();
+    while let Some(row) = rows.next()? {
+        let completed_at: DateTime<Local> = row.get(0)?;
+        days.insert(completed_at.date_naive());
+    }
+
+    Ok(days)
+}
+
+/// Records a finished sync, keeping only the most recent ones
+pub fn db_insert_sync_record(record: &FurSyncRecord) -> Result<()> {
+    let mut conn = Connection::open(db_get_directory())?;
+    let tx = conn.transaction()?;
+
+    tx.execute(
+        "INSERT INTO sync_history (
+            synced_at,
+            duration_millis,
+            uploaded,
+            downloaded,
+            payload_bytes
+        ) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            record.synced_at,
+            record.duration_millis,
+            record.uploaded as i64,
+            record.downloaded as i64,
+            record.payload_bytes as i64,
+        ],
+    )?;
+    tx.execute(
+        "DELETE FROM sync_history WHERE id NOT IN
+            (SELECT id FROM sync_history ORDER BY synced_at DESC, id DESC LIMIT ?1)",
+        params![SYNC_HISTORY_KEPT as i64],
+    )?;
+
+    tx.commit()
+}
+
+/// The most recent syncs from this device, newest first
+pub fn db_retrieve_sync_history() -> Result<Vec<FurSyncRecord>> {
+    let conn = Connection::open(db_get_directory())?;
+    let mut stmt = conn.prepare(
+        "SELECT synced_at, duration_millis, uploaded, downloaded, payload_bytes
+        FROM sync_history ORDER BY synced_at DESC, id DESC LIMIT ?1",
+    )?;
+
+    let rows = stmt.query_map(params![SYNC_HISTORY_KEPT as i64], |row| {
+        Ok(FurSyncRecord {
+            synced_at: row.get(0)?,
+            duration_millis: row.get(1)?,
+            uploaded: row.get::<_, i64>(2)? as usize,
+            downloaded: row.get::<_, i64>(3)? as usize,
+            payload_bytes: row.get::<_, i64>(4)? as usize,
+        })
+    })?;
+
+    let mut history = Vec::new();
+    for row in rows {
+        history.push(row?);
+    }
+
+    Ok(history)
+}
+
+/// How many tasks, shortcuts and todos the next sync will send. Each count is a
+/// range scan on the `last_updated` index.
+pub fn db_count_changes_since(timestamp: i64) -> Result<usize> {
+    let conn = Connection::open(db_get_directory())?;
+
+    let count: i64 = conn.query_row(
+        "SELECT (SELECT COUNT(*) FROM tasks WHERE last_updated >= ?1)
+            + (SELECT COUNT(*) FROM shortcuts WHERE last_updated >= ?1)
+            + (SELECT COUNT(*) FROM todos WHERE last_updated >= ?1)",
+        params![timestamp],
+        |row| row.get(0),
+    )?;
+
+    Ok(count as usize)
+}
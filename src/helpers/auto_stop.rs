@@ -0,0 +1,62 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use chrono::{DateTime, Local, NaiveTime, TimeZone};
+
+use crate::{constants::AUTO_STOP_TIME_FORMAT, models::fur_settings::FurSettings};
+
+/// The time of day running timers stop themselves at, if auto-stop is on
+pub fn auto_stop_time(settings: &FurSettings) -> Option<NaiveTime> {
+    if !settings.auto_stop_enabled {
+        return None;
+    }
+    parse_auto_stop_time(&settings.auto_stop_time)
+}
+
+pub fn parse_auto_stop_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value, AUTO_STOP_TIME_FORMAT).ok()
+}
+
+pub fn format_auto_stop_time(time: NaiveTime) -> String {
+    time.format(AUTO_STOP_TIME_FORMAT).to_string()
+}
+
+/// The first time a timer started at `start` reaches `stop_at`. A day where
+/// `stop_at` doesn't exist because of a clock change is skipped.
+pub fn next_auto_stop(start: DateTime<Local>, stop_at: NaiveTime) -> Option<DateTime<Local>> {
+    let mut date = start.date_naive();
+    for _ in 0..3 {
+        if let Some(boundary) = Local
+            .from_local_datetime(&date.and_time(stop_at))
+            .earliest()
+            && boundary > start
+        {
+            return Some(boundary);
+        }
+        date = date.succ_opt()?;
+    }
+    None
+}
+
+/// When a timer that ran from `start` until `now` should have stopped, if it
+/// has passed the auto-stop time
+pub fn auto_stop_due(
+    start: DateTime<Local>,
+    now: DateTime<Local>,
+    stop_at: NaiveTime,
+) -> Option<DateTime<Local>> {
+    next_auto_stop(start, stop_at).filter(|boundary| *boundary <= now)
+}
@@ -0,0 +1,70 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::constants::CLOCK_SKEW_WARNING_SECONDS;
+
+/// How far the sync server's clock is ahead of this one. Kept outside the state
+/// so last_updated can be stamped wherever a record changes.
+static CLOCK_OFFSET_SECONDS: AtomicI64 = AtomicI64::new(0);
+
+/// How far `server_time` is ahead of `local_time`. Negative when this clock is ahead.
+pub fn measure_skew(server_time: DateTime<Utc>, local_time: DateTime<Utc>) -> TimeDelta {
+    server_time - local_time
+}
+
+pub fn is_significant_skew(skew: TimeDelta) -> bool {
+    skew.num_seconds().abs() > CLOCK_SKEW_WARNING_SECONDS
+}
+
+/// The size of the skew to the nearest minute, whichever clock is ahead
+pub fn skew_minutes(skew: TimeDelta) -> i64 {
+    (skew.num_seconds().abs() + 30) / 60
+}
+
+/// The server's time from an HTTP Date header, e.g. "Sun, 06 Nov 1994 08:49:37 GMT"
+pub fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value.trim())
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// Skews under the warning threshold are left alone so network delays
+/// don't nudge timestamps back and forth on every sync
+pub fn set_clock_offset(skew: TimeDelta) {
+    let offset = if is_significant_skew(skew) {
+        skew.num_seconds()
+    } else {
+        0
+    };
+    CLOCK_OFFSET_SECONDS.store(offset, Ordering::Relaxed);
+}
+
+pub fn clock_offset() -> TimeDelta {
+    TimeDelta::seconds(CLOCK_OFFSET_SECONDS.load(Ordering::Relaxed))
+}
+
+pub fn corrected_timestamp(local_timestamp: i64, offset: TimeDelta) -> i64 {
+    local_timestamp.saturating_add(offset.num_seconds())
+}
+
+/// The last_updated value for a record changed now, in the sync server's time
+pub fn sync_timestamp() -> i64 {
+    corrected_timestamp(Utc::now().timestamp(), clock_offset())
+}
@@ -15,7 +15,7 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use iced::Color;
-use palette::Srgb;
+use palette::{Srgb, color_difference::Wcag21RelativeContrast};
 use rand::Rng;
 use std::num::ParseIntError;
 
@@ -114,3 +114,8 @@ impl ToSrgb for Color {
         Srgb::new(self.r, self.g, self.b)
     }
 }
+
+/// Whether white text reads better than black text on this color
+pub fn is_dark_color(color: Srgb) -> bool {
+    color.relative_luminance().luma < 0.6
+}
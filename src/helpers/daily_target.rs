@@ -0,0 +1,98 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::models::{fur_days_off::FurDaysOff, fur_settings::FurSettings};
+
+/// How a day's tracked time compares with the daily target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetProgress {
+    Met,
+    Partial,
+    Empty,
+}
+
+/// A number of minutes to track each day. Zero turns the target off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyTarget {
+    pub minutes: i64,
+    pub weekends: bool,
+}
+
+impl DailyTarget {
+    pub fn from_settings(settings: &FurSettings) -> Self {
+        DailyTarget {
+            minutes: settings.daily_target_minutes,
+            weekends: settings.target_applies_to_weekends,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.minutes > 0
+    }
+
+    /// Whether the target counts on `date`. Days marked off never count.
+    pub fn applies_to(&self, date: &NaiveDate, days_off: &FurDaysOff) -> bool {
+        self.is_enabled()
+            && !days_off.is_marked(date)
+            && (self.weekends || !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+
+    /// None when the target doesn't apply to `date`
+    pub fn progress(
+        &self,
+        date: &NaiveDate,
+        tracked_seconds: i64,
+        days_off: &FurDaysOff,
+    ) -> Option<TargetProgress> {
+        if !self.applies_to(date, days_off) {
+            None
+        } else if tracked_seconds >= self.minutes * 60 {
+            Some(TargetProgress::Met)
+        } else if tracked_seconds > 0 {
+            Some(TargetProgress::Partial)
+        } else {
+            Some(TargetProgress::Empty)
+        }
+    }
+
+    /// How many days from `start` to `end` met the target, and how many it applied to.
+    /// Days after `today` haven't happened yet, so they aren't counted.
+    pub fn days_met(
+        &self,
+        day_totals: &BTreeMap<NaiveDate, i64>,
+        start: NaiveDate,
+        end: NaiveDate,
+        today: NaiveDate,
+        days_off: &FurDaysOff,
+    ) -> (usize, usize) {
+        start
+            .iter_days()
+            .take_while(|date| *date <= end.min(today))
+            .filter(|date| self.applies_to(date, days_off))
+            .fold((0, 0), |(met, counted), date| {
+                let tracked = day_totals.get(&date).copied().unwrap_or(0);
+                if tracked >= self.minutes * 60 {
+                    (met + 1, counted + 1)
+                } else {
+                    (met, counted + 1)
+                }
+            })
+    }
+}
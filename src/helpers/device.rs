@@ -0,0 +1,42 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::RwLock;
+
+use sysinfo::System;
+
+/// Kept outside the settings so tasks, todos and shortcuts can be stamped
+/// wherever they're created without passing the settings along
+static DEVICE_NAME: RwLock<String> = RwLock::new(String::new());
+
+/// The default device name
+pub fn hostname() -> String {
+    System::host_name().unwrap_or_default()
+}
+
+/// The device name new tasks, todos and shortcuts are created with
+pub fn current_device_name() -> String {
+    DEVICE_NAME
+        .read()
+        .map(|name| name.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_current_device_name(name: &str) {
+    if let Ok(mut device_name) = DEVICE_NAME.write() {
+        *device_name = name.trim().to_string();
+    }
+}
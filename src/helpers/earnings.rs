@@ -0,0 +1,88 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+
+use crate::models::{fur_settings::FurSettings, fur_task::FurTask, fur_task_group::FurTaskGroup};
+
+/// What is left of `gross` after taking off `deduction_percent` percent.
+/// Only used to preview earnings, the stored tasks always keep the gross amount.
+pub fn net_earnings(gross: f32, deduction_percent: f32) -> f32 {
+    gross * (1.0 - deduction_percent.clamp(0.0, 100.0) / 100.0)
+}
+
+/// The deduction to preview net earnings with, or `None` when they aren't shown
+pub fn net_earnings_deduction(settings: &FurSettings) -> Option<f32> {
+    settings
+        .show_net_earnings
+        .then_some(settings.deduction_percent)
+}
+
+/// Reads a comma-separated list such as "#nonbill, @Internal" into the form kept in
+/// the settings. Entries without a leading `@` are taken as tags.
+pub fn parse_earnings_exclusions(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .filter_map(|entry| {
+            let entry = entry.trim();
+            if let Some(project) = entry.strip_prefix('@') {
+                let project = project.trim().to_lowercase();
+                (!project.is_empty()).then(|| format!("@{}", project))
+            } else {
+                let tag = entry.trim_start_matches('#').trim().to_lowercase();
+                (!tag.is_empty()).then(|| format!("#{}", tag))
+            }
+        })
+        .unique()
+        .collect()
+}
+
+/// Whether work with these tags and project is left out of earnings
+pub fn is_excluded_from_earnings(tags: &str, project: &str, exclusions: &[String]) -> bool {
+    exclusions.iter().any(|exclusion| {
+        if let Some(excluded_project) = exclusion.strip_prefix('@') {
+            project.trim().eq_ignore_ascii_case(excluded_project)
+        } else {
+            let excluded_tag = exclusion.trim_start_matches('#');
+            tags.split('#')
+                .any(|tag| tag.trim().eq_ignore_ascii_case(excluded_tag))
+        }
+    })
+}
+
+/// What `task` earned over `seconds`, or nothing if it's excluded from earnings.
+/// Everything that adds up earnings goes through this, so time totals never change.
+pub fn task_earnings(task: &FurTask, seconds: i64, exclusions: &[String]) -> f32 {
+    if is_excluded_from_earnings(&task.tags, &task.project, exclusions) {
+        0.0
+    } else {
+        task.earnings_for(seconds)
+    }
+}
+
+/// Whether a group's tags or project are excluded from earnings
+pub fn group_is_excluded_from_earnings(group: &FurTaskGroup, exclusions: &[String]) -> bool {
+    is_excluded_from_earnings(&group.tags, &group.project, exclusions)
+}
+
+/// Like `task_earnings`, for a group of tasks that share tags and a project
+pub fn group_earnings(group: &FurTaskGroup, seconds: i64, exclusions: &[String]) -> f32 {
+    if group_is_excluded_from_earnings(group, exclusions) {
+        0.0
+    } else {
+        group.earnings_for(seconds)
+    }
+}
@@ -0,0 +1,59 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+
+use crate::constants::MAX_LOGGED_ERRORS;
+
+/// Writes an error to stderr and keeps it for the in-app error log. Only the
+/// newest entries are kept, and nothing is saved between sessions.
+pub fn log_error(errors: &mut VecDeque<(DateTime<Local>, String)>, message: String) {
+    log_error_at(errors, message, Local::now());
+}
+
+pub fn log_error_at(
+    errors: &mut VecDeque<(DateTime<Local>, String)>,
+    message: String,
+    at: DateTime<Local>,
+) {
+    eprintln!("{}", message);
+    errors.push_back((at, message));
+    while errors.len() > MAX_LOGGED_ERRORS {
+        errors.pop_front();
+    }
+}
+
+/// Errors logged since the error log was last opened
+pub fn unseen_error_count(
+    errors: &VecDeque<(DateTime<Local>, String)>,
+    viewed_at: Option<DateTime<Local>>,
+) -> usize {
+    match viewed_at {
+        Some(viewed_at) => errors.iter().filter(|(at, _)| *at > viewed_at).count(),
+        None => errors.len(),
+    }
+}
+
+/// The error log as plain text for bug reports, oldest first
+pub fn error_log_text(errors: &VecDeque<(DateTime<Local>, String)>) -> String {
+    errors
+        .iter()
+        .map(|(at, message)| format!("{}  {}", at.format("%Y-%m-%d %H:%M:%S"), message))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,245 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::{
+    OnceLock, RwLock,
+    atomic::{AtomicBool, Ordering},
+};
+
+use chrono::{NaiveTime, Timelike};
+
+use crate::localization::detected_locale;
+
+/// Kept outside the settings so charts and rows can format amounts without them
+static DEFAULT_CURRENCY: RwLock<String> = RwLock::new(String::new());
+/// Kept outside the settings for the same reason as the default currency
+static USE_24_HOUR_TIME: AtomicBool = AtomicBool::new(true);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    pub group_separator: char,
+    pub symbol_first: bool,
+}
+
+impl NumberFormat {
+    /// Rules for a system locale such as `de_DE.UTF-8`. Unknown locales use English rules.
+    pub fn for_locale(locale: &str) -> Self {
+        let normalized = locale
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+        let mut parts = normalized.split('-');
+        let language = parts.next().unwrap_or_default().to_lowercase();
+        let region = parts.next().unwrap_or_default().to_uppercase();
+
+        let (decimal_separator, group_separator, symbol_first) =
+            match (language.as_str(), region.as_str()) {
+                ("de", "CH" | "LI") => ('.', '\'', true),
+                ("pt", "BR") => (',', '.', true),
+                ("de" | "es" | "id" | "it" | "nl" | "pt" | "tr" | "da", _) => (',', '.', false),
+                ("cs" | "fi" | "fr" | "nb" | "pl" | "ru" | "sk" | "sv" | "uk", _) => {
+                    (',', '\u{a0}', false)
+                }
+                _ => ('.', ',', true),
+            };
+
+        NumberFormat {
+            decimal_separator,
+            group_separator,
+            symbol_first,
+        }
+    }
+
+    /// The value with two decimals and grouped thousands
+    pub fn number(&self, value: f32) -> String {
+        let rounded = format!("{:.2}", value.abs());
+        let (whole, fraction) = rounded.split_once('.').unwrap_or((&rounded, "00"));
+
+        let mut grouped = String::new();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push(self.group_separator);
+            }
+            grouped.push(digit);
+        }
+
+        let sign = if value < 0.0 && rounded.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            "-"
+        } else {
+            ""
+        };
+        format!("{}{}{}{}", sign, grouped, self.decimal_separator, fraction)
+    }
+
+    pub fn currency(&self, amount: f32, currency: &str) -> String {
+        let symbol = currency_symbol(currency);
+        let number = self.number(amount);
+        if symbol.is_empty() {
+            number
+        } else if self.symbol_first {
+            // Codes like CHF need a space to stay readable
+            let separator = if symbol.chars().all(char::is_alphabetic) {
+                "\u{a0}"
+            } else {
+                ""
+            };
+            match number.strip_prefix('-') {
+                Some(positive) => format!("-{}{}{}", symbol, separator, positive),
+                None => format!("{}{}{}", symbol, separator, number),
+            }
+        } else {
+            format!("{}\u{a0}{}", number, symbol)
+        }
+    }
+}
+
+/// The symbol for a currency code, or the currency itself if it is already a symbol
+pub fn currency_symbol(currency: &str) -> String {
+    let currency = currency.trim();
+    match currency.to_uppercase().as_str() {
+        "AUD" => "A$",
+        "BRL" => "R$",
+        "CAD" => "CA$",
+        "CNY" | "JPY" => "¥",
+        "EUR" => "€",
+        "GBP" => "£",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "RUB" => "₽",
+        "TRY" => "₺",
+        "USD" => "$",
+        _ => currency,
+    }
+    .to_string()
+}
+
+fn number_format() -> &'static NumberFormat {
+    static NUMBER_FORMAT: OnceLock<NumberFormat> = OnceLock::new();
+    NUMBER_FORMAT.get_or_init(|| NumberFormat::for_locale(&detected_locale()))
+}
+
+pub fn default_currency() -> String {
+    DEFAULT_CURRENCY
+        .read()
+        .map(|currency| currency.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_default_currency(currency: &str) {
+    if let Ok(mut default_currency) = DEFAULT_CURRENCY.write() {
+        *default_currency = currency.trim().to_string();
+    }
+}
+
+/// A number in the user's locale, with two decimals
+pub fn format_number(value: f32) -> String {
+    number_format().number(value)
+}
+
+/// An amount in the user's locale. Without a currency, the default currency from the settings is used.
+pub fn format_currency(amount: f32, currency: &str) -> String {
+    if currency.trim().is_empty() {
+        number_format().currency(amount, &default_currency())
+    } else {
+        number_format().currency(amount, currency)
+    }
+}
+
+/// Whether a system locale such as `en_US.UTF-8` writes times on a 24-hour clock
+pub fn locale_uses_24_hour_time(locale: &str) -> bool {
+    let normalized = locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-");
+    let mut parts = normalized.split('-');
+    let language = parts.next().unwrap_or_default().to_lowercase();
+    let region = parts.next().unwrap_or_default().to_uppercase();
+
+    !matches!(
+        (language.as_str(), region.as_str()),
+        ("en", "" | "US" | "CA" | "AU" | "NZ" | "PH" | "IN") | ("hi" | "bn" | "ur", _)
+    )
+}
+
+pub fn uses_24_hour_time() -> bool {
+    USE_24_HOUR_TIME.load(Ordering::Relaxed)
+}
+
+pub fn set_use_24_hour_time(use_24_hour_time: bool) {
+    USE_24_HOUR_TIME.store(use_24_hour_time, Ordering::Relaxed);
+}
+
+/// A time of day such as "14:35" or "2:35 PM"
+pub fn format_time_of_day(time: NaiveTime, show_seconds: bool, use_24_hour_time: bool) -> String {
+    let format = match (use_24_hour_time, show_seconds) {
+        (true, true) => "%H:%M:%S",
+        (true, false) => "%H:%M",
+        (false, true) => "%-I:%M:%S %p",
+        (false, false) => "%-I:%M %p",
+    };
+    time.format(format).to_string()
+}
+
+/// A time of day on the clock chosen in the settings
+pub fn format_time(time: NaiveTime, show_seconds: bool) -> String {
+    format_time_of_day(time, show_seconds, uses_24_hour_time())
+}
+
+/// A short hour label for chart axes, e.g. "14" or "2p"
+pub fn format_hour(hour: u32) -> String {
+    if uses_24_hour_time() {
+        hour.to_string()
+    } else {
+        let time = NaiveTime::from_hms_opt(hour % 24, 0, 0).unwrap_or_default();
+        let (is_pm, hour12) = time.hour12();
+        format!("{}{}", hour12, if is_pm { "p" } else { "a" })
+    }
+}
+
+/// A rough data size such as "820 B", "4.2 KB" or "1.3 MB"
+pub fn format_byte_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// A time typed as "14:35", "14:35:20", "2:35 PM" or "2:35pm"
+pub fn parse_time(input: &str) -> Option<NaiveTime> {
+    let input = input.trim().to_uppercase();
+    let twelve_hour = input
+        .strip_suffix("AM")
+        .or_else(|| input.strip_suffix("PM"))
+        .map(|time| format!("{} {}", time.trim_end(), &input[input.len() - 2..]));
+    match twelve_hour {
+        Some(time) => ["%I:%M:%S %p", "%I:%M %p"]
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(&time, format).ok()),
+        None => ["%H:%M:%S", "%H:%M"]
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(&input, format).ok()),
+    }
+}
@@ -0,0 +1,102 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <r@kressle.in>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::Mutex;
+
+use user_idle::UserIdle;
+
+use crate::view_enums::FurIdleBackend;
+
+#[cfg(target_os = "linux")]
+use {crate::helpers::wayland_idle::WaylandIdleMonitor, std::sync::OnceLock};
+
+#[cfg(target_os = "linux")]
+static WAYLAND_MONITOR: OnceLock<Result<WaylandIdleMonitor, String>> = OnceLock::new();
+
+/// The backend picked by `FurIdleBackend::Auto`, kept so detection isn't redone every tick
+static RESOLVED_BACKEND: Mutex<Option<FurIdleBackend>> = Mutex::new(None);
+
+/// Seconds the user has been idle and the backend that measured it.
+/// The Wayland backend only knows whether the threshold was passed, so it reports
+/// either 0 or `idle_threshold_secs`.
+pub fn get_idle_time(
+    backend: FurIdleBackend,
+    idle_threshold_secs: u64,
+) -> Result<(u64, FurIdleBackend), String> {
+    if backend != FurIdleBackend::Auto {
+        return idle_time_from(backend, idle_threshold_secs).map(|secs| (secs, backend));
+    }
+
+    let mut resolved = RESOLVED_BACKEND.lock().map_err(|e| e.to_string())?;
+    if let Some(resolved_backend) = *resolved {
+        if let Ok(secs) = idle_time_from(resolved_backend, idle_threshold_secs) {
+            return Ok((secs, resolved_backend));
+        }
+    }
+
+    let mut errors: Vec<String> = Vec::new();
+    for candidate in FurIdleBackend::FALLBACK_ORDER {
+        match idle_time_from(candidate, idle_threshold_secs) {
+            Ok(secs) => {
+                *resolved = Some(candidate);
+                return Ok((secs, candidate));
+            }
+            Err(e) => errors.push(format!("{}: {}", candidate, e)),
+        }
+    }
+
+    *resolved = None;
+    Err(errors.join("; "))
+}
+
+fn idle_time_from(backend: FurIdleBackend, idle_threshold_secs: u64) -> Result<u64, String> {
+    match backend {
+        #[cfg(target_os = "linux")]
+        FurIdleBackend::WaylandIdleNotify => {
+            match WAYLAND_MONITOR.get_or_init(|| WaylandIdleMonitor::spawn(idle_threshold_secs)) {
+                Ok(monitor) => Ok(if monitor.is_idle() {
+                    idle_threshold_secs
+                } else {
+                    0
+                }),
+                Err(e) => Err(e.clone()),
+            }
+        }
+        #[cfg(target_os = "linux")]
+        FurIdleBackend::ScreenSaverDbus => screensaver_idle_time(),
+        FurIdleBackend::XScreenSaver | FurIdleBackend::System => UserIdle::get_time()
+            .map(|idle| idle.as_seconds())
+            .map_err(|e| format!("{:?}", e)),
+        _ => Err("Not available on this platform".to_string()),
+    }
+}
+
+/// KDE and other freedesktop ScreenSaver implementations report idle time in milliseconds
+#[cfg(target_os = "linux")]
+fn screensaver_idle_time() -> Result<u64, String> {
+    let connection = zbus::blocking::Connection::session().map_err(|e| e.to_string())?;
+    let reply = connection
+        .call_method(
+            Some("org.freedesktop.ScreenSaver"),
+            "/org/freedesktop/ScreenSaver",
+            Some("org.freedesktop.ScreenSaver"),
+            "GetSessionIdleTime",
+            &(),
+        )
+        .map_err(|e| e.to_string())?;
+    let idle_ms: u32 = reply.body().deserialize().map_err(|e| e.to_string())?;
+    Ok(u64::from(idle_ms) / 1000)
+}
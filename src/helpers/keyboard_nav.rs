@@ -0,0 +1,212 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    constants::{
+        CURRENCY_INPUT_ID, DEVICE_NAME_INPUT_ID, FIND_INPUT_ID, GOAL_PROJECT_INPUT_ID,
+        INSPECTOR_ESTIMATE_INPUT_ID, INSPECTOR_NAME_INPUT_ID, INSPECTOR_PROJECT_INPUT_ID,
+        INSPECTOR_RATE_INPUT_ID, INSPECTOR_TAGS_INPUT_ID, INVOICE_CLIENT_INPUT_ID,
+        INVOICE_FROM_INPUT_ID, INVOICE_NOTES_INPUT_ID, INVOICE_NUMBER_INPUT_ID,
+        PROFILE_NAME_INPUT_ID, PROFILE_PATH_INPUT_ID, REPLACE_INPUT_ID, SYNC_EMAIL_INPUT_ID,
+        SYNC_KEY_INPUT_ID, SYNC_SERVER_INPUT_ID,
+    },
+    update::messages::Message,
+    view_enums::{EditTaskProperty, EditTodoProperty, FurInspectorView, InvoiceField, TabId},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Move the highlight through a vertical list. Nothing highlighted yet starts at the top.
+pub fn move_list_index(
+    current: Option<usize>,
+    len: usize,
+    direction: NavDirection,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let Some(current) = current.map(|index| index.min(len - 1)) else {
+        return Some(0);
+    };
+
+    Some(match direction {
+        NavDirection::Up => current.saturating_sub(1),
+        NavDirection::Down => (current + 1).min(len - 1),
+        NavDirection::Left | NavDirection::Right => current,
+    })
+}
+
+/// Move the highlight through a grid that wraps every `columns` items.
+/// Up and Down stay put when there is no item directly above or below.
+pub fn move_grid_index(
+    current: Option<usize>,
+    len: usize,
+    columns: usize,
+    direction: NavDirection,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let columns = columns.max(1);
+    let Some(current) = current.map(|index| index.min(len - 1)) else {
+        return Some(0);
+    };
+
+    Some(match direction {
+        NavDirection::Left => current.saturating_sub(1),
+        NavDirection::Right => (current + 1).min(len - 1),
+        NavDirection::Up => current.checked_sub(columns).unwrap_or(current),
+        NavDirection::Down => {
+            if current + columns < len {
+                current + columns
+            } else {
+                current
+            }
+        }
+    })
+}
+
+/// How many fixed-width items fit on one line of a wrapping row
+pub fn grid_columns(available_width: f32, item_width: f32, spacing: f32) -> usize {
+    (((available_width + spacing) / (item_width + spacing)).floor() as usize).max(1)
+}
+
+/// The scroll position that brings an item into view, as a fraction of the list
+pub fn relative_scroll_offset(index: usize, len: usize) -> f32 {
+    if len > 1 {
+        index.min(len - 1) as f32 / (len - 1) as f32
+    } else {
+        0.0
+    }
+}
+
+/// The text inputs Tab moves through in each inspector, top to bottom.
+/// Date and time pickers are buttons, which iced doesn't give keyboard focus.
+pub fn inspector_focus_order(view: &FurInspectorView) -> &'static [&'static str] {
+    match view {
+        FurInspectorView::AddNewTask
+        | FurInspectorView::AddShortcut
+        | FurInspectorView::EditGroup
+        | FurInspectorView::EditShortcut
+        | FurInspectorView::EditTask => &[
+            INSPECTOR_NAME_INPUT_ID,
+            INSPECTOR_PROJECT_INPUT_ID,
+            INSPECTOR_TAGS_INPUT_ID,
+            INSPECTOR_RATE_INPUT_ID,
+        ],
+        FurInspectorView::AddNewTodo | FurInspectorView::EditTodo => &[
+            INSPECTOR_NAME_INPUT_ID,
+            INSPECTOR_PROJECT_INPUT_ID,
+            INSPECTOR_TAGS_INPUT_ID,
+            INSPECTOR_RATE_INPUT_ID,
+            INSPECTOR_ESTIMATE_INPUT_ID,
+        ],
+        FurInspectorView::GenerateInvoice => &[
+            INVOICE_NUMBER_INPUT_ID,
+            INVOICE_FROM_INPUT_ID,
+            INVOICE_CLIENT_INPUT_ID,
+            INVOICE_NOTES_INPUT_ID,
+        ],
+        // The group's tasks are read-only and the note is a single text editor
+        FurInspectorView::AddTaskToGroup | FurInspectorView::EditDayNote => &[],
+    }
+}
+
+/// The text inputs Tab moves through on each settings tab, top to bottom.
+/// Inputs that are only sometimes shown still need filtering by the caller.
+pub fn settings_focus_order(tab: &TabId) -> &'static [&'static str] {
+    match tab {
+        TabId::General => &[CURRENCY_INPUT_ID],
+        TabId::Advanced => &[DEVICE_NAME_INPUT_ID],
+        TabId::Report => &[GOAL_PROJECT_INPUT_ID],
+        TabId::Data => &[
+            SYNC_SERVER_INPUT_ID,
+            SYNC_EMAIL_INPUT_ID,
+            SYNC_KEY_INPUT_ID,
+            PROFILE_NAME_INPUT_ID,
+            PROFILE_PATH_INPUT_ID,
+            FIND_INPUT_ID,
+            REPLACE_INPUT_ID,
+        ],
+        TabId::Pomodoro | TabId::Charts | TabId::List => &[],
+    }
+}
+
+/// The input after `current` in `order`, wrapping at either end.
+/// Nothing focused yet, or focus somewhere outside the order, starts at the first
+/// input, or the last one when going backwards.
+pub fn next_focus(
+    order: &[&'static str],
+    current: Option<&str>,
+    reverse: bool,
+) -> Option<&'static str> {
+    let last = order.len().checked_sub(1)?;
+    let next = match order.iter().position(|id| Some(*id) == current) {
+        Some(index) if reverse => index.checked_sub(1).unwrap_or(last),
+        Some(index) if index == last => 0,
+        Some(index) => index + 1,
+        None if reverse => last,
+        None => 0,
+    };
+
+    Some(order[next])
+}
+
+/// The input a message came from, so Tab carries on from where the user is typing
+/// even if they clicked into the field instead of tabbing to it
+pub fn focused_input_for(message: &Message) -> Option<&'static str> {
+    Some(match message {
+        Message::EditShortcutTextChanged(_, property)
+        | Message::EditTaskTextChanged(_, property) => match property {
+            EditTaskProperty::Name => INSPECTOR_NAME_INPUT_ID,
+            EditTaskProperty::Project => INSPECTOR_PROJECT_INPUT_ID,
+            EditTaskProperty::Tags => INSPECTOR_TAGS_INPUT_ID,
+            EditTaskProperty::Rate => INSPECTOR_RATE_INPUT_ID,
+            _ => return None,
+        },
+        Message::EditTodoTextChanged(_, property) => match property {
+            EditTodoProperty::Task => INSPECTOR_NAME_INPUT_ID,
+            EditTodoProperty::Project => INSPECTOR_PROJECT_INPUT_ID,
+            EditTodoProperty::Tags => INSPECTOR_TAGS_INPUT_ID,
+            EditTodoProperty::Rate => INSPECTOR_RATE_INPUT_ID,
+            EditTodoProperty::Estimate => INSPECTOR_ESTIMATE_INPUT_ID,
+            _ => return None,
+        },
+        Message::InvoiceTextChanged(_, field) => match field {
+            InvoiceField::Number => INVOICE_NUMBER_INPUT_ID,
+            InvoiceField::From => INVOICE_FROM_INPUT_ID,
+            InvoiceField::Client => INVOICE_CLIENT_INPUT_ID,
+            InvoiceField::Notes => INVOICE_NOTES_INPUT_ID,
+        },
+        Message::FindReplaceFindChanged(_) => FIND_INPUT_ID,
+        Message::FindReplaceReplaceChanged(_) => REPLACE_INPUT_ID,
+        Message::GoalToAddProjectChanged(_) => GOAL_PROJECT_INPUT_ID,
+        Message::ProfileNameChanged(_) => PROFILE_NAME_INPUT_ID,
+        Message::ProfilePathChanged(_) => PROFILE_PATH_INPUT_ID,
+        Message::SettingsDefaultCurrencyChanged(_) => CURRENCY_INPUT_ID,
+        Message::SettingsDeviceNameChanged(_) => DEVICE_NAME_INPUT_ID,
+        Message::UserEmailChanged(_) => SYNC_EMAIL_INPUT_ID,
+        Message::UserEncryptionKeyChanged(_) => SYNC_KEY_INPUT_ID,
+        Message::UserServerChanged(_) => SYNC_SERVER_INPUT_ID,
+        _ => return None,
+    })
+}
@@ -0,0 +1,122 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::{Mutex, OnceLock};
+
+use iced::advanced::subscription;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::update::messages::Message;
+
+/// Buttons attached to the Pomodoro-over notification on platforms that support them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PomodoroNotificationAction {
+    StartBreak,
+    Snooze,
+    Stop,
+}
+
+impl PomodoroNotificationAction {
+    pub const ALL: [PomodoroNotificationAction; 3] = [
+        PomodoroNotificationAction::StartBreak,
+        PomodoroNotificationAction::Snooze,
+        PomodoroNotificationAction::Stop,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            PomodoroNotificationAction::StartBreak => "start-break",
+            PomodoroNotificationAction::Snooze => "snooze",
+            PomodoroNotificationAction::Stop => "stop",
+        }
+    }
+
+    /// The ids double as localization keys for the button labels
+    #[cfg_attr(not(all(unix, not(target_os = "macos"))), allow(dead_code))]
+    pub fn label_key(&self) -> &'static str {
+        self.id()
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    pub fn to_message(&self) -> Message {
+        match self {
+            PomodoroNotificationAction::StartBreak => Message::PomodoroStartBreak,
+            PomodoroNotificationAction::Snooze => Message::PomodoroSnooze,
+            PomodoroNotificationAction::Stop => Message::PomodoroStop,
+        }
+    }
+}
+
+struct ActionChannel {
+    sender: UnboundedSender<PomodoroNotificationAction>,
+    receiver: Mutex<Option<UnboundedReceiver<PomodoroNotificationAction>>>,
+}
+
+static ACTION_CHANNEL: OnceLock<ActionChannel> = OnceLock::new();
+
+fn action_channel() -> &'static ActionChannel {
+    ACTION_CHANNEL.get_or_init(|| {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        ActionChannel {
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+        }
+    })
+}
+
+/// Forward the action a user picked on a notification to the running app.
+/// Dismissing the notification reports an id that is ignored here.
+#[cfg_attr(not(all(unix, not(target_os = "macos"))), allow(dead_code))]
+pub fn send_notification_action(action_id: &str) {
+    if let Some(action) = PomodoroNotificationAction::from_id(action_id) {
+        if let Err(e) = action_channel().sender.send(action) {
+            eprintln!("Failed to send notification action: {e}");
+        }
+    }
+}
+
+pub struct NotificationActionSubscription;
+
+impl subscription::Recipe for NotificationActionSubscription {
+    type Output = Message;
+
+    fn hash(&self, state: &mut rustc_hash::FxHasher) {
+        use std::hash::Hash;
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: subscription::EventStream,
+    ) -> futures_core::stream::BoxStream<'static, Self::Output> {
+        let receiver = action_channel()
+            .receiver
+            .lock()
+            .ok()
+            .and_then(|mut receiver| receiver.take());
+
+        Box::pin(async_stream::stream! {
+            if let Some(mut receiver) = receiver {
+                while let Some(action) = receiver.recv().await {
+                    yield Message::NotificationActionReceived(action);
+                }
+            }
+        })
+    }
+}
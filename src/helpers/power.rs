@@ -0,0 +1,120 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Local, TimeDelta};
+use iced::advanced::subscription;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+use crate::{
+    constants::{POWER_CHECK_SECONDS, SLEEP_GAP_TOLERANCE_SECONDS},
+    update::messages::Message,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerEvent {
+    Suspending,
+    /// The machine woke up after sleeping since the given time
+    Resumed(DateTime<Local>),
+}
+
+/// Whether more wall-clock time passed between two checks than the check interval
+/// can explain
+pub fn slept_between(last_seen: DateTime<Local>, now: DateTime<Local>) -> bool {
+    now - last_seen > TimeDelta::seconds((POWER_CHECK_SECONDS + SLEEP_GAP_TOLERANCE_SECONDS) as i64)
+}
+
+/// A sleeping machine can't run the stopwatch, so a resume shows up as a jump in the
+/// wall clock between two checks on every platform. On Linux, logind also warns
+/// before the machine sleeps.
+pub struct PowerSubscription;
+
+impl subscription::Recipe for PowerSubscription {
+    type Output = Message;
+
+    fn hash(&self, state: &mut rustc_hash::FxHasher) {
+        use std::hash::Hash;
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: subscription::EventStream,
+    ) -> futures_core::stream::BoxStream<'static, Self::Output> {
+        Box::pin(async_stream::stream! {
+            let (sender, mut receiver) = mpsc::unbounded_channel();
+            #[cfg(target_os = "linux")]
+            watch_logind(sender.clone());
+            tokio::spawn(watch_wall_clock(sender));
+
+            while let Some(event) = receiver.recv().await {
+                yield Message::SystemPowerChanged(event);
+            }
+        })
+    }
+}
+
+async fn watch_wall_clock(sender: UnboundedSender<PowerEvent>) {
+    let mut last_seen = Local::now();
+    loop {
+        tokio::time::sleep(Duration::from_secs(POWER_CHECK_SECONDS)).await;
+        let now = Local::now();
+        if slept_between(last_seen, now) && sender.send(PowerEvent::Resumed(last_seen)).is_err() {
+            return;
+        }
+        last_seen = now;
+    }
+}
+
+/// Listen for logind's PrepareForSleep signal, which is sent with `true` before
+/// suspending and `false` after resuming
+#[cfg(target_os = "linux")]
+fn watch_logind(sender: UnboundedSender<PowerEvent>) {
+    std::thread::spawn(move || {
+        if let Err(e) = listen_for_logind_sleep(&sender) {
+            eprintln!("Sleep notifications unavailable: {e}");
+        }
+    });
+}
+
+#[cfg(target_os = "linux")]
+fn listen_for_logind_sleep(sender: &UnboundedSender<PowerEvent>) -> zbus::Result<()> {
+    let connection = zbus::blocking::Connection::system()?;
+    let proxy = zbus::blocking::Proxy::new(
+        &connection,
+        "org.freedesktop.login1",
+        "/org/freedesktop/login1",
+        "org.freedesktop.login1.Manager",
+    )?;
+
+    let mut suspended_at = None;
+    for signal in proxy.receive_signal("PrepareForSleep")? {
+        let event = if signal.body().deserialize::<bool>()? {
+            suspended_at = Some(Local::now());
+            PowerEvent::Suspending
+        } else {
+            match suspended_at.take() {
+                Some(time) => PowerEvent::Resumed(time),
+                None => continue,
+            }
+        };
+        if sender.send(event).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
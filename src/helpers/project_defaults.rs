@@ -0,0 +1,69 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::update::msg_helper_functions::normalize_tags;
+
+/// Typing this before a tag, as in `#-billable`, leaves that default tag off the task
+pub const SKIP_DEFAULT_TAG_PREFIX: char = '-';
+
+/// The default tags saved for `project`. Projects are matched ignoring case,
+/// like they are everywhere else tasks are grouped.
+pub fn default_tags_for<'a>(defaults: &'a HashMap<String, String>, project: &str) -> &'a str {
+    let project = project.trim();
+    if project.is_empty() {
+        return "";
+    }
+    defaults
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(project))
+        .map_or("", |(_, tags)| tags.as_str())
+}
+
+/// The default tags a task with `typed_tags` would gain, leaving out ones already
+/// typed and ones skipped with `#-tag`
+pub fn added_default_tags(typed_tags: &str, default_tags: &str) -> String {
+    let typed: Vec<String> = split_tags(&normalize_tags(typed_tags));
+    split_tags(&normalize_tags(default_tags))
+        .into_iter()
+        .filter(|tag| {
+            !typed.contains(tag) && !typed.contains(&format!("{SKIP_DEFAULT_TAG_PREFIX}{tag}"))
+        })
+        .join(" #")
+}
+
+/// The typed tags with the project's defaults merged in, without the `#-tag` markers
+pub fn merge_default_tags(typed_tags: &str, default_tags: &str) -> String {
+    let typed = split_tags(&normalize_tags(typed_tags))
+        .into_iter()
+        .filter(|tag| !tag.starts_with(SKIP_DEFAULT_TAG_PREFIX))
+        .join("#");
+    normalize_tags(&format!(
+        "{}#{}",
+        typed,
+        added_default_tags(typed_tags, default_tags)
+    ))
+}
+
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split('#')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
@@ -0,0 +1,103 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+use crate::{
+    database::db_retrieve_tasks_by_date_range,
+    helpers::summaries::period_summary,
+    localization::Localization,
+    view_enums::{FurSummaryFormat, FurSummarySchedule},
+};
+
+/// The latest period whose summary time has passed. A daily summary covers the
+/// day it's written on, and a weekly one is written on Sunday for the week ending then.
+pub fn latest_period(
+    schedule: FurSummarySchedule,
+    hour: u32,
+    now: NaiveDateTime,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let summary_time = NaiveTime::from_hms_opt(hour.min(23), 0, 0)?;
+    // The last day whose summary time has been reached
+    let last_day = if now.time() >= summary_time {
+        now.date()
+    } else {
+        now.date() - TimeDelta::days(1)
+    };
+
+    match schedule {
+        FurSummarySchedule::Off => None,
+        FurSummarySchedule::Daily => Some((last_day, last_day)),
+        FurSummarySchedule::Weekly => {
+            let week_end =
+                last_day - TimeDelta::days(last_day.weekday().num_days_from_sunday() as i64);
+            Some((week_end - TimeDelta::days(6), week_end))
+        }
+    }
+}
+
+/// Whether the period ending on `period_end` still needs its summary. `last_generated`
+/// is the end of the last period written, so a summary is never written twice.
+pub fn is_summary_due(period_end: NaiveDate, last_generated: &str) -> bool {
+    match NaiveDate::parse_from_str(last_generated, "%Y-%m-%d") {
+        Ok(last_generated) => last_generated < period_end,
+        Err(_) => true,
+    }
+}
+
+pub fn summary_file_name(
+    schedule: FurSummarySchedule,
+    start: NaiveDate,
+    format: FurSummaryFormat,
+) -> String {
+    let period = match schedule {
+        FurSummarySchedule::Weekly => start.format("%G-W%V").to_string(),
+        FurSummarySchedule::Daily | FurSummarySchedule::Off => start.format("%Y-%m-%d").to_string(),
+    };
+    format!("furtherance-summary-{}.{}", period, format.extension())
+}
+
+/// Write the summary for `start` through `end` into `folder`, returning where it went
+pub fn write_scheduled_summary(
+    folder: &Path,
+    schedule: FurSummarySchedule,
+    (start, end): (NaiveDate, NaiveDate),
+    format: FurSummaryFormat,
+    include_earnings: bool,
+    earnings_exclusions: &[String],
+) -> Result<PathBuf, Box<dyn Error>> {
+    let tasks = db_retrieve_tasks_by_date_range(start, end)?;
+    let summary = period_summary(
+        &tasks,
+        start,
+        end,
+        format,
+        include_earnings,
+        earnings_exclusions,
+        &Localization::new(),
+    );
+
+    fs::create_dir_all(folder)?;
+    let path = folder.join(summary_file_name(schedule, start, format));
+    fs::write(&path, summary)?;
+    Ok(path)
+}
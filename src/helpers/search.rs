@@ -0,0 +1,67 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::models::fur_task::FurTask;
+
+/// The lowercased words of a search. A task matches when it contains all of them.
+pub fn search_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// Split `text` into pieces, marking the pieces that match one of the terms
+pub fn highlight_segments(text: &str, terms: &[String]) -> Vec<(String, bool)> {
+    let lowercase = text.to_lowercase();
+    // Lowercasing can change byte lengths outside ASCII, which would break the offsets
+    if terms.is_empty() || lowercase.len() != text.len() {
+        return vec![(text.to_string(), false)];
+    }
+
+    let mut highlighted = vec![false; text.len()];
+    for term in terms.iter().filter(|term| !term.is_empty()) {
+        for (start, _) in lowercase.match_indices(term.as_str()) {
+            highlighted[start..start + term.len()].fill(true);
+        }
+    }
+
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    for (index, character) in text.char_indices() {
+        let is_match = highlighted[index];
+        match segments.last_mut() {
+            Some((segment, segment_match)) if *segment_match == is_match => segment.push(character),
+            _ => segments.push((character.to_string(), is_match)),
+        }
+    }
+    segments
+}
+
+/// Search results grouped by the day they started on, newest day first
+pub fn group_results_by_date(results: &[FurTask]) -> Vec<(NaiveDate, Vec<&FurTask>)> {
+    let mut by_date: BTreeMap<NaiveDate, Vec<&FurTask>> = BTreeMap::new();
+    for task in results {
+        by_date
+            .entry(task.start_time.date_naive())
+            .or_default()
+            .push(task);
+    }
+    by_date.into_iter().rev().collect()
+}
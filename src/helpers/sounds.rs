@@ -0,0 +1,88 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    io::Cursor,
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+};
+
+use rodio::{Decoder, OutputStream, Sink};
+
+use crate::models::fur_settings::FurSettings;
+
+/// Missing audio devices don't go away between plays, so only report the first failure
+static PLAYBACK_ERROR_LOGGED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FurSound {
+    Idle,
+    TimerStarted,
+    TimerStopped,
+}
+
+impl FurSound {
+    pub fn is_enabled(self, settings: &FurSettings) -> bool {
+        settings.sounds_enabled
+            && match self {
+                FurSound::Idle => settings.sound_on_idle,
+                FurSound::TimerStarted | FurSound::TimerStopped => settings.sound_on_start_stop,
+            }
+    }
+
+    fn bytes(self) -> &'static [u8] {
+        match self {
+            FurSound::Idle => include_bytes!("../../assets/sounds/idle.wav"),
+            FurSound::TimerStarted => include_bytes!("../../assets/sounds/timer-start.wav"),
+            FurSound::TimerStopped => include_bytes!("../../assets/sounds/timer-stop.wav"),
+        }
+    }
+}
+
+/// Play the sound if the settings allow it. Playback happens on its own thread
+/// so a slow or missing audio device never holds up the timer.
+pub fn play_sound(sound: FurSound, settings: &FurSettings) {
+    if !sound.is_enabled(settings) {
+        return;
+    }
+
+    let spawned = thread::Builder::new()
+        .name("furtherance-sound".to_string())
+        .spawn(move || {
+            if let Err(e) = play_blocking(sound) {
+                log_playback_error(&e);
+            }
+        });
+    if let Err(e) = spawned {
+        log_playback_error(&e.to_string());
+    }
+}
+
+fn play_blocking(sound: FurSound) -> Result<(), String> {
+    // The stream has to stay alive until the sound finishes
+    let (_stream, handle) = OutputStream::try_default().map_err(|e| e.to_string())?;
+    let sink = Sink::try_new(&handle).map_err(|e| e.to_string())?;
+    let source = Decoder::new(Cursor::new(sound.bytes())).map_err(|e| e.to_string())?;
+    sink.append(source);
+    sink.sleep_until_end();
+    Ok(())
+}
+
+fn log_playback_error(error: &str) {
+    if !PLAYBACK_ERROR_LOGGED.swap(true, Ordering::Relaxed) {
+        eprintln!("Unable to play sound: {}", error);
+    }
+}
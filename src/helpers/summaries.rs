@@ -0,0 +1,179 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::{
+    helpers::{earnings::task_earnings, formatting::format_currency},
+    localization::Localization,
+    models::fur_task::FurTask,
+    view_enums::FurSummaryFormat,
+};
+
+/// A summary of `tasks` to paste into notes: a heading with the date range,
+/// each project's hours with its tasks beneath, then the total
+pub fn period_summary(
+    tasks: &[FurTask],
+    start: NaiveDate,
+    end: NaiveDate,
+    format: FurSummaryFormat,
+    include_earnings: bool,
+    earnings_exclusions: &[String],
+    localization: &Localization,
+) -> String {
+    // Seconds per task name, and earnings, within each project
+    let mut projects: BTreeMap<String, (BTreeMap<String, i64>, f32)> = BTreeMap::new();
+    for task in tasks {
+        let (names, earnings) = projects.entry(task.project.trim().to_string()).or_default();
+        *names.entry(task.name.trim().to_string()).or_insert(0) += task.total_time_in_seconds();
+        *earnings += task_earnings(task, task.total_time_in_seconds(), earnings_exclusions);
+    }
+    let total_seconds: i64 = projects
+        .values()
+        .flat_map(|(names, _)| names.values())
+        .sum();
+    let total_earnings: f32 = projects.values().map(|(_, earnings)| earnings).sum();
+
+    let mut sorted_projects: Vec<(String, Vec<(String, i64)>, i64, f32)> = projects
+        .into_iter()
+        .map(|(project, (names, earnings))| {
+            let project_seconds = names.values().sum();
+            let mut names: Vec<(String, i64)> = names.into_iter().collect();
+            names.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            (project, names, project_seconds, earnings)
+        })
+        .collect();
+    // Most time first, with tasks outside a project last
+    sorted_projects.sort_by(|a, b| {
+        a.0.is_empty()
+            .cmp(&b.0.is_empty())
+            .then_with(|| b.2.cmp(&a.2))
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    let amount = |seconds: i64, earnings: f32| {
+        if include_earnings {
+            format!(
+                "{} · {}",
+                format_hours(seconds),
+                format_currency(earnings, "")
+            )
+        } else {
+            format_hours(seconds)
+        }
+    };
+    let date_range = format!("{} – {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+    let total_label = localization.get_message("total", None);
+
+    if format == FurSummaryFormat::Html {
+        let mut summary = format!("<meta charset=\"utf-8\">\n<h2>{}</h2>\n", date_range);
+        if !sorted_projects.is_empty() {
+            summary.push_str("<ul>\n");
+            for (project, names, project_seconds, earnings) in sorted_projects {
+                let project = if project.is_empty() {
+                    localization.get_message("no-project", None)
+                } else {
+                    project
+                };
+                summary.push_str(&format!(
+                    "<li><strong>{}</strong> — {}\n<ul>\n",
+                    escape(&project, format),
+                    amount(project_seconds, earnings)
+                ));
+                for (name, seconds) in names {
+                    summary.push_str(&format!(
+                        "<li>{} ({})</li>\n",
+                        escape(&name, format),
+                        format_hours(seconds)
+                    ));
+                }
+                summary.push_str("</ul>\n</li>\n");
+            }
+            summary.push_str("</ul>\n");
+        }
+        summary.push_str(&format!(
+            "<p><strong>{}:</strong> {}</p>\n",
+            escape(&total_label, format),
+            amount(total_seconds, total_earnings)
+        ));
+        return summary;
+    }
+
+    let (heading, bold) = match format {
+        FurSummaryFormat::Org => ("*", "*"),
+        FurSummaryFormat::Markdown | FurSummaryFormat::Html => ("##", "**"),
+    };
+    let mut summary = format!("{} {}\n\n", heading, date_range);
+
+    for (project, names, project_seconds, earnings) in sorted_projects {
+        let project = if project.is_empty() {
+            localization.get_message("no-project", None)
+        } else {
+            project
+        };
+        summary.push_str(&format!(
+            "- {bold}{}{bold} — {}\n",
+            escape(&project, format),
+            amount(project_seconds, earnings)
+        ));
+        for (name, seconds) in names {
+            summary.push_str(&format!(
+                "  - {} ({})\n",
+                escape(&name, format),
+                format_hours(seconds)
+            ));
+        }
+    }
+
+    summary.push_str(&format!(
+        "\n{bold}{}:{bold} {}\n",
+        total_label,
+        amount(total_seconds, total_earnings)
+    ));
+    summary
+}
+
+fn format_hours(seconds: i64) -> String {
+    format!("{:.2} h", seconds as f64 / 3600.0)
+}
+
+/// Escapes characters that would otherwise become formatting. Org has no
+/// escape character, so only Markdown and HTML text is changed.
+fn escape(text: &str, format: FurSummaryFormat) -> String {
+    match format {
+        FurSummaryFormat::Markdown => {
+            let mut escaped = String::with_capacity(text.len());
+            for c in text.chars() {
+                if matches!(
+                    c,
+                    '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+                ) {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+            escaped
+        }
+        FurSummaryFormat::Html => text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;"),
+        FurSummaryFormat::Org => text.to_string(),
+    }
+}
@@ -0,0 +1,158 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{collections::BTreeMap, error::Error, fs, path::Path, time::Duration};
+
+use chrono::{DateTime, Local, NaiveDate, TimeDelta};
+use serde::Serialize;
+
+use crate::{
+    constants::{SUMMARY_FILE_DAYS, SUMMARY_FILE_MIN_SECONDS_BETWEEN_WRITES},
+    database::db_retrieve_tasks_by_date_range,
+    helpers::earnings::task_earnings,
+    models::fur_task::FurTask,
+    update::msg_helper_functions::split_task_input,
+};
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct SummaryFile {
+    pub generated_at: String,
+    pub days: Vec<DaySummary>,
+    pub projects: Vec<ProjectSummary>,
+    pub running: Option<RunningSummary>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct DaySummary {
+    pub date: String,
+    pub seconds: i64,
+    pub earnings: f32,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ProjectSummary {
+    pub project: String,
+    pub seconds: i64,
+    pub earnings: f32,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RunningSummary {
+    pub task: String,
+    pub project: String,
+    pub tags: String,
+    pub start_time: String,
+    pub elapsed_seconds: i64,
+}
+
+impl RunningSummary {
+    /// `now` is when the pause began for a paused timer, so the elapsed time stops there
+    pub fn new(task_input: &str, start_time: DateTime<Local>, now: DateTime<Local>) -> Self {
+        let (task, project, tags, _) = split_task_input(task_input);
+        RunningSummary {
+            task,
+            project,
+            tags,
+            start_time: start_time.to_rfc3339(),
+            elapsed_seconds: (now - start_time).num_seconds().max(0),
+        }
+    }
+}
+
+/// Totals for every day from `SUMMARY_FILE_DAYS` ago through `today`, including days
+/// without tasks so a chart has no gaps, and for each project over the same days
+pub fn build_summary(
+    tasks: &[FurTask],
+    today: NaiveDate,
+    running: Option<RunningSummary>,
+    generated_at: DateTime<Local>,
+    earnings_exclusions: &[String],
+) -> SummaryFile {
+    let first_day = today - TimeDelta::days(SUMMARY_FILE_DAYS - 1);
+    let mut days: BTreeMap<NaiveDate, (i64, f32)> = first_day
+        .iter_days()
+        .take_while(|date| *date <= today)
+        .map(|date| (date, (0, 0.0)))
+        .collect();
+    let mut projects: BTreeMap<String, (i64, f32)> = BTreeMap::new();
+
+    for task in tasks {
+        let seconds = task.total_time_in_seconds();
+        let earnings = task_earnings(task, seconds, earnings_exclusions);
+        if let Some(day) = days.get_mut(&task.start_time.date_naive()) {
+            day.0 += seconds;
+            day.1 += earnings;
+            let project = projects.entry(task.project.trim().to_string()).or_default();
+            project.0 += seconds;
+            project.1 += earnings;
+        }
+    }
+
+    SummaryFile {
+        generated_at: generated_at.to_rfc3339(),
+        days: days
+            .into_iter()
+            .map(|(date, (seconds, earnings))| DaySummary {
+                date: date.to_string(),
+                seconds,
+                earnings,
+            })
+            .collect(),
+        projects: projects
+            .into_iter()
+            .map(|(project, (seconds, earnings))| ProjectSummary {
+                project,
+                seconds,
+                earnings,
+            })
+            .collect(),
+        running,
+    }
+}
+
+/// How long to wait before the summary file may be written again
+pub fn write_delay(last_written: Option<DateTime<Local>>, now: DateTime<Local>) -> Duration {
+    let Some(last_written) = last_written else {
+        return Duration::ZERO;
+    };
+    let since = (now - last_written).to_std().unwrap_or(Duration::ZERO);
+    Duration::from_secs(SUMMARY_FILE_MIN_SECONDS_BETWEEN_WRITES).saturating_sub(since)
+}
+
+/// Read the recent tasks and replace the summary file at `path` with their totals
+pub fn write_summary_file(
+    path: &Path,
+    running: Option<RunningSummary>,
+    earnings_exclusions: &[String],
+) -> Result<(), Box<dyn Error>> {
+    let now = Local::now();
+    let today = now.date_naive();
+    let tasks =
+        db_retrieve_tasks_by_date_range(today - TimeDelta::days(SUMMARY_FILE_DAYS - 1), today)?;
+    let json = serde_json::to_string(&build_summary(
+        &tasks,
+        today,
+        running,
+        now,
+        earnings_exclusions,
+    ))?;
+
+    // Write to a temporary file first so readers never see a partial file
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json)?;
+    fs::rename(temp_path, path)?;
+    Ok(())
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use chrono::Local;
+
 use crate::{
     database::db_set_todo_completed,
     models::{fur_task_group::FurTaskGroup, fur_todo::FurTodo},
@@ -27,8 +29,14 @@ pub fn after_refresh(todays_todos: Vec<FurTodo>, todays_tasks: Vec<FurTaskGroup>
             .iter()
             .find(|task_group| task_group.to_string() == todo.to_string())
         {
-            match db_set_todo_completed(&todo.uid) {
-                Ok(_) => todo.is_completed = true,
+            let completed_at = Local::now();
+            match db_set_todo_completed(&todo.uid, completed_at) {
+                Ok(_) => {
+                    if !todo.is_completed {
+                        todo.completed_at = Some(completed_at);
+                    }
+                    todo.is_completed = true;
+                }
                 Err(e) => eprintln!("Error while marking todo {} as completed: {}", todo.uid, e),
             }
         }
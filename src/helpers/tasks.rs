@@ -14,34 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeDelta, TimeZone};
 
 use crate::{
-    database::{SortBy, SortOrder, db_retrieve_tasks_with_day_limit},
-    models::{fur_task::FurTask, fur_task_group::FurTaskGroup},
+    database::{
+        SortBy, SortOrder, db_retrieve_latest_task_start_before, db_retrieve_tasks_by_date_range,
+        db_retrieve_tasks_with_day_limit,
+    },
+    models::{fur_settings::FurSettings, fur_task::FurTask, fur_task_group::FurTaskGroup},
+    view_enums::{FilterChip, FurHistoryGrouping},
 };
 
 pub fn get_task_history(limit: i64) -> BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>> {
     let mut grouped_tasks_by_date: BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>> = BTreeMap::new();
 
     match db_retrieve_tasks_with_day_limit(limit, SortBy::StopTime, SortOrder::Descending) {
-        Ok(all_tasks) => {
-            let tasks_by_date = group_tasks_by_date(all_tasks);
-
-            for (date, tasks) in tasks_by_date {
-                let mut all_groups: Vec<FurTaskGroup> = vec![];
-                for task in tasks {
-                    if let Some(matching_group) =
-                        all_groups.iter_mut().find(|x| x.is_equal_to(&task))
-                    {
-                        matching_group.add(task);
-                    } else {
-                        all_groups.push(FurTaskGroup::new_from(task));
-                    }
-                }
-                grouped_tasks_by_date.insert(date, all_groups);
-            }
-        }
+        Ok(all_tasks) => grouped_tasks_by_date = group_tasks_into_history(all_tasks),
         Err(e) => {
             eprintln!("Error retrieving tasks from database: {}", e);
         }
@@ -49,11 +39,138 @@ pub fn get_task_history(limit: i64) -> BTreeMap<chrono::NaiveDate, Vec<FurTaskGr
     grouped_tasks_by_date
 }
 
+/// History from before the dates already shown
+#[derive(Clone, Debug)]
+pub struct OlderHistory {
+    /// The first date that was already shown
+    pub before: NaiveDate,
+    pub first_day: NaiveDate,
+    pub history: BTreeMap<NaiveDate, Vec<FurTaskGroup>>,
+    pub end_reached: bool,
+}
+
+/// Loads `days` days of history ending with the latest task before `before`,
+/// so stretches without any tasks are skipped
+pub fn get_older_task_history(before: NaiveDate, days: i64) -> OlderHistory {
+    let mut older = OlderHistory {
+        before,
+        first_day: before,
+        history: BTreeMap::new(),
+        end_reached: false,
+    };
+
+    let latest_day = match db_retrieve_latest_task_start_before(before) {
+        Ok(Some(start_time)) => start_time.date_naive(),
+        Ok(None) => {
+            older.end_reached = true;
+            return older;
+        }
+        Err(e) => {
+            eprintln!("Error retrieving older tasks from database: {}", e);
+            return older;
+        }
+    };
+
+    older.first_day = latest_day - TimeDelta::days(days - 1);
+    match db_retrieve_tasks_by_date_range(older.first_day, before - TimeDelta::days(1)) {
+        Ok(mut tasks) => {
+            // Match the order of the rest of the history
+            tasks.sort_by(|a, b| b.stop_time.cmp(&a.stop_time));
+            older.history = group_tasks_into_history(tasks);
+        }
+        Err(e) => {
+            eprintln!("Error retrieving older tasks from database: {}", e);
+            older.first_day = before;
+            return older;
+        }
+    }
+
+    older.end_reached = matches!(
+        db_retrieve_latest_task_start_before(older.first_day),
+        Ok(None)
+    );
+    older
+}
+
+/// Group tasks by local date, then combine matching tasks within each date
+pub fn group_tasks_into_history(
+    tasks: Vec<FurTask>,
+) -> BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>> {
+    let mut grouped_tasks_by_date: BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>> = BTreeMap::new();
+
+    for (date, tasks) in group_tasks_by_date(tasks) {
+        let mut all_groups: Vec<FurTaskGroup> = vec![];
+        for task in tasks {
+            if let Some(matching_group) = all_groups.iter_mut().find(|x| x.is_equal_to(&task)) {
+                matching_group.add(task);
+            } else {
+                all_groups.push(FurTaskGroup::new_from(task));
+            }
+        }
+        grouped_tasks_by_date.insert(date, all_groups);
+    }
+
+    grouped_tasks_by_date
+}
+
+/// The first day of the week or month covered by a section of the history
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PeriodKey {
+    pub start: NaiveDate,
+}
+
+impl PeriodKey {
+    /// Weeks start on Monday
+    pub fn containing(date: NaiveDate, grouping: FurHistoryGrouping) -> Self {
+        let start = match grouping {
+            FurHistoryGrouping::Day => date,
+            FurHistoryGrouping::Week => {
+                date - TimeDelta::days(date.weekday().num_days_from_monday() as i64)
+            }
+            FurHistoryGrouping::Month => date.with_day(1).unwrap_or(date),
+        };
+        PeriodKey { start }
+    }
+}
+
+/// Re-key a day by day history into week or month sections,
+/// combining matching tasks across all the days of each section
+pub fn group_history_by_period(
+    history: &BTreeMap<NaiveDate, Vec<FurTaskGroup>>,
+    grouping: FurHistoryGrouping,
+) -> BTreeMap<PeriodKey, Vec<FurTaskGroup>> {
+    let mut sections: BTreeMap<PeriodKey, Vec<FurTaskGroup>> = BTreeMap::new();
+
+    // Newest day first so groups keep the order they have in the daily history
+    for (date, task_groups) in history.iter().rev() {
+        let section = sections
+            .entry(PeriodKey::containing(*date, grouping))
+            .or_default();
+        for task in task_groups.iter().flat_map(|group| group.tasks.iter()) {
+            if let Some(matching_group) = section.iter_mut().find(|x| x.is_equal_to(task)) {
+                matching_group.add(task.clone());
+            } else {
+                section.push(FurTaskGroup::new_from(task.clone()));
+            }
+        }
+    }
+
+    sections
+}
+
 fn group_tasks_by_date(tasks: Vec<FurTask>) -> BTreeMap<chrono::NaiveDate, Vec<FurTask>> {
+    group_tasks_by_date_in(tasks, &Local)
+}
+
+/// Group tasks by the date they started on as seen in the given timezone
+pub fn group_tasks_by_date_in<Tz: TimeZone>(
+    tasks: Vec<FurTask>,
+    timezone: &Tz,
+) -> BTreeMap<chrono::NaiveDate, Vec<FurTask>> {
     let mut grouped_tasks: BTreeMap<chrono::NaiveDate, Vec<FurTask>> = BTreeMap::new();
 
     for task in tasks {
-        let date = task.start_time.date_naive(); // Extract the date part
+        let date = task.start_time.with_timezone(timezone).date_naive();
         grouped_tasks
             .entry(date)
             .or_insert_with(Vec::new)
@@ -62,3 +179,156 @@ fn group_tasks_by_date(tasks: Vec<FurTask>) -> BTreeMap<chrono::NaiveDate, Vec<F
 
     grouped_tasks
 }
+
+/// The projects and tags used by the most tasks in the history, most used first
+pub fn most_used_filter_chips(
+    history: &BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>>,
+    count: usize,
+) -> Vec<FilterChip> {
+    let mut uses: HashMap<FilterChip, usize> = HashMap::new();
+
+    for task_group in history.values().flatten() {
+        let task_count = task_group.tasks.len();
+        if !task_group.project.trim().is_empty() {
+            *uses
+                .entry(FilterChip::Project(task_group.project.trim().to_string()))
+                .or_insert(0) += task_count;
+        }
+        for tag in split_tags(&task_group.tags) {
+            *uses.entry(FilterChip::Tag(tag)).or_insert(0) += task_count;
+        }
+    }
+
+    let mut chips: Vec<(FilterChip, usize)> = uses.into_iter().collect();
+    chips.sort_by(|(a_chip, a_uses), (b_chip, b_uses)| {
+        b_uses
+            .cmp(a_uses)
+            .then_with(|| a_chip.to_string().cmp(&b_chip.to_string()))
+    });
+    chips
+        .into_iter()
+        .take(count)
+        .map(|(chip, _)| chip)
+        .collect()
+}
+
+/// Whether a task group passes every active filter
+pub fn group_matches_filters(task_group: &FurTaskGroup, filters: &HashSet<FilterChip>) -> bool {
+    filters.iter().all(|filter| match filter {
+        FilterChip::Project(project) => task_group.project.trim().eq_ignore_ascii_case(project),
+        FilterChip::Tag(tag) => split_tags(&task_group.tags).any(|t| t.eq_ignore_ascii_case(tag)),
+    })
+}
+
+/// The task groups listed in the history, newest day first, in the order they're shown
+pub fn visible_history_groups<'a, K>(
+    task_history: &'a BTreeMap<K, Vec<FurTaskGroup>>,
+    filters: &HashSet<FilterChip>,
+    settings: &FurSettings,
+) -> Vec<&'a FurTaskGroup> {
+    task_history
+        .values()
+        .rev()
+        .flatten()
+        .filter(|task_group| group_matches_filters(task_group, filters))
+        .filter(|task_group| {
+            !(settings.hide_archived_in_history
+                && settings.is_project_archived(&task_group.project))
+        })
+        .collect()
+}
+
+/// The dates whose tasks differ between two task histories
+pub fn changed_history_dates(
+    old_history: &BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>>,
+    new_history: &BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>>,
+) -> BTreeSet<chrono::NaiveDate> {
+    let tasks_in = |history: &BTreeMap<chrono::NaiveDate, Vec<FurTaskGroup>>| -> Vec<FurTask> {
+        history
+            .values()
+            .flatten()
+            .flat_map(|group| group.tasks.iter().cloned())
+            .collect()
+    };
+    changed_task_dates(&tasks_in(old_history), &tasks_in(new_history))
+}
+
+/// The start dates of tasks added, removed or edited between two lists of tasks.
+/// An edited task that moved marks both its old and new dates.
+pub fn changed_task_dates(
+    old_tasks: &[FurTask],
+    new_tasks: &[FurTask],
+) -> BTreeSet<chrono::NaiveDate> {
+    let old_by_uid: HashMap<&str, &FurTask> = old_tasks
+        .iter()
+        .map(|task| (task.uid.as_str(), task))
+        .collect();
+    let new_by_uid: HashMap<&str, &FurTask> = new_tasks
+        .iter()
+        .map(|task| (task.uid.as_str(), task))
+        .collect();
+    let mut changed_dates = BTreeSet::new();
+
+    for (uid, old_task) in &old_by_uid {
+        match new_by_uid.get(uid) {
+            Some(new_task) if same_task(old_task, new_task) => {}
+            Some(new_task) => {
+                changed_dates.insert(old_task.start_time.date_naive());
+                changed_dates.insert(new_task.start_time.date_naive());
+            }
+            None => {
+                changed_dates.insert(old_task.start_time.date_naive());
+            }
+        }
+    }
+    for (uid, new_task) in &new_by_uid {
+        if !old_by_uid.contains_key(uid) {
+            changed_dates.insert(new_task.start_time.date_naive());
+        }
+    }
+
+    changed_dates
+}
+
+/// When the last task stopped on `date`, taken from the loaded history. Tasks that
+/// started the day before and ran past midnight count too.
+pub fn latest_stop_on(
+    history: &BTreeMap<NaiveDate, Vec<FurTaskGroup>>,
+    date: NaiveDate,
+) -> Option<DateTime<Local>> {
+    history
+        .range(..=date)
+        .rev()
+        .take(2)
+        .flat_map(|(_, groups)| groups)
+        .flat_map(|group| &group.tasks)
+        .map(|task| task.stop_time)
+        .filter(|stop_time| stop_time.date_naive() == date)
+        .max()
+}
+
+/// The start a timer should be moved to so it doesn't overlap the task that stopped
+/// at `previous_stop`, or `None` when `start` doesn't overlap it
+pub fn start_clamped_to_previous_stop(
+    start: DateTime<Local>,
+    previous_stop: Option<DateTime<Local>>,
+) -> Option<DateTime<Local>> {
+    previous_stop.filter(|previous_stop| start < *previous_stop)
+}
+
+fn same_task(a: &FurTask, b: &FurTask) -> bool {
+    a.name == b.name
+        && a.start_time == b.start_time
+        && a.stop_time == b.stop_time
+        && a.tags == b.tags
+        && a.project == b.project
+        && a.rate == b.rate
+        && a.currency == b.currency
+        && a.is_billable == b.is_billable
+}
+
+fn split_tags(tags: &str) -> impl Iterator<Item = String> + '_ {
+    tags.split('#')
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+}
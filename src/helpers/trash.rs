@@ -0,0 +1,92 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::localization::Localization;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The kinds of synced records that are soft deleted and can be restored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeletedItemKind {
+    Task,
+    Todo,
+    Shortcut,
+}
+
+impl DeletedItemKind {
+    pub const ALL: [DeletedItemKind; 3] = [
+        DeletedItemKind::Task,
+        DeletedItemKind::Todo,
+        DeletedItemKind::Shortcut,
+    ];
+
+    pub fn table(&self) -> &'static str {
+        match self {
+            DeletedItemKind::Task => "tasks",
+            DeletedItemKind::Todo => "todos",
+            DeletedItemKind::Shortcut => "shortcuts",
+        }
+    }
+
+    pub fn name_column(&self) -> &'static str {
+        match self {
+            DeletedItemKind::Task => "task_name",
+            DeletedItemKind::Todo | DeletedItemKind::Shortcut => "name",
+        }
+    }
+
+    pub fn label(&self, localization: &Localization) -> String {
+        localization.get_message(
+            match self {
+                DeletedItemKind::Task => "deleted-task",
+                DeletedItemKind::Todo => "deleted-todo",
+                DeletedItemKind::Shortcut => "deleted-shortcut",
+            },
+            None,
+        )
+    }
+}
+
+/// A soft-deleted record shown in the trash
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeletedItem {
+    pub kind: DeletedItemKind,
+    pub uid: String,
+    pub name: String,
+    pub project: String,
+    /// The last_updated timestamp, which is when it was deleted
+    pub deleted_at: i64,
+}
+
+/// Deleted records updated before this timestamp are past the retention period
+pub fn retention_cutoff(now: i64, retention_days: u16) -> i64 {
+    now - i64::from(retention_days) * SECONDS_PER_DAY
+}
+
+/// Purging a deletion that hasn't been uploaded yet would leave the record alive on
+/// the server, so while signed in nothing newer than the last sync is purged
+pub fn purge_cutoff(now: i64, retention_days: u16, last_sync: Option<i64>) -> i64 {
+    let cutoff = retention_cutoff(now, retention_days);
+    match last_sync {
+        Some(last_sync) => cutoff.min(last_sync),
+        None => cutoff,
+    }
+}
+
+/// How many of `items` are of `kind`
+pub fn count_of_kind(items: &[DeletedItem], kind: DeletedItemKind) -> usize {
+    items.iter().filter(|item| item.kind == kind).count()
+}
@@ -19,7 +19,9 @@ use std::collections::HashMap;
 use std::ffi::CString;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
 use wayrs_client::protocol::WlSeat;
 use wayrs_client::{Connection, EventCtx, IoMode};
 use wayrs_protocols::ext_idle_notify_v1::{
@@ -32,17 +34,24 @@ pub struct WaylandIdleMonitor {
 }
 
 impl WaylandIdleMonitor {
-    pub fn spawn(timeout_secs: u64) -> Self {
+    /// Start monitoring, failing if the compositor doesn't offer ext-idle-notify
+    pub fn spawn(timeout_secs: u64) -> Result<Self, String> {
         let is_idle = Arc::new(AtomicBool::new(false));
         let is_idle_clone = Arc::clone(&is_idle);
+        let (ready_sender, ready_receiver) = mpsc::channel();
 
         thread::spawn(move || {
-            if let Err(e) = run_monitor(timeout_secs * 1000, is_idle_clone) {
+            if let Err(e) = run_monitor(timeout_secs * 1000, is_idle_clone, &ready_sender) {
                 eprintln!("Wayland idle monitor error: {:?}", e);
+                let _ = ready_sender.send(Err(e.to_string()));
             }
         });
 
-        WaylandIdleMonitor { is_idle }
+        match ready_receiver.recv_timeout(Duration::from_secs(2)) {
+            Ok(Ok(())) => Ok(WaylandIdleMonitor { is_idle }),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err("Timed out connecting to the Wayland compositor".to_string()),
+        }
     }
 
     pub fn is_idle(&self) -> bool {
@@ -50,7 +59,11 @@ impl WaylandIdleMonitor {
     }
 }
 
-fn run_monitor(timeout_ms: u64, is_idle: Arc<AtomicBool>) -> anyhow::Result<()> {
+fn run_monitor(
+    timeout_ms: u64,
+    is_idle: Arc<AtomicBool>,
+    ready_sender: &mpsc::Sender<Result<(), String>>,
+) -> anyhow::Result<()> {
     let mut conn = Connection::connect()?;
 
     let mut state = State {
@@ -74,6 +87,7 @@ fn run_monitor(timeout_ms: u64, is_idle: Arc<AtomicBool>) -> anyhow::Result<()>
         seat,
         notification_cb,
     );
+    let _ = ready_sender.send(Ok(()));
 
     loop {
         conn.flush(IoMode::Blocking)?;
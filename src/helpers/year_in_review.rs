@@ -0,0 +1,250 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeDelta};
+use fluent::FluentValue;
+
+use crate::{
+    helpers::formatting::format_time, localization::Localization, models::fur_task::FurTask,
+    update::msg_helper_functions::seconds_to_formatted_duration,
+};
+
+/// Fun totals for one calendar year, all worked out from the local database
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct YearInReview {
+    pub year: i32,
+    pub total_seconds: i64,
+    pub task_count: usize,
+    pub busiest_day: Option<(NaiveDate, i64)>,
+    pub longest_streak: Option<Streak>,
+    pub top_project: Option<(String, i64)>,
+    pub top_tag: Option<(String, i64)>,
+    pub earliest_start: Option<DateTime<Local>>,
+    pub latest_finish: Option<DateTime<Local>>,
+}
+
+/// A run of consecutive days with tracked time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub days: u32,
+}
+
+/// Which year to review: last year during January, otherwise this one
+pub fn review_year(today: NaiveDate) -> i32 {
+    if today.month() == 1 {
+        today.year() - 1
+    } else {
+        today.year()
+    }
+}
+
+/// Tasks belong to the local day they started on, like they do in the history
+pub fn year_in_review(tasks: &[FurTask], year: i32) -> YearInReview {
+    let tasks: Vec<&FurTask> = tasks
+        .iter()
+        .filter(|task| task.start_time.date_naive().year() == year)
+        .collect();
+
+    let mut day_totals: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+    let mut project_totals: BTreeMap<&str, i64> = BTreeMap::new();
+    let mut tag_totals: BTreeMap<&str, i64> = BTreeMap::new();
+    for task in &tasks {
+        let seconds = task.total_time_in_seconds();
+        *day_totals.entry(task.start_time.date_naive()).or_default() += seconds;
+        if !task.project.trim().is_empty() {
+            *project_totals.entry(task.project.trim()).or_default() += seconds;
+        }
+        for tag in task
+            .tags
+            .split('#')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+        {
+            *tag_totals.entry(tag).or_default() += seconds;
+        }
+    }
+
+    YearInReview {
+        year,
+        total_seconds: day_totals.values().sum(),
+        task_count: tasks.len(),
+        busiest_day: largest(&day_totals).map(|(day, seconds)| (*day, seconds)),
+        longest_streak: longest_streak(day_totals.keys().copied()),
+        top_project: largest(&project_totals)
+            .map(|(project, seconds)| (project.to_string(), seconds)),
+        top_tag: largest(&tag_totals).map(|(tag, seconds)| (tag.to_string(), seconds)),
+        earliest_start: tasks
+            .iter()
+            .min_by_key(|task| task.start_time.time())
+            .map(|task| task.start_time),
+        latest_finish: tasks
+            .iter()
+            .max_by_key(|task| finish_after_start_day(task))
+            .map(|task| task.stop_time),
+    }
+}
+
+/// The longest run of consecutive days. Ties go to the earlier run.
+pub fn longest_streak(days: impl IntoIterator<Item = NaiveDate>) -> Option<Streak> {
+    let days: BTreeSet<NaiveDate> = days.into_iter().collect();
+    let mut longest: Option<Streak> = None;
+    let mut current: Option<Streak> = None;
+    for day in days {
+        let streak = match current {
+            Some(streak) if streak.end.succ_opt() == Some(day) => Streak {
+                end: day,
+                days: streak.days + 1,
+                ..streak
+            },
+            _ => Streak {
+                start: day,
+                end: day,
+                days: 1,
+            },
+        };
+        if longest.is_none_or(|longest| streak.days > longest.days) {
+            longest = Some(streak);
+        }
+        current = Some(streak);
+    }
+    longest
+}
+
+/// The key with the largest total. Ties go to the first key, which is the earliest
+/// day or the first name alphabetically.
+fn largest<K>(totals: &BTreeMap<K, i64>) -> Option<(&K, i64)> {
+    totals.iter().fold(None, |best, (key, seconds)| match best {
+        Some((_, best_seconds)) if best_seconds >= *seconds => best,
+        _ => Some((key, *seconds)),
+    })
+}
+
+/// How long after the start of its first day a task finished, so finishing after
+/// midnight counts as later than finishing in the evening
+fn finish_after_start_day(task: &FurTask) -> TimeDelta {
+    task.stop_time.naive_local() - task.start_time.date_naive().and_time(NaiveTime::MIN)
+}
+
+/// Each stat as a heading and a value, shared by the card and the text copy
+pub fn review_lines(review: &YearInReview, localization: &Localization) -> Vec<(String, String)> {
+    let duration = |seconds: i64| seconds_to_formatted_duration(seconds, false);
+    let with_time = |value: String, seconds: i64| {
+        localization.get_message(
+            "review-value-with-time",
+            Some(&HashMap::from([
+                ("value", FluentValue::from(value)),
+                ("time", FluentValue::from(duration(seconds))),
+            ])),
+        )
+    };
+    let time_on_date = |date_time: &DateTime<Local>| {
+        localization.get_message(
+            "review-time-on-date",
+            Some(&HashMap::from([
+                (
+                    "time",
+                    FluentValue::from(format_time(date_time.time(), false)),
+                ),
+                (
+                    "date",
+                    FluentValue::from(date_time.format("%b %d").to_string()),
+                ),
+            ])),
+        )
+    };
+
+    let mut lines = vec![
+        (
+            localization.get_message("review-total-time", None),
+            duration(review.total_seconds),
+        ),
+        (
+            localization.get_message("review-tasks", None),
+            review.task_count.to_string(),
+        ),
+    ];
+    if let Some((day, seconds)) = review.busiest_day {
+        lines.push((
+            localization.get_message("review-busiest-day", None),
+            with_time(day.format("%b %d").to_string(), seconds),
+        ));
+    }
+    if let Some(streak) = review.longest_streak {
+        lines.push((
+            localization.get_message("review-longest-streak", None),
+            localization.get_message(
+                "review-streak-days",
+                Some(&HashMap::from([
+                    ("days", FluentValue::from(streak.days)),
+                    (
+                        "start",
+                        FluentValue::from(streak.start.format("%b %d").to_string()),
+                    ),
+                    (
+                        "end",
+                        FluentValue::from(streak.end.format("%b %d").to_string()),
+                    ),
+                ])),
+            ),
+        ));
+    }
+    if let Some((project, seconds)) = &review.top_project {
+        lines.push((
+            localization.get_message("review-top-project", None),
+            with_time(format!("@{project}"), *seconds),
+        ));
+    }
+    if let Some((tag, seconds)) = &review.top_tag {
+        lines.push((
+            localization.get_message("review-top-tag", None),
+            with_time(format!("#{tag}"), *seconds),
+        ));
+    }
+    if let Some(start) = &review.earliest_start {
+        lines.push((
+            localization.get_message("review-earliest-start", None),
+            time_on_date(start),
+        ));
+    }
+    if let Some(finish) = &review.latest_finish {
+        lines.push((
+            localization.get_message("review-latest-finish", None),
+            time_on_date(finish),
+        ));
+    }
+    lines
+}
+
+/// The review as plain text for copying or saving
+pub fn review_text(review: &YearInReview, localization: &Localization) -> String {
+    let mut text = localization.get_message(
+        "year-in-review-title",
+        Some(&HashMap::from([(
+            "year",
+            FluentValue::from(review.year.to_string()),
+        )])),
+    );
+    text.push('\n');
+    for (heading, value) in review_lines(review, localization) {
+        text.push_str(&format!("\n{heading}: {value}"));
+    }
+    text.push('\n');
+    text
+}
@@ -195,7 +195,6 @@ error-creating-file = Fehler beim Erstellen der Datei
 error-reading-headers = Fehler beim Lesen der Kopfzeilen
 wrong-column-order = Falsche Spaltenreihenfolge.
 missing-column = Fehlende Spalte
-invalid-csv = Ungültige CSV
 backup-database-failed = Sicherung der Datenbank fehlgeschlagen
 name-cannot-contain = Aufgabenname darf keine #, @ oder $ enthalten.
 project-cannot-contain = Projekt darf keine #, @ oder $ enthalten.
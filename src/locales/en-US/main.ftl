@@ -9,8 +9,17 @@ sqlite-files = SQLite Files
 backup-successful = Database Backup Successful
 save-csv-title = Save Furtherance CSV
 open-csv-title = Open Furtherance CSV
+save-settings-title = Save Furtherance Settings
+open-settings-title = Open Furtherance Settings
+save-shortcuts-title = Save Furtherance Shortcuts
+open-shortcuts-title = Open Furtherance Shortcuts
+save-invoice-title = Save Invoice
 new-database-title = New Furtherance Database
 open-database-title = Open Furtherance Database
+merge-database-title = Merge Furtherance Database
+choose-profile-database = Choose Profile Database
+summary-file-title = Save Summary File
+scheduled-summary-folder-title = Choose Summary Folder
 
 ## General UI
 shortcuts = Shortcuts
@@ -18,12 +27,14 @@ timer = Timer
 todo = Todo
 history = History
 report = Report
+search = Search
 settings = Settings
 today = Today
 yesterday = Yesterday
 tomorrow = Tomorrow
 cancel = Cancel
 save = Save
+split = Split
 delete = Delete
 edit = Edit
 repeat-today = Repeat today
@@ -34,12 +45,36 @@ discard = Discard
 
 ## Timer
 task-input-placeholder = Task name @Project #tags $rate
+secondary-timer-placeholder = Track another task at the same time
+recent-tasks = Recent tasks
+on-this-day-last-week = On this day last week
+no-recent-tasks = No tasks yet
+switch-task = Switch
+switch-task-description = Save "{$task}" and start this task now (Ctrl+Enter)
 started-at = Started at {$time}
 recorded-today = Recorded today: {$time}
 
+## Search
+search-history = Search all history
+search-history-prompt = Search task names, projects and tags across all of your history.
+no-search-results = No tasks found
+load-more = Load more
+
 ## History
 project-prefix = @{$project}
 tags-prefix = #{$tags}
+loading-history = Loading…
+end-of-history = End of history
+load-older-history = Load Older History
+group-by-day = By Day
+group-by-week = By Week
+group-by-month = By Month
+week-of = Week of {$date}
+copy-weekly-summary = Copy weekly summary
+save-weekly-summary = Save weekly summary
+weekly-summary-copied = Weekly summary copied
+weekly-summary-saved = Weekly summary saved
+no-project = No project
 task-count = {$count ->
     [one] {$count} task
     *[other] {$count} tasks
@@ -47,17 +82,42 @@ task-count = {$count ->
 total-time-dynamic = Total: {$time}
 total-earnings = ${$amount}
 repeat = Repeat
+duplicate = Duplicate
+duplicate-to-today = Duplicate to today
+tasks-selected = {$count ->
+    [one] {$count} task selected
+    *[other] {$count} tasks selected
+}
+change-project = Change project
+tag = Tag
+add-tag = Add tag
+remove-tag = Remove tag
+set-rate = Set rate
+clear-filters = Clear filters
 
 ## Shortcuts
 new-shortcut = New Shortcut
 create-shortcut = Create shortcut
+sort-manual = Manual
+sort-alphabetical = Alphabetical
+sort-most-used = Most used
+sort-recently-used = Recently used
+move-to-front = Move to front
+move-left = Move left
+move-right = Move right
+shortcut-used-times = {$count ->
+    [one] Used {$count} time
+    *[other] Used {$count} times
+}
 
 ## Reports
 charts = Charts
 list = List
 breakdown-by-selection = Breakdown By Selection
+filter-values = Filter values
 total-time = Total Time
 earned = Earned
+net-earned = Net after {$percent}% deduction: {$amount}
 past-week = Past week
 this-month = This month
 last-month = Last month
@@ -65,11 +125,64 @@ past-thirty-days = Past 30 days
 past-six-months = Past 6 months
 all-time = All time
 date-range = Date range
+compare-with = Compare with
+previous-period = Previous period
+previous-year = Same period last year
+all-tasks = All tasks
+billable = Billable
+non-billable = Non-billable
 title = Title
 tags = Tags
 rate = Rate
 none = None
 no-tags = no tags
+generate-invoice = Generate invoice
+export-this-selection = Export this selection
+year-in-review = Year in review
+year-in-review-title = {$year} in Review
+year-in-review-empty = Nothing was tracked in {$year}.
+review-total-time = Total time
+review-tasks = Tasks
+review-busiest-day = Busiest day
+review-longest-streak = Longest streak
+review-streak-days = {$days ->
+    [one] 1 day
+    *[other] {$days} days
+} ({$start} to {$end})
+review-top-project = Top project
+review-top-tag = Top tag
+review-earliest-start = Earliest start
+review-latest-finish = Latest finish
+review-time-on-date = {$time} on {$date}
+review-value-with-time = {$value} ({$time})
+year-in-review-copied = Year in review copied
+save-year-in-review = Save year in review
+year-in-review-saved = Year in review saved
+error-saving-year-in-review = Failed to save the year in review.
+close = Close
+error-log = Error log
+error-log-description = Errors from this session, newest first. They aren't saved when you quit.
+errors-this-session = {$count ->
+    [one] {$count} error this session
+    *[other] {$count} errors this session
+}
+new-errors = {$count ->
+    [one] {$count} new error
+    *[other] {$count} new errors
+}
+view = View
+invoice = Invoice
+invoice-number = Invoice #
+invoice-period = Period: {$start} to {$end}
+invoice-from = From
+bill-to = Bill to
+invoice-notes = Notes
+all-projects = All projects
+date = Date
+description = Description
+hours = Hours
+amount = Amount
+total = Total
 
 ## Settings
 general = General
@@ -80,15 +193,30 @@ data = Data
 ### General Settings
 interface = Interface
 default-view = Default view
+default-currency = Default currency
+use-24-hour-time = Use 24-hour time
 show-delete-confirmation = Show delete confirmation
+show-timer-in-title = Show running timer in window title
+window-size = Window size
+reset-window-size = Reset Window Size
+reset-window-size-description = Open at the default size and position next time.
 task-history = Task History
 todos = Todos
 show-project = Show project
 show-tags = Show tags
+show-planned-time = Show planned time
 show-earnings = Show earnings
 show-seconds = Show seconds
 show-daily-time-total = Show daily time total
 show-rate = Show rate
+show-estimates = Show estimates
+hide-completed-todos-after = Hide completed todos after (days)
+hide-completed-todos-after-description = Set to 0 to keep completed todos visible.
+show-shortcut-stats = Show how often shortcuts are used
+sounds = Sounds
+play-sounds = Play sounds
+sound-on-start-stop = Sound when the timer starts or stops
+sound-on-idle = Sound when idle is detected
 theme = Theme
 light = Light
 dark = Dark
@@ -99,16 +227,72 @@ mac-theme-warning = On macOS, when the system theme changes, you must restart Fu
 idle = Idle
 idle-detection = Idle detection
 minutes-until-idle = Minutes until idle
+device = Device
+device-name = Device name
+device-name-description = Saved with new tasks, todos and shortcuts so you can tell where they came from
+created-on-device = Created on { $device }
+unknown-device = Unknown device
+task-length-checks = Task Length Checks
+warn-if-longer-than-hours = Confirm tasks longer than (hours)
+confirm-on-stop = Review tasks when stopping
+confirm-on-stop-description = Opens the task for editing before it's saved. Idle and Pomodoro stops save right away.
+confirm-shortcut-switch = Ask before a shortcut replaces the running task
+discard-if-shorter-than-seconds = Discard tasks shorter than (seconds)
+zero-turns-off = Set to 0 to turn off
+auto-stop-timer = Stop the timer automatically at
+auto-stop-timer-description = A timer still running at this time is stopped and saved, even if Furtherance was closed or asleep.
+idle-backend = Idle detection method
+idle-backend-auto = Automatic
+idle-backend-system = System
+idle-backend-active = Using {$backend}
+idle-detection-unavailable = Idle detection unavailable: {$error}
 dynamic-total = Dynamic total
 dynamic-total-description = Today's total time ticks up with the timer
 days-to-show = Days to show
 reminder-notification = Reminder notification
 reminder-notifications = Reminder notifications
 reminder-notifications-description = Shows a notification every X minutes to start a timer
+notifications-unavailable-hint = Desktop notifications are unavailable on this system. Furtherance will ask for attention in the taskbar instead.
+test-notification = Test notification
+test-notification-sent = Test notification sent.
+notifications-unavailable = Notification failed: { $error }
 reminder-interval = Minutes between reminders
+planned-time-notifications = Planned time notifications
+planned-time-notifications-description = Notify when a task planned with ~ reaches its planned time
+rounding = Rounding
+round-durations = Round durations
+round-durations-description = Rounds displayed and exported totals. Recorded times are not changed.
+round-to-minutes = Round to minutes
+round-direction = Round direction
+round-nearest = Nearest
+round-up = Up
+round-down = Down
+rounded = rounded
+status-file = Status file
+write-status-file = Write status file
+status-file-description = Keeps the running task in a JSON file for scripts: {$path}
+status-server = Status server
+serve-status = Serve status over HTTP
+status-server-description = Read-only JSON for status bars at http://127.0.0.1:{$port}/status and /today. Requests need an "Authorization: Bearer" header with the API key.
+port = Port
+api-key = API key
+copy = Copy
+regenerate = Regenerate
+status-server-failed = Could not start the status server on port {$port}: {$error}
+status-server-not-running = The status server is not running. Try a different port.
 
 ### Pomodoro Settings
 pomodoro-timer = Pomodoro timer
+pomodoro-stats = {$today} today · {$streak}-day streak · {$week} this week
+planned-time-ignored-during-pomodoro = Planned times (~) are ignored while Pomodoro is on
+planned-progress = {$tracked}/{$planned} min
+log-duration-hint = Press Enter to log {$duration} ending now
+log-duration-timer-running = Stop the timer to log a finished task.
+task-logged = Logged {$task} ({$duration})
+task-notes = Task notes
+task-notes-placeholder = What are you working on? Notes are saved with the task.
+undo = Undo
+tracked-of-planned = of {$planned} planned
 notification-alarm-sound = Notification alarm sound
 countdown-timer = Countdown timer
 timer-length = Timer length
@@ -123,11 +307,62 @@ extended-break-length = Extended break length
 toggle-charts = Toggle charts
 total-time-box = Total time box
 total-earnings-box = Total earnings box
+workday-stats = Workday statistics
 time-recorded = Time recorded
 earnings = Earnings
 average-time-per-task = Average time per task
 average-earnings-per-task = Average earnings per task
+activity-heatmap = Activity heatmap
+time-of-day = Time of day
+todos-planned-vs-completed = Todos planned vs completed
 breakdown-by-selection-section = Breakdown by selection section
+cumulative-earnings = Cumulative earnings
+monthly-earnings-target = Monthly earnings target
+target-per-month = Target per month
+monthly-earnings-target-description = Drawn on the cumulative earnings chart, which starts over each month. 0 hides the line.
+net-earnings = Net earnings
+show-net-earnings = Show net earnings
+show-net-earnings-description = Previews earnings after taxes or fees in the report and CSV export. Saved tasks keep their gross earnings.
+deduction-percent = Deduction (%)
+excluded-from-earnings = Excluded from earnings
+excluded-from-earnings-description = Tasks with these tags or projects still count towards time but not earnings. Separate them with commas, and start projects with @.
+daily-target = Daily target
+daily-target-minutes = Minutes per day
+daily-target-description = Each day in the history shows whether it reached the target. 0 turns it off.
+target-applies-to-weekends = Include weekends
+days-met-target = {$met} of {$days} {$days ->
+    [one] day
+    *[other] days
+} met the target
+daily-target-met = Target met
+daily-target-partial = Target not met yet
+daily-target-empty = Nothing tracked yet
+days-off = Days off
+weekends-off = Treat weekends as days off
+weekends-off-description = Right-click a day in the history to mark it as a day off. Days off are left out of per-day averages.
+weekly-summary-format = Weekly summary format
+weekly-summary-format-description = Right-click a week in the history (grouped by week) to copy or save its summary
+write-scheduled-summary = Scheduled summary
+scheduled-summary-description = Saves a summary in the format above to a folder. Weekly summaries are written on Sunday, and one missed while Furtherance was closed is written at the next launch.
+schedule-off = Off
+schedule-daily = Daily
+schedule-weekly = Weekly
+scheduled-summary-hour = Hour of day
+scheduled-summary-earnings = Include earnings
+scheduled-summary-saved = Summary saved to {$path}
+scheduled-summary-failed = Could not write the scheduled summary: {$error}
+mark-day-off = Mark as day off
+unmark-day-off = Unmark day off
+day-off-has-tasks = Day off. Time tracked on this day isn't counted in averages.
+weekly-goals = Weekly goals
+workday = Workday
+average-workday-start = Average start
+average-workday-end = Average end
+average-tracked-per-day = Average per active day
+active-days = Active days
+hours-per-week = hours per week
+add-goal = Add goal
+goal-exists = A goal for that project already exists.
 time-recorded-for-selection = Time recorded for selection
 earnings-for-selection = Earnings for selection
 
@@ -146,24 +381,76 @@ login-failed = Login failed
 login-successful = Login successful
 logged-out = Logged out
 server-must-contain-protocol = The server must contain a protocol (http:// or https://)
+server-required = Enter a server address
+server-invalid = That doesn't look like a valid server address
+server-unencrypted-warning = This server uses http://, so your email and encryption key are sent unencrypted.
+server-not-found = Couldn't find that server. Check the address and your connection.
+server-certificate-error = The server's security certificate couldn't be verified
+server-unreachable = Couldn't reach the server
 error-storing-credentials = Error storing user credentials
 error-retrieving-credentials = Error retrieving user credentials from database
 reauthenticate-error = Credentials have changed. Log in again.
 subscription-inactive = Your subscription is not active. Please log in at sync.furtherance.com to reactivate it.
 sync = Sync
 syncing = Syncing...
+sync-frequency = Sync automatically
+every-x-minutes = Every {$count} minutes
+manual-only = Manual only
 sync-successful = {$count ->
     [0] Nothing to sync
     [one] {$count} item synced
     *[other] {$count} items synced
 }
 sync-failed = Sync failed
+sync-offline-will-retry = Offline. Sync will retry automatically.
+last-synced = Synced {$time}
+never-synced = Not synced yet
+sync-pending = Sync pending
+last-sync-summary = Last sync: {$time}, {$count ->
+    [one] {$count} item
+    *[other] {$count} items
+} • {$pending} pending
+no-sync-summary = Not synced yet • {$pending} pending
+sync-details = Details
+sync-history = Recent Syncs
+sync-history-description = The last five syncs from this device. {$pending ->
+    [0] Nothing is waiting to sync.
+    [one] {$pending} change is waiting to sync.
+    *[other] {$pending} changes are waiting to sync.
+}
+sync-history-empty = No syncs have finished on this device yet.
+sync-history-entry = {$uploaded} sent, {$downloaded} received • about {$size} • {$seconds}s
+sync-incompatible-version = This version of Furtherance is too old to sync — please update.
+clock-skew-warning = Your clock differs from the server by {$minutes ->
+    [one] 1 minute
+    *[other] {$minutes} minutes
+} — sync conflicts may resolve incorrectly
+sync-update-required = Update required to sync
+retry = Retry
 error-decrypting-key = Failed to decrypt encryption key
 sign-up = Sign up
 local-database = Local Database
+profiles = Profiles
+profiles-description = Keep separate databases, such as for work and personal tracking. To rename a profile, type its new name below and press Rename.
+active-profile = Profile
+choose-profile = Choose a profile
+default-profile = Default
+stop-timer-to-switch-profile = Stop the timer to switch profiles
+profile-name = Name
+profile-database-path = Database path
+browse = Browse
+add-profile = Add profile
+rename = Rename
+projects = Projects
+archived = Archived
+default-tags = Default tags
+project-default-tags-hint = Adds {$tags} for @{$project}. Type #-tag to leave one off.
+no-projects = No projects yet
+hide-archived-in-history = Hide archived projects in task history
 database-location = Database location
 create-new = Create New
 open-existing = Open Existing
+merge-database = Merge from Another Database…
 export-options = Export Options
 start-time = Start Time
 stop-time = Stop Time
@@ -172,14 +459,72 @@ sort-by-date = Sort by Date
 currency = Currency
 filter-by-date = Filter by Date
 filter-by-project = Filter by Project
+day-notes = Day Notes
 note-about-export-columns = Note: Only CSV files exported with all columns selected can be imported into Furtherance again.
 export-csv = Export CSV
 import-csv = Import CSV
 backup = Backup
 backup-database = Backup Database
 more = More
+export-settings = Export Settings
+import-settings = Import Settings
+export-shortcuts = Export Shortcuts
+import-shortcuts = Import Shortcuts
+find-duplicates = Find Duplicates
+no-duplicates-found = No duplicates found
+duplicates-merged = {$count ->
+    [one] Removed 1 duplicate
+    *[other] Removed {$count} duplicates
+}
+summary-file = Summary file
+write-summary-file = Write summary file
+summary-file-description = Keeps daily and per-project totals for the last 90 days and the running task in a JSON file for dashboards. Updated at most every 30 seconds.
+summary-file-failed = Could not write the summary file, so it has been turned off: {$error}
+find-replace = Find & Replace
+find-replace-description = Rename a project, tag or task name everywhere it's used. Only exact matches are replaced, ignoring case.
+find = Find
+replace-with = Replace with
+preview = Preview
+replace-all = Replace All
+find-replace-preview = Matches {$tasks ->
+    [one] 1 task
+    *[other] {$tasks} tasks
+}, {$todos ->
+    [one] 1 todo
+    *[other] {$todos} todos
+} and {$shortcuts ->
+    [one] 1 shortcut
+    *[other] {$shortcuts} shortcuts
+}
+find-replace-applied = {$count ->
+    [one] Updated 1 item
+    *[other] Updated {$count} items
+}
 delete-everything = Delete Everything
 deleted-everything = Deleted everything
+deleted-local-data = Deleted local data. The next sync will download everything from the server again.
+recently-deleted = Recently Deleted ({$count})
+trash-description = Deleted tasks, todos and shortcuts are kept for {$days ->
+    [one] 1 day
+    *[other] {$days} days
+} before they're removed for good. Deleting permanently only removes this device's copy, so synced devices keep theirs until they remove it too.
+keep-deleted-items-for = Days to keep deleted items
+trash-counts = {$tasks ->
+    [one] 1 task
+    *[other] {$tasks} tasks
+}, {$todos ->
+    [one] 1 todo
+    *[other] {$todos} todos
+} and {$shortcuts ->
+    [one] 1 shortcut
+    *[other] {$shortcuts} shortcuts
+}
+trash-more = And {$count} more
+restore = Restore
+delete-permanently = Delete Permanently
+deleted-task = Task
+deleted-todo = Todo
+deleted-shortcut = Shortcut
 
 ## Inspector
 task = Task
@@ -187,36 +532,96 @@ task-name = Task name
 project = Project
 hashtag-tags = #tags
 date-colon = Date:
+estimate-placeholder = Estimate (e.g. 1h30m)
+todo-summary = {$count ->
+    [one] {$count} todo
+    *[other] {$count} todos
+} · {$estimate} estimated
+todo-tracked = {$tracked} tracked
+show-archived = Show archived ({$count})
+hide-archived = Hide archived ({$count})
+delete-all-archived-todos = Delete All Archived
+todo-tracked-of-estimate = {$tracked} tracked of {$estimate} estimated
+day-note-placeholder = Add a note for this day
 start-colon = Start:
 stop-colon = Stop:
 per-hour = /hr
 color = Color
 edit-shortcut = Edit Shortcut
+rate-history = Rate history
+rate-changed = {$date}: {$old} to {$new}
+rate-future-tasks-only = Apply new rate to future tasks only
+rate-update-past-tasks = Also update the {$count} tasks in this group
 start-to-stop = {$start} to {$stop}
 nothing-selected = Nothing selected.
 
 ## Charts
 average-earnings-per-task-title = Average Earnings Per Task
 average-time-per-task-title = Average Time Per Task
+zero-length-tasks-footnote = {$count ->
+    [one] 1 task with no length is counted in totals but left out of the averages
+    *[other] {$count} tasks with no length are counted in totals but left out of the averages
+}
 time-recorded-title = Time Recorded
 time-recorded-for-selection-title = Time Recorded For Selection
 earnings-for-selection-title = Earnings For Selection
+gross-earnings-legend = Gross
+net-earnings-legend = Net
 cant-show-charts = Not enough data to show charts.
+activity-heatmap-title = Activity Over the Last 12 Months
+time-of-day-title = Time Recorded by Hour of Day
+todo-completion-title = Todos Planned and Completed
+todos-planned = Planned
+todos-completed = Completed
+todo-completion-rate = Of planned todos completed
+less = Less
 
 ## Alerts
+merge-duplicates = Remove Duplicates
+merge-duplicates-question = Remove duplicates?
+merge-duplicates-description = Found {$tasks} duplicate tasks, {$shortcuts} duplicate shortcuts and {$todos} duplicate todos. The oldest copy of each is kept and the rest are deleted on all synced devices.
 delete-all = Delete All
 delete-all-question = Delete all?
 delete-all-description = Are you sure you want to permanently delete all tasks in this group?
+delete-selected-description = Are you sure you want to permanently delete the {$count} selected tasks?
 delete-everything-question = Delete everything?
 delete-everything-description = Are you sure you want to permanently delete everything in the database?
+delete-everything-synced-description = This will also delete everything from the sync server and your other devices.
+type-to-confirm = Type { $word } to confirm.
+delete-local-data-only = Delete Local Data Only (Keep Server Copy)
 delete-shortcut-question = Delete shortcut?
 delete-shortcut-description = Are you sure you want to delete this shortcut?
 delete-task-question = Delete task?
 delete-task-description = Are you sure you want to permanently delete this task?
 delete-todo-question = Delete todo?
 delete-todo-description = Are you sure you want to permanently delete this todo?
+delete-archived-todos-question = Delete archived todos?
+delete-archived-todos-description = Are you sure you want to permanently delete all archived todos?
+discard-stopped-task-question = Discard task?
+discard-stopped-task-description = This task hasn't been saved yet. Discarding it can't be undone.
+keep-editing = Keep Editing
+discard-changes-question = Discard changes?
+discard-changes-description = You have changes in this form that haven't been saved. They'll be lost if you leave now.
 idle-alert-title = You have been idle for {$duration}
 idle-alert-description = Would you like to discard that time, or continue the clock?
+long-task-title = That was a long task
+long-task-description = This task ran for over {$hours} {$hours ->
+    [one] hour
+    *[other] hours
+}. Did you forget to stop the timer?
+edit-stop-time = Edit stop time
+save-as-is = Save as is
+task-too-short-discarded = Task discarded for being shorter than {$seconds} {$seconds ->
+    [one] second
+    *[other] seconds
+}
+stop-before-start = The stop time must be after the start time.
+start-time-in-future = The start time can't be in the future.
+start-overlap-title = This overlaps your last task
+start-overlap-description = Your last task stopped at {$time}. Start this one then instead, or keep the earlier start and let the two tasks overlap.
+start-at-time = Start at {$time}
+keep-overlap = Keep overlap
+timer-auto-stopped = Stopped automatically at {$time}
 break-over-title = Break's over!
 break-over-description = Time to get back to work.
 pomodoro-over-title = Time's up!
@@ -227,16 +632,53 @@ snooze-button = {$duration} more {$duration ->
 }
 long-break = Long break
 break = Break
+start-break = Start break
+snooze = Snooze
 shortcut-exists = Shortcut exists
 shortcut-exists-description = A shortcut for that task already exists.
+switch-task-question = Switch tasks?
+switch-to-shortcut-description = "{$current}" will be saved and "{$next}" will start right away.
 import-old-database = Import old database?
 import-old-database-description = It looks like you were using a previous version of Furtherance. Would you like to import the old database?
 dont-import = Don't import
 import = Import
+dropped-database-question = Open or merge this database?
+dropped-database-description = {$file} can replace the database Furtherance is using, or its tasks, todos and shortcuts can be merged into the current one. Where both have the same item, the one edited last is kept.
+open-as-database = Open as Database
+merge-databases = Merge Databases
+onboarding-title = {$title} ({$step}/{$total})
+onboarding-task-syntax-title = Welcome to Furtherance
+onboarding-task-syntax-description = Type what you are working on, then add @project, #tags, and $rate in any order. Try editing the example below.
+onboarding-example-name = Task: {$name}
+onboarding-example-project = Project: {$project}
+onboarding-example-tags = Tags: {$tags}
+onboarding-example-rate = Rate: ${$rate}
+onboarding-settings-title = Settings
+onboarding-settings-description = Open Settings from the sidebar. General covers the look of the app, Advanced has idle detection and history options, Pomodoro sets up timed work sessions, and Data handles import, export, backups and sync.
+onboarding-idle-pomodoro-title = Idle detection and Pomodoro
+onboarding-idle-pomodoro-description = Idle detection asks what to do with time you spent away from your computer. The Pomodoro timer counts down work sessions and reminds you to take breaks. You can change either one later in Settings.
+onboarding-shortcut-title = Shortcuts
+onboarding-shortcut-description = Shortcuts start a timer for a task you do often with a single click. Would you like to create one from your example task?
+onboarding-shortcut-created = Shortcut created.
+onboarding-sync-title = Sync your devices
+onboarding-sync-description = Sync your encrypted task history across all of your devices. You can self-host the sync server or set up a hosted account in Settings.
+skip = Skip
+back = Back
+next = Next
+done = Done
 autosave-restored = Autosave restored
 autosave-restored-description = Furtherance shut down improperly. An autosave was restored.
+autosave-corrupt = Autosave could not be restored
+autosave-corrupt-description = Furtherance shut down improperly, but the autosave was damaged and no task was added. It was moved to autosave.bad.
+settings-recovered = Settings were recovered
+settings-recovered-description = Your settings file was damaged and could not be read. A copy was saved as settings.bad and every readable setting was kept.
+settings-recovered-not-backed-up = Your settings file was damaged and could not be read. Every readable setting was kept, but a backup copy could not be saved.
+settings-recovered-reset = These settings were reset to their defaults: { $settings }
+settings-recovered-database = If your database was in a custom location, choose it again in Settings > Data.
 track-your-time = Track your time!
 did-you-forget = Did you forget to start a timer?
+test-notification-title = Furtherance
+test-notification-body = Notifications are working.
 
 ## Sidebar
 # Number of hours, mins, secs with only one letter formatter
@@ -248,6 +690,10 @@ x-s = {$seconds} s
 pomodoro-over-notification-body = It's time to take a break.
 idle-notification-title = You've been idle.
 idle-notification-body = Open Furtherance to continue or discard the idle time.
+planned-time-reached-title = Planned time reached
+planned-time-reached-body = The task you're tracking has reached its planned time.
+timer-auto-stopped-title = Timer stopped
+timer-auto-stopped-body = The timer was still running at your auto-stop time, so it was stopped and saved.
 syncing-now-available = Syncing Now Available
 syncing-now-possible = You can now sync your task history across all of your devices! You can self-host the sync server or set up a hosted account for $5/month.
 learn-more = Learn more
@@ -255,24 +701,83 @@ learn-more = Learn more
 ## Errors
 invalid-database = Invalid database.
 error-upgrading-database = Error upgrading legacy database.
+upgrading-database = Upgrading database: {$migrated} of {$total} tasks
+merging-database = Merging database: {$merged} of {$total} items
+database-merge-summary = Added {$tasks} tasks, {$todos} todos and {$shortcuts} shortcuts; {$newer} newer versions applied; {$conflicts} conflicts skipped.
 error-accessing-database = Error accessing new database.
 database-loaded = Database loaded.
 database-created = Database created.
+error-merging-database = Error merging database.
+dropped-file-unsupported = {$file} isn't a CSV or database file.
+drop-while-timer-running = Stop the timer before importing a file.
+profile-switched = Switched to {$name}.
+profile-name-empty = Enter a profile name.
+profile-name-taken = A profile with that name already exists.
+profile-invalid-extension = Choose a database file, such as one ending in .db.
+profile-path-unreachable = That folder doesn't exist and couldn't be created.
 csv-file-saved = CSV file saved.
 error-writing-csv = Error writing data to CSV.
-csv-imported = CSV imported successfully
+invoice-saved = Invoice saved.
+error-writing-invoice = Error writing invoice.
+no-billable-tasks = There are no billable tasks to invoice.
+csv-import-summary = {$imported} imported, {$skipped} skipped{$duplicates ->
+    [0] {""}
+    *[other] , {$duplicates} already in database
+}.
+csv-import-details = View details
+csv-import-problems = Rows Not Imported
+csv-row-error = Row {$row}: {$problem}
+csv-more-problems = …and {$count} more.
+csv-wrong-column-count = wrong number of columns
+csv-missing-name = task name is empty
+csv-invalid-start-time = invalid start time
+csv-invalid-stop-time = invalid stop time
+csv-stop-before-start = stop time must be after start time
+csv-invalid-rate = invalid rate
+csv-invalid-text = text is not valid UTF-8
+error-importing-csv = Error importing CSV.
+settings-exported = Settings exported.
+settings-imported = {$applied ->
+    [one] Applied {$applied} setting.
+    *[other] Applied {$applied} settings.
+} {$skipped ->
+    [0] {""}
+    [one] Skipped {$skipped} unrecognized setting.
+    *[other] Skipped {$skipped} unrecognized settings.
+}
+error-exporting-settings = Error exporting settings.
+error-reading-settings = Invalid settings file.
+error-importing-settings = Error importing settings.
+shortcuts-exported = Shortcuts exported.
+shortcuts-imported = {$imported ->
+    [one] Imported {$imported} shortcut.
+    *[other] Imported {$imported} shortcuts.
+} {$skipped ->
+    [0] {""}
+    [one] Skipped {$skipped} duplicate.
+    *[other] Skipped {$skipped} duplicates.
+}
+error-exporting-shortcuts = Error exporting shortcuts.
+error-reading-shortcuts = Invalid shortcuts file.
+error-finding-duplicates = Failed to search for duplicates.
+error-find-replace = Failed to find and replace.
+error-creating-weekly-summary = Failed to create the weekly summary.
+error-saving-weekly-summary = Failed to save the weekly summary.
+error-merging-duplicates = Failed to remove duplicates.
 invalid-csv-file = Invalid CSV file
 error-retrieving-tasks = Failed to retrieve tasks from the database
 error-creating-file = Failed to create the file
 error-deleting-everything = Failed to delete everything
+error-deleting-local-data = Failed to delete local data
 error-reading-headers = Failed to read the headers
 wrong-column-order = Wrong column order.
 missing-column = Missing column
-invalid-csv = Invalid CSV
 backup-database-failed = Failed to backup database
-name-cannot-contain = Task name cannot contain #, @, or $.
+split-time-outside-task = The split time must be between the start and stop times.
+name-cannot-contain = Task name cannot contain #, @, or $ unless they are in quotes or escaped with \.
 project-cannot-contain = Project cannot contain #, @, or $.
 tags-cannot-contain = Tags cannot contain @ or $.
 tags-must-start = Tags must start with a #.
 no-symbol-in-rate = Do not include a $ in the rate.
 rate-invalid = Rate must be a valid dollar amount.
+estimate-invalid = Estimate must look like 1h30m, 45m or 1:30.
@@ -195,7 +195,6 @@ error-creating-file = Error al crear el archivo
 error-reading-headers = Error al leer los encabezados
 wrong-column-order = Orden de columnas incorrecto.
 missing-column = Columna faltante
-invalid-csv = CSV no válido
 backup-database-failed = Error al hacer copia de seguridad de la base de datos
 name-cannot-contain = El nombre de la tarea no puede contener #, @, o $.
 project-cannot-contain = El proyecto no puede contener #, @, o $.
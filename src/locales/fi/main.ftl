@@ -195,7 +195,6 @@ error-creating-file = Tiedoston luonti epäonnistui
 error-reading-headers = Otsikoiden luku epäonnistui
 wrong-column-order = Väärä sarakejärjestys.
 missing-column = Puuttuva sarake
-invalid-csv = Virheellinen CSV
 backup-database-failed = Tietokannan varmuuskopiointi epäonnistui
 name-cannot-contain = Tehtävän nimi ei voi sisältää merkkejä #, @ tai $.
 project-cannot-contain = Projekti ei voi sisältää merkkejä #, @ tai $.
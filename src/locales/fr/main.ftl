@@ -196,7 +196,6 @@ error-creating-file = Échec de la création du fichier
 error-reading-headers = Échec de la lecture des en-têtes
 wrong-column-order = Ordre des colonnes incorrect.
 missing-column = Colonne manquante
-invalid-csv = CSV invalide
 backup-database-failed = Échec de la sauvegarde de la base de données
 name-cannot-contain = Le nom de la tâche ne peut pas contenir #, @ ou $.
 project-cannot-contain = Le projet ne peut pas contenir #, @ ou $.
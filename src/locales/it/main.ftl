@@ -195,7 +195,6 @@ error-creating-file = Impossibile creare il file
 error-reading-headers = Impossibile leggere le intestazioni
 wrong-column-order = Ordine delle colonne errato.
 missing-column = Colonna mancante
-invalid-csv = CSV non valido
 backup-database-failed = Backup del database fallito
 name-cannot-contain = Il nome dell'attività non può contenere #, @ o $.
 project-cannot-contain = Il progetto non può contenere #, @ o $.
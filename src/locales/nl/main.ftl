@@ -196,7 +196,6 @@ error-creating-file = Kan het bestand niet aanmaken
 error-reading-headers = Kan de headers niet lezen
 wrong-column-order = Onjuiste kolomvolgorde.
 missing-column = Ontbrekende kolom
-invalid-csv = Ongeldig csv-bestand
 backup-database-failed = Maken van database-reservekopie mislukt
 name-cannot-contain = Taaknaam mag geen #, @ of $ bevatten.
 project-cannot-contain = Project mag geen #, @ of $ bevatten.
@@ -196,7 +196,6 @@ error-creating-file = Falha ao criar o arquivo
 error-reading-headers = Falha ao ler os cabeçalhos
 wrong-column-order = Ordem de colunas incorreta.
 missing-column = Coluna ausente
-invalid-csv = CSV inválido
 backup-database-failed = Falha ao fazer backup do banco de dados
 name-cannot-contain = O nome da atividade não pode conter #, @ ou $.
 project-cannot-contain = O projeto não pode conter #, @ ou $.
@@ -195,7 +195,6 @@ error-creating-file = Falha ao criar o ficheiro
 error-reading-headers = Falha ao ler os cabeçalhos
 wrong-column-order = Ordem das colunas errada.
 missing-column = Coluna em falta
-invalid-csv = CSV inválido
 backup-database-failed = Falha ao criar cópia de segurança da base de dados
 name-cannot-contain = O nome da tarefa não pode conter #, @ ou $.
 project-cannot-contain = O projeto não pode conter #, @ ou $.
@@ -195,7 +195,6 @@ error-creating-file = Не удалось создать файл
 error-reading-headers = Не удалось прочитать заголовки
 wrong-column-order = Неправильный порядок столбцов.
 missing-column = Отсутствует столбец
-invalid-csv = Недопустимый CSV
 backup-database-failed = Не удалось создать резервную копию базы данных
 name-cannot-contain = Название задачи не может содержать #, @ или $.
 project-cannot-contain = Проект не может содержать #, @ или $.
@@ -195,7 +195,6 @@ error-creating-file = Nepodarilo sa vytvoriť súbor
 error-reading-headers = Nepodarilo sa prečítať hlavičky
 wrong-column-order = Nesprávne poradie stĺpcov.
 missing-column = Chýbajúci stĺpec
-invalid-csv = Neplatné CSV
 backup-database-failed = Zálohovanie databázy zlyhalo
 name-cannot-contain = Názov úlohy nemôže obsahovať #, @ alebo $.
 project-cannot-contain = Projekt nemôže obsahovať #, @ alebo $.
@@ -195,7 +195,6 @@ error-creating-file = Dosya oluşturulamadı
 error-reading-headers = Başlıklar okunamadı
 wrong-column-order = Yanlış sütun sırası.
 missing-column = Eksik sütun
-invalid-csv = Geçersiz CSV
 backup-database-failed = Veri tabanı yedeklenemedi
 name-cannot-contain = Görev adı #, @ veya $ içeremez.
 project-cannot-contain = Proje #, @ veya $ içeremez.
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{Mutex, OnceLock},
+};
 
 use fluent::{FluentArgs, FluentBundle, FluentResource, FluentValue};
 use rust_embed::RustEmbed;
@@ -50,6 +54,10 @@ pub struct Localization {
 
 impl Localization {
     pub fn new() -> Self {
+        Self::new_with_locale(&detected_locale())
+    }
+
+    pub fn new_with_locale(requested_lang: &str) -> Self {
         let mut bundles = HashMap::new();
         bundles.insert("de".to_string(), create_bundle("de"));
         bundles.insert("en-US".to_string(), create_bundle("en-US"));
@@ -64,16 +72,7 @@ impl Localization {
         bundles.insert("sk".to_string(), create_bundle("sk"));
         bundles.insert("tr".to_string(), create_bundle("tr"));
 
-        let mut current_lang = get_locale().unwrap_or_else(|| String::from("en-US"));
-        if !bundles.contains_key(&current_lang) {
-            let truncated_lang = current_lang.chars().take(2).collect::<String>();
-            // TODO: Append country to locale if locale only exists with specified country
-            if bundles.contains_key(&truncated_lang) {
-                current_lang = truncated_lang;
-            } else {
-                current_lang = "en-US".to_string();
-            }
-        }
+        let current_lang = resolve_language(requested_lang, &bundles);
 
         Localization {
             bundles,
@@ -82,19 +81,20 @@ impl Localization {
     }
 
     pub fn get_message(&self, key: &str, args: Option<&HashMap<&str, FluentValue<'_>>>) -> String {
-        let bundle = self.bundles.get(&self.current_lang).unwrap();
-        let msg = match bundle.get_message(key) {
-            Some(message) => message,
-            None => {
-                // Fallback to English if the message doesn't exist in the current language
-                let en_bundle = self.bundles.get("en-US").expect("English bundle not found");
-                en_bundle.get_message(key).expect(&format!(
-                    "Message doesn't exist in English either ({})",
-                    key
-                ))
-            }
+        // Fall back to English if the message doesn't exist in the current language
+        let Some((bundle, pattern)) = [self.current_lang.as_str(), "en-US"]
+            .iter()
+            .filter_map(|lang| self.bundles.get(*lang))
+            .find_map(|bundle| {
+                bundle
+                    .get_message(key)
+                    .and_then(|message| message.value())
+                    .map(|pattern| (bundle, pattern))
+            })
+        else {
+            warn_missing_message(key);
+            return key.to_string();
         };
-        let pattern = msg.value().expect("Message has no value");
 
         let mut errors = vec![];
         let formatted = if let Some(arg_map) = args {
@@ -128,3 +128,50 @@ impl Localization {
     //     }
     // }
 }
+
+/// The system locale, e.g. `en_DK.UTF-8`
+pub fn detected_locale() -> String {
+    get_locale().unwrap_or_else(|| String::from("en-US"))
+}
+
+/// Turns a system locale such as `en_DK.UTF-8` into a bundle name, trying the full
+/// locale, then its language, then any bundle of that language, then English.
+fn resolve_language(
+    requested_lang: &str,
+    bundles: &HashMap<String, FluentBundle<FluentResource>>,
+) -> String {
+    let normalized = requested_lang
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-");
+    if bundles.contains_key(&normalized) {
+        return normalized;
+    }
+
+    let language = normalized.split('-').next().unwrap_or_default();
+    if bundles.contains_key(language) {
+        return language.to_string();
+    }
+
+    if let Some(regional_lang) = bundles
+        .keys()
+        .filter(|lang| lang.split('-').next() == Some(language))
+        .min()
+    {
+        return regional_lang.to_string();
+    }
+
+    "en-US".to_string()
+}
+
+fn warn_missing_message(key: &str) {
+    static WARNED_KEYS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let mut warned_keys = WARNED_KEYS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if warned_keys.insert(key.to_string()) {
+        eprintln!("Missing localization for message: {}", key);
+    }
+}
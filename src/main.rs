@@ -22,34 +22,71 @@ mod charts {
     pub mod all_charts;
     pub mod average_earnings_chart;
     pub mod average_time_chart;
+    pub mod cumulative_earnings_chart;
     pub mod earnings_chart;
+    pub mod heatmap_chart;
     pub mod selection_earnings_recorded_chart;
     pub mod selection_time_recorded_chart;
+    pub mod time_of_day_chart;
     pub mod time_recorded_chart;
+    pub mod todo_completion_chart;
 }
+mod cli;
 mod constants;
 mod database;
 mod helpers {
+    pub mod auto_stop;
+    pub mod clock_skew;
     pub mod color_utils;
+    pub mod daily_target;
+    pub mod device;
+    pub mod earnings;
+    pub mod error_log;
+    pub mod formatting;
+    pub mod idle;
+    pub mod keyboard_nav;
     pub mod midnight_subscription;
+    pub mod notification_actions;
+    pub mod power;
+    pub mod project_defaults;
+    pub mod scheduled_summary;
+    pub mod search;
+    pub mod sounds;
+    pub mod summaries;
+    pub mod summary_file;
     pub mod task_actions;
     pub mod tasks;
+    pub mod trash;
     #[cfg(target_os = "linux")]
     pub mod wayland_idle;
+    pub mod year_in_review;
 }
 mod localization;
 mod models {
+    pub mod csv_import;
     pub mod export_settings;
+    pub mod find_replace;
+    pub mod fur_day_note;
+    pub mod fur_days_off;
+    pub mod fur_goal;
     pub mod fur_idle;
+    pub mod fur_invoice;
+    pub mod fur_onboarding;
     pub mod fur_pomodoro;
+    pub mod fur_profile;
     pub mod fur_report;
+    pub mod fur_running_timer;
     pub mod fur_settings;
     pub mod fur_shortcut;
+    pub mod fur_sync_record;
     pub mod fur_task;
     pub mod fur_task_group;
     pub mod fur_todo;
     pub mod fur_user;
     pub mod group_to_edit;
+    pub mod history_selection;
+    pub mod profile_to_add;
+    pub mod recent_tasks;
     pub mod shortcut_to_add;
     pub mod shortcut_to_edit;
     pub mod task_to_add;
@@ -61,9 +98,56 @@ pub mod server {
     pub mod logout;
     pub mod sync;
 }
+mod single_instance;
+mod status_file;
+mod status_server;
 mod style;
 mod tests {
+    mod auto_stop_tests;
+    mod autosave_tests;
+    mod bulk_edit_tests;
+    mod cli_tests;
+    mod clock_skew_tests;
+    mod csv_import_tests;
+    mod daily_target_tests;
+    mod database_merge_tests;
+    mod database_upgrade_tests;
+    mod day_note_tests;
+    mod days_off_tests;
+    mod earnings_tests;
+    mod error_log_tests;
+    mod formatting_tests;
+    mod heatmap_tests;
+    mod history_filter_tests;
+    mod inspector_tests;
+    mod invoice_tests;
+    mod keyboard_nav_tests;
+    mod localization_tests;
+    mod onboarding_tests;
+    mod pomodoro_tests;
+    mod power_tests;
+    mod profile_tests;
+    mod project_color_tests;
+    mod project_defaults_tests;
+    mod recent_tasks_tests;
+    mod report_tests;
+    mod scheduled_summary_tests;
+    mod search_tests;
+    mod settings_tests;
+    mod shortcut_tests;
+    mod single_instance_tests;
+    mod sound_tests;
+    mod status_server_tests;
+    mod summary_file_tests;
+    mod summary_tests;
+    mod sync_tests;
+    mod task_input_tests;
+    mod task_time_tests;
+    mod time_of_day_tests;
     mod timer_tests;
+    mod todo_tests;
+    mod trash_tests;
+    mod year_in_review_tests;
 }
 pub mod ui {
     pub mod todos;
@@ -77,27 +161,71 @@ mod view_enums;
 use std::borrow::Cow;
 
 use app::Furtherance;
+use constants::{DEFAULT_WINDOW_HEIGHT, DEFAULT_WINDOW_WIDTH};
 use image::ImageFormat;
+use models::fur_settings::{FurSettings, get_instance_lock_path, get_instance_port_path};
+use single_instance::{InstanceLock, acquire_instance_lock, raise_running_instance};
 
 #[cfg(target_os = "linux")]
 use iced::window::settings::PlatformSpecific;
 
 fn main() -> iced::Result {
+    // Subcommands run headless for scripting. Without one, the GUI starts as usual.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = cli::parse_args(&args) {
+        std::process::exit(cli::run(command));
+    }
+
+    // Two windows sharing one database would overwrite each other's autosaves and
+    // settings, so a second launch brings the first window forward and quits
+    let _instance_lock = if cli::wants_new_instance(&args) {
+        None
+    } else {
+        match acquire_instance_lock(&get_instance_lock_path(), &get_instance_port_path()) {
+            Ok(InstanceLock::Acquired(lock)) => Some(lock),
+            Ok(InstanceLock::HeldElsewhere) => {
+                if let Err(e) = raise_running_instance(&get_instance_port_path()) {
+                    eprintln!("Furtherance is already running but couldn't be raised: {e}");
+                }
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("Failed to check for a running instance: {e}");
+                None
+            }
+        }
+    };
+
     let window_icon = iced::window::icon::from_file_data(
         include_bytes!("../assets/icon/32x32@2x.png"),
         Some(ImageFormat::Png),
     );
+    let (fur_settings, settings_recovery) = FurSettings::load();
+    let saved_window_size = fur_settings.saved_window_size();
     let window_settings = iced::window::Settings {
-        size: iced::Size {
-            width: 1024.0,
-            height: 600.0,
+        size: match saved_window_size {
+            Some((width, height)) => iced::Size { width, height },
+            None => iced::Size {
+                width: DEFAULT_WINDOW_WIDTH,
+                height: DEFAULT_WINDOW_HEIGHT,
+            },
+        },
+        // Moved back on screen at startup if the displays have changed
+        position: match saved_window_size {
+            Some(_) => iced::window::Position::Specific(iced::Point::new(
+                fur_settings.window_x,
+                fur_settings.window_y,
+            )),
+            None => iced::window::Position::default(),
         },
+        maximized: fur_settings.window_maximized,
         #[cfg(target_os = "linux")]
         platform_specific: PlatformSpecific {
             application_id: String::from("io.unobserved.furtherance"),
             ..Default::default()
         },
         icon: window_icon.ok(),
+        exit_on_close_request: false,
         ..Default::default()
     };
 
@@ -110,11 +238,15 @@ fn main() -> iced::Result {
         ..Default::default()
     };
 
-    iced::application(Furtherance::new, Furtherance::update, Furtherance::view)
-        .subscription(Furtherance::subscription)
-        .title(Furtherance::title)
-        .theme(Furtherance::theme)
-        .window(window_settings)
-        .settings(settings)
-        .run()
+    iced::application(
+        move || Furtherance::new(fur_settings.clone(), settings_recovery.clone()),
+        Furtherance::update,
+        Furtherance::view,
+    )
+    .subscription(Furtherance::subscription)
+    .title(Furtherance::title)
+    .theme(Furtherance::theme)
+    .window(window_settings)
+    .settings(settings)
+    .run()
 }
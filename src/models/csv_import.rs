@@ -0,0 +1,39 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::{fur_day_note::FurDayNote, fur_task::FurTask};
+
+/// A row that couldn't be imported, with a localized description of the problem
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRowError {
+    pub row: u64,
+    pub problem: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CsvImportSummary {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub errors: Vec<CsvRowError>,
+}
+
+/// Everything read from a CSV file, before it is written to the database
+#[derive(Debug, Default)]
+pub struct CsvImport {
+    pub tasks: Vec<FurTask>,
+    pub notes: Vec<FurDayNote>,
+    pub summary: CsvImportSummary,
+}
@@ -30,8 +30,11 @@ pub struct ExportSettings {
     pub project: bool,
     pub rate: bool,
     pub currency: bool,
+    pub billable: bool,
     pub total_time: bool,
     pub total_earnings: bool,
+    pub device: bool,
+    pub day_notes: bool,
     pub filter_by_date: bool,
     pub show_start_date_picker: bool,
     pub show_end_date_picker: bool,
@@ -56,8 +59,11 @@ impl ExportSettings {
             project: true,
             rate: true,
             currency: true,
+            billable: true,
             total_time: true,
             total_earnings: true,
+            device: false,
+            day_notes: true,
             filter_by_date: false,
             show_start_date_picker: false,
             show_end_date_picker: false,
@@ -74,7 +80,7 @@ impl ExportSettings {
         }
     }
 
-    pub fn get_all_projects(&mut self) {
+    pub fn get_all_projects(&mut self, archived_projects: &[String]) {
         let tasks_by_project =
             match db_retrieve_all_existing_tasks(SortBy::StopTime, SortOrder::Descending) {
                 Ok(all_tasks) => all_tasks
@@ -86,7 +92,15 @@ impl ExportSettings {
                 }
             };
 
-        self.list_of_projects = tasks_by_project.keys().cloned().collect();
+        self.list_of_projects = tasks_by_project
+            .keys()
+            .filter(|project| {
+                !archived_projects
+                    .iter()
+                    .any(|archived| archived.eq_ignore_ascii_case(project.trim()))
+            })
+            .cloned()
+            .collect();
     }
 
     pub fn set_picked_end_date(&mut self, new_date: Date) {
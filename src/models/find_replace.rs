@@ -0,0 +1,86 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{database::RenameCounts, view_enums::FurRenameField};
+
+#[derive(Debug, Clone)]
+pub struct FindReplace {
+    pub field: FurRenameField,
+    pub find: String,
+    pub replace: String,
+    pub preview: Option<RenameCounts>,
+}
+
+impl FindReplace {
+    pub fn new() -> Self {
+        FindReplace {
+            field: FurRenameField::Project,
+            find: String::new(),
+            replace: String::new(),
+            preview: None,
+        }
+    }
+
+    pub fn set_field(&mut self, field: FurRenameField) {
+        self.field = field;
+        self.preview = None;
+    }
+
+    pub fn set_find(&mut self, find: String) {
+        self.find = find;
+        self.preview = None;
+    }
+
+    pub fn set_replace(&mut self, replace: String) {
+        self.replace = replace;
+        self.preview = None;
+    }
+
+    /// The value to search for, without a leading `@` or `#`
+    pub fn find_value(&self) -> String {
+        self.strip_prefix(&self.find)
+    }
+
+    pub fn replace_value(&self) -> String {
+        self.strip_prefix(&self.replace)
+    }
+
+    pub fn can_preview(&self) -> bool {
+        let find = self.find_value();
+        let replace = self.replace_value();
+        !find.is_empty()
+            && !replace.is_empty()
+            && find != replace
+            && !(self.field == FurRenameField::Tag && replace.contains('#'))
+            && !(self.field == FurRenameField::Project && replace.contains('@'))
+    }
+
+    /// Nothing is written until a preview has found matches
+    pub fn can_apply(&self) -> bool {
+        self.can_preview() && self.preview.is_some_and(|counts| counts.total() > 0)
+    }
+
+    fn strip_prefix(&self, value: &str) -> String {
+        let value = value.trim();
+        match self.field {
+            FurRenameField::Project => value.trim_start_matches('@'),
+            FurRenameField::Tag => value.trim_start_matches('#'),
+            FurRenameField::TaskName => value,
+        }
+        .trim()
+        .to_string()
+    }
+}
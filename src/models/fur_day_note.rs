@@ -0,0 +1,73 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use chrono::NaiveDate;
+use iced::widget::text_editor;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::clock_skew::sync_timestamp;
+
+// Day notes are local-only for now: the sync server has no record type for them,
+// so they are never sent in sync requests.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FurDayNote {
+    pub date: NaiveDate,
+    pub text: String,
+    pub uid: String,
+    pub last_updated: i64,
+}
+
+impl FurDayNote {
+    pub fn new(date: NaiveDate, text: String) -> Self {
+        FurDayNote {
+            date,
+            text,
+            uid: generate_day_note_uid(&date),
+            last_updated: sync_timestamp(),
+        }
+    }
+
+    pub fn first_line(&self) -> &str {
+        self.text.lines().next().unwrap_or("").trim()
+    }
+}
+
+pub fn generate_day_note_uid(date: &NaiveDate) -> String {
+    let input = format!("daynote{}", date.format("%Y-%m-%d"));
+    blake3::hash(input.as_bytes()).to_hex().to_string()
+}
+
+#[derive(Debug)]
+pub struct DayNoteToEdit {
+    pub date: NaiveDate,
+    pub original_text: String,
+    pub content: text_editor::Content,
+}
+
+impl DayNoteToEdit {
+    pub fn new(date: NaiveDate, note: Option<&FurDayNote>) -> Self {
+        let original_text = note.map(|note| note.text.clone()).unwrap_or_default();
+        DayNoteToEdit {
+            date,
+            content: text_editor::Content::with_text(&original_text),
+            original_text,
+        }
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.content.text().trim() != self.original_text.trim()
+    }
+}
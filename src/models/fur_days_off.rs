@@ -0,0 +1,56 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::models::fur_task::FurTask;
+
+/// Days left out of the per-day averages. Weekends are applied when
+/// aggregating rather than stored, so turning them off again leaves no trace.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FurDaysOff {
+    pub dates: BTreeSet<NaiveDate>,
+    pub weekends: bool,
+}
+
+impl FurDaysOff {
+    pub fn new(dates: BTreeSet<NaiveDate>, weekends: bool) -> Self {
+        FurDaysOff { dates, weekends }
+    }
+
+    /// Only dates marked by hand, which can also be unmarked by hand
+    pub fn is_marked(&self, date: &NaiveDate) -> bool {
+        self.dates.contains(date)
+    }
+
+    pub fn is_day_off(&self, date: &NaiveDate) -> bool {
+        self.is_marked(date)
+            || (self.weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+
+    /// The tasks that count toward per-day averages, keyed by the day they started
+    pub fn counted_tasks<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a FurTask>,
+    ) -> Vec<&'a FurTask> {
+        tasks
+            .into_iter()
+            .filter(|task| !self.is_day_off(&task.start_time.date_naive()))
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::clock_skew::sync_timestamp;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FurGoal {
+    pub project: String,
+    pub weekly_minutes: i64,
+    pub uid: String,
+    pub is_deleted: bool,
+    pub last_updated: i64,
+}
+
+impl FurGoal {
+    pub fn new(project: String, weekly_minutes: i64) -> Self {
+        let uid = generate_goal_uid(&project);
+
+        FurGoal {
+            project,
+            weekly_minutes,
+            uid,
+            is_deleted: false,
+            last_updated: sync_timestamp(),
+        }
+    }
+}
+
+pub fn generate_goal_uid(project: &str) -> String {
+    let input = format!("goal{}", project.to_lowercase());
+    blake3::hash(input.as_bytes()).to_hex().to_string()
+}
+
+#[derive(Clone, Debug)]
+pub struct GoalToAdd {
+    pub project: String,
+    pub weekly_hours: i64,
+    pub invalid_input_error_message: String,
+}
+
+impl GoalToAdd {
+    pub fn new() -> Self {
+        GoalToAdd {
+            project: String::new(),
+            weekly_hours: 10,
+            invalid_input_error_message: String::new(),
+        }
+    }
+
+    pub fn input_error(&mut self, message: String) {
+        self.invalid_input_error_message = message;
+    }
+}
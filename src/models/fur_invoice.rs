@@ -0,0 +1,306 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
+use fluent::FluentValue;
+
+use crate::{
+    helpers::earnings::is_excluded_from_earnings,
+    localization::Localization,
+    models::{fur_report::FurReport, fur_settings::FurSettings, fur_task::FurTask},
+    update::msg_helper_functions::round_seconds,
+    view_enums::FurTaskProperty,
+};
+
+/// The fields printed at the top of an invoice, remembered between invoices
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InvoiceHeader {
+    pub from: String,
+    pub client: String,
+    pub number: String,
+    pub notes: String,
+}
+
+impl InvoiceHeader {
+    pub fn from_settings(settings: &FurSettings) -> Self {
+        InvoiceHeader {
+            from: settings.invoice_from.clone(),
+            client: settings.invoice_client.clone(),
+            number: settings.invoice_number.clone(),
+            notes: settings.invoice_notes.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvoiceProject {
+    All,
+    Project(String),
+}
+
+impl std::fmt::Display for InvoiceProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvoiceProject::All => {
+                write!(
+                    f,
+                    "{}",
+                    Localization::new().get_message("all-projects", None)
+                )
+            }
+            InvoiceProject::Project(project) => write!(f, "@{}", project),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvoiceToGenerate {
+    pub header: InvoiceHeader,
+    pub project: InvoiceProject,
+    pub projects: Vec<InvoiceProject>,
+    pub message: Result<String, Box<dyn std::error::Error>>,
+}
+
+impl InvoiceToGenerate {
+    /// Starts from the last header used and the project picked in the report, if any
+    pub fn new(report: &FurReport, settings: &FurSettings) -> Self {
+        let mut project_names: Vec<String> = report
+            .data
+            .tasks_in_range
+            .iter()
+            .filter(|task| task.is_billable && !task.project.is_empty())
+            .map(|task| task.project.clone())
+            .collect();
+        project_names.sort_by_key(|project| project.to_lowercase());
+        project_names.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+        let project = match (
+            &report.picked_task_property_key,
+            &report.picked_task_property_value,
+        ) {
+            (Some(FurTaskProperty::Project), Some(value))
+                if project_names.iter().any(|p| p.eq_ignore_ascii_case(value)) =>
+            {
+                InvoiceProject::Project(value.clone())
+            }
+            _ => InvoiceProject::All,
+        };
+
+        let mut projects = vec![InvoiceProject::All];
+        projects.extend(project_names.into_iter().map(InvoiceProject::Project));
+
+        InvoiceToGenerate {
+            header: InvoiceHeader::from_settings(settings),
+            project,
+            projects,
+            message: Ok(String::new()),
+        }
+    }
+}
+
+/// Tasks with the same name, project, rate and currency on one day, billed as one line
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceLine {
+    pub date: NaiveDate,
+    pub name: String,
+    pub project: String,
+    pub seconds: i64,
+    pub rate: f32,
+    pub currency: String,
+    pub amount: f32,
+}
+
+/// Billable tasks in `tasks` grouped into invoice lines, oldest first, leaving out
+/// the tags and projects excluded from earnings.
+/// Each task's duration is rounded on its own, as in the report totals.
+pub fn invoice_lines(
+    tasks: &[FurTask],
+    project: &InvoiceProject,
+    settings: &FurSettings,
+) -> Vec<InvoiceLine> {
+    let mut lines: Vec<InvoiceLine> = vec![];
+    let mut line_indexes: HashMap<(NaiveDate, String, String, String, String), usize> =
+        HashMap::new();
+
+    let mut tasks: Vec<&FurTask> = tasks
+        .iter()
+        .filter(|task| task.is_billable)
+        .filter(|task| {
+            !is_excluded_from_earnings(&task.tags, &task.project, &settings.earnings_exclusions)
+        })
+        .filter(|task| match project {
+            InvoiceProject::All => true,
+            InvoiceProject::Project(project) => task.project.eq_ignore_ascii_case(project),
+        })
+        .collect();
+    tasks.sort_by_key(|task| task.start_time);
+
+    for task in tasks {
+        let seconds = round_seconds(task.total_time_in_seconds(), settings);
+        let amount = task.earnings_for(seconds);
+        let key = (
+            task.start_time.date_naive(),
+            task.name.clone(),
+            task.project.clone(),
+            format!("{:.2}", task.rate),
+            task.currency.clone(),
+        );
+        match line_indexes.get(&key) {
+            Some(&index) => {
+                lines[index].seconds += seconds;
+                lines[index].amount += amount;
+            }
+            None => {
+                line_indexes.insert(key, lines.len());
+                lines.push(InvoiceLine {
+                    date: task.start_time.date_naive(),
+                    name: task.name.clone(),
+                    project: task.project.clone(),
+                    seconds,
+                    rate: task.rate,
+                    currency: task.currency.clone(),
+                    amount,
+                });
+            }
+        }
+    }
+
+    lines
+}
+
+/// The amount owed in each currency across `lines`
+pub fn invoice_totals(lines: &[InvoiceLine]) -> BTreeMap<String, f32> {
+    let mut totals: BTreeMap<String, f32> = BTreeMap::new();
+    for line in lines {
+        *totals.entry(line.currency.clone()).or_insert(0.0) += line.amount;
+    }
+    totals
+}
+
+pub fn format_invoice_amount(amount: f32, currency: &str) -> String {
+    if currency.trim().is_empty() {
+        format!("${:.2}", amount)
+    } else {
+        format!("{:.2} {}", amount, currency.trim())
+    }
+}
+
+/// A standalone HTML invoice, ready to open in a browser or print to PDF
+pub fn invoice_html(
+    header: &InvoiceHeader,
+    lines: &[InvoiceLine],
+    period: (NaiveDate, NaiveDate),
+    localization: &Localization,
+) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<style>\n\
+         body { font-family: sans-serif; margin: 40px; color: #222; }\n\
+         table { width: 100%; border-collapse: collapse; margin-top: 30px; }\n\
+         th, td { padding: 6px 8px; border-bottom: 1px solid #ddd; text-align: left; }\n\
+         .number { text-align: right; }\n\
+         .total td { font-weight: bold; border-bottom: none; }\n\
+         .party { white-space: pre-line; }\n\
+         </style>\n",
+    );
+    html += &format!(
+        "<title>{}</title>\n</head>\n<body>\n",
+        escape_html(&localization.get_message("invoice", None))
+    );
+
+    html += &format!(
+        "<h1>{}</h1>\n",
+        escape_html(&localization.get_message("invoice", None))
+    );
+    if !header.number.trim().is_empty() {
+        html += &format!(
+            "<p>{} {}</p>\n",
+            escape_html(&localization.get_message("invoice-number", None)),
+            escape_html(header.number.trim())
+        );
+    }
+    html += &format!(
+        "<p>{}</p>\n",
+        escape_html(&localization.get_message(
+            "invoice-period",
+            Some(&HashMap::from([
+                ("start", FluentValue::from(period.0.to_string())),
+                ("end", FluentValue::from(period.1.to_string())),
+            ])),
+        ))
+    );
+    for (message_id, party) in [("invoice-from", &header.from), ("bill-to", &header.client)] {
+        if !party.trim().is_empty() {
+            html += &format!(
+                "<h3>{}</h3>\n<p class=\"party\">{}</p>\n",
+                escape_html(&localization.get_message(message_id, None)),
+                escape_html(party.trim())
+            );
+        }
+    }
+
+    html += &format!(
+        "<table>\n<tr><th>{}</th><th>{}</th><th class=\"number\">{}</th>\
+         <th class=\"number\">{}</th><th class=\"number\">{}</th></tr>\n",
+        escape_html(&localization.get_message("date", None)),
+        escape_html(&localization.get_message("description", None)),
+        escape_html(&localization.get_message("hours", None)),
+        escape_html(&localization.get_message("rate", None)),
+        escape_html(&localization.get_message("amount", None)),
+    );
+    for line in lines {
+        let description = if line.project.is_empty() {
+            line.name.clone()
+        } else {
+            format!("{} @{}", line.name, line.project)
+        };
+        html += &format!(
+            "<tr><td>{}</td><td>{}</td><td class=\"number\">{:.2}</td>\
+             <td class=\"number\">{}</td><td class=\"number\">{}</td></tr>\n",
+            line.date,
+            escape_html(&description),
+            line.seconds as f32 / 3600.0,
+            escape_html(&format_invoice_amount(line.rate, &line.currency)),
+            escape_html(&format_invoice_amount(line.amount, &line.currency)),
+        );
+    }
+    for (currency, total) in invoice_totals(lines) {
+        html += &format!(
+            "<tr class=\"total\"><td colspan=\"4\">{}</td><td class=\"number\">{}</td></tr>\n",
+            escape_html(&localization.get_message("total", None)),
+            escape_html(&format_invoice_amount(total, &currency)),
+        );
+    }
+    html += "</table>\n";
+
+    if !header.notes.trim().is_empty() {
+        html += &format!(
+            "<p class=\"party\">{}</p>\n",
+            escape_html(header.notes.trim())
+        );
+    }
+    html += "</body>\n</html>\n";
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
@@ -0,0 +1,88 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::view_enums::OnboardingStep;
+
+#[derive(Clone, Debug)]
+pub struct FurOnboarding {
+    pub example_input: String,
+    pub shortcut_created: bool,
+    /// Offered once the onboarding is finished or skipped
+    pub offer_old_db_import: bool,
+    step_index: usize,
+    steps: Vec<OnboardingStep>,
+}
+
+impl FurOnboarding {
+    pub fn new(offer_old_db_import: bool) -> Self {
+        FurOnboarding {
+            example_input: "Write report @Work #writing #draft $25".to_string(),
+            shortcut_created: false,
+            offer_old_db_import,
+            step_index: 0,
+            steps: vec![
+                OnboardingStep::TaskSyntax,
+                OnboardingStep::Settings,
+                OnboardingStep::IdleAndPomodoro,
+                OnboardingStep::FirstShortcut,
+                OnboardingStep::Sync,
+            ],
+        }
+    }
+
+    pub fn current_step(&self) -> OnboardingStep {
+        self.steps
+            .get(self.step_index)
+            .copied()
+            .unwrap_or(OnboardingStep::Sync)
+    }
+
+    pub fn step_number(&self) -> usize {
+        self.step_index + 1
+    }
+
+    pub fn total_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_first_step(&self) -> bool {
+        self.step_index == 0
+    }
+
+    pub fn is_last_step(&self) -> bool {
+        self.step_index + 1 >= self.steps.len()
+    }
+
+    /// Moves to the next step. Returns false if there are no steps left.
+    pub fn advance(&mut self) -> bool {
+        if self.is_last_step() {
+            false
+        } else {
+            self.step_index += 1;
+            true
+        }
+    }
+
+    /// Moves to the previous step. Returns false if already on the first step.
+    pub fn go_back(&mut self) -> bool {
+        if self.is_first_step() {
+            false
+        } else {
+            self.step_index -= 1;
+            true
+        }
+    }
+}
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::{DateTime, Local};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
+
+use crate::database::{db_count_pomodoro_sessions, db_retrieve_pomodoro_session_days};
 
 #[derive(Clone, Debug)]
 pub struct FurPomodoro {
@@ -22,6 +26,7 @@ pub struct FurPomodoro {
     pub sessions: u16,
     pub snoozed: bool,
     pub snoozed_at: DateTime<Local>,
+    pub stats: FurPomodoroStats,
 }
 
 impl FurPomodoro {
@@ -31,6 +36,67 @@ impl FurPomodoro {
             sessions: 0,
             snoozed: false,
             snoozed_at: Local::now(),
+            stats: FurPomodoroStats::default(),
+        }
+    }
+}
+
+/// A pomodoro that ran to the end. Only kept locally.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FurPomodoroSession {
+    pub completed_at: DateTime<Local>,
+    pub task_name: String,
+    /// In seconds
+    pub length: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FurPomodoroStats {
+    pub today: usize,
+    pub this_week: usize,
+    pub streak: usize,
+}
+
+impl FurPomodoroStats {
+    /// Counts the completed sessions for today and this week (starting Monday)
+    pub fn load(today: NaiveDate) -> Self {
+        let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let count = |start: NaiveDate| match db_count_pomodoro_sessions(start, today) {
+            Ok(count) => count,
+            Err(e) => {
+                eprintln!("Could not count pomodoro sessions: {}", e);
+                0
+            }
+        };
+        let days = match db_retrieve_pomodoro_session_days() {
+            Ok(days) => days,
+            Err(e) => {
+                eprintln!("Could not retrieve pomodoro session days: {}", e);
+                BTreeSet::new()
+            }
+        };
+
+        FurPomodoroStats {
+            today: count(today),
+            this_week: count(week_start),
+            streak: pomodoro_streak(&days, today),
         }
     }
 }
+
+/// The number of consecutive days with a completed session, ending today.
+/// A streak isn't broken until a whole day passes without a session, so one ending
+/// yesterday still counts.
+pub fn pomodoro_streak(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> usize {
+    let mut day = if days.contains(&today) {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day -= Duration::days(1);
+    }
+    streak
+}
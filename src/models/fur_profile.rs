@@ -0,0 +1,105 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fmt, fs::create_dir_all, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{constants::ALLOWED_DB_EXTENSIONS, database::db_is_valid_v3};
+
+/// A named database the user can switch to
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FurProfile {
+    pub name: String,
+    pub database_url: String,
+}
+
+impl fmt::Display for FurProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProfileError {
+    EmptyName,
+    DuplicateName,
+    InvalidExtension,
+    InvalidDatabase,
+    UnreachablePath,
+}
+
+impl ProfileError {
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ProfileError::EmptyName => "profile-name-empty",
+            ProfileError::DuplicateName => "profile-name-taken",
+            ProfileError::InvalidExtension => "profile-invalid-extension",
+            ProfileError::InvalidDatabase => "invalid-database",
+            ProfileError::UnreachablePath => "profile-path-unreachable",
+        }
+    }
+}
+
+/// A profile name must be set and not match another profile's, ignoring case.
+/// `renaming` is the profile being renamed, which may keep its own name.
+pub fn validate_profile_name(
+    profiles: &[FurProfile],
+    name: &str,
+    renaming: Option<&str>,
+) -> Result<(), ProfileError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ProfileError::EmptyName);
+    }
+    if profiles.iter().any(|profile| {
+        profile.name.eq_ignore_ascii_case(name) && Some(profile.name.as_str()) != renaming
+    }) {
+        return Err(ProfileError::DuplicateName);
+    }
+    Ok(())
+}
+
+/// An existing file must be a Furtherance database. A new one needs a database
+/// extension and a folder that exists or can be created.
+pub fn validate_profile_path(database_url: &str) -> Result<(), ProfileError> {
+    let path = Path::new(database_url.trim());
+    let has_db_extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            ALLOWED_DB_EXTENSIONS
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+        });
+    if !has_db_extension {
+        return Err(ProfileError::InvalidExtension);
+    }
+
+    // Older databases are upgraded when opened from the database location instead
+    if path.exists() {
+        return match db_is_valid_v3(path) {
+            Ok(true) => Ok(()),
+            _ => Err(ProfileError::InvalidDatabase),
+        };
+    }
+
+    match path.parent() {
+        Some(parent) if parent.as_os_str().is_empty() => Err(ProfileError::UnreachablePath),
+        Some(parent) => create_dir_all(parent).map_err(|_| ProfileError::UnreachablePath),
+        None => Err(ProfileError::UnreachablePath),
+    }
+}
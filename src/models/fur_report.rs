@@ -14,65 +14,369 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
-use chrono::{Datelike, Days, Duration, Local, NaiveDate, Utc};
+use chrono::{Datelike, Days, Duration, Local, Months, NaiveDate, Utc};
+use iced::Task;
 use iced_aw::date_picker::Date;
+use itertools::Itertools;
+use palette::Srgb;
 
 use crate::{
     charts::{
-        average_earnings_chart::AverageEarningsChart, average_time_chart::AverageTimeChart,
-        earnings_chart::EarningsChart,
+        all_charts, average_earnings_chart::AverageEarningsChart,
+        average_time_chart::AverageTimeChart, cumulative_earnings_chart::CumulativeEarningsChart,
+        earnings_chart::EarningsChart, heatmap_chart::HeatmapChart,
         selection_earnings_recorded_chart::SelectionEarningsRecordedChart,
         selection_time_recorded_chart::SelectionTimeRecordedChart,
-        time_recorded_chart::TimeRecordedChart,
+        time_of_day_chart::TimeOfDayChart, time_recorded_chart::TimeRecordedChart,
+        todo_completion_chart::TodoCompletionChart,
+    },
+    database::{db_retrieve_existing_goals, db_retrieve_tasks_by_date_range},
+    helpers::{
+        daily_target::DailyTarget,
+        earnings::task_earnings,
+        tasks::{changed_task_dates, group_tasks_into_history},
     },
-    database::db_retrieve_tasks_by_date_range,
     localization::Localization,
-    view_enums::{FurDateRange, FurTaskProperty, TabId},
+    update::{messages::Message, msg_helper_functions::normalize_tags},
+    view_enums::{FurBillableFilter, FurComparisonPeriod, FurDateRange, FurTaskProperty, TabId},
+};
+
+use super::{
+    fur_days_off::FurDaysOff, fur_goal::FurGoal, fur_task::FurTask, fur_task_group::FurTaskGroup,
 };
 
-use super::fur_task::FurTask;
+/// The tasks a report covers. Computed report data is cached under this key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub billable_filter: FurBillableFilter,
+}
+
+impl ReportRange {
+    fn overlaps(&self, dates: &BTreeSet<NaiveDate>) -> bool {
+        dates.range(self.start..=self.end).next().is_some()
+    }
+
+    /// The range this one is compared with. The previous period is the same
+    /// number of days ending the day before this range starts.
+    pub fn previous(&self, period: FurComparisonPeriod) -> ReportRange {
+        let (start, end) = match period {
+            FurComparisonPeriod::PreviousPeriod => {
+                let days = (self.end - self.start).num_days() + 1;
+                (
+                    self.start - Duration::days(days),
+                    self.start - Duration::days(1),
+                )
+            }
+            FurComparisonPeriod::PreviousYear => (
+                self.start
+                    .checked_sub_months(Months::new(12))
+                    .unwrap_or(self.start),
+                self.end
+                    .checked_sub_months(Months::new(12))
+                    .unwrap_or(self.end),
+            ),
+        };
+        ReportRange {
+            start,
+            end,
+            billable_filter: self.billable_filter,
+        }
+    }
+}
+
+/// The tasks in a range and everything the charts need from them
+#[derive(Clone, Debug)]
+pub struct FurReportData {
+    pub tasks_in_range: Vec<FurTask>,
+    pub total_time: i64,
+    pub total_earned: f32,
+    pub time_recorded_chart: TimeRecordedChart,
+    pub earnings_chart: EarningsChart,
+    pub cumulative_earnings_chart: CumulativeEarningsChart,
+    pub average_time_chart: AverageTimeChart,
+    pub average_earnings_chart: AverageEarningsChart,
+    pub time_of_day_chart: TimeOfDayChart,
+    pub workday_stats: Option<WorkdayStats>,
+    /// Seconds tracked on each day, keyed by the day a task started
+    pub day_totals: BTreeMap<NaiveDate, i64>,
+    /// Tasks in range without a length, which the averages leave out
+    pub zero_length_tasks: usize,
+}
+
+impl FurReportData {
+    /// Reads and aggregates the tasks in `range`. This is slow with large
+    /// databases, so it runs in a Task rather than on the UI thread.
+    pub fn load(
+        range: ReportRange,
+        monthly_earnings_target: f32,
+        days_off: &FurDaysOff,
+        earnings_exclusions: &[String],
+    ) -> Self {
+        let mut tasks = match db_retrieve_tasks_by_date_range(range.start, range.end) {
+            Ok(tasks) => tasks,
+            Err(e) => {
+                eprintln!("Could not retrieve data in range: {}", e);
+                vec![]
+            }
+        };
+        tasks.retain(|task| range.billable_filter.includes(task.is_billable));
+
+        FurReportData::from_tasks(
+            tasks,
+            monthly_earnings_target,
+            days_off,
+            earnings_exclusions,
+        )
+    }
+
+    pub fn from_tasks(
+        tasks: Vec<FurTask>,
+        monthly_earnings_target: f32,
+        days_off: &FurDaysOff,
+        earnings_exclusions: &[String],
+    ) -> Self {
+        let (total_time, total_earned) =
+            tasks
+                .iter()
+                .fold((0, 0.0), |(time_accumulated, earned_accumulated), task| {
+                    (
+                        time_accumulated + task.total_time_in_seconds(),
+                        earned_accumulated
+                            + task_earnings(
+                                task,
+                                task.total_time_in_seconds(),
+                                earnings_exclusions,
+                            ),
+                    )
+                });
+        // Days off would drag the per-day averages down
+        let counted_tasks = days_off.counted_tasks(&tasks);
+        let mut day_totals = BTreeMap::new();
+        for task in &tasks {
+            *day_totals.entry(task.start_time.date_naive()).or_insert(0) +=
+                task.total_time_in_seconds();
+        }
+
+        FurReportData {
+            total_time,
+            total_earned,
+            time_recorded_chart: TimeRecordedChart::new(&tasks),
+            earnings_chart: EarningsChart::new(&tasks, earnings_exclusions),
+            cumulative_earnings_chart: CumulativeEarningsChart::new(
+                &tasks,
+                monthly_earnings_target,
+                earnings_exclusions,
+            ),
+            average_time_chart: AverageTimeChart::new(&counted_tasks),
+            average_earnings_chart: AverageEarningsChart::new(&counted_tasks, earnings_exclusions),
+            time_of_day_chart: TimeOfDayChart::new(
+                &tasks.iter().collect::<Vec<_>>(),
+                all_charts::series_color(None),
+            ),
+            workday_stats: WorkdayStats::from_tasks(&counted_tasks),
+            day_totals,
+            zero_length_tasks: all_charts::zero_length_task_count(&tasks),
+            tasks_in_range: tasks,
+        }
+    }
+}
+
+/// The tasks matching the picked breakdown property and value, and their charts
+#[derive(Clone, Debug)]
+pub struct FurSelectionData {
+    pub history: BTreeMap<NaiveDate, Vec<FurTaskGroup>>,
+    pub total_time: i64,
+    pub total_earned: f32,
+    pub time_recorded_chart: SelectionTimeRecordedChart,
+    pub earnings_recorded_chart: SelectionEarningsRecordedChart,
+    pub time_of_day_chart: TimeOfDayChart,
+    pub workday_stats: Option<WorkdayStats>,
+}
+
+impl FurSelectionData {
+    pub fn from_tasks(
+        tasks: &[&FurTask],
+        color: Option<Srgb>,
+        days_off: &FurDaysOff,
+        earnings_exclusions: &[String],
+    ) -> Self {
+        let (total_time, total_earned) =
+            tasks
+                .iter()
+                .fold((0, 0.0), |(time_accumulated, earned_accumulated), task| {
+                    (
+                        time_accumulated + task.total_time_in_seconds(),
+                        earned_accumulated
+                            + task_earnings(
+                                task,
+                                task.total_time_in_seconds(),
+                                earnings_exclusions,
+                            ),
+                    )
+                });
+        let color = all_charts::series_color(color);
+
+        FurSelectionData {
+            // Newest first, like the task history
+            history: group_tasks_into_history(tasks.iter().rev().map(|&t| t.clone()).collect()),
+            total_time,
+            total_earned,
+            time_recorded_chart: SelectionTimeRecordedChart::new(tasks, color),
+            earnings_recorded_chart: SelectionEarningsRecordedChart::new(
+                tasks,
+                color,
+                earnings_exclusions,
+            ),
+            time_of_day_chart: TimeOfDayChart::new(tasks, color),
+            workday_stats: WorkdayStats::from_tasks(&days_off.counted_tasks(tasks.iter().copied())),
+        }
+    }
+}
+
+/// When the tracked days typically start and end. Times are seconds after
+/// midnight of the day the first task started, so a workday ending after
+/// midnight averages past 24 hours instead of wrapping to the morning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkdayStats {
+    pub active_days: usize,
+    pub average_start: i64,
+    pub average_end: i64,
+    pub average_tracked: i64,
+}
+
+impl WorkdayStats {
+    /// None when there are no tasks, so the card can be hidden
+    pub fn from_tasks(tasks: &[&FurTask]) -> Option<Self> {
+        // Start, end and tracked seconds for each day, keyed by the day a task started
+        let mut days: BTreeMap<NaiveDate, (i64, i64, i64)> = BTreeMap::new();
+        for task in tasks {
+            let date = task.start_time.date_naive();
+            let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+            let start = (task.start_time.naive_local() - midnight).num_seconds();
+            let end = (task.stop_time.naive_local() - midnight).num_seconds();
+            let day = days.entry(date).or_insert((start, end, 0));
+            day.0 = day.0.min(start);
+            day.1 = day.1.max(end);
+            day.2 += task.total_time_in_seconds();
+        }
+
+        if days.is_empty() {
+            return None;
+        }
+
+        let active_days = days.len();
+        let (starts, ends, tracked) = days.values().fold(
+            (0, 0, 0),
+            |(starts, ends, tracked), (start, end, day_tracked)| {
+                (starts + start, ends + end, tracked + day_tracked)
+            },
+        );
+
+        Some(WorkdayStats {
+            active_days,
+            average_start: starts / active_days as i64,
+            average_end: ends / active_days as i64,
+            average_tracked: tracked / active_days as i64,
+        })
+    }
+}
+
+/// The previous period's data with the charts overlaying it on the current range
+#[derive(Clone, Debug)]
+pub struct FurReportComparison {
+    pub previous: Arc<FurReportData>,
+    pub time_recorded_chart: TimeRecordedChart,
+    pub earnings_chart: EarningsChart,
+}
+
+/// How much `current` changed since `previous` in percent, or None if there was nothing before
+pub fn percent_change(current: f64, previous: f64) -> Option<f64> {
+    if previous == 0.0 {
+        None
+    } else {
+        Some((current - previous) / previous * 100.0)
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct FurReport {
     pub active_tab: TabId,
-    pub average_earnings_chart: AverageEarningsChart,
-    pub average_time_chart: AverageTimeChart,
+    archived_projects: Vec<String>,
+    pub billable_filter: FurBillableFilter,
+    cache: HashMap<ReportRange, Arc<FurReportData>>,
+    pub compare_with_previous: bool,
+    pub comparison: Option<FurReportComparison>,
+    pub comparison_period: FurComparisonPeriod,
+    pub data: Arc<FurReportData>,
     date_range_end: NaiveDate,
     date_range_start: NaiveDate,
+    days_off: FurDaysOff,
+    earnings_exclusions: Vec<String>,
+    pub heatmap_chart: Arc<HeatmapChart>,
+    heatmap_pending: bool,
+    heatmap_stale: bool,
+    monthly_earnings_target: f32,
+    pending: HashSet<ReportRange>,
     pub picked_date_range: Option<FurDateRange>,
     pub picked_end_date: Date,
     pub picked_start_date: Date,
     pub picked_task_property_key: Option<FurTaskProperty>,
     pub picked_task_property_value: Option<String>,
-    pub selection_total_time: i64,
-    pub selection_total_earned: f32,
-    pub selection_earnings_recorded_chart: SelectionEarningsRecordedChart,
-    pub selection_time_recorded_chart: SelectionTimeRecordedChart,
+    pub picked_tags: BTreeSet<String>,
+    project_colors: HashMap<String, Srgb>,
+    running_time: Option<(NaiveDate, i64)>,
+    /// The time recorded chart with the running task added, when there is one in range
+    pub running_time_chart: Option<TimeRecordedChart>,
+    pub selection: Arc<FurSelectionData>,
+    selection_cache: HashMap<(ReportRange, FurTaskProperty, String), Arc<FurSelectionData>>,
     pub show_end_date_picker: bool,
+    show_heatmap: bool,
     pub show_start_date_picker: bool,
-    pub total_time: i64,
-    pub total_earned: f32,
-    pub tasks_in_range: Vec<FurTask>,
+    pub show_task_property_value_picker: bool,
+    show_todo_chart: bool,
+    pub task_property_value_filter: String,
+    /// Lowercase copies of `task_property_value_keys`, in the same order, for filtering
+    task_property_value_index: Vec<String>,
     pub task_property_value_keys: Vec<String>,
     pub task_property_values: HashMap<String, Vec<usize>>,
-    pub time_recorded_chart: TimeRecordedChart,
-    pub earnings_chart: EarningsChart,
+    pub todo_completion_chart: Arc<TodoCompletionChart>,
+    pub weekly_goal_progress: Vec<(FurGoal, i64)>,
 }
 
 impl FurReport {
+    /// An empty report. Call `refresh` to load the tasks in range.
     pub fn new() -> Self {
         let thirty_days_ago = Utc::now()
             .checked_sub_days(Days::new(30))
             .unwrap_or(Utc::now());
-        let mut fur_report = FurReport {
+        FurReport {
             active_tab: TabId::Charts,
-            average_earnings_chart: AverageEarningsChart::new(&[]),
-            average_time_chart: AverageTimeChart::new(&[]),
+            archived_projects: vec![],
+            billable_filter: FurBillableFilter::All,
+            cache: HashMap::new(),
+            compare_with_previous: false,
+            comparison: None,
+            comparison_period: FurComparisonPeriod::PreviousPeriod,
+            data: Arc::new(FurReportData::from_tasks(
+                vec![],
+                0.0,
+                &FurDaysOff::default(),
+                &[],
+            )),
             date_range_end: Local::now().date_naive(),
             date_range_start: (Local::now() - Duration::days(30)).date_naive(),
-            earnings_chart: EarningsChart::new(&[]),
+            days_off: FurDaysOff::default(),
+            earnings_exclusions: vec![],
+            heatmap_chart: Arc::new(HeatmapChart::new(&[], Local::now().date_naive())),
+            heatmap_pending: false,
+            heatmap_stale: true,
+            monthly_earnings_target: 0.0,
+            pending: HashSet::new(),
             picked_date_range: Some(FurDateRange::ThirtyDays),
             picked_end_date: Date::today(),
             picked_start_date: Date::from_ymd(
@@ -82,102 +386,227 @@ impl FurReport {
             ),
             picked_task_property_key: Some(FurTaskProperty::Title),
             picked_task_property_value: None,
-            selection_total_time: 0,
-            selection_total_earned: 0.0,
-            selection_earnings_recorded_chart: SelectionEarningsRecordedChart::new(&[]),
-            selection_time_recorded_chart: SelectionTimeRecordedChart::new(&[]),
+            picked_tags: BTreeSet::new(),
+            project_colors: HashMap::new(),
+            running_time: None,
+            running_time_chart: None,
+            selection: Arc::new(FurSelectionData::from_tasks(
+                &[],
+                None,
+                &FurDaysOff::default(),
+                &[],
+            )),
+            selection_cache: HashMap::new(),
             show_end_date_picker: false,
+            show_heatmap: false,
             show_start_date_picker: false,
-            total_time: 0,
-            total_earned: 0.0,
-            tasks_in_range: vec![],
+            show_task_property_value_picker: false,
+            show_todo_chart: false,
+            task_property_value_filter: String::new(),
+            task_property_value_index: vec![],
             task_property_value_keys: vec![],
             task_property_values: HashMap::new(),
-            time_recorded_chart: TimeRecordedChart::new(&[]),
-        };
-
-        fur_report.update_tasks_in_range();
-
-        fur_report
+            todo_completion_chart: Arc::new(TodoCompletionChart::default()),
+            weekly_goal_progress: vec![],
+        }
     }
 
-    pub fn set_picked_date_ranged(&mut self, new_range: FurDateRange) {
-        if self.picked_date_range != Some(new_range) {
-            self.picked_date_range = Some(new_range);
-            match new_range {
-                FurDateRange::PastWeek => {
-                    self.date_range_start = (Local::now() - Duration::days(7)).date_naive();
-                    self.date_range_end = Local::now().date_naive();
-                }
-                FurDateRange::ThisMonth => {
-                    if let Some((start_date, end_date)) = get_current_month_bounds() {
-                        self.date_range_start = start_date;
-                        self.date_range_end = end_date;
-                    } else {
-                        self.set_picked_date_ranged(FurDateRange::ThirtyDays);
-                    }
-                }
-                FurDateRange::LastMonth => {
-                    if let Some((start_date, end_date)) = get_last_month_bounds() {
-                        self.date_range_start = start_date;
-                        self.date_range_end = end_date;
-                    } else {
-                        self.set_picked_date_ranged(FurDateRange::ThirtyDays);
-                    }
-                }
-                FurDateRange::ThirtyDays => {
-                    self.date_range_start = (Local::now() - Duration::days(30)).date_naive();
-                    self.date_range_end = Local::now().date_naive();
-                }
-                FurDateRange::SixMonths => {
-                    self.date_range_start = self.subtract_months(Local::now().date_naive(), 6);
-                    self.date_range_end = Local::now().date_naive();
+    pub fn set_picked_date_ranged(&mut self, new_range: FurDateRange) -> Task<Message> {
+        if self.picked_date_range == Some(new_range) {
+            return Task::none();
+        }
+
+        self.picked_date_range = Some(new_range);
+        match new_range {
+            FurDateRange::PastWeek => {
+                self.date_range_start = (Local::now() - Duration::days(7)).date_naive();
+                self.date_range_end = Local::now().date_naive();
+            }
+            FurDateRange::ThisMonth => {
+                if let Some((start_date, end_date)) = get_current_month_bounds() {
+                    self.date_range_start = start_date;
+                    self.date_range_end = end_date;
+                } else {
+                    return self.set_picked_date_ranged(FurDateRange::ThirtyDays);
                 }
-                FurDateRange::AllTime => {
-                    self.date_range_start = NaiveDate::parse_from_str("1971-01-01", "%Y-%m-%d")
-                        .unwrap_or(Local::now().date_naive());
-                    self.date_range_end = NaiveDate::parse_from_str("2300-01-01", "%Y-%m-%d")
-                        .unwrap_or(Local::now().date_naive());
+            }
+            FurDateRange::LastMonth => {
+                if let Some((start_date, end_date)) = get_last_month_bounds() {
+                    self.date_range_start = start_date;
+                    self.date_range_end = end_date;
+                } else {
+                    return self.set_picked_date_ranged(FurDateRange::ThirtyDays);
                 }
-                FurDateRange::Range => {
-                    if let Some(new_start_date) = NaiveDate::from_ymd_opt(
-                        self.picked_start_date.year,
-                        self.picked_start_date.month,
-                        self.picked_start_date.day,
+            }
+            FurDateRange::ThirtyDays => {
+                self.date_range_start = (Local::now() - Duration::days(30)).date_naive();
+                self.date_range_end = Local::now().date_naive();
+            }
+            FurDateRange::SixMonths => {
+                self.date_range_start = self.subtract_months(Local::now().date_naive(), 6);
+                self.date_range_end = Local::now().date_naive();
+            }
+            FurDateRange::AllTime => {
+                self.date_range_start = NaiveDate::parse_from_str("1971-01-01", "%Y-%m-%d")
+                    .unwrap_or(Local::now().date_naive());
+                self.date_range_end = NaiveDate::parse_from_str("2300-01-01", "%Y-%m-%d")
+                    .unwrap_or(Local::now().date_naive());
+            }
+            FurDateRange::Range => {
+                if let Some(new_start_date) = NaiveDate::from_ymd_opt(
+                    self.picked_start_date.year,
+                    self.picked_start_date.month,
+                    self.picked_start_date.day,
+                ) {
+                    if let Some(new_end_date) = NaiveDate::from_ymd_opt(
+                        self.picked_end_date.year,
+                        self.picked_end_date.month,
+                        self.picked_end_date.day,
                     ) {
-                        if let Some(new_end_date) = NaiveDate::from_ymd_opt(
-                            self.picked_end_date.year,
-                            self.picked_end_date.month,
-                            self.picked_end_date.day,
-                        ) {
-                            if new_start_date <= new_end_date {
-                                self.date_range_start = new_start_date;
-                                self.date_range_end = new_end_date;
-                            }
+                        if new_start_date <= new_end_date {
+                            self.date_range_start = new_start_date;
+                            self.date_range_end = new_end_date;
                         }
                     }
                 }
             }
-            self.update_tasks_in_range();
+        }
+        self.refresh()
+    }
+
+    pub fn set_archived_projects(&mut self, archived_projects: Vec<String>) {
+        self.archived_projects = archived_projects;
+        self.populate_task_property_values();
+        self.update_selection();
+    }
+
+    pub fn set_project_colors(&mut self, project_colors: HashMap<String, Srgb>) {
+        self.project_colors = project_colors;
+        // Project selections are drawn in the project's color
+        self.selection_cache.clear();
+        self.update_selection();
+    }
+
+    /// Changes the target line on the cumulative earnings chart. Call `refresh`
+    /// afterward to redraw the chart.
+    pub fn set_monthly_earnings_target(&mut self, monthly_earnings_target: f32) {
+        if self.monthly_earnings_target != monthly_earnings_target {
+            self.monthly_earnings_target = monthly_earnings_target;
+            self.invalidate_all();
+        }
+    }
+
+    /// Changes which tags and projects are left out of the earnings. Call `refresh`
+    /// afterward to redraw the charts.
+    pub fn set_earnings_exclusions(&mut self, earnings_exclusions: Vec<String>) {
+        if self.earnings_exclusions != earnings_exclusions {
+            self.earnings_exclusions = earnings_exclusions;
+            self.invalidate_all();
+        }
+    }
+
+    /// Changes which days are left out of the averages. Call `refresh`
+    /// afterward to redraw the charts.
+    pub fn set_days_off(&mut self, days_off: FurDaysOff) {
+        if self.days_off != days_off {
+            self.days_off = days_off;
+            self.invalidate_all();
+        }
+    }
+
+    /// The heatmap is only loaded while it's shown
+    pub fn set_show_heatmap(&mut self, show_heatmap: bool) {
+        self.show_heatmap = show_heatmap;
+    }
+
+    /// Todos are only loaded while their chart is shown
+    pub fn set_show_todo_chart(&mut self, show_todo_chart: bool) {
+        self.show_todo_chart = show_todo_chart;
+    }
+
+    pub fn set_billable_filter(&mut self, new_filter: FurBillableFilter) -> Task<Message> {
+        if self.billable_filter != new_filter {
+            self.billable_filter = new_filter;
+            self.refresh()
+        } else {
+            Task::none()
+        }
+    }
+
+    pub fn set_compare_with_previous(&mut self, compare: bool) -> Task<Message> {
+        self.compare_with_previous = compare;
+        self.refresh()
+    }
+
+    pub fn set_comparison_period(&mut self, period: FurComparisonPeriod) -> Task<Message> {
+        if self.comparison_period != period {
+            self.comparison_period = period;
+            self.refresh()
+        } else {
+            Task::none()
+        }
+    }
+
+    /// The range being compared with, if any. There is nothing before all time.
+    pub fn previous_range(&self) -> Option<ReportRange> {
+        if self.compare_with_previous && self.picked_date_range != Some(FurDateRange::AllTime) {
+            Some(self.range().previous(self.comparison_period))
+        } else {
+            None
         }
     }
 
     pub fn set_picked_task_property_key(&mut self, new_property: FurTaskProperty) {
         if self.picked_task_property_key != Some(new_property) {
             self.picked_task_property_key = Some(new_property);
+            self.task_property_value_filter = String::new();
             self.populate_task_property_values();
-            self.update_selection_charts();
+            self.update_selection();
         }
     }
 
     pub fn set_picked_task_property_value(&mut self, new_value: String) {
         if self.picked_task_property_value.as_ref() != Some(&new_value) {
             self.picked_task_property_value = Some(new_value);
-            self.update_selection_charts();
+            self.update_selection();
+        }
+    }
+
+    /// Values containing the filter text, ignoring case, with the current pick pinned
+    /// first. At most `limit` values are returned.
+    pub fn filtered_task_property_values(&self, limit: usize) -> Vec<&String> {
+        let filter = self.task_property_value_filter.trim().to_lowercase();
+        let picked = self.picked_task_property_value.as_ref();
+        let mut values: Vec<&String> = picked
+            .filter(|value| self.task_property_value_keys.contains(value))
+            .into_iter()
+            .collect();
+        values.extend(
+            self.task_property_value_keys
+                .iter()
+                .zip(&self.task_property_value_index)
+                .filter(|(key, lowercase)| Some(*key) != picked && lowercase.contains(&filter))
+                .map(|(key, _)| key)
+                .take(limit.saturating_sub(values.len())),
+        );
+        values
+    }
+
+    /// Adds or removes a tag from the tags selected tasks must all have.
+    /// The last picked tag can't be removed.
+    pub fn toggle_picked_tag(&mut self, tag: String) {
+        if self.picked_tags.contains(&tag) {
+            if self.picked_tags.len() > 1 {
+                self.picked_tags.remove(&tag);
+                self.update_selection();
+            }
+        } else if self.task_property_values.contains_key(&tag) {
+            self.picked_tags.insert(tag);
+            self.update_selection();
         }
     }
 
-    pub fn set_date_range_end(&mut self, new_date: Date) {
+    pub fn set_date_range_end(&mut self, new_date: Date) -> Task<Message> {
         if let Some(new_end_date) =
             NaiveDate::from_ymd_opt(new_date.year, new_date.month, new_date.day)
         {
@@ -185,12 +614,13 @@ impl FurReport {
                 self.picked_end_date = new_date;
                 self.date_range_end = new_end_date;
                 self.show_end_date_picker = false;
-                self.update_tasks_in_range();
+                return self.refresh();
             }
         }
+        Task::none()
     }
 
-    pub fn set_date_range_start(&mut self, new_date: Date) {
+    pub fn set_date_range_start(&mut self, new_date: Date) -> Task<Message> {
         if let Some(new_start_date) =
             NaiveDate::from_ymd_opt(new_date.year, new_date.month, new_date.day)
         {
@@ -198,68 +628,419 @@ impl FurReport {
                 self.picked_start_date = new_date;
                 self.date_range_start = new_start_date;
                 self.show_start_date_picker = false;
-                self.update_tasks_in_range();
+                return self.refresh();
             }
         }
+        Task::none()
     }
 
-    pub fn update_tasks_in_range(&mut self) {
-        match db_retrieve_tasks_by_date_range(
-            self.date_range_start.to_string(),
-            self.date_range_end.to_string(),
-        ) {
-            Ok(s) => self.tasks_in_range = s,
-            Err(e) => {
-                self.tasks_in_range = vec![];
-                eprintln!("Could not retrieve data in range: {}", e);
+    pub fn date_range(&self) -> (NaiveDate, NaiveDate) {
+        (self.date_range_start, self.date_range_end)
+    }
+
+    pub fn range(&self) -> ReportRange {
+        ReportRange {
+            start: self.date_range_start,
+            end: self.date_range_end,
+            billable_filter: self.billable_filter,
+        }
+    }
+
+    /// Whether the current range is still being computed
+    pub fn is_computing(&self) -> bool {
+        self.pending.contains(&self.range())
+    }
+
+    /// Shows the current range from the cache, or starts computing it
+    pub fn refresh(&mut self) -> Task<Message> {
+        self.update_goal_progress();
+
+        let other_tasks = Task::batch([
+            self.load_previous(),
+            self.load_heatmap(),
+            self.load_todo_chart(),
+        ]);
+        let range = self.range();
+        if let Some(data) = self.cache.get(&range).cloned() {
+            self.show_data(data);
+            return other_tasks;
+        }
+        self.update_comparison();
+        if !self.pending.insert(range) {
+            return other_tasks;
+        }
+
+        Task::batch([self.compute(range), other_tasks])
+    }
+
+    /// Recomputes the current range even when it's cached, since tasks in it may
+    /// have changed outside the task history
+    pub fn reload(&mut self) -> Task<Message> {
+        self.update_goal_progress();
+        self.heatmap_stale = true;
+
+        let range = self.range();
+        self.pending.insert(range);
+        Task::batch([
+            self.compute(range),
+            self.load_previous(),
+            self.load_heatmap(),
+            self.load_todo_chart(),
+        ])
+    }
+
+    /// Starts loading the last year for the heatmap if it's shown and out of date
+    fn load_heatmap(&mut self) -> Task<Message> {
+        let today = Local::now().date_naive();
+        if !self.show_heatmap
+            || self.heatmap_pending
+            || (!self.heatmap_stale && self.heatmap_chart.end() == today)
+        {
+            return Task::none();
+        }
+
+        self.heatmap_pending = true;
+        self.heatmap_stale = false;
+        Task::perform(
+            async move { Arc::new(HeatmapChart::load(today)) },
+            Message::ReportHeatmapComputed,
+        )
+    }
+
+    pub fn heatmap_computed(&mut self, heatmap_chart: Arc<HeatmapChart>) {
+        self.heatmap_pending = false;
+        self.heatmap_chart = heatmap_chart;
+    }
+
+    /// Starts loading the todos in range if their chart is shown. Todos change apart
+    /// from tasks, so they're read again on every refresh rather than cached.
+    fn load_todo_chart(&self) -> Task<Message> {
+        if !self.show_todo_chart {
+            return Task::none();
+        }
+        let (start, end) = self.date_range();
+        Task::perform(
+            async move { TodoCompletionChart::load(start, end).map(Arc::new) },
+            Message::ReportTodoChartComputed,
+        )
+    }
+
+    /// Shows the loaded todos unless the range changed while they loaded
+    pub fn todo_chart_computed(&mut self, todo_completion_chart: Arc<TodoCompletionChart>) {
+        if (todo_completion_chart.start, todo_completion_chart.end) == self.date_range() {
+            self.todo_completion_chart = todo_completion_chart;
+        }
+    }
+
+    /// Starts computing the compared range unless it's cached or already computing
+    fn load_previous(&mut self) -> Task<Message> {
+        match self.previous_range() {
+            Some(range) if !self.cache.contains_key(&range) && self.pending.insert(range) => {
+                self.compute(range)
             }
+            _ => Task::none(),
         }
+    }
+
+    fn compute(&self, range: ReportRange) -> Task<Message> {
+        let monthly_earnings_target = self.monthly_earnings_target;
+        let days_off = self.days_off.clone();
+        let earnings_exclusions = self.earnings_exclusions.clone();
+        Task::perform(
+            async move {
+                Arc::new(FurReportData::load(
+                    range,
+                    monthly_earnings_target,
+                    &days_off,
+                    &earnings_exclusions,
+                ))
+            },
+            move |data| Message::ReportComputed(range, data),
+        )
+    }
+
+    pub fn report_computed(&mut self, range: ReportRange, data: Arc<FurReportData>) {
+        // The range was invalidated while this was computing, so it may be stale
+        if !self.pending.remove(&range) {
+            return;
+        }
+
+        // A reload tells us which dates changed, so other ranges with them are stale too
+        if let Some(old_data) = self.cache.get(&range) {
+            let changed_dates = changed_task_dates(&old_data.tasks_in_range, &data.tasks_in_range);
+            self.invalidate_dates(&changed_dates);
+        }
+        self.cache.insert(range, data.clone());
+        if range == self.range() {
+            self.show_data(data);
+        } else if Some(range) == self.previous_range() {
+            self.update_comparison();
+        }
+    }
+
+    /// Drops cached results covering any of `dates`, e.g. after tasks on them changed
+    pub fn invalidate_dates(&mut self, dates: &BTreeSet<NaiveDate>) {
+        if dates.is_empty() {
+            return;
+        }
+        self.cache.retain(|range, _| !range.overlaps(dates));
+        self.pending.retain(|range| !range.overlaps(dates));
+        self.selection_cache
+            .retain(|(range, _, _), _| !range.overlaps(dates));
+        if dates
+            .iter()
+            .any(|date| *date >= self.heatmap_chart.first_day())
+        {
+            self.heatmap_stale = true;
+        }
+    }
+
+    /// Drops every cached result, e.g. after a sync or import
+    pub fn invalidate_all(&mut self) {
+        self.cache.clear();
+        self.pending.clear();
+        self.selection_cache.clear();
+        self.heatmap_stale = true;
+    }
 
+    /// Shows already computed data, updating the breakdown picks to match it
+    pub fn show_data(&mut self, data: Arc<FurReportData>) {
+        self.data = data;
         self.populate_task_property_values();
-        self.update_charts();
+        self.update_selection();
+        self.update_comparison();
+        self.update_running_time_chart();
     }
 
-    fn update_charts(&mut self) {
-        (self.total_time, self.total_earned) = self.tasks_in_range.iter().fold(
-            (0, 0.0),
-            |(time_accumulated, earned_accumulated), task| {
-                (
-                    time_accumulated + task.total_time_in_seconds(),
-                    earned_accumulated + task.total_earnings(),
-                )
-            },
-        );
+    /// The running task's start date and length, shown as a provisional point
+    /// on the time recorded chart. None removes it.
+    pub fn set_running_time(&mut self, running_time: Option<(NaiveDate, i64)>) {
+        self.running_time = running_time;
+        self.update_running_time_chart();
+    }
+
+    fn update_running_time_chart(&mut self) {
+        let (start, end) = self.date_range();
+        self.running_time_chart = self
+            .running_time
+            .filter(|(date, _)| (start..=end).contains(date))
+            .map(|(date, seconds)| self.data.time_recorded_chart.with_running(date, seconds));
+    }
 
-        self.time_recorded_chart = TimeRecordedChart::new(&self.tasks_in_range);
-        self.earnings_chart = EarningsChart::new(&self.tasks_in_range);
-        self.average_time_chart = AverageTimeChart::new(&self.tasks_in_range);
-        self.average_earnings_chart = AverageEarningsChart::new(&self.tasks_in_range);
-        self.update_selection_charts();
+    /// Pairs the shown data with the compared range once both are loaded.
+    /// Ranges before the first task simply compare with whatever exists.
+    fn update_comparison(&mut self) {
+        let range = self.range();
+        self.comparison = match self.previous_range() {
+            Some(previous_range) if self.cache.contains_key(&range) => {
+                self.cache.get(&previous_range).map(|previous| {
+                    let offset = range.start - previous_range.start;
+                    FurReportComparison {
+                        time_recorded_chart: self
+                            .data
+                            .time_recorded_chart
+                            .with_previous(&previous.time_recorded_chart, offset),
+                        earnings_chart: self
+                            .data
+                            .earnings_chart
+                            .with_previous(&previous.earnings_chart, offset),
+                        previous: previous.clone(),
+                    }
+                })
+            }
+            _ => None,
+        };
     }
 
-    fn update_selection_charts(&mut self) {
-        if let Some(value) = &self.picked_task_property_value {
-            if let Some(indices) = self.task_property_values.get(value) {
-                let tasks: Vec<&FurTask> =
-                    indices.iter().map(|&i| &self.tasks_in_range[i]).collect();
+    /// Totals the time tracked this week (starting Monday) for each project with a goal
+    pub fn update_goal_progress(&mut self) {
+        let goals = match db_retrieve_existing_goals() {
+            Ok(goals) => goals,
+            Err(e) => {
+                eprintln!("Could not retrieve goals: {}", e);
+                vec![]
+            }
+        };
+        if goals.is_empty() {
+            self.weekly_goal_progress = vec![];
+            return;
+        }
 
-                (self.selection_total_time, self.selection_total_earned) =
-                    tasks
-                        .iter()
-                        .fold((0, 0.0), |(time_accumulated, earned_accumulated), task| {
-                            (
-                                time_accumulated + task.total_time_in_seconds(),
-                                earned_accumulated + task.total_earnings(),
-                            )
-                        });
-
-                self.selection_time_recorded_chart = SelectionTimeRecordedChart::new(&tasks);
-                self.selection_earnings_recorded_chart =
-                    SelectionEarningsRecordedChart::new(&tasks);
+        let today = Local::now().date_naive();
+        let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let tasks_this_week = match db_retrieve_tasks_by_date_range(week_start, today) {
+            Ok(tasks) => tasks,
+            Err(e) => {
+                eprintln!("Could not retrieve tasks for this week: {}", e);
+                vec![]
+            }
+        };
+
+        self.weekly_goal_progress = goals
+            .into_iter()
+            .map(|goal| {
+                let tracked = tasks_this_week
+                    .iter()
+                    .filter(|task| task.project.trim().eq_ignore_ascii_case(&goal.project))
+                    .map(|task| task.total_time_in_seconds())
+                    .sum();
+                (goal, tracked)
+            })
+            .collect();
+    }
+
+    /// How many days in range met the daily target, and how many it applied to.
+    /// The running task counts toward the day it started.
+    pub fn days_meeting_target(&self, target: &DailyTarget) -> (usize, usize) {
+        let (start, end) = self.date_range();
+        let mut day_totals = self.data.day_totals.clone();
+        if let Some((date, seconds)) = self.running_time {
+            *day_totals.entry(date).or_insert(0) += seconds;
+        }
+        target.days_met(
+            &day_totals,
+            start,
+            end,
+            Local::now().date_naive(),
+            &self.days_off,
+        )
+    }
+
+    /// Workday stats for the breakdown selection when one is picked, otherwise for the range
+    pub fn workday_stats(&self) -> Option<&WorkdayStats> {
+        if self.picked_value().is_some() {
+            self.selection.workday_stats.as_ref()
+        } else {
+            self.data.workday_stats.as_ref()
+        }
+    }
+
+    /// The tasks in range matching the picked breakdown property and value.
+    /// For tags, the tasks having every picked tag.
+    pub fn selected_tasks(&self) -> Vec<&FurTask> {
+        let indices: Vec<usize> = match self.picked_task_property_key {
+            Some(FurTaskProperty::Tags) => {
+                let mut picked_tags = self.picked_tags.iter();
+                let Some(first_indices) = picked_tags
+                    .next()
+                    .and_then(|tag| self.task_property_values.get(tag))
+                else {
+                    return vec![];
+                };
+                let other_indices: Vec<HashSet<usize>> = picked_tags
+                    .map(|tag| {
+                        self.task_property_values
+                            .get(tag)
+                            .map_or(HashSet::new(), |indices| indices.iter().copied().collect())
+                    })
+                    .collect();
+                first_indices
+                    .iter()
+                    .copied()
+                    .filter(|i| other_indices.iter().all(|indices| indices.contains(i)))
+                    .collect()
+            }
+            _ => self
+                .picked_task_property_value
+                .as_ref()
+                .and_then(|value| self.task_property_values.get(value))
+                .cloned()
+                .unwrap_or_default(),
+        };
+
+        indices
+            .iter()
+            .map(|&i| &self.data.tasks_in_range[i])
+            .collect()
+    }
+
+    /// The tasks an export of the report covers: the breakdown selection
+    /// when it's included, otherwise every task in range
+    pub fn export_tasks(&self, include_selection: bool) -> Vec<&FurTask> {
+        if include_selection && self.picked_value().is_some() {
+            self.selected_tasks()
+        } else {
+            self.data.tasks_in_range.iter().collect()
+        }
+    }
+
+    /// Describes the range and filters behind `export_tasks`
+    pub fn export_description(&self, include_selection: bool) -> String {
+        let mut description = format!(
+            "{} - {}",
+            self.date_range_start.format("%Y-%m-%d"),
+            self.date_range_end.format("%Y-%m-%d")
+        );
+        if self.billable_filter != FurBillableFilter::All {
+            description.push_str(&format!(", {}", self.billable_filter));
+        }
+        if let (true, Some(key), Some(value)) = (
+            include_selection,
+            self.picked_task_property_key,
+            self.picked_value(),
+        ) {
+            let value = match key {
+                FurTaskProperty::Tags => self
+                    .picked_tags
+                    .iter()
+                    .map(|tag| format!("#{tag}"))
+                    .join(" "),
+                _ => value,
+            };
+            description.push_str(&format!(", {}: {}", key, value));
+        }
+        description
+    }
+
+    /// Identifies the current pick in the selection cache
+    fn picked_value(&self) -> Option<String> {
+        match self.picked_task_property_key {
+            Some(FurTaskProperty::Tags) => {
+                if self.picked_tags.is_empty() {
+                    None
+                } else {
+                    Some(self.picked_tags.iter().join("#"))
+                }
             }
+            _ => self
+                .picked_task_property_value
+                .clone()
+                .filter(|value| self.task_property_values.contains_key(value)),
         }
     }
 
+    fn update_selection(&mut self) {
+        let (Some(key), Some(value)) = (self.picked_task_property_key, self.picked_value()) else {
+            self.selection = Arc::new(FurSelectionData::from_tasks(
+                &[],
+                None,
+                &self.days_off,
+                &self.earnings_exclusions,
+            ));
+            return;
+        };
+
+        let cache_key = (self.range(), key, value);
+        if let Some(selection) = self.selection_cache.get(&cache_key) {
+            self.selection = selection.clone();
+            return;
+        }
+
+        // A project's charts use the project's color
+        let color = match key {
+            FurTaskProperty::Project => self.project_colors.get(&cache_key.2).copied(),
+            _ => None,
+        };
+        let selection = Arc::new(FurSelectionData::from_tasks(
+            &self.selected_tasks(),
+            color,
+            &self.days_off,
+            &self.earnings_exclusions,
+        ));
+        self.selection_cache.insert(cache_key, selection.clone());
+        self.selection = selection;
+    }
+
     fn subtract_months(&self, date: NaiveDate, months: i32) -> NaiveDate {
         let mut year = date.year();
         let mut month = date.month() as i32;
@@ -306,7 +1087,7 @@ impl FurReport {
         if let Some(property_key) = self.picked_task_property_key {
             self.task_property_values = HashMap::new();
 
-            for (index, task) in self.tasks_in_range.iter().enumerate() {
+            for (index, task) in self.data.tasks_in_range.iter().enumerate() {
                 let keys = match property_key {
                     FurTaskProperty::Title => vec![task.name.to_string()],
                     FurTaskProperty::Project => vec![if task.project.trim().is_empty() {
@@ -315,11 +1096,10 @@ impl FurReport {
                         task.project.to_string()
                     }],
                     FurTaskProperty::Tags => {
-                        let tags = task
-                            .tags
-                            .split('#')
-                            .map(|s| s.trim().to_string())
+                        let tags = normalize_tags(&task.tags)
+                            .split(" #")
                             .filter(|s| !s.is_empty())
+                            .map(|s| s.to_string())
                             .collect::<Vec<String>>();
                         if tags.is_empty() {
                             vec![localization.get_message("no-tags", None)]
@@ -332,6 +1112,11 @@ impl FurReport {
                     } else {
                         format!("${:.2}", task.rate)
                     }],
+                    FurTaskProperty::Device => vec![if task.device_name.trim().is_empty() {
+                        localization.get_message("unknown-device", None)
+                    } else {
+                        task.device_name.to_string()
+                    }],
                 };
 
                 for key in keys {
@@ -343,6 +1128,14 @@ impl FurReport {
             }
 
             self.task_property_value_keys = self.task_property_values.keys().cloned().collect();
+            if property_key == FurTaskProperty::Project {
+                self.task_property_value_keys.retain(|key| {
+                    !self
+                        .archived_projects
+                        .iter()
+                        .any(|project| project.eq_ignore_ascii_case(key))
+                });
+            }
 
             // Sort keys
             match property_key {
@@ -362,8 +1155,32 @@ impl FurReport {
                     .sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase())),
             }
 
-            if let Some(value) = self.task_property_value_keys.first() {
-                self.picked_task_property_value = Some(value.to_owned());
+            self.task_property_value_index = self
+                .task_property_value_keys
+                .iter()
+                .map(|key| key.to_lowercase())
+                .collect();
+
+            // Keep the current picks when they're still available, e.g. after editing a task
+            if property_key == FurTaskProperty::Tags {
+                let task_property_values = &self.task_property_values;
+                self.picked_tags
+                    .retain(|tag| task_property_values.contains_key(tag));
+                if self.picked_tags.is_empty() {
+                    if let Some(tag) = self.task_property_value_keys.first() {
+                        self.picked_tags.insert(tag.to_owned());
+                    }
+                }
+            }
+
+            let pick_still_available = self
+                .picked_task_property_value
+                .as_ref()
+                .is_some_and(|value| self.task_property_value_keys.contains(value));
+            if !pick_still_available {
+                if let Some(value) = self.task_property_value_keys.first() {
+                    self.picked_task_property_value = Some(value.to_owned());
+                }
             }
         }
     }
@@ -0,0 +1,61 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    models::fur_task::FurTask,
+    update::msg_helper_functions::{
+        split_task_input, task_input_is_billable, task_input_planned_seconds,
+    },
+};
+
+/// A timer running alongside the main one. Only the main timer counts toward
+/// Pomodoro sessions and idle detection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FurRunningTimer {
+    pub task_input: String,
+    pub start_time: DateTime<Local>,
+}
+
+impl FurRunningTimer {
+    pub fn new(task_input: String) -> Self {
+        FurRunningTimer {
+            task_input,
+            start_time: Local::now(),
+        }
+    }
+
+    pub fn seconds_elapsed(&self, now: DateTime<Local>) -> i64 {
+        (now - self.start_time).num_seconds().max(0)
+    }
+
+    pub fn to_task(&self, stop_time: DateTime<Local>) -> FurTask {
+        let (name, project, tags, rate) = split_task_input(&self.task_input);
+        let mut task = FurTask::new(
+            name,
+            self.start_time,
+            stop_time,
+            tags,
+            project,
+            rate,
+            String::new(),
+        );
+        task.is_billable = task_input_is_billable(&self.task_input, rate);
+        task.planned_seconds = task_input_planned_seconds(&self.task_input);
+        task
+    }
+}
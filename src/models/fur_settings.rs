@@ -14,27 +14,106 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{constants::DEBUG_MODE, view_enums::FurView};
+use crate::{
+    constants::{
+        DEBUG_MODE, DEFAULT_STATUS_SERVER_PORT, DEFAULT_SYNC_INTERVAL_MINUTES,
+        DEFAULT_TRASH_RETENTION_DAYS,
+    },
+    helpers::{device::hostname, formatting::locale_uses_24_hour_time},
+    localization::detected_locale,
+    models::fur_profile::FurProfile,
+    view_enums::{
+        FurHistoryGrouping, FurIdleBackend, FurRoundingDirection, FurShortcutSort,
+        FurSummaryFormat, FurSummarySchedule, FurSyncInterval, FurThemePreference, FurView,
+    },
+};
 
 use config::{Config, ConfigError, File};
 use directories::ProjectDirs;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::HashSet;
 use std::fs::{self, create_dir_all};
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Settings that only make sense on this machine
+const LOCAL_ONLY_SETTINGS: [&str; 18] = [
+    "database_url",
+    "device_name",
+    "first_run",
+    "last_successful_sync",
+    "last_sync",
+    "needs_full_sync",
+    "profiles",
+    "scheduled_summary_folder",
+    "scheduled_summary_last_generated",
+    "status_server_api_key",
+    "summary_file_path",
+    "tags_normalized",
+    "window_height",
+    "window_maximized",
+    "window_width",
+    "window_x",
+    "window_y",
+    "write_summary_file",
+];
 
 #[derive(Debug, Serialize, Deserialize)]
+pub struct SettingsExport {
+    pub version: u32,
+    pub settings: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default)]
+pub struct SettingsImportSummary {
+    pub applied: usize,
+    pub skipped: Vec<String>,
+}
+
+/// What happened while salvaging a settings file that could not be loaded
+#[derive(Debug, Clone, Default)]
+pub struct SettingsRecovery {
+    pub backed_up: bool,
+    pub reset: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FurSettings {
+    pub archived_projects: Vec<String>,
+    pub auto_hide_completed_after_days: u16,
+    pub auto_stop_enabled: bool,
+    pub auto_stop_time: String,
     pub chosen_idle_time: i64,
+    pub confirm_on_stop: bool,
+    pub confirm_shortcut_switch: bool,
+    pub daily_target_minutes: i64,
     pub database_url: String,
     pub days_to_show: i64,
+    pub deduction_percent: f32,
+    pub default_currency: String,
     pub default_view: FurView,
+    pub device_name: String,
+    pub discard_if_shorter_than_seconds: i64,
     pub dynamic_total: bool,
+    pub earnings_exclusions: Vec<String>,
     #[serde(default)]
     pub first_run: bool,
+    pub hide_archived_in_history: bool,
+    pub history_grouping: FurHistoryGrouping,
+    pub idle_backend: FurIdleBackend,
+    pub invoice_client: String,
+    pub invoice_from: String,
+    pub invoice_notes: String,
+    pub invoice_number: String,
+    pub last_successful_sync: i64,
+    pub monthly_earnings_target: f32,
     pub last_sync: i64,
     pub needs_full_sync: bool,
     pub notify_of_sync: bool,
     pub notify_on_idle: bool,
+    pub notify_on_planned_time: bool,
     pub notify_reminder: bool,
     pub notify_reminder_interval: u16,
     pub pomodoro: bool,
@@ -45,24 +124,67 @@ pub struct FurSettings {
     pub pomodoro_length: i64,
     pub pomodoro_notification_alarm_sound: bool,
     pub pomodoro_snooze_length: i64,
+    pub profiles: Vec<FurProfile>,
+    pub report_weekends_off: bool,
+    pub rounding_direction: FurRoundingDirection,
+    pub rounding_enabled: bool,
+    pub rounding_minutes: i64,
+    pub scheduled_summary: FurSummarySchedule,
+    pub scheduled_summary_earnings: bool,
+    pub scheduled_summary_folder: String,
+    pub scheduled_summary_hour: u32,
+    pub scheduled_summary_last_generated: String,
+    pub shortcut_sort: FurShortcutSort,
     pub show_chart_average_earnings: bool,
     pub show_chart_average_time: bool,
     pub show_chart_breakdown_by_selection: bool,
+    pub show_chart_cumulative_earnings: bool,
     pub show_chart_earnings: bool,
+    pub show_chart_heatmap: bool,
     pub show_chart_selection_earnings: bool,
     pub show_chart_selection_time: bool,
+    pub show_chart_time_of_day: bool,
     pub show_chart_time_recorded: bool,
+    pub show_chart_todos: bool,
     pub show_chart_total_earnings_box: bool,
     pub show_chart_total_time_box: bool,
+    pub show_chart_workday_stats: bool,
     pub show_daily_time_total: bool,
     pub show_delete_confirmation: bool,
     pub show_seconds: bool,
+    pub show_net_earnings: bool,
+    pub show_shortcut_stats: bool,
     pub show_task_earnings: bool,
     pub show_task_project: bool,
+    pub show_task_planned_time: bool,
     pub show_task_tags: bool,
+    pub show_timer_in_title: bool,
+    pub show_todo_estimates: bool,
     pub show_todo_project: bool,
     pub show_todo_rate: bool,
     pub show_todo_tags: bool,
+    pub sound_on_idle: bool,
+    pub sound_on_start_stop: bool,
+    pub sounds_enabled: bool,
+    pub status_file: bool,
+    pub status_server: bool,
+    pub status_server_api_key: String,
+    pub status_server_port: u16,
+    pub summary_file_path: String,
+    pub summary_format: FurSummaryFormat,
+    pub sync_interval_minutes: u16,
+    pub tags_normalized: bool,
+    pub target_applies_to_weekends: bool,
+    pub theme_preference: FurThemePreference,
+    pub trash_retention_days: u16,
+    pub use_24_hour_time: bool,
+    pub warn_if_longer_than_hours: i64,
+    pub window_height: f32,
+    pub window_maximized: bool,
+    pub window_width: f32,
+    pub window_x: f32,
+    pub window_y: f32,
+    pub write_summary_file: bool,
 }
 
 impl Default for FurSettings {
@@ -70,16 +192,38 @@ impl Default for FurSettings {
         let db_url: PathBuf = get_default_db_path();
 
         FurSettings {
+            archived_projects: Vec::new(),
+            auto_hide_completed_after_days: 0,
+            auto_stop_enabled: false,
+            auto_stop_time: "18:30".to_string(),
             chosen_idle_time: 6,
+            confirm_on_stop: false,
+            confirm_shortcut_switch: true,
+            daily_target_minutes: 0,
             database_url: db_url.to_string_lossy().into_owned(),
             days_to_show: 365,
+            deduction_percent: 0.0,
+            default_currency: "USD".to_string(),
             default_view: FurView::Timer,
+            device_name: hostname(),
+            discard_if_shorter_than_seconds: 0,
             dynamic_total: false,
+            earnings_exclusions: Vec::new(),
             first_run: true,
+            hide_archived_in_history: false,
+            history_grouping: FurHistoryGrouping::Day,
+            idle_backend: FurIdleBackend::Auto,
+            invoice_client: String::new(),
+            invoice_from: String::new(),
+            invoice_notes: String::new(),
+            invoice_number: String::new(),
+            last_successful_sync: 0,
+            monthly_earnings_target: 0.0,
             last_sync: 0,
             needs_full_sync: true,
             notify_of_sync: true,
             notify_on_idle: true,
+            notify_on_planned_time: true,
             notify_reminder: false,
             notify_reminder_interval: 10,
             pomodoro: false,
@@ -90,24 +234,71 @@ impl Default for FurSettings {
             pomodoro_length: 25,
             pomodoro_notification_alarm_sound: true,
             pomodoro_snooze_length: 5,
+            profiles: Vec::new(),
+            report_weekends_off: false,
+            rounding_direction: FurRoundingDirection::Nearest,
+            rounding_enabled: false,
+            rounding_minutes: 15,
+            scheduled_summary: FurSummarySchedule::Off,
+            scheduled_summary_earnings: false,
+            scheduled_summary_folder: get_default_scheduled_summary_folder()
+                .to_string_lossy()
+                .into_owned(),
+            scheduled_summary_hour: 17,
+            scheduled_summary_last_generated: String::new(),
+            shortcut_sort: FurShortcutSort::Manual,
             show_chart_average_earnings: true,
             show_chart_average_time: true,
             show_chart_breakdown_by_selection: true,
+            show_chart_cumulative_earnings: true,
             show_chart_earnings: true,
+            show_chart_heatmap: true,
             show_chart_selection_earnings: true,
             show_chart_selection_time: true,
+            show_chart_time_of_day: true,
             show_chart_time_recorded: true,
+            show_chart_todos: true,
             show_chart_total_earnings_box: true,
             show_chart_total_time_box: true,
+            show_chart_workday_stats: true,
             show_daily_time_total: true,
             show_delete_confirmation: true,
             show_seconds: true,
+            show_net_earnings: false,
+            show_shortcut_stats: false,
             show_task_earnings: true,
             show_task_project: true,
+            show_task_planned_time: true,
             show_task_tags: true,
+            show_timer_in_title: true,
+            show_todo_estimates: true,
             show_todo_project: true,
             show_todo_rate: true,
             show_todo_tags: true,
+            sound_on_idle: false,
+            sound_on_start_stop: false,
+            sounds_enabled: true,
+            status_file: false,
+            status_server: false,
+            status_server_api_key: String::new(),
+            status_server_port: DEFAULT_STATUS_SERVER_PORT,
+            summary_file_path: get_default_summary_file_path()
+                .to_string_lossy()
+                .into_owned(),
+            summary_format: FurSummaryFormat::Markdown,
+            sync_interval_minutes: DEFAULT_SYNC_INTERVAL_MINUTES,
+            tags_normalized: true,
+            target_applies_to_weekends: false,
+            theme_preference: FurThemePreference::System,
+            trash_retention_days: DEFAULT_TRASH_RETENTION_DAYS,
+            use_24_hour_time: locale_uses_24_hour_time(&detected_locale()),
+            warn_if_longer_than_hours: 8,
+            window_height: 0.0,
+            window_maximized: false,
+            window_width: 0.0,
+            window_x: 0.0,
+            window_y: 0.0,
+            write_summary_file: false,
         }
     }
 }
@@ -126,26 +317,115 @@ impl FurSettings {
             let default_settings = FurSettings::default();
             let toml =
                 toml::to_string(&default_settings).expect("Failed to serialize default settings");
-            fs::write(&config_path, &toml).expect("Failed to write default config file");
+            write_atomically(&config_path, &toml).expect("Failed to write default config file");
 
             builder = builder.add_source(File::from_str(&toml, config::FileFormat::Toml));
         }
 
         // Add new settings to existing configuration file
+        builder = builder.set_default("archived_projects", Vec::<String>::new())?;
+        builder = builder.set_default("profiles", Vec::<String>::new())?;
+        builder = builder.set_default("confirm_on_stop", "false")?;
+        builder = builder.set_default("confirm_shortcut_switch", "true")?;
+        builder = builder.set_default("daily_target_minutes", "0")?;
+        builder = builder.set_default("deduction_percent", "0.0")?;
+        builder = builder.set_default("default_currency", "USD")?;
+        builder = builder.set_default("device_name", hostname())?;
+        builder = builder.set_default("discard_if_shorter_than_seconds", "0")?;
+        builder = builder.set_default("earnings_exclusions", Vec::<String>::new())?;
         builder = builder.set_default("first_run", "true")?;
+        builder = builder.set_default("hide_archived_in_history", "false")?;
+        builder = builder.set_default("history_grouping", "Day")?;
+        builder = builder.set_default("idle_backend", "Auto")?;
+        builder = builder.set_default("invoice_client", "")?;
+        builder = builder.set_default("invoice_from", "")?;
+        builder = builder.set_default("invoice_notes", "")?;
+        builder = builder.set_default("invoice_number", "")?;
         builder = builder.set_default("notify_reminder", "false")?;
         builder = builder.set_default("notify_reminder_interval", "10")?;
+        builder = builder.set_default("notify_on_planned_time", "true")?;
+        builder = builder.set_default("report_weekends_off", "false")?;
+        builder = builder.set_default("rounding_direction", "Nearest")?;
+        builder = builder.set_default("rounding_enabled", "false")?;
+        builder = builder.set_default("rounding_minutes", "15")?;
+        builder = builder.set_default("scheduled_summary", "Off")?;
+        builder = builder.set_default("scheduled_summary_earnings", "false")?;
+        builder = builder.set_default(
+            "scheduled_summary_folder",
+            get_default_scheduled_summary_folder()
+                .to_string_lossy()
+                .into_owned(),
+        )?;
+        builder = builder.set_default("scheduled_summary_hour", "17")?;
+        // The end date of the last period a scheduled summary was written for
+        builder = builder.set_default("scheduled_summary_last_generated", "")?;
+        builder = builder.set_default("shortcut_sort", "Manual")?;
         builder = builder.set_default("show_chart_selection_earnings", "true")?;
+        builder = builder.set_default("last_successful_sync", "0")?;
+        builder = builder.set_default("monthly_earnings_target", "0.0")?;
+        builder = builder.set_default("show_chart_cumulative_earnings", "true")?;
+        builder = builder.set_default("show_chart_workday_stats", "true")?;
+        builder = builder.set_default("show_chart_heatmap", "true")?;
+        builder = builder.set_default("show_chart_todos", "true")?;
+        builder = builder.set_default("show_chart_time_of_day", "true")?;
+        builder = builder.set_default("auto_hide_completed_after_days", "0")?;
+        builder = builder.set_default("auto_stop_enabled", "false")?;
+        builder = builder.set_default("auto_stop_time", "18:30")?;
         builder = builder.set_default("last_sync", "0")?;
         builder = builder.set_default("needs_full_sync", "true")?;
         builder = builder.set_default("notify_of_sync", "true")?;
         builder = builder.set_default("pomodoro_notification_alarm_sound", "true")?;
+        builder = builder.set_default("show_net_earnings", "false")?;
+        builder = builder.set_default("show_shortcut_stats", "false")?;
         builder = builder.set_default("show_task_earnings", "true")?;
         builder = builder.set_default("show_task_project", "true")?;
+        builder = builder.set_default("show_task_planned_time", "true")?;
         builder = builder.set_default("show_task_tags", "true")?;
+        builder = builder.set_default("show_timer_in_title", "true")?;
+        builder = builder.set_default("show_todo_estimates", "true")?;
         builder = builder.set_default("show_todo_project", "true")?;
         builder = builder.set_default("show_todo_rate", "true")?;
         builder = builder.set_default("show_todo_tags", "true")?;
+        builder = builder.set_default("sound_on_idle", "false")?;
+        builder = builder.set_default("sound_on_start_stop", "false")?;
+        builder = builder.set_default("sounds_enabled", "true")?;
+        builder = builder.set_default("status_file", "false")?;
+        builder = builder.set_default("status_server", "false")?;
+        builder = builder.set_default("status_server_api_key", "")?;
+        builder =
+            builder.set_default("status_server_port", DEFAULT_STATUS_SERVER_PORT.to_string())?;
+        builder = builder.set_default(
+            "summary_file_path",
+            get_default_summary_file_path()
+                .to_string_lossy()
+                .into_owned(),
+        )?;
+        builder = builder.set_default("summary_format", "Markdown")?;
+        // 0 means only sync when the Sync button is pressed
+        builder = builder.set_default(
+            "sync_interval_minutes",
+            DEFAULT_SYNC_INTERVAL_MINUTES.to_string(),
+        )?;
+        // Existing databases may still hold tags saved before normalization
+        builder = builder.set_default("tags_normalized", "false")?;
+        builder = builder.set_default("target_applies_to_weekends", "false")?;
+        builder = builder.set_default("theme_preference", "System")?;
+        builder = builder.set_default(
+            "trash_retention_days",
+            DEFAULT_TRASH_RETENTION_DAYS.to_string(),
+        )?;
+        builder = builder.set_default(
+            "use_24_hour_time",
+            locale_uses_24_hour_time(&detected_locale()).to_string(),
+        )?;
+        builder = builder.set_default("warn_if_longer_than_hours", "8")?;
+        // A zero size means the window hasn't been resized yet
+        builder = builder.set_default("window_height", "0.0")?;
+        builder = builder.set_default("window_maximized", "false")?;
+        builder = builder.set_default("window_width", "0.0")?;
+        builder = builder.set_default("window_x", "0.0")?;
+        builder = builder.set_default("window_y", "0.0")?;
+        builder = builder.set_default("write_summary_file", "false")?;
 
         let config = builder.build()?;
         let settings: FurSettings = config.try_deserialize()?;
@@ -160,9 +440,139 @@ impl FurSettings {
         Ok(settings)
     }
 
+    /// Load the settings, salvaging what can be kept from a settings file that fails to load
+    pub fn load() -> (Self, Option<SettingsRecovery>) {
+        match FurSettings::new() {
+            Ok(loaded_settings) => (loaded_settings, None),
+            Err(e) => {
+                eprintln!("Error loading settings: {}", e);
+                let (recovered_settings, recovery) = FurSettings::recover();
+                (recovered_settings, Some(recovery))
+            }
+        }
+    }
+
+    /// Back up a settings file that failed to load to settings.bad and keep
+    /// whatever can still be read from it. Everything else is reset to its default.
+    pub fn recover() -> (Self, SettingsRecovery) {
+        let config_path = get_settings_path();
+        let contents = match fs::read(&config_path) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(e) => {
+                eprintln!("Error reading settings file: {e}");
+                String::new()
+            }
+        };
+
+        let backed_up = match fs::copy(&config_path, config_path.with_file_name("settings.bad")) {
+            Ok(_) => true,
+            Err(e) => {
+                eprintln!("Error backing up damaged settings file: {e}");
+                false
+            }
+        };
+
+        let (settings, reset) = FurSettings::recover_from_str(&contents);
+        if let Err(e) = settings.save() {
+            eprintln!("Error saving recovered settings: {e}");
+        }
+
+        (settings, SettingsRecovery { backed_up, reset })
+    }
+
+    /// Read a damaged settings file one line at a time so a single bad value doesn't
+    /// lose the rest. Returns the settings and the keys that were reset to defaults.
+    pub fn recover_from_str(contents: &str) -> (Self, Vec<String>) {
+        let mut table = match toml::Value::try_from(FurSettings::default()) {
+            Ok(toml::Value::Table(table)) => table,
+            _ => return (FurSettings::default(), vec![]),
+        };
+        let mut recovered: HashSet<String> = HashSet::new();
+
+        for line in contents.lines() {
+            let Ok(parsed) = toml::from_str::<toml::Table>(line) else {
+                continue;
+            };
+            for (key, value) in parsed {
+                if !table.contains_key(&key) {
+                    continue;
+                }
+                let previous = table.insert(key.clone(), value);
+                if toml::Value::Table(table.clone())
+                    .try_into::<FurSettings>()
+                    .is_ok()
+                {
+                    recovered.insert(key);
+                } else if let Some(previous) = previous {
+                    table.insert(key, previous);
+                }
+            }
+        }
+
+        let mut settings: FurSettings = toml::Value::Table(table.clone())
+            .try_into()
+            .unwrap_or_default();
+        // The file existed, so this isn't a new user
+        settings.first_run = false;
+
+        let reset = table
+            .keys()
+            .filter(|key| key.as_str() != "first_run" && !recovered.contains(*key))
+            .cloned()
+            .collect();
+
+        (settings, reset)
+    }
+
     pub fn save(&self) -> Result<(), std::io::Error> {
         let toml = toml::to_string(self).expect("Failed to serialize settings");
-        fs::write(get_settings_path(), toml)
+        write_atomically(&get_settings_path(), &toml)
+    }
+
+    pub fn archive_project(&mut self, project: &str) -> Result<(), std::io::Error> {
+        if !self.is_project_archived(project) {
+            self.archived_projects.push(project.trim().to_owned());
+            self.archived_projects.sort_by_key(|p| p.to_lowercase());
+        }
+        self.save()
+    }
+
+    pub fn unarchive_project(&mut self, project: &str) -> Result<(), std::io::Error> {
+        self.archived_projects
+            .retain(|p| !p.eq_ignore_ascii_case(project.trim()));
+        self.save()
+    }
+
+    pub fn is_project_archived(&self, project: &str) -> bool {
+        let project = project.trim();
+        !project.is_empty()
+            && self
+                .archived_projects
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(project))
+    }
+
+    pub fn change_archived_projects(&mut self, value: &[String]) -> Result<(), std::io::Error> {
+        self.archived_projects = value.to_vec();
+        self.save()
+    }
+
+    pub fn change_auto_hide_completed_after_days(
+        &mut self,
+        value: &u16,
+    ) -> Result<(), std::io::Error> {
+        self.auto_hide_completed_after_days = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_auto_stop_enabled(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.auto_stop_enabled = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_auto_stop_time(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.auto_stop_time = value.to_owned();
+        self.save()
     }
 
     pub fn change_chosen_idle_time(&mut self, value: &i64) -> Result<(), std::io::Error> {
@@ -170,31 +580,122 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_confirm_on_stop(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.confirm_on_stop = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_confirm_shortcut_switch(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.confirm_shortcut_switch = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_daily_target_minutes(&mut self, value: &i64) -> Result<(), std::io::Error> {
+        self.daily_target_minutes = value.to_owned();
+        self.save()
+    }
+
     pub fn change_days_to_show(&mut self, value: &i64) -> Result<(), std::io::Error> {
         self.days_to_show = value.to_owned();
         self.save()
     }
 
+    pub fn change_deduction_percent(&mut self, value: &f32) -> Result<(), std::io::Error> {
+        self.deduction_percent = value.clamp(0.0, 100.0);
+        self.save()
+    }
+
+    pub fn change_device_name(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.device_name = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_discard_if_shorter_than_seconds(
+        &mut self,
+        value: &i64,
+    ) -> Result<(), std::io::Error> {
+        self.discard_if_shorter_than_seconds = value.to_owned();
+        self.save()
+    }
+
     pub fn change_db_url(&mut self, value: &str) -> Result<(), std::io::Error> {
         self.database_url = value.to_owned();
         self.save()
     }
 
+    pub fn change_default_currency(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.default_currency = value.to_owned();
+        self.save()
+    }
+
     pub fn change_default_view(&mut self, value: &FurView) -> Result<(), std::io::Error> {
         self.default_view = value.to_owned();
         self.save()
     }
 
+    pub fn change_earnings_exclusions(&mut self, value: &[String]) -> Result<(), std::io::Error> {
+        self.earnings_exclusions = value.to_vec();
+        self.save()
+    }
+
     pub fn change_first_run(&mut self, value: bool) -> Result<(), std::io::Error> {
         self.first_run = value;
         self.save()
     }
 
+    pub fn change_hide_archived_in_history(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.hide_archived_in_history = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_history_grouping(
+        &mut self,
+        value: &FurHistoryGrouping,
+    ) -> Result<(), std::io::Error> {
+        self.history_grouping = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_idle_backend(&mut self, value: &FurIdleBackend) -> Result<(), std::io::Error> {
+        self.idle_backend = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_invoice_client(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.invoice_client = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_invoice_from(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.invoice_from = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_invoice_notes(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.invoice_notes = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_invoice_number(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.invoice_number = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_last_successful_sync(&mut self, value: &i64) -> Result<(), std::io::Error> {
+        self.last_successful_sync = value.to_owned();
+        self.save()
+    }
+
     pub fn change_last_sync(&mut self, value: &i64) -> Result<(), std::io::Error> {
         self.last_sync = value.to_owned();
         self.save()
     }
 
+    pub fn change_monthly_earnings_target(&mut self, value: &f32) -> Result<(), std::io::Error> {
+        self.monthly_earnings_target = value.to_owned();
+        self.save()
+    }
+
     pub fn change_needs_full_sync(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.needs_full_sync = value.to_owned();
         self.save()
@@ -210,6 +711,11 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_notify_on_planned_time(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.notify_on_planned_time = value.to_owned();
+        self.save()
+    }
+
     pub fn change_notify_reminder(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.notify_reminder = value.to_owned();
         self.save()
@@ -266,6 +772,18 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_profiles(&mut self, value: &[FurProfile]) -> Result<(), std::io::Error> {
+        self.profiles = value.to_vec();
+        self.save()
+    }
+
+    /// The profile using the current database, if any
+    pub fn active_profile(&self) -> Option<&FurProfile> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.database_url == self.database_url)
+    }
+
     pub fn change_pomodoro_notification_alarm_sound(
         &mut self,
         value: &bool,
@@ -274,6 +792,68 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_report_weekends_off(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.report_weekends_off = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_rounding_direction(
+        &mut self,
+        value: &FurRoundingDirection,
+    ) -> Result<(), std::io::Error> {
+        self.rounding_direction = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_rounding_enabled(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.rounding_enabled = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_rounding_minutes(&mut self, value: &i64) -> Result<(), std::io::Error> {
+        self.rounding_minutes = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_scheduled_summary(
+        &mut self,
+        value: &FurSummarySchedule,
+    ) -> Result<(), std::io::Error> {
+        self.scheduled_summary = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_scheduled_summary_earnings(
+        &mut self,
+        value: &bool,
+    ) -> Result<(), std::io::Error> {
+        self.scheduled_summary_earnings = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_scheduled_summary_folder(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.scheduled_summary_folder = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_scheduled_summary_hour(&mut self, value: &u32) -> Result<(), std::io::Error> {
+        self.scheduled_summary_hour = value.to_owned().min(23);
+        self.save()
+    }
+
+    pub fn change_scheduled_summary_last_generated(
+        &mut self,
+        value: &str,
+    ) -> Result<(), std::io::Error> {
+        self.scheduled_summary_last_generated = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_shortcut_sort(&mut self, value: &FurShortcutSort) -> Result<(), std::io::Error> {
+        self.shortcut_sort = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_daily_time_total(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_daily_time_total = value.to_owned();
         self.save()
@@ -305,11 +885,24 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_show_chart_cumulative_earnings(
+        &mut self,
+        value: &bool,
+    ) -> Result<(), std::io::Error> {
+        self.show_chart_cumulative_earnings = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_chart_earnings(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_chart_earnings = value.to_owned();
         self.save()
     }
 
+    pub fn change_show_chart_heatmap(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_chart_heatmap = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_chart_selection_earnings(
         &mut self,
         value: &bool,
@@ -323,11 +916,21 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_show_chart_time_of_day(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_chart_time_of_day = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_chart_time_recorded(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_chart_time_recorded = value.to_owned();
         self.save()
     }
 
+    pub fn change_show_chart_todos(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_chart_todos = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_chart_total_earnings_box(
         &mut self,
         value: &bool,
@@ -341,11 +944,26 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_show_chart_workday_stats(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_chart_workday_stats = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_seconds(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_seconds = value.to_owned();
         self.save()
     }
 
+    pub fn change_show_net_earnings(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_net_earnings = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_show_shortcut_stats(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_shortcut_stats = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_task_earnings(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_task_earnings = value.to_owned();
         self.save()
@@ -356,11 +974,26 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_show_task_planned_time(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_task_planned_time = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_task_tags(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_task_tags = value.to_owned();
         self.save()
     }
 
+    pub fn change_show_timer_in_title(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_timer_in_title = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_show_todo_estimates(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.show_todo_estimates = value.to_owned();
+        self.save()
+    }
+
     pub fn change_show_todo_project(&mut self, value: &bool) -> Result<(), std::io::Error> {
         self.show_todo_project = value.to_owned();
         self.save()
@@ -376,6 +1009,396 @@ impl FurSettings {
         self.save()
     }
 
+    pub fn change_sound_on_idle(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.sound_on_idle = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_sound_on_start_stop(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.sound_on_start_stop = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_sounds_enabled(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.sounds_enabled = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_status_file(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.status_file = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_status_server(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.status_server = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_status_server_api_key(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.status_server_api_key = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_status_server_port(&mut self, value: &u16) -> Result<(), std::io::Error> {
+        self.status_server_port = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_summary_file_path(&mut self, value: &str) -> Result<(), std::io::Error> {
+        self.summary_file_path = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_summary_format(
+        &mut self,
+        value: &FurSummaryFormat,
+    ) -> Result<(), std::io::Error> {
+        self.summary_format = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_sync_interval_minutes(&mut self, value: &u16) -> Result<(), std::io::Error> {
+        self.sync_interval_minutes = FurSyncInterval::from_minutes(*value).minutes();
+        self.save()
+    }
+
+    pub fn change_tags_normalized(&mut self, value: bool) -> Result<(), std::io::Error> {
+        self.tags_normalized = value;
+        self.save()
+    }
+
+    pub fn change_target_applies_to_weekends(
+        &mut self,
+        value: &bool,
+    ) -> Result<(), std::io::Error> {
+        self.target_applies_to_weekends = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_theme_preference(
+        &mut self,
+        value: &FurThemePreference,
+    ) -> Result<(), std::io::Error> {
+        self.theme_preference = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_trash_retention_days(&mut self, value: &u16) -> Result<(), std::io::Error> {
+        self.trash_retention_days = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_use_24_hour_time(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.use_24_hour_time = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_warn_if_longer_than_hours(&mut self, value: &i64) -> Result<(), std::io::Error> {
+        self.warn_if_longer_than_hours = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_window_maximized(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.window_maximized = value.to_owned();
+        self.save()
+    }
+
+    pub fn change_window_geometry(
+        &mut self,
+        (width, height): (f32, f32),
+        (x, y): (f32, f32),
+    ) -> Result<(), std::io::Error> {
+        self.window_width = width;
+        self.window_height = height;
+        self.window_x = x;
+        self.window_y = y;
+        self.save()
+    }
+
+    pub fn change_write_summary_file(&mut self, value: &bool) -> Result<(), std::io::Error> {
+        self.write_summary_file = value.to_owned();
+        self.save()
+    }
+
+    /// Forget the stored window geometry so the next launch uses the default size
+    pub fn reset_window_geometry(&mut self) -> Result<(), std::io::Error> {
+        self.window_height = 0.0;
+        self.window_maximized = false;
+        self.window_width = 0.0;
+        self.window_x = 0.0;
+        self.window_y = 0.0;
+        self.save()
+    }
+
+    /// The remembered window size, if the window has been resized before
+    pub fn saved_window_size(&self) -> Option<(f32, f32)> {
+        (self.window_width > 0.0 && self.window_height > 0.0)
+            .then_some((self.window_width, self.window_height))
+    }
+
+    /// Settings for copying to another machine. Machine-specific values like the
+    /// database path and sync state are left out, and credentials live in the database.
+    pub fn to_export(&self) -> Result<SettingsExport, serde_json::Error> {
+        let mut settings = match serde_json::to_value(self)? {
+            serde_json::Value::Object(settings) => settings,
+            _ => serde_json::Map::new(),
+        };
+        settings.retain(|key, _| !LOCAL_ONLY_SETTINGS.contains(&key.as_str()));
+
+        Ok(SettingsExport {
+            version: SETTINGS_EXPORT_VERSION,
+            settings,
+        })
+    }
+
+    /// Apply exported settings through their change_ functions. Unknown settings
+    /// (e.g. from a newer version) and values of the wrong type are skipped.
+    pub fn apply_export(
+        &mut self,
+        export: &SettingsExport,
+    ) -> Result<SettingsImportSummary, std::io::Error> {
+        let mut summary = SettingsImportSummary::default();
+
+        for (key, value) in &export.settings {
+            let result =
+                match key.as_str() {
+                    "archived_projects" => setting_value::<Vec<String>>(value)
+                        .map(|v| self.change_archived_projects(&v)),
+                    "auto_hide_completed_after_days" => setting_value::<u16>(value)
+                        .map(|v| self.change_auto_hide_completed_after_days(&v)),
+                    "auto_stop_enabled" => {
+                        setting_value::<bool>(value).map(|v| self.change_auto_stop_enabled(&v))
+                    }
+                    "auto_stop_time" => {
+                        setting_value::<String>(value).map(|v| self.change_auto_stop_time(&v))
+                    }
+                    "chosen_idle_time" => {
+                        setting_value::<i64>(value).map(|v| self.change_chosen_idle_time(&v))
+                    }
+                    "confirm_on_stop" => {
+                        setting_value::<bool>(value).map(|v| self.change_confirm_on_stop(&v))
+                    }
+                    "confirm_shortcut_switch" => setting_value::<bool>(value)
+                        .map(|v| self.change_confirm_shortcut_switch(&v)),
+                    "daily_target_minutes" => {
+                        setting_value::<i64>(value).map(|v| self.change_daily_target_minutes(&v))
+                    }
+                    "deduction_percent" => {
+                        setting_value::<f32>(value).map(|v| self.change_deduction_percent(&v))
+                    }
+                    "days_to_show" => {
+                        setting_value::<i64>(value).map(|v| self.change_days_to_show(&v))
+                    }
+                    "default_currency" => {
+                        setting_value::<String>(value).map(|v| self.change_default_currency(&v))
+                    }
+                    "default_view" => {
+                        setting_value::<FurView>(value).map(|v| self.change_default_view(&v))
+                    }
+                    "discard_if_shorter_than_seconds" => setting_value::<i64>(value)
+                        .map(|v| self.change_discard_if_shorter_than_seconds(&v)),
+                    "dynamic_total" => {
+                        setting_value::<bool>(value).map(|v| self.change_dynamic_total(&v))
+                    }
+                    "earnings_exclusions" => setting_value::<Vec<String>>(value)
+                        .map(|v| self.change_earnings_exclusions(&v)),
+                    "hide_archived_in_history" => setting_value::<bool>(value)
+                        .map(|v| self.change_hide_archived_in_history(&v)),
+                    "history_grouping" => setting_value::<FurHistoryGrouping>(value)
+                        .map(|v| self.change_history_grouping(&v)),
+                    "idle_backend" => {
+                        setting_value::<FurIdleBackend>(value).map(|v| self.change_idle_backend(&v))
+                    }
+                    "invoice_client" => {
+                        setting_value::<String>(value).map(|v| self.change_invoice_client(&v))
+                    }
+                    "invoice_from" => {
+                        setting_value::<String>(value).map(|v| self.change_invoice_from(&v))
+                    }
+                    "invoice_notes" => {
+                        setting_value::<String>(value).map(|v| self.change_invoice_notes(&v))
+                    }
+                    "invoice_number" => {
+                        setting_value::<String>(value).map(|v| self.change_invoice_number(&v))
+                    }
+                    "monthly_earnings_target" => {
+                        setting_value::<f32>(value).map(|v| self.change_monthly_earnings_target(&v))
+                    }
+                    "notify_of_sync" => {
+                        setting_value::<bool>(value).map(|v| self.change_notify_of_sync(v))
+                    }
+                    "notify_on_idle" => {
+                        setting_value::<bool>(value).map(|v| self.change_notify_on_idle(&v))
+                    }
+                    "notify_on_planned_time" => {
+                        setting_value::<bool>(value).map(|v| self.change_notify_on_planned_time(&v))
+                    }
+                    "notify_reminder" => {
+                        setting_value::<bool>(value).map(|v| self.change_notify_reminder(&v))
+                    }
+                    "notify_reminder_interval" => setting_value::<u16>(value)
+                        .map(|v| self.change_notify_reminder_interval(&v)),
+                    "pomodoro" => setting_value::<bool>(value).map(|v| self.change_pomodoro(&v)),
+                    "pomodoro_break_length" => {
+                        setting_value::<i64>(value).map(|v| self.change_pomodoro_break_length(&v))
+                    }
+                    "pomodoro_extended_break_interval" => setting_value::<u16>(value)
+                        .map(|v| self.change_pomodoro_extended_break_interval(&v)),
+                    "pomodoro_extended_break_length" => setting_value::<i64>(value)
+                        .map(|v| self.change_pomodoro_extended_break_length(&v)),
+                    "pomodoro_extended_breaks" => setting_value::<bool>(value)
+                        .map(|v| self.change_pomodoro_extended_breaks(&v)),
+                    "pomodoro_length" => {
+                        setting_value::<i64>(value).map(|v| self.change_pomodoro_length(&v))
+                    }
+                    "pomodoro_notification_alarm_sound" => setting_value::<bool>(value)
+                        .map(|v| self.change_pomodoro_notification_alarm_sound(&v)),
+                    "pomodoro_snooze_length" => {
+                        setting_value::<i64>(value).map(|v| self.change_pomodoro_snooze_length(&v))
+                    }
+                    "report_weekends_off" => {
+                        setting_value::<bool>(value).map(|v| self.change_report_weekends_off(&v))
+                    }
+                    "rounding_direction" => setting_value::<FurRoundingDirection>(value)
+                        .map(|v| self.change_rounding_direction(&v)),
+                    "rounding_enabled" => {
+                        setting_value::<bool>(value).map(|v| self.change_rounding_enabled(&v))
+                    }
+                    "rounding_minutes" => {
+                        setting_value::<i64>(value).map(|v| self.change_rounding_minutes(&v))
+                    }
+                    "scheduled_summary" => setting_value::<FurSummarySchedule>(value)
+                        .map(|v| self.change_scheduled_summary(&v)),
+                    "scheduled_summary_earnings" => setting_value::<bool>(value)
+                        .map(|v| self.change_scheduled_summary_earnings(&v)),
+                    "scheduled_summary_hour" => {
+                        setting_value::<u32>(value).map(|v| self.change_scheduled_summary_hour(&v))
+                    }
+                    "shortcut_sort" => setting_value::<FurShortcutSort>(value)
+                        .map(|v| self.change_shortcut_sort(&v)),
+                    "show_chart_average_earnings" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_average_earnings(&v)),
+                    "show_chart_average_time" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_average_time(&v)),
+                    "show_chart_breakdown_by_selection" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_breakdown_by_selection(&v)),
+                    "show_chart_cumulative_earnings" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_cumulative_earnings(&v)),
+                    "show_chart_earnings" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_chart_earnings(&v))
+                    }
+                    "show_chart_heatmap" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_chart_heatmap(&v))
+                    }
+                    "show_chart_selection_earnings" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_selection_earnings(&v)),
+                    "show_chart_selection_time" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_selection_time(&v)),
+                    "show_chart_time_of_day" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_chart_time_of_day(&v))
+                    }
+                    "show_chart_time_recorded" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_time_recorded(&v)),
+                    "show_chart_todos" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_chart_todos(&v))
+                    }
+                    "show_chart_total_earnings_box" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_total_earnings_box(&v)),
+                    "show_chart_total_time_box" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_total_time_box(&v)),
+                    "show_chart_workday_stats" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_chart_workday_stats(&v)),
+                    "show_daily_time_total" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_daily_time_total(&v))
+                    }
+                    "show_delete_confirmation" => setting_value::<bool>(value)
+                        .map(|v| self.change_show_delete_confirmation(&v)),
+                    "show_seconds" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_seconds(&v))
+                    }
+                    "show_net_earnings" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_net_earnings(&v))
+                    }
+                    "show_shortcut_stats" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_shortcut_stats(&v))
+                    }
+                    "show_task_earnings" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_task_earnings(&v))
+                    }
+                    "show_task_project" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_task_project(&v))
+                    }
+                    "show_task_planned_time" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_task_planned_time(&v))
+                    }
+                    "show_task_tags" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_task_tags(&v))
+                    }
+                    "show_timer_in_title" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_timer_in_title(&v))
+                    }
+                    "show_todo_estimates" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_todo_estimates(&v))
+                    }
+                    "show_todo_project" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_todo_project(&v))
+                    }
+                    "show_todo_rate" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_todo_rate(&v))
+                    }
+                    "show_todo_tags" => {
+                        setting_value::<bool>(value).map(|v| self.change_show_todo_tags(&v))
+                    }
+                    "sound_on_idle" => {
+                        setting_value::<bool>(value).map(|v| self.change_sound_on_idle(&v))
+                    }
+                    "sound_on_start_stop" => {
+                        setting_value::<bool>(value).map(|v| self.change_sound_on_start_stop(&v))
+                    }
+                    "sounds_enabled" => {
+                        setting_value::<bool>(value).map(|v| self.change_sounds_enabled(&v))
+                    }
+                    "status_file" => {
+                        setting_value::<bool>(value).map(|v| self.change_status_file(&v))
+                    }
+                    "status_server" => {
+                        setting_value::<bool>(value).map(|v| self.change_status_server(&v))
+                    }
+                    "status_server_port" => {
+                        setting_value::<u16>(value).map(|v| self.change_status_server_port(&v))
+                    }
+                    "summary_format" => setting_value::<FurSummaryFormat>(value)
+                        .map(|v| self.change_summary_format(&v)),
+                    "sync_interval_minutes" => {
+                        setting_value::<u16>(value).map(|v| self.change_sync_interval_minutes(&v))
+                    }
+                    "target_applies_to_weekends" => setting_value::<bool>(value)
+                        .map(|v| self.change_target_applies_to_weekends(&v)),
+                    "theme_preference" => setting_value::<FurThemePreference>(value)
+                        .map(|v| self.change_theme_preference(&v)),
+                    "trash_retention_days" => {
+                        setting_value::<u16>(value).map(|v| self.change_trash_retention_days(&v))
+                    }
+                    "use_24_hour_time" => {
+                        setting_value::<bool>(value).map(|v| self.change_use_24_hour_time(&v))
+                    }
+                    "warn_if_longer_than_hours" => setting_value::<i64>(value)
+                        .map(|v| self.change_warn_if_longer_than_hours(&v)),
+                    _ => None,
+                };
+
+            match result {
+                Some(saved) => {
+                    saved?;
+                    summary.applied += 1;
+                }
+                None => summary.skipped.push(key.clone()),
+            }
+        }
+
+        Ok(summary)
+    }
+
     pub fn reset_to_default_db_location(&mut self) -> Result<(), std::io::Error> {
         self.database_url = get_default_db_path().to_string_lossy().into_owned();
         self.save()
@@ -402,6 +1425,46 @@ pub fn get_default_db_path() -> PathBuf {
     path
 }
 
+/// Held by the running instance so a second launch can find it
+pub fn get_instance_lock_path() -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&["instance.lock"]);
+    path
+}
+
+/// Kept apart from the lock file because Windows locks block reading it
+pub fn get_instance_port_path() -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&["instance.port"]);
+    path
+}
+
+pub fn get_default_scheduled_summary_folder() -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&["summaries"]);
+    path
+}
+
+pub fn get_default_summary_file_path() -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&["summary.json"]);
+    path
+}
+
+fn setting_value<T: DeserializeOwned>(value: &serde_json::Value) -> Option<T> {
+    serde_json::from_value(value.clone()).ok()
+}
+
+/// Write to a temporary file and rename it into place so a crash mid-write
+/// can't leave a truncated file behind
+fn write_atomically(path: &Path, contents: &str) -> Result<(), std::io::Error> {
+    let temp_path = path.with_extension("toml.tmp");
+    let mut file = fs::File::create(&temp_path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
+
 fn get_settings_path() -> PathBuf {
     let mut path = get_data_path();
     path.extend(&["settings.toml"]);
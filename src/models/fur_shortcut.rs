@@ -14,9 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::Utc;
+use palette::Srgb;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{cmp::Reverse, collections::HashMap, fmt};
+
+use crate::{
+    helpers::{
+        clock_skew::sync_timestamp,
+        color_utils::{RandomColor, ToHex},
+        device::current_device_name,
+    },
+    update::msg_helper_functions::task_input_literal,
+    view_enums::FurShortcutSort,
+};
+
+pub const SHORTCUTS_EXPORT_VERSION: u32 = 1;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FurShortcut {
@@ -29,6 +41,12 @@ pub struct FurShortcut {
     pub uid: String,
     pub is_deleted: bool,
     pub last_updated: i64,
+    /// The device the shortcut was created on. Empty for shortcuts from before this was tracked.
+    #[serde(default)]
+    pub device_name: String,
+    /// Position in the manual order. Each device keeps its own order, so it isn't synced.
+    #[serde(skip)]
+    pub sort_order: i64,
 }
 
 impl FurShortcut {
@@ -51,17 +69,19 @@ impl FurShortcut {
             color_hex,
             uid,
             is_deleted: false,
-            last_updated: Utc::now().timestamp(),
+            last_updated: sync_timestamp(),
+            device_name: current_device_name(),
+            sort_order: 0,
         }
     }
 }
 
 impl fmt::Display for FurShortcut {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", task_input_literal(&self.name))?;
 
         if !self.project.is_empty() {
-            write!(f, " @{}", self.project)?;
+            write!(f, " @{}", task_input_literal(&self.project))?;
         }
 
         if !self.tags.is_empty() {
@@ -76,6 +96,86 @@ impl fmt::Display for FurShortcut {
     }
 }
 
+/// How a shortcut has been used on this device. Not synced.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ShortcutUsage {
+    pub count: usize,
+    pub last_used: Option<i64>,
+}
+
+/// A change to a shortcut's rate, kept so past billing can be reconstructed. Not synced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShortcutRateChange {
+    pub old_rate: f32,
+    pub new_rate: f32,
+    pub changed_at: i64,
+}
+
+impl ShortcutRateChange {
+    /// None when the rate is the same to the cent
+    pub fn between(old_rate: f32, new_rate: f32, changed_at: i64) -> Option<Self> {
+        if (old_rate * 100.0).round() == (new_rate * 100.0).round() {
+            None
+        } else {
+            Some(ShortcutRateChange {
+                old_rate,
+                new_rate,
+                changed_at,
+            })
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortcutMove {
+    Front,
+    Left,
+    Right,
+}
+
+/// Move a shortcut within the manual order. Returns false if it was already in place.
+pub fn move_shortcut(shortcuts: &mut Vec<FurShortcut>, uid: &str, direction: ShortcutMove) -> bool {
+    let Some(index) = shortcuts.iter().position(|shortcut| shortcut.uid == uid) else {
+        return false;
+    };
+    let new_index = match direction {
+        ShortcutMove::Front => 0,
+        ShortcutMove::Left => index.saturating_sub(1),
+        ShortcutMove::Right => (index + 1).min(shortcuts.len() - 1),
+    };
+    if new_index == index {
+        return false;
+    }
+
+    let shortcut = shortcuts.remove(index);
+    shortcuts.insert(new_index, shortcut);
+    for (position, shortcut) in shortcuts.iter_mut().enumerate() {
+        shortcut.sort_order = position as i64;
+    }
+    true
+}
+
+/// The shortcuts in the order they are shown. Manual keeps the saved order.
+pub fn sort_shortcuts<'a>(
+    shortcuts: &'a [FurShortcut],
+    usage: &HashMap<String, ShortcutUsage>,
+    sort: FurShortcutSort,
+) -> Vec<&'a FurShortcut> {
+    let usage_of = |shortcut: &FurShortcut| usage.get(&shortcut.uid).copied().unwrap_or_default();
+    let mut sorted: Vec<&FurShortcut> = shortcuts.iter().collect();
+    match sort {
+        FurShortcutSort::Manual => {}
+        FurShortcutSort::Alphabetical => sorted.sort_by_key(|s| s.name.to_lowercase()),
+        FurShortcutSort::MostUsed => {
+            sorted.sort_by_key(|s| (Reverse(usage_of(s).count), s.name.to_lowercase()))
+        }
+        FurShortcutSort::RecentlyUsed => {
+            sorted.sort_by_key(|s| (Reverse(usage_of(s).last_used), s.name.to_lowercase()))
+        }
+    }
+    sorted
+}
+
 pub fn generate_shortcut_uid(
     name: &str,
     tags: &str,
@@ -88,6 +188,88 @@ pub fn generate_shortcut_uid(
     blake3::hash(input.as_bytes()).to_hex().to_string()
 }
 
+/// A shortcuts file, for copying shortcuts to another device without sync
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShortcutsExport {
+    pub version: u32,
+    pub shortcuts: Vec<ExportedShortcut>,
+}
+
+impl ShortcutsExport {
+    pub fn new(shortcuts: &[FurShortcut]) -> Self {
+        ShortcutsExport {
+            version: SHORTCUTS_EXPORT_VERSION,
+            shortcuts: shortcuts.iter().map(ExportedShortcut::from).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ExportedShortcut {
+    pub name: String,
+    #[serde(default)]
+    pub project: String,
+    #[serde(default)]
+    pub tags: String,
+    #[serde(default)]
+    pub rate: f32,
+    #[serde(default)]
+    pub currency: String,
+    #[serde(default)]
+    pub color_hex: String,
+    #[serde(default)]
+    pub uid: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub device_name: String,
+}
+
+impl From<&FurShortcut> for ExportedShortcut {
+    fn from(shortcut: &FurShortcut) -> Self {
+        ExportedShortcut {
+            name: shortcut.name.clone(),
+            project: shortcut.project.clone(),
+            tags: shortcut.tags.clone(),
+            rate: shortcut.rate,
+            currency: shortcut.currency.clone(),
+            color_hex: shortcut.color_hex.clone(),
+            uid: shortcut.uid.clone(),
+            device_name: shortcut.device_name.clone(),
+        }
+    }
+}
+
+impl ExportedShortcut {
+    /// A new shortcut from the exported one. An invalid color is replaced with a random one.
+    pub fn into_shortcut(self) -> FurShortcut {
+        let color_hex = if is_valid_color_hex(&self.color_hex) {
+            self.color_hex
+        } else {
+            Srgb::random().to_hex()
+        };
+        let mut shortcut = FurShortcut::new(
+            self.name,
+            self.tags,
+            self.project,
+            self.rate,
+            self.currency,
+            color_hex,
+        );
+        if !self.uid.is_empty() {
+            shortcut.uid = self.uid;
+        }
+        if !self.device_name.is_empty() {
+            shortcut.device_name = self.device_name;
+        }
+        shortcut
+    }
+}
+
+/// Whether `hex` is a color in the `#RRGGBB` form written by `ToHex`
+pub fn is_valid_color_hex(hex: &str) -> bool {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EncryptedShortcut {
     pub encrypted_data: String,
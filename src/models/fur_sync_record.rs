@@ -0,0 +1,33 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// What one sync with the server did. Only kept locally.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FurSyncRecord {
+    /// When the sync finished, as a Unix timestamp
+    pub synced_at: i64,
+    pub duration_millis: i64,
+    pub uploaded: usize,
+    pub downloaded: usize,
+    /// The serialized size of the encrypted records sent and received
+    pub payload_bytes: usize,
+}
+
+impl FurSyncRecord {
+    pub fn items(&self) -> usize {
+        self.uploaded + self.downloaded
+    }
+}
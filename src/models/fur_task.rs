@@ -14,9 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, TimeDelta};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    constants::NON_BILLABLE_MARKER,
+    helpers::{clock_skew::sync_timestamp, device::current_device_name},
+    update::msg_helper_functions::task_input_literal,
+};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FurTask {
     pub name: String,
@@ -29,20 +35,34 @@ pub struct FurTask {
     pub uid: String,
     pub is_deleted: bool,
     pub last_updated: i64,
+    #[serde(default = "default_is_billable")]
+    pub is_billable: bool,
+    /// The device the task was created on. Empty for tasks from before this was tracked.
+    #[serde(default)]
+    pub device_name: String,
+    /// The length the task was planned to take, typed as `~45m`. 0 when there was no plan.
+    #[serde(default)]
+    pub planned_seconds: i64,
+    /// Notes written while the timer ran
+    #[serde(default)]
+    pub notes: String,
 }
 
 impl ToString for FurTask {
     fn to_string(&self) -> String {
-        let mut task_string: String = self.name.to_string();
+        let mut task_string: String = task_input_literal(&self.name);
 
         if !self.project.is_empty() {
-            task_string += &format!(" @{}", self.project);
+            task_string += &format!(" @{}", task_input_literal(&self.project));
         }
         if !self.tags.is_empty() {
             task_string += &format!(" #{}", self.tags);
         }
         if self.rate != 0.0 {
             task_string += &format!(" ${:.2}", self.rate);
+            if !self.is_billable {
+                task_string += &format!(" {}", NON_BILLABLE_MARKER);
+            }
         }
 
         task_string
@@ -71,7 +91,11 @@ impl FurTask {
             currency,
             uid,
             is_deleted: false,
-            last_updated: Utc::now().timestamp(),
+            last_updated: sync_timestamp(),
+            is_billable: rate > 0.0,
+            device_name: current_device_name(),
+            planned_seconds: 0,
+            notes: String::new(),
         }
     }
 
@@ -98,6 +122,10 @@ impl FurTask {
             uid,
             is_deleted: false,
             last_updated,
+            is_billable: rate > 0.0,
+            device_name: current_device_name(),
+            planned_seconds: 0,
+            notes: String::new(),
         }
     }
 
@@ -105,9 +133,64 @@ impl FurTask {
         (self.stop_time - self.start_time).num_seconds()
     }
 
-    pub fn total_earnings(&self) -> f32 {
-        (self.total_time_in_seconds() as f32 / 3600.0) * self.rate
+    /// A copy of this task moved by `offset`. The copy gets its own uid even when it
+    /// lands on the same times, since the uid is otherwise derived from them.
+    pub fn duplicate(&self, offset: TimeDelta) -> Self {
+        let start_time = self.start_time + offset;
+        let stop_time = self.stop_time + offset;
+        let uid = generate_task_uid(
+            &format!("{}{}", self.name, rand::random::<u64>()),
+            &start_time,
+            &stop_time,
+        );
+
+        FurTask {
+            start_time,
+            stop_time,
+            uid,
+            is_deleted: false,
+            last_updated: sync_timestamp(),
+            device_name: current_device_name(),
+            ..self.clone()
+        }
+    }
+
+    /// Divide this task in two at `at`, which must fall strictly inside it.
+    /// The first part keeps this task's uid; the second part is a new task.
+    pub fn split_at(&self, at: DateTime<Local>) -> Option<(FurTask, FurTask)> {
+        if at <= self.start_time || at >= self.stop_time {
+            return None;
+        }
+
+        let last_updated = sync_timestamp();
+        let first_part = FurTask {
+            stop_time: at,
+            last_updated,
+            ..self.clone()
+        };
+        let second_part = FurTask {
+            start_time: at,
+            uid: generate_task_uid(&self.name, &at, &self.stop_time),
+            last_updated,
+            ..self.clone()
+        };
+
+        Some((first_part, second_part))
     }
+
+    /// Earnings for a (possibly rounded) duration at this task's rate
+    pub fn earnings_for(&self, seconds: i64) -> f32 {
+        if self.is_billable {
+            (seconds as f32 / 3600.0) * self.rate
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Tasks synced from older versions don't carry the flag and stay billable
+fn default_is_billable() -> bool {
+    true
 }
 
 pub fn generate_task_uid(
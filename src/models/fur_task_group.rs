@@ -14,8 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::models::fur_task::FurTask;
+use crate::{
+    constants::NON_BILLABLE_MARKER, models::fur_task::FurTask,
+    update::msg_helper_functions::task_input_literal,
+};
 
+use chrono::{DateTime, Local, TimeDelta};
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -25,6 +29,7 @@ pub struct FurTaskGroup {
     pub tags: String,
     pub project: String,
     pub rate: f32,
+    pub is_billable: bool,
     pub total_time: i64,
     pub tasks: Vec<FurTask>,
 }
@@ -37,6 +42,7 @@ impl FurTaskGroup {
             tags: task.tags.clone(),
             project: task.project.clone(),
             rate: task.rate,
+            is_billable: task.is_billable,
             total_time: (task.stop_time - task.start_time).num_seconds(),
             tasks: vec![task],
         }
@@ -52,6 +58,7 @@ impl FurTaskGroup {
             && self.tags == task.tags
             && self.project.to_lowercase() == task.project.to_lowercase()
             && self.rate == task.rate
+            && self.is_billable == task.is_billable
         {
             true
         } else {
@@ -59,17 +66,47 @@ impl FurTaskGroup {
         }
     }
 
+    /// Earnings for a (possibly rounded) duration at this group's rate
+    pub fn earnings_for(&self, seconds: i64) -> f32 {
+        if self.is_billable {
+            (seconds as f32 / 3600.0) * self.rate
+        } else {
+            0.0
+        }
+    }
+
+    /// The planned time of every task in the group that had a plan
+    pub fn planned_seconds(&self) -> i64 {
+        self.tasks.iter().map(|task| task.planned_seconds).sum()
+    }
+
     pub fn all_task_ids(&self) -> Vec<String> {
         self.tasks.iter().map(|task| task.uid.clone()).collect()
     }
+
+    /// Copies of every task in the group. With `starting_at`, the copies are shifted
+    /// so the earliest one starts then, keeping each duration and the gaps between them.
+    pub fn duplicate_tasks(&self, starting_at: Option<DateTime<Local>>) -> Vec<FurTask> {
+        let offset = match (
+            starting_at,
+            self.tasks.iter().map(|task| task.start_time).min(),
+        ) {
+            (Some(new_start), Some(earliest_start)) => new_start - earliest_start,
+            _ => TimeDelta::zero(),
+        };
+        self.tasks
+            .iter()
+            .map(|task| task.duplicate(offset))
+            .collect()
+    }
 }
 
 impl fmt::Display for FurTaskGroup {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", task_input_literal(&self.name))?;
 
         if !self.project.is_empty() {
-            write!(f, " @{}", self.project)?;
+            write!(f, " @{}", task_input_literal(&self.project))?;
         }
 
         if !self.tags.is_empty() {
@@ -78,6 +115,9 @@ impl fmt::Display for FurTaskGroup {
 
         if self.rate != 0.0 {
             write!(f, " ${:.2}", self.rate)?;
+            if !self.is_billable {
+                write!(f, " {}", NON_BILLABLE_MARKER)?;
+            }
         }
 
         Ok(())
@@ -14,10 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local};
 use iced_aw::date_picker::Date;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    helpers::{clock_skew::sync_timestamp, device::current_device_name},
+    models::fur_task_group::FurTaskGroup,
+    update::msg_helper_functions::{
+        escape_task_input_symbols, format_duration_input, parse_duration_input, task_input_literal,
+        unescape_task_input_text,
+    },
+};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FurTodo {
     pub name: String,
@@ -30,6 +39,15 @@ pub struct FurTodo {
     pub is_completed: bool,
     pub is_deleted: bool,
     pub last_updated: i64,
+    /// Estimated time to complete in seconds, 0 if not estimated
+    #[serde(default)]
+    pub estimate: i64,
+    /// The device the todo was created on. Empty for todos from before this was tracked.
+    #[serde(default)]
+    pub device_name: String,
+    /// When the todo was marked complete. Empty for todos completed before this was tracked.
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Local>>,
 }
 
 impl FurTodo {
@@ -52,17 +70,50 @@ impl FurTodo {
             uid,
             is_completed: false,
             is_deleted: false,
-            last_updated: Utc::now().timestamp(),
+            last_updated: sync_timestamp(),
+            estimate: 0,
+            device_name: current_device_name(),
+            completed_at: None,
+        }
+    }
+
+    /// What the estimate would earn at the todo's rate
+    pub fn estimated_earnings(&self) -> f32 {
+        (self.estimate as f32 / 3600.0) * self.rate
+    }
+
+    /// The time tracked on tasks with the same name, project and tags,
+    /// or `None` if nothing matching was tracked
+    pub fn tracked_time(&self, task_groups: &[FurTaskGroup]) -> Option<i64> {
+        let matching: Vec<&FurTaskGroup> = task_groups
+            .iter()
+            .filter(|group| {
+                group.name == self.name && group.project == self.project && group.tags == self.tags
+            })
+            .collect();
+        if matching.is_empty() {
+            None
+        } else {
+            Some(matching.iter().map(|group| group.total_time).sum())
         }
     }
 }
 
+/// The number of todos, their total estimated time, and the total estimated earnings
+pub fn todo_estimate_totals(todos: &[FurTodo]) -> (usize, i64, f32) {
+    (
+        todos.len(),
+        todos.iter().map(|todo| todo.estimate).sum(),
+        todos.iter().map(FurTodo::estimated_earnings).sum(),
+    )
+}
+
 impl ToString for FurTodo {
     fn to_string(&self) -> String {
-        let mut todo_string: String = self.name.to_string();
+        let mut todo_string: String = task_input_literal(&self.name);
 
         if !self.project.is_empty() {
-            todo_string += &format!(" @{}", self.project);
+            todo_string += &format!(" @{}", task_input_literal(&self.project));
         }
         if !self.tags.is_empty() {
             todo_string += &format!(" #{}", self.tags);
@@ -84,7 +135,10 @@ pub struct TodoToAdd {
     pub date: DateTime<Local>,
     pub displayed_date: Date,
     pub show_date_picker: bool,
+    pub estimate: String,
     pub invalid_input_error_message: String,
+    /// How the form looked when it opened
+    pub original: Option<Box<TodoToAdd>>,
 }
 
 impl TodoToAdd {
@@ -98,8 +152,28 @@ impl TodoToAdd {
             date: now,
             displayed_date: Date::from(now.date_naive()),
             show_date_picker: false,
+            estimate: String::new(),
             invalid_input_error_message: String::new(),
+            original: None,
         }
+        .remember_original()
+    }
+
+    fn remember_original(mut self) -> Self {
+        self.original = Some(Box::new(self.clone()));
+        self
+    }
+
+    /// Whether anything was filled in or changed since the form opened
+    pub fn is_changed(&self) -> bool {
+        self.original.as_ref().is_some_and(|original| {
+            self.name.trim() != original.name.trim()
+                || self.project.trim() != original.project.trim()
+                || self.tags.trim() != original.tags.trim()
+                || self.rate.trim() != original.rate.trim()
+                || self.date != original.date
+                || self.estimate.trim() != original.estimate.trim()
+        })
     }
 
     pub fn input_error(&mut self, message: String) {
@@ -122,6 +196,10 @@ pub struct TodoToEdit {
     pub new_rate: String,
     pub uid: String,
     pub is_completed: bool,
+    pub completed_at: Option<DateTime<Local>>,
+    pub estimate: i64,
+    pub new_estimate: String,
+    pub device_name: String,
     pub invalid_input_error_message: String,
 }
 
@@ -129,7 +207,7 @@ impl TodoToEdit {
     pub fn new_from(todo: &FurTodo) -> Self {
         TodoToEdit {
             name: todo.name.clone(),
-            new_name: todo.name.clone(),
+            new_name: escape_task_input_symbols(&todo.name),
             date: todo.date,
             new_date: todo.date,
             displayed_date: Date::from(todo.date.date_naive()),
@@ -146,12 +224,16 @@ impl TodoToEdit {
             new_rate: format!("{:.2}", todo.rate),
             uid: todo.uid.clone(),
             is_completed: todo.is_completed,
+            completed_at: todo.completed_at,
+            estimate: todo.estimate,
+            new_estimate: format_duration_input(todo.estimate),
+            device_name: todo.device_name.clone(),
             invalid_input_error_message: String::new(),
         }
     }
 
     pub fn is_changed(&self) -> bool {
-        if self.name != self.new_name.trim()
+        if self.name != unescape_task_input_text(self.new_name.trim())
             || self.date != self.new_date
             || self.tags
                 != self
@@ -162,6 +244,7 @@ impl TodoToEdit {
                     .trim()
             || self.project != self.new_project.trim()
             || self.rate != self.new_rate.trim().parse::<f32>().unwrap_or(0.0)
+            || Some(self.estimate) != parse_duration_input(&self.new_estimate)
         {
             true
         } else {
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::models::{fur_task::FurTask, fur_task_group::FurTaskGroup};
+use crate::{
+    models::{fur_task::FurTask, fur_task_group::FurTaskGroup},
+    update::msg_helper_functions::{escape_task_input_symbols, unescape_task_input_text},
+};
 
 #[derive(Debug, Clone)]
 pub struct GroupToEdit {
@@ -30,6 +33,7 @@ pub struct GroupToEdit {
     pub tasks: Vec<FurTask>,
     pub is_in_edit_mode: bool,
     pub invalid_input_error_message: String,
+    pub update_past_rates: bool,
 }
 
 impl GroupToEdit {
@@ -37,7 +41,7 @@ impl GroupToEdit {
         GroupToEdit {
             uid: group.uid.clone(),
             name: group.name.clone(),
-            new_name: group.name.clone(),
+            new_name: escape_task_input_symbols(&group.name),
             tags: group.tags.clone(),
             new_tags: if group.tags.is_empty() {
                 group.tags.clone()
@@ -51,11 +55,12 @@ impl GroupToEdit {
             tasks: group.tasks.clone(),
             is_in_edit_mode: false,
             invalid_input_error_message: String::new(),
+            update_past_rates: false,
         }
     }
 
     pub fn is_changed(&self) -> bool {
-        if self.name != self.new_name.trim()
+        if self.name != unescape_task_input_text(self.new_name.trim())
             || self.tags
                 != self
                     .new_tags
@@ -64,7 +69,7 @@ impl GroupToEdit {
                     .unwrap_or(&self.tags)
                     .trim()
             || self.project != self.new_project.trim()
-            || self.rate != self.new_rate.trim().parse::<f32>().unwrap_or(0.0)
+            || self.rate_is_changed()
         {
             true
         } else {
@@ -72,6 +77,20 @@ impl GroupToEdit {
         }
     }
 
+    pub fn rate_is_changed(&self) -> bool {
+        self.rate != self.new_rate.trim().parse::<f32>().unwrap_or(0.0)
+    }
+
+    /// The rate written to the group's tasks. A new rate only replaces the stored one
+    /// when it should also apply to these past tasks.
+    pub fn rate_to_save(&self) -> f32 {
+        if self.update_past_rates {
+            self.new_rate.trim().parse::<f32>().unwrap_or(0.0)
+        } else {
+            self.rate
+        }
+    }
+
     pub fn input_error(&mut self, message: String) {
         self.invalid_input_error_message = message;
     }
@@ -0,0 +1,65 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+
+use crate::models::fur_task_group::FurTaskGroup;
+
+#[derive(Debug, Clone)]
+pub struct HistorySelection {
+    pub selected_task_ids: HashSet<String>,
+    pub new_project: String,
+    pub tag: String,
+    pub new_rate: String,
+    pub invalid_input_error_message: String,
+}
+
+impl HistorySelection {
+    pub fn new() -> Self {
+        HistorySelection {
+            selected_task_ids: HashSet::new(),
+            new_project: String::new(),
+            tag: String::new(),
+            new_rate: String::new(),
+            invalid_input_error_message: String::new(),
+        }
+    }
+
+    pub fn is_group_selected(&self, group: &FurTaskGroup) -> bool {
+        group
+            .all_task_ids()
+            .iter()
+            .all(|uid| self.selected_task_ids.contains(uid))
+    }
+
+    pub fn set_group_selected(&mut self, task_ids: Vec<String>, selected: bool) {
+        for uid in task_ids {
+            if selected {
+                self.selected_task_ids.insert(uid);
+            } else {
+                self.selected_task_ids.remove(&uid);
+            }
+        }
+    }
+
+    pub fn selected_task_ids(&self) -> Vec<String> {
+        self.selected_task_ids.iter().cloned().collect()
+    }
+
+    pub fn input_error(&mut self, message: String) {
+        self.invalid_input_error_message = message;
+    }
+}
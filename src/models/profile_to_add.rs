@@ -0,0 +1,36 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// The name and database typed into the profile settings. The name is also
+/// what a profile is renamed to.
+#[derive(Clone, Debug)]
+pub struct ProfileToAdd {
+    pub name: String,
+    pub database_url: String,
+}
+
+impl ProfileToAdd {
+    pub fn new() -> Self {
+        ProfileToAdd {
+            name: String::new(),
+            database_url: String::new(),
+        }
+    }
+
+    pub fn can_add(&self) -> bool {
+        !self.name.trim().is_empty() && !self.database_url.trim().is_empty()
+    }
+}
@@ -0,0 +1,90 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::keyboard_nav::{NavDirection, move_list_index},
+    models::{fur_task::FurTask, fur_task_group::FurTaskGroup},
+};
+
+/// The task strings offered under the task input, so a task can be repeated
+/// without retyping it or scrolling the history for it
+#[derive(Clone, Debug)]
+pub struct RecentTasks {
+    pub recent: Vec<String>,
+    pub last_week: Vec<String>,
+    pub is_open: bool,
+    pub focus: Option<usize>,
+}
+
+impl RecentTasks {
+    pub fn new() -> Self {
+        RecentTasks {
+            recent: vec![],
+            last_week: vec![],
+            is_open: false,
+            focus: None,
+        }
+    }
+
+    pub fn set_tasks(&mut self, recent: Vec<String>, last_week: Vec<String>) {
+        self.recent = recent;
+        self.last_week = last_week;
+        self.focus = self.focus.filter(|&index| index < self.len());
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.focus = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.focus = None;
+    }
+
+    pub fn len(&self) -> usize {
+        self.recent.len() + self.last_week.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Both sections in the order they're shown, which is the order keyboard focus moves in
+    pub fn entries(&self) -> impl Iterator<Item = &String> {
+        self.recent.iter().chain(self.last_week.iter())
+    }
+
+    pub fn move_focus(&mut self, direction: NavDirection) {
+        self.focus = move_list_index(self.focus, self.len(), direction);
+    }
+
+    pub fn focused_task(&self) -> Option<&String> {
+        self.focus.and_then(|index| self.entries().nth(index))
+    }
+}
+
+/// Each task's input string, as shown on its history row, once and in the order given
+pub fn distinct_task_strings(tasks: &[FurTask]) -> Vec<String> {
+    let mut strings: Vec<String> = Vec::new();
+    for task in tasks {
+        let task_string = FurTaskGroup::new_from(task.clone()).to_string();
+        if !strings.contains(&task_string) {
+            strings.push(task_string);
+        }
+    }
+    strings
+}
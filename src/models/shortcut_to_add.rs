@@ -27,6 +27,8 @@ pub struct ShortcutToAdd {
     pub color: Color,
     pub show_color_picker: bool,
     pub invalid_input_error_message: String,
+    /// How the form looked when it opened
+    pub original: Option<Box<ShortcutToAdd>>,
 }
 
 impl ShortcutToAdd {
@@ -39,7 +41,25 @@ impl ShortcutToAdd {
             color: Color::random(),
             show_color_picker: false,
             invalid_input_error_message: String::new(),
+            original: None,
         }
+        .remember_original()
+    }
+
+    fn remember_original(mut self) -> Self {
+        self.original = Some(Box::new(self.clone()));
+        self
+    }
+
+    /// Whether anything was filled in or changed since the form opened
+    pub fn is_changed(&self) -> bool {
+        self.original.as_ref().is_some_and(|original| {
+            self.name.trim() != original.name.trim()
+                || self.tags.trim() != original.tags.trim()
+                || self.project.trim() != original.project.trim()
+                || self.new_rate.trim() != original.new_rate.trim()
+                || self.color != original.color
+        })
     }
 
     pub fn input_error(&mut self, message: String) {
@@ -16,7 +16,11 @@
 
 use iced::Color;
 
-use super::fur_shortcut::FurShortcut;
+use crate::update::msg_helper_functions::{
+    escape_task_input_symbols, normalize_shortcut_tags, unescape_task_input_text,
+};
+
+use super::fur_shortcut::{FurShortcut, ShortcutRateChange};
 
 #[derive(Clone, Debug)]
 pub struct ShortcutToEdit {
@@ -32,7 +36,10 @@ pub struct ShortcutToEdit {
     pub new_color: Color,
     pub show_color_picker: bool,
     pub uid: String,
+    pub device_name: String,
+    pub sort_order: i64,
     pub invalid_input_error_message: String,
+    pub rate_history: Vec<ShortcutRateChange>,
 }
 
 impl ShortcutToEdit {
@@ -44,7 +51,7 @@ impl ShortcutToEdit {
         );
         ShortcutToEdit {
             name: shortcut.name.clone(),
-            new_name: shortcut.name.clone(),
+            new_name: escape_task_input_symbols(&shortcut.name),
             tags: shortcut.tags.clone(),
             new_tags: shortcut.tags.clone(),
             project: shortcut.project.clone(),
@@ -55,19 +62,17 @@ impl ShortcutToEdit {
             new_color: color,
             show_color_picker: false,
             uid: shortcut.uid.clone(),
+            device_name: shortcut.device_name.clone(),
+            sort_order: shortcut.sort_order,
             invalid_input_error_message: "".to_string(),
+            rate_history: Vec::new(),
         }
     }
 
     pub fn is_changed(&self) -> bool {
-        self.name != self.new_name
-            || self.tags
-                != self
-                    .new_tags
-                    .trim()
-                    .strip_prefix('#')
-                    .unwrap_or(&self.tags)
-                    .trim()
+        // Shortcut tags are stored with their leading '#'
+        self.name != unescape_task_input_text(self.new_name.trim())
+            || self.tags != normalize_shortcut_tags(&self.new_tags)
             || self.project != self.new_project.trim()
             || self.rate != self.new_rate.parse::<f32>().unwrap_or(0.0)
             || self.color != self.new_color
@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::{DateTime, Duration, Local, NaiveTime};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime};
 use iced_aw::{date_picker::Date, time_picker::Time};
 
+use crate::update::msg_helper_functions::escape_task_input_symbols;
+
 use super::group_to_edit::GroupToEdit;
 
 #[derive(Clone, Debug)]
@@ -36,7 +38,10 @@ pub struct TaskToAdd {
     pub project: String,
     pub rate: f32,
     pub new_rate: String,
+    pub is_billable: bool,
     pub invalid_input_error_message: String,
+    /// How the form looked when it opened
+    pub original: Option<Box<TaskToAdd>>,
 }
 
 impl TaskToAdd {
@@ -59,8 +64,33 @@ impl TaskToAdd {
             project: String::new(),
             rate: 0.0,
             new_rate: format!("{:.2}", 0.0),
+            is_billable: true,
             invalid_input_error_message: String::new(),
+            original: None,
         }
+        .remember_original()
+    }
+
+    /// A task from 09:00 to 10:00 on `date`, for filling in forgotten time
+    pub fn new_for_date(date: NaiveDate) -> Self {
+        let at = |hour| {
+            NaiveTime::from_hms_opt(hour, 0, 0)
+                .and_then(|time| date.and_time(time).and_local_timezone(Local).earliest())
+        };
+        let (Some(start_time), Some(stop_time)) = (at(9), at(10)) else {
+            return TaskToAdd::new();
+        };
+
+        TaskToAdd {
+            start_time,
+            displayed_start_time: Time::from(start_time.time()),
+            displayed_start_date: Date::from(date),
+            stop_time,
+            displayed_stop_time: Time::from(stop_time.time()),
+            displayed_stop_date: Date::from(date),
+            ..TaskToAdd::new()
+        }
+        .remember_original()
     }
 
     pub fn new_from(group: &GroupToEdit) -> Self {
@@ -87,7 +117,7 @@ impl TaskToAdd {
             },
         );
         TaskToAdd {
-            name: group.name.clone(),
+            name: escape_task_input_symbols(&group.name),
             start_time: begin_date_time,
             displayed_start_time: Time::from(begin_date_time.time()),
             displayed_start_date: Date::from(begin_date_time.date_naive()),
@@ -106,8 +136,42 @@ impl TaskToAdd {
             project: group.project.clone(),
             rate: group.rate,
             new_rate: format!("{:.2}", group.rate),
+            is_billable: group
+                .tasks
+                .first()
+                .map_or(true, |task| task.is_billable || task.rate == 0.0),
             invalid_input_error_message: String::new(),
+            original: None,
         }
+        .remember_original()
+    }
+
+    fn remember_original(mut self) -> Self {
+        self.original = None;
+        self.original = Some(Box::new(self.clone()));
+        self
+    }
+
+    /// Whether anything was filled in or changed since the form opened
+    pub fn is_changed(&self) -> bool {
+        self.original.as_ref().is_some_and(|original| {
+            self.name.trim() != original.name.trim()
+                || self.start_time != original.start_time
+                || self.stop_time != original.stop_time
+                || self.tags.trim() != original.tags.trim()
+                || self.project.trim() != original.project.trim()
+                || self.new_rate.trim() != original.new_rate.trim()
+                || self.is_billable != original.is_billable
+        })
+    }
+
+    pub fn times_are_valid(&self) -> bool {
+        self.stop_time > self.start_time
+    }
+
+    /// Only tasks with a rate can be billable
+    pub fn billable_to_save(&self) -> bool {
+        self.is_billable && self.new_rate.trim().parse::<f32>().unwrap_or(0.0) > 0.0
     }
 
     pub fn input_error(&mut self, message: String) {
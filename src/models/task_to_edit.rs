@@ -17,6 +17,8 @@
 use chrono::{DateTime, Local};
 use iced_aw::{date_picker::Date, time_picker::Time};
 
+use crate::update::msg_helper_functions::{escape_task_input_symbols, unescape_task_input_text};
+
 use super::fur_task::FurTask;
 
 #[derive(Clone, Debug)]
@@ -35,21 +37,30 @@ pub struct TaskToEdit {
     pub displayed_stop_date: Date,
     pub show_displayed_stop_time_picker: bool,
     pub show_displayed_stop_date_picker: bool,
+    pub displayed_split_time: Time,
+    pub show_split_time_picker: bool,
     pub tags: String,
     pub new_tags: String,
     pub project: String,
     pub new_project: String,
     pub rate: f32,
     pub new_rate: String,
+    pub is_billable: bool,
+    pub new_is_billable: bool,
     pub uid: String,
+    pub device_name: String,
+    pub planned_seconds: i64,
+    pub notes: String,
     pub invalid_input_error_message: String,
+    /// A just-stopped task that isn't in the database until it's saved
+    pub is_unsaved: bool,
 }
 
 impl TaskToEdit {
     pub fn new_from(task: &FurTask) -> Self {
         TaskToEdit {
             name: task.name.clone(),
-            new_name: task.name.clone(),
+            new_name: escape_task_input_symbols(&task.name),
             start_time: task.start_time,
             new_start_time: task.start_time,
             displayed_start_time: Time::from(task.start_time.naive_local().time()),
@@ -62,6 +73,13 @@ impl TaskToEdit {
             displayed_stop_date: Date::from(task.stop_time.date_naive()),
             show_displayed_stop_time_picker: false,
             show_displayed_stop_date_picker: false,
+            // Suggest splitting halfway through
+            displayed_split_time: Time::from(
+                (task.start_time + (task.stop_time - task.start_time) / 2)
+                    .naive_local()
+                    .time(),
+            ),
+            show_split_time_picker: false,
             tags: task.tags.clone(),
             new_tags: if task.tags.is_empty() {
                 task.tags.clone()
@@ -72,13 +90,29 @@ impl TaskToEdit {
             new_project: task.project.clone(),
             rate: task.rate,
             new_rate: format!("{:.2}", task.rate),
+            is_billable: task.is_billable,
+            // Tasks without a rate start out billable in case a rate is added
+            new_is_billable: task.is_billable || task.rate == 0.0,
             uid: task.uid.clone(),
+            device_name: task.device_name.clone(),
+            planned_seconds: task.planned_seconds,
+            notes: task.notes.clone(),
             invalid_input_error_message: String::new(),
+            is_unsaved: false,
+        }
+    }
+
+    pub fn new_unsaved(task: &FurTask) -> Self {
+        TaskToEdit {
+            is_unsaved: true,
+            ..TaskToEdit::new_from(task)
         }
     }
 
+    /// An unsaved task can always be saved, even without changes
     pub fn is_changed(&self) -> bool {
-        if self.name != self.new_name.trim()
+        if self.is_unsaved
+            || self.name != unescape_task_input_text(self.new_name.trim())
             || self.start_time != self.new_start_time
             || self.stop_time != self.new_stop_time
             || self.tags
@@ -90,6 +124,7 @@ impl TaskToEdit {
                     .trim()
             || self.project != self.new_project.trim()
             || self.rate != self.new_rate.trim().parse::<f32>().unwrap_or(0.0)
+            || self.is_billable != self.billable_to_save()
         {
             true
         } else {
@@ -97,6 +132,15 @@ impl TaskToEdit {
         }
     }
 
+    pub fn times_are_valid(&self) -> bool {
+        self.new_stop_time > self.new_start_time
+    }
+
+    /// Only tasks with a rate can be billable
+    pub fn billable_to_save(&self) -> bool {
+        self.new_is_billable && self.new_rate.trim().parse::<f32>().unwrap_or(0.0) > 0.0
+    }
+
     pub fn input_error(&mut self, message: String) {
         self.invalid_input_error_message = message;
     }
@@ -16,32 +16,144 @@
 
 use std::sync::Arc;
 
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::{Client, StatusCode, header::HeaderMap};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
-use crate::server::encryption::generate_device_id;
+use crate::{
+    constants::{FURTHERANCE_VERSION, SYNC_PROTOCOL_VERSION},
+    helpers::clock_skew::{measure_skew, parse_http_date},
+    server::encryption::generate_device_id,
+};
+
+/// Error codes the server uses when it no longer speaks this client's protocol
+const INCOMPATIBLE_VERSION_ERRORS: [&str; 3] = [
+    "incompatible_version",
+    "unsupported_client",
+    "version_mismatch",
+];
 
 #[derive(Clone, Debug)]
 pub enum ApiError {
     Auth(String),
     Device(String),
     InactiveSubscription(String),
+    IncompatibleVersion(String),
     Network(Arc<reqwest::Error>),
     Server(String),
     TokenRefresh(String),
 }
 
+impl ApiError {
+    /// The kind of connection failure, for network errors
+    pub fn network_failure(&self) -> Option<NetworkFailure> {
+        match self {
+            ApiError::Network(e) => Some(classify_network_error(e.as_ref())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkFailure {
+    Certificate,
+    Dns,
+    Other,
+}
+
+/// reqwest wraps the resolver and TLS errors several layers deep,
+/// so look through the whole chain for what actually went wrong
+pub fn classify_network_error(error: &(dyn std::error::Error + 'static)) -> NetworkFailure {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        let message = error.to_string().to_lowercase();
+        if message.contains("dns error")
+            || message.contains("failed to lookup address")
+            || message.contains("name or service not known")
+            || message.contains("no such host")
+        {
+            return NetworkFailure::Dns;
+        }
+        if message.contains("certificate") || message.contains("tls") || message.contains("ssl") {
+            return NetworkFailure::Certificate;
+        }
+        current = error.source();
+    }
+    NetworkFailure::Other
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServerUrlError {
+    Empty,
+    Invalid,
+    UnsupportedScheme,
+}
+
+/// Tidy a server address typed by hand. An address without a scheme gets
+/// https://, and trailing slashes are dropped so paths can be appended.
+pub fn normalize_server_url(input: &str) -> Result<String, ServerUrlError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ServerUrlError::Empty);
+    }
+
+    let with_scheme = if trimmed.contains("://") {
+        trimmed.to_string()
+    } else {
+        format!("https://{}", trimmed)
+    };
+    let url = reqwest::Url::parse(&with_scheme).map_err(|_| ServerUrlError::Invalid)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(ServerUrlError::UnsupportedScheme);
+    }
+    if url.host_str().is_none_or(str::is_empty)
+        || url.query().is_some()
+        || url.fragment().is_some()
+        || !url.username().is_empty()
+    {
+        return Err(ServerUrlError::Invalid);
+    }
+
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// Credentials sent to this server travel unencrypted
+pub fn is_unencrypted_server(url: &str) -> bool {
+    url.trim().to_lowercase().starts_with("http://")
+}
+
+/// Sent with every login and sync so the server can refuse clients it can't serve
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClientVersion {
+    pub client_version: String,
+    pub protocol_version: u32,
+}
+
+impl ClientVersion {
+    pub fn current() -> Self {
+        ClientVersion {
+            client_version: FURTHERANCE_VERSION.to_string(),
+            protocol_version: SYNC_PROTOCOL_VERSION,
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct LoginRequest {
     pub email: String,
     pub encryption_key: String,
     pub device_id: String,
+    #[serde(flatten)]
+    pub version: ClientVersion,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct LoginResponse {
     pub access_token: String,
     pub refresh_token: String,
+    /// How far the server's clock is ahead of this one
+    #[serde(skip)]
+    pub clock_skew: Option<TimeDelta>,
 }
 
 #[derive(Serialize)]
@@ -72,21 +184,71 @@ pub async fn login(
             email,
             encryption_key,
             device_id,
+            version: ClientVersion::current(),
         })
         .send()
         .await
         .map_err(|e| ApiError::Network(Arc::new(e)))?;
 
-    if response.status().is_success() {
-        response
-            .json()
-            .await
-            .map_err(|e| ApiError::Network(Arc::new(e)))
+    let clock_skew = server_time(response.headers()).map(|time| measure_skew(time, Utc::now()));
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| ApiError::Network(Arc::new(e)))?;
+
+    if status.is_success() {
+        parse_response(&body).map(|login_response| LoginResponse {
+            clock_skew,
+            ..login_response
+        })
+    } else if let Some(error) = incompatible_version_error(status, &body) {
+        Err(error)
     } else {
         Err(ApiError::Auth("Invalid credentials".into()))
     }
 }
 
+/// The server's clock, from the Date header it sends with every response
+pub fn server_time(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    headers
+        .get(reqwest::header::DATE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_http_date)
+}
+
+/// Parse a successful response. Valid JSON in a shape this client doesn't know
+/// means the server moved on to a newer schema.
+pub fn parse_response<T: DeserializeOwned>(body: &str) -> Result<T, ApiError> {
+    serde_json::from_str(body).map_err(|e| {
+        if serde_json::from_str::<serde_json::Value>(body).is_ok_and(|value| value.is_object()) {
+            ApiError::IncompatibleVersion(e.to_string())
+        } else {
+            ApiError::Server(format!("Invalid response from server: {}", e))
+        }
+    })
+}
+
+/// The version error in a failed response, if the server rejected this client's version
+pub fn incompatible_version_error(status: StatusCode, body: &str) -> Option<ApiError> {
+    let error = serde_json::from_str::<serde_json::Value>(body).ok();
+    let message = error
+        .as_ref()
+        .and_then(|error| error.get("message"))
+        .and_then(|message| message.as_str())
+        .unwrap_or("Incompatible client version")
+        .to_string();
+
+    let is_version_error = status == StatusCode::UPGRADE_REQUIRED
+        || error
+            .as_ref()
+            .and_then(|error| error.get("error"))
+            .and_then(|error_type| error_type.as_str())
+            .is_some_and(|error_type| INCOMPATIBLE_VERSION_ERRORS.contains(&error_type));
+
+    is_version_error.then_some(ApiError::IncompatibleVersion(message))
+}
+
 pub async fn refresh_auth_token(refresh_token: String, server: &str) -> Result<String, ApiError> {
     let client = Client::new();
     let device_id = match generate_device_id() {
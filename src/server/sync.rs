@@ -18,13 +18,18 @@ use std::sync::Arc;
 
 use crate::{
     database::db_update_access_token,
+    helpers::clock_skew::measure_skew,
     models::{
         fur_shortcut::EncryptedShortcut, fur_task::EncryptedTask, fur_todo::EncryptedTodo,
         fur_user::FurUser,
     },
-    server::login::{ApiError, refresh_auth_token},
+    server::login::{
+        ApiError, ClientVersion, incompatible_version_error, parse_response, refresh_auth_token,
+        server_time,
+    },
 };
 
+use chrono::{DateTime, TimeDelta, Utc};
 use reqwest::{self, Client};
 use serde::{Deserialize, Serialize};
 
@@ -37,6 +42,8 @@ pub struct SyncRequest {
     tasks: Vec<EncryptedTask>,
     shortcuts: Vec<EncryptedShortcut>,
     todos: Vec<EncryptedTodo>,
+    #[serde(flatten)]
+    version: ClientVersion,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -48,6 +55,9 @@ pub struct SyncResponse {
     pub orphaned_tasks: Vec<String>,
     pub orphaned_shortcuts: Vec<String>,
     pub orphaned_todos: Vec<String>,
+    /// How far the server's clock is ahead of this one
+    #[serde(skip)]
+    pub clock_skew: Option<TimeDelta>,
 }
 
 pub async fn sync_with_server(
@@ -69,6 +79,7 @@ pub async fn sync_with_server(
         tasks,
         shortcuts,
         todos,
+        version: ClientVersion::current(),
     };
 
     let mut response = client
@@ -97,13 +108,28 @@ pub async fn sync_with_server(
             .map_err(|e| ApiError::Network(Arc::new(e)))?;
     }
 
-    if response.status().is_success() {
-        response
-            .json()
-            .await
-            .map_err(|e| ApiError::Network(Arc::new(e)))
+    let received_at = Utc::now();
+    let header_time = server_time(response.headers());
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| ApiError::Network(Arc::new(e)))?;
+
+    if status.is_success() {
+        parse_response::<SyncResponse>(&body).map(|sync_response| {
+            // Servers that don't send a Date header still report their time
+            let reported_time =
+                header_time.or_else(|| DateTime::from_timestamp(sync_response.server_timestamp, 0));
+            SyncResponse {
+                clock_skew: reported_time.map(|time| measure_skew(time, received_at)),
+                ..sync_response
+            }
+        })
+    } else if let Some(error) = incompatible_version_error(status, &body) {
+        Err(error)
     } else {
-        if let Ok(error) = response.json::<serde_json::Value>().await {
+        if let Ok(error) = serde_json::from_str::<serde_json::Value>(&body) {
             if let Some(error_type) = error.get("error").and_then(|e| e.as_str()) {
                 if error_type == "inactive_subscription" {
                     return Err(ApiError::InactiveSubscription(
@@ -119,3 +145,19 @@ pub async fn sync_with_server(
         Err(ApiError::Server("Sync failed".into()))
     }
 }
+
+/// Roughly how many bytes the records take up in a sync request or response
+pub fn approximate_payload_bytes(
+    tasks: &[EncryptedTask],
+    shortcuts: &[EncryptedShortcut],
+    todos: &[EncryptedTodo],
+) -> usize {
+    [
+        serde_json::to_vec(tasks),
+        serde_json::to_vec(shortcuts),
+        serde_json::to_vec(todos),
+    ]
+    .into_iter()
+    .map(|json| json.map_or(0, |bytes| bytes.len()))
+    .sum()
+}
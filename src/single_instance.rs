@@ -0,0 +1,168 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    fs::{self, File, TryLockError},
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    path::Path,
+    sync::{Mutex, OnceLock},
+    thread,
+    time::Duration,
+};
+
+use iced::advanced::subscription;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    constants::{RAISE_CONNECT_ATTEMPTS, RAISE_CONNECT_RETRY_MILLIS},
+    update::messages::Message,
+};
+
+const RAISE_REQUEST: &str = "raise";
+const MAX_REQUEST_BYTES: u64 = 64;
+
+pub enum InstanceLock {
+    /// This is the only instance. The lock is released when the file is dropped.
+    Acquired(File),
+    /// Another instance already holds the lock
+    HeldElsewhere,
+}
+
+/// Take the instance lock and start listening for raise requests from later launches
+pub fn acquire_instance_lock(lock_path: &Path, port_path: &Path) -> io::Result<InstanceLock> {
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(lock_path)?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return Ok(InstanceLock::HeldElsewhere),
+        Err(TryLockError::Error(e)) => return Err(e),
+    }
+
+    listen_for_raise_requests(port_path)?;
+    Ok(InstanceLock::Acquired(file))
+}
+
+fn listen_for_raise_requests(port_path: &Path) -> io::Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+    fs::write(port_path, listener.local_addr()?.port().to_string())?;
+
+    thread::Builder::new()
+        .name("instance-listener".to_string())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                match stream.and_then(read_request) {
+                    Ok(request) if request == RAISE_REQUEST => {
+                        if let Err(e) = raise_channel().sender.send(()) {
+                            eprintln!("Failed to forward raise request: {e}");
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Instance listener connection failed: {e}"),
+                }
+            }
+        })?;
+    Ok(())
+}
+
+fn read_request(stream: TcpStream) -> io::Result<String> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    let mut request = String::new();
+    BufReader::new(stream)
+        .take(MAX_REQUEST_BYTES)
+        .read_line(&mut request)?;
+    Ok(request.trim().to_string())
+}
+
+/// Ask the running instance to bring its window forward. It may have taken the
+/// lock but not opened its listener yet, so connecting is retried for a moment.
+pub fn raise_running_instance(port_path: &Path) -> io::Result<()> {
+    let mut attempt = 1;
+    loop {
+        match send_raise_request(port_path) {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= RAISE_CONNECT_ATTEMPTS => return Err(e),
+            Err(_) => {
+                attempt += 1;
+                thread::sleep(Duration::from_millis(RAISE_CONNECT_RETRY_MILLIS));
+            }
+        }
+    }
+}
+
+fn send_raise_request(port_path: &Path) -> io::Result<()> {
+    let port: u16 = fs::read_to_string(port_path)?
+        .trim()
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut stream = TcpStream::connect_timeout(
+        &SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+        Duration::from_secs(1),
+    )?;
+    writeln!(stream, "{RAISE_REQUEST}")?;
+    stream.flush()
+}
+
+struct RaiseChannel {
+    sender: UnboundedSender<()>,
+    receiver: Mutex<Option<UnboundedReceiver<()>>>,
+}
+
+static RAISE_CHANNEL: OnceLock<RaiseChannel> = OnceLock::new();
+
+fn raise_channel() -> &'static RaiseChannel {
+    RAISE_CHANNEL.get_or_init(|| {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        RaiseChannel {
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+        }
+    })
+}
+
+pub struct RaiseRequestSubscription;
+
+impl subscription::Recipe for RaiseRequestSubscription {
+    type Output = Message;
+
+    fn hash(&self, state: &mut rustc_hash::FxHasher) {
+        use std::hash::Hash;
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: subscription::EventStream,
+    ) -> futures_core::stream::BoxStream<'static, Self::Output> {
+        let receiver = raise_channel()
+            .receiver
+            .lock()
+            .ok()
+            .and_then(|mut receiver| receiver.take());
+
+        Box::pin(async_stream::stream! {
+            if let Some(mut receiver) = receiver {
+                while receiver.recv().await.is_some() {
+                    yield Message::RaiseWindowRequested;
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,91 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    fs::{self, remove_file},
+    io::Result,
+    path::PathBuf,
+};
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+use crate::{models::fur_settings::get_data_path, update::msg_helper_functions::split_task_input};
+
+#[derive(Serialize)]
+struct FurStatus {
+    running: bool,
+    paused: bool,
+    task: String,
+    project: String,
+    start_time: Option<String>,
+    elapsed_seconds: i64,
+}
+
+/// `paused_at` is when the running timer was paused, if it is, so the elapsed
+/// time stops counting there
+pub fn write_status_file(
+    timer_is_running: bool,
+    task_input: &str,
+    start_time: DateTime<Local>,
+    paused_at: Option<DateTime<Local>>,
+) -> Result<()> {
+    let status = if timer_is_running {
+        let (name, project, _, _) = split_task_input(task_input);
+        FurStatus {
+            running: true,
+            paused: paused_at.is_some(),
+            task: name,
+            project,
+            start_time: Some(start_time.to_rfc3339()),
+            elapsed_seconds: (paused_at.unwrap_or_else(Local::now) - start_time)
+                .num_seconds()
+                .max(0),
+        }
+    } else {
+        FurStatus {
+            running: false,
+            paused: false,
+            task: String::new(),
+            project: String::new(),
+            start_time: None,
+            elapsed_seconds: 0,
+        }
+    };
+
+    let json = serde_json::to_string_pretty(&status)?;
+
+    // Write to a temporary file first so readers never see a partial file
+    let path = get_status_file_path();
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json)?;
+    fs::rename(temp_path, path)
+}
+
+pub fn delete_status_file() {
+    let path = get_status_file_path();
+    if path.exists() {
+        if let Err(e) = remove_file(path) {
+            eprintln!("Error deleting status file: {e}");
+        }
+    }
+}
+
+pub fn get_status_file_path() -> PathBuf {
+    let mut path = get_data_path();
+    path.extend(&["status.json"]);
+    path
+}
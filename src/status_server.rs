@@ -0,0 +1,284 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+const MAX_REQUEST_LINE_BYTES: u64 = 8 * 1024;
+const MAX_HEADERS: usize = 64;
+
+/// What the status server reports. The app refreshes this as the timer ticks and the
+/// history changes, so the server never touches the database or the iced state.
+#[derive(Debug, Clone, Default)]
+pub struct StatusSnapshot {
+    pub task: String,
+    pub project: String,
+    pub tags: Vec<String>,
+    pub rate: f32,
+    /// Set while the timer is running
+    pub start_time: Option<DateTime<Local>>,
+    /// Set while the running timer is paused, where its elapsed time stops
+    pub paused_at: Option<DateTime<Local>>,
+    pub pomodoro: PomodoroSnapshot,
+    /// Recorded today, not counting the running timer
+    pub today_seconds: i64,
+    pub today_earnings: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PomodoroSnapshot {
+    pub enabled: bool,
+    pub on_break: bool,
+    pub end_time: Option<DateTime<Local>>,
+}
+
+#[derive(Serialize)]
+struct StatusResponse<'a> {
+    running: bool,
+    paused: bool,
+    task: &'a str,
+    project: &'a str,
+    tags: &'a [String],
+    elapsed_seconds: i64,
+    pomodoro: PomodoroResponse,
+}
+
+#[derive(Serialize)]
+struct PomodoroResponse {
+    enabled: bool,
+    on_break: bool,
+    remaining_seconds: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct TodayResponse {
+    date: String,
+    total_seconds: i64,
+    earnings: f32,
+}
+
+/// A read-only HTTP server on localhost for status bars. Dropping it stops the server.
+pub struct StatusServer {
+    port: u16,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StatusServer {
+    pub fn start(
+        port: u16,
+        api_key: String,
+        snapshot: Arc<RwLock<StatusSnapshot>>,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = Arc::clone(&shutdown);
+
+        let handle = thread::Builder::new()
+            .name("status-server".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => {
+                            if let Err(e) = handle_connection(stream, &api_key, &snapshot) {
+                                eprintln!("Status server request failed: {e}");
+                            }
+                        }
+                        Err(e) => eprintln!("Status server connection failed: {e}"),
+                    }
+                }
+            })?;
+
+        Ok(StatusServer {
+            port,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Drop for StatusServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the listener so it sees the shutdown flag and releases the port
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    api_key: &str,
+    snapshot: &RwLock<StatusSnapshot>,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(2)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    (&mut reader)
+        .take(MAX_REQUEST_LINE_BYTES)
+        .read_line(&mut request_line)?;
+
+    let mut authorization = None;
+    for _ in 0..MAX_HEADERS {
+        let mut header = String::new();
+        let bytes_read = (&mut reader)
+            .take(MAX_REQUEST_LINE_BYTES)
+            .read_line(&mut header)?;
+        if bytes_read == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("authorization") {
+                authorization = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    let snapshot = match snapshot.read() {
+        Ok(snapshot) => snapshot.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    let (status, body) = route(
+        method,
+        path,
+        authorization.as_deref(),
+        api_key,
+        &snapshot,
+        Local::now(),
+    );
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Build the status line and JSON body for a request
+pub fn route(
+    method: &str,
+    path: &str,
+    authorization: Option<&str>,
+    api_key: &str,
+    snapshot: &StatusSnapshot,
+    now: DateTime<Local>,
+) -> (&'static str, String) {
+    let authorized = authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|key| !api_key.is_empty() && key.trim() == api_key);
+    if !authorized {
+        return ("401 Unauthorized", error_body("unauthorized"));
+    }
+    if method != "GET" {
+        return ("405 Method Not Allowed", error_body("method not allowed"));
+    }
+
+    let path = path.split('?').next().unwrap_or("");
+    let body = match path {
+        "/status" => serde_json::to_string(&status_response(snapshot, now)),
+        "/today" => serde_json::to_string(&today_response(snapshot, now)),
+        _ => return ("404 Not Found", error_body("not found")),
+    };
+
+    match body {
+        Ok(body) => ("200 OK", body),
+        Err(e) => {
+            eprintln!("Failed to serialize status: {e}");
+            ("500 Internal Server Error", error_body("internal error"))
+        }
+    }
+}
+
+fn status_response(snapshot: &StatusSnapshot, now: DateTime<Local>) -> StatusResponse<'_> {
+    let now = timer_now(snapshot, now);
+    StatusResponse {
+        running: snapshot.start_time.is_some(),
+        paused: snapshot.paused_at.is_some(),
+        task: &snapshot.task,
+        project: &snapshot.project,
+        tags: &snapshot.tags,
+        elapsed_seconds: elapsed_seconds(snapshot, now),
+        pomodoro: PomodoroResponse {
+            enabled: snapshot.pomodoro.enabled,
+            on_break: snapshot.pomodoro.on_break,
+            remaining_seconds: snapshot
+                .pomodoro
+                .end_time
+                .map(|end_time| (end_time - now).num_seconds().max(0)),
+        },
+    }
+}
+
+fn today_response(snapshot: &StatusSnapshot, now: DateTime<Local>) -> TodayResponse {
+    // Count the running timer only for the part of it that falls on today
+    let timer_now = timer_now(snapshot, now);
+    let running_seconds = snapshot.start_time.map_or(0, |start_time| {
+        let midnight = now
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+            .unwrap_or(start_time);
+        (timer_now - start_time.max(midnight)).num_seconds().max(0)
+    });
+
+    TodayResponse {
+        date: now.format("%Y-%m-%d").to_string(),
+        total_seconds: snapshot.today_seconds + running_seconds,
+        earnings: snapshot.today_earnings + (running_seconds as f32 / 3600.0) * snapshot.rate,
+    }
+}
+
+/// Where the running timer has counted up to, which stops while it's paused
+fn timer_now(snapshot: &StatusSnapshot, now: DateTime<Local>) -> DateTime<Local> {
+    snapshot.paused_at.unwrap_or(now)
+}
+
+fn elapsed_seconds(snapshot: &StatusSnapshot, now: DateTime<Local>) -> i64 {
+    snapshot
+        .start_time
+        .map_or(0, |start_time| (now - start_time).num_seconds().max(0))
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
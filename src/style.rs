@@ -24,7 +24,8 @@ use iced_aw::style::number_input;
 use palette::{Lighten, Srgb};
 
 use crate::constants::FURTHERANCE_PURPLE;
-use crate::helpers::color_utils::{ToIcedColor, ToSrgb};
+use crate::helpers::color_utils::{ToIcedColor, ToSrgb, is_dark_color};
+use crate::helpers::daily_target::TargetProgress;
 
 pub struct FurPalette;
 
@@ -42,13 +43,21 @@ impl FurPalette {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FurTheme {
     Light,
     Dark,
 }
 
 impl FurTheme {
+    /// The system's current light/dark mode, falling back to light
+    pub fn detect() -> Self {
+        match dark_light::detect() {
+            Ok(dark_light::Mode::Dark) => FurTheme::Dark,
+            Ok(dark_light::Mode::Light | dark_light::Mode::Unspecified) | Err(_) => FurTheme::Light,
+        }
+    }
+
     pub fn to_theme(&self) -> Theme {
         match self {
             FurTheme::Light => Theme::Custom(Arc::new(Custom::new(
@@ -119,6 +128,35 @@ pub fn task_row(theme: &Theme) -> container::Style {
     }
 }
 
+pub fn task_row_focused(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        background: Some(palette.background.weak.color.into()),
+        border: Border {
+            color: palette.primary.base.color,
+            width: 2.0,
+            radius: 12.0.into(),
+        },
+        ..Default::default()
+    }
+}
+
+pub fn keyboard_focus_ring(theme: &Theme, focused: bool) -> container::Style {
+    container::Style {
+        border: Border {
+            color: if focused {
+                theme.extended_palette().primary.base.color
+            } else {
+                Color::TRANSPARENT
+            },
+            width: 3.0,
+            radius: 18.0.into(),
+        },
+        ..Default::default()
+    }
+}
+
 pub fn group_edit_task_row(theme: &Theme) -> container::Style {
     let palette = theme.extended_palette();
 
@@ -146,6 +184,23 @@ pub fn group_count_circle(theme: &Theme) -> container::Style {
     }
 }
 
+pub fn project_badge(_theme: &Theme, color: Srgb) -> container::Style {
+    container::Style {
+        background: Some(color.to_iced_color().into()),
+        text_color: Some(if is_dark_color(color) {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        }),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: 8.0.into(),
+        },
+        ..Default::default()
+    }
+}
+
 pub fn primary_button_style(theme: &Theme, status: button::Status) -> button::Style {
     let palette = theme.extended_palette();
 
@@ -200,6 +255,22 @@ pub fn green_text(_theme: &Theme) -> text::Style {
     }
 }
 
+pub fn daily_target_indicator(theme: &Theme, progress: TargetProgress) -> text::Style {
+    let palette = theme.extended_palette();
+    let color = match progress {
+        TargetProgress::Met => palette.success.base.color,
+        TargetProgress::Partial => palette.primary.base.color,
+        TargetProgress::Empty => palette.background.strong.color,
+    };
+    text::Style { color: Some(color) }
+}
+
+pub fn search_highlight(theme: &Theme) -> text::Style {
+    text::Style {
+        color: Some(theme.extended_palette().primary.base.color),
+    }
+}
+
 pub fn shortcut_button_style(
     _theme: &Theme,
     status: button::Status,
@@ -0,0 +1,87 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod auto_stop_tests {
+    use chrono::{DateTime, Local, NaiveTime, TimeZone};
+
+    use crate::{
+        helpers::auto_stop::{
+            auto_stop_due, auto_stop_time, format_auto_stop_time, next_auto_stop,
+            parse_auto_stop_time,
+        },
+        models::fur_settings::FurSettings,
+    };
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 3, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn half_past_six() -> NaiveTime {
+        NaiveTime::from_hms_opt(18, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_auto_stop_time_needs_enabling() {
+        let mut settings = FurSettings::default();
+        assert_eq!(auto_stop_time(&settings), None);
+
+        settings.auto_stop_enabled = true;
+        assert_eq!(auto_stop_time(&settings), Some(half_past_six()));
+
+        settings.auto_stop_time = "not a time".to_string();
+        assert_eq!(auto_stop_time(&settings), None);
+    }
+
+    #[test]
+    fn test_time_round_trip() {
+        assert_eq!(format_auto_stop_time(half_past_six()), "18:30");
+        assert_eq!(parse_auto_stop_time("18:30"), Some(half_past_six()));
+    }
+
+    #[test]
+    fn test_next_auto_stop_same_day() {
+        assert_eq!(
+            next_auto_stop(at(10, 9, 0), half_past_six()),
+            Some(at(10, 18, 30))
+        );
+    }
+
+    #[test]
+    fn test_next_auto_stop_after_boundary_is_next_day() {
+        assert_eq!(
+            next_auto_stop(at(10, 20, 0), half_past_six()),
+            Some(at(11, 18, 30))
+        );
+    }
+
+    #[test]
+    fn test_auto_stop_due() {
+        let start = at(10, 9, 0);
+        assert_eq!(auto_stop_due(start, at(10, 18, 0), half_past_six()), None);
+        assert_eq!(
+            auto_stop_due(start, at(10, 18, 30), half_past_six()),
+            Some(at(10, 18, 30))
+        );
+        // Waking up the next morning still ends the task at the boundary
+        assert_eq!(
+            auto_stop_due(start, at(11, 8, 0), half_past_six()),
+            Some(at(10, 18, 30))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod autosave_tests {
+    use crate::autosave::{AutosaveError, parse_autosave};
+
+    const BODY: &str = "Write report\n2025-03-10T09:00:00+01:00\n2025-03-10T10:30:00+01:00\nwriting\nClient\n50\n\ntrue\n";
+
+    fn with_header(body: &str) -> String {
+        format!(
+            "furtherance-autosave v2\n{}\n{}",
+            blake3::hash(body.as_bytes()).to_hex(),
+            body
+        )
+    }
+
+    fn is_corrupt(bytes: &[u8]) -> bool {
+        matches!(parse_autosave(bytes), Err(AutosaveError::Corrupt(_)))
+    }
+
+    #[test]
+    fn test_valid_autosave() {
+        let task = parse_autosave(with_header(BODY).as_bytes()).unwrap();
+        assert_eq!(task.name, "Write report");
+        assert_eq!(task.project, "Client");
+        assert_eq!(task.rate, 50.0);
+        assert!(task.is_billable);
+        assert_eq!(task.total_time_in_seconds(), 90 * 60);
+    }
+
+    #[test]
+    fn test_autosave_notes_round_trip() {
+        let contents = format!("{}\nFixed login\\nUpdated docs \\\\ misc", BODY);
+        let task = parse_autosave(contents.as_bytes()).unwrap();
+        assert_eq!(task.notes, "Fixed login\nUpdated docs \\ misc");
+    }
+
+    #[test]
+    fn test_autosave_without_notes() {
+        let task = parse_autosave(BODY.as_bytes()).unwrap();
+        assert!(task.notes.is_empty());
+    }
+
+    #[test]
+    fn test_legacy_autosave_without_header() {
+        assert!(parse_autosave(BODY.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_truncated_autosave() {
+        let full = with_header(BODY);
+        assert!(is_corrupt(&full.as_bytes()[..full.len() - 10]));
+        assert!(is_corrupt(&BODY.as_bytes()[..30]));
+        assert!(is_corrupt(b""));
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let tampered = with_header(BODY).replace("Client", "Clyent");
+        assert!(is_corrupt(tampered.as_bytes()));
+    }
+
+    #[test]
+    fn test_garbled_bytes() {
+        assert!(is_corrupt(&[0xff, 0xfe, 0x00, 0x12, 0x80]));
+        assert!(is_corrupt(b"furtherance-autosave v2"));
+        assert!(is_corrupt(b"\n\n\n\n\n\n\n"));
+    }
+
+    #[test]
+    fn test_stop_before_start_is_rejected() {
+        let body = "Task\n2025-03-10T10:30:00+01:00\n2025-03-10T09:00:00+01:00\n\n\n0\n\nfalse\n";
+        assert!(is_corrupt(with_header(body).as_bytes()));
+    }
+}
@@ -0,0 +1,123 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod bulk_edit_tests {
+    use crate::{
+        database::RenameCounts,
+        models::{find_replace::FindReplace, group_to_edit::GroupToEdit},
+        update::msg_helper_functions::{add_or_remove_tag, replace_tag},
+        view_enums::FurRenameField,
+    };
+
+    #[test]
+    fn test_add_tag_keeps_sorted_unique_tags() {
+        assert_eq!(
+            add_or_remove_tag("work #meeting", "#Admin", false),
+            "admin #meeting #work"
+        );
+        assert_eq!(
+            add_or_remove_tag("meeting #work", "work", false),
+            "meeting #work"
+        );
+        assert_eq!(add_or_remove_tag("", "work", false), "work");
+    }
+
+    #[test]
+    fn test_remove_tag() {
+        assert_eq!(add_or_remove_tag("meeting #work", "#work", true), "meeting");
+        assert_eq!(add_or_remove_tag("meeting", "work", true), "meeting");
+    }
+
+    #[test]
+    fn test_empty_tag_leaves_tags_untouched() {
+        assert_eq!(
+            add_or_remove_tag("meeting #work", " # ", false),
+            "meeting #work"
+        );
+    }
+
+    #[test]
+    fn test_replace_tag_matches_whole_tags_only() {
+        assert_eq!(
+            replace_tag("meeting #work", "#Work", "job"),
+            Some("job #meeting".to_string())
+        );
+        assert_eq!(replace_tag("meeting #workshop", "work", "job"), None);
+        assert_eq!(
+            replace_tag("job #work", "work", "job"),
+            Some("job".to_string())
+        );
+        assert_eq!(replace_tag("", "work", "job"), None);
+    }
+
+    #[test]
+    fn test_find_replace_needs_preview_before_apply() {
+        let mut find_replace = FindReplace::new();
+        find_replace.set_field(FurRenameField::Tag);
+        find_replace.set_find("#work".to_string());
+        find_replace.set_replace("job".to_string());
+        assert_eq!(find_replace.find_value(), "work");
+        assert!(find_replace.can_preview());
+        assert!(!find_replace.can_apply());
+
+        find_replace.preview = Some(RenameCounts {
+            tasks: 2,
+            todos: 0,
+            shortcuts: 1,
+        });
+        assert!(find_replace.can_apply());
+
+        find_replace.set_replace("gig".to_string());
+        assert!(!find_replace.can_apply());
+    }
+
+    fn group_with_new_rate(new_rate: &str) -> GroupToEdit {
+        GroupToEdit {
+            uid: "group".to_string(),
+            name: "Design".to_string(),
+            new_name: "Design".to_string(),
+            tags: String::new(),
+            new_tags: String::new(),
+            project: "Website".to_string(),
+            new_project: "Website".to_string(),
+            rate: 45.0,
+            new_rate: new_rate.to_string(),
+            tasks: Vec::new(),
+            is_in_edit_mode: true,
+            invalid_input_error_message: String::new(),
+            update_past_rates: false,
+        }
+    }
+
+    #[test]
+    fn test_group_rate_change_defaults_to_future_tasks_only() {
+        let group = group_with_new_rate("60.00");
+        assert!(group.rate_is_changed());
+        assert!(group.is_changed());
+        assert_eq!(group.rate_to_save(), 45.0);
+    }
+
+    #[test]
+    fn test_group_rate_change_can_update_past_tasks() {
+        let mut group = group_with_new_rate(" 60.00 ");
+        group.update_past_rates = true;
+        assert_eq!(group.rate_to_save(), 60.0);
+
+        let unchanged = group_with_new_rate("45.00");
+        assert!(!unchanged.rate_is_changed());
+    }
+}
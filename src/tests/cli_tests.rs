@@ -0,0 +1,88 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod cli_tests {
+    use std::path::PathBuf;
+
+    use chrono::NaiveDate;
+
+    use crate::cli::{CliCommand, CliExportFormat, parse_args, wants_new_instance};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_no_subcommand_starts_the_gui() {
+        assert!(parse_args(&[]).is_none());
+        assert!(parse_args(&args("-psn_0_12345")).is_none());
+        assert!(parse_args(&args("--new-instance")).is_none());
+    }
+
+    #[test]
+    fn test_new_instance_flag() {
+        assert!(wants_new_instance(&args("--new-instance")));
+        assert!(wants_new_instance(&args("-psn_0_12345 --new-instance")));
+        assert!(!wants_new_instance(&args("-psn_0_12345")));
+        assert!(!wants_new_instance(&[]));
+    }
+
+    #[test]
+    fn test_export_with_date_range() {
+        assert_eq!(
+            parse_args(&args(
+                "export --format json --output out.json --from 2026-01-01 --to 2026-01-31"
+            )),
+            Some(Ok(CliCommand::Export {
+                format: CliExportFormat::Json,
+                output: PathBuf::from("out.json"),
+                from: NaiveDate::from_ymd_opt(2026, 1, 1),
+                to: NaiveDate::from_ymd_opt(2026, 1, 31),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_backup() {
+        assert_eq!(
+            parse_args(&args("backup -o furtherance.db")),
+            Some(Ok(CliCommand::Backup {
+                output: PathBuf::from("furtherance.db"),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bad_arguments_are_errors() {
+        for line in [
+            "export --format xml --output out.xml",
+            "export --format csv",
+            "export --output out.csv",
+            "export --format csv --output out.csv --from 01/31/2026",
+            "export --format csv --output out.csv --from 2026-02-01 --to 2026-01-01",
+            "export --format csv --output",
+            "backup",
+            "backup --output out.db --verbose",
+        ] {
+            assert!(
+                matches!(parse_args(&args(line)), Some(Err(_))),
+                "{} should fail",
+                line
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod clock_skew_tests {
+    use chrono::{DateTime, FixedOffset, TimeDelta, TimeZone, Utc};
+
+    use crate::helpers::clock_skew::{
+        corrected_timestamp, is_significant_skew, measure_skew, parse_http_date, skew_minutes,
+    };
+
+    fn utc(timestamp: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(timestamp, 0).unwrap()
+    }
+
+    #[test]
+    fn test_server_ahead_is_positive_skew() {
+        let local = utc(1_700_000_000);
+        let skew = measure_skew(local + TimeDelta::minutes(20), local);
+
+        assert_eq!(skew, TimeDelta::minutes(20));
+        assert!(is_significant_skew(skew));
+        assert_eq!(skew_minutes(skew), 20);
+    }
+
+    #[test]
+    fn test_server_behind_is_negative_skew() {
+        let local = utc(1_700_000_000);
+        let skew = measure_skew(local - TimeDelta::minutes(20), local);
+
+        assert_eq!(skew, TimeDelta::minutes(-20));
+        assert!(is_significant_skew(skew));
+        assert_eq!(skew_minutes(skew), 20);
+        assert_eq!(
+            corrected_timestamp(1_700_000_000, skew),
+            1_700_000_000 - 1200
+        );
+    }
+
+    #[test]
+    fn test_small_skew_is_ignored() {
+        assert!(!is_significant_skew(TimeDelta::seconds(120)));
+        assert!(!is_significant_skew(TimeDelta::seconds(-120)));
+        assert!(is_significant_skew(TimeDelta::seconds(121)));
+        assert!(is_significant_skew(TimeDelta::seconds(-121)));
+    }
+
+    #[test]
+    fn test_skew_is_unaffected_by_dst_change() {
+        // Europe's spring change at 2025-03-30 01:00 UTC: the same instant
+        // reads 02:00 before the change and 03:00 after it
+        let before_change = FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2025, 3, 30, 2, 0, 0)
+            .unwrap();
+        let after_change = FixedOffset::east_opt(7200)
+            .unwrap()
+            .with_ymd_and_hms(2025, 3, 30, 3, 0, 0)
+            .unwrap();
+        let server_time = utc(before_change.timestamp() + 300);
+
+        let skew_before = measure_skew(server_time, before_change.with_timezone(&Utc));
+        let skew_after = measure_skew(server_time, after_change.with_timezone(&Utc));
+
+        assert_eq!(skew_before, TimeDelta::minutes(5));
+        assert_eq!(skew_before, skew_after);
+    }
+
+    #[test]
+    fn test_corrected_timestamp_applies_offset() {
+        assert_eq!(
+            corrected_timestamp(1_700_000_000, TimeDelta::minutes(20)),
+            1_700_001_200
+        );
+        assert_eq!(
+            corrected_timestamp(1_700_000_000, TimeDelta::zero()),
+            1_700_000_000
+        );
+        assert_eq!(
+            corrected_timestamp(i64::MAX, TimeDelta::seconds(1)),
+            i64::MAX
+        );
+    }
+
+    #[test]
+    fn test_minutes_are_rounded() {
+        assert_eq!(skew_minutes(TimeDelta::seconds(149)), 2);
+        assert_eq!(skew_minutes(TimeDelta::seconds(150)), 3);
+        assert_eq!(skew_minutes(TimeDelta::seconds(-150)), 3);
+    }
+
+    #[test]
+    fn test_http_date_is_parsed() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(Utc.with_ymd_and_hms(1994, 11, 6, 8, 49, 37).unwrap())
+        );
+        assert_eq!(parse_http_date("not a date"), None);
+    }
+}
@@ -0,0 +1,157 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+#[cfg(test)]
+mod csv_import_tests {
+    use chrono::{Local, TimeZone};
+    use csv::StringRecord;
+
+    use crate::{
+        localization::Localization,
+        update::msg_helper_functions::{parse_csv_datetime, parse_csv_task, read_csv},
+    };
+
+    fn record(fields: &[&str]) -> StringRecord {
+        StringRecord::from(fields.to_vec())
+    }
+
+    #[test]
+    fn test_parse_csv_datetime_formats() {
+        let expected = Local.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap();
+        assert_eq!(parse_csv_datetime(&expected.to_rfc3339()), Some(expected));
+        assert_eq!(parse_csv_datetime("2025-03-14 09:30:00"), Some(expected));
+        assert_eq!(parse_csv_datetime("2025-03-14T09:30:00"), Some(expected));
+        assert_eq!(parse_csv_datetime("2025-03-14 09:30"), Some(expected));
+        assert_eq!(parse_csv_datetime("2025-03-14 9:30 AM"), Some(expected));
+        assert_eq!(parse_csv_datetime("2025-03-14 9:30:00am"), Some(expected));
+        assert_eq!(parse_csv_datetime("14/03/2025"), None);
+        assert_eq!(parse_csv_datetime(""), None);
+    }
+
+    #[test]
+    fn test_parse_valid_row() {
+        let localization = Localization::new_with_locale("en-US");
+        let task = parse_csv_task(
+            &record(&[
+                "Write report",
+                "2025-03-14 09:00:00",
+                "2025-03-14 10:30:00",
+                "#writing",
+                "Client",
+                "$25.50",
+                "USD",
+                "01:30:00",
+                "38.25",
+            ]),
+            None,
+            &localization,
+        )
+        .unwrap();
+        assert_eq!(task.name, "Write report");
+        assert_eq!(task.project, "Client");
+        assert_eq!(task.rate, 25.5);
+        assert_eq!(task.total_time_in_seconds(), 5400);
+    }
+
+    #[test]
+    fn test_invalid_rows_are_reported() {
+        let localization = Localization::new_with_locale("en-US");
+        let row = |name: &str, start: &str, stop: &str, rate: &str| {
+            parse_csv_task(
+                &record(&[name, start, stop, "", "", rate, "", "", ""]),
+                None,
+                &localization,
+            )
+            .unwrap_err()
+        };
+
+        assert_eq!(
+            row("", "2025-03-14 09:00", "2025-03-14 10:00", ""),
+            localization.get_message("csv-missing-name", None)
+        );
+        assert_eq!(
+            row("Task", "yesterday", "2025-03-14 10:00", ""),
+            localization.get_message("csv-invalid-start-time", None)
+        );
+        assert_eq!(
+            row("Task", "2025-03-14 09:00", "", ""),
+            localization.get_message("csv-invalid-stop-time", None)
+        );
+        assert_eq!(
+            row("Task", "2025-03-14 10:00", "2025-03-14 09:00", ""),
+            localization.get_message("csv-stop-before-start", None)
+        );
+        assert_eq!(
+            row("Task", "2025-03-14 09:00", "2025-03-14 10:00", "ten"),
+            localization.get_message("csv-invalid-rate", None)
+        );
+        assert_eq!(
+            parse_csv_task(&record(&["Task", "2025-03-14 09:00"]), None, &localization)
+                .unwrap_err(),
+            localization.get_message("csv-wrong-column-count", None)
+        );
+    }
+
+    #[test]
+    fn test_report_export_comment_is_skipped() {
+        let localization = Localization::new_with_locale("en-US");
+        let csv = "# 2025-03-01 - 2025-03-31, Project: clientA\n\
+            Name,Start Time,Stop Time,Tags,Project,Rate,Currency,Total Time,Total Earnings\n\
+            Write report,2025-03-14 09:00:00,2025-03-14 10:30:00,,clientA,0,,01:30:00,0\n";
+        let import = read_csv(csv.as_bytes(), &localization).unwrap();
+        assert_eq!(import.tasks.len(), 1);
+        assert!(import.summary.errors.is_empty());
+    }
+
+    #[test]
+    fn test_task_names_starting_with_hash_are_imported() {
+        let localization = Localization::new_with_locale("en-US");
+        let csv = "# 2025-03-01 - 2025-03-31\n\
+            Name,Start Time,Stop Time,Tags,Project,Rate,Currency,Total Time,Total Earnings\n\
+            #1 priority,2025-03-14 09:00:00,2025-03-14 10:30:00,,clientA,0,,01:30:00,0\n\
+            Review,2025-03-14 11:00:00,2025-03-14 10:00:00,,clientA,0,,01:00:00,0\n";
+        let import = read_csv(csv.as_bytes(), &localization).unwrap();
+        assert_eq!(import.tasks.len(), 1);
+        assert_eq!(import.tasks[0].name, "#1 priority");
+        // Rows are still numbered by their line in the file
+        assert_eq!(import.summary.errors.len(), 1);
+        assert_eq!(import.summary.errors[0].row, 4);
+    }
+
+    #[test]
+    fn test_net_earnings_column_is_ignored() {
+        let localization = Localization::new_with_locale("en-US");
+        let csv = "Name,Start Time,Stop Time,Tags,Project,Rate,Currency,Billable,Total Time,Total Earnings,Net Earnings\n\
+            Write report,2025-03-14 09:00:00,2025-03-14 10:00:00,,Client,40,USD,true,01:00:00,$40.00,$30.00\n";
+        let import = read_csv(csv.as_bytes(), &localization).unwrap();
+        assert!(import.summary.errors.is_empty());
+        assert_eq!(import.tasks.len(), 1);
+        assert_eq!(import.tasks[0].rate, 40.0);
+    }
+
+    #[test]
+    fn test_device_column_is_imported() {
+        let localization = Localization::new_with_locale("en-US");
+        let csv = "Name,Start Time,Stop Time,Tags,Project,Rate,Currency,Billable,Total Time,Total Earnings,Net Earnings,Device\n\
+            Write report,2025-03-14 09:00:00,2025-03-14 10:00:00,,Client,40,USD,true,01:00:00,$40.00,$30.00,Laptop\n\
+            Review,2025-03-14 11:00:00,2025-03-14 12:00:00,,Client,40,USD,true,01:00:00,$40.00,$30.00,\n";
+        let import = read_csv(csv.as_bytes(), &localization).unwrap();
+        assert!(import.summary.errors.is_empty());
+        assert_eq!(import.tasks.len(), 2);
+        assert_eq!(import.tasks[0].device_name, "Laptop");
+        // Without a device, the task keeps this one's, as for any other import
+        assert_ne!(import.tasks[1].device_name, "Laptop");
+    }
+}
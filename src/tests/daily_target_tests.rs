@@ -0,0 +1,127 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod daily_target_tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        helpers::daily_target::{DailyTarget, TargetProgress},
+        models::{fur_days_off::FurDaysOff, fur_report::FurReportData, fur_task::FurTask},
+    };
+
+    const WEEKDAYS: DailyTarget = DailyTarget {
+        minutes: 360,
+        weekends: false,
+    };
+
+    // June 1, 2026 is a Monday
+    fn june(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, day).unwrap()
+    }
+
+    #[test]
+    fn test_progress() {
+        let days_off = FurDaysOff::default();
+        assert_eq!(
+            WEEKDAYS.progress(&june(1), 6 * 3600, &days_off),
+            Some(TargetProgress::Met)
+        );
+        assert_eq!(
+            WEEKDAYS.progress(&june(1), 3600, &days_off),
+            Some(TargetProgress::Partial)
+        );
+        assert_eq!(
+            WEEKDAYS.progress(&june(1), 0, &days_off),
+            Some(TargetProgress::Empty)
+        );
+    }
+
+    #[test]
+    fn test_target_skips_weekends_and_days_off() {
+        let days_off = FurDaysOff::new(BTreeSet::from([june(2)]), false);
+        assert_eq!(WEEKDAYS.progress(&june(6), 3600, &days_off), None);
+        assert_eq!(WEEKDAYS.progress(&june(2), 3600, &days_off), None);
+
+        let every_day = DailyTarget {
+            weekends: true,
+            ..WEEKDAYS
+        };
+        assert_eq!(
+            every_day.progress(&june(6), 3600, &days_off),
+            Some(TargetProgress::Partial)
+        );
+    }
+
+    #[test]
+    fn test_zero_turns_the_target_off() {
+        let off = DailyTarget {
+            minutes: 0,
+            weekends: true,
+        };
+        assert!(!off.is_enabled());
+        assert_eq!(off.progress(&june(1), 3600, &FurDaysOff::default()), None);
+    }
+
+    #[test]
+    fn test_days_met_in_range() {
+        let day_totals = BTreeMap::from([
+            (june(1), 7 * 3600),
+            (june(2), 2 * 3600),
+            (june(3), 6 * 3600),
+            (june(6), 8 * 3600),
+        ]);
+        // Mon 1 to Sun 7, seen on Thursday the 4th: Friday hasn't happened yet
+        assert_eq!(
+            WEEKDAYS.days_met(
+                &day_totals,
+                june(1),
+                june(7),
+                june(4),
+                &FurDaysOff::default()
+            ),
+            (2, 4)
+        );
+    }
+
+    #[test]
+    fn test_report_totals_each_day() {
+        let start = Local.with_ymd_and_hms(2026, 6, 1, 9, 0, 0).unwrap();
+        let task = |offset_hours: i64, minutes: i64| {
+            FurTask::new(
+                "Task".to_string(),
+                start + TimeDelta::hours(offset_hours),
+                start + TimeDelta::hours(offset_hours) + TimeDelta::minutes(minutes),
+                String::new(),
+                String::new(),
+                0.0,
+                String::new(),
+            )
+        };
+        let data = FurReportData::from_tasks(
+            vec![task(0, 90), task(3, 30), task(24, 45)],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        );
+        assert_eq!(
+            data.day_totals,
+            BTreeMap::from([(june(1), 120 * 60), (june(2), 45 * 60)])
+        );
+    }
+}
@@ -0,0 +1,190 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod database_merge_tests {
+    use std::path::PathBuf;
+
+    use rusqlite::{Connection, params};
+
+    use crate::database::{DatabaseMergeSummary, db_merge_database, db_upgrade_legacy};
+
+    /// An up-to-date database file of its own for each test
+    fn database(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "furtherance-merge-{}-{}.db",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        db_upgrade_legacy(&path, |_| {}).unwrap();
+        path
+    }
+
+    fn insert_task(path: &PathBuf, name: &str, uid: &str, is_deleted: bool) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute(
+            "INSERT INTO tasks (task_name, start_time, stop_time, tags, project, rate, currency, uid, is_deleted)
+            VALUES (?1, '2024-05-01T09:00:00+00:00', '2024-05-01T10:00:00+00:00', '', '', 0.0, '', ?2, ?3)",
+            params![name, uid, is_deleted],
+        )
+        .unwrap();
+    }
+
+    fn insert_versioned_task(path: &PathBuf, name: &str, uid: &str, last_updated: i64) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute(
+            "INSERT INTO tasks (task_name, start_time, stop_time, tags, project, rate, currency, uid, last_updated)
+            VALUES (?1, '2024-05-01T09:00:00+00:00', '2024-05-01T10:00:00+00:00', '', '', 0.0, '', ?2, ?3)",
+            params![name, uid, last_updated],
+        )
+        .unwrap();
+    }
+
+    fn insert_todo(path: &PathBuf, name: &str, uid: &str) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute(
+            "INSERT INTO todos (name, project, tags, rate, currency, date, uid)
+            VALUES (?1, '', '', 0.0, '', '2024-05-01T00:00:00+00:00', ?2)",
+            params![name, uid],
+        )
+        .unwrap();
+    }
+
+    fn insert_shortcut(path: &PathBuf, name: &str, uid: &str) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute(
+            "INSERT INTO shortcuts (name, tags, project, rate, currency, color_hex, uid)
+            VALUES (?1, '', '', 0.0, '', '#000000', ?2)",
+            params![name, uid],
+        )
+        .unwrap();
+    }
+
+    fn task_names(path: &PathBuf) -> Vec<String> {
+        let conn = Connection::open(path).unwrap();
+        let mut stmt = conn
+            .prepare("SELECT task_name FROM tasks ORDER BY task_name")
+            .unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_merge_skips_tasks_already_present() {
+        let source = database("skip-source");
+        let target = database("skip-target");
+        insert_task(&source, "Shared", "uid-shared", false);
+        insert_task(&source, "New", "uid-new", false);
+        insert_task(&target, "Shared", "uid-shared", false);
+
+        let summary = db_merge_database(&source, &target, |_| {}).unwrap();
+        assert_eq!(summary.tasks_added, 1);
+        assert_eq!(task_names(&target), vec!["New", "Shared"]);
+
+        // Merging again has nothing left to add
+        let summary = db_merge_database(&source, &target, |_| {}).unwrap();
+        assert_eq!(summary, DatabaseMergeSummary::default());
+    }
+
+    #[test]
+    fn test_merge_ignores_deleted_tasks() {
+        let source = database("deleted-source");
+        let target = database("deleted-target");
+        insert_task(&source, "Kept", "uid-kept", false);
+        insert_task(&source, "Deleted", "uid-deleted", true);
+
+        let summary = db_merge_database(&source, &target, |_| {}).unwrap();
+        assert_eq!(summary.tasks_added, 1);
+        assert_eq!(task_names(&target), vec!["Kept"]);
+    }
+
+    #[test]
+    fn test_database_merge_keeps_the_newest_rows() {
+        let source = database("full-source");
+        let target = database("full-target");
+        insert_versioned_task(&source, "Only in source", "uid-new", 10);
+        insert_versioned_task(&source, "Edited in source", "uid-newer", 20);
+        insert_versioned_task(&target, "Old local copy", "uid-newer", 10);
+        insert_versioned_task(&source, "Old source copy", "uid-older", 10);
+        insert_versioned_task(&target, "Edited locally", "uid-older", 20);
+        insert_versioned_task(&source, "Edited here", "uid-conflict", 30);
+        insert_versioned_task(&target, "Edited there", "uid-conflict", 30);
+        insert_todo(&source, "Todo", "uid-todo");
+        insert_shortcut(&source, "Shortcut", "uid-shortcut");
+
+        let summary = db_merge_database(&source, &target, |_| {}).unwrap();
+
+        assert_eq!(
+            summary,
+            DatabaseMergeSummary {
+                tasks_added: 1,
+                todos_added: 1,
+                shortcuts_added: 1,
+                newer_applied: 1,
+                conflicts_skipped: 1,
+            }
+        );
+        assert_eq!(
+            task_names(&target),
+            vec![
+                "Edited in source",
+                "Edited locally",
+                "Edited there",
+                "Only in source"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_database_merge_leaves_the_source_untouched() {
+        let source = std::env::temp_dir().join(format!(
+            "furtherance-merge-v1-source-{}.db",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&source);
+        {
+            let conn = Connection::open(&source).unwrap();
+            conn.execute(
+                "CREATE TABLE tasks (
+                    id INTEGER PRIMARY KEY,
+                    task_name TEXT,
+                    start_time TIMESTAMP,
+                    stop_time TIMESTAMP,
+                    tags TEXT
+                )",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO tasks (task_name, start_time, stop_time, tags)
+                VALUES ('Legacy', '2019-03-01T10:00:00+02:00', '2019-03-01T11:30:00+02:00', 'work')",
+                [],
+            )
+            .unwrap();
+        }
+        let before = std::fs::read(&source).unwrap();
+        let target = database("v1-target");
+
+        let summary = db_merge_database(&source, &target, |_| {}).unwrap();
+
+        assert_eq!(summary.tasks_added, 1);
+        assert_eq!(task_names(&target), vec!["Legacy"]);
+        assert_eq!(std::fs::read(&source).unwrap(), before);
+    }
+}
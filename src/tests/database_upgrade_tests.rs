@@ -0,0 +1,312 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod database_upgrade_tests {
+    use std::path::PathBuf;
+
+    use rusqlite::{Connection, params};
+
+    use crate::database::{UpgradeProgress, db_upgrade_legacy};
+
+    /// A database file of its own for each test, created with the given tasks table
+    fn legacy_database(name: &str, schema: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "furtherance-upgrade-{}-{}.db",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let conn = Connection::open(&path).unwrap();
+        conn.execute(schema, []).unwrap();
+        path
+    }
+
+    fn v1_database(name: &str) -> PathBuf {
+        legacy_database(
+            name,
+            "CREATE TABLE tasks (
+                id INTEGER PRIMARY KEY,
+                task_name TEXT,
+                start_time TIMESTAMP,
+                stop_time TIMESTAMP,
+                tags TEXT
+            )",
+        )
+    }
+
+    fn insert_v1_task(path: &PathBuf, name: &str, start: &str, stop: &str, tags: Option<&str>) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute(
+            "INSERT INTO tasks (task_name, start_time, stop_time, tags) VALUES (?1, ?2, ?3, ?4)",
+            params![name, start, stop, tags],
+        )
+        .unwrap();
+    }
+
+    fn column_names(path: &PathBuf) -> Vec<String> {
+        let conn = Connection::open(path).unwrap();
+        let mut stmt = conn
+            .prepare("SELECT name FROM pragma_table_info('tasks')")
+            .unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_v1_tasks_are_upgraded() {
+        let path = v1_database("v1");
+        insert_v1_task(
+            &path,
+            "Write",
+            "2019-03-01T10:00:00+02:00",
+            "2019-03-01T11:30:00+02:00",
+            Some("work"),
+        );
+        insert_v1_task(
+            &path,
+            "Read",
+            "2019-03-02T10:00:00+02:00",
+            "2019-03-02T11:00:00+02:00",
+            None,
+        );
+
+        db_upgrade_legacy(&path, |_| {}).unwrap();
+
+        let conn = Connection::open(&path).unwrap();
+        let tasks: Vec<(
+            String,
+            String,
+            String,
+            String,
+            String,
+            f64,
+            String,
+            bool,
+            i64,
+        )> = conn
+            .prepare(
+                "SELECT task_name, start_time, stop_time, tags, project, rate, currency,
+                is_billable, planned_seconds FROM tasks ORDER BY id",
+            )
+            .unwrap()
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get(6)?,
+                    row.get(7)?,
+                    row.get(8)?,
+                ))
+            })
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            tasks,
+            vec![
+                (
+                    "Write".to_string(),
+                    "2019-03-01T08:00:00+00:00".to_string(),
+                    "2019-03-01T09:30:00+00:00".to_string(),
+                    "work".to_string(),
+                    String::new(),
+                    0.0,
+                    String::new(),
+                    false,
+                    0,
+                ),
+                (
+                    "Read".to_string(),
+                    "2019-03-02T08:00:00+00:00".to_string(),
+                    "2019-03-02T09:00:00+00:00".to_string(),
+                    String::new(),
+                    String::new(),
+                    0.0,
+                    String::new(),
+                    false,
+                    0,
+                ),
+            ]
+        );
+        let missing_uids: i64 = conn
+            .query_row("SELECT COUNT(*) FROM tasks WHERE uid IS NULL", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(missing_uids, 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_task_stopped_before_it_started_is_swapped() {
+        let path = v1_database("swapped");
+        insert_v1_task(
+            &path,
+            "Clock change",
+            "2019-10-27T03:30:00+02:00",
+            "2019-10-27T02:45:00+02:00",
+            Some(""),
+        );
+
+        db_upgrade_legacy(&path, |_| {}).unwrap();
+
+        let conn = Connection::open(&path).unwrap();
+        let times: (String, String) = conn
+            .query_row("SELECT start_time, stop_time FROM tasks", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(
+            times,
+            (
+                "2019-10-27T00:45:00+00:00".to_string(),
+                "2019-10-27T01:30:00+00:00".to_string()
+            )
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_only_tasks_with_a_rate_become_billable() {
+        let path = legacy_database(
+            "rates",
+            "CREATE TABLE tasks (
+                id INTEGER PRIMARY KEY,
+                task_name TEXT,
+                start_time TIMESTAMP,
+                stop_time TIMESTAMP,
+                tags TEXT,
+                project TEXT,
+                rate REAL
+            )",
+        );
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "INSERT INTO tasks (task_name, start_time, stop_time, tags, project, rate) VALUES
+                ('Free', '2020-01-01T10:00:00+00:00', '2020-01-01T11:00:00+00:00', '', 'A', 0.0),
+                ('Paid', '2020-01-01T12:00:00+00:00', '2020-01-01T13:00:00+00:00', '', 'A', 12.5),
+                ('Unset', '2020-01-01T14:00:00+00:00', '2020-01-01T15:00:00+00:00', '', NULL, NULL);",
+            )
+            .unwrap();
+        }
+
+        db_upgrade_legacy(&path, |_| {}).unwrap();
+
+        let conn = Connection::open(&path).unwrap();
+        let tasks: Vec<(String, f64, bool)> = conn
+            .prepare("SELECT project, rate, is_billable FROM tasks ORDER BY id")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            tasks,
+            vec![
+                ("A".to_string(), 0.0, false),
+                ("A".to_string(), 12.5, true),
+                (String::new(), 0.0, false),
+            ]
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_progress_counts_every_rewritten_row() {
+        let path = v1_database("progress");
+        for day in 1..=3 {
+            insert_v1_task(
+                &path,
+                "Task",
+                &format!("2019-03-0{}T10:00:00+01:00", day),
+                &format!("2019-03-0{}T11:00:00+01:00", day),
+                Some(""),
+            );
+        }
+
+        let mut reports: Vec<UpgradeProgress> = vec![];
+        db_upgrade_legacy(&path, |progress| reports.push(progress)).unwrap();
+
+        // Three uids and three time conversions
+        assert_eq!(
+            reports.first(),
+            Some(&UpgradeProgress {
+                migrated: 0,
+                total: 6
+            })
+        );
+        assert_eq!(
+            reports.last(),
+            Some(&UpgradeProgress {
+                migrated: 6,
+                total: 6
+            })
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_failed_upgrade_leaves_database_untouched() {
+        let path = v1_database("failed");
+        insert_v1_task(
+            &path,
+            "Fine",
+            "2019-03-01T10:00:00+01:00",
+            "2019-03-01T11:00:00+01:00",
+            Some(""),
+        );
+        insert_v1_task(&path, "Broken", "not a time", "also not a time", Some(""));
+
+        assert!(db_upgrade_legacy(&path, |_| {}).is_err());
+
+        assert_eq!(
+            column_names(&path),
+            vec!["id", "task_name", "start_time", "stop_time", "tags"]
+        );
+        let conn = Connection::open(&path).unwrap();
+        let tables: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(tables, 1);
+        let start_time: String = conn
+            .query_row(
+                "SELECT start_time FROM tasks WHERE task_name = 'Fine'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(start_time, "2019-03-01T10:00:00+01:00");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,46 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod day_note_tests {
+    use chrono::NaiveDate;
+
+    use crate::models::fur_day_note::{FurDayNote, generate_day_note_uid};
+
+    #[test]
+    fn test_first_line() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let note = FurDayNote::new(
+            date,
+            "  Client call went long\nFollow up Monday".to_string(),
+        );
+        assert_eq!(note.first_line(), "Client call went long");
+
+        let empty_note = FurDayNote::new(date, String::new());
+        assert_eq!(empty_note.first_line(), "");
+    }
+
+    #[test]
+    fn test_uid_is_stable_per_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let other_date = NaiveDate::from_ymd_opt(2025, 3, 15).unwrap();
+        assert_eq!(generate_day_note_uid(&date), generate_day_note_uid(&date));
+        assert_ne!(
+            generate_day_note_uid(&date),
+            generate_day_note_uid(&other_date)
+        );
+    }
+}
@@ -0,0 +1,94 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod days_off_tests {
+    use std::collections::BTreeSet;
+
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::models::{
+        fur_days_off::FurDaysOff,
+        fur_report::{FurReportData, FurSelectionData},
+        fur_task::FurTask,
+    };
+
+    // June 1, 2026 is a Monday
+    fn task_on(day: u32, hour: u32, minutes: i64) -> FurTask {
+        let start = Local.with_ymd_and_hms(2026, 6, day, hour, 0, 0).unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, day).unwrap()
+    }
+
+    #[test]
+    fn test_weekends_only_count_when_enabled() {
+        let saturday = date(6);
+        assert!(!FurDaysOff::default().is_day_off(&saturday));
+        assert!(FurDaysOff::new(BTreeSet::new(), true).is_day_off(&saturday));
+        assert!(!FurDaysOff::new(BTreeSet::new(), true).is_day_off(&date(5)));
+    }
+
+    #[test]
+    fn test_weekends_are_not_marked_dates() {
+        let days_off = FurDaysOff::new(BTreeSet::new(), true);
+        assert!(!days_off.is_marked(&date(6)));
+    }
+
+    #[test]
+    fn test_counted_tasks_skip_days_off() {
+        let tasks = [task_on(1, 9, 60), task_on(2, 9, 60), task_on(6, 9, 60)];
+        let days_off = FurDaysOff::new(BTreeSet::from([date(2)]), true);
+        let counted = days_off.counted_tasks(&tasks);
+        assert_eq!(counted.len(), 1);
+        assert_eq!(counted[0].start_time.date_naive(), date(1));
+    }
+
+    #[test]
+    fn test_workday_stats_leave_out_days_off() {
+        let tasks = vec![task_on(1, 8, 60), task_on(2, 12, 240)];
+        let days_off = FurDaysOff::new(BTreeSet::from([date(2)]), false);
+
+        let data = FurReportData::from_tasks(tasks, 0.0, &days_off, &[]);
+        let stats = data.workday_stats.unwrap();
+        assert_eq!(stats.active_days, 1);
+        assert_eq!(stats.average_start, 8 * 3600);
+        assert_eq!(stats.average_tracked, 60 * 60);
+        // Totals still include time tracked on a day off
+        assert_eq!(data.total_time, 300 * 60);
+
+        let selected: Vec<&FurTask> = data.tasks_in_range.iter().collect();
+        let selection = FurSelectionData::from_tasks(&selected, None, &days_off, &[]);
+        assert_eq!(selection.workday_stats.unwrap().active_days, 1);
+    }
+
+    #[test]
+    fn test_only_days_off_hide_workday_stats() {
+        let days_off = FurDaysOff::new(BTreeSet::from([date(1)]), false);
+        let data = FurReportData::from_tasks(vec![task_on(1, 9, 60)], 0.0, &days_off, &[]);
+        assert_eq!(data.workday_stats, None);
+    }
+}
@@ -0,0 +1,150 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod earnings_tests {
+    use chrono::{Local, TimeDelta, TimeZone};
+
+    use crate::{
+        helpers::earnings::{
+            is_excluded_from_earnings, net_earnings, net_earnings_deduction,
+            parse_earnings_exclusions, task_earnings,
+        },
+        models::{
+            fur_days_off::FurDaysOff,
+            fur_invoice::{InvoiceProject, invoice_lines, invoice_totals},
+            fur_report::FurReportData,
+            fur_settings::FurSettings,
+            fur_task::FurTask,
+        },
+    };
+
+    /// An hour-long task on the same day, at $50 an hour
+    fn task_at(hour: u32, tags: &str, project: &str) -> FurTask {
+        let start = Local.with_ymd_and_hms(2026, 3, 10, hour, 0, 0).unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::hours(1),
+            tags.to_string(),
+            project.to_string(),
+            50.0,
+            "USD".to_string(),
+        )
+    }
+
+    fn mixed_day() -> Vec<FurTask> {
+        vec![
+            task_at(9, "client", "Acme"),
+            task_at(10, "nonbill #admin", "Acme"),
+            task_at(11, "client", "Internal"),
+            task_at(12, "", ""),
+        ]
+    }
+
+    #[test]
+    fn test_no_deduction_keeps_gross() {
+        assert_eq!(net_earnings(250.0, 0.0), 250.0);
+        assert_eq!(net_earnings(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_full_deduction_leaves_nothing() {
+        assert_eq!(net_earnings(250.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_fractional_deduction() {
+        assert!((net_earnings(200.0, 12.5) - 175.0).abs() < 0.001);
+        assert!((net_earnings(99.99, 33.3) - 66.693_33).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_deduction_is_clamped() {
+        assert_eq!(net_earnings(100.0, -20.0), 100.0);
+        assert_eq!(net_earnings(100.0, 150.0), 0.0);
+    }
+
+    #[test]
+    fn test_deduction_only_when_shown() {
+        let mut settings = FurSettings::default();
+        settings.deduction_percent = 25.0;
+        assert_eq!(net_earnings_deduction(&settings), None);
+
+        settings.show_net_earnings = true;
+        assert_eq!(net_earnings_deduction(&settings), Some(25.0));
+    }
+
+    #[test]
+    fn test_exclusions_are_parsed_from_text() {
+        assert_eq!(
+            parse_earnings_exclusions("#NonBill, @Internal Work,, admin ,#nonbill, @, #"),
+            vec!["#nonbill", "@internal work", "#admin"]
+        );
+        assert!(parse_earnings_exclusions("").is_empty());
+    }
+
+    #[test]
+    fn test_exclusions_match_whole_tags_and_projects() {
+        let exclusions = parse_earnings_exclusions("#nonbill, @internal");
+        assert!(is_excluded_from_earnings(
+            "admin #nonbill",
+            "Acme",
+            &exclusions
+        ));
+        assert!(is_excluded_from_earnings("", "Internal", &exclusions));
+        assert!(!is_excluded_from_earnings(
+            "nonbillable",
+            "Internal Tools",
+            &exclusions
+        ));
+        assert!(!is_excluded_from_earnings("client", "Acme", &exclusions));
+    }
+
+    #[test]
+    fn test_mixed_day_only_drops_excluded_earnings() {
+        let exclusions = parse_earnings_exclusions("#nonbill, @internal");
+        let tasks = mixed_day();
+
+        let earned: f32 = tasks
+            .iter()
+            .map(|task| task_earnings(task, task.total_time_in_seconds(), &exclusions))
+            .sum();
+        let tracked: i64 = tasks.iter().map(|task| task.total_time_in_seconds()).sum();
+
+        // Only the client task at Acme and the untagged one still earn
+        assert!((earned - 100.0).abs() < 0.001);
+        assert_eq!(tracked, 4 * 3600);
+
+        // Nor are the excluded ones billed on an invoice
+        let mut settings = FurSettings::default();
+        settings.earnings_exclusions = exclusions;
+        let lines = invoice_lines(&tasks, &InvoiceProject::All, &settings);
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.project != "Internal"));
+        let billed: f32 = invoice_totals(&lines).values().sum();
+        assert!((billed - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_report_totals_leave_out_excluded_earnings() {
+        let exclusions = parse_earnings_exclusions("#nonbill, @internal");
+        let data = FurReportData::from_tasks(mixed_day(), 0.0, &FurDaysOff::default(), &exclusions);
+
+        assert!((data.total_earned - 100.0).abs() < 0.001);
+        assert_eq!(data.total_time, 4 * 3600);
+    }
+}
@@ -0,0 +1,87 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod error_log_tests {
+    use std::collections::VecDeque;
+
+    use chrono::{Local, TimeDelta, TimeZone};
+
+    use crate::{
+        constants::MAX_LOGGED_ERRORS,
+        helpers::error_log::{error_log_text, log_error_at, unseen_error_count},
+    };
+
+    #[test]
+    fn test_log_keeps_only_newest_errors() {
+        let start = Local.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let mut errors = VecDeque::new();
+        for i in 0..MAX_LOGGED_ERRORS + 5 {
+            log_error_at(
+                &mut errors,
+                format!("Error {}", i),
+                start + TimeDelta::seconds(i as i64),
+            );
+        }
+
+        assert_eq!(errors.len(), MAX_LOGGED_ERRORS);
+        assert_eq!(errors.front().unwrap().1, "Error 5");
+        assert_eq!(
+            errors.back().unwrap().1,
+            format!("Error {}", MAX_LOGGED_ERRORS + 4)
+        );
+    }
+
+    #[test]
+    fn test_unseen_errors_are_newer_than_last_view() {
+        let start = Local.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let mut errors = VecDeque::new();
+        log_error_at(&mut errors, "First".to_string(), start);
+        log_error_at(
+            &mut errors,
+            "Second".to_string(),
+            start + TimeDelta::minutes(5),
+        );
+
+        assert_eq!(unseen_error_count(&errors, None), 2);
+        assert_eq!(
+            unseen_error_count(&errors, Some(start + TimeDelta::minutes(1))),
+            1
+        );
+        assert_eq!(
+            unseen_error_count(&errors, Some(start + TimeDelta::minutes(5))),
+            0
+        );
+    }
+
+    #[test]
+    fn test_error_log_text_lists_oldest_first() {
+        let start = Local.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let mut errors = VecDeque::new();
+        log_error_at(&mut errors, "Disk full".to_string(), start);
+        log_error_at(
+            &mut errors,
+            "Sync failed".to_string(),
+            start + TimeDelta::seconds(30),
+        );
+
+        assert_eq!(
+            error_log_text(&errors),
+            "2026-03-02 09:00:00  Disk full\n2026-03-02 09:00:30  Sync failed"
+        );
+        assert_eq!(error_log_text(&VecDeque::new()), "");
+    }
+}
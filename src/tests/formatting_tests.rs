@@ -0,0 +1,120 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod formatting_tests {
+    use chrono::NaiveTime;
+
+    use crate::helpers::formatting::{
+        NumberFormat, currency_symbol, format_byte_size, format_time_of_day,
+        locale_uses_24_hour_time, parse_time,
+    };
+
+    #[test]
+    fn test_english_numbers() {
+        let format = NumberFormat::for_locale("en_US.UTF-8");
+        assert_eq!(format.number(1234.5), "1,234.50");
+        assert_eq!(format.number(1234567.0), "1,234,567.00");
+        assert_eq!(format.number(12.0), "12.00");
+        assert_eq!(format.currency(1234.56, "USD"), "$1,234.56");
+        assert_eq!(format.currency(-5.0, "$"), "-$5.00");
+    }
+
+    #[test]
+    fn test_european_numbers() {
+        let german = NumberFormat::for_locale("de_DE.UTF-8");
+        assert_eq!(german.number(1234.56), "1.234,56");
+        assert_eq!(german.currency(1234.56, "EUR"), "1.234,56\u{a0}€");
+
+        let french = NumberFormat::for_locale("fr-FR");
+        assert_eq!(french.currency(1234.56, "€"), "1\u{a0}234,56\u{a0}€");
+    }
+
+    #[test]
+    fn test_unknown_locale_uses_english_rules() {
+        assert_eq!(
+            NumberFormat::for_locale("eo"),
+            NumberFormat::for_locale("en-US")
+        );
+        assert_eq!(NumberFormat::for_locale("").number(1000.0), "1,000.00");
+    }
+
+    #[test]
+    fn test_rounding_to_zero_has_no_sign() {
+        let format = NumberFormat::for_locale("en-US");
+        assert_eq!(format.number(-0.001), "0.00");
+    }
+
+    #[test]
+    fn test_currency_codes_become_symbols() {
+        assert_eq!(currency_symbol("eur"), "€");
+        assert_eq!(currency_symbol(" GBP "), "£");
+        assert_eq!(currency_symbol("CHF"), "CHF");
+        assert_eq!(currency_symbol("€"), "€");
+        assert_eq!(
+            NumberFormat::for_locale("en-US").currency(10.0, "CHF"),
+            "CHF\u{a0}10.00"
+        );
+    }
+
+    #[test]
+    fn test_no_currency_is_just_the_number() {
+        assert_eq!(NumberFormat::for_locale("de").currency(3.5, ""), "3,50");
+    }
+
+    #[test]
+    fn test_time_formats() {
+        let afternoon = NaiveTime::from_hms_opt(14, 35, 20).unwrap();
+        assert_eq!(format_time_of_day(afternoon, false, true), "14:35");
+        assert_eq!(format_time_of_day(afternoon, true, true), "14:35:20");
+        assert_eq!(format_time_of_day(afternoon, false, false), "2:35 PM");
+        assert_eq!(format_time_of_day(afternoon, true, false), "2:35:20 PM");
+
+        let midnight = NaiveTime::from_hms_opt(0, 5, 0).unwrap();
+        assert_eq!(format_time_of_day(midnight, false, false), "12:05 AM");
+    }
+
+    #[test]
+    fn test_locale_clock() {
+        assert!(!locale_uses_24_hour_time("en_US.UTF-8"));
+        assert!(!locale_uses_24_hour_time("en-CA"));
+        assert!(locale_uses_24_hour_time("en_GB.UTF-8"));
+        assert!(locale_uses_24_hour_time("de_DE.UTF-8"));
+        assert!(locale_uses_24_hour_time("fr"));
+    }
+
+    #[test]
+    fn test_parse_either_clock() {
+        let afternoon = NaiveTime::from_hms_opt(14, 35, 0);
+        assert_eq!(parse_time("14:35"), afternoon);
+        assert_eq!(parse_time("2:35 PM"), afternoon);
+        assert_eq!(parse_time(" 2:35pm "), afternoon);
+        assert_eq!(parse_time("12:00 am"), NaiveTime::from_hms_opt(0, 0, 0));
+        assert_eq!(parse_time("14:35:20"), NaiveTime::from_hms_opt(14, 35, 20));
+        assert_eq!(parse_time("13:00 PM"), None);
+        assert_eq!(parse_time("noon"), None);
+    }
+
+    #[test]
+    fn test_format_byte_size() {
+        assert_eq!(format_byte_size(0), "0 B");
+        assert_eq!(format_byte_size(1023), "1023 B");
+        assert_eq!(format_byte_size(1024), "1.0 KB");
+        assert_eq!(format_byte_size(4300), "4.2 KB");
+        assert_eq!(format_byte_size(3 * 1024 * 1024 / 2), "1.5 MB");
+        assert_eq!(format_byte_size(5 * 1024 * 1024 * 1024), "5.0 GB");
+    }
+}
@@ -0,0 +1,84 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod heatmap_tests {
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        charts::{all_charts, heatmap_chart::HeatmapChart},
+        models::fur_task::FurTask,
+    };
+
+    fn task_on(date: NaiveDate, hour: u32, minutes: i64) -> FurTask {
+        let start = Local
+            .from_local_datetime(&date.and_hms_opt(hour, 0, 0).unwrap())
+            .unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_time_per_day_adds_up_each_day() {
+        let day = date(2026, 6, 17);
+        let totals = all_charts::time_per_day(&[
+            task_on(day, 9, 30),
+            task_on(day, 14, 45),
+            task_on(date(2026, 6, 18), 9, 10),
+        ]);
+        assert_eq!(totals.get(&day), Some(&(75 * 60)));
+        assert_eq!(totals.len(), 2);
+    }
+
+    #[test]
+    fn test_heatmap_starts_on_a_monday_a_year_back() {
+        // A Wednesday
+        let heatmap = HeatmapChart::new(&[], date(2026, 6, 17));
+        assert_eq!(heatmap.first_day(), date(2025, 6, 16));
+        assert_eq!(heatmap.cell(date(2025, 6, 16)), Some((0, 0)));
+        assert_eq!(heatmap.cell(date(2026, 6, 17)), Some((52, 2)));
+        assert_eq!(heatmap.cell(date(2026, 6, 18)), None);
+        assert_eq!(heatmap.cell(date(2025, 6, 15)), None);
+    }
+
+    #[test]
+    fn test_heatmap_levels_scale_with_the_busiest_day() {
+        let end = date(2026, 6, 17);
+        let heatmap = HeatmapChart::new(
+            &[
+                task_on(date(2026, 6, 1), 9, 60),
+                task_on(date(2026, 6, 2), 9, 15),
+                task_on(date(2026, 6, 3), 9, 31),
+            ],
+            end,
+        );
+        assert_eq!(heatmap.level(date(2026, 6, 1)), 4);
+        assert_eq!(heatmap.level(date(2026, 6, 2)), 1);
+        assert_eq!(heatmap.level(date(2026, 6, 3)), 3);
+        assert_eq!(heatmap.level(date(2026, 6, 4)), 0);
+    }
+}
@@ -0,0 +1,165 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod history_filter_tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use chrono::{Local, NaiveDate, TimeDelta};
+
+    use crate::{
+        helpers::tasks::{
+            PeriodKey, group_history_by_period, group_matches_filters, most_used_filter_chips,
+            visible_history_groups,
+        },
+        models::{fur_settings::FurSettings, fur_task::FurTask, fur_task_group::FurTaskGroup},
+        view_enums::{FilterChip, FurHistoryGrouping},
+    };
+
+    fn group(project: &str, tags: &str, task_count: usize) -> FurTaskGroup {
+        let start = Local::now() - TimeDelta::hours(2);
+        let task = FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::minutes(30),
+            tags.to_string(),
+            project.to_string(),
+            0.0,
+            String::new(),
+        );
+        let mut task_group = FurTaskGroup::new_from(task.clone());
+        for _ in 1..task_count {
+            task_group.add(task.clone());
+        }
+        task_group
+    }
+
+    #[test]
+    fn test_most_used_chips_are_ordered_by_use() {
+        let mut history = BTreeMap::new();
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 5, 1).unwrap(),
+            vec![group("Client", "design #review", 3), group("", "admin", 1)],
+        );
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 5, 2).unwrap(),
+            vec![group("Internal", "review", 2)],
+        );
+
+        let chips = most_used_filter_chips(&history, 3);
+        assert_eq!(
+            chips,
+            vec![
+                FilterChip::Tag("review".to_string()),
+                FilterChip::Tag("design".to_string()),
+                FilterChip::Project("Client".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filters_combine_with_and() {
+        let task_group = group("Client", "design #review", 1);
+        let mut filters = HashSet::new();
+        assert!(group_matches_filters(&task_group, &filters));
+
+        filters.insert(FilterChip::Project("client".to_string()));
+        filters.insert(FilterChip::Tag("review".to_string()));
+        assert!(group_matches_filters(&task_group, &filters));
+
+        filters.insert(FilterChip::Tag("admin".to_string()));
+        assert!(!group_matches_filters(&task_group, &filters));
+    }
+
+    #[test]
+    fn test_visible_history_groups_follow_display_order() {
+        let mut history = BTreeMap::new();
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 5, 1).unwrap(),
+            vec![group("Old", "", 1)],
+        );
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 5, 2).unwrap(),
+            vec![group("First", "", 1), group("Archived", "", 1)],
+        );
+        let mut settings = FurSettings::default();
+        settings.archived_projects = vec!["Archived".to_string()];
+        settings.hide_archived_in_history = true;
+
+        let projects: Vec<&str> = visible_history_groups(&history, &HashSet::new(), &settings)
+            .iter()
+            .map(|task_group| task_group.project.as_str())
+            .collect();
+        assert_eq!(projects, vec!["First", "Old"]);
+    }
+
+    #[test]
+    fn test_period_key_starts_weeks_on_monday() {
+        let sunday = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        assert_eq!(
+            PeriodKey::containing(sunday, FurHistoryGrouping::Week).start,
+            monday
+        );
+        assert_eq!(
+            PeriodKey::containing(sunday, FurHistoryGrouping::Month).start,
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+        );
+        assert_eq!(
+            PeriodKey::containing(sunday, FurHistoryGrouping::Day).start,
+            sunday
+        );
+    }
+
+    #[test]
+    fn test_history_by_week_merges_groups_across_days() {
+        let mut history = BTreeMap::new();
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+            vec![group("Client", "", 2), group("Internal", "", 1)],
+        );
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 3, 5).unwrap(),
+            vec![group("Client", "", 1)],
+        );
+        history.insert(
+            NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            vec![group("Client", "", 1)],
+        );
+
+        let sections = group_history_by_period(&history, FurHistoryGrouping::Week);
+        assert_eq!(sections.len(), 2);
+
+        let first_week = &sections[&PeriodKey {
+            start: NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+        }];
+        let projects: Vec<&str> = first_week
+            .iter()
+            .map(|task_group| task_group.project.as_str())
+            .collect();
+        assert_eq!(projects, vec!["Client", "Internal"]);
+        assert_eq!(first_week[0].tasks.len(), 3);
+        assert_eq!(first_week[0].total_time, 3 * 30 * 60);
+
+        // Sections list newest first, like days do
+        let projects: Vec<&str> =
+            visible_history_groups(&sections, &HashSet::new(), &FurSettings::default())
+                .iter()
+                .map(|task_group| task_group.project.as_str())
+                .collect();
+        assert_eq!(projects, vec!["Client", "Client", "Internal"]);
+    }
+}
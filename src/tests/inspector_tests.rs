@@ -0,0 +1,170 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod inspector_tests {
+    use chrono::{Local, NaiveDate, TimeDelta};
+    use iced::Color;
+
+    use crate::{
+        models::{
+            fur_shortcut::FurShortcut,
+            fur_task::FurTask,
+            fur_task_group::FurTaskGroup,
+            fur_todo::{FurTodo, TodoToAdd, TodoToEdit},
+            group_to_edit::GroupToEdit,
+            shortcut_to_add::ShortcutToAdd,
+            shortcut_to_edit::ShortcutToEdit,
+            task_to_add::TaskToAdd,
+            task_to_edit::TaskToEdit,
+        },
+        update::{messages::Message, msg_helper_functions::inspector_cancel_message},
+        view_enums::FurInspectorView,
+    };
+
+    fn task(name: &str) -> FurTask {
+        let stop_time = Local::now();
+        FurTask::new(
+            name.to_string(),
+            stop_time - TimeDelta::minutes(45),
+            stop_time,
+            "client".to_string(),
+            "Website".to_string(),
+            40.0,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_new_forms_start_unchanged() {
+        assert!(!TaskToAdd::new().is_changed());
+        assert!(
+            !TaskToAdd::new_for_date(NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()).is_changed()
+        );
+        assert!(
+            !TaskToAdd::new_from(&GroupToEdit::new_from(&FurTaskGroup::new_from(task(
+                "Design"
+            ))))
+            .is_changed()
+        );
+        assert!(!ShortcutToAdd::new().is_changed());
+        assert!(!TodoToAdd::new().is_changed());
+    }
+
+    #[test]
+    fn test_typing_in_a_new_form_changes_it() {
+        let mut task_to_add = TaskToAdd::new();
+        task_to_add.name = "Design".to_string();
+        assert!(task_to_add.is_changed());
+
+        let mut task_to_add = TaskToAdd::new();
+        task_to_add.start_time -= TimeDelta::minutes(5);
+        assert!(task_to_add.is_changed());
+
+        let mut task_to_add = TaskToAdd::new();
+        task_to_add.is_billable = false;
+        assert!(task_to_add.is_changed());
+
+        let mut shortcut_to_add = ShortcutToAdd::new();
+        shortcut_to_add.color = Color::from_rgb(0.1, 0.2, 0.3);
+        assert!(shortcut_to_add.is_changed());
+
+        let mut todo_to_add = TodoToAdd::new();
+        todo_to_add.estimate = "30m".to_string();
+        assert!(todo_to_add.is_changed());
+    }
+
+    #[test]
+    fn test_whitespace_alone_is_not_a_change() {
+        let mut task_to_add = TaskToAdd::new();
+        task_to_add.name = "  ".to_string();
+        assert!(!task_to_add.is_changed());
+
+        let mut todo_to_add = TodoToAdd::new();
+        todo_to_add.tags = " ".to_string();
+        assert!(!todo_to_add.is_changed());
+    }
+
+    #[test]
+    fn test_edit_forms_with_symbols_start_unchanged() {
+        let task = task("Email \"Bob\" @ home");
+        assert!(!TaskToEdit::new_from(&task).is_changed());
+        assert!(!GroupToEdit::new_from(&FurTaskGroup::new_from(task)).is_changed());
+
+        let shortcut = FurShortcut::new(
+            "Budget #2".to_string(),
+            "#client".to_string(),
+            "Website".to_string(),
+            45.0,
+            "EUR".to_string(),
+            "#A1B2C3".to_string(),
+        );
+        assert!(!ShortcutToEdit::new_from(&shortcut).is_changed());
+
+        let todo = FurTodo::new(
+            "Pay $5 fee".to_string(),
+            String::new(),
+            String::new(),
+            0.0,
+            Local::now(),
+        );
+        assert!(!TodoToEdit::new_from(&todo).is_changed());
+    }
+
+    #[test]
+    fn test_escape_closes_each_inspector_like_its_cancel_button() {
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::AddNewTask),
+            Message::CancelTaskEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::AddTaskToGroup),
+            Message::CancelTaskEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::EditTask),
+            Message::CancelTaskEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::EditGroup),
+            Message::CancelGroupEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::AddShortcut),
+            Message::CancelShortcut
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::EditShortcut),
+            Message::CancelShortcut
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::AddNewTodo),
+            Message::CancelTodoEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::EditTodo),
+            Message::CancelTodoEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::EditDayNote),
+            Message::CancelDayNoteEdit
+        ));
+        assert!(matches!(
+            inspector_cancel_message(&FurInspectorView::GenerateInvoice),
+            Message::CancelInvoice
+        ));
+    }
+}
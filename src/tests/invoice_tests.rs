@@ -0,0 +1,122 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod invoice_tests {
+    use chrono::{Local, TimeZone};
+
+    use crate::{
+        localization::Localization,
+        models::{
+            fur_invoice::{
+                InvoiceHeader, InvoiceProject, invoice_html, invoice_lines, invoice_totals,
+            },
+            fur_settings::FurSettings,
+            fur_task::FurTask,
+        },
+    };
+
+    fn task(
+        name: &str,
+        project: &str,
+        hour: u32,
+        minutes: i64,
+        rate: f32,
+        currency: &str,
+    ) -> FurTask {
+        let start = Local.with_ymd_and_hms(2026, 5, 4, hour, 0, 0).unwrap();
+        FurTask::new(
+            name.to_string(),
+            start,
+            start + chrono::Duration::minutes(minutes),
+            String::new(),
+            project.to_string(),
+            rate,
+            currency.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_same_task_on_a_day_is_one_line() {
+        let tasks = vec![
+            task("Design", "acme", 9, 60, 50.0, ""),
+            task("Design", "acme", 13, 30, 50.0, ""),
+            task("Review", "acme", 15, 30, 50.0, ""),
+        ];
+        let lines = invoice_lines(&tasks, &InvoiceProject::All, &FurSettings::default());
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].name, "Design");
+        assert_eq!(lines[0].seconds, 5400);
+        assert_eq!(lines[0].amount, 75.0);
+    }
+
+    #[test]
+    fn test_project_filter_and_non_billable_tasks_are_left_out() {
+        let tasks = vec![
+            task("Design", "acme", 9, 60, 50.0, ""),
+            task("Design", "other", 11, 60, 50.0, ""),
+            task("Lunch", "acme", 12, 60, 0.0, ""),
+        ];
+        let lines = invoice_lines(
+            &tasks,
+            &InvoiceProject::Project("ACME".to_string()),
+            &FurSettings::default(),
+        );
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].project, "acme");
+    }
+
+    #[test]
+    fn test_rounding_applies_to_each_task() {
+        let mut settings = FurSettings::default();
+        settings.rounding_enabled = true;
+        settings.rounding_minutes = 15;
+        let tasks = vec![
+            task("Design", "", 9, 50, 60.0, ""),
+            task("Design", "", 13, 50, 60.0, ""),
+        ];
+        let lines = invoice_lines(&tasks, &InvoiceProject::All, &settings);
+        assert_eq!(lines[0].seconds, 2 * 45 * 60);
+        assert_eq!(lines[0].amount, 90.0);
+    }
+
+    #[test]
+    fn test_totals_are_kept_per_currency() {
+        let tasks = vec![
+            task("Design", "", 9, 60, 50.0, "USD"),
+            task("Design", "", 11, 60, 40.0, "EUR"),
+            task("Review", "", 13, 30, 40.0, "EUR"),
+        ];
+        let lines = invoice_lines(&tasks, &InvoiceProject::All, &FurSettings::default());
+        let totals = invoice_totals(&lines);
+        assert_eq!(totals.get("USD"), Some(&50.0));
+        assert_eq!(totals.get("EUR"), Some(&60.0));
+    }
+
+    #[test]
+    fn test_html_escapes_user_text() {
+        let header = InvoiceHeader {
+            client: "Smith & <Sons>".to_string(),
+            ..InvoiceHeader::default()
+        };
+        let tasks = vec![task("Design", "", 9, 60, 50.0, "")];
+        let lines = invoice_lines(&tasks, &InvoiceProject::All, &FurSettings::default());
+        let date = Local::now().date_naive();
+        let html = invoice_html(&header, &lines, (date, date), &Localization::new());
+        assert!(html.contains("Smith &amp; &lt;Sons&gt;"));
+        assert!(html.contains("$50.00"));
+    }
+}
@@ -0,0 +1,179 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod keyboard_nav_tests {
+    use crate::{
+        constants::{
+            INSPECTOR_ESTIMATE_INPUT_ID, INSPECTOR_NAME_INPUT_ID, INSPECTOR_RATE_INPUT_ID,
+            SYNC_KEY_INPUT_ID, SYNC_SERVER_INPUT_ID,
+        },
+        helpers::keyboard_nav::{
+            NavDirection, focused_input_for, grid_columns, inspector_focus_order, move_grid_index,
+            move_list_index, next_focus, relative_scroll_offset, settings_focus_order,
+        },
+        update::messages::Message,
+        view_enums::{EditTaskProperty, EditTodoProperty, FurInspectorView, TabId},
+    };
+
+    const ALL_INSPECTOR_VIEWS: [FurInspectorView; 10] = [
+        FurInspectorView::AddNewTask,
+        FurInspectorView::AddNewTodo,
+        FurInspectorView::AddShortcut,
+        FurInspectorView::AddTaskToGroup,
+        FurInspectorView::EditDayNote,
+        FurInspectorView::EditGroup,
+        FurInspectorView::EditShortcut,
+        FurInspectorView::EditTask,
+        FurInspectorView::EditTodo,
+        FurInspectorView::GenerateInvoice,
+    ];
+
+    #[test]
+    fn test_move_list_index_starts_at_top() {
+        assert_eq!(move_list_index(None, 3, NavDirection::Down), Some(0));
+        assert_eq!(move_list_index(None, 3, NavDirection::Up), Some(0));
+        assert_eq!(move_list_index(None, 0, NavDirection::Down), None);
+    }
+
+    #[test]
+    fn test_move_list_index_stops_at_ends() {
+        assert_eq!(move_list_index(Some(0), 3, NavDirection::Up), Some(0));
+        assert_eq!(move_list_index(Some(1), 3, NavDirection::Down), Some(2));
+        assert_eq!(move_list_index(Some(2), 3, NavDirection::Down), Some(2));
+        assert_eq!(move_list_index(Some(1), 3, NavDirection::Left), Some(1));
+    }
+
+    #[test]
+    fn test_move_list_index_clamps_after_list_shrinks() {
+        assert_eq!(move_list_index(Some(9), 3, NavDirection::Up), Some(1));
+    }
+
+    #[test]
+    fn test_move_grid_index() {
+        // 0 1 2
+        // 3 4
+        assert_eq!(move_grid_index(Some(1), 5, 3, NavDirection::Down), Some(4));
+        assert_eq!(move_grid_index(Some(2), 5, 3, NavDirection::Down), Some(2));
+        assert_eq!(move_grid_index(Some(4), 5, 3, NavDirection::Up), Some(1));
+        assert_eq!(move_grid_index(Some(1), 5, 3, NavDirection::Up), Some(1));
+        assert_eq!(move_grid_index(Some(2), 5, 3, NavDirection::Right), Some(3));
+        assert_eq!(move_grid_index(Some(0), 5, 3, NavDirection::Left), Some(0));
+    }
+
+    #[test]
+    fn test_grid_columns() {
+        assert_eq!(grid_columns(640.0, 200.0, 20.0), 2);
+        assert_eq!(grid_columns(660.0, 200.0, 20.0), 3);
+        assert_eq!(grid_columns(50.0, 200.0, 20.0), 1);
+    }
+
+    #[test]
+    fn test_relative_scroll_offset() {
+        assert_eq!(relative_scroll_offset(0, 1), 0.0);
+        assert_eq!(relative_scroll_offset(2, 5), 0.5);
+        assert_eq!(relative_scroll_offset(7, 5), 1.0);
+    }
+
+    #[test]
+    fn test_next_focus_wraps() {
+        let order = [INSPECTOR_NAME_INPUT_ID, INSPECTOR_RATE_INPUT_ID];
+
+        assert_eq!(
+            next_focus(&order, Some(INSPECTOR_NAME_INPUT_ID), false),
+            Some(INSPECTOR_RATE_INPUT_ID)
+        );
+        assert_eq!(
+            next_focus(&order, Some(INSPECTOR_RATE_INPUT_ID), false),
+            Some(INSPECTOR_NAME_INPUT_ID)
+        );
+        assert_eq!(
+            next_focus(&order, Some(INSPECTOR_NAME_INPUT_ID), true),
+            Some(INSPECTOR_RATE_INPUT_ID)
+        );
+    }
+
+    #[test]
+    fn test_next_focus_starts_at_either_end() {
+        let order = [INSPECTOR_NAME_INPUT_ID, INSPECTOR_RATE_INPUT_ID];
+
+        assert_eq!(
+            next_focus(&order, None, false),
+            Some(INSPECTOR_NAME_INPUT_ID)
+        );
+        assert_eq!(
+            next_focus(&order, None, true),
+            Some(INSPECTOR_RATE_INPUT_ID)
+        );
+        assert_eq!(
+            next_focus(&order, Some(SYNC_KEY_INPUT_ID), false),
+            Some(INSPECTOR_NAME_INPUT_ID)
+        );
+        assert_eq!(next_focus(&[], None, false), None);
+    }
+
+    #[test]
+    fn test_every_inspector_has_a_focus_order() {
+        for view in &ALL_INSPECTOR_VIEWS {
+            let order = inspector_focus_order(view);
+            for (index, id) in order.iter().enumerate() {
+                assert!(!order[index + 1..].contains(id), "{view:?} repeats {id}");
+            }
+            match view {
+                FurInspectorView::AddTaskToGroup | FurInspectorView::EditDayNote => {
+                    assert!(order.is_empty(), "{view:?} has no text inputs")
+                }
+                _ => assert!(!order.is_empty(), "{view:?} has no focus order"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_edited_task_fields_are_in_focus_order() {
+        let order = inspector_focus_order(&FurInspectorView::EditTask);
+        for property in [
+            EditTaskProperty::Name,
+            EditTaskProperty::Project,
+            EditTaskProperty::Tags,
+            EditTaskProperty::Rate,
+        ] {
+            let message = Message::EditTaskTextChanged(String::new(), property);
+            assert!(order.contains(&focused_input_for(&message).unwrap()));
+        }
+
+        let message = Message::EditTodoTextChanged(String::new(), EditTodoProperty::Estimate);
+        assert_eq!(
+            focused_input_for(&message),
+            Some(INSPECTOR_ESTIMATE_INPUT_ID)
+        );
+        assert!(
+            inspector_focus_order(&FurInspectorView::EditTodo)
+                .contains(&INSPECTOR_ESTIMATE_INPUT_ID)
+        );
+    }
+
+    #[test]
+    fn test_data_tab_focus_order() {
+        let order = settings_focus_order(&TabId::Data);
+
+        assert_eq!(order.first(), Some(&SYNC_SERVER_INPUT_ID));
+        assert_eq!(
+            focused_input_for(&Message::UserEncryptionKeyChanged(String::new())),
+            Some(SYNC_KEY_INPUT_ID)
+        );
+        assert!(order.contains(&SYNC_KEY_INPUT_ID));
+    }
+}
@@ -0,0 +1,70 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod localization_tests {
+    use regex::Regex;
+
+    use crate::localization::Localization;
+
+    fn message_ids_in_app() -> Vec<String> {
+        let re_message = Regex::new(r#"get_message\(\s*"([^"]+)""#).unwrap();
+        re_message
+            .captures_iter(include_str!("../app.rs"))
+            .map(|cap| cap[1].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_unsupported_locale_falls_back_to_english() {
+        let localization = Localization::new_with_locale("eo");
+        let english = Localization::new_with_locale("en-US");
+        for id in message_ids_in_app() {
+            assert_eq!(
+                localization.get_message(&id, None),
+                english.get_message(&id, None)
+            );
+        }
+    }
+
+    #[test]
+    fn test_posix_locale_resolves_all_app_messages() {
+        let localization = Localization::new_with_locale("en_DK.UTF-8");
+        for id in message_ids_in_app() {
+            let message = localization.get_message(&id, None);
+            assert!(!message.is_empty());
+            assert_ne!(message, id, "Missing English message: {}", id);
+        }
+    }
+
+    #[test]
+    fn test_regional_locale_uses_language_bundle() {
+        let localization = Localization::new_with_locale("de_AT.UTF-8");
+        assert_eq!(
+            localization.get_message("shortcuts", None),
+            Localization::new_with_locale("de").get_message("shortcuts", None)
+        );
+    }
+
+    #[test]
+    fn test_missing_message_returns_id() {
+        let localization = Localization::new_with_locale("fr");
+        assert_eq!(
+            localization.get_message("this-message-does-not-exist", None),
+            "this-message-does-not-exist"
+        );
+    }
+}
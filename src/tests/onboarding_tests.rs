@@ -0,0 +1,53 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod onboarding_tests {
+    use crate::{models::fur_onboarding::FurOnboarding, view_enums::OnboardingStep};
+
+    #[test]
+    fn test_back_returns_to_the_previous_step() {
+        let mut onboarding = FurOnboarding::new(false);
+        assert!(onboarding.is_first_step());
+        assert!(!onboarding.go_back());
+
+        assert!(onboarding.advance());
+        assert_eq!(onboarding.current_step(), OnboardingStep::Settings);
+        assert!(onboarding.go_back());
+        assert_eq!(onboarding.current_step(), OnboardingStep::TaskSyntax);
+    }
+
+    #[test]
+    fn test_advance_stops_at_the_last_step() {
+        let mut onboarding = FurOnboarding::new(false);
+        while onboarding.advance() {}
+        assert!(onboarding.is_last_step());
+        assert_eq!(onboarding.step_number(), onboarding.total_steps());
+        assert_eq!(onboarding.current_step(), OnboardingStep::Sync);
+    }
+
+    #[test]
+    fn test_old_database_import_is_not_a_step() {
+        let mut onboarding = FurOnboarding::new(true);
+        assert!(onboarding.offer_old_db_import);
+        assert_eq!(
+            onboarding.total_steps(),
+            FurOnboarding::new(false).total_steps()
+        );
+        while onboarding.advance() {}
+        assert_eq!(onboarding.current_step(), OnboardingStep::Sync);
+    }
+}
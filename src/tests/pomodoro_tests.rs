@@ -0,0 +1,57 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod pomodoro_tests {
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+
+    use crate::models::fur_pomodoro::pomodoro_streak;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_streak_counts_consecutive_days_ending_today() {
+        let days = BTreeSet::from([day(1), day(3), day(4), day(5)]);
+        assert_eq!(pomodoro_streak(&days, day(5)), 3);
+    }
+
+    #[test]
+    fn test_streak_continues_from_yesterday() {
+        let days = BTreeSet::from([day(3), day(4)]);
+        assert_eq!(pomodoro_streak(&days, day(5)), 2);
+    }
+
+    #[test]
+    fn test_streak_broken_by_missed_day() {
+        let days = BTreeSet::from([day(2), day(3)]);
+        assert_eq!(pomodoro_streak(&days, day(5)), 0);
+        assert_eq!(pomodoro_streak(&BTreeSet::new(), day(5)), 0);
+    }
+
+    #[test]
+    fn test_streak_across_month_boundary() {
+        let days = BTreeSet::from([
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+            day(1),
+            day(2),
+        ]);
+        assert_eq!(pomodoro_streak(&days, day(2)), 3);
+    }
+}
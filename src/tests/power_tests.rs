@@ -0,0 +1,52 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod power_tests {
+    use chrono::{Local, TimeDelta};
+
+    use crate::{
+        constants::{POWER_CHECK_SECONDS, SLEEP_GAP_TOLERANCE_SECONDS},
+        helpers::power::slept_between,
+    };
+
+    #[test]
+    fn test_regular_check_is_not_sleep() {
+        let last_seen = Local::now();
+        let now = last_seen + TimeDelta::seconds(POWER_CHECK_SECONDS as i64);
+        assert!(!slept_between(last_seen, now));
+    }
+
+    #[test]
+    fn test_late_check_within_tolerance_is_not_sleep() {
+        let last_seen = Local::now();
+        let now = last_seen
+            + TimeDelta::seconds((POWER_CHECK_SECONDS + SLEEP_GAP_TOLERANCE_SECONDS) as i64);
+        assert!(!slept_between(last_seen, now));
+    }
+
+    #[test]
+    fn test_overnight_gap_is_sleep() {
+        let last_seen = Local::now();
+        assert!(slept_between(last_seen, last_seen + TimeDelta::hours(9)));
+    }
+
+    #[test]
+    fn test_clock_moving_backwards_is_not_sleep() {
+        let last_seen = Local::now();
+        assert!(!slept_between(last_seen, last_seen - TimeDelta::hours(1)));
+    }
+}
@@ -0,0 +1,105 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod profile_tests {
+    use crate::models::fur_profile::{
+        FurProfile, ProfileError, validate_profile_name, validate_profile_path,
+    };
+
+    fn profiles() -> Vec<FurProfile> {
+        vec![
+            FurProfile {
+                name: "Work".to_string(),
+                database_url: "/data/work.db".to_string(),
+            },
+            FurProfile {
+                name: "Personal".to_string(),
+                database_url: "/data/personal.db".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_new_profile_name_is_valid() {
+        assert_eq!(
+            validate_profile_name(&profiles(), "Side project", None),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_profile_name_cannot_be_empty() {
+        assert_eq!(
+            validate_profile_name(&profiles(), "   ", None),
+            Err(ProfileError::EmptyName)
+        );
+    }
+
+    #[test]
+    fn test_profile_names_are_unique_ignoring_case() {
+        assert_eq!(
+            validate_profile_name(&profiles(), "work", None),
+            Err(ProfileError::DuplicateName)
+        );
+    }
+
+    #[test]
+    fn test_renamed_profile_can_keep_its_name() {
+        assert_eq!(
+            validate_profile_name(&profiles(), "WORK", Some("Work")),
+            Ok(())
+        );
+        assert_eq!(
+            validate_profile_name(&profiles(), "Personal", Some("Work")),
+            Err(ProfileError::DuplicateName)
+        );
+    }
+
+    #[test]
+    fn test_profile_path_needs_database_extension() {
+        assert_eq!(
+            validate_profile_path("/data/notes.txt"),
+            Err(ProfileError::InvalidExtension)
+        );
+    }
+
+    #[test]
+    fn test_profile_path_folder_is_created() {
+        let folder = std::env::temp_dir().join(format!(
+            "furtherance-profile-test-{}",
+            rand::random::<u64>()
+        ));
+        let database = folder.join("work.db");
+        assert_eq!(validate_profile_path(&database.to_string_lossy()), Ok(()));
+        assert!(folder.exists());
+        let _ = std::fs::remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_existing_file_must_be_a_database() {
+        let path = std::env::temp_dir().join(format!(
+            "furtherance-profile-test-{}.db",
+            rand::random::<u64>()
+        ));
+        std::fs::write(&path, "not a database").unwrap();
+        assert_eq!(
+            validate_profile_path(&path.to_string_lossy()),
+            Err(ProfileError::InvalidDatabase)
+        );
+        let _ = std::fs::remove_file(path);
+    }
+}
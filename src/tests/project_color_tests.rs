@@ -0,0 +1,47 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+#[cfg(test)]
+mod project_color_tests {
+    use palette::Srgb;
+    use plotters::style::RGBColor;
+
+    use crate::{
+        charts::all_charts::series_color,
+        constants::CHART_COLOR,
+        helpers::color_utils::{FromHex, ToHex, is_dark_color},
+    };
+
+    #[test]
+    fn test_badge_text_contrast() {
+        assert!(is_dark_color(Srgb::new(0.1, 0.1, 0.4)));
+        assert!(!is_dark_color(Srgb::new(1.0, 0.95, 0.6)));
+    }
+
+    #[test]
+    fn test_project_color_round_trips_through_hex() {
+        let color = Srgb::from_hex("#3A7BD5").unwrap();
+        assert_eq!(color.to_hex(), "#3A7BD5");
+    }
+
+    #[test]
+    fn test_series_color() {
+        assert_eq!(series_color(None), CHART_COLOR);
+        assert_eq!(
+            series_color(Some(Srgb::new(1.0, 0.0, 0.0))),
+            RGBColor(255, 0, 0)
+        );
+    }
+}
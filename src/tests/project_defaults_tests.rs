@@ -0,0 +1,72 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod project_defaults_tests {
+    use std::collections::HashMap;
+
+    use crate::helpers::project_defaults::{
+        added_default_tags, default_tags_for, merge_default_tags,
+    };
+
+    #[test]
+    fn test_defaults_merge_with_typed_tags() {
+        assert_eq!(
+            merge_default_tags("urgent", "billable #support"),
+            "billable #support #urgent"
+        );
+    }
+
+    #[test]
+    fn test_typed_default_is_not_duplicated() {
+        assert_eq!(
+            merge_default_tags("Billable", "billable #support"),
+            "billable #support"
+        );
+        assert_eq!(
+            added_default_tags("billable", "billable #support"),
+            "support"
+        );
+    }
+
+    #[test]
+    fn test_skip_prefix_leaves_default_off() {
+        assert_eq!(
+            merge_default_tags("-billable #urgent", "billable #support"),
+            "support #urgent"
+        );
+        assert_eq!(
+            added_default_tags("-billable", "billable #support"),
+            "support"
+        );
+    }
+
+    #[test]
+    fn test_empty_defaults_keep_typed_tags() {
+        assert_eq!(merge_default_tags("urgent", ""), "urgent");
+        assert_eq!(added_default_tags("urgent", ""), "");
+    }
+
+    #[test]
+    fn test_project_lookup_ignores_case() {
+        let defaults = HashMap::from([("Acme".to_string(), "billable".to_string())]);
+
+        assert_eq!(default_tags_for(&defaults, "acme"), "billable");
+        assert_eq!(default_tags_for(&defaults, " ACME "), "billable");
+        assert_eq!(default_tags_for(&defaults, "Other"), "");
+        assert_eq!(default_tags_for(&defaults, ""), "");
+    }
+}
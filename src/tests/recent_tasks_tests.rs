@@ -0,0 +1,116 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod recent_tasks_tests {
+    use chrono::{Local, TimeDelta};
+
+    use crate::{
+        helpers::keyboard_nav::NavDirection,
+        models::{
+            fur_task::FurTask,
+            recent_tasks::{RecentTasks, distinct_task_strings},
+        },
+    };
+
+    fn task(name: &str, project: &str, tags: &str, rate: f32) -> FurTask {
+        let start = Local::now();
+        FurTask::new(
+            name.to_string(),
+            start,
+            start + TimeDelta::hours(1),
+            tags.to_string(),
+            project.to_string(),
+            rate,
+            String::new(),
+        )
+    }
+
+    fn recent_tasks() -> RecentTasks {
+        let mut recent_tasks = RecentTasks::new();
+        recent_tasks.set_tasks(
+            vec!["Write report @Work".to_string(), "Email".to_string()],
+            vec!["Standup #meeting".to_string()],
+        );
+        recent_tasks
+    }
+
+    #[test]
+    fn test_task_strings_match_the_task_input() {
+        assert_eq!(
+            distinct_task_strings(&[task("Design", "Client", "ui", 50.0)]),
+            vec!["Design @Client #ui $50.00".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_repeated_tasks_are_listed_once_in_order() {
+        let tasks = [
+            task("Email", "", "", 0.0),
+            task("Design", "Client", "", 0.0),
+            task("Email", "", "", 0.0),
+            task("Design", "Other", "", 0.0),
+        ];
+        assert_eq!(
+            distinct_task_strings(&tasks),
+            vec![
+                "Email".to_string(),
+                "Design @Client".to_string(),
+                "Design @Other".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_focus_moves_from_recent_into_last_week() {
+        let mut recent_tasks = recent_tasks();
+        recent_tasks.open();
+        assert_eq!(recent_tasks.focused_task(), None);
+
+        recent_tasks.move_focus(NavDirection::Down);
+        recent_tasks.move_focus(NavDirection::Down);
+        recent_tasks.move_focus(NavDirection::Down);
+        recent_tasks.move_focus(NavDirection::Down);
+        assert_eq!(
+            recent_tasks.focused_task(),
+            Some(&"Standup #meeting".to_string())
+        );
+
+        recent_tasks.move_focus(NavDirection::Up);
+        assert_eq!(recent_tasks.focused_task(), Some(&"Email".to_string()));
+    }
+
+    #[test]
+    fn test_closing_clears_focus() {
+        let mut recent_tasks = recent_tasks();
+        recent_tasks.open();
+        recent_tasks.move_focus(NavDirection::Down);
+        recent_tasks.close();
+        assert!(!recent_tasks.is_open);
+        assert_eq!(recent_tasks.focused_task(), None);
+    }
+
+    #[test]
+    fn test_refreshing_drops_focus_past_the_end() {
+        let mut recent_tasks = recent_tasks();
+        recent_tasks.open();
+        for _ in 0..3 {
+            recent_tasks.move_focus(NavDirection::Down);
+        }
+        recent_tasks.set_tasks(vec!["Email".to_string()], vec![]);
+        assert_eq!(recent_tasks.focused_task(), None);
+    }
+}
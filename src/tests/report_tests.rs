@@ -0,0 +1,453 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod report_tests {
+    use std::{
+        sync::Arc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        charts::all_charts::{
+            average_earnings_per_task, average_time_per_task, zero_length_task_count,
+        },
+        helpers::tasks::{changed_history_dates, changed_task_dates, group_tasks_into_history},
+        localization::Localization,
+        models::{
+            fur_days_off::FurDaysOff,
+            fur_report::{
+                FurReport, FurReportData, FurSelectionData, ReportRange, WorkdayStats,
+                percent_change,
+            },
+            fur_task::FurTask,
+        },
+        view_enums::{FurBillableFilter, FurComparisonPeriod, FurTaskProperty},
+    };
+
+    fn tagged_task(tags: &str, minutes: i64) -> FurTask {
+        let mut task = task("Task", "", 1, minutes);
+        task.tags = tags.to_string();
+        task
+    }
+
+    fn task_at(day: u32, hour: u32, minute: u32, minutes: i64) -> FurTask {
+        let start = Local
+            .with_ymd_and_hms(2026, 6, day, hour, minute, 0)
+            .unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn task(name: &str, project: &str, days_ago: i64, minutes: i64) -> FurTask {
+        let start =
+            Local.with_ymd_and_hms(2026, 6, 30, 9, 0, 0).unwrap() - TimeDelta::days(days_ago);
+        FurTask::new(
+            name.to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            project.to_string(),
+            25.0,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_aggregating_fifty_thousand_tasks_off_the_ui_thread() {
+        let tasks: Vec<FurTask> = (0..50_000)
+            .map(|i| {
+                task(
+                    &format!("Task {}", i % 40),
+                    &format!("project{}", i % 7),
+                    i % 365,
+                    30,
+                )
+            })
+            .collect();
+
+        let started = Instant::now();
+        let (data, selection) = thread::spawn(move || {
+            let data = FurReportData::from_tasks(tasks, 1000.0, &FurDaysOff::default(), &[]);
+            let selected: Vec<&FurTask> = data
+                .tasks_in_range
+                .iter()
+                .filter(|task| task.project == "project3")
+                .collect();
+            let selection =
+                FurSelectionData::from_tasks(&selected, None, &FurDaysOff::default(), &[]);
+            (data, selection)
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(data.tasks_in_range.len(), 50_000);
+        assert_eq!(data.total_time, 50_000 * 30 * 60);
+        assert!(selection.total_time > 0);
+        assert!(
+            started.elapsed() < Duration::from_secs(30),
+            "aggregation took {:?}",
+            started.elapsed()
+        );
+    }
+
+    #[test]
+    fn test_moved_task_changes_both_dates() {
+        let old_tasks = vec![task("Write", "", 3, 60), task("Read", "", 1, 60)];
+        let mut moved = old_tasks[0].clone();
+        moved.start_time = moved.start_time + TimeDelta::days(1);
+        moved.stop_time = moved.stop_time + TimeDelta::days(1);
+        let new_tasks = vec![moved.clone(), old_tasks[1].clone()];
+
+        let changed = changed_task_dates(&old_tasks, &new_tasks);
+        assert_eq!(changed.len(), 2);
+        assert!(changed.contains(&old_tasks[0].start_time.date_naive()));
+        assert!(changed.contains(&moved.start_time.date_naive()));
+        assert!(!changed.contains(&old_tasks[1].start_time.date_naive()));
+    }
+
+    #[test]
+    fn test_added_and_deleted_tasks_change_their_dates() {
+        let kept = task("Write", "", 5, 60);
+        let deleted = task("Read", "", 4, 60);
+        let added = task("Plan", "", 2, 60);
+
+        let changed = changed_task_dates(&[kept.clone(), deleted.clone()], &[kept, added.clone()]);
+        assert_eq!(
+            changed.into_iter().collect::<Vec<_>>(),
+            vec![
+                deleted.start_time.date_naive(),
+                added.start_time.date_naive()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unchanged_history_has_no_changed_dates() {
+        let history =
+            group_tasks_into_history(vec![task("Write", "", 2, 60), task("Read", "", 1, 60)]);
+        assert!(changed_history_dates(&history, &history.clone()).is_empty());
+
+        let mut renamed = history.clone();
+        let date = *renamed.keys().next().unwrap();
+        renamed.get_mut(&date).unwrap()[0].tasks[0].name = "Edit".to_string();
+        assert_eq!(
+            changed_history_dates(&history, &renamed)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![date]
+        );
+    }
+
+    #[test]
+    fn test_tag_keys_are_individual_tags() {
+        let mut report = FurReport::new();
+        report.set_picked_task_property_key(FurTaskProperty::Tags);
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![
+                tagged_task("client-a #design", 60),
+                tagged_task("design", 30),
+            ],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+        assert_eq!(report.task_property_value_keys, vec!["client-a", "design"]);
+    }
+
+    #[test]
+    fn test_picked_tags_must_all_match() {
+        let mut report = FurReport::new();
+        report.set_picked_task_property_key(FurTaskProperty::Tags);
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![
+                tagged_task("client-a #design", 60),
+                tagged_task("design", 30),
+                tagged_task("client-a #review", 15),
+            ],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+
+        // The first tag is picked by default
+        assert_eq!(report.selected_tasks().len(), 2);
+        assert_eq!(report.selection.total_time, 75 * 60);
+
+        report.toggle_picked_tag("design".to_string());
+        assert_eq!(report.selected_tasks().len(), 1);
+        assert_eq!(report.selection.total_time, 60 * 60);
+
+        report.toggle_picked_tag("client-a".to_string());
+        assert_eq!(report.selected_tasks().len(), 2);
+        assert_eq!(report.selection.total_time, 90 * 60);
+
+        // The last picked tag stays picked
+        report.toggle_picked_tag("design".to_string());
+        assert_eq!(report.picked_tags.len(), 1);
+    }
+
+    #[test]
+    fn test_workday_stats_with_one_task_per_day() {
+        let tasks = [task_at(1, 8, 0, 60), task_at(2, 10, 0, 120)];
+        let stats = WorkdayStats::from_tasks(&tasks.iter().collect::<Vec<&FurTask>>()).unwrap();
+        assert_eq!(stats.active_days, 2);
+        assert_eq!(stats.average_start, 9 * 3600);
+        assert_eq!(stats.average_end, 10 * 3600 + 30 * 60);
+        assert_eq!(stats.average_tracked, 90 * 60);
+    }
+
+    #[test]
+    fn test_workday_stats_overnight_task_counts_toward_its_start_day() {
+        let tasks = [task_at(1, 18, 0, 60), task_at(1, 23, 0, 120)];
+        let stats = WorkdayStats::from_tasks(&tasks.iter().collect::<Vec<&FurTask>>()).unwrap();
+        assert_eq!(stats.active_days, 1);
+        assert_eq!(stats.average_start, 18 * 3600);
+        // Ends at 01:00 the next morning, past 24 hours rather than wrapping
+        assert_eq!(stats.average_end, 25 * 3600);
+        assert_eq!(stats.average_tracked, 180 * 60);
+    }
+
+    #[test]
+    fn test_workday_stats_hidden_for_empty_range() {
+        assert_eq!(WorkdayStats::from_tasks(&[]), None);
+        assert_eq!(
+            FurReportData::from_tasks(vec![], 0.0, &FurDaysOff::default(), &[]).workday_stats,
+            None
+        );
+    }
+
+    #[test]
+    fn test_workday_stats_follow_breakdown_selection() {
+        let mut report = FurReport::new();
+        report.set_picked_task_property_key(FurTaskProperty::Tags);
+        let mut early = task_at(1, 7, 0, 60);
+        early.tags = "early".to_string();
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![early, task_at(2, 11, 0, 60)],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+
+        let stats = report.workday_stats().unwrap();
+        assert_eq!(stats.active_days, 1);
+        assert_eq!(stats.average_start, 7 * 3600);
+        assert_eq!(report.data.workday_stats.unwrap().active_days, 2);
+    }
+
+    fn report_range(start: (i32, u32, u32), end: (i32, u32, u32)) -> ReportRange {
+        ReportRange {
+            start: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+            billable_filter: FurBillableFilter::Billable,
+        }
+    }
+
+    #[test]
+    fn test_previous_period_has_the_same_length() {
+        let range = report_range((2026, 6, 1), (2026, 6, 30));
+        assert_eq!(
+            range.previous(FurComparisonPeriod::PreviousPeriod),
+            report_range((2026, 5, 2), (2026, 5, 31))
+        );
+    }
+
+    #[test]
+    fn test_previous_year_keeps_the_dates() {
+        let range = report_range((2024, 2, 1), (2024, 2, 29));
+        assert_eq!(
+            range.previous(FurComparisonPeriod::PreviousYear),
+            report_range((2023, 2, 1), (2023, 2, 28))
+        );
+    }
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(percent_change(150.0, 100.0), Some(50.0));
+        assert_eq!(percent_change(50.0, 100.0), Some(-50.0));
+        assert_eq!(percent_change(50.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_no_comparison_without_previous_data() {
+        let mut report = FurReport::new();
+        report.compare_with_previous = true;
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![task("Task", "", 1, 60)],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+        assert!(report.comparison.is_none());
+    }
+
+    #[test]
+    fn test_device_keys_name_tasks_without_a_device() {
+        let mut desktop = task("Task", "", 1, 60);
+        desktop.device_name = "desktop".to_string();
+        let mut older = task("Task", "", 1, 30);
+        older.device_name = String::new();
+
+        let mut report = FurReport::new();
+        report.set_picked_task_property_key(FurTaskProperty::Device);
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![desktop, older],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+        assert_eq!(
+            report.task_property_value_keys,
+            vec![
+                "desktop".to_string(),
+                Localization::new().get_message("unknown-device", None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_filter_pins_pick_and_limits_matches() {
+        let tasks = vec![
+            task("Write docs", "", 1, 60),
+            task("Review docs", "", 1, 30),
+            task("Standup", "", 1, 15),
+            task("DOCS triage", "", 1, 20),
+        ];
+        let mut report = FurReport::new();
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            tasks,
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+        report.set_picked_task_property_value("Standup".to_string());
+
+        report.task_property_value_filter = "docs".to_string();
+        assert_eq!(
+            report.filtered_task_property_values(50),
+            vec!["Standup", "DOCS triage", "Review docs", "Write docs"]
+        );
+        assert_eq!(
+            report.filtered_task_property_values(2),
+            vec!["Standup", "DOCS triage"]
+        );
+
+        report.task_property_value_filter = String::new();
+        assert_eq!(report.filtered_task_property_values(50).len(), 4);
+
+        report.task_property_value_filter = "nothing".to_string();
+        assert_eq!(report.filtered_task_property_values(50), vec!["Standup"]);
+    }
+
+    #[test]
+    fn test_grouping_ignores_the_device() {
+        let mut desktop = task("Write", "", 1, 60);
+        desktop.device_name = "desktop".to_string();
+        let mut laptop = task("Write", "", 1, 30);
+        laptop.device_name = "laptop".to_string();
+
+        let history = group_tasks_into_history(vec![desktop, laptop]);
+        let groups = history.values().next().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_export_follows_breakdown_selection() {
+        let mut report = FurReport::new();
+        report.set_picked_task_property_key(FurTaskProperty::Project);
+        report.show_data(Arc::new(FurReportData::from_tasks(
+            vec![
+                task("Task", "clientA", 1, 60),
+                task("Task", "clientB", 2, 30),
+                task("Task", "clientA", 3, 15),
+            ],
+            0.0,
+            &FurDaysOff::default(),
+            &[],
+        )));
+        let (start, end) = report.date_range();
+        let range = format!("{} - {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+
+        assert_eq!(report.export_tasks(false).len(), 3);
+        assert_eq!(report.export_description(false), range);
+
+        assert_eq!(report.export_tasks(true).len(), 2);
+        assert_eq!(
+            report.export_description(true),
+            format!("{}, {}: clientA", range, FurTaskProperty::Project)
+        );
+    }
+
+    #[test]
+    fn test_averages_of_an_empty_range() {
+        assert!(average_time_per_task(&[]).is_empty());
+        assert!(average_earnings_per_task(&[], &[]).is_empty());
+        assert_eq!(zero_length_task_count(&[]), 0);
+    }
+
+    #[test]
+    fn test_averages_leave_out_zero_length_tasks() {
+        let tasks = [
+            task("Task", "", 1, 60),
+            task("Task", "", 1, 0),
+            task("Task", "", 1, 30),
+            task("Task", "", 2, 0),
+        ];
+        let task_refs: Vec<&FurTask> = tasks.iter().collect();
+        let day = tasks[0].start_time.date_naive();
+
+        let average_time = average_time_per_task(&task_refs);
+        assert_eq!(average_time.len(), 1);
+        assert_eq!(average_time[&day], 45 * 60);
+
+        let average_earnings = average_earnings_per_task(&task_refs, &[]);
+        assert_eq!(average_earnings.len(), 1);
+        assert!((average_earnings[&day] - 18.75).abs() < 0.001);
+
+        assert_eq!(zero_length_task_count(&tasks), 2);
+        let data = FurReportData::from_tasks(tasks.to_vec(), 0.0, &FurDaysOff::default(), &[]);
+        assert_eq!(data.zero_length_tasks, 2);
+        assert_eq!(data.tasks_in_range.len(), 4);
+    }
+
+    #[test]
+    fn test_running_task_is_provisional_only_in_range() {
+        let mut report = FurReport::new();
+        let (start, end) = report.date_range();
+
+        report.set_running_time(Some((end, 600)));
+        assert!(report.running_time_chart.is_some());
+
+        report.set_running_time(Some((start - TimeDelta::days(1), 600)));
+        assert!(report.running_time_chart.is_none());
+
+        report.set_running_time(Some((end, 600)));
+        report.set_running_time(None);
+        assert!(report.running_time_chart.is_none());
+    }
+}
@@ -0,0 +1,107 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod scheduled_summary_tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use crate::{
+        helpers::scheduled_summary::{is_summary_due, latest_period, summary_file_name},
+        view_enums::{FurSummaryFormat, FurSummarySchedule},
+    };
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn at(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        date(month, day).and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_off_has_no_period() {
+        assert_eq!(
+            latest_period(FurSummarySchedule::Off, 17, at(6, 5, 18, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_daily_period_waits_for_the_hour() {
+        assert_eq!(
+            latest_period(FurSummarySchedule::Daily, 17, at(6, 5, 16, 59)),
+            Some((date(6, 4), date(6, 4)))
+        );
+        assert_eq!(
+            latest_period(FurSummarySchedule::Daily, 17, at(6, 5, 17, 0)),
+            Some((date(6, 5), date(6, 5)))
+        );
+    }
+
+    #[test]
+    fn test_weekly_period_ends_on_sunday() {
+        // 2025-06-08 is a Sunday
+        assert_eq!(
+            latest_period(FurSummarySchedule::Weekly, 17, at(6, 8, 17, 30)),
+            Some((date(6, 2), date(6, 8)))
+        );
+        assert_eq!(
+            latest_period(FurSummarySchedule::Weekly, 17, at(6, 8, 9, 0)),
+            Some((date(5, 26), date(6, 1)))
+        );
+        assert_eq!(
+            latest_period(FurSummarySchedule::Weekly, 17, at(6, 11, 12, 0)),
+            Some((date(6, 2), date(6, 8)))
+        );
+    }
+
+    #[test]
+    fn test_summary_is_only_due_once() {
+        assert!(is_summary_due(date(6, 8), ""));
+        assert!(is_summary_due(date(6, 8), "2025-06-01"));
+        assert!(!is_summary_due(date(6, 8), "2025-06-08"));
+        assert!(!is_summary_due(date(6, 1), "2025-06-08"));
+    }
+
+    #[test]
+    fn test_missed_week_is_caught_up_once() {
+        // Closed over the weekend, opened on Tuesday
+        let (_, period_end) =
+            latest_period(FurSummarySchedule::Weekly, 17, at(6, 10, 8, 0)).unwrap();
+        assert!(is_summary_due(period_end, "2025-06-01"));
+        assert!(!is_summary_due(period_end, &period_end.to_string()));
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert_eq!(
+            summary_file_name(
+                FurSummarySchedule::Weekly,
+                date(6, 2),
+                FurSummaryFormat::Markdown
+            ),
+            "furtherance-summary-2025-W23.md"
+        );
+        assert_eq!(
+            summary_file_name(
+                FurSummarySchedule::Daily,
+                date(6, 5),
+                FurSummaryFormat::Html
+            ),
+            "furtherance-summary-2025-06-05.html"
+        );
+    }
+}
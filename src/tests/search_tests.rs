@@ -0,0 +1,91 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod search_tests {
+    use chrono::{Local, NaiveDate, TimeZone};
+
+    use crate::{
+        helpers::search::{group_results_by_date, highlight_segments, search_terms},
+        models::fur_task::FurTask,
+    };
+
+    fn task_on(date: NaiveDate, hour: u32) -> FurTask {
+        let start = Local
+            .from_local_datetime(&date.and_hms_opt(hour, 0, 0).unwrap())
+            .unwrap();
+        FurTask::new(
+            "Invoice bug".to_string(),
+            start,
+            start + chrono::TimeDelta::hours(1),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_search_terms_are_lowercase_words() {
+        assert_eq!(search_terms("  Invoice   BUG "), vec!["invoice", "bug"]);
+        assert!(search_terms("   ").is_empty());
+    }
+
+    #[test]
+    fn test_highlight_segments_marks_every_match() {
+        let terms = search_terms("bug");
+        assert_eq!(
+            highlight_segments("Bug in debugger", &terms),
+            vec![
+                ("Bug".to_string(), true),
+                (" in de".to_string(), false),
+                ("bug".to_string(), true),
+                ("ger".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_segments_merges_overlapping_terms() {
+        let terms = search_terms("invo voice");
+        assert_eq!(
+            highlight_segments("Invoices", &terms),
+            vec![("Invoice".to_string(), true), ("s".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_highlight_segments_without_match() {
+        let terms = search_terms("xyz");
+        assert_eq!(
+            highlight_segments("Invoice", &terms),
+            vec![("Invoice".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_results_are_grouped_newest_day_first() {
+        let older = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let newer = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let results = vec![task_on(newer, 14), task_on(newer, 9), task_on(older, 10)];
+
+        let grouped = group_results_by_date(&results);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].0, newer);
+        assert_eq!(grouped[0].1.len(), 2);
+        assert_eq!(grouped[1].0, older);
+    }
+}
@@ -0,0 +1,115 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod settings_tests {
+    use iced::{Point, Size};
+
+    use crate::models::fur_settings::{FurSettings, SETTINGS_EXPORT_VERSION, SettingsExport};
+    use crate::update::msg_helper_functions::clamp_window_position;
+    use crate::view_enums::FurThemePreference;
+
+    #[test]
+    fn test_export_leaves_out_local_settings() {
+        let export = FurSettings::default().to_export().unwrap();
+        assert_eq!(export.version, SETTINGS_EXPORT_VERSION);
+        assert!(export.settings.contains_key("days_to_show"));
+        assert!(export.settings.contains_key("pomodoro_length"));
+        assert!(!export.settings.contains_key("database_url"));
+        assert!(!export.settings.contains_key("last_sync"));
+        assert!(!export.settings.contains_key("needs_full_sync"));
+        assert!(!export.settings.contains_key("window_width"));
+        assert!(!export.settings.contains_key("window_x"));
+    }
+
+    #[test]
+    fn test_window_size_is_only_restored_once_saved() {
+        let mut settings = FurSettings::default();
+        assert_eq!(settings.saved_window_size(), None);
+        settings.window_width = 1280.0;
+        settings.window_height = 800.0;
+        assert_eq!(settings.saved_window_size(), Some((1280.0, 800.0)));
+    }
+
+    #[test]
+    fn test_window_position_is_clamped_to_the_display() {
+        let display = Size::new(1920.0, 1080.0);
+        let window = Size::new(1024.0, 600.0);
+        assert_eq!(
+            clamp_window_position(Point::new(100.0, 50.0), window, display),
+            Point::new(100.0, 50.0)
+        );
+        // Saved on a second monitor that's no longer connected
+        assert_eq!(
+            clamp_window_position(Point::new(2500.0, 900.0), window, display),
+            Point::new(896.0, 480.0)
+        );
+        assert_eq!(
+            clamp_window_position(Point::new(-300.0, -20.0), window, display),
+            Point::new(0.0, 0.0)
+        );
+        assert_eq!(
+            clamp_window_position(Point::new(40.0, 40.0), Size::new(2560.0, 1440.0), display),
+            Point::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_newer_export_still_parses() {
+        let json = r#"{
+            "version": 99,
+            "exported_by": "a future version",
+            "settings": { "days_to_show": 30, "a_future_setting": true }
+        }"#;
+        let export: SettingsExport = serde_json::from_str(json).unwrap();
+        assert_eq!(export.settings.len(), 2);
+    }
+
+    #[test]
+    fn test_theme_preference_exports_by_name() {
+        let export = FurSettings::default().to_export().unwrap();
+        let value = export.settings.get("theme_preference").unwrap();
+        assert_eq!(value, &serde_json::json!("System"));
+        let preference: FurThemePreference =
+            serde_json::from_value(serde_json::json!("Dark")).unwrap();
+        assert_eq!(preference, FurThemePreference::Dark);
+    }
+
+    #[test]
+    fn test_recover_keeps_readable_settings_from_truncated_file() {
+        let mut settings = FurSettings::default();
+        settings.database_url = "/custom/place/furtherance.db".to_string();
+        settings.days_to_show = 30;
+        let toml = toml::to_string(&settings).unwrap();
+        let truncated = &toml[..toml.find("warn_if_longer_than_hours").unwrap() + 5];
+
+        let (recovered, reset) = FurSettings::recover_from_str(truncated);
+        assert_eq!(recovered.database_url, "/custom/place/furtherance.db");
+        assert_eq!(recovered.days_to_show, 30);
+        assert_eq!(reset, vec!["warn_if_longer_than_hours".to_string()]);
+        assert!(!recovered.first_run);
+    }
+
+    #[test]
+    fn test_recover_resets_values_of_the_wrong_type() {
+        let contents = "database_url = \"/data/fur.db\"\ndays_to_show = \"lots\"\n\u{0}garbage";
+        let (recovered, reset) = FurSettings::recover_from_str(contents);
+        assert_eq!(recovered.database_url, "/data/fur.db");
+        assert_eq!(recovered.days_to_show, FurSettings::default().days_to_show);
+        assert!(reset.contains(&"days_to_show".to_string()));
+        assert!(!reset.contains(&"database_url".to_string()));
+    }
+}
@@ -0,0 +1,200 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod shortcut_tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        models::fur_shortcut::{
+            FurShortcut, SHORTCUTS_EXPORT_VERSION, ShortcutMove, ShortcutRateChange, ShortcutUsage,
+            ShortcutsExport, is_valid_color_hex, move_shortcut, sort_shortcuts,
+        },
+        view_enums::FurShortcutSort,
+    };
+
+    fn shortcut(color_hex: &str) -> FurShortcut {
+        FurShortcut::new(
+            "Design".to_string(),
+            "#client #ui".to_string(),
+            "Website".to_string(),
+            45.0,
+            "EUR".to_string(),
+            color_hex.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_shortcuts_round_trip_through_json() {
+        let mut original = shortcut("#A1B2C3");
+        original.device_name = "laptop".to_string();
+        let json = serde_json::to_string(&ShortcutsExport::new(&[original.clone()])).unwrap();
+        let export: ShortcutsExport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(export.version, SHORTCUTS_EXPORT_VERSION);
+        let imported = export.shortcuts[0].clone().into_shortcut();
+        assert_eq!(imported.name, original.name);
+        assert_eq!(imported.project, original.project);
+        assert_eq!(imported.tags, original.tags);
+        assert_eq!(imported.rate, original.rate);
+        assert_eq!(imported.currency, original.currency);
+        assert_eq!(imported.color_hex, original.color_hex);
+        assert_eq!(imported.uid, original.uid);
+        assert_eq!(imported.device_name, original.device_name);
+    }
+
+    #[test]
+    fn test_invalid_color_is_replaced() {
+        for color_hex in ["", "#12", "not a color", "#GGGGGG", "#ééé"] {
+            let export = ShortcutsExport::new(&[shortcut(color_hex)]);
+            let imported = export.shortcuts[0].clone().into_shortcut();
+            assert!(is_valid_color_hex(&imported.color_hex), "{}", color_hex);
+        }
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let json = r#"{"version": 1, "shortcuts": [{"name": "Email"}]}"#;
+        let export: ShortcutsExport = serde_json::from_str(json).unwrap();
+        let imported = export.shortcuts[0].clone().into_shortcut();
+
+        assert_eq!(imported.name, "Email");
+        assert!(imported.project.is_empty());
+        assert_eq!(imported.rate, 0.0);
+        assert!(!imported.uid.is_empty());
+        assert!(is_valid_color_hex(&imported.color_hex));
+    }
+
+    fn named(name: &str) -> FurShortcut {
+        FurShortcut::new(
+            name.to_string(),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+            "#A1B2C3".to_string(),
+        )
+    }
+
+    fn sorted_names(
+        shortcuts: &[FurShortcut],
+        usage: &HashMap<String, ShortcutUsage>,
+        sort: FurShortcutSort,
+    ) -> Vec<String> {
+        sort_shortcuts(shortcuts, usage, sort)
+            .into_iter()
+            .map(|s| s.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_shortcuts_by_usage() {
+        let shortcuts = vec![named("write"), named("Admin"), named("email")];
+        let usage = HashMap::from([
+            (
+                shortcuts[0].uid.clone(),
+                ShortcutUsage {
+                    count: 2,
+                    last_used: Some(100),
+                },
+            ),
+            (
+                shortcuts[2].uid.clone(),
+                ShortcutUsage {
+                    count: 7,
+                    last_used: Some(50),
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            sorted_names(&shortcuts, &usage, FurShortcutSort::Manual),
+            vec!["write", "Admin", "email"]
+        );
+        assert_eq!(
+            sorted_names(&shortcuts, &usage, FurShortcutSort::Alphabetical),
+            vec!["Admin", "email", "write"]
+        );
+        assert_eq!(
+            sorted_names(&shortcuts, &usage, FurShortcutSort::MostUsed),
+            vec!["email", "write", "Admin"]
+        );
+        assert_eq!(
+            sorted_names(&shortcuts, &usage, FurShortcutSort::RecentlyUsed),
+            vec!["write", "email", "Admin"]
+        );
+    }
+
+    #[test]
+    fn test_move_shortcut_renumbers_sort_order() {
+        let mut shortcuts = vec![named("write"), named("Admin"), named("email")];
+        let uid = shortcuts[2].uid.clone();
+
+        assert!(move_shortcut(&mut shortcuts, &uid, ShortcutMove::Left));
+        assert_eq!(
+            sorted_names(&shortcuts, &HashMap::new(), FurShortcutSort::Manual),
+            vec!["write", "email", "Admin"]
+        );
+        assert!(move_shortcut(&mut shortcuts, &uid, ShortcutMove::Front));
+        assert_eq!(shortcuts[0].uid, uid);
+        assert_eq!(
+            shortcuts.iter().map(|s| s.sort_order).collect::<Vec<i64>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_move_shortcut_stops_at_the_ends() {
+        let mut shortcuts = vec![named("write"), named("Admin")];
+        let first = shortcuts[0].uid.clone();
+        let last = shortcuts[1].uid.clone();
+
+        assert!(!move_shortcut(&mut shortcuts, &first, ShortcutMove::Left));
+        assert!(!move_shortcut(&mut shortcuts, &first, ShortcutMove::Front));
+        assert!(!move_shortcut(&mut shortcuts, &last, ShortcutMove::Right));
+        assert!(!move_shortcut(
+            &mut shortcuts,
+            "missing",
+            ShortcutMove::Right
+        ));
+    }
+
+    #[test]
+    fn test_sort_order_is_not_synced() {
+        let mut shortcut = named("write");
+        shortcut.sort_order = 4;
+        let json = serde_json::to_string(&shortcut).unwrap();
+        let received: FurShortcut = serde_json::from_str(&json).unwrap();
+        assert_eq!(received.sort_order, 0);
+    }
+
+    #[test]
+    fn test_rate_change_recorded_only_when_rate_differs() {
+        assert_eq!(
+            ShortcutRateChange::between(45.0, 60.0, 1_700_000_000),
+            Some(ShortcutRateChange {
+                old_rate: 45.0,
+                new_rate: 60.0,
+                changed_at: 1_700_000_000,
+            })
+        );
+        assert_eq!(ShortcutRateChange::between(45.0, 45.0, 1_700_000_000), None);
+        assert_eq!(
+            ShortcutRateChange::between(45.0, 45.001, 1_700_000_000),
+            None
+        );
+    }
+}
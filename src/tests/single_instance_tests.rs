@@ -0,0 +1,54 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod single_instance_tests {
+    use std::path::PathBuf;
+
+    use crate::single_instance::{InstanceLock, acquire_instance_lock, raise_running_instance};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "furtherance-instance-test-{}.{}",
+            rand::random::<u64>(),
+            name
+        ))
+    }
+
+    #[test]
+    fn test_second_launch_finds_the_first() {
+        let lock_path = temp_path("lock");
+        let port_path = temp_path("port");
+
+        let first = acquire_instance_lock(&lock_path, &port_path).unwrap();
+        assert!(matches!(first, InstanceLock::Acquired(_)));
+        assert!(matches!(
+            acquire_instance_lock(&lock_path, &port_path).unwrap(),
+            InstanceLock::HeldElsewhere
+        ));
+        assert!(raise_running_instance(&port_path).is_ok());
+
+        // Quitting releases the lock for the next launch
+        drop(first);
+        assert!(matches!(
+            acquire_instance_lock(&lock_path, &port_path).unwrap(),
+            InstanceLock::Acquired(_)
+        ));
+
+        let _ = std::fs::remove_file(lock_path);
+        let _ = std::fs::remove_file(port_path);
+    }
+}
@@ -0,0 +1,47 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod sound_tests {
+    use crate::{helpers::sounds::FurSound, models::fur_settings::FurSettings};
+
+    #[test]
+    fn test_sounds_are_off_by_default() {
+        let settings = FurSettings::default();
+        assert!(!FurSound::TimerStarted.is_enabled(&settings));
+        assert!(!FurSound::TimerStopped.is_enabled(&settings));
+        assert!(!FurSound::Idle.is_enabled(&settings));
+    }
+
+    #[test]
+    fn test_each_sound_follows_its_own_toggle() {
+        let mut settings = FurSettings::default();
+        settings.sound_on_start_stop = true;
+        assert!(FurSound::TimerStarted.is_enabled(&settings));
+        assert!(FurSound::TimerStopped.is_enabled(&settings));
+        assert!(!FurSound::Idle.is_enabled(&settings));
+    }
+
+    #[test]
+    fn test_master_toggle_mutes_every_sound() {
+        let mut settings = FurSettings::default();
+        settings.sound_on_start_stop = true;
+        settings.sound_on_idle = true;
+        settings.sounds_enabled = false;
+        assert!(!FurSound::TimerStarted.is_enabled(&settings));
+        assert!(!FurSound::Idle.is_enabled(&settings));
+    }
+}
@@ -0,0 +1,132 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+#[cfg(test)]
+mod status_server_tests {
+    use chrono::{Local, TimeDelta, TimeZone};
+    use serde_json::Value;
+
+    use crate::status_server::{PomodoroSnapshot, StatusSnapshot, route};
+
+    const KEY: &str = "0123456789abcdef";
+
+    fn running_snapshot() -> StatusSnapshot {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap();
+        StatusSnapshot {
+            task: "Write report".to_string(),
+            project: "Client".to_string(),
+            tags: vec!["writing".to_string()],
+            rate: 60.0,
+            start_time: Some(now - TimeDelta::minutes(30)),
+            paused_at: None,
+            pomodoro: PomodoroSnapshot {
+                enabled: true,
+                on_break: false,
+                end_time: Some(now + TimeDelta::minutes(10)),
+            },
+            today_seconds: 3600,
+            today_earnings: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_status_requires_api_key() {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap();
+        let snapshot = running_snapshot();
+
+        let (status, _) = route("GET", "/status", None, KEY, &snapshot, now);
+        assert_eq!(status, "401 Unauthorized");
+        let (status, _) = route("GET", "/status", Some("Bearer wrong"), KEY, &snapshot, now);
+        assert_eq!(status, "401 Unauthorized");
+        let (status, _) = route("GET", "/status", Some("Bearer "), "", &snapshot, now);
+        assert_eq!(status, "401 Unauthorized");
+    }
+
+    #[test]
+    fn test_status_response() {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap();
+        let authorization = format!("Bearer {KEY}");
+        let (status, body) = route(
+            "GET",
+            "/status",
+            Some(&authorization),
+            KEY,
+            &running_snapshot(),
+            now,
+        );
+        assert_eq!(status, "200 OK");
+
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["running"], true);
+        assert_eq!(json["paused"], false);
+        assert_eq!(json["task"], "Write report");
+        assert_eq!(json["project"], "Client");
+        assert_eq!(json["tags"][0], "writing");
+        assert_eq!(json["elapsed_seconds"], 1800);
+        assert_eq!(json["pomodoro"]["remaining_seconds"], 600);
+    }
+
+    #[test]
+    fn test_today_includes_running_timer() {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap();
+        let authorization = format!("Bearer {KEY}");
+        let (status, body) = route(
+            "GET",
+            "/today?format=json",
+            Some(&authorization),
+            KEY,
+            &running_snapshot(),
+            now,
+        );
+        assert_eq!(status, "200 OK");
+
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["date"], "2025-03-14");
+        assert_eq!(json["total_seconds"], 5400);
+        assert_eq!(json["earnings"], 80.0);
+    }
+
+    #[test]
+    fn test_paused_timer_stops_counting() {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap();
+        let authorization = format!("Bearer {KEY}");
+        let mut snapshot = running_snapshot();
+        snapshot.paused_at = Some(now - TimeDelta::minutes(15));
+
+        let (_, body) = route("GET", "/status", Some(&authorization), KEY, &snapshot, now);
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["running"], true);
+        assert_eq!(json["paused"], true);
+        assert_eq!(json["elapsed_seconds"], 15 * 60);
+        assert_eq!(json["pomodoro"]["remaining_seconds"], 25 * 60);
+
+        let (_, body) = route("GET", "/today", Some(&authorization), KEY, &snapshot, now);
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["total_seconds"], 3600 + 15 * 60);
+        assert_eq!(json["earnings"], 65.0);
+    }
+
+    #[test]
+    fn test_unknown_routes() {
+        let now = Local::now();
+        let authorization = format!("Bearer {KEY}");
+        let snapshot = StatusSnapshot::default();
+
+        let (status, _) = route("GET", "/tasks", Some(&authorization), KEY, &snapshot, now);
+        assert_eq!(status, "404 Not Found");
+        let (status, _) = route("POST", "/status", Some(&authorization), KEY, &snapshot, now);
+        assert_eq!(status, "405 Method Not Allowed");
+    }
+}
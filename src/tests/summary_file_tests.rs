@@ -0,0 +1,133 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod summary_file_tests {
+    use std::time::Duration;
+
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        constants::SUMMARY_FILE_DAYS,
+        helpers::summary_file::{RunningSummary, build_summary, write_delay},
+        models::fur_task::FurTask,
+    };
+
+    fn task_on(date: NaiveDate, hours: i64, project: &str, rate: f32) -> FurTask {
+        let start = Local
+            .from_local_datetime(&date.and_hms_opt(9, 0, 0).unwrap())
+            .unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::hours(hours),
+            String::new(),
+            project.to_string(),
+            rate,
+            String::new(),
+        )
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()
+    }
+
+    #[test]
+    fn test_every_day_in_range_is_listed() {
+        let summary = build_summary(&[], today(), None, Local::now(), &[]);
+
+        assert_eq!(summary.days.len(), SUMMARY_FILE_DAYS as usize);
+        assert_eq!(summary.days.last().unwrap().date, "2025-03-31");
+        assert!(summary.days.iter().all(|day| day.seconds == 0));
+        assert!(summary.projects.is_empty());
+    }
+
+    #[test]
+    fn test_tasks_are_totaled_by_day_and_project() {
+        let yesterday = today() - TimeDelta::days(1);
+        let tasks = vec![
+            task_on(today(), 2, "Work", 10.0),
+            task_on(today(), 1, "Home", 0.0),
+            task_on(yesterday, 1, "Work", 10.0),
+        ];
+
+        let summary = build_summary(&tasks, today(), None, Local::now(), &[]);
+
+        let today_total = summary.days.last().unwrap();
+        assert_eq!(today_total.seconds, 3 * 3600);
+        assert_eq!(today_total.earnings, 20.0);
+        let yesterday_total = &summary.days[summary.days.len() - 2];
+        assert_eq!(yesterday_total.seconds, 3600);
+
+        assert_eq!(summary.projects.len(), 2);
+        let work = summary
+            .projects
+            .iter()
+            .find(|project| project.project == "Work")
+            .unwrap();
+        assert_eq!(work.seconds, 3 * 3600);
+        assert_eq!(work.earnings, 30.0);
+    }
+
+    #[test]
+    fn test_tasks_outside_the_range_are_ignored() {
+        let too_old = today() - TimeDelta::days(SUMMARY_FILE_DAYS);
+        let summary = build_summary(
+            &[task_on(too_old, 1, "Work", 0.0)],
+            today(),
+            None,
+            Local::now(),
+            &[],
+        );
+
+        assert!(summary.days.iter().all(|day| day.seconds == 0));
+        assert!(summary.projects.is_empty());
+    }
+
+    #[test]
+    fn test_running_task_is_split_into_parts() {
+        let start = Local::now();
+        let running = RunningSummary::new(
+            "Write report @Work #writing",
+            start,
+            start + TimeDelta::minutes(5),
+        );
+
+        assert_eq!(running.task, "Write report");
+        assert_eq!(running.project, "Work");
+        assert_eq!(running.tags, "writing");
+        assert_eq!(running.elapsed_seconds, 300);
+    }
+
+    #[test]
+    fn test_first_write_is_not_delayed() {
+        assert_eq!(write_delay(None, Local::now(), &[]), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_writes_are_throttled() {
+        let now = Local::now();
+
+        assert_eq!(
+            write_delay(Some(now - TimeDelta::seconds(10)), now),
+            Duration::from_secs(20)
+        );
+        assert_eq!(
+            write_delay(Some(now - TimeDelta::seconds(45)), now),
+            Duration::ZERO
+        );
+    }
+}
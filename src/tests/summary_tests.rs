@@ -0,0 +1,150 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod summary_tests {
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        helpers::formatting::format_currency, helpers::summaries::period_summary,
+        localization::Localization, models::fur_task::FurTask, view_enums::FurSummaryFormat,
+    };
+
+    fn task(name: &str, project: &str, day: u32, minutes: i64) -> FurTask {
+        let start = Local.with_ymd_and_hms(2026, 6, day, 9, 0, 0).unwrap();
+        FurTask::new(
+            name.to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            project.to_string(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn week_tasks() -> Vec<FurTask> {
+        vec![
+            task("Write *report*", "client_a", 8, 90),
+            task("Email", "", 9, 15),
+            task("Review", "client_a", 10, 30),
+            task("Write *report*", "client_a", 11, 30),
+        ]
+    }
+
+    fn week() -> (NaiveDate, NaiveDate) {
+        (
+            NaiveDate::from_ymd_opt(2026, 6, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 6, 14).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_markdown_summary() {
+        let (start, end) = week();
+        let summary = period_summary(
+            &week_tasks(),
+            start,
+            end,
+            FurSummaryFormat::Markdown,
+            false,
+            &[],
+            &Localization::new_with_locale("en-US"),
+        );
+        assert_eq!(
+            summary,
+            "## 2026-06-08 – 2026-06-14\n\n\
+             - **client\\_a** — 2.50 h\n\
+             \x20 - Write \\*report\\* (2.00 h)\n\
+             \x20 - Review (0.50 h)\n\
+             - **No project** — 0.25 h\n\
+             \x20 - Email (0.25 h)\n\
+             \n**Total:** 2.75 h\n"
+        );
+    }
+
+    #[test]
+    fn test_org_summary_is_not_escaped() {
+        let (start, end) = week();
+        let summary = period_summary(
+            &week_tasks(),
+            start,
+            end,
+            FurSummaryFormat::Org,
+            false,
+            &[],
+            &Localization::new_with_locale("en-US"),
+        );
+        assert!(summary.starts_with("* 2026-06-08 – 2026-06-14\n\n- *client_a* — 2.50 h\n"));
+        assert!(summary.contains("  - Write *report* (2.00 h)\n"));
+        assert!(summary.ends_with("\n*Total:* 2.75 h\n"));
+    }
+
+    #[test]
+    fn test_empty_week_still_has_a_total() {
+        let (start, end) = week();
+        let summary = period_summary(
+            &[],
+            start,
+            end,
+            FurSummaryFormat::Markdown,
+            false,
+            &[],
+            &Localization::new_with_locale("en-US"),
+        );
+        assert_eq!(
+            summary,
+            "## 2026-06-08 – 2026-06-14\n\n\n**Total:** 0.00 h\n"
+        );
+    }
+
+    #[test]
+    fn test_html_summary_is_escaped() {
+        let (start, end) = week();
+        let summary = period_summary(
+            &[task("Fix <div> & co", "client_a", 8, 60)],
+            start,
+            end,
+            FurSummaryFormat::Html,
+            false,
+            &[],
+            &Localization::new_with_locale("en-US"),
+        );
+        assert!(summary.contains("<h2>2026-06-08 – 2026-06-14</h2>\n"));
+        assert!(summary.contains("<li><strong>client_a</strong> — 1.00 h\n"));
+        assert!(summary.contains("<li>Fix &lt;div&gt; &amp; co (1.00 h)</li>\n"));
+        assert!(summary.ends_with("<p><strong>Total:</strong> 1.00 h</p>\n"));
+    }
+
+    #[test]
+    fn test_summary_with_earnings() {
+        let (start, end) = week();
+        let mut paid = task("Review", "client_a", 8, 120);
+        paid.rate = 25.0;
+        let summary = period_summary(
+            &[paid],
+            start,
+            end,
+            FurSummaryFormat::Markdown,
+            true,
+            &[],
+            &Localization::new_with_locale("en-US"),
+        );
+        let earnings = format_currency(50.0, "");
+        assert!(summary.contains(&format!("- **client\\_a** — 2.00 h · {}\n", earnings)));
+        assert!(summary.ends_with(&format!("\n**Total:** 2.00 h · {}\n", earnings)));
+    }
+}
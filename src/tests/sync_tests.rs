@@ -0,0 +1,251 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod sync_tests {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
+    use crate::{
+        models::{
+            fur_shortcut::EncryptedShortcut,
+            fur_sync_record::FurSyncRecord,
+            fur_task::{EncryptedTask, FurTask},
+        },
+        server::{
+            login::{
+                ApiError, LoginResponse, NetworkFailure, ServerUrlError, classify_network_error,
+                incompatible_version_error, is_unencrypted_server, normalize_server_url,
+                parse_response,
+            },
+            sync::{SyncResponse, approximate_payload_bytes},
+        },
+        update::msg_helper_functions::sync_retry_delay,
+        view_enums::FurSyncInterval,
+    };
+
+    #[test]
+    fn test_sync_retry_delay_doubles() {
+        assert_eq!(sync_retry_delay(1), Duration::from_secs(30));
+        assert_eq!(sync_retry_delay(2), Duration::from_secs(60));
+        assert_eq!(sync_retry_delay(4), Duration::from_secs(240));
+    }
+
+    #[test]
+    fn test_sync_retry_delay_is_capped() {
+        assert_eq!(sync_retry_delay(6), Duration::from_secs(900));
+        assert_eq!(sync_retry_delay(u32::MAX), Duration::from_secs(900));
+    }
+
+    #[test]
+    fn test_version_error_code_is_incompatible() {
+        let body = r#"{"error": "version_mismatch", "message": "Please update"}"#;
+        assert!(matches!(
+            incompatible_version_error(StatusCode::BAD_REQUEST, body),
+            Some(ApiError::IncompatibleVersion(msg)) if msg == "Please update"
+        ));
+    }
+
+    #[test]
+    fn test_upgrade_required_is_incompatible() {
+        assert!(matches!(
+            incompatible_version_error(StatusCode::UPGRADE_REQUIRED, ""),
+            Some(ApiError::IncompatibleVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_other_errors_are_not_version_errors() {
+        let body = r#"{"error": "inactive_subscription"}"#;
+        assert!(incompatible_version_error(StatusCode::FORBIDDEN, body).is_none());
+        assert!(incompatible_version_error(StatusCode::INTERNAL_SERVER_ERROR, "oops").is_none());
+    }
+
+    #[test]
+    fn test_unknown_response_shape_is_incompatible() {
+        let body = r#"{"server_time": 5, "changes": []}"#;
+        assert!(matches!(
+            parse_response::<SyncResponse>(body),
+            Err(ApiError::IncompatibleVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_garbled_response_is_a_server_error() {
+        assert!(matches!(
+            parse_response::<LoginResponse>("<html>Bad gateway</html>"),
+            Err(ApiError::Server(_))
+        ));
+        let response: LoginResponse =
+            parse_response(r#"{"access_token": "a", "refresh_token": "r"}"#).unwrap();
+        assert_eq!(response.access_token, "a");
+    }
+
+    #[test]
+    fn test_tasks_from_older_clients_have_no_device() {
+        let json = r#"{
+            "name": "Task",
+            "start_time": "2026-06-01T09:00:00+00:00",
+            "stop_time": "2026-06-01T10:00:00+00:00",
+            "tags": "",
+            "project": "",
+            "rate": 0.0,
+            "currency": "",
+            "uid": "abc",
+            "is_deleted": false,
+            "last_updated": 0
+        }"#;
+        let task: FurTask = serde_json::from_str(json).unwrap();
+        assert!(task.device_name.is_empty());
+    }
+
+    #[test]
+    fn test_server_url_without_scheme_uses_https() {
+        assert_eq!(
+            normalize_server_url("  myserver.local:8080/ "),
+            Ok("https://myserver.local:8080".to_string())
+        );
+    }
+
+    #[test]
+    fn test_server_url_keeps_path_and_http() {
+        assert_eq!(
+            normalize_server_url("http://example.com/furtherance//"),
+            Ok("http://example.com/furtherance".to_string())
+        );
+        assert!(is_unencrypted_server("http://example.com"));
+        assert!(!is_unencrypted_server("https://example.com"));
+    }
+
+    #[test]
+    fn test_invalid_server_urls_are_rejected() {
+        assert_eq!(normalize_server_url("   "), Err(ServerUrlError::Empty));
+        assert_eq!(
+            normalize_server_url("ftp://example.com"),
+            Err(ServerUrlError::UnsupportedScheme)
+        );
+        assert_eq!(
+            normalize_server_url("my server.local"),
+            Err(ServerUrlError::Invalid)
+        );
+        assert_eq!(
+            normalize_server_url("https://example.com/?a=b"),
+            Err(ServerUrlError::Invalid)
+        );
+    }
+
+    #[derive(Debug)]
+    struct ChainedError {
+        message: &'static str,
+        source: Option<Box<ChainedError>>,
+    }
+
+    impl std::fmt::Display for ChainedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.message)
+        }
+    }
+
+    impl std::error::Error for ChainedError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.source
+                .as_deref()
+                .map(|source| source as &(dyn std::error::Error + 'static))
+        }
+    }
+
+    fn wrapped(inner: &'static str) -> ChainedError {
+        ChainedError {
+            message: "error sending request for url (https://example.com/api/login)",
+            source: Some(Box::new(ChainedError {
+                message: "client error (Connect)",
+                source: Some(Box::new(ChainedError {
+                    message: inner,
+                    source: None,
+                })),
+            })),
+        }
+    }
+
+    #[test]
+    fn test_network_errors_are_classified_through_the_chain() {
+        assert_eq!(
+            classify_network_error(&wrapped("dns error: failed to lookup address information")),
+            NetworkFailure::Dns
+        );
+        assert_eq!(
+            classify_network_error(&wrapped("invalid peer certificate: UnknownIssuer")),
+            NetworkFailure::Certificate
+        );
+        assert_eq!(
+            classify_network_error(&wrapped("Connection refused (os error 111)")),
+            NetworkFailure::Other
+        );
+    }
+
+    #[test]
+    fn test_sync_interval_from_stored_minutes() {
+        assert_eq!(FurSyncInterval::from_minutes(0), FurSyncInterval::Manual);
+        assert_eq!(
+            FurSyncInterval::from_minutes(30),
+            FurSyncInterval::Minutes(30)
+        );
+        assert_eq!(
+            FurSyncInterval::from_minutes(1),
+            FurSyncInterval::Minutes(5)
+        );
+        assert_eq!(
+            FurSyncInterval::from_minutes(600),
+            FurSyncInterval::Minutes(120)
+        );
+        assert_eq!(FurSyncInterval::Manual.minutes(), 0);
+    }
+
+    #[test]
+    fn test_payload_size_grows_with_the_records() {
+        let task = |uid: &str| EncryptedTask {
+            encrypted_data: "a".repeat(200),
+            nonce: "n".repeat(24),
+            uid: uid.to_string(),
+            last_updated: 1_700_000_000,
+        };
+        let empty = approximate_payload_bytes(&[], &[], &[]);
+        let one = approximate_payload_bytes(&[task("1")], &[], &[]);
+        let two = approximate_payload_bytes(&[task("1"), task("2")], &[], &[]);
+        assert_eq!(empty, "[][][]".len());
+        assert!(one > empty + 224);
+        assert_eq!(two - one, one - empty + 1);
+
+        let shortcut = EncryptedShortcut {
+            encrypted_data: "b".repeat(100),
+            nonce: "n".repeat(24),
+            uid: "s".to_string(),
+            last_updated: 1_700_000_000,
+        };
+        assert!(approximate_payload_bytes(&[task("1")], &[shortcut], &[]) > one + 124);
+    }
+
+    #[test]
+    fn test_sync_record_counts_both_directions() {
+        let record = FurSyncRecord {
+            uploaded: 3,
+            downloaded: 9,
+            ..FurSyncRecord::default()
+        };
+        assert_eq!(record.items(), 12);
+    }
+}
@@ -0,0 +1,271 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod task_input_tests {
+    use chrono::{Local, TimeDelta};
+
+    use crate::{
+        models::{fur_shortcut::FurShortcut, fur_task::FurTask, fur_task_group::FurTaskGroup},
+        update::msg_helper_functions::{
+            accepted_task_input, escape_task_input_symbols, has_unescaped_task_symbol,
+            normalize_tags, split_logged_duration, split_task_input, task_input_is_billable,
+            task_input_literal, task_input_planned_seconds, unescape_task_input_text,
+        },
+    };
+
+    fn split(input: &str) -> (String, String, String, f32) {
+        split_task_input(input)
+    }
+
+    fn parts(name: &str, project: &str, tags: &str, rate: f32) -> (String, String, String, f32) {
+        (
+            name.to_string(),
+            project.to_string(),
+            tags.to_string(),
+            rate,
+        )
+    }
+
+    fn task(name: &str, project: &str, tags: &str, rate: f32) -> FurTask {
+        let stop_time = Local::now();
+        FurTask::new(
+            name.to_string(),
+            stop_time - TimeDelta::minutes(30),
+            stop_time,
+            tags.to_string(),
+            project.to_string(),
+            rate,
+            String::new(),
+        )
+    }
+
+    const AWKWARD_NAMES: [&str; 14] = [
+        "Plain name",
+        "Email @ home",
+        "Budget #2",
+        "Pay $5 fee",
+        "Say \"hi\"",
+        "\"Quoted\"",
+        "C:\\Temp\\",
+        "Ends with \\",
+        "a\\@b",
+        "\\\\server",
+        "Wait !",
+        "Run ~5m drills",
+        "Standup 15m",
+        "1h",
+    ];
+
+    #[test]
+    fn test_quoted_name_keeps_symbols() {
+        assert_eq!(
+            split("\"Email @ home #1\" @Work #admin"),
+            parts("Email @ home #1", "Work", "admin", 0.0)
+        );
+        assert_eq!(split("\"@#$\""), parts("@#$", "", "", 0.0));
+        assert_eq!(
+            split("Pay \"$5\" fee $20"),
+            parts("Pay $5 fee", "", "", 20.0)
+        );
+    }
+
+    #[test]
+    fn test_escaped_symbols_are_literal() {
+        assert_eq!(
+            split("Pay \\$5 fee \\#42 @Ops"),
+            parts("Pay $5 fee #42", "Ops", "", 0.0)
+        );
+        assert_eq!(
+            split("me\\@example.com #mail"),
+            parts("me@example.com", "", "mail", 0.0)
+        );
+    }
+
+    #[test]
+    fn test_escaped_quotes_and_backslashes() {
+        assert_eq!(
+            split("\"Say \\\"hi\\\"\" #chat"),
+            parts("Say \"hi\"", "", "chat", 0.0)
+        );
+        assert_eq!(split("5\\\" screen"), parts("5\" screen", "", "", 0.0));
+        assert_eq!(split("a\\\\@b"), parts("a\\", "b", "", 0.0));
+        assert_eq!(split("a\\\\\\@b"), parts("a\\@b", "", "", 0.0));
+    }
+
+    #[test]
+    fn test_other_backslashes_are_kept() {
+        assert_eq!(split("C:\\Temp @Disk"), parts("C:\\Temp", "Disk", "", 0.0));
+        assert_eq!(
+            split("Path C:\\ @Disk"),
+            parts("Path C:\\", "Disk", "", 0.0)
+        );
+        assert_eq!(split("Trailing\\"), parts("Trailing\\", "", "", 0.0));
+    }
+
+    #[test]
+    fn test_quotes_toggle_rather_than_nest() {
+        assert_eq!(
+            split("\"Review \"draft\" notes\" @Docs"),
+            parts("Review draft notes", "Docs", "", 0.0)
+        );
+        assert_eq!(split("\"a \"@b"), parts("a", "b", "", 0.0));
+    }
+
+    #[test]
+    fn test_unclosed_quote_runs_to_the_end() {
+        assert_eq!(
+            split("\"Email @home #x"),
+            parts("Email @home #x", "", "", 0.0)
+        );
+        assert_eq!(split("Call \"Bob $5"), parts("Call Bob $5", "", "", 0.0));
+    }
+
+    #[test]
+    fn test_quoted_project_and_tags() {
+        assert_eq!(
+            split("Plan @\"R&D #2\" #q3"),
+            parts("Plan", "R&D #2", "q3", 0.0)
+        );
+        assert_eq!(
+            split("Plan #\"big $ idea\""),
+            parts("Plan", "", "big $ idea", 0.0)
+        );
+    }
+
+    #[test]
+    fn test_quoted_markers_and_durations_stay_in_the_name() {
+        assert!(task_input_is_billable("\"Wait !\" $20", 20.0));
+        assert!(!task_input_is_billable("\"Wait\" ! $20", 20.0));
+        assert_eq!(split("\"Wait !\" $20"), parts("Wait !", "", "", 20.0));
+        assert_eq!(task_input_planned_seconds("\"Run ~5m drills\""), 0);
+        assert_eq!(task_input_planned_seconds("\"Run\" ~5m"), 5 * 60);
+        assert_eq!(
+            split("\"Run ~5m drills\""),
+            parts("Run ~5m drills", "", "", 0.0)
+        );
+    }
+
+    #[test]
+    fn test_logged_duration_skips_quoted_and_escaped_text() {
+        assert_eq!(split_logged_duration("\"Standup 15m\""), None);
+        assert_eq!(split_logged_duration("\"15m\" @Team"), None);
+        assert_eq!(
+            split_logged_duration("\"Standup 15m\" 5m"),
+            Some(("\"Standup 15m\"".to_string(), 5 * 60))
+        );
+        assert_eq!(
+            split_logged_duration("Watch \\\"Yoga 15m"),
+            Some(("Watch \\\"Yoga".to_string(), 15 * 60))
+        );
+        assert_eq!(split_logged_duration("Standup 1h\"\" 30m"), None);
+    }
+
+    #[test]
+    fn test_accepted_task_input_ignores_protected_symbols() {
+        assert_eq!(accepted_task_input("\\@home"), Some("\\@home".to_string()));
+        assert_eq!(
+            accepted_task_input("\"@a\" @b"),
+            Some("\"@a\" @b".to_string())
+        );
+        assert_eq!(
+            accepted_task_input("Pay \\$5 $10"),
+            Some("Pay \\$5 $10".to_string())
+        );
+        assert_eq!(
+            accepted_task_input("  \"Half typed"),
+            Some("\"Half typed".to_string())
+        );
+        assert_eq!(accepted_task_input("\"a\" @b @c"), None);
+        assert_eq!(accepted_task_input("\"a\"$5 $6"), None);
+        assert_eq!(accepted_task_input("\"a\" $\"5\""), None);
+    }
+
+    #[test]
+    fn test_task_input_literal() {
+        assert_eq!(task_input_literal("Plain name"), "Plain name");
+        assert_eq!(task_input_literal("Email @ home"), "Email \\@ home");
+        assert_eq!(task_input_literal("Say \"hi\""), "Say \\\"hi\\\"");
+        assert_eq!(task_input_literal("C:\\Temp\\"), "C:\\Temp\\\\");
+        assert_eq!(task_input_literal("Wait !"), "\"Wait !\"");
+        assert_eq!(task_input_literal("Standup 15m"), "\"Standup 15m\"");
+        assert_eq!(task_input_literal("Pay $5 ~5m"), "\"Pay $5 ~5m\"");
+    }
+
+    #[test]
+    fn test_task_group_round_trips_through_task_input() {
+        for name in AWKWARD_NAMES {
+            for project in ["", "Website", "R&D #2", "Ops 15m"] {
+                let mut task = task(name, project, "client #ui", 42.5);
+                task.is_billable = false;
+                let group = FurTaskGroup::new_from(task);
+                let input = group.to_string();
+                assert_eq!(
+                    split(&input),
+                    parts(name, project, "client #ui", 42.5),
+                    "{input}"
+                );
+                assert!(!task_input_is_billable(&input, 42.5), "{input}");
+                assert_eq!(task_input_planned_seconds(&input), 0, "{input}");
+                assert_eq!(split_logged_duration(&input), None, "{input}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_task_and_shortcut_round_trip_through_task_input() {
+        for name in AWKWARD_NAMES {
+            let task = task(name, "Website", "", 0.0);
+            assert_eq!(split(&task.to_string()), parts(name, "Website", "", 0.0));
+
+            let shortcut = FurShortcut::new(
+                name.to_string(),
+                "#client #ui".to_string(),
+                "R&D #2".to_string(),
+                45.0,
+                "EUR".to_string(),
+                "#A1B2C3".to_string(),
+            );
+            let input = shortcut.to_string();
+            assert_eq!(
+                split(&input),
+                parts(name, "R&D #2", &normalize_tags(&shortcut.tags), 45.0),
+                "{input}"
+            );
+            assert!(task_input_is_billable(&input, 45.0), "{input}");
+            assert_eq!(accepted_task_input(&input), Some(input.clone()));
+        }
+    }
+
+    #[test]
+    fn test_inspector_name_field() {
+        assert!(has_unescaped_task_symbol("Email @home"));
+        assert!(has_unescaped_task_symbol("Pay $5"));
+        assert!(has_unescaped_task_symbol("\"Budget\" #2"));
+        assert!(!has_unescaped_task_symbol("Email \\@home"));
+        assert!(!has_unescaped_task_symbol("\"Budget #2\""));
+        assert!(!has_unescaped_task_symbol("Pay \"$5"));
+
+        assert_eq!(unescape_task_input_text("Email \\@home"), "Email @home");
+        assert_eq!(unescape_task_input_text("\"Budget #2\""), "Budget #2");
+        assert_eq!(unescape_task_input_text("C:\\Temp"), "C:\\Temp");
+        for name in AWKWARD_NAMES {
+            let field = escape_task_input_symbols(name);
+            assert!(!has_unescaped_task_symbol(&field), "{field}");
+            assert_eq!(unescape_task_input_text(&field), name);
+        }
+    }
+}
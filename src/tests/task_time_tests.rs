@@ -0,0 +1,397 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod task_time_tests {
+    use chrono::{DateTime, FixedOffset, Local, NaiveDate, TimeZone};
+    use iced_aw::{
+        date_picker::Date,
+        time_picker::{Period, Time},
+    };
+
+    use crate::{
+        helpers::tasks::{group_tasks_by_date_in, latest_stop_on, start_clamped_to_previous_stop},
+        localization::Localization,
+        models::{
+            fur_task::FurTask, fur_task_group::FurTaskGroup, task_to_add::TaskToAdd,
+            task_to_edit::TaskToEdit,
+        },
+        update::msg_helper_functions::{
+            combine_chosen_date_with_time, combine_chosen_time_with_date, task_times_error,
+        },
+    };
+
+    fn task_between(start: DateTime<FixedOffset>, stop: DateTime<FixedOffset>) -> FurTask {
+        FurTask::new(
+            "Task".to_string(),
+            start.with_timezone(&Local),
+            stop.with_timezone(&Local),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn cet() -> FixedOffset {
+        FixedOffset::east_opt(3600).unwrap()
+    }
+
+    fn cest() -> FixedOffset {
+        FixedOffset::east_opt(2 * 3600).unwrap()
+    }
+
+    #[test]
+    fn test_duration_across_dst_start() {
+        // Clocks jump from 02:00 CET to 03:00 CEST, so 00:30 to 03:30 is only two hours
+        let start = cet().with_ymd_and_hms(2026, 3, 29, 0, 30, 0).unwrap();
+        let stop = cest().with_ymd_and_hms(2026, 3, 29, 3, 30, 0).unwrap();
+        assert_eq!(task_between(start, stop).total_time_in_seconds(), 2 * 3600);
+    }
+
+    #[test]
+    fn test_duration_across_dst_end() {
+        // Clocks fall back from 03:00 CEST to 02:00 CET, so 01:30 to 02:30 is two hours
+        let start = cest().with_ymd_and_hms(2026, 10, 25, 1, 30, 0).unwrap();
+        let stop = cet().with_ymd_and_hms(2026, 10, 25, 2, 30, 0).unwrap();
+        assert_eq!(task_between(start, stop).total_time_in_seconds(), 2 * 3600);
+    }
+
+    #[test]
+    fn test_group_by_local_date() {
+        // 23:30 CET is already the next day in CEST and still the same day in UTC
+        let start = cet().with_ymd_and_hms(2026, 3, 28, 23, 30, 0).unwrap();
+        let stop = cet().with_ymd_and_hms(2026, 3, 28, 23, 45, 0).unwrap();
+        let tasks = vec![task_between(start, stop)];
+
+        let in_cet = group_tasks_by_date_in(tasks.clone(), &cet());
+        assert!(in_cet.contains_key(&NaiveDate::from_ymd_opt(2026, 3, 28).unwrap()));
+
+        let in_cest = group_tasks_by_date_in(tasks.clone(), &cest());
+        assert!(in_cest.contains_key(&NaiveDate::from_ymd_opt(2026, 3, 29).unwrap()));
+
+        let in_utc = group_tasks_by_date_in(tasks, &FixedOffset::east_opt(0).unwrap());
+        assert!(in_utc.contains_key(&NaiveDate::from_ymd_opt(2026, 3, 28).unwrap()));
+    }
+
+    #[test]
+    fn test_group_keeps_both_sides_of_dst_change_on_one_day() {
+        let before = task_between(
+            cet().with_ymd_and_hms(2026, 3, 29, 1, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 3, 29, 1, 30, 0).unwrap(),
+        );
+        let after = task_between(
+            cest().with_ymd_and_hms(2026, 3, 29, 22, 0, 0).unwrap(),
+            cest().with_ymd_and_hms(2026, 3, 29, 23, 0, 0).unwrap(),
+        );
+
+        let grouped = group_tasks_by_date_in(vec![before, after], &cest());
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(
+            grouped
+                .get(&NaiveDate::from_ymd_opt(2026, 3, 29).unwrap())
+                .map(|tasks| tasks.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_to_new_start_keeps_durations_and_gaps() {
+        let first = task_between(
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 45, 0).unwrap(),
+        );
+        let second = task_between(
+            cet().with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 1, 12, 10, 30, 0).unwrap(),
+        );
+        let mut group = FurTaskGroup::new_from(first.clone());
+        group.add(second.clone());
+
+        let new_start = cet()
+            .with_ymd_and_hms(2026, 1, 15, 14, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let copies = group.duplicate_tasks(Some(new_start));
+        assert_eq!(copies.len(), 2);
+        assert_eq!(copies[0].start_time, new_start);
+        assert_eq!(copies[0].total_time_in_seconds(), 45 * 60);
+        assert_eq!(
+            (copies[1].start_time - copies[0].start_time).num_minutes(),
+            60
+        );
+        assert_eq!(copies[1].total_time_in_seconds(), 30 * 60);
+    }
+
+    #[test]
+    fn test_duplicate_in_place_gets_new_uids() {
+        let task = task_between(
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 45, 0).unwrap(),
+        );
+        let copies = FurTaskGroup::new_from(task.clone()).duplicate_tasks(None);
+        assert_eq!(copies[0].start_time, task.start_time);
+        assert_eq!(copies[0].stop_time, task.stop_time);
+        assert_ne!(copies[0].uid, task.uid);
+    }
+
+    #[test]
+    fn test_split_at_divides_task() {
+        let task = task_between(
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 1, 12, 13, 0, 0).unwrap(),
+        );
+        let split_at = cet()
+            .with_ymd_and_hms(2026, 1, 12, 11, 30, 0)
+            .unwrap()
+            .with_timezone(&Local);
+
+        let (first, second) = task.split_at(split_at).unwrap();
+        assert_eq!(first.uid, task.uid);
+        assert_eq!(first.start_time, task.start_time);
+        assert_eq!(first.stop_time, split_at);
+        assert_eq!(second.start_time, split_at);
+        assert_eq!(second.stop_time, task.stop_time);
+        assert_ne!(second.uid, task.uid);
+        assert_eq!(second.name, task.name);
+        assert_eq!(
+            first.total_time_in_seconds() + second.total_time_in_seconds(),
+            task.total_time_in_seconds()
+        );
+    }
+
+    #[test]
+    fn test_split_at_rejects_boundaries() {
+        let task = task_between(
+            cet().with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap(),
+            cet().with_ymd_and_hms(2026, 1, 12, 13, 0, 0).unwrap(),
+        );
+        assert!(task.split_at(task.start_time).is_none());
+        assert!(task.split_at(task.stop_time).is_none());
+        assert!(
+            task.split_at(task.stop_time + chrono::TimeDelta::minutes(5))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_task_to_add_for_date_fills_in_the_date() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let task_to_add = TaskToAdd::new_for_date(date);
+        assert_eq!(task_to_add.start_time.date_naive(), date);
+        assert_eq!(task_to_add.stop_time.date_naive(), date);
+        assert_eq!(task_to_add.start_time.format("%H:%M").to_string(), "09:00");
+        assert_eq!(task_to_add.stop_time.format("%H:%M").to_string(), "10:00");
+        assert!(task_to_add.name.is_empty());
+    }
+
+    #[test]
+    fn test_task_edit_times_validated_across_midnight() {
+        let localization = Localization::new_with_locale("en-US");
+        let start = Local.with_ymd_and_hms(2026, 6, 14, 23, 50, 0).unwrap();
+        let stop = Local.with_ymd_and_hms(2026, 6, 15, 0, 10, 0).unwrap();
+        let mut task_to_edit = TaskToEdit::new_from(&FurTask::new(
+            "Task".to_string(),
+            start,
+            stop,
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        ));
+        assert!(task_to_edit.times_are_valid());
+        assert!(task_times_error(start, stop, &localization).is_empty());
+
+        // An earlier clock time is still after the start because the stop is on the next day
+        task_to_edit.new_stop_time = combine_chosen_time_with_date(
+            task_to_edit.new_stop_time,
+            Time::Hm {
+                hour: 0,
+                minute: 5,
+                period: Period::H24,
+            },
+        )
+        .unwrap();
+        assert!(task_to_edit.times_are_valid());
+
+        // Moving the stop back to the start's day puts it before the start
+        task_to_edit.new_stop_time = combine_chosen_date_with_time(
+            task_to_edit.new_stop_time,
+            Date::from(NaiveDate::from_ymd_opt(2026, 6, 14).unwrap()),
+        )
+        .unwrap();
+        assert!(!task_to_edit.times_are_valid());
+        assert_eq!(
+            task_times_error(
+                task_to_edit.new_start_time,
+                task_to_edit.new_stop_time,
+                &localization
+            ),
+            localization.get_message("stop-before-start", None)
+        );
+        assert!(!task_times_error(start, start, &localization).is_empty());
+    }
+
+    #[test]
+    fn test_unsaved_task_can_be_saved_without_changes() {
+        let start = Local.with_ymd_and_hms(2026, 6, 15, 9, 0, 0).unwrap();
+        let stop = Local.with_ymd_and_hms(2026, 6, 15, 10, 0, 0).unwrap();
+        let task = FurTask::new(
+            "Task".to_string(),
+            start,
+            stop,
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        );
+
+        assert!(!TaskToEdit::new_from(&task).is_changed());
+        let unsaved = TaskToEdit::new_unsaved(&task);
+        assert!(unsaved.is_unsaved);
+        assert!(unsaved.is_changed());
+        assert_eq!(unsaved.uid, task.uid);
+    }
+
+    #[test]
+    fn test_unchanged_task_times_round_trip_with_seconds() {
+        let start = Local.with_ymd_and_hms(2026, 3, 9, 10, 0, 37).unwrap();
+        let stop = Local.with_ymd_and_hms(2026, 3, 9, 10, 25, 14).unwrap();
+        let mut task_to_edit = TaskToEdit::new_from(&FurTask::new(
+            "Task".to_string(),
+            start,
+            stop,
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        ));
+
+        // Submitting the pickers without touching them
+        task_to_edit.new_start_time = combine_chosen_time_with_date(
+            task_to_edit.new_start_time,
+            task_to_edit.displayed_start_time,
+        )
+        .unwrap();
+        task_to_edit.new_stop_time = combine_chosen_time_with_date(
+            task_to_edit.new_stop_time,
+            task_to_edit.displayed_stop_time,
+        )
+        .unwrap();
+
+        assert_eq!(task_to_edit.new_start_time, start);
+        assert_eq!(task_to_edit.new_stop_time, stop);
+        assert!(!task_to_edit.is_changed());
+    }
+
+    #[test]
+    fn test_chosen_time_seconds() {
+        let old = Local.with_ymd_and_hms(2026, 3, 9, 10, 0, 37).unwrap();
+
+        let picked = combine_chosen_time_with_date(
+            old,
+            Time::Hms {
+                hour: 10,
+                minute: 5,
+                second: 9,
+                period: Period::H24,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            picked,
+            Local.with_ymd_and_hms(2026, 3, 9, 10, 5, 9).unwrap()
+        );
+
+        // A time without seconds keeps them only when the minute is unchanged
+        let same_minute = Time::Hm {
+            hour: 10,
+            minute: 0,
+            period: Period::H24,
+        };
+        assert_eq!(
+            combine_chosen_time_with_date(old, same_minute).unwrap(),
+            old
+        );
+        let new_minute = Time::Hm {
+            hour: 10,
+            minute: 1,
+            period: Period::H24,
+        };
+        assert_eq!(
+            combine_chosen_time_with_date(old, new_minute).unwrap(),
+            Local.with_ymd_and_hms(2026, 3, 9, 10, 1, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_latest_stop_includes_tasks_from_the_night_before() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
+        let at = |d: u32, h: u32, m: u32| {
+            Local
+                .with_ymd_and_hms(2026, 2, d, h, m, 0)
+                .unwrap()
+                .fixed_offset()
+        };
+        let late_night = task_between(at(9, 23, 0), at(10, 0, 30));
+        let morning = task_between(at(10, 8, 0), at(10, 9, 15));
+        let earlier = task_between(at(9, 14, 0), at(9, 15, 0));
+
+        let mut history = std::collections::BTreeMap::new();
+        history.insert(
+            NaiveDate::from_ymd_opt(2026, 2, 9).unwrap(),
+            vec![
+                FurTaskGroup::new_from(late_night),
+                FurTaskGroup::new_from(earlier),
+            ],
+        );
+        assert_eq!(
+            latest_stop_on(&history, day),
+            Some(at(10, 0, 30).with_timezone(&Local))
+        );
+
+        history.insert(day, vec![FurTaskGroup::new_from(morning)]);
+        assert_eq!(
+            latest_stop_on(&history, day),
+            Some(at(10, 9, 15).with_timezone(&Local))
+        );
+        assert_eq!(
+            latest_stop_on(&history, NaiveDate::from_ymd_opt(2026, 2, 11).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_start_clamped_only_when_it_overlaps() {
+        let previous_stop = Local.with_ymd_and_hms(2026, 2, 10, 9, 15, 0).unwrap();
+        let before = Local.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap();
+        let after = Local.with_ymd_and_hms(2026, 2, 10, 9, 30, 0).unwrap();
+
+        assert_eq!(
+            start_clamped_to_previous_stop(before, Some(previous_stop)),
+            Some(previous_stop)
+        );
+        assert_eq!(
+            start_clamped_to_previous_stop(after, Some(previous_stop)),
+            None
+        );
+        assert_eq!(
+            start_clamped_to_previous_stop(previous_stop, Some(previous_stop)),
+            None
+        );
+        assert_eq!(start_clamped_to_previous_stop(before, None), None);
+    }
+}
@@ -0,0 +1,77 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod time_of_day_tests {
+    use chrono::{Local, TimeDelta, TimeZone};
+
+    use crate::{charts::all_charts::time_per_hour, models::fur_task::FurTask};
+
+    fn task_at(day: u32, hour: u32, minute: u32, minutes: i64) -> FurTask {
+        let start = Local
+            .with_ymd_and_hms(2026, 6, day, hour, minute, 0)
+            .unwrap();
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            start + TimeDelta::minutes(minutes),
+            String::new(),
+            String::new(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_task_is_split_at_hour_boundaries() {
+        let task = task_at(10, 9, 30, 105);
+        let hours = time_per_hour(&[&task]);
+        assert_eq!(hours[9], 30 * 60);
+        assert_eq!(hours[10], 60 * 60);
+        assert_eq!(hours[11], 15 * 60);
+        assert_eq!(hours.iter().sum::<i64>(), task.total_time_in_seconds());
+    }
+
+    #[test]
+    fn test_task_within_one_hour() {
+        let hours = time_per_hour(&[&task_at(10, 14, 5, 20)]);
+        assert_eq!(hours[14], 20 * 60);
+        assert_eq!(hours.iter().sum::<i64>(), 20 * 60);
+    }
+
+    #[test]
+    fn test_overnight_task_splits_across_midnight() {
+        let hours = time_per_hour(&[&task_at(10, 23, 30, 105)]);
+        assert_eq!(hours[23], 30 * 60);
+        assert_eq!(hours[0], 60 * 60);
+        assert_eq!(hours[1], 15 * 60);
+    }
+
+    #[test]
+    fn test_tasks_add_up_per_hour() {
+        let first = task_at(10, 9, 0, 30);
+        let second = task_at(11, 9, 15, 30);
+        let hours = time_per_hour(&[&first, &second]);
+        assert_eq!(hours[9], 60 * 60);
+    }
+
+    #[test]
+    fn test_task_ending_on_the_hour() {
+        let hours = time_per_hour(&[&task_at(10, 8, 0, 60)]);
+        assert_eq!(hours[8], 60 * 60);
+        assert_eq!(hours[9], 0);
+    }
+}
@@ -16,7 +16,18 @@
 
 #[cfg(test)]
 mod timer_tests {
-    use crate::update::msg_helper_functions::split_task_input;
+    use chrono::{Local, TimeDelta};
+
+    use crate::{
+        models::{fur_running_timer::FurRunningTimer, fur_settings::FurSettings},
+        update::msg_helper_functions::{
+            TaskLengthCheck, accepted_task_input, check_task_length, is_task_switch,
+            normalize_shortcut_tags, normalize_tags, round_seconds, split_logged_duration,
+            split_task_input, switch_times, task_input_is_billable, task_input_planned_seconds,
+            window_title,
+        },
+        view_enums::FurRoundingDirection,
+    };
 
     #[test]
     fn test_split_task_input_basic() {
@@ -24,12 +35,34 @@ mod timer_tests {
         let expected = (
             "Check".to_string(),
             "Proj".to_string(),
-            "Bond james bond #007".to_string(),
+            "007 #bond james bond".to_string(),
             10.0,
         );
         assert_eq!(split_task_input(input), expected);
     }
 
+    #[test]
+    fn test_split_task_input_merges_tags_differing_in_case() {
+        let (_, _, tags, _) = split_task_input("Write #Work #work #Docs");
+        assert_eq!(tags, "docs #work");
+    }
+
+    #[test]
+    fn test_normalize_tags() {
+        assert_eq!(normalize_tags("#Work #meeting #work"), "meeting #work");
+        assert_eq!(
+            normalize_tags("Work, Meeting,  admin "),
+            "admin #meeting #work"
+        );
+        assert_eq!(normalize_tags(" # , "), "");
+    }
+
+    #[test]
+    fn test_normalize_shortcut_tags_keeps_leading_pound() {
+        assert_eq!(normalize_shortcut_tags("Work #Meeting"), "#meeting #work");
+        assert_eq!(normalize_shortcut_tags("  "), "");
+    }
+
     #[test]
     fn test_split_task_input_no_tags() {
         let input = "Task without tags @Proj $5";
@@ -101,4 +134,280 @@ mod timer_tests {
         );
         assert_eq!(split_task_input(input), expected);
     }
+
+    #[test]
+    fn test_split_task_input_non_billable_marker() {
+        let input = "Internal meeting @Client #standup $50 !";
+        let expected = (
+            "Internal meeting".to_string(),
+            "Client".to_string(),
+            "standup".to_string(),
+            50.0,
+        );
+        assert_eq!(split_task_input(input), expected);
+        assert!(!task_input_is_billable(input, 50.0));
+    }
+
+    #[test]
+    fn test_task_input_is_billable() {
+        assert!(task_input_is_billable("Write report! @Client $50", 50.0));
+        assert!(!task_input_is_billable("Write report @Client", 0.0));
+        assert!(!task_input_is_billable("Write report ! @Client $50", 50.0));
+    }
+
+    #[test]
+    fn test_split_task_input_planned_duration() {
+        let input = "Write docs @proj #rust ~45m";
+        let expected = (
+            "Write docs".to_string(),
+            "proj".to_string(),
+            "rust".to_string(),
+            0.0,
+        );
+        assert_eq!(split_task_input(input), expected);
+        assert_eq!(task_input_planned_seconds(input), 45 * 60);
+    }
+
+    #[test]
+    fn test_task_input_planned_seconds_formats() {
+        assert_eq!(task_input_planned_seconds("Task ~30m"), 30 * 60);
+        assert_eq!(task_input_planned_seconds("Task ~1h"), 3600);
+        assert_eq!(task_input_planned_seconds("Task ~1h15m @Proj"), 75 * 60);
+        assert_eq!(task_input_planned_seconds("Task ~ later"), 0);
+        assert_eq!(task_input_planned_seconds("Task ~5x"), 0);
+        assert_eq!(task_input_planned_seconds("Task"), 0);
+    }
+
+    #[test]
+    fn test_tilde_without_duration_stays_in_name() {
+        let (name, _, _, _) = split_task_input("Approx ~ figures");
+        assert_eq!(name, "Approx ~ figures");
+    }
+
+    #[test]
+    fn test_planned_duration_allowed_after_rate() {
+        assert_eq!(
+            accepted_task_input("Call $40 ~30m"),
+            Some("Call $40 ~30m".to_string())
+        );
+    }
+
+    #[test]
+    fn test_split_logged_duration() {
+        assert_eq!(
+            split_logged_duration("Standup @team 1h"),
+            Some(("Standup @team".to_string(), 3600))
+        );
+        assert_eq!(
+            split_logged_duration("Standup @team 90m"),
+            Some(("Standup @team".to_string(), 90 * 60))
+        );
+        assert_eq!(
+            split_logged_duration("Review #code 1h 30m"),
+            Some(("Review #code".to_string(), 90 * 60))
+        );
+        assert_eq!(
+            split_logged_duration("Call $40 15m"),
+            Some(("Call $40".to_string(), 15 * 60))
+        );
+        assert_eq!(
+            split_task_input("Standup @team"),
+            (
+                "Standup".to_string(),
+                "team".to_string(),
+                String::new(),
+                0.0
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_logged_duration_ignores_quoted_names() {
+        assert_eq!(split_logged_duration("Watch \"Yoga 15m\""), None);
+        assert_eq!(split_logged_duration("Read \"Tips in 15m"), None);
+        assert_eq!(
+            split_logged_duration("Watch \"Yoga 15m\" 20m"),
+            Some(("Watch \"Yoga 15m\"".to_string(), 20 * 60))
+        );
+    }
+
+    #[test]
+    fn test_split_logged_duration_needs_bare_trailing_token() {
+        assert_eq!(split_logged_duration("15m"), None);
+        assert_eq!(split_logged_duration("Standup ~15m"), None);
+        assert_eq!(split_logged_duration("Standup ~1h 30m"), None);
+        assert_eq!(split_logged_duration("Run 5k"), None);
+        assert_eq!(split_logged_duration("Plan 15m sprint"), None);
+        assert_eq!(split_logged_duration("Standup 0m"), None);
+    }
+
+    #[test]
+    fn test_duration_allowed_after_rate() {
+        assert_eq!(
+            accepted_task_input("Call $40 1h 3"),
+            Some("Call $40 1h 3".to_string())
+        );
+        assert_eq!(accepted_task_input("Call $40 later"), None);
+    }
+
+    #[test]
+    fn test_round_seconds() {
+        let mut settings = FurSettings {
+            rounding_enabled: true,
+            rounding_minutes: 15,
+            ..Default::default()
+        };
+        assert_eq!(round_seconds(22 * 60, &settings), 15 * 60);
+        assert_eq!(round_seconds(23 * 60, &settings), 30 * 60);
+
+        settings.rounding_direction = FurRoundingDirection::Up;
+        assert_eq!(round_seconds(16 * 60, &settings), 30 * 60);
+        assert_eq!(round_seconds(15 * 60, &settings), 15 * 60);
+
+        settings.rounding_direction = FurRoundingDirection::Down;
+        assert_eq!(round_seconds(29 * 60, &settings), 15 * 60);
+
+        settings.rounding_enabled = false;
+        assert_eq!(round_seconds(29 * 60, &settings), 29 * 60);
+    }
+
+    #[test]
+    fn test_window_title() {
+        assert_eq!(
+            window_title("0:42:17", "Write report"),
+            "0:42:17 – Write report — Furtherance"
+        );
+
+        let long_name = "Refactor the synchronization engine to handle offline edits";
+        let title = window_title("1:05", long_name);
+        assert_eq!(
+            title,
+            "1:05 – Refactor the synchronization engine to… — Furtherance"
+        );
+    }
+
+    #[test]
+    fn test_check_task_length_thresholds() {
+        let mut settings = FurSettings::default();
+        settings.warn_if_longer_than_hours = 8;
+        settings.discard_if_shorter_than_seconds = 5;
+
+        assert_eq!(check_task_length(3, &settings), TaskLengthCheck::TooShort);
+        assert_eq!(check_task_length(5, &settings), TaskLengthCheck::Normal);
+        assert_eq!(
+            check_task_length(8 * 3600, &settings),
+            TaskLengthCheck::Normal
+        );
+        assert_eq!(
+            check_task_length(8 * 3600 + 1, &settings),
+            TaskLengthCheck::TooLong
+        );
+    }
+
+    #[test]
+    fn test_check_task_length_zero_turns_off() {
+        let mut settings = FurSettings::default();
+        settings.warn_if_longer_than_hours = 0;
+        settings.discard_if_shorter_than_seconds = 0;
+
+        assert_eq!(check_task_length(0, &settings), TaskLengthCheck::Normal);
+        assert_eq!(
+            check_task_length(100 * 3600, &settings),
+            TaskLengthCheck::Normal
+        );
+    }
+
+    #[test]
+    fn test_running_timer_to_task() {
+        let start_time = Local::now() - TimeDelta::minutes(30);
+        let timer = FurRunningTimer {
+            task_input: "Build @Release #ci $20".to_string(),
+            start_time,
+        };
+        let task = timer.to_task(start_time + TimeDelta::minutes(30));
+        assert_eq!(task.name, "Build");
+        assert_eq!(task.project, "Release");
+        assert_eq!(task.tags, "ci");
+        assert_eq!(task.rate, 20.0);
+        assert!(task.is_billable);
+        assert_eq!(task.total_time_in_seconds(), 30 * 60);
+    }
+
+    #[test]
+    fn test_running_timer_elapsed_never_negative() {
+        let timer = FurRunningTimer::new("Meeting".to_string());
+        assert_eq!(
+            timer.seconds_elapsed(timer.start_time - TimeDelta::seconds(5)),
+            0
+        );
+        assert_eq!(
+            timer.seconds_elapsed(timer.start_time + TimeDelta::seconds(5)),
+            5
+        );
+    }
+
+    #[test]
+    fn test_accepted_task_input() {
+        assert_eq!(
+            accepted_task_input("  Write @Docs"),
+            Some("Write @Docs".to_string())
+        );
+        assert_eq!(accepted_task_input("@Docs"), None);
+        assert_eq!(accepted_task_input("Write @Docs @More"), None);
+        assert_eq!(accepted_task_input("Write $"), Some("Write $".to_string()));
+        assert_eq!(
+            accepted_task_input("Write $1.5 #tag"),
+            Some("Write $1.5 #tag".to_string())
+        );
+        assert_eq!(accepted_task_input("Write $1.555"), None);
+        assert_eq!(accepted_task_input("Write $10 extra"), None);
+    }
+
+    #[test]
+    fn test_typing_a_different_task_is_a_switch() {
+        assert!(is_task_switch("Write @Docs", "Review @Docs"));
+        assert!(is_task_switch("Write @Docs", "Write @Docs #draft"));
+    }
+
+    #[test]
+    fn test_unchanged_or_empty_input_is_not_a_switch() {
+        assert!(!is_task_switch("Write @Docs", "Write @Docs"));
+        assert!(!is_task_switch("Write @Docs", " Write @Docs "));
+        assert!(!is_task_switch("Write @Docs", "   "));
+    }
+
+    #[test]
+    fn test_switched_tasks_meet_without_a_gap() {
+        let start = Local::now() - TimeDelta::minutes(30);
+        let switch_time = Local::now();
+        let finished = FurRunningTimer {
+            task_input: "Write".to_string(),
+            start_time: start,
+        }
+        .to_task(switch_time);
+        let next = FurRunningTimer {
+            task_input: "Review".to_string(),
+            start_time: switch_time,
+        }
+        .to_task(switch_time + TimeDelta::minutes(10));
+        assert_eq!(finished.stop_time, next.start_time);
+        assert_eq!(finished.total_time_in_seconds(), 30 * 60);
+    }
+
+    #[test]
+    fn test_switch_stops_and_starts_at_the_same_instant() {
+        let now = Local::now();
+        let (stop_time, next_start) = switch_times(false, now - TimeDelta::minutes(5), now);
+        assert_eq!(stop_time, now);
+        assert_eq!(next_start, now);
+    }
+
+    #[test]
+    fn test_paused_switch_stops_when_the_pause_began() {
+        let now = Local::now();
+        let paused_at = now - TimeDelta::minutes(5);
+        let (stop_time, next_start) = switch_times(true, paused_at, now);
+        assert_eq!(stop_time, paused_at);
+        assert_eq!(next_start, now);
+    }
 }
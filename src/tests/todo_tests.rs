@@ -0,0 +1,197 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod todo_tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+
+    use crate::{
+        charts::todo_completion_chart::TodoCompletionChart,
+        models::{
+            fur_task::FurTask,
+            fur_task_group::FurTaskGroup,
+            fur_todo::{FurTodo, todo_estimate_totals},
+        },
+        ui::todos::archive_completed_todos,
+        update::msg_helper_functions::{format_duration_input, parse_duration_input},
+    };
+
+    fn todo(name: &str, project: &str, tags: &str, rate: f32, estimate: i64) -> FurTodo {
+        let mut todo = FurTodo::new(
+            name.to_string(),
+            project.to_string(),
+            tags.to_string(),
+            rate,
+            Local::now(),
+        );
+        todo.estimate = estimate;
+        todo
+    }
+
+    fn group(name: &str, project: &str, tags: &str, minutes: i64) -> FurTaskGroup {
+        let stop_time = Local::now();
+        FurTaskGroup::new_from(FurTask::new(
+            name.to_string(),
+            stop_time - TimeDelta::minutes(minutes),
+            stop_time,
+            tags.to_string(),
+            project.to_string(),
+            0.0,
+            String::new(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_duration_input() {
+        assert_eq!(parse_duration_input(""), Some(0));
+        assert_eq!(parse_duration_input("1h30m"), Some(5400));
+        assert_eq!(parse_duration_input("1h 30m"), Some(5400));
+        assert_eq!(parse_duration_input("1H30"), Some(5400));
+        assert_eq!(parse_duration_input("1.5h"), Some(5400));
+        assert_eq!(parse_duration_input("2h"), Some(7200));
+        assert_eq!(parse_duration_input("45m"), Some(2700));
+        assert_eq!(parse_duration_input("45"), Some(2700));
+        assert_eq!(parse_duration_input("1:30"), Some(5400));
+    }
+
+    #[test]
+    fn test_parse_duration_input_rejects_invalid() {
+        for input in ["m", "h", "abc", "1:75", "-1h", "1h-5m", "1.5", "1x"] {
+            assert_eq!(parse_duration_input(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_format_duration_input_round_trips() {
+        for seconds in [0, 1800, 3600, 5400, 36000 + 60] {
+            assert_eq!(
+                parse_duration_input(&format_duration_input(seconds)),
+                Some(seconds)
+            );
+        }
+        assert_eq!(format_duration_input(5400), "1h30m");
+    }
+
+    #[test]
+    fn test_estimate_totals() {
+        let todos = vec![
+            todo("Design", "site", "", 40.0, 5400),
+            todo("Email", "", "", 0.0, 1800),
+            todo("Call", "", "", 100.0, 0),
+        ];
+        let (count, estimate, earnings) = todo_estimate_totals(&todos);
+        assert_eq!(count, 3);
+        assert_eq!(estimate, 7200);
+        assert!((earnings - 60.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_tracked_time_matches_name_project_and_tags() {
+        let groups = vec![
+            group("Design", "site", "ui", 30),
+            group("Design", "site", "ui", 15),
+            group("Design", "other", "ui", 60),
+            group("Design", "site", "", 60),
+        ];
+        assert_eq!(
+            todo("Design", "site", "ui", 0.0, 3600).tracked_time(&groups),
+            Some(45 * 60)
+        );
+        assert_eq!(
+            todo("Review", "site", "ui", 0.0, 3600).tracked_time(&groups),
+            None
+        );
+    }
+
+    #[test]
+    fn test_archive_completed_todos_after_threshold() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+        let mut old_done = todo("Old done", "", "", 0.0, 0);
+        old_done.is_completed = true;
+        let old_open = todo("Old open", "", "", 0.0, 0);
+        let mut recent_done = todo("Recent done", "", "", 0.0, 0);
+        recent_done.is_completed = true;
+        let mut only_done = todo("Only done", "", "", 0.0, 0);
+        only_done.is_completed = true;
+
+        let mut todos = BTreeMap::from([
+            (today - TimeDelta::days(10), vec![old_done, old_open]),
+            (today - TimeDelta::days(8), vec![only_done]),
+            (today - TimeDelta::days(2), vec![recent_done]),
+        ]);
+        let mut unchanged = todos.clone();
+
+        assert!(archive_completed_todos(&mut unchanged, 0, today).is_empty());
+        assert_eq!(unchanged.len(), 3);
+
+        let archived = archive_completed_todos(&mut todos, 7, today);
+        assert_eq!(archived.len(), 2);
+        assert_eq!(archived[&(today - TimeDelta::days(10))][0].name, "Old done");
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[&(today - TimeDelta::days(10))][0].name, "Old open");
+        assert!(!todos.contains_key(&(today - TimeDelta::days(8))));
+        assert_eq!(todos[&(today - TimeDelta::days(2))][0].name, "Recent done");
+    }
+
+    #[test]
+    fn test_todo_completion_chart_counts_planned_and_completed_days() {
+        let day = |day: u32| NaiveDate::from_ymd_opt(2024, 5, day).unwrap();
+        let at = |day: u32| Local.with_ymd_and_hms(2024, 5, day, 12, 0, 0).unwrap();
+        let planned_on = |name: &str, day: u32| {
+            let mut todo = todo(name, "", "", 0.0, 0);
+            todo.date = at(day);
+            todo
+        };
+
+        let open = planned_on("Open", 10);
+        let mut done_late = planned_on("Done late", 10);
+        done_late.is_completed = true;
+        done_late.completed_at = Some(at(12));
+        // Completed before completion times were tracked
+        let mut done_untracked = planned_on("Done untracked", 11);
+        done_untracked.is_completed = true;
+        // Planned before the range but finished inside it
+        let mut done_early = planned_on("Planned earlier", 1);
+        done_early.is_completed = true;
+        done_early.completed_at = Some(at(11));
+        let mut deleted = planned_on("Deleted", 11);
+        deleted.is_deleted = true;
+
+        let chart = TodoCompletionChart::new(
+            &[open, done_late, done_untracked, done_early, deleted],
+            day(10),
+            day(12),
+        );
+        assert_eq!(chart.planned_on(day(10)), 2);
+        assert_eq!(chart.planned_on(day(11)), 1);
+        assert_eq!(chart.planned_on(day(1)), 0);
+        assert_eq!(chart.completed_on(day(11)), 1);
+        assert_eq!(chart.completed_on(day(12)), 1);
+        // Two of the three todos planned in range are done
+        assert!((chart.completion_rate().unwrap() - 200.0 / 3.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_todo_completion_rate_needs_planned_todos() {
+        let day = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+        assert_eq!(
+            TodoCompletionChart::new(&[], day, day).completion_rate(),
+            None
+        );
+    }
+}
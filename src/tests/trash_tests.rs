@@ -0,0 +1,76 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod trash_tests {
+    use crate::helpers::trash::{
+        DeletedItem, DeletedItemKind, count_of_kind, purge_cutoff, retention_cutoff,
+    };
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn deleted(kind: DeletedItemKind, uid: &str) -> DeletedItem {
+        DeletedItem {
+            kind,
+            uid: uid.to_string(),
+            name: String::from("Deleted"),
+            project: String::new(),
+            deleted_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_retention_counts_back_whole_days() {
+        let now = 1_700_000_000;
+        assert_eq!(retention_cutoff(now, 30), now - 30 * DAY);
+        assert_eq!(retention_cutoff(now, 1), now - DAY);
+    }
+
+    #[test]
+    fn test_signed_out_purges_by_retention_only() {
+        let now = 1_700_000_000;
+        assert_eq!(purge_cutoff(now, 30, None), now - 30 * DAY);
+    }
+
+    #[test]
+    fn test_unsynced_deletions_are_not_purged() {
+        let now = 1_700_000_000;
+        let last_sync = now - 60 * DAY;
+
+        assert_eq!(purge_cutoff(now, 30, Some(last_sync)), last_sync);
+        assert_eq!(purge_cutoff(now, 30, Some(now)), now - 30 * DAY);
+    }
+
+    #[test]
+    fn test_counts_each_kind() {
+        let items = vec![
+            deleted(DeletedItemKind::Task, "a"),
+            deleted(DeletedItemKind::Task, "b"),
+            deleted(DeletedItemKind::Shortcut, "c"),
+        ];
+
+        assert_eq!(count_of_kind(&items, DeletedItemKind::Task), 2);
+        assert_eq!(count_of_kind(&items, DeletedItemKind::Todo), 0);
+        assert_eq!(count_of_kind(&items, DeletedItemKind::Shortcut), 1);
+    }
+
+    #[test]
+    fn test_tasks_use_their_own_name_column() {
+        assert_eq!(DeletedItemKind::Task.name_column(), "task_name");
+        assert_eq!(DeletedItemKind::Todo.table(), "todos");
+        assert_eq!(DeletedItemKind::Shortcut.name_column(), "name");
+    }
+}
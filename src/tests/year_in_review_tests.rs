@@ -0,0 +1,153 @@
+// Furtherance - Track your time without being tracked
+// Copyright (C) 2025  Ricky Kresslein <rk@unobserved.io>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod year_in_review_tests {
+    use chrono::{DateTime, Local, NaiveDate, TimeZone};
+
+    use crate::{
+        helpers::year_in_review::{Streak, longest_streak, review_year, year_in_review},
+        models::fur_task::FurTask,
+    };
+
+    fn local(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn task(start: DateTime<Local>, stop: DateTime<Local>, project: &str, tags: &str) -> FurTask {
+        FurTask::new(
+            "Task".to_string(),
+            start,
+            stop,
+            tags.to_string(),
+            project.to_string(),
+            0.0,
+            String::new(),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_january_reviews_last_year() {
+        assert_eq!(review_year(date(2027, 1, 15)), 2026);
+        assert_eq!(review_year(date(2026, 12, 1)), 2026);
+    }
+
+    #[test]
+    fn test_longest_streak_prefers_earlier_run_on_tie() {
+        let streak = longest_streak([
+            date(2026, 3, 1),
+            date(2026, 3, 2),
+            date(2026, 3, 5),
+            date(2026, 3, 6),
+        ]);
+
+        assert_eq!(
+            streak,
+            Some(Streak {
+                start: date(2026, 3, 1),
+                end: date(2026, 3, 2),
+                days: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_longest_streak_crosses_month_end() {
+        let streak = longest_streak([
+            date(2026, 1, 30),
+            date(2026, 1, 31),
+            date(2026, 2, 1),
+            date(2026, 2, 3),
+        ])
+        .unwrap();
+
+        assert_eq!(streak.start, date(2026, 1, 30));
+        assert_eq!(streak.days, 3);
+        assert_eq!(longest_streak([]), None);
+    }
+
+    #[test]
+    fn test_busiest_day_tie_goes_to_earlier_day() {
+        let tasks = vec![
+            task(local(2026, 5, 2, 9, 0), local(2026, 5, 2, 10, 0), "", ""),
+            task(local(2026, 5, 1, 9, 0), local(2026, 5, 1, 10, 0), "", ""),
+        ];
+        let review = year_in_review(&tasks, 2026);
+
+        assert_eq!(review.busiest_day, Some((date(2026, 5, 1), 3600)));
+        assert_eq!(review.total_seconds, 7200);
+        assert_eq!(review.task_count, 2);
+    }
+
+    #[test]
+    fn test_late_night_task_counts_on_the_day_it_started() {
+        let tasks = vec![
+            task(
+                local(2026, 12, 31, 23, 30),
+                local(2027, 1, 1, 0, 30),
+                "",
+                "",
+            ),
+            task(local(2027, 1, 1, 9, 0), local(2027, 1, 1, 9, 30), "", ""),
+        ];
+        let review = year_in_review(&tasks, 2026);
+
+        assert_eq!(review.task_count, 1);
+        assert_eq!(review.busiest_day, Some((date(2026, 12, 31), 3600)));
+        assert_eq!(review.longest_streak.map(|streak| streak.days), Some(1));
+    }
+
+    #[test]
+    fn test_finishing_after_midnight_is_the_latest_finish() {
+        let tasks = vec![
+            task(local(2026, 6, 1, 20, 0), local(2026, 6, 1, 23, 0), "", ""),
+            task(local(2026, 6, 2, 22, 0), local(2026, 6, 3, 1, 0), "", ""),
+            task(local(2026, 6, 4, 6, 15), local(2026, 6, 4, 7, 0), "", ""),
+        ];
+        let review = year_in_review(&tasks, 2026);
+
+        assert_eq!(review.latest_finish, Some(local(2026, 6, 3, 1, 0)));
+        assert_eq!(review.earliest_start, Some(local(2026, 6, 4, 6, 15)));
+    }
+
+    #[test]
+    fn test_top_project_and_tag_by_time() {
+        let tasks = vec![
+            task(
+                local(2026, 2, 1, 9, 0),
+                local(2026, 2, 1, 11, 0),
+                "Acme",
+                "design",
+            ),
+            task(
+                local(2026, 2, 2, 9, 0),
+                local(2026, 2, 2, 10, 0),
+                "Other",
+                "design #calls",
+            ),
+        ];
+        let review = year_in_review(&tasks, 2026);
+
+        assert_eq!(review.top_project, Some(("Acme".to_string(), 7200)));
+        assert_eq!(review.top_tag, Some(("design".to_string(), 10800)));
+    }
+}
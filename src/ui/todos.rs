@@ -14,22 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use chrono::{Datelike, Local, NaiveDate, TimeDelta};
+use fluent::FluentValue;
 use iced::{
-    Alignment, Element, Length, Renderer, Theme, font,
+    Alignment, Border, Color, Element, Length, Renderer, Theme, font,
     widget::{Container, Row, button, column, rich_text, row, space, span, text},
 };
 use iced_aw::ContextMenu;
 use iced_fonts::bootstrap;
+use palette::Srgb;
 
 use crate::{
     database,
+    helpers::{
+        color_utils::{ToIcedColor, is_dark_color},
+        formatting::format_currency,
+    },
     localization::Localization,
-    models::{fur_settings::FurSettings, fur_todo::FurTodo},
+    models::{
+        fur_settings::FurSettings,
+        fur_todo::{FurTodo, todo_estimate_totals},
+    },
     style,
-    update::messages::Message,
+    update::{
+        messages::Message,
+        msg_helper_functions::{format_duration_input, seconds_to_formatted_duration},
+    },
 };
 
 pub fn get_all_todos() -> BTreeMap<chrono::NaiveDate, Vec<FurTodo>> {
@@ -52,6 +64,33 @@ pub fn get_all_todos() -> BTreeMap<chrono::NaiveDate, Vec<FurTodo>> {
     todos_by_date
 }
 
+/// Moves completed todos from more than `after_days` days ago out of `todos`
+/// and returns them. Nothing is archived when `after_days` is 0.
+pub fn archive_completed_todos(
+    todos: &mut BTreeMap<NaiveDate, Vec<FurTodo>>,
+    after_days: u16,
+    today: NaiveDate,
+) -> BTreeMap<NaiveDate, Vec<FurTodo>> {
+    let mut archived: BTreeMap<NaiveDate, Vec<FurTodo>> = BTreeMap::new();
+    if after_days == 0 {
+        return archived;
+    }
+
+    let cutoff = today - TimeDelta::days(after_days as i64);
+    for (date, date_todos) in todos.iter_mut().filter(|(date, _)| **date < cutoff) {
+        let (completed, open): (Vec<FurTodo>, Vec<FurTodo>) = std::mem::take(date_todos)
+            .into_iter()
+            .partition(|todo| todo.is_completed);
+        *date_todos = open;
+        if !completed.is_empty() {
+            archived.insert(*date, completed);
+        }
+    }
+    todos.retain(|_, date_todos| !date_todos.is_empty());
+
+    archived
+}
+
 fn group_todos_by_date(todos: Vec<FurTodo>) -> BTreeMap<chrono::NaiveDate, Vec<FurTodo>> {
     let mut grouped_todos: BTreeMap<chrono::NaiveDate, Vec<FurTodo>> = BTreeMap::new();
 
@@ -74,6 +113,34 @@ pub fn todo_title_row<'a>(date: &NaiveDate, localization: &Localization) -> Row<
     .align_y(Alignment::Center)
 }
 
+/// The todo count and estimated totals for a date. Hidden when nothing was estimated.
+pub fn todo_summary_row<'a>(
+    todos: &[FurTodo],
+    settings: &FurSettings,
+    localization: &Localization,
+) -> Option<Row<'a, Message>> {
+    let (count, estimate, earnings) = todo_estimate_totals(todos);
+    if !settings.show_todo_estimates || estimate == 0 {
+        return None;
+    }
+
+    let mut summary = localization.get_message(
+        "todo-summary",
+        Some(&HashMap::from([
+            ("count", FluentValue::from(count)),
+            (
+                "estimate",
+                FluentValue::from(seconds_to_formatted_duration(estimate, false)),
+            ),
+        ])),
+    );
+    if settings.show_todo_rate && earnings > 0.0 {
+        summary = format!("{}  {}", summary, format_currency(earnings, ""));
+    }
+
+    Some(row![text(summary).size(12)].align_y(Alignment::Center))
+}
+
 fn format_todo_date(date: &NaiveDate, localization: &Localization) -> String {
     let today = Local::now().date_naive();
     let yesterday = today - TimeDelta::days(1);
@@ -95,20 +162,65 @@ fn format_todo_date(date: &NaiveDate, localization: &Localization) -> String {
 
 pub fn todo_row<'a, 'loc>(
     todo: &'a FurTodo,
+    project_color: Option<Srgb>,
+    tracked_time: Option<i64>,
     timer_is_running: bool,
     settings: &'a FurSettings,
     localization: &'loc Localization,
 ) -> ContextMenu<'a, Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'loc>, Message> {
-    let mut todo_extra_text: String = String::new();
-    if settings.show_todo_project && !todo.project.is_empty() {
-        todo_extra_text = format!("  @{}", todo.project);
+    let show_project = settings.show_todo_project && !todo.project.is_empty();
+    let mut project_span = span::<'_, (), _>(if show_project {
+        format!("@{}", todo.project)
+    } else {
+        String::new()
+    })
+    .strikethrough(todo.is_completed);
+    if let Some(color) = project_color.filter(|_| show_project) {
+        project_span = project_span
+            .color(if is_dark_color(color) {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            })
+            .background(color.to_iced_color())
+            .border(Border {
+                radius: 8.0.into(),
+                ..Default::default()
+            })
+            .padding([0, 6]);
     }
+
+    let mut todo_extra_text: String = String::new();
     if settings.show_todo_tags && !todo.tags.is_empty() {
         todo_extra_text = todo_extra_text + &format!("  #{}", todo.tags);
     }
     if settings.show_todo_rate && todo.rate > 0.0 {
-        todo_extra_text = todo_extra_text + &format!("  ${}", todo.rate);
+        todo_extra_text =
+            todo_extra_text + &format!("  {}", format_currency(todo.rate, &todo.currency));
+    }
+    if settings.show_todo_estimates && todo.estimate > 0 && tracked_time.is_none() {
+        todo_extra_text = todo_extra_text + &format!("  ~{}", format_duration_input(todo.estimate));
     }
+    let tracked_text = tracked_time.map(|tracked| {
+        let tracked = seconds_to_formatted_duration(tracked, false);
+        if todo.estimate > 0 {
+            localization.get_message(
+                "todo-tracked-of-estimate",
+                Some(&HashMap::from([
+                    ("tracked", FluentValue::from(tracked)),
+                    (
+                        "estimate",
+                        FluentValue::from(seconds_to_formatted_duration(todo.estimate, false)),
+                    ),
+                ])),
+            )
+        } else {
+            localization.get_message(
+                "todo-tracked",
+                Some(&HashMap::from([("tracked", FluentValue::from(tracked))])),
+            )
+        }
+    });
 
     // let todo_text: text::Rich<'_, Message, Theme> = rich_text![
     //     span(todo.name.clone())
@@ -135,7 +247,12 @@ pub fn todo_row<'a, 'loc>(
                     ..Default::default()
                 })
                 .strikethrough(todo.is_completed),
-            span::<'_, (), _>(todo_extra_text).strikethrough(todo.is_completed)
+            span::<'_, (), _>(if show_project { "  " } else { "" }),
+            project_span,
+            span::<'_, (), _>(todo_extra_text).strikethrough(todo.is_completed),
+            span::<'_, (), _>(
+                tracked_text.map_or(String::new(), |tracked| format!("  {}", tracked))
+            )
         ]
         .width(Length::FillPortion(10)),
         space::horizontal().width(Length::FillPortion(1)),
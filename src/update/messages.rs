@@ -15,28 +15,64 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs::File,
-    path::Path,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use crate::{
-    app::{Furtherance, write_furtasks_to_csv},
-    autosave::write_autosave,
-    constants::{ALLOWED_DB_EXTENSIONS, OFFICIAL_SERVER},
+    app::{Furtherance, write_filtered_tasks_to_csv, write_furtasks_to_csv},
+    autosave::{delete_autosave, write_autosave},
+    charts::{all_charts, heatmap_chart::HeatmapChart, todo_completion_chart::TodoCompletionChart},
+    constants::{
+        ALLOWED_DB_EXTENSIONS, HISTORY_FILTER_CHIP_COUNT, HISTORY_LOAD_OLDER_OFFSET,
+        HISTORY_SCROLLABLE_ID, MIN_SECONDS_BETWEEN_SYNCS, OFFICIAL_SERVER, SEARCH_INPUT_ID,
+        SEARCH_PAGE_SIZE, SETTINGS_MESSAGE_DURATION, SHORTCUTS_SCROLLABLE_ID, TASK_INPUT_ID,
+    },
     database::*,
     helpers::{
-        color_utils::{RandomColor, ToHex},
-        task_actions,
+        auto_stop::{auto_stop_due, auto_stop_time, format_auto_stop_time},
+        clock_skew::sync_timestamp,
+        color_utils::{RandomColor, ToHex, ToSrgb},
+        device::set_current_device_name,
+        earnings::{net_earnings_deduction, parse_earnings_exclusions},
+        error_log::{error_log_text, log_error},
+        formatting::{set_default_currency, set_use_24_hour_time},
+        idle,
+        keyboard_nav::{
+            NavDirection, focused_input_for, move_grid_index, move_list_index, next_focus,
+            relative_scroll_offset,
+        },
+        notification_actions::PomodoroNotificationAction,
+        power::PowerEvent,
+        sounds::{FurSound, play_sound},
+        task_actions, tasks,
+        trash::{DeletedItem, DeletedItemKind},
+        year_in_review::{YearInReview, review_text, review_year, year_in_review},
     },
     models::{
+        find_replace::FindReplace,
+        fur_day_note::{DayNoteToEdit, FurDayNote},
+        fur_goal::{FurGoal, GoalToAdd},
         fur_idle::FurIdle,
-        fur_shortcut::{EncryptedShortcut, FurShortcut},
+        fur_invoice::{InvoiceProject, InvoiceToGenerate, invoice_html, invoice_lines},
+        fur_pomodoro::FurPomodoroStats,
+        fur_profile::{FurProfile, validate_profile_name, validate_profile_path},
+        fur_report::{FurReportData, ReportRange},
+        fur_settings::SettingsExport,
+        fur_shortcut::{
+            EncryptedShortcut, FurShortcut, ShortcutMove, ShortcutRateChange, ShortcutsExport,
+            move_shortcut, sort_shortcuts,
+        },
+        fur_sync_record::FurSyncRecord,
         fur_task::{EncryptedTask, FurTask},
         fur_task_group::FurTaskGroup,
         fur_todo::{EncryptedTodo, FurTodo, TodoToAdd, TodoToEdit},
         fur_user::FurUserFields,
         group_to_edit::GroupToEdit,
+        history_selection::HistorySelection,
+        profile_to_add::ProfileToAdd,
         shortcut_to_add::ShortcutToAdd,
         shortcut_to_edit::ShortcutToEdit,
         task_to_add::TaskToAdd,
@@ -44,134 +80,295 @@ use crate::{
     },
     server::{
         encryption::{self, decrypt_encryption_key, encrypt_encryption_key},
-        login::{ApiError, LoginResponse, login},
+        login::{
+            ApiError, LoginResponse, NetworkFailure, ServerUrlError, login, normalize_server_url,
+        },
         logout,
-        sync::{SyncResponse, sync_with_server},
+        sync::{SyncResponse, approximate_payload_bytes, sync_with_server},
     },
+    status_file::delete_status_file,
+    style::FurTheme,
     update::msg_helper_functions::{
-        chain_tasks, combine_chosen_date_with_time, combine_chosen_time_with_date,
-        convert_iced_time_to_chrono_local, detect_wayland, get_stopped_timer_text,
-        get_timer_duration, get_timer_text, has_max_two_decimals, import_csv_to_database,
-        reset_fur_user, reset_timer, set_negative_temp_notice, set_positive_temp_notice,
-        show_notification, start_timer, stop_timer, sync_after_change, update_task_history,
-        update_todo_list, verify_csv,
+        accepted_task_input, active_planned_seconds, advance_onboarding,
+        apply_project_default_tags, chain_tasks, check_scheduled_summary, clamp_window_position,
+        clear_clock_skew, clock_skew_warning, combine_chosen_date_with_time,
+        combine_chosen_time_with_date, confirm_discarding_changes,
+        convert_iced_time_to_chrono_local, current_focus_order, discard_inspector_changes,
+        finish_onboarding, focusable_history_groups, format_duration_input,
+        generate_status_server_api_key, get_day_notes, get_stopped_timer_text, get_timer_text,
+        has_max_two_decimals, has_unescaped_task_symbol, import_csv_to_database,
+        inspector_cancel_message, is_task_switch, log_task_from_input, merge_database,
+        normalize_shortcut_tags, normalize_tags, notify, parse_duration_input, pause_timer,
+        record_clock_skew, record_pomodoro_session, refresh_history_sections,
+        refresh_project_lists, refresh_recent_tasks, refresh_report_running_time,
+        refresh_search_results, refresh_status_snapshot, refresh_stopwatch, refresh_sync_status,
+        refresh_trash, reload_database, request_summary_file, reset_fur_user, reset_timer,
+        restart_status_server, resume_timer, save_window_geometry, schedule_stopwatch_tick,
+        set_negative_temp_notice, set_positive_temp_notice, set_timer_start_time, set_todo_list,
+        shortcut_grid_columns, show_notification, show_report_export_notice, show_timer_notice,
+        skip_current_scheduled_summary, split_logged_duration, split_task_input,
+        start_secondary_timer, start_timer, stop_secondary_timer, stop_timer,
+        stop_timer_for_review, switch_timer, sync_after_change, task_times_error, timer_now,
+        unescape_task_input_text, update_status_file, update_task_history, update_todo_list,
+        upgrade_legacy_database, verify_csv, weekly_summary, write_secondary_timer_autosaves,
+        write_summary_file_now,
     },
     view_enums::*,
 };
-use chrono::{Local, NaiveDate, TimeDelta, TimeZone, offset::LocalResult};
+use chrono::{Local, NaiveDate, NaiveTime, TimeDelta, TimeZone, offset::LocalResult};
 use fluent::FluentValue;
 use iced::{
-    Color, Task, font,
-    widget::{self},
+    Color, Point, Size, Task, font, keyboard,
+    widget::{self, text_editor},
+    window,
 };
 use iced_aw::{date_picker, time_picker};
-use itertools::Itertools;
 use palette::Srgb;
 use rfd::FileDialog;
-use user_idle::UserIdle;
 use webbrowser;
 
-#[cfg(target_os = "linux")]
-use {crate::helpers::wayland_idle::WaylandIdleMonitor, std::sync::OnceLock};
-
-#[cfg(target_os = "linux")]
-static WAYLAND_MONITOR: OnceLock<WaylandIdleMonitor> = OnceLock::new();
-
 #[derive(Debug, Clone)]
 pub enum Message {
+    AddGoalPressed,
     AddNewShortcutPressed,
+    AddProfilePressed,
+    AddNewTaskForDate(NaiveDate),
     AddNewTaskPressed,
     AddNewTodoPressed,
     AddTaskToGroup(GroupToEdit),
     AlertClose,
     BackupDatabase,
+    BulkAddTagPressed,
+    BulkChangeProjectPressed,
+    BulkDeletePressed,
+    BulkEditTextChanged(String, EditTaskProperty),
+    BulkRemoveTagPressed,
+    BulkSetRatePressed,
+    CancelAutoStopTime,
     CancelCurrentTaskStartTime,
+    CancelDayNoteEdit,
     CancelExportEndDate,
     CancelExportStartDate,
     CancelGroupEdit,
+    CancelInvoice,
+    CancelProjectColor,
     CancelReportEndDate,
     CancelReportStartDate,
     CancelShortcut,
+    BillableFilterSelected(FurBillableFilter),
     CancelShortcutColor,
     CancelTaskEdit,
     CancelTaskEditDateTime(EditTaskProperty),
+    CancelTaskSplitTime,
     CancelTodoEdit,
     CancelTodoEditDate,
+    ChangeDatabase(PathBuf, ChangeDB),
+    ChartTagToggled(String),
     ChartTaskPropertyKeySelected(FurTaskProperty),
+    ChartTaskPropertyValueFilterChanged(String),
+    ChartTaskPropertyValuePickerToggled,
     ChartTaskPropertyValueSelected(String),
+    CheckSystemTheme,
+    ChooseAutoStopTime,
     ChooseCurrentTaskStartTime,
     ChooseExportEndDate,
     ChooseExportStartDate,
+    ChooseProjectColor(String),
     ChooseReportEndDate,
     ChooseReportStartDate,
     ChooseShortcutColor,
     ChooseTaskEditDateTime(EditTaskProperty),
+    ChooseTaskSplitTime,
     ChooseTodoEditDate,
+    ClearHistoryFilters,
+    ClearLoggedTask(String),
     ClearLoginMessage,
+    ClearReportExportMessage,
+    ClearTimerNotice,
     CloseInspector,
+    CompareWithPreviousToggled(bool),
+    ComparisonPeriodSelected(FurComparisonPeriod),
+    CopyErrorLog,
+    CopyStatusServerKey,
+    CopyWeeklySummary(NaiveDate),
+    CopyYearInReview,
     CreateShortcutFromTaskGroup(FurTaskGroup),
+    DatabaseMergeFinished(Result<DatabaseMergeSummary, String>),
+    DatabaseMergeProgressed(UpgradeProgress),
+    DeleteArchivedTodos,
+    DeleteArchivedTodosPressed,
     DeleteEverything,
+    DeleteEverythingConfirmationChanged(String),
+    DeleteLocalData,
     DateRangeSelected(FurDateRange),
+    DayNoteEdited(text_editor::Action),
+    DeleteGoalPressed(String),
+    DeleteProfile(String),
     DeleteShortcut,
     DeleteShortcutFromContext(String),
     DeleteTasks,
     DeleteTasksFromContext(Vec<String>),
     DeleteTodo,
     DeleteTodoPressed(String),
+    DiscardChanges,
+    DiscardStoppedTask,
     Done,
+    DroppedDatabaseMergePressed,
+    DroppedDatabaseOpenPressed,
+    DuplicateTaskGroup(FurTaskGroup),
+    DuplicateTaskGroupToToday(FurTaskGroup),
+    EditDayNote(NaiveDate),
     EditGroup(FurTaskGroup),
     EditShortcutPressed(FurShortcut),
     EditShortcutTextChanged(String, EditTaskProperty),
     EditTask(FurTask),
+    EditTaskBillableToggled(bool),
     EditTaskTextChanged(String, EditTaskProperty),
     EditTodoTextChanged(String, EditTodoProperty),
     EditTodo(FurTodo),
     EnterPressedInTaskInput,
     EnterPressedInSyncFields,
     ExportCsvPressed,
+    ExportBillableColumnToggled(bool),
     ExportCurrencyColumnToggled(bool),
+    ExportDayNotesToggled(bool),
+    ExportDeviceColumnToggled(bool),
     ExportFilterByDateToggled(bool),
     ExportFilterByProjectToggled(bool),
     ExportNameColumnToggled(bool),
     ExportProjectColumnToggled(bool),
     ExportProjectSelected(String),
     ExportRateColumnToggled(bool),
+    ExportReportSelectionPressed,
+    ExportSettingsPressed,
+    ExportShortcutsPressed,
     ExportSortOrderSelected(SortOrder),
     ExportStartTimeColumnToggled(bool),
     ExportStopTimeColumnToggled(bool),
     ExportTagsColumnToggled(bool),
     ExportTotalTimeColumnToggled(bool),
     ExportTotalEarningsColumnToggled(bool),
+    FileDropped(PathBuf),
+    FindDuplicatesPressed,
+    FindReplaceApplyPressed,
+    FindReplaceFieldSelected(FurRenameField),
+    FindReplaceFindChanged(String),
+    FindReplacePreviewPressed,
+    FindReplaceReplaceChanged(String),
     FontLoaded(Result<(), font::Error>),
+    GenerateInvoicePressed,
+    GoalHoursChanged(String, i64),
+    GoalToAddHoursChanged(i64),
+    GoalToAddProjectChanged(String),
+    GroupRateScopeSelected(bool),
+    HistoryGroupSelected(Vec<String>, bool),
+    HistoryGroupingSelected(FurHistoryGrouping),
+    HistoryScrolled(f32),
     IdleDiscard,
     IdleReset,
+    ImportCsvFile(PathBuf),
     ImportCsvPressed,
+    ImportSettingsPressed,
+    ImportShortcutsPressed,
     ImportOldMacDatabase,
+    InvoiceProjectSelected(InvoiceProject),
+    InvoiceTextChanged(String, InvoiceField),
+    KeyboardActivate,
+    KeyboardContextMenu,
+    KeyboardDelete,
+    KeyboardFocusCleared,
+    KeyboardModifiersChanged(keyboard::Modifiers),
+    KeyboardNavigate(NavDirection),
     LearnAboutSync,
+    LegacyUpgradeFinished(PathBuf, Result<(), String>),
+    LegacyUpgradeProgressed(UpgradeProgress),
+    LoadOlderHistory,
+    LongTaskDiscard,
+    LongTaskEdit,
+    LongTaskKeep,
+    MergeDatabase(PathBuf),
+    MergeDatabasePressed,
+    MergeDuplicates,
     MidnightReached,
+    MoveShortcut(String, ShortcutMove),
     NavigateTo(FurView),
+    NotificationActionReceived(PomodoroNotificationAction),
+    NotificationSupportChecked(Result<(), String>),
     NotifyOfSyncClose,
+    OlderHistoryLoaded(tasks::OlderHistory),
+    OnboardingBack,
+    OnboardingCreateShortcut,
+    OnboardingExampleChanged(String),
+    OnboardingNext,
+    OnboardingSkip,
     OpenUrl(String),
+    PauseResumePressed,
     PomodoroContinueAfterBreak,
     PomodoroSnooze,
     PomodoroStartBreak,
     PomodoroStop,
     PomodoroStopAfterBreak,
+    ProfileBrowsePressed,
+    ProfileNameChanged(String),
+    ProfilePathChanged(String),
+    ProjectArchiveToggled(String, bool),
+    ProjectDefaultTagsChanged(String, String),
+    PurgeDeletedItem(DeletedItem),
+    RaiseWindowRequested,
+    RecentTaskPicked(String),
+    RecentTasksDismissed,
+    RecentTasksToggled,
+    RegenerateStatusServerKey,
+    RenameProfile(String),
     RepeatLastTaskPressed(String),
     RepeatTodoToday(FurTodo),
+    RestoreDeletedItem(DeletedItem),
+    ReportComputed(ReportRange, Arc<FurReportData>),
+    ReportHeatmapComputed(Arc<HeatmapChart>),
     ReportTabSelected(TabId),
+    ReportTodoChartComputed(Result<Arc<TodoCompletionChart>, String>),
+    RetrySyncPressed,
+    RunningTaskNotesEdited(text_editor::Action),
+    SaveDayNote,
     SaveGroupEdit,
+    SaveInvoice,
     SaveShortcut,
     SaveTaskEdit,
     SaveTodoEdit,
+    SaveWeeklySummary(NaiveDate),
+    SaveWindowGeometry,
+    SaveYearInReview,
+    ScheduledSummaryCheck,
+    ScheduledSummaryFolderBrowsePressed,
+    ScheduledSummaryWritten(NaiveDate, Result<PathBuf, String>),
+    SearchLoadMore,
+    SearchQueryChanged(String),
+    SearchResultPressed(FurTask),
+    SearchShortcutPressed,
+    SecondaryTimerInputChanged(String),
+    SecondaryTimerTick,
+    SettingsAutoHideCompletedAfterDaysChanged(u16),
+    SettingsAutoStopToggled(bool),
     SettingsChangeDatabaseLocationPressed(ChangeDB),
+    SettingsConfirmOnStopToggled(bool),
+    SettingsConfirmShortcutSwitchToggled(bool),
+    SettingsDailyTargetChanged(i64),
     SettingsDatabaseLocationInputChanged(String),
     SettingsDaysToShowChanged(i64),
+    SettingsDeductionPercentChanged(f32),
+    SettingsDefaultCurrencyChanged(String),
     SettingsDefaultViewSelected(FurView),
     SettingsDeleteConfirmationToggled(bool),
+    SettingsDeviceNameChanged(String),
+    SettingsDiscardIfShorterThanChanged(i64),
     SettingsDynamicTotalToggled(bool),
+    SettingsEarningsExclusionsChanged(String),
+    SettingsHideArchivedInHistoryToggled(bool),
+    SettingsIdleBackendSelected(FurIdleBackend),
     SettingsIdleTimeChanged(i64),
     SettingsIdleToggled(bool),
+    SettingsMonthlyEarningsTargetChanged(f32),
+    SettingsNotifyOnPlannedTimeToggled(bool),
     SettingsPomodoroBreakLengthChanged(i64),
     SettingsPomodoroExtendedBreaksToggled(bool),
     SettingsPomodoroExtendedBreakIntervalChanged(u16),
@@ -182,47 +379,102 @@ pub enum Message {
     SettingsPomodoroToggled(bool),
     SettingsReminderIntervalChanged(u16),
     SettingsRemindersToggled(bool),
+    SettingsReportWeekendsOffToggled(bool),
+    SettingsResetWindowSizePressed,
+    SettingsRoundingDirectionSelected(FurRoundingDirection),
+    SettingsRoundingMinutesSelected(i64),
+    SettingsRoundingToggled(bool),
+    SettingsScheduledSummaryEarningsToggled(bool),
+    SettingsScheduledSummaryHourChanged(u32),
+    SettingsScheduledSummarySelected(FurSummarySchedule),
     ShowReminderNotification,
     SettingsServerChoiceSelected(ServerChoices),
     SettingsShowChartAverageEarningsToggled(bool),
     SettingsShowChartAverageTimeToggled(bool),
     SettingsShowChartBreakdownBySelectionToggled(bool),
+    SettingsShowChartCumulativeEarningsToggled(bool),
     SettingsShowChartEarningsToggled(bool),
+    SettingsShowChartHeatmapToggled(bool),
     SettingsShowChartSelectionEarningsToggled(bool),
     SettingsShowChartSelectionTimeToggled(bool),
+    SettingsShowChartTimeOfDayToggled(bool),
     SettingsShowChartTimeRecordedToggled(bool),
+    SettingsShowChartTodosToggled(bool),
     SettingsShowChartTotalEarningsBoxToggled(bool),
     SettingsShowChartTotalTimeBoxToggled(bool),
+    SettingsShowChartWorkdayStatsToggled(bool),
     SettingsShowDailyTimeTotalToggled(bool),
     SettingsShowEarningsToggled(bool),
+    SettingsShowNetEarningsToggled(bool),
     SettingsShowSecondsToggled(bool),
+    SettingsShowShortcutStatsToggled(bool),
     SettingsShowTaskProjectToggled(bool),
+    SettingsShowTaskPlannedTimeToggled(bool),
     SettingsShowTaskTagsToggled(bool),
+    SettingsShowTimerInTitleToggled(bool),
+    SettingsShowTodoEstimatesToggled(bool),
     SettingsShowTodoProjectToggled(bool),
     SettingsShowTodoRateToggled(bool),
     SettingsShowTodoTagsToggled(bool),
+    SettingsSoundOnIdleToggled(bool),
+    SettingsSoundOnStartStopToggled(bool),
+    SettingsSoundsToggled(bool),
+    SettingsStatusFileToggled(bool),
+    SettingsStatusServerPortChanged(u16),
+    SettingsStatusServerToggled(bool),
+    SettingsSummaryFormatSelected(FurSummaryFormat),
+    SettingsSyncIntervalSelected(FurSyncInterval),
     SettingsTabSelected(TabId),
-    ShortcutPressed(String),
+    SettingsTargetAppliesToWeekendsToggled(bool),
+    SettingsThemePreferenceSelected(FurThemePreference),
+    SettingsTrashRetentionChanged(u16),
+    SettingsUse24HourTimeToggled(bool),
+    SettingsWarnIfLongerThanChanged(i64),
+    SettingsWriteSummaryFileToggled(bool),
+    ShortcutPressed(FurShortcut),
+    ShortcutSortSelected(FurShortcutSort),
     ShowAlert(FurAlert),
+    SplitTask(time_picker::Time),
+    StartSecondaryTimer,
     StartStopPressed,
     StartTimerWithTask(String),
-    StopwatchTick,
+    StopSecondaryTimer(usize),
+    StopwatchTick(u64),
+    SubmitAutoStopTime(time_picker::Time),
     SubmitCurrentTaskStartTime(time_picker::Time),
     SubmitExportEndDate(date_picker::Date),
     SubmitExportStartDate(date_picker::Date),
     SubmitReportEndDate(date_picker::Date),
     SubmitReportStartDate(date_picker::Date),
+    SubmitProjectColor(String, Color),
     SubmitShortcutColor(Color),
     SubmitTaskEditDate(date_picker::Date, EditTaskProperty),
     SubmitTaskEditTime(time_picker::Time, EditTaskProperty),
     SubmitTodoEditDate(date_picker::Date),
+    SummaryFileBrowsePressed,
+    SummaryFileDue,
+    SummaryFileWritten(Result<(), String>),
+    SwitchProfile(String),
+    SwitchTask,
+    SwitchToShortcut,
+    SyncTimerFired,
     SyncWithServer,
-    SyncComplete((Result<SyncResponse, ApiError>, usize)),
+    SystemPowerChanged(PowerEvent),
+    SyncComplete((Result<SyncResponse, ApiError>, FurSyncRecord)),
     TabPressed { shift: bool },
     TaskInputChanged(String),
+    TestNotificationPressed,
+    TimerStartOverlapClampPressed,
+    TimerStartOverlapKeepPressed,
+    ToggleArchivedTodos,
+    ToggleDayOff(NaiveDate),
     ToggleGroupEditor,
+    ToggleHistoryFilter(FilterChip),
+    ToggleHistorySelection,
+    ToggleRunningTaskNotes,
     ToggleSidebar,
     ToggleTodoCompletePressed(String),
+    UndoLoggedTask,
     UpdateTaskHistory(BTreeMap<NaiveDate, Vec<FurTaskGroup>>),
     UpdateTodaysTodos(Vec<FurTodo>),
     UpdateTodoList(BTreeMap<NaiveDate, Vec<FurTodo>>),
@@ -234,15 +486,100 @@ pub enum Message {
     UserLogoutComplete,
     UserEncryptionKeyChanged(String),
     UserServerChanged(String),
+    WindowCloseRequested(window::Id),
+    WindowMaximizedChanged(bool),
+    WindowMinimizedChanged(bool),
+    WindowMonitorSizeChecked(window::Id, Option<Size>),
+    WindowMoved(Point),
+    WindowResized(Size),
+    WindowStateChanged(window::Id),
+    YearInReviewComputed(YearInReview),
+    YearInReviewPressed,
 }
 
 impl Furtherance {
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        if let Some(input_id) = focused_input_for(&message) {
+            self.focused_input = Some(input_id);
+        }
+
         match message {
+            Message::AddGoalPressed => {
+                let project = self
+                    .goal_to_add
+                    .project
+                    .trim()
+                    .trim_start_matches('@')
+                    .trim()
+                    .to_string();
+                if project.is_empty() {
+                    return Task::none();
+                }
+                match db_goal_exists(&project) {
+                    Ok(true) => {
+                        self.goal_to_add
+                            .input_error(self.localization.get_message("goal-exists", None));
+                    }
+                    Ok(false) => {
+                        let new_goal = FurGoal::new(project, self.goal_to_add.weekly_hours * 60);
+                        match db_insert_goal(&new_goal) {
+                            Ok(_) => {
+                                self.goal_to_add = GoalToAdd::new();
+                                self.report.update_goal_progress();
+                            }
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to write goal to database: {}", e),
+                            ),
+                        }
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to check if goal exists: {}", e),
+                    ),
+                }
+            }
+            Message::AddProfilePressed => {
+                let name = self.profile_to_add.name.trim().to_string();
+                let database_url = self.profile_to_add.database_url.trim().to_string();
+                if let Err(e) = validate_profile_name(&self.fur_settings.profiles, &name, None)
+                    .and_then(|_| validate_profile_path(&database_url))
+                {
+                    return set_negative_temp_notice(
+                        &mut self.settings_profiles_message,
+                        self.localization.get_message(e.message_key(), None),
+                    );
+                }
+
+                let mut profiles = self.fur_settings.profiles.clone();
+                // Keep the database in use so switching away doesn't lose its path
+                let default_name = self.localization.get_message("default-profile", None);
+                if profiles.is_empty()
+                    && database_url != self.fur_settings.database_url
+                    && !default_name.eq_ignore_ascii_case(&name)
+                {
+                    profiles.push(FurProfile {
+                        name: default_name,
+                        database_url: self.fur_settings.database_url.clone(),
+                    });
+                }
+                profiles.push(FurProfile { name, database_url });
+                if let Err(e) = self.fur_settings.change_profiles(&profiles) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change profiles in settings: {}", e),
+                    );
+                }
+                self.profile_to_add = ProfileToAdd::new();
+            }
             Message::AddNewShortcutPressed => {
                 self.shortcut_to_add = Some(ShortcutToAdd::new());
                 self.inspector_view = Some(FurInspectorView::AddShortcut);
             }
+            Message::AddNewTaskForDate(date) => {
+                self.task_to_add = Some(TaskToAdd::new_for_date(date));
+                self.inspector_view = Some(FurInspectorView::AddNewTask);
+            }
             Message::AddNewTaskPressed => {
                 self.task_to_add = Some(TaskToAdd::new());
                 self.inspector_view = Some(FurInspectorView::AddNewTask);
@@ -256,9 +593,143 @@ impl Furtherance {
                 self.inspector_view = Some(FurInspectorView::AddTaskToGroup);
             }
             Message::AlertClose => {
+                self.delete_everything_confirmation = String::new();
                 self.delete_tasks_from_context = None;
                 self.delete_shortcut_from_context = None;
+                self.discard_changes_then = None;
                 self.displayed_alert = None;
+                self.dropped_database = None;
+                self.duplicates_found = None;
+                self.shortcut_to_switch_to = None;
+                self.timer_start_overlap = None;
+            }
+            Message::BulkAddTagPressed => {
+                if let Some(selection) = &self.history_selection {
+                    if let Err(e) =
+                        db_update_tasks_tags(&selection.selected_task_ids(), &selection.tag, false)
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to add tag to tasks: {}", e),
+                        );
+                    }
+                    self.history_selection = None;
+                    let mut tasks = vec![];
+                    tasks.push(update_task_history(self.history_days_loaded));
+                    tasks.push(sync_after_change(&self.fur_user));
+                    return chain_tasks(tasks);
+                }
+            }
+            Message::BulkChangeProjectPressed => {
+                if let Some(selection) = &self.history_selection {
+                    if let Err(e) = db_update_tasks_project(
+                        &selection.selected_task_ids(),
+                        &selection.new_project,
+                    ) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change project of tasks: {}", e),
+                        );
+                    }
+                    self.history_selection = None;
+                    let mut tasks = vec![];
+                    tasks.push(update_task_history(self.history_days_loaded));
+                    tasks.push(sync_after_change(&self.fur_user));
+                    return chain_tasks(tasks);
+                }
+            }
+            Message::BulkDeletePressed => {
+                if let Some(selection) = &self.history_selection {
+                    let task_ids = selection.selected_task_ids();
+                    return Task::perform(
+                        async move { Message::DeleteTasksFromContext(task_ids) },
+                        |msg| msg,
+                    );
+                }
+            }
+            Message::BulkEditTextChanged(new_value, property) => {
+                if let Some(selection) = self.history_selection.as_mut() {
+                    match property {
+                        EditTaskProperty::Project => {
+                            if new_value.contains('#')
+                                || new_value.contains('@')
+                                || new_value.contains('$')
+                            {
+                                selection.input_error(
+                                    self.localization
+                                        .get_message("project-cannot-contain", None),
+                                );
+                            } else {
+                                selection.new_project = new_value;
+                                selection.input_error(String::new());
+                            }
+                        }
+                        EditTaskProperty::Tags => {
+                            if new_value.contains('@') || new_value.contains('$') {
+                                selection.input_error(
+                                    self.localization.get_message("tags-cannot-contain", None),
+                                );
+                            } else {
+                                selection.tag = new_value;
+                                selection.input_error(String::new());
+                            }
+                        }
+                        EditTaskProperty::Rate => {
+                            let new_value_parsed = new_value.parse::<f32>();
+                            if new_value.is_empty() {
+                                selection.new_rate = String::new();
+                            } else if new_value.contains('$') {
+                                selection.input_error(
+                                    self.localization.get_message("no-symbol-in-rate", None),
+                                );
+                            } else if new_value_parsed.is_ok()
+                                && has_max_two_decimals(&new_value)
+                                && new_value_parsed.unwrap_or(f32::MAX) < f32::MAX
+                            {
+                                selection.new_rate = new_value;
+                                selection.input_error(String::new());
+                            } else {
+                                selection.input_error(
+                                    self.localization.get_message("rate-invalid", None),
+                                );
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Message::BulkRemoveTagPressed => {
+                if let Some(selection) = &self.history_selection {
+                    if let Err(e) =
+                        db_update_tasks_tags(&selection.selected_task_ids(), &selection.tag, true)
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to remove tag from tasks: {}", e),
+                        );
+                    }
+                    self.history_selection = None;
+                    let mut tasks = vec![];
+                    tasks.push(update_task_history(self.history_days_loaded));
+                    tasks.push(sync_after_change(&self.fur_user));
+                    return chain_tasks(tasks);
+                }
+            }
+            Message::BulkSetRatePressed => {
+                if let Some(selection) = &self.history_selection {
+                    let new_rate = selection.new_rate.trim().parse::<f32>().unwrap_or(0.0);
+                    if let Err(e) = db_update_tasks_rate(&selection.selected_task_ids(), new_rate) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change rate of tasks: {}", e),
+                        );
+                    }
+                    self.history_selection = None;
+                    let mut tasks = vec![];
+                    tasks.push(update_task_history(self.history_days_loaded));
+                    tasks.push(sync_after_change(&self.fur_user));
+                    return chain_tasks(tasks);
+                }
             }
             Message::BackupDatabase => {
                 self.settings_csv_message = Ok(String::new());
@@ -289,13 +760,21 @@ impl Furtherance {
                     }
                 }
             }
-            Message::CancelCurrentTaskStartTime => self.show_timer_start_picker = false,
+            Message::CancelAutoStopTime => self.show_auto_stop_time_picker = false,
+            Message::CancelCurrentTaskStartTime => {
+                self.show_timer_start_picker = false;
+                self.timer_start_error = None;
+            }
             Message::CancelExportEndDate => self.export_settings.show_end_date_picker = false,
             Message::CancelExportStartDate => self.export_settings.show_start_date_picker = false,
             Message::CancelGroupEdit => {
                 self.group_to_edit = None;
                 self.inspector_view = None;
             }
+            Message::CancelInvoice => {
+                self.invoice_to_generate = None;
+                self.inspector_view = None;
+            }
             Message::CancelReportEndDate => self.report.show_end_date_picker = false,
             Message::CancelReportStartDate => self.report.show_start_date_picker = false,
             Message::CancelShortcut => {
@@ -303,6 +782,7 @@ impl Furtherance {
                 self.shortcut_to_edit = None;
                 self.inspector_view = None;
             }
+            Message::CancelProjectColor => self.project_color_to_edit = None,
             Message::CancelShortcutColor => {
                 if let Some(shortcut_to_add) = self.shortcut_to_add.as_mut() {
                     shortcut_to_add.show_color_picker = false;
@@ -311,6 +791,14 @@ impl Furtherance {
                 }
             }
             Message::CancelTaskEdit => {
+                if self
+                    .task_to_edit
+                    .as_ref()
+                    .is_some_and(|task| task.is_unsaved)
+                {
+                    self.displayed_alert = Some(FurAlert::DiscardStoppedTaskConfirmation);
+                    return Task::none();
+                }
                 self.task_to_edit = None;
                 self.task_to_add = None;
                 if self.group_to_edit.is_some() {
@@ -319,6 +807,11 @@ impl Furtherance {
                     self.inspector_view = None;
                 }
             }
+            Message::CancelTaskSplitTime => {
+                if let Some(task_to_edit) = self.task_to_edit.as_mut() {
+                    task_to_edit.show_split_time_picker = false;
+                }
+            }
             Message::CancelTaskEditDateTime(property) => {
                 if let Some(task_to_edit) = self.task_to_edit.as_mut() {
                     match property {
@@ -354,6 +847,10 @@ impl Furtherance {
                     }
                 }
             }
+            Message::CancelDayNoteEdit => {
+                self.day_note_to_edit = None;
+                self.inspector_view = None;
+            }
             Message::CancelTodoEdit => {
                 self.todo_to_edit = None;
                 self.todo_to_add = None;
@@ -366,17 +863,120 @@ impl Furtherance {
                     todo_to_add.show_date_picker = false;
                 }
             }
+            Message::ChangeDatabase(file, new_or_open) => {
+                self.settings_database_message = Ok(String::new());
+                let mut is_old_db = false;
+
+                if file.exists() {
+                    match db_is_valid_v3(file.as_path()) {
+                        Err(e) => {
+                            log_error(&mut self.errors, format!("Invalid database: {}", e));
+                            self.settings_database_message = Err(self
+                                .localization
+                                .get_message("invalid-database", None)
+                                .into());
+                        }
+                        Ok(is_valid_v3) => {
+                            if !is_valid_v3 {
+                                match db_is_valid_v1(file.as_path()) {
+                                    Ok(is_valid_v2) => {
+                                        if is_valid_v2 {
+                                            is_old_db = true
+                                        } else {
+                                            self.settings_database_message = Err(self
+                                                .localization
+                                                .get_message("invalid-database", None)
+                                                .into());
+                                        }
+                                    }
+                                    Err(e) => {
+                                        log_error(
+                                            &mut self.errors,
+                                            format!("Invalid v1 database: {}", e),
+                                        );
+                                        self.settings_database_message = Err(self
+                                            .localization
+                                            .get_message("invalid-database", None)
+                                            .into());
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if self.settings_database_message.is_ok() {
+                    // Valid file or not yet a file
+                    if let Some(file_str) = file.to_str() {
+                        if is_old_db {
+                            // Only switched to once the upgrade succeeds, so a failure
+                            // leaves the current database in use
+                            self.legacy_upgrade = Some(UpgradeProgress::default());
+                            return upgrade_legacy_database(file);
+                        }
+                        if let Ok(_) = self.fur_settings.change_db_url(file_str) {
+                            match db_init() {
+                                Ok(_) => {
+                                    self.settings_database_message = Ok(match new_or_open {
+                                        ChangeDB::Open => {
+                                            self.localization.get_message("database-loaded", None)
+                                        }
+                                        ChangeDB::New => self
+                                            .localization
+                                            .get_message("database-created", None)
+                                            .to_string(),
+                                    });
+                                    return reload_database(self);
+                                }
+                                Err(e) => {
+                                    log_error(
+                                        &mut self.errors,
+                                        format!("Error accessing new database: {}", e),
+                                    );
+                                    self.settings_database_message = Err(self
+                                        .localization
+                                        .get_message("error-accessing-database", None)
+                                        .into());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Message::ChartTagToggled(tag) => self.report.toggle_picked_tag(tag),
             Message::ChartTaskPropertyKeySelected(new_property) => {
                 self.report.set_picked_task_property_key(new_property);
             }
+            Message::ChartTaskPropertyValueFilterChanged(new_filter) => {
+                self.report.task_property_value_filter = new_filter;
+            }
+            Message::ChartTaskPropertyValuePickerToggled => {
+                self.report.show_task_property_value_picker =
+                    !self.report.show_task_property_value_picker;
+                self.report.task_property_value_filter = String::new();
+            }
             Message::ChartTaskPropertyValueSelected(new_value) => {
                 self.report.set_picked_task_property_value(new_value);
+                self.report.show_task_property_value_picker = false;
+                self.report.task_property_value_filter = String::new();
+            }
+            Message::CheckSystemTheme => {
+                let system_theme = FurTheme::detect();
+                if system_theme != self.system_theme {
+                    self.system_theme = system_theme;
+                    all_charts::set_chart_theme(self.resolved_theme());
+                }
+            }
+            Message::ChooseAutoStopTime => self.show_auto_stop_time_picker = true,
+            Message::ChooseCurrentTaskStartTime => {
+                self.show_timer_start_picker = true;
+                self.timer_start_error = None;
             }
-            Message::ChooseCurrentTaskStartTime => self.show_timer_start_picker = true,
             Message::ChooseExportEndDate => self.export_settings.show_end_date_picker = true,
             Message::ChooseExportStartDate => self.export_settings.show_start_date_picker = true,
             Message::ChooseReportEndDate => self.report.show_end_date_picker = true,
             Message::ChooseReportStartDate => self.report.show_start_date_picker = true,
+            Message::ChooseProjectColor(project) => self.project_color_to_edit = Some(project),
             Message::ChooseShortcutColor => {
                 if let Some(shortcut_to_add) = self.shortcut_to_add.as_mut() {
                     shortcut_to_add.show_color_picker = true
@@ -384,6 +984,11 @@ impl Furtherance {
                     shortcut_to_edit.show_color_picker = true
                 }
             }
+            Message::ChooseTaskSplitTime => {
+                if let Some(task_to_edit) = self.task_to_edit.as_mut() {
+                    task_to_edit.show_split_time_picker = true;
+                }
+            }
             Message::ChooseTaskEditDateTime(property) => {
                 if let Some(task_to_edit) = self.task_to_edit.as_mut() {
                     match property {
@@ -426,6 +1031,16 @@ impl Furtherance {
                     todo_to_add.show_date_picker = true;
                 }
             }
+            Message::ClearHistoryFilters => self.active_history_filters.clear(),
+            Message::ClearLoggedTask(uid) => {
+                if self
+                    .logged_task
+                    .as_ref()
+                    .is_some_and(|task| task.uid == uid)
+                {
+                    self.logged_task = None;
+                }
+            }
             Message::ClearLoginMessage => {
                 if self
                     .login_message
@@ -435,8 +1050,23 @@ impl Furtherance {
                     self.login_message = Ok(String::new());
                 }
             }
+            Message::ClearReportExportMessage => self.report_export_message = Ok(String::new()),
+            Message::ClearTimerNotice => self.timer_notice = None,
             Message::CloseInspector => {
+                if self
+                    .task_to_edit
+                    .as_ref()
+                    .is_some_and(|task| task.is_unsaved)
+                {
+                    self.displayed_alert = Some(FurAlert::DiscardStoppedTaskConfirmation);
+                    return Task::none();
+                }
+                if confirm_discarding_changes(self, Message::CloseInspector) {
+                    return Task::none();
+                }
+                self.day_note_to_edit = None;
                 self.group_to_edit = None;
+                self.invoice_to_generate = None;
                 self.shortcut_to_add = None;
                 self.shortcut_to_edit = None;
                 self.task_to_add = None;
@@ -445,6 +1075,47 @@ impl Furtherance {
                 self.todo_to_edit = None;
                 self.inspector_view = None;
             }
+            Message::CompareWithPreviousToggled(compare) => {
+                return self.report.set_compare_with_previous(compare);
+            }
+            Message::ComparisonPeriodSelected(period) => {
+                return self.report.set_comparison_period(period);
+            }
+            Message::CopyErrorLog => {
+                return iced::clipboard::write(error_log_text(&self.errors));
+            }
+            Message::CopyStatusServerKey => {
+                return iced::clipboard::write(self.fur_settings.status_server_api_key.clone());
+            }
+            Message::CopyWeeklySummary(week_start) => match weekly_summary(self, week_start) {
+                Some(summary) => {
+                    return chain_tasks(vec![
+                        iced::clipboard::write(summary),
+                        show_timer_notice(
+                            self,
+                            self.localization.get_message("weekly-summary-copied", None),
+                        ),
+                    ]);
+                }
+                None => {
+                    return show_timer_notice(
+                        self,
+                        self.localization
+                            .get_message("error-creating-weekly-summary", None),
+                    );
+                }
+            },
+            Message::CopyYearInReview => {
+                if let Some(review) = &self.year_in_review {
+                    let summary = review_text(review, &self.localization);
+                    self.displayed_alert = None;
+                    let notice = Ok(self.localization.get_message("year-in-review-copied", None));
+                    return chain_tasks(vec![
+                        iced::clipboard::write(summary),
+                        show_report_export_notice(self, notice),
+                    ]);
+                }
+            }
             Message::CreateShortcutFromTaskGroup(task_group) => {
                 let new_shortcut = FurShortcut::new(
                     task_group.name,
@@ -465,34 +1136,127 @@ impl Furtherance {
                             self.displayed_alert = Some(FurAlert::ShortcutExists);
                         } else {
                             if let Err(e) = db_insert_shortcut(&new_shortcut) {
-                                eprintln!("Failed to write shortcut to database: {}", e);
+                                log_error(
+                                    &mut self.errors,
+                                    format!("Failed to write shortcut to database: {}", e),
+                                );
                             }
                             match db_retrieve_existing_shortcuts() {
                                 Ok(shortcuts) => self.shortcuts = shortcuts,
-                                Err(e) => {
-                                    eprintln!("Failed to retrieve shortcuts from database: {}", e)
-                                }
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to retrieve shortcuts from database: {}", e),
+                                ),
                             };
                             self.current_view = FurView::Shortcuts;
                             return sync_after_change(&self.fur_user);
                         }
                     }
-                    Err(e) => eprintln!("Failed to check if shortcut exists: {}", e),
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to check if shortcut exists: {}", e),
+                    ),
+                }
+            }
+            Message::DatabaseMergeFinished(result) => {
+                self.database_merge = None;
+                match result {
+                    Ok(summary) => {
+                        self.settings_database_message = Ok(self.localization.get_message(
+                            "database-merge-summary",
+                            Some(&HashMap::from([
+                                ("tasks", FluentValue::from(summary.tasks_added)),
+                                ("todos", FluentValue::from(summary.todos_added)),
+                                ("shortcuts", FluentValue::from(summary.shortcuts_added)),
+                                ("newer", FluentValue::from(summary.newer_applied)),
+                                ("conflicts", FluentValue::from(summary.conflicts_skipped)),
+                            ])),
+                        ));
+
+                        // Always do a full sync after import
+                        if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error changing needs_full_sync: {}", e),
+                            );
+                        };
+
+                        match db_retrieve_existing_shortcuts() {
+                            Ok(shortcuts) => self.shortcuts = shortcuts,
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve shortcuts from database: {}", e),
+                            ),
+                        };
+                        return chain_tasks(vec![reload_database(self), update_todo_list()]);
+                    }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Error merging database: {}", e));
+                        self.settings_database_message = Err(self
+                            .localization
+                            .get_message("error-merging-database", None)
+                            .into());
+                    }
+                }
+            }
+            Message::DatabaseMergeProgressed(progress) => {
+                self.database_merge = Some(progress);
+            }
+            Message::DeleteArchivedTodos => {
+                let uids: Vec<String> = self
+                    .archived_todos
+                    .values()
+                    .flatten()
+                    .map(|todo| todo.uid.clone())
+                    .collect();
+                if let Err(e) = db_delete_todos_by_ids(&uids) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to delete archived todos: {}", e),
+                    );
                 }
+                self.show_archived_todos = false;
+                self.displayed_alert = None;
+                return chain_tasks(vec![update_todo_list(), sync_after_change(&self.fur_user)]);
+            }
+            Message::DeleteArchivedTodosPressed => {
+                let delete_confirmation = self.fur_settings.show_delete_confirmation;
+                return Task::perform(
+                    async move {
+                        if delete_confirmation {
+                            Message::ShowAlert(FurAlert::DeleteArchivedTodosConfirmation)
+                        } else {
+                            Message::DeleteArchivedTodos
+                        }
+                    },
+                    |msg| msg,
+                );
             }
             Message::DeleteEverything => match db_delete_everything() {
                 Ok(_) => {
                     self.displayed_alert = None;
+                    self.delete_everything_confirmation = String::new();
                     self.settings_more_message =
                         Ok(self.localization.get_message("deleted-everything", None));
+                    self.day_notes = BTreeMap::new();
+                    self.days_off.dates = BTreeSet::new();
+                    self.report.set_days_off(self.days_off.clone());
+                    self.project_colors = HashMap::new();
+                    self.report.set_project_colors(HashMap::new());
+                    self.project_default_tags = HashMap::new();
+                    self.history_end_reached = false;
+                    self.report.invalidate_all();
+                    self.report.update_goal_progress();
+                    self.pomodoro.stats = FurPomodoroStats::default();
                     match db_retrieve_existing_shortcuts() {
                         Ok(shortcuts) => self.shortcuts = shortcuts,
-                        Err(e) => {
-                            eprintln!("Failed to retrieve shortcuts from database: {}", e)
-                        }
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to retrieve shortcuts from database: {}", e),
+                        ),
                     };
                     let mut tasks = vec![];
-                    tasks.push(update_task_history(self.fur_settings.days_to_show));
+                    tasks.push(update_task_history(self.history_days_loaded));
                     tasks.push(update_todo_list());
                     tasks.push(sync_after_change(&self.fur_user));
                     return chain_tasks(tasks);
@@ -504,17 +1268,98 @@ impl Furtherance {
                         .into());
                 }
             },
-            Message::DateRangeSelected(new_range) => self.report.set_picked_date_ranged(new_range),
+            Message::DeleteEverythingConfirmationChanged(new_value) => {
+                self.delete_everything_confirmation = new_value;
+            }
+            Message::DeleteLocalData => match db_delete_local_data() {
+                Ok(_) => {
+                    self.displayed_alert = None;
+                    self.delete_everything_confirmation = String::new();
+                    // Nothing was marked deleted, so the next sync downloads everything again
+                    if let Err(e) = self.fur_settings.change_last_sync(&0) {
+                        log_error(&mut self.errors, format!("Error changing last_sync: {}", e));
+                    };
+                    if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Error changing needs_full_sync: {}", e),
+                        );
+                    };
+                    self.settings_more_message =
+                        Ok(self.localization.get_message("deleted-local-data", None));
+                    self.day_notes = BTreeMap::new();
+                    self.days_off.dates = BTreeSet::new();
+                    self.report.set_days_off(self.days_off.clone());
+                    self.project_colors = HashMap::new();
+                    self.report.set_project_colors(HashMap::new());
+                    self.project_default_tags = HashMap::new();
+                    self.history_end_reached = false;
+                    self.report.invalidate_all();
+                    self.report.update_goal_progress();
+                    self.pomodoro.stats = FurPomodoroStats::default();
+                    self.shortcuts = vec![];
+                    return chain_tasks(vec![
+                        update_task_history(self.history_days_loaded),
+                        update_todo_list(),
+                    ]);
+                }
+                Err(e) => {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to delete local data: {}", e),
+                    );
+                    self.settings_more_message = Err(self
+                        .localization
+                        .get_message("error-deleting-local-data", None)
+                        .into());
+                }
+            },
+            Message::BillableFilterSelected(new_filter) => {
+                return self.report.set_billable_filter(new_filter);
+            }
+            Message::DateRangeSelected(new_range) => {
+                return self.report.set_picked_date_ranged(new_range);
+            }
+            Message::DayNoteEdited(action) => {
+                if let Some(day_note_to_edit) = self.day_note_to_edit.as_mut() {
+                    day_note_to_edit.content.perform(action);
+                }
+            }
+            Message::DeleteProfile(name) => {
+                // The database itself is left alone
+                let mut profiles = self.fur_settings.profiles.clone();
+                profiles.retain(|profile| {
+                    profile.name != name || profile.database_url == self.fur_settings.database_url
+                });
+                if let Err(e) = self.fur_settings.change_profiles(&profiles) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change profiles in settings: {}", e),
+                    );
+                }
+            }
+            Message::DeleteGoalPressed(uid) => {
+                if let Err(e) = db_delete_goal_by_id(&uid) {
+                    log_error(&mut self.errors, format!("Failed to delete goal: {}", e));
+                }
+                self.report.update_goal_progress();
+            }
             Message::DeleteShortcut => {
                 if let Some(uid) = &self.delete_shortcut_from_context {
                     if let Err(e) = db_delete_shortcut_by_id(uid) {
-                        eprintln!("Failed to delete shortcut: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to delete shortcut: {}", e),
+                        );
                     }
                     self.delete_shortcut_from_context = None;
                     self.displayed_alert = None;
                     match db_retrieve_existing_shortcuts() {
                         Ok(shortcuts) => self.shortcuts = shortcuts,
-                        Err(e) => eprintln!("Failed to retrieve shortcuts from database: {}", e),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to retrieve shortcuts from database: {}", e),
+                        ),
                     };
                 }
             }
@@ -535,30 +1380,31 @@ impl Furtherance {
             Message::DeleteTasks => {
                 if let Some(tasks_to_delete) = &self.delete_tasks_from_context {
                     if let Err(e) = db_delete_tasks_by_ids(tasks_to_delete) {
-                        eprintln!("Failed to delete tasks: {}", e);
+                        log_error(&mut self.errors, format!("Failed to delete tasks: {}", e));
                     }
                     self.delete_tasks_from_context = None;
+                    self.history_selection = None;
                     self.inspector_view = None;
                     self.group_to_edit = None;
                     self.task_to_edit = None;
                     self.displayed_alert = None;
-                    return update_task_history(self.fur_settings.days_to_show);
+                    return update_task_history(self.history_days_loaded);
                 } else if let Some(task_to_edit) = &self.task_to_edit {
                     self.inspector_view = None;
                     if let Err(e) = db_delete_tasks_by_ids(&[task_to_edit.uid.clone()]) {
-                        eprintln!("Failed to delete task: {}", e);
+                        log_error(&mut self.errors, format!("Failed to delete task: {}", e));
                     }
                     self.task_to_edit = None;
                     self.displayed_alert = None;
-                    return update_task_history(self.fur_settings.days_to_show);
+                    return update_task_history(self.history_days_loaded);
                 } else if let Some(group_to_edit) = &self.group_to_edit {
                     self.inspector_view = None;
                     if let Err(e) = db_delete_tasks_by_ids(&group_to_edit.all_task_ids()) {
-                        eprintln!("Failed to delete tasks: {}", e);
+                        log_error(&mut self.errors, format!("Failed to delete tasks: {}", e));
                     }
                     self.group_to_edit = None;
                     self.displayed_alert = None;
-                    return update_task_history(self.fur_settings.days_to_show);
+                    return update_task_history(self.history_days_loaded);
                 }
             }
             Message::DeleteTasksFromContext(task_group_ids) => {
@@ -584,7 +1430,7 @@ impl Furtherance {
             Message::DeleteTodo => {
                 if let Some(todo_to_delete) = &self.delete_todo_uid {
                     if let Err(e) = db_delete_todo_by_id(todo_to_delete) {
-                        eprintln!("Failed to delete todo: {}", e);
+                        log_error(&mut self.errors, format!("Failed to delete todo: {}", e));
                     }
                     self.delete_todo_uid = None;
                     self.inspector_view = None;
@@ -594,7 +1440,7 @@ impl Furtherance {
                 } else if let Some(todo_to_edit) = &self.todo_to_edit {
                     self.inspector_view = None;
                     if let Err(e) = db_delete_todo_by_id(&todo_to_edit.uid.clone()) {
-                        eprintln!("Failed to delete todo: {}", e);
+                        log_error(&mut self.errors, format!("Failed to delete todo: {}", e));
                     }
                     self.todo_to_edit = None;
                     self.displayed_alert = None;
@@ -615,30 +1461,110 @@ impl Furtherance {
                     |msg| msg,
                 );
             }
-            Message::Done => {}
-            Message::EditGroup(task_group) => {
-                if task_group.tasks.len() == 1 {
-                    if let Some(task_to_edit) = task_group.tasks.first() {
-                        self.task_to_edit = Some(TaskToEdit::new_from(task_to_edit));
-                    }
-                    self.inspector_view = Some(FurInspectorView::EditTask);
-                } else {
-                    self.group_to_edit = Some(GroupToEdit::new_from(&task_group));
-                    self.inspector_view = Some(FurInspectorView::EditGroup);
+            Message::DiscardChanges => {
+                self.displayed_alert = None;
+                discard_inspector_changes(self);
+                if let Some(then) = self.discard_changes_then.take() {
+                    return Task::perform(async move { then }, |msg| msg);
                 }
             }
-            Message::EditShortcutPressed(shortcut) => {
-                self.shortcut_to_edit = Some(ShortcutToEdit::new_from(&shortcut));
-                self.inspector_view = Some(FurInspectorView::EditShortcut);
+            Message::DiscardStoppedTask => {
+                self.displayed_alert = None;
+                if self
+                    .task_to_edit
+                    .as_ref()
+                    .is_some_and(|task| task.is_unsaved)
+                {
+                    delete_autosave();
+                    self.task_to_edit = None;
+                    self.inspector_view = None;
+                }
             }
-            Message::EditShortcutTextChanged(new_value, property) => {
+            Message::Done => {}
+            Message::DroppedDatabaseMergePressed => {
+                self.displayed_alert = None;
+                let Some(path) = self.dropped_database.take() else {
+                    return Task::none();
+                };
+                if self.timer_is_running {
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message("drop-while-timer-running", None)
+                        .into());
+                    return Task::none();
+                }
+                return Task::perform(async move { Message::MergeDatabase(path) }, |msg| msg);
+            }
+            Message::DroppedDatabaseOpenPressed => {
+                self.displayed_alert = None;
+                let Some(path) = self.dropped_database.take() else {
+                    return Task::none();
+                };
+                if self.timer_is_running {
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message("drop-while-timer-running", None)
+                        .into());
+                    return Task::none();
+                }
+                return Task::perform(
+                    async move { Message::ChangeDatabase(path, ChangeDB::Open) },
+                    |msg| msg,
+                );
+            }
+            Message::DuplicateTaskGroup(task_group) => {
+                // Copies simply coexist with the originals, even though they overlap
+                if let Err(e) = db_insert_tasks(&task_group.duplicate_tasks(None)) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to duplicate tasks: {}", e),
+                    );
+                }
+                return chain_tasks(vec![
+                    update_task_history(self.history_days_loaded),
+                    sync_after_change(&self.fur_user),
+                ]);
+            }
+            Message::DuplicateTaskGroupToToday(task_group) => {
+                if let Err(e) = db_insert_tasks(&task_group.duplicate_tasks(Some(Local::now()))) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to duplicate tasks: {}", e),
+                    );
+                }
+                return chain_tasks(vec![
+                    update_task_history(self.history_days_loaded),
+                    sync_after_change(&self.fur_user),
+                ]);
+            }
+            Message::EditGroup(task_group) => {
+                if task_group.tasks.len() == 1 {
+                    if let Some(task_to_edit) = task_group.tasks.first() {
+                        self.task_to_edit = Some(TaskToEdit::new_from(task_to_edit));
+                    }
+                    self.inspector_view = Some(FurInspectorView::EditTask);
+                } else {
+                    self.group_to_edit = Some(GroupToEdit::new_from(&task_group));
+                    self.inspector_view = Some(FurInspectorView::EditGroup);
+                }
+            }
+            Message::EditShortcutPressed(shortcut) => {
+                let mut shortcut_to_edit = ShortcutToEdit::new_from(&shortcut);
+                match db_retrieve_shortcut_rate_changes(&shortcut.uid) {
+                    Ok(changes) => shortcut_to_edit.rate_history = changes,
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to retrieve shortcut rate changes: {}", e),
+                    ),
+                }
+                self.shortcut_to_edit = Some(shortcut_to_edit);
+                self.inspector_view = Some(FurInspectorView::EditShortcut);
+            }
+            Message::EditShortcutTextChanged(new_value, property) => {
                 if let Some(shortcut_to_add) = self.shortcut_to_add.as_mut() {
                     match property {
                         EditTaskProperty::Name => {
-                            if new_value.contains('#')
-                                || new_value.contains('@')
-                                || new_value.contains('$')
-                            {
+                            if has_unescaped_task_symbol(&new_value) {
                                 shortcut_to_add.input_error(
                                     self.localization.get_message("name-cannot-contain", None),
                                 );
@@ -701,10 +1627,7 @@ impl Furtherance {
                 } else if let Some(shortcut_to_edit) = self.shortcut_to_edit.as_mut() {
                     match property {
                         EditTaskProperty::Name => {
-                            if new_value.contains('#')
-                                || new_value.contains('@')
-                                || new_value.contains('$')
-                            {
+                            if has_unescaped_task_symbol(&new_value) {
                                 shortcut_to_edit.input_error(
                                     self.localization.get_message("name-cannot-contain", None),
                                 )
@@ -771,15 +1694,25 @@ impl Furtherance {
                 self.task_to_edit = Some(TaskToEdit::new_from(&task));
                 self.inspector_view = Some(FurInspectorView::EditTask);
             }
+            Message::EditTaskBillableToggled(is_billable) => match self.inspector_view {
+                Some(FurInspectorView::AddNewTask) => {
+                    if let Some(task_to_add) = self.task_to_add.as_mut() {
+                        task_to_add.is_billable = is_billable;
+                    }
+                }
+                Some(FurInspectorView::EditTask) => {
+                    if let Some(task_to_edit) = self.task_to_edit.as_mut() {
+                        task_to_edit.new_is_billable = is_billable;
+                    }
+                }
+                _ => {}
+            },
             Message::EditTaskTextChanged(new_value, property) => match self.inspector_view {
                 Some(FurInspectorView::AddNewTask) => {
                     if let Some(task_to_add) = self.task_to_add.as_mut() {
                         match property {
                             EditTaskProperty::Name => {
-                                if new_value.contains('#')
-                                    || new_value.contains('@')
-                                    || new_value.contains('$')
-                                {
+                                if has_unescaped_task_symbol(&new_value) {
                                     task_to_add.invalid_input_error_message =
                                         self.localization.get_message("name-cannot-contain", None);
                                 } else {
@@ -844,10 +1777,7 @@ impl Furtherance {
                     if let Some(task_to_edit) = self.task_to_edit.as_mut() {
                         match property {
                             EditTaskProperty::Name => {
-                                if new_value.contains('#')
-                                    || new_value.contains('@')
-                                    || new_value.contains('$')
-                                {
+                                if has_unescaped_task_symbol(&new_value) {
                                     task_to_edit.input_error(
                                         self.localization.get_message("name-cannot-contain", None),
                                     );
@@ -913,10 +1843,7 @@ impl Furtherance {
                     if let Some(group_to_edit) = self.group_to_edit.as_mut() {
                         match property {
                             EditTaskProperty::Name => {
-                                if new_value.contains('#')
-                                    || new_value.contains('@')
-                                    || new_value.contains('$')
-                                {
+                                if has_unescaped_task_symbol(&new_value) {
                                     group_to_edit.input_error(
                                         self.localization.get_message("name-cannot-contain", None),
                                     );
@@ -985,10 +1912,7 @@ impl Furtherance {
                     if let Some(todo_to_add) = self.todo_to_add.as_mut() {
                         match property {
                             EditTodoProperty::Task => {
-                                if new_value.contains('#')
-                                    || new_value.contains('@')
-                                    || new_value.contains('$')
-                                {
+                                if has_unescaped_task_symbol(&new_value) {
                                     todo_to_add.invalid_input_error_message =
                                         self.localization.get_message("name-cannot-contain", None);
                                 } else {
@@ -1045,6 +1969,16 @@ impl Furtherance {
                                     );
                                 }
                             }
+                            EditTodoProperty::Estimate => {
+                                if parse_duration_input(&new_value).is_some() {
+                                    todo_to_add.input_error(String::new());
+                                } else {
+                                    todo_to_add.input_error(
+                                        self.localization.get_message("estimate-invalid", None),
+                                    );
+                                }
+                                todo_to_add.estimate = new_value;
+                            }
                             _ => {}
                         }
                     }
@@ -1053,10 +1987,7 @@ impl Furtherance {
                     if let Some(todo_to_edit) = self.todo_to_edit.as_mut() {
                         match property {
                             EditTodoProperty::Task => {
-                                if new_value.contains('#')
-                                    || new_value.contains('@')
-                                    || new_value.contains('$')
-                                {
+                                if has_unescaped_task_symbol(&new_value) {
                                     todo_to_edit.input_error(
                                         self.localization.get_message("name-cannot-contain", None),
                                     );
@@ -1114,17 +2045,53 @@ impl Furtherance {
                                     );
                                 }
                             }
+                            EditTodoProperty::Estimate => {
+                                if parse_duration_input(&new_value).is_some() {
+                                    todo_to_edit.input_error(String::new());
+                                } else {
+                                    todo_to_edit.input_error(
+                                        self.localization.get_message("estimate-invalid", None),
+                                    );
+                                }
+                                todo_to_edit.new_estimate = new_value;
+                            }
                             _ => {}
                         }
                     }
                 }
                 _ => {}
             },
+            Message::EditDayNote(date) => {
+                self.day_note_to_edit = Some(DayNoteToEdit::new(date, self.day_notes.get(&date)));
+                self.inspector_view = Some(FurInspectorView::EditDayNote);
+            }
             Message::EditTodo(todo_to_edit) => {
                 self.todo_to_edit = Some(TodoToEdit::new_from(&todo_to_edit));
                 self.inspector_view = Some(FurInspectorView::EditTodo);
             }
             Message::EnterPressedInTaskInput => {
+                if let Some(task_input) = self.recent_tasks.focused_task() {
+                    let task_input = task_input.clone();
+                    return Task::perform(
+                        async move { Message::RecentTaskPicked(task_input) },
+                        |msg| msg,
+                    );
+                }
+                self.recent_tasks.close();
+                if self.timer_is_running && self.keyboard_modifiers.command() {
+                    return Task::perform(async { Message::SwitchTask }, |msg| msg);
+                }
+                self.task_input_focused = false;
+                if let Some((task_input, seconds)) = split_logged_duration(&self.task_input) {
+                    if self.timer_is_running {
+                        return show_timer_notice(
+                            self,
+                            self.localization
+                                .get_message("log-duration-timer-running", None),
+                        );
+                    }
+                    return log_task_from_input(self, &task_input, seconds);
+                }
                 if !self.task_input.is_empty() {
                     if !self.timer_is_running {
                         return Task::perform(async { Message::StartStopPressed }, |msg| msg);
@@ -1141,6 +2108,7 @@ impl Furtherance {
             }
             Message::ExportCsvPressed => {
                 self.settings_csv_message = Ok(String::new());
+                self.csv_import_errors = vec![];
                 self.settings_database_message = Ok(String::new());
                 let file_name = format!("furtherance-{}.csv", Local::now().format("%Y-%m-%d"));
                 let selected_file = FileDialog::new()
@@ -1151,13 +2119,21 @@ impl Furtherance {
                     .save_file();
 
                 if let Some(path) = selected_file {
-                    match write_furtasks_to_csv(path, &self.export_settings, &self.localization) {
+                    match write_furtasks_to_csv(
+                        path,
+                        &self.export_settings,
+                        &self.fur_settings,
+                        &self.localization,
+                    ) {
                         Ok(_) => {
                             self.settings_csv_message =
                                 Ok(self.localization.get_message("csv-file-saved", None))
                         }
                         Err(e) => {
-                            eprintln!("Error writing data to CSV: {}", e);
+                            log_error(
+                                &mut self.errors,
+                                format!("Error writing data to CSV: {}", e),
+                            );
                             self.settings_csv_message = Err(self
                                 .localization
                                 .get_message("error-writing-csv", None)
@@ -1166,9 +2142,92 @@ impl Furtherance {
                     }
                 }
             }
+            Message::ExportSettingsPressed => {
+                self.settings_more_message = Ok(String::new());
+                let file_name = format!(
+                    "furtherance-settings-{}.json",
+                    Local::now().format("%Y-%m-%d")
+                );
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("save-settings-title", None))
+                    .add_filter("JSON", &["json"])
+                    .set_can_create_directories(true)
+                    .set_file_name(file_name)
+                    .save_file();
+
+                if let Some(path) = selected_file {
+                    let result = self
+                        .fur_settings
+                        .to_export()
+                        .and_then(|export| serde_json::to_string_pretty(&export))
+                        .map_err(|e| e.to_string())
+                        .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+                    match result {
+                        Ok(_) => {
+                            self.settings_more_message =
+                                Ok(self.localization.get_message("settings-exported", None))
+                        }
+                        Err(e) => {
+                            log_error(&mut self.errors, format!("Error exporting settings: {}", e));
+                            self.settings_more_message = Err(self
+                                .localization
+                                .get_message("error-exporting-settings", None)
+                                .into());
+                        }
+                    }
+                }
+            }
+            Message::ExportShortcutsPressed => {
+                self.settings_more_message = Ok(String::new());
+                let file_name = format!(
+                    "furtherance-shortcuts-{}.json",
+                    Local::now().format("%Y-%m-%d")
+                );
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("save-shortcuts-title", None))
+                    .add_filter("JSON", &["json"])
+                    .set_can_create_directories(true)
+                    .set_file_name(file_name)
+                    .save_file();
+
+                if let Some(path) = selected_file {
+                    let result = db_retrieve_existing_shortcuts()
+                        .map_err(|e| e.to_string())
+                        .and_then(|shortcuts| {
+                            serde_json::to_string_pretty(&ShortcutsExport::new(&shortcuts))
+                                .map_err(|e| e.to_string())
+                        })
+                        .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+                    match result {
+                        Ok(_) => {
+                            self.settings_more_message =
+                                Ok(self.localization.get_message("shortcuts-exported", None))
+                        }
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error exporting shortcuts: {}", e),
+                            );
+                            self.settings_more_message = Err(self
+                                .localization
+                                .get_message("error-exporting-shortcuts", None)
+                                .into());
+                        }
+                    }
+                }
+            }
+            Message::ExportBillableColumnToggled(toggled) => {
+                self.export_settings.billable = toggled;
+            }
             Message::ExportCurrencyColumnToggled(toggled) => {
                 self.export_settings.currency = toggled;
             }
+            Message::ExportDayNotesToggled(toggled) => {
+                self.export_settings.day_notes = toggled;
+            }
+            Message::ExportDeviceColumnToggled(toggled) => {
+                self.export_settings.device = toggled;
+            }
             Message::ExportFilterByDateToggled(toggled) => {
                 self.export_settings.filter_by_date = toggled;
             }
@@ -1187,6 +2246,52 @@ impl Furtherance {
             Message::ExportRateColumnToggled(toggled) => {
                 self.export_settings.rate = toggled;
             }
+            Message::ExportReportSelectionPressed => {
+                let file_name =
+                    format!("furtherance-report-{}.csv", Local::now().format("%Y-%m-%d"));
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("save-csv-title", None))
+                    .add_filter("CSV", &["csv"])
+                    .set_can_create_directories(true)
+                    .set_file_name(file_name)
+                    .save_file();
+
+                if let Some(path) = selected_file {
+                    // The selection only applies while its breakdown is on screen
+                    let include_selection = self.fur_settings.show_chart_breakdown_by_selection;
+                    match write_filtered_tasks_to_csv(
+                        path,
+                        &self.report.export_tasks(include_selection),
+                        &self.report.export_description(include_selection),
+                        &self.export_settings,
+                        &self.fur_settings,
+                    ) {
+                        Ok(_) => {
+                            self.report_export_message =
+                                Ok(self.localization.get_message("csv-file-saved", None))
+                        }
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error writing report selection to CSV: {}", e),
+                            );
+                            self.report_export_message = Err(self
+                                .localization
+                                .get_message("error-writing-csv", None)
+                                .into());
+                        }
+                    }
+                    return Task::perform(
+                        async {
+                            tokio::time::sleep(std::time::Duration::from_secs(
+                                SETTINGS_MESSAGE_DURATION,
+                            ))
+                            .await;
+                        },
+                        |_| Message::ClearReportExportMessage,
+                    );
+                }
+            }
             Message::ExportSortOrderSelected(sort_order) => {
                 self.export_settings.sort_order = sort_order;
             }
@@ -1205,102 +2310,1041 @@ impl Furtherance {
             Message::ExportTotalEarningsColumnToggled(toggled) => {
                 self.export_settings.total_earnings = toggled;
             }
-            Message::FontLoaded(_) => {}
-            Message::IdleDiscard => {
-                stop_timer(self, self.idle.start_time);
-                self.displayed_alert = None;
-                let mut tasks = vec![];
-                tasks.push(update_task_history(self.fur_settings.days_to_show));
-                tasks.push(sync_after_change(&self.fur_user));
-                return chain_tasks(tasks);
-            }
-            Message::IdleReset => {
-                self.idle = FurIdle::new();
-                self.displayed_alert = None;
-            }
-            Message::ImportCsvPressed => {
+            Message::FileDropped(path) => {
+                // Don't replace an alert that's still waiting for an answer, or start
+                // on the database while an upgrade or merge is still writing to it
+                if self.displayed_alert.is_some()
+                    || self.legacy_upgrade.is_some()
+                    || self.database_merge.is_some()
+                {
+                    return Task::none();
+                }
+                // Switching or merging databases under a running or unsaved timer could
+                // lose the task it's tracking, so the drop only explains why it was ignored
+                let timer_busy = self.timer_is_running
+                    || self
+                        .task_to_edit
+                        .as_ref()
+                        .is_some_and(|task| task.is_unsaved);
+                if !timer_busy
+                    && confirm_discarding_changes(self, Message::FileDropped(path.clone()))
+                {
+                    return Task::none();
+                }
+
                 self.settings_csv_message = Ok(String::new());
+                self.csv_import_errors = vec![];
                 self.settings_database_message = Ok(String::new());
-                let selected_file = FileDialog::new()
-                    .set_title(self.localization.get_message("open-csv-title", None))
-                    .add_filter("CSV", &["csv"])
-                    .set_can_create_directories(false)
-                    .pick_file();
-                if let Some(path) = selected_file {
-                    if let Ok(mut file) = File::open(path) {
-                        match verify_csv(&file, &self.localization) {
-                            Ok(_) => {
-                                import_csv_to_database(&mut file, &self.localization);
-                                self.settings_csv_message =
-                                    Ok(self.localization.get_message("csv-imported", None).into());
 
-                                // Always do a full sync after import
-                                if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
-                                    eprintln!("Error changing needs_full_sync: {}", e);
-                                };
+                // Imports report how they went in the Data settings, so show them there
+                self.current_view = FurView::Settings;
+                self.settings_active_tab = TabId::Data;
+                refresh_trash(self);
+                refresh_sync_status(self);
+                if timer_busy {
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message("drop-while-timer-running", None)
+                        .into());
+                    return Task::none();
+                }
+                let close_inspector = Task::perform(async { Message::CloseInspector }, |msg| msg);
 
-                                return update_task_history(self.fur_settings.days_to_show);
-                            }
-                            Err(e) => {
-                                eprintln!("Invalid CSV file: {}", e);
-                                self.settings_csv_message = Err(self
-                                    .localization
-                                    .get_message("invalid-csv-file", None)
-                                    .into());
-                            }
+                let extension = path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .map(|extension| extension.to_lowercase())
+                    .unwrap_or_default();
+                if extension == "csv" {
+                    return chain_tasks(vec![
+                        close_inspector,
+                        Task::perform(async move { Message::ImportCsvFile(path) }, |msg| msg),
+                    ]);
+                } else if ALLOWED_DB_EXTENSIONS.contains(&extension.as_str()) {
+                    self.dropped_database = Some(path);
+                    self.displayed_alert = Some(FurAlert::DroppedDatabase);
+                } else {
+                    let file_name = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message(
+                            "dropped-file-unsupported",
+                            Some(&HashMap::from([("file", FluentValue::from(file_name))])),
+                        )
+                        .into());
+                }
+                return close_inspector;
+            }
+            Message::FindDuplicatesPressed => {
+                self.settings_more_message = Ok(String::new());
+                match db_find_duplicates() {
+                    Ok(duplicates) => {
+                        if duplicates.count() == 0 {
+                            self.settings_more_message =
+                                Ok(self.localization.get_message("no-duplicates-found", None));
+                        } else {
+                            self.duplicates_found = Some(duplicates);
+                            self.displayed_alert = Some(FurAlert::MergeDuplicatesConfirmation);
                         }
                     }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Error finding duplicates: {}", e));
+                        self.settings_more_message = Err(self
+                            .localization
+                            .get_message("error-finding-duplicates", None)
+                            .into());
+                    }
                 }
             }
-            Message::ImportOldMacDatabase => {
-                self.displayed_alert = None;
-                match db_import_old_mac_db() {
-                    Ok(_) => {
-                        // Always do a full sync after import
-                        if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
-                            eprintln!("Error changing needs_full_sync: {}", e);
+            Message::FindReplaceApplyPressed => {
+                if !self.find_replace.can_apply() {
+                    return Task::none();
+                }
+                let find = self.find_replace.find_value();
+                let replace = self.find_replace.replace_value();
+                let result = match self.find_replace.field {
+                    FurRenameField::Project => db_rename_project(&find, &replace),
+                    FurRenameField::Tag => db_rename_tag(&find, &replace),
+                    FurRenameField::TaskName => db_rename_task_name(&find, &replace),
+                };
+                match result {
+                    Ok(counts) => {
+                        self.settings_find_replace_message = Ok(self.localization.get_message(
+                            "find-replace-applied",
+                            Some(&HashMap::from([(
+                                "count",
+                                FluentValue::from(counts.total()),
+                            )])),
+                        ));
+                        self.find_replace = FindReplace::new();
+                        self.report.invalidate_all();
+                        self.report.update_goal_progress();
+                        refresh_project_lists(self);
+                        match db_retrieve_existing_shortcuts() {
+                            Ok(shortcuts) => self.shortcuts = shortcuts,
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve shortcuts from database: {}", e),
+                            ),
                         };
-
-                        return update_task_history(self.fur_settings.days_to_show);
+                        return chain_tasks(vec![
+                            update_task_history(self.history_days_loaded),
+                            update_todo_list(),
+                            sync_after_change(&self.fur_user),
+                        ]);
                     }
                     Err(e) => {
-                        eprintln!("Error importing existing Core Data database: {e}")
+                        log_error(&mut self.errors, format!("Error replacing values: {}", e));
+                        self.settings_find_replace_message = Err(self
+                            .localization
+                            .get_message("error-find-replace", None)
+                            .into());
                     }
                 }
             }
-            Message::LearnAboutSync => {
-                if let Err(e) = webbrowser::open("https://furtherance.app/sync") {
-                    eprintln!("Failed to open URL in browser: {}", e);
+            Message::FindReplaceFieldSelected(field) => {
+                self.find_replace.set_field(field);
+                self.settings_find_replace_message = Ok(String::new());
+            }
+            Message::FindReplaceFindChanged(new_value) => {
+                self.find_replace.set_find(new_value);
+                self.settings_find_replace_message = Ok(String::new());
+            }
+            Message::FindReplacePreviewPressed => {
+                if !self.find_replace.can_preview() {
+                    return Task::none();
                 }
-                self.displayed_alert = None;
-                if let Err(e) = self.fur_settings.change_notify_of_sync(false) {
-                    eprintln!("Error changing notify_of_sync: {}", e);
+                let find = self.find_replace.find_value();
+                let counts = match self.find_replace.field {
+                    FurRenameField::Project => db_count_project_matches(&find),
+                    FurRenameField::Tag => db_count_tag_matches(&find),
+                    FurRenameField::TaskName => db_count_task_name_matches(&find),
                 };
+                match counts {
+                    Ok(counts) => {
+                        self.settings_find_replace_message = Ok(self.localization.get_message(
+                            "find-replace-preview",
+                            Some(&HashMap::from([
+                                ("tasks", FluentValue::from(counts.tasks)),
+                                ("todos", FluentValue::from(counts.todos)),
+                                ("shortcuts", FluentValue::from(counts.shortcuts)),
+                            ])),
+                        ));
+                        self.find_replace.preview = Some(counts);
+                    }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Error counting matches: {}", e));
+                        self.settings_find_replace_message = Err(self
+                            .localization
+                            .get_message("error-find-replace", None)
+                            .into());
+                    }
+                }
             }
-            Message::MidnightReached => {
-                let mut tasks = vec![];
-                tasks.push(update_task_history(self.fur_settings.days_to_show));
-                tasks.push(update_todo_list());
-                return chain_tasks(tasks);
+            Message::FindReplaceReplaceChanged(new_value) => {
+                self.find_replace.set_replace(new_value);
+                self.settings_find_replace_message = Ok(String::new());
             }
-            Message::NavigateTo(destination) => {
-                if self.current_view != destination {
-                    self.current_view = destination;
-                    if destination == FurView::Report {
-                        self.report.update_tasks_in_range();
+            Message::FontLoaded(_) => {}
+            Message::GenerateInvoicePressed => {
+                self.invoice_to_generate =
+                    Some(InvoiceToGenerate::new(&self.report, &self.fur_settings));
+                self.inspector_view = Some(FurInspectorView::GenerateInvoice);
+            }
+            Message::GoalHoursChanged(uid, new_hours) => {
+                if let Some((goal, _)) = self
+                    .report
+                    .weekly_goal_progress
+                    .iter_mut()
+                    .find(|(goal, _)| goal.uid == uid)
+                {
+                    goal.weekly_minutes = new_hours * 60;
+                    goal.last_updated = sync_timestamp();
+                    if let Err(e) = db_update_goal(goal) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to update goal in database: {}", e),
+                        );
                     }
-                    return Task::perform(async { Message::CloseInspector }, |msg| msg);
                 }
             }
-            Message::NotifyOfSyncClose => {
-                if let Err(e) = self.fur_settings.change_notify_of_sync(false) {
-                    eprintln!("Error changing notify_of_sync: {}", e);
-                };
-                return Task::perform(async { Message::AlertClose }, |msg| msg);
+            Message::GoalToAddHoursChanged(new_hours) => {
+                self.goal_to_add.weekly_hours = new_hours;
             }
-            Message::OpenUrl(url) => {
-                if let Err(e) = webbrowser::open(&url) {
-                    eprintln!("Failed to open URL in browser: {}", e);
+            Message::GoalToAddProjectChanged(new_value) => {
+                if new_value.contains('#') || new_value.contains('$') {
+                    self.goal_to_add.input_error(
+                        self.localization
+                            .get_message("project-cannot-contain", None),
+                    );
+                } else {
+                    self.goal_to_add.project = new_value;
+                    self.goal_to_add.input_error(String::new());
+                }
+            }
+            Message::GroupRateScopeSelected(update_past_rates) => {
+                if let Some(group_to_edit) = self.group_to_edit.as_mut() {
+                    group_to_edit.update_past_rates = update_past_rates;
+                }
+            }
+            Message::HistoryGroupSelected(task_ids, selected) => {
+                if let Some(selection) = self.history_selection.as_mut() {
+                    selection.set_group_selected(task_ids, selected);
+                }
+            }
+            Message::HistoryGroupingSelected(new_grouping) => {
+                if let Err(e) = self.fur_settings.change_history_grouping(&new_grouping) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change history_grouping in settings: {}", e),
+                    );
+                }
+                // Rows move around, so the keyboard focus would land on a different group
+                self.history_focus = None;
+                self.history_focus_actions = false;
+                refresh_history_sections(self);
+            }
+            Message::HistoryScrolled(relative_offset) => {
+                if relative_offset >= HISTORY_LOAD_OLDER_OFFSET {
+                    return Task::perform(async { Message::LoadOlderHistory }, |msg| msg);
+                }
+            }
+            Message::IdleDiscard => {
+                let length_check = stop_timer(self, self.idle.start_time);
+                self.displayed_alert = None;
+                let mut tasks = vec![length_check];
+                tasks.push(update_task_history(self.history_days_loaded));
+                tasks.push(sync_after_change(&self.fur_user));
+                return chain_tasks(tasks);
+            }
+            Message::IdleReset => {
+                self.idle = FurIdle::new();
+                self.displayed_alert = None;
+            }
+            Message::ImportSettingsPressed => {
+                self.settings_more_message = Ok(String::new());
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("open-settings-title", None))
+                    .add_filter("JSON", &["json"])
+                    .set_can_create_directories(false)
+                    .pick_file();
+
+                if let Some(path) = selected_file {
+                    let export = std::fs::read_to_string(path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|json| {
+                            serde_json::from_str::<SettingsExport>(&json).map_err(|e| e.to_string())
+                        });
+                    let export = match export {
+                        Ok(export) => export,
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error reading settings file: {}", e),
+                            );
+                            self.settings_more_message = Err(self
+                                .localization
+                                .get_message("error-reading-settings", None)
+                                .into());
+                            return Task::none();
+                        }
+                    };
+
+                    let old_status_file = self.fur_settings.status_file;
+                    match self.fur_settings.apply_export(&export) {
+                        Ok(summary) => {
+                            set_default_currency(&self.fur_settings.default_currency);
+                            self.earnings_exclusions_input =
+                                self.fur_settings.earnings_exclusions.join(", ");
+                            set_use_24_hour_time(self.fur_settings.use_24_hour_time);
+                            if !summary.skipped.is_empty() {
+                                log_error(
+                                    &mut self.errors,
+                                    format!(
+                                        "Skipped unrecognized settings: {}",
+                                        summary.skipped.join(", ")
+                                    ),
+                                );
+                            }
+                            self.settings_more_message = Ok(self.localization.get_message(
+                                "settings-imported",
+                                Some(&HashMap::from([
+                                    ("applied", FluentValue::from(summary.applied)),
+                                    ("skipped", FluentValue::from(summary.skipped.len())),
+                                ])),
+                            ));
+                        }
+                        Err(e) => {
+                            log_error(&mut self.errors, format!("Error importing settings: {}", e));
+                            self.settings_more_message = Err(self
+                                .localization
+                                .get_message("error-importing-settings", None)
+                                .into());
+                        }
+                    }
+
+                    // Apply the side effects the individual settings controls would have
+                    self.report
+                        .set_monthly_earnings_target(self.fur_settings.monthly_earnings_target);
+                    self.report
+                        .set_earnings_exclusions(self.fur_settings.earnings_exclusions.clone());
+                    self.report
+                        .set_show_heatmap(self.fur_settings.show_chart_heatmap);
+                    self.report
+                        .set_show_todo_chart(self.fur_settings.show_chart_todos);
+                    all_charts::set_chart_net_earnings(net_earnings_deduction(&self.fur_settings));
+                    self.days_off.weekends = self.fur_settings.report_weekends_off;
+                    self.report.set_days_off(self.days_off.clone());
+                    self.report
+                        .set_archived_projects(self.fur_settings.archived_projects.clone());
+                    self.export_settings
+                        .get_all_projects(&self.fur_settings.archived_projects);
+                    self.idle_backend_status = None;
+                    if self.fur_settings.status_file {
+                        update_status_file(self);
+                    } else if old_status_file {
+                        delete_status_file();
+                    }
+                    restart_status_server(self);
+
+                    let mut tasks = vec![];
+                    if self.timer_is_running {
+                        tasks.push(refresh_stopwatch(self));
+                    } else {
+                        self.timer_text = get_timer_text(self, 0);
+                    }
+                    tasks.push(update_task_history(self.history_days_loaded));
+                    return chain_tasks(tasks);
+                }
+            }
+            Message::ImportShortcutsPressed => {
+                self.settings_more_message = Ok(String::new());
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("open-shortcuts-title", None))
+                    .add_filter("JSON", &["json"])
+                    .set_can_create_directories(false)
+                    .pick_file();
+
+                if let Some(path) = selected_file {
+                    let export = std::fs::read_to_string(path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|json| {
+                            serde_json::from_str::<ShortcutsExport>(&json)
+                                .map_err(|e| e.to_string())
+                        });
+                    let export = match export {
+                        Ok(export) => export,
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error reading shortcuts file: {}", e),
+                            );
+                            self.settings_more_message = Err(self
+                                .localization
+                                .get_message("error-reading-shortcuts", None)
+                                .into());
+                            return Task::none();
+                        }
+                    };
+
+                    let mut imported = 0;
+                    let mut skipped = 0;
+                    for exported in export.shortcuts {
+                        let shortcut = exported.into_shortcut();
+                        match db_shortcut_exists(&shortcut) {
+                            Ok(true) => skipped += 1,
+                            Ok(false) => match db_insert_shortcut(&shortcut) {
+                                Ok(_) => imported += 1,
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to write shortcut to database: {}", e),
+                                ),
+                            },
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to check if shortcut exists: {}", e),
+                            ),
+                        }
+                    }
+
+                    self.settings_more_message = Ok(self.localization.get_message(
+                        "shortcuts-imported",
+                        Some(&HashMap::from([
+                            ("imported", FluentValue::from(imported)),
+                            ("skipped", FluentValue::from(skipped)),
+                        ])),
+                    ));
+                    if imported > 0 {
+                        match db_retrieve_existing_shortcuts() {
+                            Ok(shortcuts) => self.shortcuts = shortcuts,
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve shortcuts from database: {}", e),
+                            ),
+                        };
+                        return sync_after_change(&self.fur_user);
+                    }
+                }
+            }
+            Message::ImportCsvFile(path) => {
+                if let Ok(mut file) = File::open(path) {
+                    match verify_csv(&file, &self.localization) {
+                        Ok(_) => match import_csv_to_database(&mut file, &self.localization) {
+                            Ok(summary) => {
+                                self.day_notes = get_day_notes();
+                                self.history_end_reached = false;
+                                self.report.invalidate_all();
+                                self.settings_csv_message = Ok(self
+                                    .localization
+                                    .get_message(
+                                        "csv-import-summary",
+                                        Some(&HashMap::from([
+                                            ("imported", FluentValue::from(summary.imported)),
+                                            ("skipped", FluentValue::from(summary.errors.len())),
+                                            ("duplicates", FluentValue::from(summary.duplicates)),
+                                        ])),
+                                    )
+                                    .into());
+                                self.csv_import_errors = summary.errors;
+
+                                // Always do a full sync after import
+                                if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
+                                    log_error(
+                                        &mut self.errors,
+                                        format!("Error changing needs_full_sync: {}", e),
+                                    );
+                                };
+
+                                return update_task_history(self.history_days_loaded);
+                            }
+                            Err(e) => {
+                                log_error(&mut self.errors, format!("Error importing CSV: {}", e));
+                                self.settings_csv_message = Err(self
+                                    .localization
+                                    .get_message("error-importing-csv", None)
+                                    .into());
+                            }
+                        },
+                        Err(e) => {
+                            log_error(&mut self.errors, format!("Invalid CSV file: {}", e));
+                            self.settings_csv_message = Err(self
+                                .localization
+                                .get_message("invalid-csv-file", None)
+                                .into());
+                        }
+                    }
+                }
+            }
+            Message::ImportCsvPressed => {
+                self.settings_csv_message = Ok(String::new());
+                self.csv_import_errors = vec![];
+                self.settings_database_message = Ok(String::new());
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("open-csv-title", None))
+                    .add_filter("CSV", &["csv"])
+                    .set_can_create_directories(false)
+                    .pick_file();
+                if let Some(path) = selected_file {
+                    return Task::perform(async move { Message::ImportCsvFile(path) }, |msg| msg);
+                }
+            }
+            Message::ImportOldMacDatabase => {
+                self.displayed_alert = None;
+                match db_import_old_mac_db() {
+                    Ok(_) => {
+                        // Always do a full sync after import
+                        if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error changing needs_full_sync: {}", e),
+                            );
+                        };
+
+                        self.history_end_reached = false;
+                        self.report.invalidate_all();
+                        return update_task_history(self.history_days_loaded);
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Error importing existing Core Data database: {e}"),
+                    ),
+                }
+            }
+            Message::InvoiceProjectSelected(project) => {
+                if let Some(invoice_to_generate) = self.invoice_to_generate.as_mut() {
+                    invoice_to_generate.project = project;
+                    invoice_to_generate.message = Ok(String::new());
+                }
+            }
+            Message::InvoiceTextChanged(new_value, field) => {
+                if let Some(invoice_to_generate) = self.invoice_to_generate.as_mut() {
+                    match field {
+                        InvoiceField::From => invoice_to_generate.header.from = new_value,
+                        InvoiceField::Client => invoice_to_generate.header.client = new_value,
+                        InvoiceField::Number => invoice_to_generate.header.number = new_value,
+                        InvoiceField::Notes => invoice_to_generate.header.notes = new_value,
+                    }
+                    invoice_to_generate.message = Ok(String::new());
+                }
+            }
+            Message::KeyboardActivate => {
+                if self.displayed_alert.is_some() {
+                    return Task::none();
+                }
+                if let Some(task_input) = self.recent_tasks.focused_task() {
+                    let task_input = task_input.clone();
+                    return Task::perform(
+                        async move { Message::RecentTaskPicked(task_input) },
+                        |msg| msg,
+                    );
+                }
+                match self.current_view {
+                    FurView::Timer => {
+                        let task_groups = focusable_history_groups(self);
+                        if let Some(task_group) =
+                            self.history_focus.and_then(|index| task_groups.get(index))
+                        {
+                            // Same as clicking the row
+                            let message = match &self.history_selection {
+                                Some(selection) => Message::HistoryGroupSelected(
+                                    task_group.all_task_ids(),
+                                    !selection.is_group_selected(task_group),
+                                ),
+                                None => Message::EditGroup((*task_group).clone()),
+                            };
+                            self.history_focus_actions = false;
+                            return Task::perform(async move { message }, |msg| msg);
+                        }
+                    }
+                    FurView::Shortcuts => {
+                        let shortcuts = sort_shortcuts(
+                            &self.shortcuts,
+                            &self.shortcut_usage,
+                            self.fur_settings.shortcut_sort,
+                        );
+                        if let Some(shortcut) =
+                            self.shortcut_focus.and_then(|index| shortcuts.get(index))
+                        {
+                            if !self.pomodoro.on_break {
+                                let shortcut = (*shortcut).clone();
+                                self.shortcut_focus_actions = false;
+                                return Task::perform(
+                                    async move { Message::ShortcutPressed(shortcut) },
+                                    |msg| msg,
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Message::KeyboardContextMenu => {
+                if self.displayed_alert.is_some() {
+                    return Task::none();
+                }
+                match self.current_view {
+                    FurView::Timer if self.history_focus.is_some() => {
+                        self.history_focus_actions = !self.history_focus_actions;
+                    }
+                    FurView::Shortcuts if self.shortcut_focus.is_some() => {
+                        self.shortcut_focus_actions = !self.shortcut_focus_actions;
+                    }
+                    _ => {}
+                }
+            }
+            Message::KeyboardDelete => {
+                if self.displayed_alert.is_some() {
+                    return Task::none();
+                }
+                let message = match self.current_view {
+                    FurView::Timer => {
+                        let task_groups = focusable_history_groups(self);
+                        self.history_focus
+                            .and_then(|index| task_groups.get(index))
+                            .map(|task_group| {
+                                Message::DeleteTasksFromContext(task_group.all_task_ids())
+                            })
+                    }
+                    FurView::Shortcuts => {
+                        let shortcuts = sort_shortcuts(
+                            &self.shortcuts,
+                            &self.shortcut_usage,
+                            self.fur_settings.shortcut_sort,
+                        );
+                        self.shortcut_focus
+                            .and_then(|index| shortcuts.get(index))
+                            .map(|shortcut| {
+                                Message::DeleteShortcutFromContext(shortcut.uid.clone())
+                            })
+                    }
+                    _ => None,
+                };
+                if let Some(message) = message {
+                    self.history_focus_actions = false;
+                    self.shortcut_focus_actions = false;
+                    return Task::perform(async move { message }, |msg| msg);
+                }
+            }
+            Message::KeyboardFocusCleared => {
+                let menu_was_open = self.recent_tasks.is_open;
+                self.recent_tasks.close();
+                self.task_input_focused = false;
+                self.history_focus = None;
+                self.history_focus_actions = false;
+                self.shortcut_focus = None;
+                self.shortcut_focus_actions = false;
+
+                // Escape closes the inspector like its Cancel button, unless that would lose changes
+                if !menu_was_open
+                    && self.displayed_alert.is_none()
+                    && let Some(view) = &self.inspector_view
+                {
+                    let cancel = inspector_cancel_message(view);
+                    if !confirm_discarding_changes(self, cancel.clone()) {
+                        return Task::perform(async move { cancel }, |msg| msg);
+                    }
+                }
+            }
+            Message::KeyboardModifiersChanged(modifiers) => {
+                self.keyboard_modifiers = modifiers;
+            }
+            Message::KeyboardNavigate(direction) => {
+                if self.displayed_alert.is_some() {
+                    return Task::none();
+                }
+                if self.recent_tasks.is_open {
+                    self.recent_tasks.move_focus(direction);
+                    return Task::none();
+                }
+                // The task input lets Down through, which opens the recent tasks below it
+                if self.task_input_focused
+                    && direction == NavDirection::Down
+                    && self.current_view == FurView::Timer
+                    && !self.timer_is_running
+                {
+                    self.recent_tasks.open();
+                    return Task::none();
+                }
+                match self.current_view {
+                    FurView::Timer => {
+                        let len = focusable_history_groups(self).len();
+                        self.history_focus = move_list_index(self.history_focus, len, direction);
+                        self.history_focus_actions = false;
+                        if let Some(index) = self.history_focus {
+                            return widget::operation::snap_to(
+                                HISTORY_SCROLLABLE_ID,
+                                widget::scrollable::RelativeOffset {
+                                    x: 0.0,
+                                    y: relative_scroll_offset(index, len),
+                                },
+                            );
+                        }
+                    }
+                    FurView::Shortcuts => {
+                        let len = self.shortcuts.len();
+                        let columns = shortcut_grid_columns(self);
+                        self.shortcut_focus =
+                            move_grid_index(self.shortcut_focus, len, columns, direction);
+                        self.shortcut_focus_actions = false;
+                        if let Some(index) = self.shortcut_focus {
+                            let rows = len.div_ceil(columns);
+                            return widget::operation::snap_to(
+                                SHORTCUTS_SCROLLABLE_ID,
+                                widget::scrollable::RelativeOffset {
+                                    x: 0.0,
+                                    y: relative_scroll_offset(index / columns, rows),
+                                },
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Message::LearnAboutSync => {
+                if let Err(e) = webbrowser::open("https://furtherance.app/sync") {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to open URL in browser: {}", e),
+                    );
+                }
+                self.displayed_alert = None;
+                if let Err(e) = self.fur_settings.change_notify_of_sync(false) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error changing notify_of_sync: {}", e),
+                    );
+                };
+            }
+            Message::LegacyUpgradeFinished(path, result) => {
+                self.legacy_upgrade = None;
+                if let Err(e) = result {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error upgrading legacy database: {}", e),
+                    );
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message("error-upgrading-database", None)
+                        .into());
+                    return Task::none();
+                }
+                let previous_url = self.fur_settings.database_url.clone();
+                let switched = match path.to_str() {
+                    Some(path_str) => self
+                        .fur_settings
+                        .change_db_url(path_str)
+                        .map_err(|e| e.to_string())
+                        .and_then(|_| db_init().map_err(|e| e.to_string())),
+                    None => Err("Invalid database path".to_string()),
+                };
+                if let Err(e) = switched {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error accessing upgraded database: {}", e),
+                    );
+                    if let Err(e) = self.fur_settings.change_db_url(&previous_url) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to restore the previous database: {}", e),
+                        );
+                    }
+                    self.settings_database_message = Err(self
+                        .localization
+                        .get_message("error-accessing-database", None)
+                        .into());
+                    return Task::none();
+                }
+                self.settings_database_message =
+                    Ok(self.localization.get_message("database-loaded", None));
+                return reload_database(self);
+            }
+            Message::LegacyUpgradeProgressed(progress) => {
+                self.legacy_upgrade = Some(progress);
+            }
+            Message::LoadOlderHistory => {
+                if self.history_loading_older || self.history_end_reached {
+                    return Task::none();
+                }
+                self.history_loading_older = true;
+                let before =
+                    Local::now().date_naive() - TimeDelta::days(self.history_days_loaded - 1);
+                let days = self.fur_settings.days_to_show;
+                return Task::perform(
+                    async move { tasks::get_older_task_history(before, days) },
+                    Message::OlderHistoryLoaded,
+                );
+            }
+            Message::LongTaskDiscard => {
+                self.displayed_alert = None;
+                if let Some(task) = self.long_task_to_confirm.take() {
+                    if let Err(e) = db_delete_tasks_by_ids(&[task.uid]) {
+                        log_error(&mut self.errors, format!("Failed to delete task: {}", e));
+                    }
+                    return chain_tasks(vec![
+                        update_task_history(self.history_days_loaded),
+                        sync_after_change(&self.fur_user),
+                    ]);
+                }
+            }
+            Message::LongTaskEdit => {
+                self.displayed_alert = None;
+                if let Some(task) = self.long_task_to_confirm.take() {
+                    self.current_view = FurView::Timer;
+                    self.task_to_edit = Some(TaskToEdit::new_from(&task));
+                    self.inspector_view = Some(FurInspectorView::EditTask);
+                }
+            }
+            Message::LongTaskKeep => {
+                self.displayed_alert = None;
+                self.long_task_to_confirm = None;
+            }
+            Message::MergeDatabasePressed => {
+                self.settings_csv_message = Ok(String::new());
+                self.settings_database_message = Ok(String::new());
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("merge-database-title", None))
+                    .add_filter(
+                        self.localization.get_message("sqlite-files", None),
+                        ALLOWED_DB_EXTENSIONS,
+                    )
+                    .set_can_create_directories(false)
+                    .pick_file();
+                if let Some(file) = selected_file {
+                    return Task::perform(async move { Message::MergeDatabase(file) }, |msg| msg);
+                }
+            }
+            Message::MergeDatabase(file) => {
+                // Databases from older versions are upgraded in a copy during the merge
+                let is_valid = match db_is_valid_v3(&file) {
+                    Ok(true) => Ok(true),
+                    Ok(false) => db_is_valid_v1(&file),
+                    Err(e) => Err(e),
+                };
+                match is_valid {
+                    Ok(true) => {
+                        self.database_merge = Some(UpgradeProgress::default());
+                        return merge_database(file);
+                    }
+                    Ok(false) => {
+                        self.settings_database_message = Err(self
+                            .localization
+                            .get_message("invalid-database", None)
+                            .into());
+                    }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Invalid database: {}", e));
+                        self.settings_database_message = Err(self
+                            .localization
+                            .get_message("invalid-database", None)
+                            .into());
+                    }
+                }
+            }
+            Message::MergeDuplicates => {
+                self.displayed_alert = None;
+                let Some(duplicates) = self.duplicates_found.take() else {
+                    return Task::none();
+                };
+                match db_soft_delete_duplicates(&duplicates) {
+                    Ok(_) => {
+                        self.settings_more_message = Ok(self.localization.get_message(
+                            "duplicates-merged",
+                            Some(&HashMap::from([(
+                                "count",
+                                FluentValue::from(duplicates.count()),
+                            )])),
+                        ));
+                        self.history_end_reached = false;
+                        self.report.invalidate_all();
+                        self.report.update_goal_progress();
+                        match db_retrieve_existing_shortcuts() {
+                            Ok(shortcuts) => self.shortcuts = shortcuts,
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve shortcuts from database: {}", e),
+                            ),
+                        };
+                        return chain_tasks(vec![
+                            update_task_history(self.history_days_loaded),
+                            update_todo_list(),
+                            sync_after_change(&self.fur_user),
+                        ]);
+                    }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Error merging duplicates: {}", e));
+                        self.settings_more_message = Err(self
+                            .localization
+                            .get_message("error-merging-duplicates", None)
+                            .into());
+                    }
+                }
+            }
+            Message::MidnightReached => {
+                self.pomodoro.stats = FurPomodoroStats::load(Local::now().date_naive());
+                // Keep showing the same older dates once they were loaded
+                if self.history_days_loaded > self.fur_settings.days_to_show {
+                    self.history_days_loaded += 1;
+                }
+                let mut tasks = vec![];
+                tasks.push(update_task_history(self.history_days_loaded));
+                tasks.push(update_todo_list());
+                return chain_tasks(tasks);
+            }
+            Message::MoveShortcut(uid, direction) => {
+                if move_shortcut(&mut self.shortcuts, &uid, direction) {
+                    let order: Vec<String> = self
+                        .shortcuts
+                        .iter()
+                        .map(|shortcut| shortcut.uid.clone())
+                        .collect();
+                    if let Err(e) = db_update_shortcut_order(&order) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to save shortcut order: {}", e),
+                        );
+                    }
+                    // Keep the keyboard highlight on the shortcut that moved
+                    if self.shortcut_focus.is_some() {
+                        self.shortcut_focus = order.iter().position(|moved| *moved == uid);
+                    }
+                }
+            }
+            Message::NavigateTo(destination) => {
+                if self.current_view != destination {
+                    if confirm_discarding_changes(self, Message::NavigateTo(destination)) {
+                        return Task::none();
+                    }
+                    self.current_view = destination;
+                    let close_inspector =
+                        Task::perform(async { Message::CloseInspector }, |msg| msg);
+                    if destination == FurView::Report {
+                        return chain_tasks(vec![close_inspector, self.report.refresh()]);
+                    }
+                    if destination == FurView::Search {
+                        refresh_search_results(self);
+                    }
+                    if destination == FurView::Settings {
+                        refresh_trash(self);
+                        refresh_sync_status(self);
+                    }
+                    return close_inspector;
+                }
+            }
+            Message::NotificationActionReceived(action) => {
+                // Ignore the notification if the alert was already answered in the app
+                if self.displayed_alert == Some(FurAlert::PomodoroOver) {
+                    let message = action.to_message();
+                    return Task::perform(async move { message }, |msg| msg);
+                }
+            }
+            Message::NotificationSupportChecked(result) => {
+                if let Err(e) = &result {
+                    log_error(
+                        &mut self.errors,
+                        format!("Desktop notifications unavailable: {e}"),
+                    );
+                }
+                self.notification_status = Some(result);
+            }
+            Message::NotifyOfSyncClose => {
+                if let Err(e) = self.fur_settings.change_notify_of_sync(false) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error changing notify_of_sync: {}", e),
+                    );
+                };
+                return Task::perform(async { Message::AlertClose }, |msg| msg);
+            }
+            Message::OlderHistoryLoaded(older) => {
+                self.history_loading_older = false;
+                let today = Local::now().date_naive();
+                // The shown history changed while loading
+                if older.before != today - TimeDelta::days(self.history_days_loaded - 1) {
+                    return Task::none();
+                }
+                self.history_end_reached = older.end_reached;
+                self.history_days_loaded = (today - older.first_day).num_days() + 1;
+                self.task_history.extend(older.history);
+                refresh_history_sections(self);
+            }
+            Message::OnboardingBack => {
+                if let Some(onboarding) = self.onboarding.as_mut() {
+                    onboarding.go_back();
+                }
+            }
+            Message::OnboardingCreateShortcut => {
+                if let Some(onboarding) = self.onboarding.as_mut() {
+                    let (name, project, tags, rate) = split_task_input(&onboarding.example_input);
+                    if name.is_empty() {
+                        return Task::none();
+                    }
+                    let new_shortcut = FurShortcut::new(
+                        name,
+                        if tags.is_empty() {
+                            String::new()
+                        } else {
+                            format!("#{}", tags)
+                        },
+                        project,
+                        rate,
+                        String::new(),
+                        Srgb::random().to_hex(),
+                    );
+
+                    match db_shortcut_exists(&new_shortcut) {
+                        Ok(true) => onboarding.shortcut_created = true,
+                        Ok(false) => match db_insert_shortcut(&new_shortcut) {
+                            Ok(_) => {
+                                onboarding.shortcut_created = true;
+                                match db_retrieve_existing_shortcuts() {
+                                    Ok(shortcuts) => self.shortcuts = shortcuts,
+                                    Err(e) => log_error(
+                                        &mut self.errors,
+                                        format!(
+                                            "Failed to retrieve shortcuts from database: {}",
+                                            e
+                                        ),
+                                    ),
+                                };
+                                return sync_after_change(&self.fur_user);
+                            }
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to write shortcut to database: {}", e),
+                            ),
+                        },
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to check if shortcut exists: {}", e),
+                        ),
+                    }
+                }
+            }
+            Message::OnboardingExampleChanged(new_value) => {
+                if let Some(onboarding) = self.onboarding.as_mut() {
+                    onboarding.example_input = new_value;
+                }
+            }
+            Message::OnboardingNext => advance_onboarding(self),
+            Message::OnboardingSkip => finish_onboarding(self),
+            Message::OpenUrl(url) => {
+                if let Err(e) = webbrowser::open(&url) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to open URL in browser: {}", e),
+                    );
+                }
+            }
+            Message::PauseResumePressed => {
+                if self.timer_is_running {
+                    if self.timer_paused {
+                        return resume_timer(self);
+                    } else {
+                        pause_timer(self);
+                    }
                 }
             }
             Message::PomodoroContinueAfterBreak => {
@@ -1313,10 +3357,8 @@ impl Furtherance {
                 self.displayed_alert = None;
                 start_timer(self);
                 let mut tasks = vec![];
-                tasks.push(Task::perform(get_timer_duration(), |_| {
-                    Message::StopwatchTick
-                }));
-                tasks.push(update_task_history(self.fur_settings.days_to_show));
+                tasks.push(schedule_stopwatch_tick(self));
+                tasks.push(update_task_history(self.history_days_loaded));
                 return chain_tasks(tasks);
             }
             Message::PomodoroSnooze => {
@@ -1325,43 +3367,166 @@ impl Furtherance {
                 // Timer is still running but we want to first show the snooze time total
                 self.timer_text = get_stopped_timer_text(self);
                 self.displayed_alert = None;
-                return Task::perform(get_timer_duration(), |_| Message::StopwatchTick);
+                return schedule_stopwatch_tick(self);
+            }
+            Message::PomodoroStartBreak => {
+                let original_task_input = self.task_input.clone();
+                self.pomodoro.on_break = true;
+                self.pomodoro.snoozed = false;
+                let length_check = stop_timer(self, Local::now());
+                self.task_input = original_task_input;
+                self.displayed_alert = None;
+                start_timer(self);
+
+                let mut tasks = vec![length_check];
+                tasks.push(schedule_stopwatch_tick(self));
+                tasks.push(update_task_history(self.history_days_loaded));
+                tasks.push(sync_after_change(&self.fur_user));
+                return chain_tasks(tasks);
+            }
+            Message::PomodoroStop => {
+                self.pomodoro.snoozed = false;
+                let length_check = stop_timer(self, Local::now());
+                self.displayed_alert = None;
+                self.pomodoro.sessions = 0;
+                let mut tasks = vec![length_check];
+                tasks.push(update_task_history(self.history_days_loaded));
+                tasks.push(sync_after_change(&self.fur_user));
+                return chain_tasks(tasks);
+            }
+            Message::PomodoroStopAfterBreak => {
+                self.timer_is_running = false;
+                self.pomodoro.on_break = false;
+                self.pomodoro.snoozed = false;
+                reset_timer(self);
+                self.pomodoro.sessions = 0;
+                self.displayed_alert = None;
+                return update_task_history(self.history_days_loaded);
+            }
+            Message::ProfileBrowsePressed => {
+                let selected_file = FileDialog::new()
+                    .set_title(
+                        self.localization
+                            .get_message("choose-profile-database", None),
+                    )
+                    .add_filter(
+                        self.localization.get_message("sqlite-files", None),
+                        ALLOWED_DB_EXTENSIONS,
+                    )
+                    .set_file_name("furtherance.db")
+                    .set_can_create_directories(true)
+                    .save_file();
+                if let Some(path) = selected_file.and_then(|file| file.to_str().map(String::from)) {
+                    self.profile_to_add.database_url = path;
+                }
+            }
+            Message::ProfileNameChanged(new_value) => {
+                self.profile_to_add.name = new_value;
+            }
+            Message::ProfilePathChanged(new_value) => {
+                self.profile_to_add.database_url = new_value;
+            }
+            Message::ProjectArchiveToggled(project, archive) => {
+                let result = if archive {
+                    self.fur_settings.archive_project(&project)
+                } else {
+                    self.fur_settings.unarchive_project(&project)
+                };
+                if let Err(e) = result {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change archived_projects in settings: {}", e),
+                    );
+                }
+                self.report
+                    .set_archived_projects(self.fur_settings.archived_projects.clone());
+                self.export_settings
+                    .get_all_projects(&self.fur_settings.archived_projects);
+            }
+            Message::ProjectDefaultTagsChanged(project, tags) => {
+                match db_set_project_default_tags(&project, &tags) {
+                    Ok(_) => {
+                        if tags.trim().is_empty() {
+                            self.project_default_tags.remove(&project);
+                        } else {
+                            self.project_default_tags.insert(project, tags);
+                        }
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Error saving project default tags: {}", e),
+                    ),
+                }
+            }
+            Message::PurgeDeletedItem(item) => {
+                if let Err(e) = db_purge_deleted_item(&item) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error permanently deleting item: {}", e),
+                    );
+                }
+                refresh_trash(self);
+            }
+            Message::RenameProfile(old_name) => {
+                let new_name = self.profile_to_add.name.trim().to_string();
+                if let Err(e) =
+                    validate_profile_name(&self.fur_settings.profiles, &new_name, Some(&old_name))
+                {
+                    return set_negative_temp_notice(
+                        &mut self.settings_profiles_message,
+                        self.localization.get_message(e.message_key(), None),
+                    );
+                }
+                let mut profiles = self.fur_settings.profiles.clone();
+                if let Some(profile) = profiles.iter_mut().find(|profile| profile.name == old_name)
+                {
+                    profile.name = new_name;
+                }
+                if let Err(e) = self.fur_settings.change_profiles(&profiles) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change profiles in settings: {}", e),
+                    );
+                }
+                self.profile_to_add.name = String::new();
             }
-            Message::PomodoroStartBreak => {
-                let original_task_input = self.task_input.clone();
-                self.pomodoro.on_break = true;
-                self.pomodoro.snoozed = false;
-                stop_timer(self, Local::now());
-                self.task_input = original_task_input;
-                self.displayed_alert = None;
-                start_timer(self);
-
-                let mut tasks = vec![];
-                tasks.push(Task::perform(get_timer_duration(), |_| {
-                    Message::StopwatchTick
-                }));
-                tasks.push(update_task_history(self.fur_settings.days_to_show));
-                tasks.push(sync_after_change(&self.fur_user));
-                return chain_tasks(tasks);
+            Message::RaiseWindowRequested => {
+                // Another launch was stopped in favor of this window
+                return window::latest().and_then(|id| {
+                    Task::batch([window::minimize(id, false), window::gain_focus(id)])
+                });
             }
-            Message::PomodoroStop => {
-                self.pomodoro.snoozed = false;
-                stop_timer(self, Local::now());
-                self.displayed_alert = None;
-                self.pomodoro.sessions = 0;
-                let mut tasks = vec![];
-                tasks.push(update_task_history(self.fur_settings.days_to_show));
-                tasks.push(sync_after_change(&self.fur_user));
-                return chain_tasks(tasks);
+            Message::RecentTaskPicked(task_input) => {
+                // Filled in rather than started so it can be adjusted first
+                if !self.timer_is_running {
+                    self.task_input = task_input;
+                }
+                self.recent_tasks.close();
+                self.task_input_focused = true;
+                return widget::operation::focus(TASK_INPUT_ID);
+            }
+            Message::RecentTasksDismissed => {
+                self.recent_tasks.close();
+                self.task_input_focused = false;
+            }
+            Message::RecentTasksToggled => {
+                if self.recent_tasks.is_open {
+                    self.recent_tasks.close();
+                } else if !self.timer_is_running {
+                    self.recent_tasks.open();
+                }
             }
-            Message::PomodoroStopAfterBreak => {
-                self.timer_is_running = false;
-                self.pomodoro.on_break = false;
-                self.pomodoro.snoozed = false;
-                reset_timer(self);
-                self.pomodoro.sessions = 0;
-                self.displayed_alert = None;
-                return update_task_history(self.fur_settings.days_to_show);
+            Message::RegenerateStatusServerKey => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_status_server_api_key(&generate_status_server_api_key())
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change status_server_api_key in settings: {}", e),
+                    );
+                }
+                restart_status_server(self);
             }
             Message::RepeatLastTaskPressed(last_task_input) => {
                 self.task_input = last_task_input;
@@ -1372,30 +3537,160 @@ impl Furtherance {
                 return Task::perform(async { Message::StartStopPressed }, |msg| msg);
             }
             Message::RepeatTodoToday(todo_to_copy) => {
-                match db_insert_todo(&FurTodo::new(
+                let mut new_todo = FurTodo::new(
                     todo_to_copy.name,
                     todo_to_copy.project,
                     todo_to_copy.tags,
                     todo_to_copy.rate,
                     Local::now(),
-                )) {
+                );
+                new_todo.estimate = todo_to_copy.estimate;
+                match db_insert_todo(&new_todo) {
                     Ok(_) => {
                         let mut tasks = vec![];
                         tasks.push(update_todo_list());
                         tasks.push(sync_after_change(&self.fur_user));
                         return chain_tasks(tasks);
                     }
-                    Err(e) => eprintln!("Error duplicating todo: {}", e),
+                    Err(e) => log_error(&mut self.errors, format!("Error duplicating todo: {}", e)),
+                }
+            }
+            Message::RestoreDeletedItem(item) => {
+                if let Err(e) = db_restore_deleted_item(&item) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error restoring deleted item: {}", e),
+                    );
+                    return Task::none();
                 }
+                refresh_trash(self);
+                let refresh = match item.kind {
+                    DeletedItemKind::Task => update_task_history(self.history_days_loaded),
+                    DeletedItemKind::Todo => update_todo_list(),
+                    DeletedItemKind::Shortcut => {
+                        match db_retrieve_existing_shortcuts() {
+                            Ok(shortcuts) => self.shortcuts = shortcuts,
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve shortcuts from database: {}", e),
+                            ),
+                        };
+                        Task::none()
+                    }
+                };
+                return chain_tasks(vec![refresh, sync_after_change(&self.fur_user)]);
+            }
+            Message::ReportComputed(range, data) => self.report.report_computed(range, data),
+            Message::ReportHeatmapComputed(heatmap_chart) => {
+                self.report.heatmap_computed(heatmap_chart)
             }
             Message::ReportTabSelected(new_tab) => self.report.active_tab = new_tab,
+            Message::ReportTodoChartComputed(result) => match result {
+                Ok(todo_completion_chart) => self.report.todo_chart_computed(todo_completion_chart),
+                Err(e) => log_error(
+                    &mut self.errors,
+                    format!("Could not retrieve todos in range: {}", e),
+                ),
+            },
+            Message::RetrySyncPressed => {
+                self.sync_suspended = false;
+                return Task::perform(async { Message::SyncWithServer }, |msg| msg);
+            }
+            Message::RunningTaskNotesEdited(action) => {
+                if self.timer_is_running {
+                    self.running_task_notes.perform(action);
+                }
+            }
+            Message::SaveInvoice => {
+                if let Some(invoice_to_generate) = self.invoice_to_generate.as_mut() {
+                    invoice_to_generate.message = Ok(String::new());
+                    let lines = invoice_lines(
+                        &self.report.data.tasks_in_range,
+                        &invoice_to_generate.project,
+                        &self.fur_settings,
+                    );
+                    if lines.is_empty() {
+                        invoice_to_generate.message = Err(self
+                            .localization
+                            .get_message("no-billable-tasks", None)
+                            .into());
+                        return Task::none();
+                    }
+
+                    let file_name = format!("invoice-{}.html", Local::now().format("%Y-%m-%d"));
+                    let selected_file = FileDialog::new()
+                        .set_title(self.localization.get_message("save-invoice-title", None))
+                        .add_filter("HTML", &["html"])
+                        .set_can_create_directories(true)
+                        .set_file_name(file_name)
+                        .save_file();
+
+                    if let Some(path) = selected_file {
+                        let html = invoice_html(
+                            &invoice_to_generate.header,
+                            &lines,
+                            self.report.date_range(),
+                            &self.localization,
+                        );
+                        match std::fs::write(path, html) {
+                            Ok(_) => {
+                                let header = &invoice_to_generate.header;
+                                if let Err(e) = self
+                                    .fur_settings
+                                    .change_invoice_from(&header.from)
+                                    .and_then(|_| {
+                                        self.fur_settings.change_invoice_client(&header.client)
+                                    })
+                                    .and_then(|_| {
+                                        self.fur_settings.change_invoice_number(&header.number)
+                                    })
+                                    .and_then(|_| {
+                                        self.fur_settings.change_invoice_notes(&header.notes)
+                                    })
+                                {
+                                    log_error(
+                                        &mut self.errors,
+                                        format!("Failed to save invoice header in settings: {}", e),
+                                    );
+                                }
+                                invoice_to_generate.message =
+                                    Ok(self.localization.get_message("invoice-saved", None));
+                            }
+                            Err(e) => {
+                                log_error(
+                                    &mut self.errors,
+                                    format!("Error writing invoice: {}", e),
+                                );
+                                invoice_to_generate.message = Err(self
+                                    .localization
+                                    .get_message("error-writing-invoice", None)
+                                    .into());
+                            }
+                        }
+                    }
+                }
+            }
+            Message::SaveDayNote => {
+                if let Some(day_note_to_edit) = &self.day_note_to_edit {
+                    let note = FurDayNote::new(
+                        day_note_to_edit.date,
+                        day_note_to_edit.content.text().trim().to_string(),
+                    );
+                    if let Err(e) = db_save_day_note(&note) {
+                        log_error(&mut self.errors, format!("Failed to save day note: {}", e));
+                    }
+                    self.day_note_to_edit = None;
+                    self.inspector_view = None;
+                    self.day_notes = get_day_notes();
+                }
+            }
             Message::SaveGroupEdit => {
                 if let Some(group_to_edit) = &self.group_to_edit {
                     let _ = db_update_group_of_tasks(group_to_edit);
                     self.inspector_view = None;
                     self.group_to_edit = None;
                     let mut tasks = vec![];
-                    tasks.push(update_task_history(self.fur_settings.days_to_show));
+                    tasks.push(update_task_history(self.history_days_loaded));
                     tasks.push(sync_after_change(&self.fur_user));
                     return chain_tasks(tasks);
                 }
@@ -1403,8 +3698,8 @@ impl Furtherance {
             Message::SaveShortcut => {
                 if let Some(shortcut_to_add) = &self.shortcut_to_add {
                     let new_shortcut = FurShortcut::new(
-                        shortcut_to_add.name.trim().to_string(),
-                        shortcut_to_add.tags.trim().to_string(),
+                        unescape_task_input_text(shortcut_to_add.name.trim()),
+                        normalize_shortcut_tags(&shortcut_to_add.tags),
                         shortcut_to_add.project.trim().to_string(),
                         shortcut_to_add
                             .new_rate
@@ -1425,152 +3720,180 @@ impl Furtherance {
                                         self.shortcut_to_add = None;
                                         match db_retrieve_existing_shortcuts() {
                                             Ok(shortcuts) => self.shortcuts = shortcuts,
-                                            Err(e) => eprintln!(
-                                                "Failed to retrieve shortcuts from database: {}",
-                                                e
+                                            Err(e) => log_error(
+                                                &mut self.errors,
+                                                format!(
+                                                    "Failed to retrieve shortcuts from database: {}",
+                                                    e
+                                                ),
                                             ),
                                         };
                                         return sync_after_change(&self.fur_user);
                                     }
-                                    Err(e) => {
-                                        eprintln!("Failed to write shortcut to database: {}", e)
-                                    }
+                                    Err(e) => log_error(
+                                        &mut self.errors,
+                                        format!("Failed to write shortcut to database: {}", e),
+                                    ),
                                 }
                             }
                         }
-                        Err(e) => eprintln!("Failed to check if shortcut exists: {}", e),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to check if shortcut exists: {}", e),
+                        ),
                     }
                 } else if let Some(shortcut_to_edit) = &self.shortcut_to_edit {
+                    let new_rate = shortcut_to_edit
+                        .new_rate
+                        .trim()
+                        .parse::<f32>()
+                        .unwrap_or(0.0);
                     match db_update_shortcut(&FurShortcut {
-                        name: shortcut_to_edit.new_name.trim().to_string(),
-                        tags: shortcut_to_edit.new_tags.trim().to_string(),
+                        name: unescape_task_input_text(shortcut_to_edit.new_name.trim()),
+                        tags: normalize_shortcut_tags(&shortcut_to_edit.new_tags),
                         project: shortcut_to_edit.new_project.trim().to_string(),
-                        rate: shortcut_to_edit
-                            .new_rate
-                            .trim()
-                            .parse::<f32>()
-                            .unwrap_or(0.0),
+                        rate: new_rate,
                         currency: String::new(),
                         color_hex: shortcut_to_edit.new_color.to_hex(),
                         uid: shortcut_to_edit.uid.clone(),
                         is_deleted: false,
-                        last_updated: chrono::Utc::now().timestamp(),
+                        last_updated: sync_timestamp(),
+                        device_name: shortcut_to_edit.device_name.clone(),
+                        sort_order: shortcut_to_edit.sort_order,
                     }) {
                         Ok(_) => {
+                            if let Some(change) = ShortcutRateChange::between(
+                                shortcut_to_edit.rate,
+                                new_rate,
+                                Local::now().timestamp(),
+                            ) {
+                                if let Err(e) =
+                                    db_insert_shortcut_rate_change(&shortcut_to_edit.uid, &change)
+                                {
+                                    log_error(
+                                        &mut self.errors,
+                                        format!("Failed to record shortcut rate change: {}", e),
+                                    );
+                                }
+                            }
                             self.inspector_view = None;
                             self.shortcut_to_edit = None;
                             match db_retrieve_existing_shortcuts() {
                                 Ok(shortcuts) => self.shortcuts = shortcuts,
-                                Err(e) => {
-                                    eprintln!("Failed to retrieve shortcuts from database: {}", e)
-                                }
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to retrieve shortcuts from database: {}", e),
+                                ),
                             };
                             return sync_after_change(&self.fur_user);
                         }
-                        Err(e) => eprintln!("Failed to update shortcut in database: {}", e),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to update shortcut in database: {}", e),
+                        ),
                     }
                 }
             }
             Message::SaveTaskEdit => {
                 if let Some(task_to_edit) = &mut self.task_to_edit {
-                    if task_to_edit.new_stop_time >= task_to_edit.new_start_time {
-                        let tags_without_first_pound = task_to_edit
-                            .new_tags
-                            .trim()
-                            .strip_prefix('#')
-                            .unwrap_or(&task_to_edit.new_tags)
-                            .trim()
-                            .to_string();
-                        match db_update_task(&FurTask {
-                            name: task_to_edit.new_name.trim().to_string(),
+                    if task_to_edit.times_are_valid() {
+                        let task = FurTask {
+                            name: unescape_task_input_text(task_to_edit.new_name.trim()),
                             start_time: task_to_edit.new_start_time,
                             stop_time: task_to_edit.new_stop_time,
-                            tags: tags_without_first_pound,
+                            tags: normalize_tags(&task_to_edit.new_tags),
                             project: task_to_edit.new_project.trim().to_string(),
                             rate: task_to_edit.new_rate.trim().parse::<f32>().unwrap_or(0.0),
                             currency: String::new(),
                             uid: task_to_edit.uid.clone(),
                             is_deleted: false,
-                            last_updated: chrono::Utc::now().timestamp(),
-                        }) {
+                            last_updated: sync_timestamp(),
+                            is_billable: task_to_edit.billable_to_save(),
+                            device_name: task_to_edit.device_name.clone(),
+                            planned_seconds: task_to_edit.planned_seconds,
+                            notes: task_to_edit.notes.clone(),
+                        };
+                        let result = if task_to_edit.is_unsaved {
+                            db_insert_task(&task)
+                        } else {
+                            db_update_task(&task)
+                        };
+                        match result {
                             Ok(_) => {
+                                if task_to_edit.is_unsaved {
+                                    delete_autosave();
+                                }
                                 self.inspector_view = None;
                                 self.task_to_edit = None;
                                 self.group_to_edit = None;
                                 let mut tasks = vec![];
-                                tasks.push(update_task_history(self.fur_settings.days_to_show));
+                                tasks.push(update_task_history(self.history_days_loaded));
                                 tasks.push(sync_after_change(&self.fur_user));
                                 return chain_tasks(tasks);
                             }
-                            Err(e) => eprintln!("Failed to update task in database: {}", e),
+                            Err(e) => log_error(
+                                &mut self.errors,
+                                format!("Failed to update task in database: {}", e),
+                            ),
                         }
                     } else {
-                        task_to_edit.invalid_input_error_message =
-                            "The start time must be before the stop time.".to_string();
+                        task_to_edit
+                            .input_error(self.localization.get_message("stop-before-start", None));
                     }
                 } else if let Some(task_to_add) = &mut self.task_to_add {
-                    if task_to_add.stop_time >= task_to_add.start_time {
-                        let tags_without_first_pound = task_to_add
-                            .tags
-                            .trim()
-                            .strip_prefix('#')
-                            .unwrap_or(&task_to_add.tags)
-                            .trim()
-                            .to_string();
-                        match db_insert_task(&FurTask::new(
-                            task_to_add.name.trim().to_string(),
+                    if task_to_add.times_are_valid() {
+                        let mut new_task = FurTask::new(
+                            unescape_task_input_text(task_to_add.name.trim()),
                             task_to_add.start_time,
                             task_to_add.stop_time,
-                            tags_without_first_pound,
+                            normalize_tags(&task_to_add.tags),
                             task_to_add.project.trim().to_string(),
                             task_to_add.new_rate.trim().parse::<f32>().unwrap_or(0.0),
                             String::new(),
-                        )) {
+                        );
+                        new_task.is_billable = task_to_add.billable_to_save();
+                        apply_project_default_tags(self, &mut new_task);
+                        match db_insert_task(&new_task) {
                             Ok(_) => {
                                 self.inspector_view = None;
                                 self.task_to_add = None;
                                 self.group_to_edit = None;
                                 let mut tasks = vec![];
-                                tasks.push(update_task_history(self.fur_settings.days_to_show));
+                                tasks.push(update_task_history(self.history_days_loaded));
                                 tasks.push(sync_after_change(&self.fur_user));
                                 return chain_tasks(tasks);
                             }
-                            Err(e) => eprintln!("Error adding task: {}", e),
+                            Err(e) => {
+                                log_error(&mut self.errors, format!("Error adding task: {}", e))
+                            }
                         }
                     } else {
-                        task_to_add.invalid_input_error_message =
-                            "The start time must be before the stop time.".to_string();
+                        task_to_add
+                            .input_error(self.localization.get_message("stop-before-start", None));
                     }
                 }
             }
             Message::SaveTodoEdit => {
-                if let Some(todo_to_edit) = &self.todo_to_edit {
-                    let tags_without_first_pound = todo_to_edit
-                        .new_tags
-                        .trim()
-                        .split('#')
-                        .filter_map(|tag| {
-                            let trimmed = tag.trim();
-                            if trimmed.is_empty() {
-                                None
-                            } else {
-                                Some(trimmed.to_lowercase())
-                            }
-                        })
-                        .sorted()
-                        .collect::<Vec<String>>()
-                        .join(" #");
+                if let Some(todo_to_edit) = self.todo_to_edit.as_mut() {
+                    let Some(estimate) = parse_duration_input(&todo_to_edit.new_estimate) else {
+                        todo_to_edit
+                            .input_error(self.localization.get_message("estimate-invalid", None));
+                        return Task::none();
+                    };
                     match db_update_todo(&FurTodo {
-                        name: todo_to_edit.new_name.trim().to_string(),
+                        name: unescape_task_input_text(todo_to_edit.new_name.trim()),
                         project: todo_to_edit.new_project.trim().to_string(),
-                        tags: tags_without_first_pound,
+                        tags: normalize_tags(&todo_to_edit.new_tags),
                         rate: todo_to_edit.new_rate.trim().parse::<f32>().unwrap_or(0.0),
                         currency: String::new(),
                         date: todo_to_edit.new_date,
                         uid: todo_to_edit.uid.clone(),
                         is_completed: todo_to_edit.is_completed,
                         is_deleted: false,
-                        last_updated: chrono::Utc::now().timestamp(),
+                        last_updated: sync_timestamp(),
+                        estimate,
+                        device_name: todo_to_edit.device_name.clone(),
+                        completed_at: todo_to_edit.completed_at,
                     }) {
                         Ok(_) => {
                             self.inspector_view = None;
@@ -1580,31 +3903,26 @@ impl Furtherance {
                             tasks.push(sync_after_change(&self.fur_user));
                             return chain_tasks(tasks);
                         }
-                        Err(e) => eprintln!("Failed to update todo in database: {}", e),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to update todo in database: {}", e),
+                        ),
                     }
-                } else if let Some(todo_to_add) = &self.todo_to_add {
-                    let tags_without_first_pound = todo_to_add
-                        .tags
-                        .trim()
-                        .split('#')
-                        .filter_map(|tag| {
-                            let trimmed = tag.trim();
-                            if trimmed.is_empty() {
-                                None
-                            } else {
-                                Some(trimmed.to_lowercase())
-                            }
-                        })
-                        .sorted()
-                        .collect::<Vec<String>>()
-                        .join(" #");
-                    match db_insert_todo(&FurTodo::new(
-                        todo_to_add.name.trim().to_string(),
+                } else if let Some(todo_to_add) = self.todo_to_add.as_mut() {
+                    let Some(estimate) = parse_duration_input(&todo_to_add.estimate) else {
+                        todo_to_add
+                            .input_error(self.localization.get_message("estimate-invalid", None));
+                        return Task::none();
+                    };
+                    let mut new_todo = FurTodo::new(
+                        unescape_task_input_text(todo_to_add.name.trim()),
                         todo_to_add.project.trim().to_string(),
-                        tags_without_first_pound,
+                        normalize_tags(&todo_to_add.tags),
                         todo_to_add.rate.trim().parse::<f32>().unwrap_or(0.0),
                         todo_to_add.date,
-                    )) {
+                    );
+                    new_todo.estimate = estimate;
+                    match db_insert_todo(&new_todo) {
                         Ok(_) => {
                             self.inspector_view = None;
                             self.todo_to_add = None;
@@ -1613,8 +3931,232 @@ impl Furtherance {
                             tasks.push(sync_after_change(&self.fur_user));
                             return chain_tasks(tasks);
                         }
-                        Err(e) => eprintln!("Error adding todo: {}", e),
+                        Err(e) => log_error(&mut self.errors, format!("Error adding todo: {}", e)),
+                    }
+                }
+            }
+            Message::SaveWeeklySummary(week_start) => {
+                let Some(summary) = weekly_summary(self, week_start) else {
+                    return show_timer_notice(
+                        self,
+                        self.localization
+                            .get_message("error-creating-weekly-summary", None),
+                    );
+                };
+                let extension = self.fur_settings.summary_format.extension();
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("save-weekly-summary", None))
+                    .add_filter(self.fur_settings.summary_format.to_string(), &[extension])
+                    .set_can_create_directories(true)
+                    .set_file_name(format!(
+                        "furtherance-week-{}.{}",
+                        week_start.format("%Y-%m-%d"),
+                        extension
+                    ))
+                    .save_file();
+
+                if let Some(path) = selected_file {
+                    let notice = match std::fs::write(path, summary) {
+                        Ok(_) => self.localization.get_message("weekly-summary-saved", None),
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error saving weekly summary: {}", e),
+                            );
+                            self.localization
+                                .get_message("error-saving-weekly-summary", None)
+                        }
+                    };
+                    return show_timer_notice(self, notice);
+                }
+            }
+            Message::SaveWindowGeometry => save_window_geometry(self),
+            Message::SaveYearInReview => {
+                let Some(review) = &self.year_in_review else {
+                    return Task::none();
+                };
+                let summary = review_text(review, &self.localization);
+                let selected_file = FileDialog::new()
+                    .set_title(self.localization.get_message("save-year-in-review", None))
+                    .add_filter("Text", &["txt"])
+                    .set_can_create_directories(true)
+                    .set_file_name(format!("furtherance-{}-in-review.txt", review.year))
+                    .save_file();
+
+                if let Some(path) = selected_file {
+                    self.displayed_alert = None;
+                    let notice = match std::fs::write(path, summary) {
+                        Ok(_) => Ok(self.localization.get_message("year-in-review-saved", None)),
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Error saving year in review: {}", e),
+                            );
+                            Err(self
+                                .localization
+                                .get_message("error-saving-year-in-review", None)
+                                .into())
+                        }
+                    };
+                    return show_report_export_notice(self, notice);
+                }
+            }
+            Message::ScheduledSummaryCheck => {
+                return check_scheduled_summary(self);
+            }
+            Message::ScheduledSummaryFolderBrowsePressed => {
+                let selected_folder = FileDialog::new()
+                    .set_title(
+                        self.localization
+                            .get_message("scheduled-summary-folder-title", None),
+                    )
+                    .set_directory(&self.fur_settings.scheduled_summary_folder)
+                    .set_can_create_directories(true)
+                    .pick_folder();
+
+                if let Some(folder) = selected_folder {
+                    if let Err(e) = self
+                        .fur_settings
+                        .change_scheduled_summary_folder(&folder.to_string_lossy())
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change scheduled_summary_folder in settings: {}",
+                                e
+                            ),
+                        );
+                    }
+                    // Try again in case the old folder was the problem
+                    self.scheduled_summary_failed = None;
+                }
+            }
+            Message::ScheduledSummaryWritten(period_end, result) => {
+                self.scheduled_summary_in_progress = false;
+                match result {
+                    Ok(path) => {
+                        if let Err(e) = self
+                            .fur_settings
+                            .change_scheduled_summary_last_generated(&period_end.to_string())
+                        {
+                            log_error(
+                                &mut self.errors,
+                                format!(
+                                    "Failed to change scheduled_summary_last_generated in settings: {}",
+                                    e
+                                ),
+                            );
+                        }
+                        self.settings_more_message = Ok(self.localization.get_message(
+                            "scheduled-summary-saved",
+                            Some(&HashMap::from([(
+                                "path",
+                                FluentValue::from(path.to_string_lossy().into_owned()),
+                            )])),
+                        ));
+                    }
+                    Err(e) => {
+                        log_error(
+                            &mut self.errors,
+                            format!("Error writing scheduled summary: {e}"),
+                        );
+                        // Don't retry every minute, only after the settings change
+                        self.scheduled_summary_failed = Some(period_end);
+                        self.settings_more_message = Err(self
+                            .localization
+                            .get_message(
+                                "scheduled-summary-failed",
+                                Some(&HashMap::from([("error", FluentValue::from(e))])),
+                            )
+                            .into());
+                    }
+                }
+            }
+            Message::SearchLoadMore => {
+                match db_search_tasks(
+                    &self.search_query,
+                    SEARCH_PAGE_SIZE,
+                    self.search_results.len(),
+                ) {
+                    Ok(more) => {
+                        self.search_end_reached = more.len() < SEARCH_PAGE_SIZE;
+                        self.search_results.extend(more);
+                    }
+                    Err(e) => log_error(&mut self.errors, format!("Failed to search tasks: {}", e)),
+                }
+            }
+            Message::SearchQueryChanged(new_value) => {
+                self.search_query = new_value;
+                refresh_search_results(self);
+            }
+            Message::SearchResultPressed(task) => {
+                self.current_view = FurView::Timer;
+                self.task_to_edit = Some(TaskToEdit::new_from(&task));
+                self.inspector_view = Some(FurInspectorView::EditTask);
+
+                // History is shown newest first
+                let date = task.start_time.date_naive();
+                if self.task_history.contains_key(&date) && self.task_history.len() > 1 {
+                    let days_above = self.task_history.range(date..).count() - 1;
+                    let y = days_above as f32 / (self.task_history.len() - 1) as f32;
+                    return widget::operation::snap_to(
+                        HISTORY_SCROLLABLE_ID,
+                        widget::scrollable::RelativeOffset { x: 0.0, y },
+                    );
+                }
+            }
+            Message::SearchShortcutPressed => {
+                let focus = widget::operation::focus(SEARCH_INPUT_ID);
+                if self.current_view != FurView::Search {
+                    if confirm_discarding_changes(self, Message::SearchShortcutPressed) {
+                        return Task::none();
                     }
+                    self.current_view = FurView::Search;
+                    refresh_search_results(self);
+                    return chain_tasks(vec![
+                        Task::perform(async { Message::CloseInspector }, |msg| msg),
+                        focus,
+                    ]);
+                }
+                return focus;
+            }
+            Message::SecondaryTimerInputChanged(new_value) => {
+                if let Some(accepted) = accepted_task_input(&new_value) {
+                    self.secondary_timer_input = accepted;
+                }
+            }
+            Message::SecondaryTimerTick => {
+                // Each tick also redraws the secondary timers' elapsed time
+                if (Local::now() - self.secondary_timers_autosaved_at).num_seconds() >= 60 {
+                    write_secondary_timer_autosaves(self);
+                }
+            }
+            Message::SettingsAutoHideCompletedAfterDaysChanged(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_auto_hide_completed_after_days(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change auto_hide_completed_after_days in settings: {}",
+                            e
+                        ),
+                    );
+                }
+                // Re-split the full list against the new threshold
+                let mut all_todos = std::mem::take(&mut self.todos);
+                for (date, archived) in std::mem::take(&mut self.archived_todos) {
+                    all_todos.entry(date).or_default().extend(archived);
+                }
+                set_todo_list(self, all_todos);
+            }
+            Message::SettingsAutoStopToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_auto_stop_enabled(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change auto_stop_enabled in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsChangeDatabaseLocationPressed(new_or_open) => {
@@ -1639,83 +4181,20 @@ impl Furtherance {
                         .pick_file(),
                 };
 
-                let mut is_old_db = false;
-
                 if let Some(file) = selected_file {
-                    self.settings_database_message = Ok(String::new());
-
-                    if file.exists() {
-                        match db_is_valid_v3(file.as_path()) {
-                            Err(e) => {
-                                eprintln!("Invalid database: {}", e);
-                                self.settings_database_message = Err(self
-                                    .localization
-                                    .get_message("invalid-database", None)
-                                    .into());
-                            }
-                            Ok(is_valid_v3) => {
-                                if !is_valid_v3 {
-                                    match db_is_valid_v1(file.as_path()) {
-                                        Ok(is_valid_v2) => {
-                                            if is_valid_v2 {
-                                                is_old_db = true
-                                            } else {
-                                                self.settings_database_message = Err(self
-                                                    .localization
-                                                    .get_message("invalid-database", None)
-                                                    .into());
-                                            }
-                                        }
-                                        Err(e) => {
-                                            eprintln!("Invalid v1 database: {}", e);
-                                            self.settings_database_message = Err(self
-                                                .localization
-                                                .get_message("invalid-database", None)
-                                                .into());
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    if self.settings_database_message.is_ok() {
-                        // Valid file or not yet a file
-                        if let Some(file_str) = file.to_str() {
-                            if let Ok(_) = self.fur_settings.change_db_url(file_str) {
-                                match db_init() {
-                                    Ok(_) => {
-                                        if is_old_db {
-                                            if let Err(e) = db_upgrade_old() {
-                                                eprintln!("Error upgrading legacy database: {}", e);
-                                                self.settings_database_message = Err(self
-                                                    .localization
-                                                    .get_message("error-upgrading-database", None)
-                                                    .into());
-                                                return Task::none();
-                                            }
-                                        }
-                                        self.settings_database_message = Ok(match new_or_open {
-                                            ChangeDB::Open => self
-                                                .localization
-                                                .get_message("database-loaded", None),
-                                            ChangeDB::New => self
-                                                .localization
-                                                .get_message("database-created", None)
-                                                .to_string(),
-                                        });
-                                        return update_task_history(self.fur_settings.days_to_show);
-                                    }
-                                    Err(e) => {
-                                        eprintln!("Error accessing new database: {}", e);
-                                        self.settings_database_message = Err(self
-                                            .localization
-                                            .get_message("error-accessing-database", None)
-                                            .into());
-                                    }
-                                }
-                            }
-                        }
+                    return Task::perform(
+                        async move { Message::ChangeDatabase(file, new_or_open) },
+                        |msg| msg,
+                    );
+                }
+            }
+            Message::SettingsDailyTargetChanged(new_minutes) => {
+                if new_minutes >= 0 {
+                    if let Err(e) = self.fur_settings.change_daily_target_minutes(&new_minutes) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change daily_target_minutes in settings: {}", e),
+                        );
                     }
                 }
             }
@@ -1724,15 +4203,64 @@ impl Furtherance {
                 if new_days >= 1 {
                     match self.fur_settings.change_days_to_show(&new_days) {
                         Ok(_) => {
-                            return update_task_history(self.fur_settings.days_to_show);
+                            self.history_days_loaded = new_days;
+                            self.history_end_reached = false;
+                            return update_task_history(self.history_days_loaded);
                         }
-                        Err(e) => eprintln!("Failed to change days_to_show in settings: {}", e),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to change days_to_show in settings: {}", e),
+                        ),
+                    }
+                }
+            }
+            Message::SettingsDeductionPercentChanged(new_value) => {
+                if (0.0..=100.0).contains(&new_value) {
+                    match self.fur_settings.change_deduction_percent(&new_value) {
+                        Ok(_) => all_charts::set_chart_net_earnings(net_earnings_deduction(
+                            &self.fur_settings,
+                        )),
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to change deduction_percent in settings: {}", e),
+                        ),
                     }
                 }
             }
+            Message::SettingsDefaultCurrencyChanged(new_currency) => {
+                set_default_currency(&new_currency);
+                if let Err(e) = self.fur_settings.change_default_currency(&new_currency) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change default_currency in settings: {}", e),
+                    );
+                }
+            }
             Message::SettingsDefaultViewSelected(selected_view) => {
                 if let Err(e) = self.fur_settings.change_default_view(&selected_view) {
-                    eprintln!("Failed to change default_view in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change default_view in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsConfirmOnStopToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_confirm_on_stop(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change confirm_on_stop in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsConfirmShortcutSwitchToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_confirm_shortcut_switch(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change confirm_shortcut_switch in settings: {}",
+                            e
+                        ),
+                    );
                 }
             }
             Message::SettingsDeleteConfirmationToggled(new_value) => {
@@ -1740,33 +4268,140 @@ impl Furtherance {
                     .fur_settings
                     .change_show_delete_confirmation(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_delete_confirmation in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_delete_confirmation in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsDeviceNameChanged(new_name) => {
+                set_current_device_name(&new_name);
+                if let Err(e) = self.fur_settings.change_device_name(&new_name) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change device_name in settings: {}", e),
                     );
                 }
             }
+            Message::SettingsDiscardIfShorterThanChanged(new_seconds) => {
+                if new_seconds >= 0 {
+                    if let Err(e) = self
+                        .fur_settings
+                        .change_discard_if_shorter_than_seconds(&new_seconds)
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change discard_if_shorter_than_seconds in settings: {}",
+                                e
+                            ),
+                        );
+                    }
+                }
+            }
             Message::SettingsDynamicTotalToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_dynamic_total(&new_value) {
-                    eprintln!("Failed to change dynamic_total in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change dynamic_total in settings: {}", e),
+                    );
+                }
+                refresh_report_running_time(self);
+            }
+            Message::SettingsEarningsExclusionsChanged(new_value) => {
+                let exclusions = parse_earnings_exclusions(&new_value);
+                self.earnings_exclusions_input = new_value;
+                // Only recompute the totals once the typed text changes what's excluded
+                if exclusions != self.fur_settings.earnings_exclusions {
+                    match self.fur_settings.change_earnings_exclusions(&exclusions) {
+                        Ok(_) => {
+                            refresh_status_snapshot(self);
+                            self.report.set_earnings_exclusions(exclusions);
+                            return self.report.refresh();
+                        }
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!("Failed to change earnings_exclusions in settings: {}", e),
+                        ),
+                    }
+                }
+            }
+            Message::SettingsHideArchivedInHistoryToggled(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_hide_archived_in_history(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change hide_archived_in_history in settings: {}",
+                            e
+                        ),
+                    );
                 }
             }
             Message::SettingsIdleTimeChanged(new_minutes) => {
                 if new_minutes >= 1 {
                     if let Err(e) = self.fur_settings.change_chosen_idle_time(&new_minutes) {
-                        eprintln!("Failed to change chosen_idle_time in settings: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change chosen_idle_time in settings: {}", e),
+                        );
                     }
                 }
             }
+            Message::SettingsIdleBackendSelected(new_value) => {
+                if let Err(e) = self.fur_settings.change_idle_backend(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change idle_backend in settings: {}", e),
+                    );
+                }
+                self.idle_backend_status = None;
+            }
             Message::SettingsIdleToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_notify_on_idle(&new_value) {
-                    eprintln!("Failed to change notify_on_idle in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change notify_on_idle in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsMonthlyEarningsTargetChanged(new_value) => {
+                if new_value >= 0.0 {
+                    match self.fur_settings.change_monthly_earnings_target(&new_value) {
+                        Ok(_) => {
+                            self.report.set_monthly_earnings_target(new_value);
+                            return self.report.refresh();
+                        }
+                        Err(e) => log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change monthly_earnings_target in settings: {}",
+                                e
+                            ),
+                        ),
+                    }
+                }
+            }
+            Message::SettingsNotifyOnPlannedTimeToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_notify_on_planned_time(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change notify_on_planned_time in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsPomodoroBreakLengthChanged(new_minutes) => {
                 if new_minutes >= 1 {
                     if let Err(e) = self.fur_settings.change_pomodoro_break_length(&new_minutes) {
-                        eprintln!("Failed to change pomodoro_break_length in settings: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change pomodoro_break_length in settings: {}", e),
+                        );
                     }
                 }
             }
@@ -1775,9 +4410,12 @@ impl Furtherance {
                     .fur_settings
                     .change_pomodoro_extended_breaks(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change pomdoro_extended_breaks in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change pomdoro_extended_breaks in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
@@ -1787,9 +4425,12 @@ impl Furtherance {
                         .fur_settings
                         .change_pomodoro_extended_break_interval(&new_interval)
                     {
-                        eprintln!(
-                            "Failed to change pomdoro_extended_break_interval in settings: {}",
-                            e
+                        log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change pomdoro_extended_break_interval in settings: {}",
+                                e
+                            ),
                         );
                     }
                 }
@@ -1800,9 +4441,12 @@ impl Furtherance {
                         .fur_settings
                         .change_pomodoro_extended_break_length(&new_minutes)
                     {
-                        eprintln!(
-                            "Failed to change pomdoro_extended_break_length in settings: {}",
-                            e
+                        log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change pomdoro_extended_break_length in settings: {}",
+                                e
+                            ),
                         );
                     }
                 }
@@ -1810,7 +4454,10 @@ impl Furtherance {
             Message::SettingsPomodoroLengthChanged(new_minutes) => {
                 if new_minutes >= 1 {
                     if let Err(e) = self.fur_settings.change_pomodoro_length(&new_minutes) {
-                        eprintln!("Failed to change pomodoro_length in settings: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change pomodoro_length in settings: {}", e),
+                        );
                     }
                     self.timer_text = get_timer_text(
                         &self,
@@ -1826,13 +4473,19 @@ impl Furtherance {
                         .fur_settings
                         .change_pomodoro_snooze_length(&new_minutes)
                     {
-                        eprintln!("Failed to change pomodoro_snooze_length in settings: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change pomodoro_snooze_length in settings: {}", e),
+                        );
                     }
                 }
             }
             Message::SettingsPomodoroToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_pomodoro(&new_value) {
-                    eprintln!("Failed to change pomodoro in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change pomodoro in settings: {}", e),
+                    );
                 }
                 self.timer_text = get_timer_text(
                     &self,
@@ -1846,9 +4499,12 @@ impl Furtherance {
                     .fur_settings
                     .change_pomodoro_notification_alarm_sound(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change pomodoro_notification_alarm_sound in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change pomodoro_notification_alarm_sound in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
@@ -1857,24 +4513,104 @@ impl Furtherance {
                     .fur_settings
                     .change_notify_reminder_interval(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change notify_reminder_interval in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change notify_reminder_interval in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
             Message::SettingsRemindersToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_notify_reminder(&new_value) {
-                    eprintln!("Failed to change notify_reminder in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change notify_reminder in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsResetWindowSizePressed => {
+                self.window_geometry_changed = false;
+                if let Err(e) = self.fur_settings.reset_window_geometry() {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to reset window geometry in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsReportWeekendsOffToggled(new_value) => {
+                match self.fur_settings.change_report_weekends_off(&new_value) {
+                    Ok(_) => {
+                        self.days_off.weekends = new_value;
+                        self.report.set_days_off(self.days_off.clone());
+                        return self.report.refresh();
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to change report_weekends_off in settings: {}", e),
+                    ),
+                }
+            }
+            Message::SettingsRoundingDirectionSelected(new_value) => {
+                if let Err(e) = self.fur_settings.change_rounding_direction(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change rounding_direction in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsRoundingMinutesSelected(new_value) => {
+                if let Err(e) = self.fur_settings.change_rounding_minutes(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change rounding_minutes in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsRoundingToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_rounding_enabled(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change rounding_enabled in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsScheduledSummaryEarningsToggled(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_scheduled_summary_earnings(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change scheduled_summary_earnings in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsScheduledSummaryHourChanged(new_value) => {
+                if let Err(e) = self.fur_settings.change_scheduled_summary_hour(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change scheduled_summary_hour in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsScheduledSummarySelected(new_value) => {
+                if let Err(e) = self.fur_settings.change_scheduled_summary(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change scheduled_summary in settings: {}", e),
+                    );
+                } else {
+                    skip_current_scheduled_summary(self);
                 }
             }
             Message::ShowReminderNotification => {
                 if !self.timer_is_running {
-                    show_notification(
-                        NotificationType::Reminder,
-                        &self.localization,
-                        self.fur_settings.pomodoro_notification_alarm_sound,
-                    );
+                    return notify(self, NotificationType::Reminder);
                 }
             }
             Message::SettingsServerChoiceSelected(new_value) => {
@@ -1894,17 +4630,23 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_average_earnings(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_average_earnings in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_average_earnings in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
             Message::SettingsShowChartAverageTimeToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_chart_average_time(&new_value) {
-                    eprintln!(
-                        "Failed to change show_chart_average_time in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_average_time in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
@@ -1913,15 +4655,47 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_breakdown_by_selection(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_breakdown_by_selection in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_breakdown_by_selection in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsShowChartCumulativeEarningsToggled(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_show_chart_cumulative_earnings(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_cumulative_earnings in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
             Message::SettingsShowChartEarningsToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_chart_earnings(&new_value) {
-                    eprintln!("Failed to change show_chart_earnings in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_chart_earnings in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsShowChartHeatmapToggled(new_value) => {
+                match self.fur_settings.change_show_chart_heatmap(&new_value) {
+                    Ok(_) => {
+                        self.report.set_show_heatmap(new_value);
+                        return self.report.refresh();
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_chart_heatmap in settings: {}", e),
+                    ),
                 }
             }
             Message::SettingsShowChartSelectionEarningsToggled(new_value) => {
@@ -1929,9 +4703,12 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_selection_earnings(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_selection_earnings in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_selection_earnings in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
@@ -1940,9 +4717,20 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_selection_time(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_selection_time in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_selection_time in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsShowChartTimeOfDayToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_show_chart_time_of_day(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_chart_time_of_day in settings: {}", e),
                     );
                 }
             }
@@ -1951,20 +4739,38 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_time_recorded(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_time_recorded in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_time_recorded in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
+            Message::SettingsShowChartTodosToggled(new_value) => {
+                match self.fur_settings.change_show_chart_todos(&new_value) {
+                    Ok(_) => {
+                        self.report.set_show_todo_chart(new_value);
+                        return self.report.refresh();
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_chart_todos in settings: {}", e),
+                    ),
+                }
+            }
             Message::SettingsShowChartTotalEarningsBoxToggled(new_value) => {
                 if let Err(e) = self
                     .fur_settings
                     .change_show_chart_total_earnings_box(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_total_earnings_box in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_total_earnings_box in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
@@ -1973,63 +4779,405 @@ impl Furtherance {
                     .fur_settings
                     .change_show_chart_total_time_box(&new_value)
                 {
-                    eprintln!(
-                        "Failed to change show_chart_total_time_box in settings: {}",
-                        e
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_total_time_box in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsShowChartWorkdayStatsToggled(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_show_chart_workday_stats(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change show_chart_workday_stats in settings: {}",
+                            e
+                        ),
                     );
                 }
             }
             Message::SettingsShowDailyTimeTotalToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_daily_time_total(&new_value) {
-                    eprintln!("Failed to change show_daily_time_total in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_daily_time_total in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsSummaryFormatSelected(new_format) => {
+                if let Err(e) = self.fur_settings.change_summary_format(&new_format) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change summary_format in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsSyncIntervalSelected(new_interval) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_sync_interval_minutes(&new_interval.minutes())
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change sync_interval_minutes in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsTabSelected(new_tab) => {
+                if new_tab == TabId::Data {
+                    refresh_trash(self);
+                    refresh_sync_status(self);
+                }
+                self.settings_active_tab = new_tab;
+            }
+            Message::SettingsTargetAppliesToWeekendsToggled(new_value) => {
+                if let Err(e) = self
+                    .fur_settings
+                    .change_target_applies_to_weekends(&new_value)
+                {
+                    log_error(
+                        &mut self.errors,
+                        format!(
+                            "Failed to change target_applies_to_weekends in settings: {}",
+                            e
+                        ),
+                    );
+                }
+            }
+            Message::SettingsThemePreferenceSelected(new_value) => {
+                if let Err(e) = self.fur_settings.change_theme_preference(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change theme_preference in settings: {}", e),
+                    );
+                }
+                // The system may have changed while a fixed theme was chosen
+                self.system_theme = FurTheme::detect();
+                all_charts::set_chart_theme(self.resolved_theme());
+            }
+            Message::SettingsTrashRetentionChanged(new_days) => {
+                if let Err(e) = self.fur_settings.change_trash_retention_days(&new_days) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change trash_retention_days in settings: {}", e),
+                    );
+                }
+                // Anything now past the retention period is purged on the next launch
+                refresh_trash(self);
+            }
+            Message::SettingsUse24HourTimeToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_use_24_hour_time(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change use_24_hour_time in settings: {}", e),
+                    );
+                }
+                set_use_24_hour_time(self.fur_settings.use_24_hour_time);
+            }
+            Message::SettingsWarnIfLongerThanChanged(new_hours) => {
+                if new_hours >= 0 {
+                    if let Err(e) = self
+                        .fur_settings
+                        .change_warn_if_longer_than_hours(&new_hours)
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!(
+                                "Failed to change warn_if_longer_than_hours in settings: {}",
+                                e
+                            ),
+                        );
+                    }
+                }
+            }
+            Message::SettingsWriteSummaryFileToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_write_summary_file(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change write_summary_file in settings: {}", e),
+                    );
+                } else if new_value {
+                    self.settings_more_message = Ok(String::new());
+                    return write_summary_file_now(self);
                 }
             }
-            Message::SettingsTabSelected(new_tab) => self.settings_active_tab = new_tab,
-            Message::ShortcutPressed(shortcut_task_input) => {
-                self.task_input = shortcut_task_input;
+            Message::ShortcutPressed(shortcut) => {
+                if self.timer_is_running {
+                    if self.pomodoro.on_break {
+                        return Task::none();
+                    }
+                    self.shortcut_to_switch_to = Some(shortcut);
+                    if self.fur_settings.confirm_shortcut_switch {
+                        self.displayed_alert = Some(FurAlert::SwitchToShortcutConfirmation);
+                        return Task::none();
+                    }
+                    return Task::perform(async { Message::SwitchToShortcut }, |msg| msg);
+                }
+                if let Err(e) = db_record_shortcut_use(&shortcut.uid) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to record shortcut use: {}", e),
+                    );
+                }
+                match db_retrieve_shortcut_usage() {
+                    Ok(usage) => self.shortcut_usage = usage,
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to retrieve shortcut usage: {}", e),
+                    ),
+                }
+                self.task_input = shortcut.to_string();
                 self.inspector_view = None;
                 self.shortcut_to_add = None;
                 self.shortcut_to_edit = None;
                 self.current_view = FurView::Timer;
                 return Task::perform(async { Message::StartStopPressed }, |msg| msg);
             }
-            Message::ShowAlert(alert_to_show) => self.displayed_alert = Some(alert_to_show),
+            Message::ShortcutSortSelected(new_sort) => {
+                if let Err(e) = self.fur_settings.change_shortcut_sort(&new_sort) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change shortcut_sort in settings: {}", e),
+                    );
+                }
+            }
+            Message::ShowAlert(alert_to_show) => {
+                if alert_to_show == FurAlert::ErrorLog {
+                    self.errors_viewed_at = Some(Local::now());
+                }
+                if alert_to_show == FurAlert::SyncHistory {
+                    refresh_sync_status(self);
+                }
+                self.displayed_alert = Some(alert_to_show);
+            }
+            Message::SplitTask(split_time) => {
+                if let Some(task_to_edit) = self.task_to_edit.as_mut() {
+                    task_to_edit.show_split_time_picker = false;
+                    task_to_edit.displayed_split_time = split_time;
+
+                    let original = match db_retrieve_task_by_id(&task_to_edit.uid) {
+                        Ok(Some(task)) => task,
+                        Ok(None) => return Task::none(),
+                        Err(e) => {
+                            log_error(
+                                &mut self.errors,
+                                format!("Failed to retrieve task to split: {}", e),
+                            );
+                            return Task::none();
+                        }
+                    };
+
+                    // The picker only chooses a time, so try the stop date for tasks
+                    // that run past midnight
+                    let split_parts =
+                        [original.start_time, original.stop_time]
+                            .iter()
+                            .find_map(|date| {
+                                match combine_chosen_time_with_date(*date, split_time) {
+                                    LocalResult::Single(split_at) => original.split_at(split_at),
+                                    _ => None,
+                                }
+                            });
+
+                    match split_parts {
+                        Some((first_part, second_part)) => {
+                            match db_split_task(&first_part, &second_part) {
+                                Ok(_) => {
+                                    // Keep the new part open so it can be renamed right away
+                                    self.task_to_edit = Some(TaskToEdit::new_from(&second_part));
+                                    return chain_tasks(vec![
+                                        update_task_history(self.history_days_loaded),
+                                        sync_after_change(&self.fur_user),
+                                    ]);
+                                }
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to split task: {}", e),
+                                ),
+                            }
+                        }
+                        None => task_to_edit.input_error(
+                            self.localization
+                                .get_message("split-time-outside-task", None),
+                        ),
+                    }
+                }
+            }
             Message::SettingsShowEarningsToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_task_earnings(&new_value) {
-                    eprintln!("Failed to change show_earnings in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_earnings in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsShowNetEarningsToggled(new_value) => {
+                match self.fur_settings.change_show_net_earnings(&new_value) {
+                    Ok(_) => all_charts::set_chart_net_earnings(net_earnings_deduction(
+                        &self.fur_settings,
+                    )),
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_net_earnings in settings: {}", e),
+                    ),
                 }
             }
             Message::SettingsShowSecondsToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_seconds(&new_value) {
-                    eprintln!("Failed to change show_seconds in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_seconds in settings: {}", e),
+                    );
+                }
+                if self.timer_is_running {
+                    return refresh_stopwatch(self);
+                } else {
+                    self.timer_text = get_timer_text(self, 0);
+                }
+            }
+            Message::SettingsShowShortcutStatsToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_show_shortcut_stats(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_shortcut_stats in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsShowTaskProjectToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_task_project(&new_value) {
-                    eprintln!("Failed to change show_task_project in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_task_project in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsShowTaskPlannedTimeToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_show_task_planned_time(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_task_planned_time in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsShowTaskTagsToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_task_tags(&new_value) {
-                    eprintln!("Failed to change show_task_tags in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_task_tags in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsShowTimerInTitleToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_show_timer_in_title(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_timer_in_title in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsShowTodoEstimatesToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_show_todo_estimates(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_todo_estimates in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsShowTodoProjectToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_todo_project(&new_value) {
-                    eprintln!("Failed to change show_todo_project in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_todo_project in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsShowTodoRateToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_todo_rate(&new_value) {
-                    eprintln!("Failed to change show_todo_rate in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_todo_rate in settings: {}", e),
+                    );
                 }
             }
             Message::SettingsShowTodoTagsToggled(new_value) => {
                 if let Err(e) = self.fur_settings.change_show_todo_tags(&new_value) {
-                    eprintln!("Failed to change show_todo_tags in settings: {}", e);
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change show_todo_tags in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsSoundOnIdleToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_sound_on_idle(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change sound_on_idle in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsSoundOnStartStopToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_sound_on_start_stop(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change sound_on_start_stop in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsSoundsToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_sounds_enabled(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change sounds_enabled in settings: {}", e),
+                    );
+                }
+            }
+            Message::SettingsStatusFileToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_status_file(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change status_file in settings: {}", e),
+                    );
+                }
+                if new_value {
+                    update_status_file(self);
+                } else {
+                    delete_status_file();
+                }
+            }
+            Message::SettingsStatusServerPortChanged(new_port) => {
+                if let Err(e) = self.fur_settings.change_status_server_port(&new_port) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change status_server_port in settings: {}", e),
+                    );
+                }
+                self.settings_more_message = Ok(String::new());
+                restart_status_server(self);
+            }
+            Message::SettingsStatusServerToggled(new_value) => {
+                if let Err(e) = self.fur_settings.change_status_server(&new_value) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change status_server in settings: {}", e),
+                    );
+                }
+                self.settings_more_message = Ok(String::new());
+                restart_status_server(self);
+            }
+            Message::StartSecondaryTimer => {
+                if self.timer_is_running {
+                    start_secondary_timer(self);
                 }
             }
             Message::StartStopPressed => {
+                self.recent_tasks.close();
+                self.task_input_focused = false;
                 if self.timer_is_running {
                     // Do not move declarations to after if else
                     // They are needed in this position to properly initiate timer on reset
@@ -2039,21 +5187,25 @@ impl Furtherance {
                         self.pomodoro.snoozed = false;
                         self.pomodoro.sessions = 0;
                         reset_timer(self);
-                        return update_task_history(self.fur_settings.days_to_show);
+                        return update_task_history(self.history_days_loaded);
                     } else {
                         self.pomodoro.on_break = false;
                         self.pomodoro.snoozed = false;
                         self.pomodoro.sessions = 0;
-                        stop_timer(self, Local::now());
+                        // Stopping while paused ends the task when the pause began
+                        let length_check = stop_timer_for_review(self, timer_now(self));
 
-                        let mut tasks = vec![];
-                        tasks.push(update_task_history(self.fur_settings.days_to_show));
+                        let mut tasks = vec![length_check];
+                        tasks.push(update_task_history(self.history_days_loaded));
                         tasks.push(sync_after_change(&self.fur_user));
                         return chain_tasks(tasks);
                     }
                 } else {
                     start_timer(self);
-                    return Task::perform(get_timer_duration(), |_| Message::StopwatchTick);
+                    return chain_tasks(vec![
+                        schedule_stopwatch_tick(self),
+                        request_summary_file(self),
+                    ]);
                 }
             }
             Message::StartTimerWithTask(task_input) => {
@@ -2064,8 +5216,27 @@ impl Furtherance {
                 self.current_view = FurView::Timer;
                 return Task::perform(async { Message::StartStopPressed }, |msg| msg);
             }
-            Message::StopwatchTick => {
-                if self.timer_is_running {
+            Message::StopSecondaryTimer(index) => {
+                let length_check = stop_secondary_timer(self, index, Local::now());
+                return chain_tasks(vec![
+                    length_check,
+                    update_task_history(self.history_days_loaded),
+                    sync_after_change(&self.fur_user),
+                ]);
+            }
+            Message::StopwatchTick(tick_id) => {
+                // A newer tick has been scheduled since this one
+                if tick_id != self.stopwatch_tick_id {
+                    return Task::none();
+                }
+                if self.timer_is_running && !self.timer_paused {
+                    // Also catches a boundary that passed while the computer was asleep
+                    if let Some(stop_at) = auto_stop_time(&self.fur_settings)
+                        && let Some(stop_time) =
+                            auto_stop_due(self.timer_start_time, Local::now(), stop_at)
+                    {
+                        return auto_stop_timer(self, stop_time);
+                    }
                     let duration = Local::now().signed_duration_since(self.timer_start_time);
                     let seconds_elapsed = duration.num_seconds();
                     self.timer_text = get_timer_text(self, seconds_elapsed);
@@ -2073,54 +5244,45 @@ impl Furtherance {
                         && self.timer_text == "0:00:00".to_string()
                         && seconds_elapsed > 2
                     {
+                        // A snoozed pomodoro was recorded when it first ran out
+                        if !self.pomodoro.on_break && !self.pomodoro.snoozed {
+                            record_pomodoro_session(self);
+                        }
                         // Check if idle or other alert is being displayed so as not to replace it
                         if self.displayed_alert.is_none() {
+                            // The alert shows whether or not the notification could be
                             if self.pomodoro.on_break {
-                                show_notification(
-                                    NotificationType::BreakOver,
-                                    &self.localization,
-                                    self.fur_settings.pomodoro_notification_alarm_sound,
-                                );
                                 self.displayed_alert = Some(FurAlert::PomodoroBreakOver);
+                                return notify(self, NotificationType::BreakOver);
                             } else {
-                                show_notification(
-                                    NotificationType::PomodoroOver,
-                                    &self.localization,
-                                    self.fur_settings.pomodoro_notification_alarm_sound,
-                                );
                                 self.displayed_alert = Some(FurAlert::PomodoroOver);
+                                return notify(self, NotificationType::PomodoroOver);
                             }
                         }
                         return Task::none();
-                    }
-
-                    if self.fur_settings.notify_on_idle
-                        && self.displayed_alert != Some(FurAlert::PomodoroOver)
-                    {
-                        let is_wayland = detect_wayland();
-                        let is_currently_idle = if is_wayland {
-                            #[cfg(target_os = "linux")]
-                            {
-                                let monitor = WAYLAND_MONITOR.get_or_init(|| {
-                                    WaylandIdleMonitor::spawn(
-                                        (self.fur_settings.chosen_idle_time * 60) as u64,
-                                    )
-                                });
-                                monitor.is_idle()
-                            }
-
-                            #[cfg(not(target_os = "linux"))]
-                            {
-                                false
-                            }
-                        } else {
-                            let secs: u64 = if let Ok(idle) = UserIdle::get_time() {
-                                idle.as_seconds()
-                            } else {
-                                0
-                            };
-                            secs >= (self.fur_settings.chosen_idle_time * 60) as u64
+                    }
+
+                    let mut idle_notification = Task::none();
+                    if self.fur_settings.notify_on_idle
+                        && self.displayed_alert != Some(FurAlert::PomodoroOver)
+                    {
+                        let idle_threshold = (self.fur_settings.chosen_idle_time * 60) as u64;
+                        let idle_result =
+                            idle::get_idle_time(self.fur_settings.idle_backend, idle_threshold);
+                        let is_currently_idle = match &idle_result {
+                            Ok((secs, _)) => *secs >= idle_threshold,
+                            Err(_) => false,
                         };
+                        let idle_status = idle_result.map(|(_, backend)| backend);
+                        if self.idle_backend_status.as_ref() != Some(&idle_status) {
+                            if let Err(e) = &idle_status {
+                                log_error(
+                                    &mut self.errors,
+                                    format!("Idle detection unavailable: {}", e),
+                                );
+                            }
+                            self.idle_backend_status = Some(idle_status);
+                        }
 
                         if is_currently_idle && !self.idle.reached {
                             self.idle.reached = true;
@@ -2128,39 +5290,95 @@ impl Furtherance {
                                 - TimeDelta::seconds(self.fur_settings.chosen_idle_time * 60);
                         } else if !is_currently_idle && self.idle.reached && !self.idle.notified {
                             self.idle.notified = true;
-                            show_notification(
-                                NotificationType::Idle,
-                                &self.localization,
-                                self.fur_settings.pomodoro_notification_alarm_sound,
-                            );
                             self.displayed_alert = Some(FurAlert::Idle);
+                            idle_notification = notify(self, NotificationType::Idle);
+                            play_sound(FurSound::Idle, &self.fur_settings);
+                        }
+                    }
+
+                    // Notify once per plan, so changing the plan while running notifies again
+                    let mut planned_notification = Task::none();
+                    let planned_seconds = active_planned_seconds(self);
+                    if planned_seconds > 0
+                        && seconds_elapsed >= planned_seconds
+                        && self.planned_time_notified != Some(planned_seconds)
+                    {
+                        self.planned_time_notified = Some(planned_seconds);
+                        if self.fur_settings.notify_on_planned_time {
+                            planned_notification =
+                                notify(self, NotificationType::PlannedTimeReached);
                         }
                     }
 
-                    // Write autosave every minute
+                    // Write autosave and status file every minute
                     if seconds_elapsed > 1 && seconds_elapsed % 60 == 0 {
-                        if let Err(e) = write_autosave(&self.task_input, self.timer_start_time) {
-                            eprintln!("Error writing autosave: {e}");
+                        // Until the user decides about an idle gap, a crash shouldn't keep it
+                        let last_active = if self.idle.reached {
+                            self.idle.start_time
+                        } else {
+                            Local::now()
+                        };
+                        if let Err(e) = write_autosave(
+                            &self.task_input,
+                            &self.running_task_notes.text(),
+                            self.timer_start_time,
+                            last_active,
+                        ) {
+                            log_error(&mut self.errors, format!("Error writing autosave: {e}"));
                         }
+                        update_status_file(self);
+                        refresh_report_running_time(self);
                     }
+                    refresh_status_snapshot(self);
 
-                    return Task::perform(get_timer_duration(), |_| Message::StopwatchTick);
+                    return Task::batch([
+                        idle_notification,
+                        planned_notification,
+                        schedule_stopwatch_tick(self),
+                    ]);
                 } else {
                     return Task::none();
                 }
             }
+            Message::SubmitAutoStopTime(new_time) => {
+                self.show_auto_stop_time_picker = false;
+                let auto_stop_time = format_auto_stop_time(NaiveTime::from(new_time));
+                if let Err(e) = self.fur_settings.change_auto_stop_time(&auto_stop_time) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change auto_stop_time in settings: {}", e),
+                    );
+                }
+            }
             Message::SubmitCurrentTaskStartTime(new_time) => {
                 match convert_iced_time_to_chrono_local(new_time) {
                     LocalResult::Single(local_time) => {
-                        if local_time <= Local::now() {
-                            self.displayed_task_start_time = new_time;
-                            self.timer_start_time = local_time;
-                            self.show_timer_start_picker = false;
+                        if local_time > Local::now() {
+                            // Leave the picker open so another time can be chosen
+                            self.timer_start_error =
+                                Some(self.localization.get_message("start-time-in-future", None));
+                            return Task::none();
+                        }
+                        self.show_timer_start_picker = false;
+                        self.timer_start_error = None;
+                        let previous_stop =
+                            tasks::latest_stop_on(&self.task_history, local_time.date_naive());
+                        if let Some(previous_stop) =
+                            tasks::start_clamped_to_previous_stop(local_time, previous_stop)
+                        {
+                            self.timer_start_overlap = Some((local_time, previous_stop));
+                            self.displayed_alert = Some(FurAlert::TimerStartOverlap);
+                            return Task::none();
                         }
+                        return set_timer_start_time(self, local_time);
                     }
                     _ => {
                         self.show_timer_start_picker = false;
-                        eprintln!("Error converting chosen time to local time.");
+                        self.timer_start_error = None;
+                        log_error(
+                            &mut self.errors,
+                            format!("Error converting chosen time to local time."),
+                        );
                     }
                 }
             }
@@ -2170,8 +5388,26 @@ impl Furtherance {
             Message::SubmitExportStartDate(new_date) => {
                 self.export_settings.set_picked_start_date(new_date)
             }
-            Message::SubmitReportEndDate(new_date) => self.report.set_date_range_end(new_date),
-            Message::SubmitReportStartDate(new_date) => self.report.set_date_range_start(new_date),
+            Message::SubmitReportEndDate(new_date) => {
+                return self.report.set_date_range_end(new_date);
+            }
+            Message::SubmitReportStartDate(new_date) => {
+                return self.report.set_date_range_start(new_date);
+            }
+            Message::SubmitProjectColor(project, new_color) => {
+                self.project_color_to_edit = None;
+                let new_color = new_color.to_srgb();
+                match db_set_project_color(&project, &new_color.to_hex()) {
+                    Ok(_) => {
+                        self.project_colors.insert(project, new_color);
+                        self.report.set_project_colors(self.project_colors.clone());
+                    }
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Error saving project color: {}", e),
+                    ),
+                }
+            }
             Message::SubmitShortcutColor(new_color) => {
                 if let Some(shortcut_to_add) = self.shortcut_to_add.as_mut() {
                     shortcut_to_add.color = new_color;
@@ -2208,6 +5444,11 @@ impl Furtherance {
                         }
                         _ => {}
                     }
+                    task_to_edit.input_error(task_times_error(
+                        task_to_edit.new_start_time,
+                        task_to_edit.new_stop_time,
+                        &self.localization,
+                    ));
                 } else if let Some(task_to_add) = self.task_to_add.as_mut() {
                     match property {
                         EditTaskProperty::StartDate => {
@@ -2234,10 +5475,14 @@ impl Furtherance {
                         }
                         _ => {}
                     }
+                    task_to_add.input_error(task_times_error(
+                        task_to_add.start_time,
+                        task_to_add.stop_time,
+                        &self.localization,
+                    ));
                 }
             }
             Message::SubmitTaskEditTime(new_time, property) => {
-                // TODO: Edit to fix issues in greater than stop, etc. like below
                 if let Some(task_to_edit) = self.task_to_edit.as_mut() {
                     match property {
                         EditTaskProperty::StartTime => {
@@ -2264,6 +5509,11 @@ impl Furtherance {
                         }
                         _ => {}
                     }
+                    task_to_edit.input_error(task_times_error(
+                        task_to_edit.new_start_time,
+                        task_to_edit.new_stop_time,
+                        &self.localization,
+                    ));
                 } else if let Some(task_to_add) = self.task_to_add.as_mut() {
                     match property {
                         EditTaskProperty::StartTime => {
@@ -2290,6 +5540,11 @@ impl Furtherance {
                         }
                         _ => {}
                     }
+                    task_to_add.input_error(task_times_error(
+                        task_to_add.start_time,
+                        task_to_add.stop_time,
+                        &self.localization,
+                    ));
                 }
             }
             Message::SubmitTodoEditDate(new_date) => {
@@ -2311,24 +5566,204 @@ impl Furtherance {
                     }
                 }
             }
+            Message::SummaryFileBrowsePressed => {
+                let current_path = PathBuf::from(&self.fur_settings.summary_file_path);
+                let mut dialog = FileDialog::new()
+                    .set_title(self.localization.get_message("summary-file-title", None))
+                    .add_filter("JSON", &["json"])
+                    .set_can_create_directories(true)
+                    .set_file_name("summary.json");
+                if let Some(directory) = current_path.parent() {
+                    dialog = dialog.set_directory(directory);
+                }
+
+                if let Some(path) = dialog.save_file() {
+                    if let Err(e) = self
+                        .fur_settings
+                        .change_summary_file_path(&path.to_string_lossy())
+                    {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change summary_file_path in settings: {}", e),
+                        );
+                    } else if self.fur_settings.write_summary_file {
+                        return write_summary_file_now(self);
+                    }
+                }
+            }
+            Message::SummaryFileDue => {
+                self.summary_file_pending = false;
+                if self.fur_settings.write_summary_file {
+                    return write_summary_file_now(self);
+                }
+            }
+            Message::SummaryFileWritten(result) => {
+                if let Err(e) = result {
+                    log_error(&mut self.errors, format!("Error writing summary file: {e}"));
+                    // Stop retrying so a bad path only produces one error
+                    if let Err(e) = self.fur_settings.change_write_summary_file(&false) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change write_summary_file in settings: {}", e),
+                        );
+                    }
+                    self.settings_more_message = Err(self
+                        .localization
+                        .get_message(
+                            "summary-file-failed",
+                            Some(&HashMap::from([("error", FluentValue::from(e))])),
+                        )
+                        .into());
+                }
+            }
+            Message::SwitchProfile(name) => {
+                // A running timer would write its task into the wrong database
+                if self.timer_is_running || !self.secondary_timers.is_empty() {
+                    return Task::none();
+                }
+                let Some(profile) = self
+                    .fur_settings
+                    .profiles
+                    .iter()
+                    .find(|profile| profile.name == name)
+                    .cloned()
+                else {
+                    return Task::none();
+                };
+                if profile.database_url == self.fur_settings.database_url {
+                    return Task::none();
+                }
+
+                let previous_url = self.fur_settings.database_url.clone();
+                if let Err(e) = self.fur_settings.change_db_url(&profile.database_url) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to change database_url in settings: {}", e),
+                    );
+                    return Task::none();
+                }
+                if let Err(e) = db_init() {
+                    log_error(
+                        &mut self.errors,
+                        format!("Error accessing profile database: {}", e),
+                    );
+                    if let Err(e) = self.fur_settings.change_db_url(&previous_url) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to change database_url in settings: {}", e),
+                        );
+                    }
+                    return set_negative_temp_notice(
+                        &mut self.settings_profiles_message,
+                        self.localization
+                            .get_message("error-accessing-database", None),
+                    );
+                }
+
+                // Reload everything from the new database as if the app had just started
+                self.status_server = None;
+                let (mut reloaded, startup_tasks) =
+                    Furtherance::new(self.fur_settings.clone(), None);
+                reloaded.current_view = self.current_view;
+                reloaded.displayed_alert = None;
+                reloaded.idle_backend_status = self.idle_backend_status.take();
+                reloaded.notification_status = self.notification_status.take();
+                reloaded.settings_active_tab = self.settings_active_tab.clone();
+                reloaded.show_sidebar = self.show_sidebar;
+                reloaded.system_theme = self.system_theme;
+                reloaded.window_geometry_changed = self.window_geometry_changed;
+                reloaded.window_height = self.window_height;
+                reloaded.window_maximized = self.window_maximized;
+                reloaded.window_minimized = self.window_minimized;
+                reloaded.window_position = self.window_position;
+                reloaded.window_width = self.window_width;
+                *self = reloaded;
+
+                let notice = set_positive_temp_notice(
+                    &mut self.settings_profiles_message,
+                    self.localization.get_message(
+                        "profile-switched",
+                        Some(&HashMap::from([("name", FluentValue::from(profile.name))])),
+                    ),
+                );
+                return Task::batch([startup_tasks, notice]);
+            }
+            Message::SwitchTask => {
+                if !self.timer_is_running
+                    || self.pomodoro.on_break
+                    || !is_task_switch(&self.running_task_input, &self.task_input)
+                {
+                    return Task::none();
+                }
+                // What was typed is the next task, so the running one is saved as it started
+                let next_input =
+                    std::mem::replace(&mut self.task_input, self.running_task_input.clone());
+                return switch_timer(self, next_input.trim().to_string());
+            }
+            Message::SwitchToShortcut => {
+                self.displayed_alert = None;
+                let Some(shortcut) = self.shortcut_to_switch_to.take() else {
+                    return Task::none();
+                };
+                if !self.timer_is_running || self.pomodoro.on_break {
+                    return Task::none();
+                }
+                if let Err(e) = db_record_shortcut_use(&shortcut.uid) {
+                    log_error(
+                        &mut self.errors,
+                        format!("Failed to record shortcut use: {}", e),
+                    );
+                }
+                match db_retrieve_shortcut_usage() {
+                    Ok(usage) => self.shortcut_usage = usage,
+                    Err(e) => log_error(
+                        &mut self.errors,
+                        format!("Failed to retrieve shortcut usage: {}", e),
+                    ),
+                }
+                self.inspector_view = None;
+                self.shortcut_to_add = None;
+                self.shortcut_to_edit = None;
+                self.current_view = FurView::Timer;
+                return switch_timer(self, shortcut.to_string());
+            }
+            Message::SyncTimerFired => {
+                // A manual sync just finished, so this one would find nothing new
+                if Local::now().timestamp() - self.fur_settings.last_successful_sync
+                    < MIN_SECONDS_BETWEEN_SYNCS
+                {
+                    return Task::none();
+                }
+                return Task::perform(async { Message::SyncWithServer }, |msg| msg);
+            }
             Message::SyncWithServer => {
+                if self.sync_suspended {
+                    return Task::none();
+                }
                 let last_sync = self.fur_settings.last_sync;
 
                 let user = match self.fur_user.clone() {
                     Some(user) => user,
                     None => {
-                        eprintln!("Please log in first");
+                        log_error(&mut self.errors, format!("Please log in first"));
                         return Task::none();
                     }
                 };
 
                 self.login_message = Ok(self.localization.get_message("syncing", None));
+                self.sync_started_at = Some(Local::now());
 
                 let encryption_key =
                     match decrypt_encryption_key(&user.encrypted_key, &user.key_nonce) {
                         Ok(key) => key,
                         Err(e) => {
-                            eprintln!("Failed to decrypt encryption key (SyncWithServer): {:?}", e);
+                            log_error(
+                                &mut self.errors,
+                                format!(
+                                    "Failed to decrypt encryption key (SyncWithServer): {:?}",
+                                    e
+                                ),
+                            );
                             return set_negative_temp_notice(
                                 &mut self.login_message,
                                 self.localization.get_message("error-decrypting-key", None),
@@ -2409,9 +5844,17 @@ impl Furtherance {
                             })
                             .collect();
 
-                        let sync_count = encrypted_tasks.len()
-                            + encrypted_shortcuts.len()
-                            + encrypted_todos.len();
+                        let record = FurSyncRecord {
+                            uploaded: encrypted_tasks.len()
+                                + encrypted_shortcuts.len()
+                                + encrypted_todos.len(),
+                            payload_bytes: approximate_payload_bytes(
+                                &encrypted_tasks,
+                                &encrypted_shortcuts,
+                                &encrypted_todos,
+                            ),
+                            ..FurSyncRecord::default()
+                        };
 
                         let sync_result = sync_with_server(
                             &user,
@@ -2422,18 +5865,24 @@ impl Furtherance {
                         )
                         .await;
 
-                        (sync_result, sync_count)
+                        (sync_result, record)
                     },
                     Message::SyncComplete,
                 );
             }
             Message::SyncComplete(sync_result) => {
                 match sync_result {
-                    (Ok(response), mut sync_count) => {
+                    (Ok(response), mut record) => {
+                        record_clock_skew(self, response.clock_skew);
+                        record.payload_bytes += approximate_payload_bytes(
+                            &response.tasks,
+                            &response.shortcuts,
+                            &response.todos,
+                        );
                         let user = match self.fur_user.clone() {
                             Some(user) => user,
                             None => {
-                                eprintln!("Please log in first");
+                                log_error(&mut self.errors, format!("Please log in first"));
                                 return set_negative_temp_notice(
                                     &mut self.login_message,
                                     self.localization.get_message("log-in-first", None),
@@ -2445,9 +5894,12 @@ impl Furtherance {
                             match decrypt_encryption_key(&user.encrypted_key, &user.key_nonce) {
                                 Ok(key) => key,
                                 Err(e) => {
-                                    eprintln!(
-                                        "Failed to decrypt encryption key (SyncComplete): {:?}",
-                                        e
+                                    log_error(
+                                        &mut self.errors,
+                                        format!(
+                                            "Failed to decrypt encryption key (SyncComplete): {:?}",
+                                            e
+                                        ),
                                     );
                                     return set_negative_temp_notice(
                                         &mut self.login_message,
@@ -2457,6 +5909,7 @@ impl Furtherance {
                             };
 
                         // Decrypt and process server tasks
+                        let mut changed_dates = BTreeSet::new();
                         for encrypted_task in response.tasks {
                             match encryption::decrypt::<FurTask>(
                                 &encrypted_task.encrypted_data,
@@ -2470,13 +5923,22 @@ impl Furtherance {
                                             if server_task.last_updated > client_task.last_updated {
                                                 match db_update_task(&server_task) {
                                                     Err(e) => {
-                                                        eprintln!(
-                                                            "Error updating task from server: {}",
-                                                            e
+                                                        log_error(
+                                                            &mut self.errors,
+                                                            format!(
+                                                                "Error updating task from server: {}",
+                                                                e
+                                                            ),
                                                         );
                                                     }
                                                     _ => {
-                                                        sync_count += 1;
+                                                        record.downloaded += 1;
+                                                        changed_dates.insert(
+                                                            client_task.start_time.date_naive(),
+                                                        );
+                                                        changed_dates.insert(
+                                                            server_task.start_time.date_naive(),
+                                                        );
                                                     }
                                                 }
                                             }
@@ -2485,27 +5947,38 @@ impl Furtherance {
                                             // Task does not exist - insert it
                                             match db_insert_task(&server_task) {
                                                 Err(e) => {
-                                                    eprintln!(
-                                                        "Error writing new task from server: {}",
-                                                        e
+                                                    log_error(
+                                                        &mut self.errors,
+                                                        format!(
+                                                            "Error writing new task from server: {}",
+                                                            e
+                                                        ),
                                                     );
                                                 }
                                                 _ => {
-                                                    sync_count += 1;
+                                                    record.downloaded += 1;
+                                                    changed_dates.insert(
+                                                        server_task.start_time.date_naive(),
+                                                    );
                                                 }
                                             }
                                         }
-                                        Err(e) => {
-                                            eprintln!(
+                                        Err(e) => log_error(
+                                            &mut self.errors,
+                                            format!(
                                                 "Error checking for existing task from server: {}",
                                                 e
-                                            )
-                                        }
+                                            ),
+                                        ),
                                     }
                                 }
-                                Err(e) => eprintln!("Failed to decrypt task: {:?}", e),
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to decrypt task: {:?}", e),
+                                ),
                             }
                         }
+                        self.report.invalidate_dates(&changed_dates);
 
                         // Decrypt and process server shortcuts
                         for encrypted_shortcut in response.shortcuts {
@@ -2523,13 +5996,16 @@ impl Furtherance {
                                             {
                                                 match db_update_shortcut(&server_shortcut) {
                                                     Err(e) => {
-                                                        eprintln!(
-                                                            "Error updating shortcut from server: {}",
-                                                            e
+                                                        log_error(
+                                                            &mut self.errors,
+                                                            format!(
+                                                                "Error updating shortcut from server: {}",
+                                                                e
+                                                            ),
                                                         );
                                                     }
                                                     _ => {
-                                                        sync_count += 1;
+                                                        record.downloaded += 1;
                                                     }
                                                 }
                                             }
@@ -2538,25 +6014,32 @@ impl Furtherance {
                                             // Shortcut does not exist - insert it
                                             match db_insert_shortcut(&server_shortcut) {
                                                 Err(e) => {
-                                                    eprintln!(
-                                                        "Error writing new shortcut from server: {}",
-                                                        e
+                                                    log_error(
+                                                        &mut self.errors,
+                                                        format!(
+                                                            "Error writing new shortcut from server: {}",
+                                                            e
+                                                        ),
                                                     );
                                                 }
                                                 _ => {
-                                                    sync_count += 1;
+                                                    record.downloaded += 1;
                                                 }
                                             }
                                         }
-                                        Err(e) => {
-                                            eprintln!(
+                                        Err(e) => log_error(
+                                            &mut self.errors,
+                                            format!(
                                                 "Error checking for existing shortcut from server: {}",
                                                 e
-                                            )
-                                        }
+                                            ),
+                                        ),
                                     }
                                 }
-                                Err(e) => eprintln!("Failed to decrypt shortcut: {:?}", e),
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to decrypt shortcut: {:?}", e),
+                                ),
                             }
                         }
 
@@ -2574,13 +6057,16 @@ impl Furtherance {
                                             if server_todo.last_updated > client_todo.last_updated {
                                                 match db_update_todo(&server_todo) {
                                                     Err(e) => {
-                                                        eprintln!(
-                                                            "Error updating todo from server: {}",
-                                                            e
+                                                        log_error(
+                                                            &mut self.errors,
+                                                            format!(
+                                                                "Error updating todo from server: {}",
+                                                                e
+                                                            ),
                                                         );
                                                     }
                                                     _ => {
-                                                        sync_count += 1;
+                                                        record.downloaded += 1;
                                                     }
                                                 }
                                             }
@@ -2589,25 +6075,32 @@ impl Furtherance {
                                             // Todo does not exist - insert it
                                             match db_insert_todo(&server_todo) {
                                                 Err(e) => {
-                                                    eprintln!(
-                                                        "Error writing new todo from server: {}",
-                                                        e
+                                                    log_error(
+                                                        &mut self.errors,
+                                                        format!(
+                                                            "Error writing new todo from server: {}",
+                                                            e
+                                                        ),
                                                     );
                                                 }
                                                 _ => {
-                                                    sync_count += 1;
+                                                    record.downloaded += 1;
                                                 }
                                             }
                                         }
-                                        Err(e) => {
-                                            eprintln!(
+                                        Err(e) => log_error(
+                                            &mut self.errors,
+                                            format!(
                                                 "Error checking for existing todo from server: {}",
                                                 e
-                                            )
-                                        }
+                                            ),
+                                        ),
                                     }
                                 }
-                                Err(e) => eprintln!("Failed to decrypt todo: {:?}", e),
+                                Err(e) => log_error(
+                                    &mut self.errors,
+                                    format!("Failed to decrypt todo: {:?}", e),
+                                ),
                             }
                         }
 
@@ -2616,8 +6109,21 @@ impl Furtherance {
                             .fur_settings
                             .change_last_sync(&response.server_timestamp)
                         {
-                            eprintln!("Failed to change last_sync in settings: {}", e);
+                            log_error(
+                                &mut self.errors,
+                                format!("Failed to change last_sync in settings: {}", e),
+                            );
+                        }
+                        if let Err(e) = self
+                            .fur_settings
+                            .change_last_successful_sync(&Local::now().timestamp())
+                        {
+                            log_error(
+                                &mut self.errors,
+                                format!("Failed to change last_successful_sync in settings: {}", e),
+                            );
                         }
+                        self.sync_retry_attempt = None;
 
                         // If the database_id changed, send all tasks, or if the server has orphaned tasks, re-sync those
                         if !response.orphaned_tasks.is_empty()
@@ -2726,9 +6232,14 @@ impl Furtherance {
                                             })
                                             .collect();
 
-                                        sync_count += encrypted_tasks.len()
+                                        record.uploaded += encrypted_tasks.len()
                                             + encrypted_shortcuts.len()
                                             + encrypted_todos.len();
+                                        record.payload_bytes += approximate_payload_bytes(
+                                            &encrypted_tasks,
+                                            &encrypted_shortcuts,
+                                            &encrypted_todos,
+                                        );
 
                                         let sync_result = sync_with_server(
                                             &user,
@@ -2739,7 +6250,7 @@ impl Furtherance {
                                         )
                                         .await;
 
-                                        (sync_result, sync_count)
+                                        (sync_result, record)
                                     },
                                     Message::SyncComplete,
                                 );
@@ -2748,20 +6259,43 @@ impl Furtherance {
 
                         self.fur_settings.needs_full_sync = false;
 
+                        let now = Local::now();
+                        record.synced_at = now.timestamp();
+                        record.duration_millis =
+                            self.sync_started_at.take().map_or(0, |started_at| {
+                                now.signed_duration_since(started_at).num_milliseconds()
+                            });
+                        if let Err(e) = db_insert_sync_record(&record) {
+                            log_error(&mut self.errors, format!("Failed to record sync: {}", e));
+                        }
+                        refresh_sync_status(self);
+
                         let mut tasks = vec![];
-                        tasks.push(update_task_history(self.fur_settings.days_to_show));
+                        tasks.push(update_task_history(self.history_days_loaded));
                         tasks.push(update_todo_list());
-                        tasks.push(set_positive_temp_notice(
-                            &mut self.login_message,
-                            self.localization.get_message(
-                                "sync-successful",
-                                Some(&HashMap::from([("count", FluentValue::from(sync_count))])),
+                        tasks.push(match self.clock_skew {
+                            Some(skew) => set_negative_temp_notice(
+                                &mut self.login_message,
+                                clock_skew_warning(&self.localization, skew),
                             ),
-                        ));
+                            None => set_positive_temp_notice(
+                                &mut self.login_message,
+                                self.localization.get_message(
+                                    "sync-successful",
+                                    Some(&HashMap::from([(
+                                        "count",
+                                        FluentValue::from(record.items()),
+                                    )])),
+                                ),
+                            ),
+                        });
                         return chain_tasks(tasks);
                     }
                     (Err(ApiError::TokenRefresh(msg)), _) if msg == "Failed to refresh token" => {
-                        eprintln!("Sync error. Credentials have changed. Log in again.");
+                        log_error(
+                            &mut self.errors,
+                            format!("Sync error. Credentials have changed. Log in again."),
+                        );
                         if let Some(user) = self.fur_user.clone() {
                             return Task::perform(
                                 async move { logout::server_logout(&user).await },
@@ -2769,15 +6303,53 @@ impl Furtherance {
                             );
                         }
                     }
+                    (Err(error @ ApiError::Network(_)), _)
+                        if error.network_failure() == Some(NetworkFailure::Certificate) =>
+                    {
+                        // Retrying won't fix a certificate the client doesn't trust
+                        log_error(&mut self.errors, format!("Sync error: {:?}", error));
+                        self.sync_retry_attempt = None;
+                        return set_negative_temp_notice(
+                            &mut self.login_message,
+                            self.localization
+                                .get_message("server-certificate-error", None),
+                        );
+                    }
+                    (Err(ApiError::Network(e)), _) => {
+                        log_error(&mut self.errors, format!("Sync error, will retry: {:?}", e));
+                        self.sync_retry_attempt =
+                            Some(self.sync_retry_attempt.map_or(1, |attempt| attempt + 1));
+                        return set_negative_temp_notice(
+                            &mut self.login_message,
+                            self.localization
+                                .get_message("sync-offline-will-retry", None),
+                        );
+                    }
+                    (Err(ApiError::Auth(msg)), _) | (Err(ApiError::TokenRefresh(msg)), _) => {
+                        log_error(&mut self.errors, format!("Sync error: {}", msg));
+                        return set_negative_temp_notice(
+                            &mut self.login_message,
+                            self.localization.get_message("reauthenticate-error", None),
+                        );
+                    }
+                    (Err(ApiError::IncompatibleVersion(msg)), _) => {
+                        log_error(
+                            &mut self.errors,
+                            format!("Sync suspended, the server needs a newer client: {}", msg),
+                        );
+                        self.sync_suspended = true;
+                        self.sync_retry_attempt = None;
+                        self.login_message = Ok(String::new());
+                    }
                     (Err(ApiError::InactiveSubscription(msg)), _) => {
-                        eprintln!("Sync error: {}", msg);
+                        log_error(&mut self.errors, format!("Sync error: {}", msg));
                         return set_negative_temp_notice(
                             &mut self.login_message,
                             self.localization.get_message("subscription-inactive", None),
                         );
                     }
                     (Err(e), _) => {
-                        eprintln!("Sync error: {:?}", e);
+                        log_error(&mut self.errors, format!("Sync error: {:?}", e));
                         return set_negative_temp_notice(
                             &mut self.login_message,
                             self.localization.get_message("sync-failed", None),
@@ -2785,14 +6357,57 @@ impl Furtherance {
                     }
                 }
             }
+            Message::SystemPowerChanged(event) => match event {
+                PowerEvent::Suspending => {
+                    // If the machine never wakes, the task ends when it went to sleep
+                    if self.timer_is_running && !self.timer_paused {
+                        if let Err(e) = write_autosave(
+                            &self.task_input,
+                            &self.running_task_notes.text(),
+                            self.timer_start_time,
+                            Local::now(),
+                        ) {
+                            log_error(&mut self.errors, format!("Error writing autosave: {e}"));
+                        }
+                    }
+                    if !self.secondary_timers.is_empty() {
+                        write_secondary_timer_autosaves(self);
+                    }
+                }
+                PowerEvent::Resumed(slept_at) => {
+                    // Idle detection can't run while asleep, so treat the sleep as idle time
+                    let slept_at = slept_at.max(self.timer_start_time);
+                    if self.timer_is_running
+                        && !self.timer_paused
+                        && self.fur_settings.notify_on_idle
+                        && !self.idle.reached
+                        && self.displayed_alert != Some(FurAlert::PomodoroOver)
+                        && Local::now() - slept_at
+                            >= TimeDelta::minutes(self.fur_settings.chosen_idle_time)
+                    {
+                        self.idle.reached = true;
+                        self.idle.notified = true;
+                        self.idle.start_time = slept_at;
+                        self.displayed_alert = Some(FurAlert::Idle);
+                        play_sound(FurSound::Idle, &self.fur_settings);
+                        return notify(self, NotificationType::Idle);
+                    }
+                }
+            },
             Message::TabPressed { shift } => {
-                if shift {
-                    return widget::operation::focus_previous();
-                } else {
-                    return widget::operation::focus_next();
+                self.recent_tasks.close();
+                self.task_input_focused = false;
+                match next_focus(&current_focus_order(self), self.focused_input, shift) {
+                    Some(input_id) => {
+                        self.focused_input = Some(input_id);
+                        return widget::operation::focus(input_id);
+                    }
+                    None if shift => return widget::operation::focus_previous(),
+                    None => return widget::operation::focus_next(),
                 }
             }
             Message::TaskInputChanged(new_value) => {
+                self.task_input_focused = true;
                 // Handle all possible task input checks here rather than on start/stop press
                 // If timer is running, task can never be empty
                 if self.timer_is_running {
@@ -2800,51 +6415,62 @@ impl Furtherance {
                         return Task::none();
                     }
                 }
-                let new_value_trimmed = new_value.trim_start();
-                // Doesn't start with @
-                if new_value_trimmed.chars().next() != Some('@')
-                // Doesn't start with #
-                && new_value_trimmed.chars().next() != Some('#')
-                // Doesn't start with $
-                && new_value_trimmed.chars().next() != Some('$')
-                // No more than 1 @
-                && new_value_trimmed.chars().filter(|&c| c == '@').count() < 2
-                // No more than 1 $
-                && new_value_trimmed.chars().filter(|&c| c == '$').count() < 2
-                {
-                    // Check if there is a $ and the subsequent part is a parseable f32
-                    if let Some(dollar_index) = new_value_trimmed.find('$') {
-                        let after_dollar = &new_value_trimmed[dollar_index + 1..];
-                        if after_dollar.is_empty() {
-                            // Allow typing the $ in the first place
-                            self.task_input = new_value_trimmed.to_string();
-                        } else {
-                            // Find the parseable number right after the $
-                            let end_index = after_dollar.find(' ').unwrap_or(after_dollar.len());
-                            let number_str = &after_dollar[..end_index];
-                            let parsed_num = number_str.parse::<f32>();
-
-                            if parsed_num.is_ok()
-                                && has_max_two_decimals(&number_str)
-                                && parsed_num.unwrap_or(f32::MAX) < f32::MAX
-                            {
-                                let remaining_str = &after_dollar[end_index..].trim_start();
-                                if remaining_str.is_empty() {
-                                    // Allow a number to be typed after the $
-                                    self.task_input = new_value_trimmed.to_string();
-                                } else {
-                                    // Only allow a space, @, or # to be typed after the $ amount
-                                    if remaining_str.starts_with('@')
-                                        || remaining_str.starts_with('#')
-                                    {
-                                        self.task_input = new_value_trimmed.to_string();
-                                    }
-                                }
-                            }
+                if let Some(accepted) = accepted_task_input(&new_value) {
+                    self.task_input = accepted;
+                }
+            }
+            Message::TestNotificationPressed => {
+                let result = show_notification(
+                    NotificationType::Test,
+                    &self.localization,
+                    self.fur_settings.pomodoro_notification_alarm_sound,
+                );
+                self.settings_notification_message = match &result {
+                    Ok(_) => Ok(self
+                        .localization
+                        .get_message("test-notification-sent", None)),
+                    Err(e) => Err(self
+                        .localization
+                        .get_message(
+                            "notifications-unavailable",
+                            Some(&HashMap::from([("error", FluentValue::from(e.clone()))])),
+                        )
+                        .into()),
+                };
+                // A working test brings notifications back without a restart
+                self.notification_status = Some(result);
+            }
+            Message::TimerStartOverlapClampPressed => {
+                self.displayed_alert = None;
+                if let Some((_, previous_stop)) = self.timer_start_overlap.take() {
+                    return set_timer_start_time(self, previous_stop);
+                }
+            }
+            Message::TimerStartOverlapKeepPressed => {
+                self.displayed_alert = None;
+                if let Some((start_time, _)) = self.timer_start_overlap.take() {
+                    return set_timer_start_time(self, start_time);
+                }
+            }
+            Message::ToggleArchivedTodos => {
+                self.show_archived_todos = !self.show_archived_todos;
+            }
+            Message::ToggleDayOff(date) => {
+                let result = if self.days_off.is_marked(&date) {
+                    db_delete_day_off(&date)
+                } else {
+                    db_insert_day_off(&date)
+                };
+                match result {
+                    Ok(_) => {
+                        if !self.days_off.dates.remove(&date) {
+                            self.days_off.dates.insert(date);
                         }
-                    } else {
-                        // If there is no $, no other checks are necessary
-                        self.task_input = new_value_trimmed.to_string();
+                        self.report.set_days_off(self.days_off.clone());
+                        return self.report.refresh();
+                    }
+                    Err(e) => {
+                        log_error(&mut self.errors, format!("Failed to change day off: {}", e))
                     }
                 }
             }
@@ -2853,6 +6479,20 @@ impl Furtherance {
                     .as_mut()
                     .map(|group| group.is_in_edit_mode = !group.is_in_edit_mode);
             }
+            Message::ToggleHistoryFilter(chip) => {
+                if !self.active_history_filters.remove(&chip) {
+                    self.active_history_filters.insert(chip);
+                }
+            }
+            Message::ToggleHistorySelection => {
+                self.history_selection = match self.history_selection {
+                    Some(_) => None,
+                    None => Some(HistorySelection::new()),
+                };
+            }
+            Message::ToggleRunningTaskNotes => {
+                self.show_running_task_notes = !self.show_running_task_notes;
+            }
             Message::ToggleSidebar => {
                 self.show_sidebar = !self.show_sidebar;
             }
@@ -2863,13 +6503,18 @@ impl Furtherance {
                 .find(|todo| todo.uid == uid)
             {
                 Some(todo) => {
+                    let previous_completed_at = todo.completed_at;
                     todo.is_completed = !todo.is_completed;
-                    match db_toggle_todo_completed(&uid) {
+                    todo.completed_at = todo.is_completed.then(Local::now);
+                    match db_toggle_todo_completed(&uid, todo.completed_at) {
                         Ok(_) => return sync_after_change(&self.fur_user),
                         Err(e) => {
-                            eprintln!(
-                                "Failed to toggle is_completed on todo with uid {}: {}",
-                                uid, e
+                            log_error(
+                                &mut self.errors,
+                                format!(
+                                    "Failed to toggle is_completed on todo with uid {}: {}",
+                                    uid, e
+                                ),
                             );
                             match self
                                 .todos
@@ -2877,19 +6522,68 @@ impl Furtherance {
                                 .flat_map(|vec| vec.iter_mut())
                                 .find(|todo| todo.uid == uid)
                             {
-                                Some(todo_undo) => todo_undo.is_completed = !todo_undo.is_completed,
-                                None => eprintln!(
-                                    "Failed to undo toggle is_completed on todo with uid {}.",
-                                    uid
+                                Some(todo_undo) => {
+                                    todo_undo.is_completed = !todo_undo.is_completed;
+                                    todo_undo.completed_at = previous_completed_at;
+                                }
+                                None => log_error(
+                                    &mut self.errors,
+                                    format!(
+                                        "Failed to undo toggle is_completed on todo with uid {}.",
+                                        uid
+                                    ),
                                 ),
                             }
                         }
                     }
                 }
-                None => eprintln!("Failed to toggle is_completed on todo with uid {}.", uid),
+                None => log_error(
+                    &mut self.errors,
+                    format!("Failed to toggle is_completed on todo with uid {}.", uid),
+                ),
             },
+            Message::UndoLoggedTask => {
+                if let Some(task) = self.logged_task.take() {
+                    if let Err(e) = db_delete_tasks_by_ids(&[task.uid.clone()]) {
+                        log_error(
+                            &mut self.errors,
+                            format!("Failed to undo logged task: {}", e),
+                        );
+                        return Task::none();
+                    }
+                    if self.task_input.trim().is_empty() && !self.timer_is_running {
+                        self.task_input = format!(
+                            "{} {}",
+                            task.to_string(),
+                            format_duration_input(task.total_time_in_seconds())
+                        );
+                    }
+                    return chain_tasks(vec![
+                        update_task_history(self.history_days_loaded),
+                        sync_after_change(&self.fur_user),
+                    ]);
+                }
+            }
             Message::UpdateTaskHistory(new_history) => {
+                self.report.invalidate_dates(&tasks::changed_history_dates(
+                    &self.task_history,
+                    &new_history,
+                ));
                 self.task_history = new_history;
+                self.history_filter_chips =
+                    tasks::most_used_filter_chips(&self.task_history, HISTORY_FILTER_CHIP_COUNT);
+                refresh_history_sections(self);
+                refresh_project_lists(self);
+                refresh_recent_tasks(self);
+                refresh_status_snapshot(self);
+                // Tasks can be edited from the report's selection list, which may
+                // reach further back than the task history
+                let report_refresh = if self.current_view == FurView::Report {
+                    self.report.reload()
+                } else {
+                    Task::none()
+                };
+                let summary_file = request_summary_file(self);
 
                 let today = Local::now().date_naive();
                 if let Some(todays_todos) = self.todos.get(&today) {
@@ -2897,13 +6591,17 @@ impl Furtherance {
                         let todos_clone = todays_todos.clone();
                         let tasks_clone = todays_tasks.clone();
 
-                        return Task::perform(
-                            async move { task_actions::after_refresh(todos_clone, tasks_clone) },
-                            |new_todos| Message::UpdateTodaysTodos(new_todos),
-                        );
+                        return chain_tasks(vec![
+                            report_refresh,
+                            summary_file,
+                            Task::perform(
+                                async move { task_actions::after_refresh(todos_clone, tasks_clone) },
+                                |new_todos| Message::UpdateTodaysTodos(new_todos),
+                            ),
+                        ]);
                     }
                 };
-                self.export_settings.get_all_projects();
+                return chain_tasks(vec![report_refresh, summary_file]);
             }
             Message::UpdateTodaysTodos(new_todos) => {
                 let today = Local::now().date_naive();
@@ -2912,18 +6610,26 @@ impl Furtherance {
                 }
             }
             Message::UpdateTodoList(new_list) => {
-                self.todos = new_list;
+                set_todo_list(self, new_list);
             }
             Message::UserEmailChanged(new_email) => {
                 self.fur_user_fields.email = new_email;
             }
             Message::UserLoginPressed => {
-                self.fur_user_fields.server = self
-                    .fur_user_fields
-                    .server
-                    .clone()
-                    .trim_end_matches('/')
-                    .to_string();
+                match normalize_server_url(&self.fur_user_fields.server) {
+                    Ok(server) => self.fur_user_fields.server = server,
+                    Err(e) => {
+                        let message_key = match e {
+                            ServerUrlError::Empty => "server-required",
+                            ServerUrlError::Invalid => "server-invalid",
+                            ServerUrlError::UnsupportedScheme => "server-must-contain-protocol",
+                        };
+                        return set_negative_temp_notice(
+                            &mut self.login_message,
+                            self.localization.get_message(message_key, None),
+                        );
+                    }
+                }
                 let email = self.fur_user_fields.email.clone();
                 let encryption_key = self.fur_user_fields.encryption_key.clone();
                 let server = self.fur_user_fields.server.clone();
@@ -2936,12 +6642,16 @@ impl Furtherance {
             }
             Message::UserLoginComplete(response_result) => match response_result {
                 Ok(response) => {
+                    record_clock_skew(self, response.clock_skew);
                     // Encrypt encryption key with device-specific key
                     let (encrypted_key, key_nonce) =
                         match encrypt_encryption_key(&self.fur_user_fields.encryption_key) {
                             Ok(result) => result,
                             Err(e) => {
-                                eprintln!("Error encrypting key: {:?}", e);
+                                log_error(
+                                    &mut self.errors,
+                                    format!("Error encrypting key: {:?}", e),
+                                );
                                 reset_fur_user(&mut self.fur_user);
                                 return set_negative_temp_notice(
                                     &mut self.login_message,
@@ -2960,7 +6670,10 @@ impl Furtherance {
                         &response.refresh_token,
                         &self.fur_user_fields.server,
                     ) {
-                        eprintln!("Error storing user credentials: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Error storing user credentials: {}", e),
+                        );
                         reset_fur_user(&mut self.fur_user);
                         return set_negative_temp_notice(
                             &mut self.login_message,
@@ -2971,7 +6684,10 @@ impl Furtherance {
 
                     // Always do a full sync after login
                     if let Err(e) = self.fur_settings.change_needs_full_sync(&true) {
-                        eprintln!("Error changing needs_full_sync: {}", e);
+                        log_error(
+                            &mut self.errors,
+                            format!("Error changing needs_full_sync: {}", e),
+                        );
                     };
 
                     let key_length = self.fur_user_fields.encryption_key.len();
@@ -2980,7 +6696,10 @@ impl Furtherance {
                     match db_retrieve_credentials() {
                         Ok(optional_user) => self.fur_user = optional_user,
                         Err(e) => {
-                            eprintln!("Error retrieving user credentials from database: {}", e);
+                            log_error(
+                                &mut self.errors,
+                                format!("Error retrieving user credentials from database: {}", e),
+                            );
                             reset_fur_user(&mut self.fur_user);
                             return set_negative_temp_notice(
                                 &mut self.login_message,
@@ -2995,23 +6714,40 @@ impl Furtherance {
                         self.fur_user_fields.encryption_key = "x".repeat(key_length);
                         self.fur_user_fields.server = fur_user.server;
                         let mut tasks: Vec<Task<Message>> = vec![];
-                        tasks.push(set_positive_temp_notice(
-                            &mut self.login_message,
-                            self.localization.get_message("login-successful", None),
-                        ));
+                        tasks.push(match self.clock_skew {
+                            Some(skew) => set_negative_temp_notice(
+                                &mut self.login_message,
+                                clock_skew_warning(&self.localization, skew),
+                            ),
+                            None => set_positive_temp_notice(
+                                &mut self.login_message,
+                                self.localization.get_message("login-successful", None),
+                            ),
+                        });
                         tasks.push(sync_after_change(&self.fur_user));
                         return chain_tasks(tasks);
                     }
                 }
                 Err(e) => {
-                    eprintln!("Error logging in: {:?}", e);
+                    log_error(&mut self.errors, format!("Error logging in: {:?}", e));
                     reset_fur_user(&mut self.fur_user);
                     match e {
-                        ApiError::Network(e) if e.to_string() == "builder error" => {
+                        ApiError::Network(_) => {
+                            let message_key = match e.network_failure() {
+                                Some(NetworkFailure::Dns) => "server-not-found",
+                                Some(NetworkFailure::Certificate) => "server-certificate-error",
+                                _ => "server-unreachable",
+                            };
+                            return set_negative_temp_notice(
+                                &mut self.login_message,
+                                self.localization.get_message(message_key, None),
+                            );
+                        }
+                        ApiError::IncompatibleVersion(_) => {
                             return set_negative_temp_notice(
                                 &mut self.login_message,
                                 self.localization
-                                    .get_message("server-must-contain-protocol", None),
+                                    .get_message("sync-incompatible-version", None),
                             );
                         }
                         _ => {
@@ -3034,6 +6770,8 @@ impl Furtherance {
             }
             Message::UserLogoutComplete => {
                 reset_fur_user(&mut self.fur_user);
+                clear_clock_skew(self);
+                self.sync_suspended = false;
                 self.fur_user_fields = FurUserFields::default();
                 self.settings_server_choice = Some(ServerChoices::Official);
                 return set_positive_temp_notice(
@@ -3043,6 +6781,7 @@ impl Furtherance {
             }
             Message::UserAutoLogoutComplete => {
                 reset_fur_user(&mut self.fur_user);
+                clear_clock_skew(self);
                 self.fur_user_fields = FurUserFields::default();
                 self.settings_server_choice = Some(ServerChoices::Official);
                 return set_negative_temp_notice(
@@ -3054,7 +6793,85 @@ impl Furtherance {
                 self.fur_user_fields.encryption_key = new_key;
             }
             Message::UserServerChanged(new_server) => {
-                self.fur_user_fields.server = new_server;
+                // Spaces are never part of an address, so drop them while typing
+                self.fur_user_fields.server = new_server.trim().to_string();
+            }
+            Message::WindowCloseRequested(_) => {
+                // The periodic save may not have caught the last move or resize yet
+                if self.window_geometry_changed {
+                    save_window_geometry(self);
+                }
+                delete_status_file();
+                return iced::exit();
+            }
+            Message::WindowMaximizedChanged(maximized) => {
+                if self.window_maximized != maximized {
+                    self.window_maximized = maximized;
+                    self.window_geometry_changed = true;
+                }
+            }
+            Message::WindowMinimizedChanged(minimized) => {
+                if self.window_minimized != minimized {
+                    self.window_minimized = minimized;
+                    // Catch the timer up and switch tick frequency
+                    if self.timer_is_running {
+                        return refresh_stopwatch(self);
+                    }
+                }
+            }
+            Message::WindowMonitorSizeChecked(id, monitor_size) => {
+                if let Some(monitor_size) = monitor_size {
+                    let saved_position =
+                        Point::new(self.fur_settings.window_x, self.fur_settings.window_y);
+                    let position = clamp_window_position(
+                        saved_position,
+                        Size::new(self.window_width, self.window_height),
+                        monitor_size,
+                    );
+                    if position != saved_position {
+                        return window::move_to(id, position);
+                    }
+                }
+            }
+            Message::WindowMoved(position) => {
+                self.window_position = Some(position);
+                self.window_geometry_changed = true;
+            }
+            Message::WindowResized(size) => {
+                self.window_width = size.width;
+                self.window_height = size.height;
+                self.window_geometry_changed = true;
+            }
+            Message::WindowStateChanged(id) => {
+                return Task::batch([
+                    window::is_minimized(id).map(|minimized| {
+                        Message::WindowMinimizedChanged(minimized.unwrap_or(false))
+                    }),
+                    window::is_maximized(id).map(Message::WindowMaximizedChanged),
+                ]);
+            }
+            Message::YearInReviewComputed(review) => {
+                self.year_in_review = Some(review);
+                self.displayed_alert = Some(FurAlert::YearInReview);
+            }
+            Message::YearInReviewPressed => {
+                let year = review_year(Local::now().date_naive());
+                return Task::perform(
+                    async move {
+                        let tasks = match NaiveDate::from_ymd_opt(year, 1, 1)
+                            .zip(NaiveDate::from_ymd_opt(year, 12, 31))
+                        {
+                            Some((start, end)) => db_retrieve_tasks_by_date_range(start, end)
+                                .unwrap_or_else(|e| {
+                                    eprintln!("Error retrieving tasks for year in review: {}", e);
+                                    Vec::new()
+                                }),
+                            None => Vec::new(),
+                        };
+                        year_in_review(&tasks, year)
+                    },
+                    Message::YearInReviewComputed,
+                );
             }
         }
         Task::none()
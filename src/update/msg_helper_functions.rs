@@ -14,295 +14,1868 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std::{fs::File, io::Seek, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs::File,
+    io::{BufRead, BufReader, Seek},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use chrono::{
-    DateTime, Datelike, Local, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Timelike,
+    DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeDelta, TimeZone, Timelike,
     offset::LocalResult,
 };
-use csv::{Reader, ReaderBuilder, StringRecord};
-use iced::Task;
+use csv::{ReaderBuilder, StringRecord};
+use fluent::FluentValue;
+use iced::{Point, Size, Task, widget, window};
 use iced_aw::{date_picker, time_picker};
 use itertools::Itertools;
 use notify_rust::{Notification, Timeout};
+use palette::Srgb;
 use regex::Regex;
-use tokio::time;
+use tokio::{sync::mpsc, time};
 
 use crate::{
     app::Furtherance,
-    autosave::delete_autosave,
-    constants::SETTINGS_MESSAGE_DURATION,
-    database::{db_delete_all_credentials, db_insert_task, db_insert_tasks, db_task_exists},
-    helpers::tasks,
+    autosave::{delete_autosave, write_autosave, write_secondary_autosaves},
+    constants::{
+        DAY_NOTES_CSV_HEADER, ESCAPABLE_TASK_INPUT_CHARS, INSPECTOR_ROW_WIDTH,
+        LITERAL_STAND_IN_START, MAX_SECONDARY_TIMERS, NON_BILLABLE_MARKER, RECENT_TASKS_SHOWN,
+        SEARCH_PAGE_SIZE, SETTINGS_MESSAGE_DURATION, SHORTCUT_BUTTON_WIDTH,
+        SHORTCUT_FOCUS_RING_WIDTH, SHORTCUTS_SPACING, SIDEBAR_WIDTH, SYNC_RETRY_BASE_SECONDS,
+        SYNC_RETRY_MAX_SECONDS, SYNC_SERVER_INPUT_ID, TASK_INPUT_ID, TITLE_TASK_NAME_MAX_CHARS,
+    },
+    database::{
+        db_count_changes_since, db_delete_all_credentials, db_get_directory,
+        db_insert_pomodoro_session, db_insert_task, db_insert_tasks, db_merge_database,
+        db_retrieve_all_day_notes, db_retrieve_all_project_names, db_retrieve_day_note,
+        db_retrieve_days_off, db_retrieve_deleted_items, db_retrieve_project_colors,
+        db_retrieve_project_default_tags, db_retrieve_recent_distinct_tasks,
+        db_retrieve_sync_history, db_retrieve_tasks_by_date_range, db_save_day_note,
+        db_search_tasks, db_set_project_color, db_task_with_times_exists, db_upgrade_legacy,
+    },
+    helpers::{
+        clock_skew::{is_significant_skew, set_clock_offset, skew_minutes, sync_timestamp},
+        color_utils::{FromHex, RandomColor, ToHex},
+        earnings::{group_earnings, is_excluded_from_earnings},
+        error_log::log_error,
+        formatting::{format_time, parse_time},
+        keyboard_nav::{grid_columns, inspector_focus_order, settings_focus_order},
+        project_defaults::{default_tags_for, merge_default_tags},
+        scheduled_summary::{is_summary_due, latest_period, write_scheduled_summary},
+        sounds::{FurSound, play_sound},
+        summaries,
+        summary_file::{RunningSummary, write_delay, write_summary_file},
+        tasks,
+        trash::retention_cutoff,
+    },
     localization::Localization,
-    models::{fur_idle::FurIdle, fur_task::FurTask, fur_user::FurUser},
+    models::{
+        csv_import::{CsvImport, CsvImportSummary, CsvRowError},
+        fur_day_note::FurDayNote,
+        fur_idle::FurIdle,
+        fur_pomodoro::{FurPomodoroSession, FurPomodoroStats},
+        fur_running_timer::FurRunningTimer,
+        fur_settings::FurSettings,
+        fur_task::FurTask,
+        fur_task_group::FurTaskGroup,
+        fur_todo::FurTodo,
+        fur_user::FurUser,
+        recent_tasks::distinct_task_strings,
+        task_to_edit::TaskToEdit,
+    },
+    status_file::write_status_file,
+    status_server::{PomodoroSnapshot, StatusServer, StatusSnapshot},
     ui::todos,
     update::messages::Message,
-    view_enums::NotificationType,
+    view_enums::{
+        FurAlert, FurHistoryGrouping, FurInspectorView, FurRoundingDirection, FurView,
+        NotificationType, ServerChoices,
+    },
 };
 
-#[cfg(target_os = "linux")]
-use {
-    std::{env, path::Path},
-    uzers::get_current_uid,
-};
+#[cfg(all(unix, not(target_os = "macos")))]
+use crate::helpers::notification_actions::{PomodoroNotificationAction, send_notification_action};
 
 pub fn chain_tasks(commands: Vec<Task<Message>>) -> Task<Message> {
     Task::batch(commands)
 }
 
-pub fn update_task_history(days_to_show: i64) -> Task<Message> {
-    Task::perform(
-        async move { tasks::get_task_history(days_to_show) },
-        Message::UpdateTaskHistory,
-    )
+/// Run the current search again from the first page
+pub fn refresh_search_results(state: &mut Furtherance) {
+    match db_search_tasks(&state.search_query, SEARCH_PAGE_SIZE, 0) {
+        Ok(results) => {
+            state.search_end_reached = results.len() < SEARCH_PAGE_SIZE;
+            state.search_results = results;
+        }
+        Err(e) => {
+            eprintln!("Failed to search tasks: {}", e);
+            state.search_end_reached = true;
+            state.search_results = vec![];
+        }
+    }
+}
+
+pub fn update_task_history(days_to_show: i64) -> Task<Message> {
+    Task::perform(
+        async move { tasks::get_task_history(days_to_show) },
+        Message::UpdateTaskHistory,
+    )
+}
+
+/// Reload everything read from the database after switching to another one
+pub fn reload_database(state: &mut Furtherance) -> Task<Message> {
+    state.day_notes = get_day_notes();
+    state.days_off.dates = get_days_off();
+    state.report.set_days_off(state.days_off.clone());
+    state.history_end_reached = false;
+    state.report.invalidate_all();
+    update_task_history(state.history_days_loaded)
+}
+
+/// Upgrade a legacy database on another thread so the window stays responsive,
+/// reporting progress as it goes
+pub fn upgrade_legacy_database(path: PathBuf) -> Task<Message> {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        let progress_sender = sender.clone();
+        let result = db_upgrade_legacy(&path, |progress| {
+            let _ = progress_sender.send(Message::LegacyUpgradeProgressed(progress));
+        })
+        .map_err(|e| e.to_string());
+        let _ = sender.send(Message::LegacyUpgradeFinished(path, result));
+    });
+
+    Task::run(
+        async_stream::stream! {
+            while let Some(message) = receiver.recv().await {
+                yield message;
+            }
+        },
+        |msg| msg,
+    )
+}
+
+/// Merge another database into the current one on another thread, reporting
+/// progress as it goes like `upgrade_legacy_database`
+pub fn merge_database(path: PathBuf) -> Task<Message> {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        let progress_sender = sender.clone();
+        let result = db_merge_database(&path, &db_get_directory(), |progress| {
+            let _ = progress_sender.send(Message::DatabaseMergeProgressed(progress));
+        })
+        .map_err(|e| e.to_string());
+        let _ = sender.send(Message::DatabaseMergeFinished(result));
+    });
+
+    Task::run(
+        async_stream::stream! {
+            while let Some(message) = receiver.recv().await {
+                yield message;
+            }
+        },
+        |msg| msg,
+    )
+}
+
+pub fn update_todo_list() -> Task<Message> {
+    Task::perform(
+        async move { todos::get_all_todos() },
+        Message::UpdateTodoList,
+    )
+}
+
+/// Shows the todos, keeping completed ones past the archive threshold out of the list
+pub fn set_todo_list(state: &mut Furtherance, mut todos: BTreeMap<NaiveDate, Vec<FurTodo>>) {
+    state.archived_todos = todos::archive_completed_todos(
+        &mut todos,
+        state.fur_settings.auto_hide_completed_after_days,
+        Local::now().date_naive(),
+    );
+    state.todos = todos;
+}
+
+/// Rebuilds the week or month sections after the history or its grouping changes
+pub fn refresh_history_sections(state: &mut Furtherance) {
+    state.history_sections = match state.fur_settings.history_grouping {
+        FurHistoryGrouping::Day => BTreeMap::new(),
+        grouping => tasks::group_history_by_period(&state.task_history, grouping),
+    };
+}
+
+/// The task groups shown in the history in display order, whichever way it's grouped
+pub fn focusable_history_groups(state: &Furtherance) -> Vec<&FurTaskGroup> {
+    match state.fur_settings.history_grouping {
+        FurHistoryGrouping::Day => tasks::visible_history_groups(
+            &state.task_history,
+            &state.active_history_filters,
+            &state.fur_settings,
+        ),
+        _ => tasks::visible_history_groups(
+            &state.history_sections,
+            &state.active_history_filters,
+            &state.fur_settings,
+        ),
+    }
+}
+
+/// Whether closing the open inspector would lose anything the user typed or picked
+pub fn inspector_has_unsaved_changes(state: &Furtherance) -> bool {
+    match &state.inspector_view {
+        Some(FurInspectorView::AddNewTask | FurInspectorView::AddTaskToGroup) => state
+            .task_to_add
+            .as_ref()
+            .is_some_and(|task_to_add| task_to_add.is_changed()),
+        Some(FurInspectorView::AddNewTodo) => state
+            .todo_to_add
+            .as_ref()
+            .is_some_and(|todo_to_add| todo_to_add.is_changed()),
+        Some(FurInspectorView::AddShortcut) => state
+            .shortcut_to_add
+            .as_ref()
+            .is_some_and(|shortcut_to_add| shortcut_to_add.is_changed()),
+        Some(FurInspectorView::EditGroup) => state
+            .group_to_edit
+            .as_ref()
+            .is_some_and(|group_to_edit| group_to_edit.is_changed()),
+        Some(FurInspectorView::EditShortcut) => state
+            .shortcut_to_edit
+            .as_ref()
+            .is_some_and(|shortcut_to_edit| shortcut_to_edit.is_changed()),
+        Some(FurInspectorView::EditTask) => state
+            .task_to_edit
+            .as_ref()
+            .is_some_and(|task_to_edit| task_to_edit.is_changed()),
+        Some(FurInspectorView::EditTodo) => state
+            .todo_to_edit
+            .as_ref()
+            .is_some_and(|todo_to_edit| todo_to_edit.is_changed()),
+        Some(FurInspectorView::EditDayNote | FurInspectorView::GenerateInvoice) | None => false,
+    }
+}
+
+/// If the open inspector has unsaved changes, asks before discarding them and holds on
+/// to `then` until the user decides. Returns true when the caller should stop.
+pub fn confirm_discarding_changes(state: &mut Furtherance, then: Message) -> bool {
+    if inspector_has_unsaved_changes(state) {
+        state.discard_changes_then = Some(then);
+        state.displayed_alert = Some(FurAlert::DiscardChangesConfirmation);
+        true
+    } else {
+        false
+    }
+}
+
+/// Drops the form in the open inspector, so whatever closes it next goes ahead
+pub fn discard_inspector_changes(state: &mut Furtherance) {
+    match &state.inspector_view {
+        Some(FurInspectorView::AddNewTask | FurInspectorView::AddTaskToGroup) => {
+            state.task_to_add = None;
+        }
+        Some(FurInspectorView::AddNewTodo) => state.todo_to_add = None,
+        Some(FurInspectorView::AddShortcut) => state.shortcut_to_add = None,
+        Some(FurInspectorView::EditGroup) => state.group_to_edit = None,
+        Some(FurInspectorView::EditShortcut) => state.shortcut_to_edit = None,
+        Some(FurInspectorView::EditTask) => state.task_to_edit = None,
+        Some(FurInspectorView::EditTodo) => state.todo_to_edit = None,
+        Some(FurInspectorView::EditDayNote | FurInspectorView::GenerateInvoice) | None => {}
+    }
+}
+
+/// What the inspector's Cancel button does, for closing it with Escape
+pub fn inspector_cancel_message(view: &FurInspectorView) -> Message {
+    match view {
+        FurInspectorView::AddNewTask
+        | FurInspectorView::AddTaskToGroup
+        | FurInspectorView::EditTask => Message::CancelTaskEdit,
+        FurInspectorView::AddNewTodo | FurInspectorView::EditTodo => Message::CancelTodoEdit,
+        FurInspectorView::AddShortcut | FurInspectorView::EditShortcut => Message::CancelShortcut,
+        FurInspectorView::EditDayNote => Message::CancelDayNoteEdit,
+        FurInspectorView::EditGroup => Message::CancelGroupEdit,
+        FurInspectorView::GenerateInvoice => Message::CancelInvoice,
+    }
+}
+
+/// The inputs Tab cycles through in whatever is showing, leaving out any that are hidden.
+/// Empty when the view has no fixed order, in which case Tab moves through the window.
+pub fn current_focus_order(state: &Furtherance) -> Vec<&'static str> {
+    match &state.inspector_view {
+        Some(FurInspectorView::EditGroup)
+            if !state
+                .group_to_edit
+                .as_ref()
+                .is_some_and(|group| group.is_in_edit_mode) =>
+        {
+            Vec::new()
+        }
+        Some(view) => inspector_focus_order(view).to_vec(),
+        None if state.current_view == FurView::Settings => {
+            settings_focus_order(&state.settings_active_tab)
+                .iter()
+                .copied()
+                .filter(|id| {
+                    *id != SYNC_SERVER_INPUT_ID
+                        || state.settings_server_choice == Some(ServerChoices::Custom)
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+pub fn set_positive_temp_notice(
+    message_holder: &mut Result<String, Box<dyn std::error::Error>>,
+    message: String,
+) -> Task<Message> {
+    *message_holder = Ok(message);
+    Task::perform(
+        async {
+            tokio::time::sleep(std::time::Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
+        },
+        |_| Message::ClearLoginMessage,
+    )
+}
+
+pub fn set_negative_temp_notice(
+    message_holder: &mut Result<String, Box<dyn std::error::Error>>,
+    message: String,
+) -> Task<Message> {
+    *message_holder = Err(message.into());
+    Task::perform(
+        async {
+            tokio::time::sleep(std::time::Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
+        },
+        |_| Message::ClearLoginMessage,
+    )
+}
+
+/// Exponential backoff for retrying a failed sync, capped at 15 minutes
+pub fn sync_retry_delay(attempt: u32) -> Duration {
+    let delay =
+        SYNC_RETRY_BASE_SECONDS.saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)));
+    Duration::from_secs(delay.min(SYNC_RETRY_MAX_SECONDS))
+}
+
+pub fn sync_after_change(user: &Option<FurUser>) -> Task<Message> {
+    if user.is_some() {
+        Task::perform(
+            async {
+                // Small delay to allow any pending DB operations to complete
+                time::sleep(Duration::from_secs(1)).await;
+            },
+            |_| Message::SyncWithServer,
+        )
+    } else {
+        Task::none()
+    }
+}
+
+pub fn advance_onboarding(state: &mut Furtherance) {
+    let has_next_step = state
+        .onboarding
+        .as_mut()
+        .map_or(false, |onboarding| onboarding.advance());
+    if !has_next_step {
+        finish_onboarding(state);
+    }
+}
+
+pub fn finish_onboarding(state: &mut Furtherance) {
+    let offer_old_db_import = state
+        .onboarding
+        .take()
+        .map_or(false, |onboarding| onboarding.offer_old_db_import);
+    state.displayed_alert = if offer_old_db_import {
+        Some(FurAlert::ImportMacDatabase)
+    } else {
+        None
+    };
+    // Onboarding covers syncing, so the separate announcement is not needed
+    if let Err(e) = state.fur_settings.change_first_run(false) {
+        eprintln!("Error changing first_run: {}", e);
+    }
+    if let Err(e) = state.fur_settings.change_notify_of_sync(false) {
+        eprintln!("Error changing notify_of_sync: {}", e);
+    }
+}
+
+pub fn has_max_two_decimals(input: &str) -> bool {
+    let parts: Vec<&str> = input.split('.').collect();
+    match parts.len() {
+        1 => true,
+        2 => {
+            let decimal_part = parts[1];
+            decimal_part.len() <= 2
+        }
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskLengthCheck {
+    Normal,
+    TooLong,
+    TooShort,
+}
+
+/// Compares a stopped task against the sanity thresholds in settings. A threshold of 0 is off.
+pub fn check_task_length(seconds: i64, settings: &FurSettings) -> TaskLengthCheck {
+    if settings.discard_if_shorter_than_seconds > 0
+        && seconds < settings.discard_if_shorter_than_seconds
+    {
+        TaskLengthCheck::TooShort
+    } else if settings.warn_if_longer_than_hours > 0
+        && seconds > settings.warn_if_longer_than_hours * 3600
+    {
+        TaskLengthCheck::TooLong
+    } else {
+        TaskLengthCheck::Normal
+    }
+}
+
+/// Saves the running task unless it is too short to keep. The returned task
+/// shows the short task notice or the long task confirmation when needed.
+pub fn stop_timer(state: &mut Furtherance, stop_time: DateTime<Local>) -> Task<Message> {
+    let task = stopped_timer_task(state, stop_time);
+    let follow_up = save_stopped_task(state, task);
+
+    delete_autosave();
+    finish_stopped_timer(state);
+
+    follow_up
+}
+
+/// Stops a timer that was left running past the auto-stop time, ending the task
+/// at `stop_time`. A pomodoro break isn't recorded, so it ends the session instead.
+pub fn auto_stop_timer(state: &mut Furtherance, stop_time: DateTime<Local>) -> Task<Message> {
+    let mut tasks = vec![];
+    if state.fur_settings.pomodoro {
+        state.pomodoro.snoozed = false;
+        state.pomodoro.sessions = 0;
+        if matches!(
+            state.displayed_alert,
+            Some(FurAlert::PomodoroOver | FurAlert::PomodoroBreakOver)
+        ) {
+            state.displayed_alert = None;
+        }
+    }
+    if state.fur_settings.pomodoro && state.pomodoro.on_break {
+        state.timer_is_running = false;
+        state.pomodoro.on_break = false;
+        reset_timer(state);
+    } else {
+        tasks.push(stop_timer(state, stop_time));
+        tasks.push(sync_after_change(&state.fur_user));
+    }
+    tasks.push(update_task_history(state.history_days_loaded));
+    tasks.push(notify(state, NotificationType::AutoStopped));
+
+    let notice = state.localization.get_message(
+        "timer-auto-stopped",
+        Some(&HashMap::from([(
+            "time",
+            FluentValue::from(format_time(stop_time.time(), false)),
+        )])),
+    );
+    tasks.push(show_timer_notice(state, notice));
+    chain_tasks(tasks)
+}
+
+/// Stops the timer like `stop_timer` but, with confirm_on_stop enabled, opens the
+/// task in the inspector instead of saving it. Tasks that are too short are
+/// still discarded right away.
+pub fn stop_timer_for_review(state: &mut Furtherance, stop_time: DateTime<Local>) -> Task<Message> {
+    if !state.fur_settings.confirm_on_stop {
+        return stop_timer(state, stop_time);
+    }
+
+    let task = stopped_timer_task(state, stop_time);
+    if check_task_length(task.total_time_in_seconds(), &state.fur_settings)
+        == TaskLengthCheck::TooShort
+    {
+        let follow_up = save_stopped_task(state, task);
+        delete_autosave();
+        finish_stopped_timer(state);
+        return follow_up;
+    }
+
+    // Keep the autosave with the final stop time so quitting mid-review still keeps the task
+    if let Err(e) = write_autosave(
+        &state.task_input,
+        &task.notes,
+        task.start_time,
+        task.stop_time,
+    ) {
+        eprintln!("Error writing autosave: {e}");
+    }
+    finish_stopped_timer(state);
+    state.group_to_edit = None;
+    state.task_to_add = None;
+    state.task_to_edit = Some(TaskToEdit::new_unsaved(&task));
+    state.inspector_view = Some(FurInspectorView::EditTask);
+    Task::none()
+}
+
+/// The task the running timer recorded, with the timer marked as stopped
+fn stopped_timer_task(state: &mut Furtherance, stop_time: DateTime<Local>) -> FurTask {
+    state.timer_is_running = false;
+    let mut task = FurRunningTimer {
+        task_input: state.task_input.clone(),
+        start_time: state.timer_start_time,
+    }
+    .to_task(stop_time);
+    if state.fur_settings.pomodoro {
+        task.planned_seconds = 0;
+    }
+    task.notes = state.running_task_notes.text().trim().to_string();
+    apply_project_default_tags(state, &mut task);
+    task
+}
+
+fn finish_stopped_timer(state: &mut Furtherance) {
+    reset_timer(state);
+    play_sound(FurSound::TimerStopped, &state.fur_settings);
+}
+
+/// Saves the running task and starts `next_input` in the same instant, so the switch
+/// leaves no gap and no overlap. While paused, the saved task still ends when the
+/// pause began.
+pub fn switch_timer(state: &mut Furtherance, next_input: String) -> Task<Message> {
+    let now = Local::now();
+    let (stop_time, next_start) = switch_times(state.timer_paused, state.timer_paused_at, now);
+    let task = stopped_timer_task(state, stop_time);
+    let follow_up = save_stopped_task(state, task);
+
+    reset_timer(state);
+    state.pomodoro.snoozed = false;
+    state.pomodoro.sessions = 0;
+    state.task_input = next_input;
+    start_timer_at(state, next_start);
+    if let Err(e) = write_autosave(&state.task_input, "", state.timer_start_time, now) {
+        eprintln!("Error writing autosave: {e}");
+    }
+
+    chain_tasks(vec![
+        follow_up,
+        schedule_stopwatch_tick(state),
+        update_task_history(state.history_days_loaded),
+        sync_after_change(&state.fur_user),
+        widget::operation::focus(TASK_INPUT_ID),
+    ])
+}
+
+/// When a switched task stops and the next one starts. Both are `now` unless the
+/// timer is paused, when the stopped task ends where the pause began.
+pub fn switch_times(
+    timer_paused: bool,
+    timer_paused_at: DateTime<Local>,
+    now: DateTime<Local>,
+) -> (DateTime<Local>, DateTime<Local>) {
+    if timer_paused {
+        (timer_paused_at, now)
+    } else {
+        (now, now)
+    }
+}
+
+/// Starts a timer alongside the main one with the secondary task input
+pub fn start_secondary_timer(state: &mut Furtherance) {
+    if state.secondary_timer_input.trim().is_empty()
+        || state.secondary_timers.len() >= MAX_SECONDARY_TIMERS
+    {
+        return;
+    }
+    let task_input = std::mem::take(&mut state.secondary_timer_input);
+    state
+        .secondary_timers
+        .push(FurRunningTimer::new(task_input.trim().to_string()));
+    write_secondary_timer_autosaves(state);
+    play_sound(FurSound::TimerStarted, &state.fur_settings);
+}
+
+/// Stops one secondary timer and saves its task independently of the others
+pub fn stop_secondary_timer(
+    state: &mut Furtherance,
+    index: usize,
+    stop_time: DateTime<Local>,
+) -> Task<Message> {
+    if index >= state.secondary_timers.len() {
+        return Task::none();
+    }
+    let timer = state.secondary_timers.remove(index);
+    let mut task = timer.to_task(stop_time);
+    apply_project_default_tags(state, &mut task);
+    let follow_up = save_stopped_task(state, task);
+    write_secondary_timer_autosaves(state);
+    play_sound(FurSound::TimerStopped, &state.fur_settings);
+    follow_up
+}
+
+pub fn write_secondary_timer_autosaves(state: &mut Furtherance) {
+    state.secondary_timers_autosaved_at = Local::now();
+    if let Err(e) = write_secondary_autosaves(&state.secondary_timers, Local::now()) {
+        eprintln!("Error writing autosave: {e}");
+    }
+}
+
+/// Writes a stopped timer's task unless it is too short to keep
+fn save_stopped_task(state: &mut Furtherance, task: FurTask) -> Task<Message> {
+    let length_check = check_task_length(task.total_time_in_seconds(), &state.fur_settings);
+    if length_check != TaskLengthCheck::TooShort {
+        db_insert_task(&task).expect("Couldn't write task to database.");
+    }
+
+    match length_check {
+        TaskLengthCheck::Normal => Task::none(),
+        TaskLengthCheck::TooLong => {
+            state.long_task_to_confirm = Some(task);
+            Task::perform(
+                async { Message::ShowAlert(FurAlert::LongTaskConfirmation) },
+                |msg| msg,
+            )
+        }
+        TaskLengthCheck::TooShort => {
+            state.timer_notice = Some(state.localization.get_message(
+                "task-too-short-discarded",
+                Some(&HashMap::from([(
+                    "seconds",
+                    FluentValue::from(state.fur_settings.discard_if_shorter_than_seconds),
+                )])),
+            ));
+            Task::perform(
+                async {
+                    time::sleep(Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
+                },
+                |_| Message::ClearTimerNotice,
+            )
+        }
+    }
+}
+
+/// Shows a notice above the report for a few seconds
+pub fn show_report_export_notice(
+    state: &mut Furtherance,
+    notice: Result<String, Box<dyn std::error::Error>>,
+) -> Task<Message> {
+    state.report_export_message = notice;
+    Task::perform(
+        async {
+            tokio::time::sleep(std::time::Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
+        },
+        |_| Message::ClearReportExportMessage,
+    )
+}
+
+/// Shows `message` under the timer for a few seconds
+pub fn show_timer_notice(state: &mut Furtherance, message: String) -> Task<Message> {
+    state.timer_notice = Some(message);
+    Task::perform(
+        async {
+            time::sleep(Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
+        },
+        |_| Message::ClearTimerNotice,
+    )
+}
+
+/// The summary of the week starting on `week_start` in the chosen format
+pub fn weekly_summary(state: &Furtherance, week_start: NaiveDate) -> Option<String> {
+    let week_end = week_start + TimeDelta::days(6);
+    match db_retrieve_tasks_by_date_range(week_start, week_end) {
+        Ok(tasks) => Some(summaries::period_summary(
+            &tasks,
+            week_start,
+            week_end,
+            state.fur_settings.summary_format,
+            false,
+            &state.fur_settings.earnings_exclusions,
+            &state.localization,
+        )),
+        Err(e) => {
+            eprintln!("Error retrieving tasks for weekly summary: {}", e);
+            None
+        }
+    }
+}
+
+/// How many shortcuts fit on each line of the Shortcuts view at the current window size
+pub fn shortcut_grid_columns(state: &Furtherance) -> usize {
+    // The grid is padded by 20 on each side
+    let mut available_width = state.window_width - 40.0;
+    if state.show_sidebar {
+        available_width -= SIDEBAR_WIDTH;
+    }
+    if state.inspector_view.is_some() {
+        available_width -= INSPECTOR_ROW_WIDTH;
+    }
+    grid_columns(
+        available_width,
+        SHORTCUT_BUTTON_WIDTH + SHORTCUT_FOCUS_RING_WIDTH * 2.0,
+        SHORTCUTS_SPACING,
+    )
+}
+
+/// Saves a pomodoro that ran to the end and refreshes the session counts
+pub fn record_pomodoro_session(state: &mut Furtherance) {
+    let (name, _, _, _) = split_task_input(&state.task_input);
+    let session = FurPomodoroSession {
+        completed_at: Local::now(),
+        task_name: name,
+        length: state.fur_settings.pomodoro_length * 60,
+    };
+    if let Err(e) = db_insert_pomodoro_session(&session) {
+        eprintln!("Failed to write pomodoro session to database: {}", e);
+    }
+    state.pomodoro.stats = FurPomodoroStats::load(Local::now().date_naive());
+}
+
+/// The moment the running timer counts up to, which stands still while paused
+pub fn timer_now(state: &Furtherance) -> DateTime<Local> {
+    if state.timer_paused {
+        state.timer_paused_at
+    } else {
+        Local::now()
+    }
+}
+
+pub fn pause_timer(state: &mut Furtherance) {
+    state.timer_paused = true;
+    state.timer_paused_at = Local::now();
+    // Invalidate the pending tick so nothing counts, notifies or checks idle while paused
+    state.stopwatch_tick_id = state.stopwatch_tick_id.wrapping_add(1);
+    let seconds_elapsed = state
+        .timer_paused_at
+        .signed_duration_since(state.timer_start_time)
+        .num_seconds();
+    state.timer_text = get_timer_text(state, seconds_elapsed);
+
+    // If Furtherance quits while paused, the restored task ends when the pause began
+    if let Err(e) = write_autosave(
+        &state.task_input,
+        &state.running_task_notes.text(),
+        state.timer_start_time,
+        state.timer_paused_at,
+    ) {
+        eprintln!("Error writing autosave: {e}");
+    }
+    update_status_file(state);
+    refresh_status_snapshot(state);
+}
+
+/// Moves the start time forward by the length of the pause so it isn't counted
+pub fn resume_timer(state: &mut Furtherance) -> Task<Message> {
+    let paused_for = Local::now().signed_duration_since(state.timer_paused_at);
+    state.timer_start_time += paused_for;
+    state.displayed_task_start_time = convert_datetime_to_iced_time(state.timer_start_time);
+    if state.pomodoro.snoozed {
+        state.pomodoro.snoozed_at += paused_for;
+    }
+    state.timer_paused = false;
+    state.idle = FurIdle::new();
+    update_status_file(state);
+    refresh_status_snapshot(state);
+    refresh_stopwatch(state)
+}
+
+pub fn start_timer(state: &mut Furtherance) {
+    start_timer_at(state, Local::now());
+}
+
+fn start_timer_at(state: &mut Furtherance, start_time: DateTime<Local>) {
+    state.timer_start_time = start_time;
+    state.running_task_input = state.task_input.clone();
+    state.displayed_task_start_time = convert_datetime_to_iced_time(state.timer_start_time);
+    state.timer_is_running = true;
+    if state.fur_settings.pomodoro && !state.pomodoro.on_break {
+        state.pomodoro.sessions += 1;
+    }
+
+    // Working on an archived project brings it back
+    let (_, project, _, _) = split_task_input(&state.task_input);
+    if state.fur_settings.is_project_archived(&project) {
+        if let Err(e) = state.fur_settings.unarchive_project(&project) {
+            eprintln!("Failed to change archived_projects in settings: {}", e);
+        }
+        state
+            .report
+            .set_archived_projects(state.fur_settings.archived_projects.clone());
+        state
+            .export_settings
+            .get_all_projects(&state.fur_settings.archived_projects);
+    }
+
+    update_status_file(state);
+    refresh_status_snapshot(state);
+    refresh_report_running_time(state);
+    play_sound(FurSound::TimerStarted, &state.fur_settings);
+}
+
+/// Shows the running task on the time recorded chart while dynamic totals are on
+pub fn refresh_report_running_time(state: &mut Furtherance) {
+    let running_time =
+        if state.timer_is_running && state.fur_settings.dynamic_total && !state.pomodoro.on_break {
+            Some((
+                state.timer_start_time.date_naive(),
+                (timer_now(state) - state.timer_start_time).num_seconds(),
+            ))
+        } else {
+            None
+        };
+    state.report.set_running_time(running_time);
+}
+
+pub fn refresh_project_lists(state: &mut Furtherance) {
+    state.all_projects = match db_retrieve_all_project_names() {
+        Ok(projects) => projects,
+        Err(e) => {
+            eprintln!("Error retrieving projects from database: {}", e);
+            vec![]
+        }
+    };
+    state
+        .export_settings
+        .get_all_projects(&state.fur_settings.archived_projects);
+    state.project_colors = get_project_colors(&state.all_projects);
+    state
+        .report
+        .set_project_colors(state.project_colors.clone());
+    state.project_default_tags = get_project_default_tags();
+}
+
+/// Adds the project's default tags to a new task. Tasks already saved are never
+/// changed, so editing the defaults only affects tasks recorded afterwards.
+pub fn apply_project_default_tags(state: &Furtherance, task: &mut FurTask) {
+    let default_tags = default_tags_for(&state.project_default_tags, &task.project);
+    if !default_tags.is_empty() {
+        task.tags = merge_default_tags(&task.tags, default_tags);
+    }
+}
+
+pub fn get_project_default_tags() -> HashMap<String, String> {
+    match db_retrieve_project_default_tags() {
+        Ok(default_tags) => default_tags.into_iter().collect(),
+        Err(e) => {
+            eprintln!("Error retrieving project default tags from database: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Reload the recent syncs and how many changes are waiting to be sent
+pub fn refresh_sync_status(state: &mut Furtherance) {
+    if state.fur_user.is_none() {
+        return;
+    }
+    match db_retrieve_sync_history() {
+        Ok(history) => state.sync_history = history,
+        Err(e) => log_error(
+            &mut state.errors,
+            format!("Failed to retrieve sync history: {}", e),
+        ),
+    }
+    // A full sync sends everything again
+    let since = if state.fur_settings.needs_full_sync {
+        0
+    } else {
+        state.fur_settings.last_sync
+    };
+    match db_count_changes_since(since) {
+        Ok(count) => state.pending_sync_changes = count,
+        Err(e) => log_error(
+            &mut state.errors,
+            format!("Failed to count pending sync changes: {}", e),
+        ),
+    }
+}
+
+/// Reloads the deleted items that are still within the retention period
+pub fn refresh_trash(state: &mut Furtherance) {
+    let since = retention_cutoff(sync_timestamp(), state.fur_settings.trash_retention_days);
+    match db_retrieve_deleted_items(since) {
+        Ok(items) => state.trash = items,
+        Err(e) => eprintln!("Failed to retrieve deleted items: {}", e),
+    }
+}
+
+/// Reload the tasks offered under the task input
+pub fn refresh_recent_tasks(state: &mut Furtherance) {
+    let recent = match db_retrieve_recent_distinct_tasks(RECENT_TASKS_SHOWN) {
+        Ok(tasks) => distinct_task_strings(&tasks),
+        Err(e) => {
+            eprintln!("Error retrieving recent tasks from database: {}", e);
+            vec![]
+        }
+    };
+    let last_week_date = Local::now().date_naive() - TimeDelta::days(7);
+    let last_week = match db_retrieve_tasks_by_date_range(last_week_date, last_week_date) {
+        Ok(tasks) => distinct_task_strings(&tasks),
+        Err(e) => {
+            eprintln!("Error retrieving last week's tasks from database: {}", e);
+            vec![]
+        }
+    };
+    state.recent_tasks.set_tasks(recent, last_week);
+}
+
+/// Every project's color. Projects without one get a random color that is saved,
+/// so each project keeps its color from then on.
+pub fn get_project_colors(projects: &[String]) -> HashMap<String, Srgb> {
+    let mut project_colors: HashMap<String, Srgb> = match db_retrieve_project_colors() {
+        Ok(colors) => colors
+            .into_iter()
+            .filter_map(|(project, color_hex)| {
+                Srgb::from_hex(&color_hex)
+                    .ok()
+                    .map(|color| (project, color))
+            })
+            .collect(),
+        Err(e) => {
+            eprintln!("Error retrieving project colors from database: {}", e);
+            return HashMap::new();
+        }
+    };
+
+    for project in projects {
+        if !project_colors.contains_key(project) {
+            let color = Srgb::random();
+            if let Err(e) = db_set_project_color(project, &color.to_hex()) {
+                eprintln!("Error saving project color: {}", e);
+            }
+            project_colors.insert(project.clone(), color);
+        }
+    }
+
+    project_colors
+}
+
+pub fn update_status_file(state: &Furtherance) {
+    if state.fur_settings.status_file {
+        if let Err(e) = write_status_file(
+            state.timer_is_running,
+            &state.task_input,
+            state.timer_start_time,
+            state.timer_paused.then_some(state.timer_paused_at),
+        ) {
+            eprintln!("Error writing status file: {e}");
+        }
+    }
+}
+
+/// Write the latest scheduled summary if it hasn't been written yet,
+/// including one that came due while Furtherance was closed
+pub fn check_scheduled_summary(state: &mut Furtherance) -> Task<Message> {
+    let schedule = state.fur_settings.scheduled_summary;
+    let Some(period) = latest_period(
+        schedule,
+        state.fur_settings.scheduled_summary_hour,
+        Local::now().naive_local(),
+    ) else {
+        return Task::none();
+    };
+    let (_, period_end) = period;
+    if state.scheduled_summary_in_progress
+        || state.scheduled_summary_failed == Some(period_end)
+        || !is_summary_due(
+            period_end,
+            &state.fur_settings.scheduled_summary_last_generated,
+        )
+    {
+        return Task::none();
+    }
+
+    state.scheduled_summary_in_progress = true;
+    let folder = PathBuf::from(&state.fur_settings.scheduled_summary_folder);
+    let format = state.fur_settings.summary_format;
+    let include_earnings = state.fur_settings.scheduled_summary_earnings;
+    let earnings_exclusions = state.fur_settings.earnings_exclusions.clone();
+    Task::perform(
+        async move {
+            write_scheduled_summary(
+                &folder,
+                schedule,
+                period,
+                format,
+                include_earnings,
+                &earnings_exclusions,
+            )
+            .map_err(|e| e.to_string())
+        },
+        move |result| Message::ScheduledSummaryWritten(period_end, result),
+    )
+}
+
+/// Count the latest period as written so a new schedule starts with the next one
+pub fn skip_current_scheduled_summary(state: &mut Furtherance) {
+    state.scheduled_summary_failed = None;
+    if let Some((_, period_end)) = latest_period(
+        state.fur_settings.scheduled_summary,
+        state.fur_settings.scheduled_summary_hour,
+        Local::now().naive_local(),
+    ) {
+        if let Err(e) = state
+            .fur_settings
+            .change_scheduled_summary_last_generated(&period_end.to_string())
+        {
+            eprintln!(
+                "Failed to change scheduled_summary_last_generated in settings: {}",
+                e
+            );
+        }
+    }
+}
+
+/// Keep the skew measured on the last login or sync, and correct outgoing
+/// timestamps for it. Responses without a server time leave the last measurement.
+pub fn record_clock_skew(state: &mut Furtherance, skew: Option<TimeDelta>) {
+    if let Some(skew) = skew {
+        set_clock_offset(skew);
+        state.clock_skew = is_significant_skew(skew).then_some(skew);
+    }
+}
+
+pub fn clear_clock_skew(state: &mut Furtherance) {
+    set_clock_offset(TimeDelta::zero());
+    state.clock_skew = None;
+}
+
+pub fn clock_skew_warning(localization: &Localization, skew: TimeDelta) -> String {
+    localization.get_message(
+        "clock-skew-warning",
+        Some(&HashMap::from([(
+            "minutes",
+            FluentValue::from(skew_minutes(skew)),
+        )])),
+    )
+}
+
+/// Write the summary file now, or once enough time has passed since the last write
+pub fn request_summary_file(state: &mut Furtherance) -> Task<Message> {
+    if !state.fur_settings.write_summary_file {
+        return Task::none();
+    }
+
+    let delay = write_delay(state.summary_file_written_at, Local::now());
+    if delay.is_zero() {
+        write_summary_file_now(state)
+    } else if state.summary_file_pending {
+        // The write already scheduled will pick up these changes
+        Task::none()
+    } else {
+        state.summary_file_pending = true;
+        Task::perform(
+            async move {
+                time::sleep(delay).await;
+            },
+            |_| Message::SummaryFileDue,
+        )
+    }
+}
+
+pub fn write_summary_file_now(state: &mut Furtherance) -> Task<Message> {
+    let now = Local::now();
+    state.summary_file_written_at = Some(now);
+    let path = PathBuf::from(&state.fur_settings.summary_file_path);
+    let running = if state.timer_is_running {
+        let elapsed_until = if state.timer_paused {
+            state.timer_paused_at
+        } else {
+            now
+        };
+        Some(RunningSummary::new(
+            &state.task_input,
+            state.timer_start_time,
+            elapsed_until,
+        ))
+    } else {
+        None
+    };
+
+    let earnings_exclusions = state.fur_settings.earnings_exclusions.clone();
+    Task::perform(
+        async move {
+            write_summary_file(&path, running, &earnings_exclusions).map_err(|e| e.to_string())
+        },
+        Message::SummaryFileWritten,
+    )
+}
+
+/// Copy the current timer and today's totals to where the status server can read them
+pub fn refresh_status_snapshot(state: &Furtherance) {
+    if state.status_server.is_none() {
+        return;
+    }
+
+    let mut snapshot = StatusSnapshot::default();
+    if state.timer_is_running {
+        let (name, project, tags, rate) = split_task_input(&state.task_input);
+        if task_input_is_billable(&state.task_input, rate)
+            && !is_excluded_from_earnings(&tags, &project, &state.fur_settings.earnings_exclusions)
+        {
+            snapshot.rate = rate;
+        }
+        snapshot.task = name;
+        snapshot.project = project;
+        snapshot.tags = tags
+            .split('#')
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        snapshot.start_time = Some(state.timer_start_time);
+        snapshot.paused_at = state.timer_paused.then_some(state.timer_paused_at);
+    }
+    snapshot.pomodoro = PomodoroSnapshot {
+        enabled: state.fur_settings.pomodoro,
+        on_break: state.pomodoro.on_break,
+        end_time: if state.fur_settings.pomodoro && state.timer_is_running {
+            Some(pomodoro_end_time(state))
+        } else {
+            None
+        },
+    };
+    if let Some(todays_groups) = state.task_history.get(&Local::now().date_naive()) {
+        for group in todays_groups {
+            snapshot.today_seconds += group.total_time;
+            snapshot.today_earnings += group_earnings(
+                group,
+                group.total_time,
+                &state.fur_settings.earnings_exclusions,
+            );
+        }
+    }
+
+    match state.status_snapshot.write() {
+        Ok(mut shared) => *shared = snapshot,
+        Err(poisoned) => *poisoned.into_inner() = snapshot,
+    }
+}
+
+/// Stop the status server if it's running, then start it again if it's enabled
+pub fn restart_status_server(state: &mut Furtherance) {
+    // Dropping the old server stops it and frees the port
+    state.status_server = None;
+    if !state.fur_settings.status_server {
+        return;
+    }
+
+    if state.fur_settings.status_server_api_key.is_empty() {
+        if let Err(e) = state
+            .fur_settings
+            .change_status_server_api_key(&generate_status_server_api_key())
+        {
+            eprintln!("Failed to change status_server_api_key in settings: {}", e);
+        }
+    }
+
+    match StatusServer::start(
+        state.fur_settings.status_server_port,
+        state.fur_settings.status_server_api_key.clone(),
+        Arc::clone(&state.status_snapshot),
+    ) {
+        Ok(server) => {
+            state.status_server = Some(server);
+            refresh_status_snapshot(state);
+        }
+        Err(e) => {
+            eprintln!("Failed to start status server: {e}");
+            state.settings_more_message = Err(state
+                .localization
+                .get_message(
+                    "status-server-failed",
+                    Some(&HashMap::from([
+                        (
+                            "port",
+                            FluentValue::from(state.fur_settings.status_server_port),
+                        ),
+                        ("error", FluentValue::from(e.to_string())),
+                    ])),
+                )
+                .into());
+        }
+    }
+}
+
+pub fn generate_status_server_api_key() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+/// Schedule the next StopwatchTick. Scheduling a new tick invalidates any tick
+/// that is still pending, so there is only ever one tick loop running.
+pub fn schedule_stopwatch_tick(state: &mut Furtherance) -> Task<Message> {
+    state.stopwatch_tick_id = state.stopwatch_tick_id.wrapping_add(1);
+    let tick_id = state.stopwatch_tick_id;
+    let delay = next_tick_delay(state);
+    Task::perform(time::sleep(delay), move |_| Message::StopwatchTick(tick_id))
+}
+
+/// Redraw the timer now and restart the tick loop, e.g. after the tick frequency changes.
+pub fn refresh_stopwatch(state: &mut Furtherance) -> Task<Message> {
+    let seconds_elapsed = timer_now(state)
+        .signed_duration_since(state.timer_start_time)
+        .num_seconds();
+    state.timer_text = get_timer_text(state, seconds_elapsed);
+    if state.timer_paused {
+        Task::none()
+    } else {
+        schedule_stopwatch_tick(state)
+    }
+}
+
+/// Moves the running timer's start and redraws it right away
+pub fn set_timer_start_time(state: &mut Furtherance, start_time: DateTime<Local>) -> Task<Message> {
+    state.displayed_task_start_time = convert_datetime_to_iced_time(start_time);
+    state.timer_start_time = start_time;
+    if state.timer_is_running {
+        refresh_stopwatch(state)
+    } else {
+        Task::none()
+    }
+}
+
+/// Time until the timer next needs redrawing. Ticks land on whole seconds (or whole
+/// minutes in the background) counted from the start time, so they never drift.
+pub fn next_tick_delay(state: &Furtherance) -> Duration {
+    let low_frequency = state.window_minimized
+        || (!state.fur_settings.show_seconds && !state.fur_settings.pomodoro);
+    let interval_ms: i64 = if low_frequency { 60_000 } else { 1_000 };
+    let now = Local::now();
+    let elapsed_ms = now
+        .signed_duration_since(state.timer_start_time)
+        .num_milliseconds()
+        .max(0);
+    let mut delay_ms = interval_ms - elapsed_ms % interval_ms;
+
+    // Always wake right at the end of a Pomodoro session, even in the background
+    if state.fur_settings.pomodoro {
+        let until_end_ms = pomodoro_end_time(state)
+            .signed_duration_since(now)
+            .num_milliseconds();
+        if until_end_ms > 0 {
+            delay_ms = delay_ms.min(until_end_ms);
+        }
+    }
+
+    Duration::from_millis(delay_ms as u64)
+}
+
+pub fn split_task_input(input: &str) -> (String, String, String, f32) {
+    let (input, literals) = protect_literals(input);
+    let input = &non_billable_marker_regex().replace_all(&input, " ");
+    let input = &planned_duration_regex().replace_all(input, " ");
+    let re_name = Regex::new(r"^[^@#$]+").unwrap();
+    let re_project = Regex::new(r"@([^#\$]+)").unwrap();
+    let re_tags = Regex::new(r"#([^@#$]+)").unwrap();
+    let re_rate = Regex::new(r"\$([^@#$]+)").unwrap();
+
+    let name = re_name.find(input).map_or(String::new(), |m| {
+        restore_literals(m.as_str(), &literals).trim().to_string()
+    });
+
+    let project = re_project
+        .captures(input)
+        .and_then(|cap| {
+            cap.get(1)
+                .map(|m| restore_literals(m.as_str(), &literals).trim().to_string())
+        })
+        .unwrap_or(String::new());
+
+    let tags = normalize_tags(&restore_literals(
+        &re_tags
+            .captures_iter(input)
+            .map(|cap| cap.get(1).map_or("", |m| m.as_str()))
+            .join("#"),
+        &literals,
+    ));
+
+    let rate_string = re_rate
+        .captures(input)
+        .and_then(|cap| cap.get(1).map(|m| m.as_str().trim().to_string()))
+        .unwrap_or("0.0".to_string());
+    let rate: f32 = rate_string.parse().unwrap_or(0.0);
+
+    (name, project, tags, rate)
+}
+
+/// Replaces text inside double quotes and the escapes `\@`, `\#`, `\$`, `\"` and `\\`
+/// with private-use stand-ins, so the task input patterns skip over them. Returns the
+/// protected text and the characters the stand-ins point to. An unclosed quote runs
+/// to the end of the input, and any other backslash is kept as typed.
+fn protect_literals(input: &str) -> (String, Vec<char>) {
+    let mut protected = String::with_capacity(input.len());
+    let mut literals: Vec<char> = Vec::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let literal = match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                continue;
+            }
+            '\\' if chars
+                .peek()
+                .is_some_and(|next| ESCAPABLE_TASK_INPUT_CHARS.contains(next)) =>
+            {
+                chars.next()
+            }
+            _ if in_quotes => Some(c),
+            _ => None,
+        };
+
+        match literal.and_then(|literal| {
+            char::from_u32(LITERAL_STAND_IN_START + literals.len() as u32)
+                .map(|stand_in| (literal, stand_in))
+        }) {
+            Some((literal, stand_in)) => {
+                literals.push(literal);
+                protected.push(stand_in);
+            }
+            None => protected.push(literal.unwrap_or(c)),
+        }
+    }
+
+    (protected, literals)
+}
+
+/// Puts the quoted and escaped characters back in place of their stand-ins
+fn restore_literals(text: &str, literals: &[char]) -> String {
+    text.chars()
+        .map(|c| {
+            (c as u32)
+                .checked_sub(LITERAL_STAND_IN_START)
+                .and_then(|index| literals.get(index as usize))
+                .copied()
+                .unwrap_or(c)
+        })
+        .collect()
+}
+
+/// Whether the text has a `@`, `#` or `$` that isn't quoted or escaped
+pub fn has_unescaped_task_symbol(text: &str) -> bool {
+    protect_literals(text).0.contains(['@', '#', '$'])
+}
+
+/// The text as typed into the task input, with quotes and escapes removed
+pub fn unescape_task_input_text(text: &str) -> String {
+    let (protected, literals) = protect_literals(text);
+    restore_literals(&protected, &literals)
+}
+
+/// Backslash-escapes the symbols the task input would otherwise read as a project,
+/// tag or rate, so the text can be edited in a field that accepts escapes
+pub fn escape_task_input_symbols(text: &str) -> String {
+    escape_chars(text, &ESCAPABLE_TASK_INPUT_CHARS)
+}
+
+/// A name or project written so that `split_task_input` reads it back unchanged.
+/// Text that would also be taken for a non-billable marker or a duration is quoted.
+pub fn task_input_literal(text: &str) -> String {
+    if non_billable_marker_regex().is_match(text)
+        || planned_duration_regex().is_match(text)
+        || logged_duration_regex().is_match(text)
+    {
+        format!("\"{}\"", escape_chars(text, &['"', '\\']))
+    } else {
+        escape_task_input_symbols(text)
+    }
+}
+
+/// Puts a backslash before each of `special`. A backslash is only doubled when it
+/// would otherwise start an escape or sit at the end of the text.
+fn escape_chars(text: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let needs_escape = if c == '\\' {
+            chars
+                .peek()
+                .is_none_or(|next| ESCAPABLE_TASK_INPUT_CHARS.contains(next))
+        } else {
+            special.contains(&c)
+        };
+        if needs_escape {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The stored form of tags typed by the user: lowercase, deduplicated and sorted
+/// as `a #b #c`. Tags can be separated by `#` or commas.
+pub fn normalize_tags(tags: &str) -> String {
+    tags.split(['#', ','])
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .sorted()
+        .unique()
+        .join(" #")
+}
+
+/// Shortcuts keep the leading `#` so their tags read like the task input
+pub fn normalize_shortcut_tags(tags: &str) -> String {
+    let tags = normalize_tags(tags);
+    if tags.is_empty() {
+        tags
+    } else {
+        format!("#{tags}")
+    }
+}
+
+/// Add or remove one tag from a stored tag string, keeping the sorted `a #b` format
+pub fn add_or_remove_tag(tags: &str, tag: &str, remove: bool) -> String {
+    let tag = tag.trim().trim_start_matches('#').trim().to_lowercase();
+    let mut tag_list: Vec<String> = tags
+        .split('#')
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tag.is_empty() {
+        return tags.to_string();
+    }
+
+    if remove {
+        tag_list.retain(|t| t != &tag);
+    } else {
+        tag_list.push(tag);
+    }
+
+    tag_list.into_iter().sorted().unique().join(" #")
+}
+
+/// Swap one tag for another in a stored tag string, or None if it doesn't have the tag
+pub fn replace_tag(tags: &str, old_tag: &str, new_tag: &str) -> Option<String> {
+    let old_tag = old_tag.trim().trim_start_matches('#').trim().to_lowercase();
+    let has_tag = tags
+        .split('#')
+        .any(|tag| !old_tag.is_empty() && tag.trim().to_lowercase() == old_tag);
+    if !has_tag {
+        return None;
+    }
+
+    Some(add_or_remove_tag(
+        &add_or_remove_tag(tags, &old_tag, true),
+        new_tag,
+        false,
+    ))
+}
+
+/// Whether the task input now holds a different task than the one the timer was started
+/// with, rather than nothing at all
+pub fn is_task_switch(running_input: &str, new_input: &str) -> bool {
+    !new_input.trim().is_empty() && new_input.trim() != running_input.trim()
+}
+
+/// The trimmed task input if the new value is allowed, or None if the change should be ignored
+/// Quoted and escaped symbols are part of the name, so they aren't checked.
+pub fn accepted_task_input(new_value: &str) -> Option<String> {
+    let new_value = new_value.trim_start();
+    let protected = protect_literals(new_value).0;
+    // Doesn't start with @
+    if protected.chars().next() != Some('@')
+    // Doesn't start with #
+    && protected.chars().next() != Some('#')
+    // Doesn't start with $
+    && protected.chars().next() != Some('$')
+    // No more than 1 @
+    && protected.chars().filter(|&c| c == '@').count() < 2
+    // No more than 1 $
+    && protected.chars().filter(|&c| c == '$').count() < 2
+    {
+        // Check if there is a $ and the subsequent part is a parseable f32
+        if let Some(dollar_index) = protected.find('$') {
+            let after_dollar = &protected[dollar_index + 1..];
+            if after_dollar.is_empty() {
+                // Allow typing the $ in the first place
+                return Some(new_value.to_string());
+            }
+            // Find the parseable number right after the $
+            let end_index = after_dollar.find(' ').unwrap_or(after_dollar.len());
+            let number_str = &after_dollar[..end_index];
+            let parsed_num = number_str.parse::<f32>();
+
+            if parsed_num.is_ok()
+                && has_max_two_decimals(&number_str)
+                && parsed_num.unwrap_or(f32::MAX) < f32::MAX
+            {
+                let remaining_str = &after_dollar[end_index..].trim_start();
+                // Allow a number to be typed after the $,
+                // then only a space, @, #, or a duration after the $ amount
+                if remaining_str.is_empty()
+                    || remaining_str.starts_with('@')
+                    || remaining_str.starts_with('#')
+                    || remaining_str.starts_with('~')
+                    || (remaining_str.starts_with(|c: char| c.is_ascii_digit())
+                        && remaining_str
+                            .chars()
+                            .all(|c| c.is_ascii_digit() || matches!(c, 'h' | 'm' | '.' | ' ')))
+                {
+                    return Some(new_value.to_string());
+                }
+            }
+            None
+        } else {
+            // If there is no $, no other checks are necessary
+            Some(new_value.to_string())
+        }
+    } else {
+        None
+    }
+}
+
+/// Tasks with a rate are billable unless the input contains a standalone `!`
+pub fn task_input_is_billable(input: &str, rate: f32) -> bool {
+    rate > 0.0 && !non_billable_marker_regex().is_match(&protect_literals(input).0)
 }
 
-pub fn update_todo_list() -> Task<Message> {
-    Task::perform(
-        async move { todos::get_all_todos() },
-        Message::UpdateTodoList,
-    )
+/// The planned length typed as `~45m`, `~1h` or `~1h15m`, or 0 if there isn't a valid one
+pub fn task_input_planned_seconds(input: &str) -> i64 {
+    planned_duration_regex()
+        .captures_iter(&protect_literals(input).0)
+        .last()
+        .and_then(|cap| parse_duration_input(&cap[2]))
+        .unwrap_or(0)
 }
 
-pub fn set_positive_temp_notice(
-    message_holder: &mut Result<String, Box<dyn std::error::Error>>,
-    message: String,
-) -> Task<Message> {
-    *message_holder = Ok(message);
-    Task::perform(
-        async {
-            tokio::time::sleep(std::time::Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
-        },
-        |_| Message::ClearLoginMessage,
-    )
+/// Splits a finished task's length off the end of the task input, as in
+/// `Standup @team 15m` or `Review 1h 30m`. Returns the rest of the input and the
+/// length in seconds. Durations inside quotes and `~` planned lengths don't count.
+pub fn split_logged_duration(input: &str) -> Option<(String, i64)> {
+    let (protected, _) = protect_literals(input);
+    let captures = logged_duration_regex().captures(&protected)?;
+    let duration = captures.get(2)?;
+    let suffix = &protected[duration.start()..];
+    // The duration has to be typed as-is, not pieced together around empty quotes
+    let rest = input.strip_suffix(suffix)?;
+    // "~1h 30m" is a planned length written with a space
+    if protected[..duration.start()]
+        .split_whitespace()
+        .last()
+        .is_some_and(|token| token.starts_with('~') && token.ends_with('h'))
+    {
+        return None;
+    }
+
+    let seconds = parse_duration_input(duration.as_str())?;
+    let (name, _, _, _) = split_task_input(rest);
+    if seconds <= 0 || name.is_empty() {
+        return None;
+    }
+
+    Some((rest.trim().to_string(), seconds))
 }
 
-pub fn set_negative_temp_notice(
-    message_holder: &mut Result<String, Box<dyn std::error::Error>>,
-    message: String,
+/// Saves a finished task from the task input that ends now, instead of starting the timer
+pub fn log_task_from_input(
+    state: &mut Furtherance,
+    task_input: &str,
+    seconds: i64,
 ) -> Task<Message> {
-    *message_holder = Err(message.into());
-    Task::perform(
-        async {
-            tokio::time::sleep(std::time::Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
-        },
-        |_| Message::ClearLoginMessage,
-    )
-}
+    let now = Local::now();
+    let mut task = FurRunningTimer {
+        task_input: task_input.to_string(),
+        start_time: now - TimeDelta::seconds(seconds),
+    }
+    .to_task(now);
+    apply_project_default_tags(state, &mut task);
+    if let Err(e) = db_insert_task(&task) {
+        eprintln!("Failed to write logged task to database: {}", e);
+        return Task::none();
+    }
 
-pub fn sync_after_change(user: &Option<FurUser>) -> Task<Message> {
-    if user.is_some() {
+    let uid = task.uid.clone();
+    state.task_input = String::new();
+    state.logged_task = Some(task);
+    chain_tasks(vec![
+        update_task_history(state.history_days_loaded),
+        sync_after_change(&state.fur_user),
         Task::perform(
             async {
-                // Small delay to allow any pending DB operations to complete
-                time::sleep(Duration::from_secs(1)).await;
+                time::sleep(Duration::from_secs(SETTINGS_MESSAGE_DURATION)).await;
             },
-            |_| Message::SyncWithServer,
-        )
+            move |_| Message::ClearLoggedTask(uid),
+        ),
+    ])
+}
+
+/// The planned length of the running task. Pomodoros set their own length, so
+/// plans are ignored while Pomodoro is on.
+pub fn active_planned_seconds(state: &Furtherance) -> i64 {
+    if state.fur_settings.pomodoro {
+        0
     } else {
-        Task::none()
+        task_input_planned_seconds(&state.task_input)
     }
 }
 
-pub fn has_max_two_decimals(input: &str) -> bool {
-    let parts: Vec<&str> = input.split('.').collect();
-    match parts.len() {
-        1 => true,
-        2 => {
-            let decimal_part = parts[1];
-            decimal_part.len() <= 2
-        }
-        _ => false,
-    }
+fn planned_duration_regex() -> Regex {
+    Regex::new(r"(^|\s)~(\d[\dhm:.]*)(\s|$)").unwrap()
 }
 
-pub fn stop_timer(state: &mut Furtherance, stop_time: DateTime<Local>) {
-    state.timer_is_running = false;
+fn logged_duration_regex() -> Regex {
+    Regex::new(r"(^|\s)(\d+(?:\.\d+)?h(?:\s*\d+m)?|\d+m)\s*$").unwrap()
+}
 
-    let (name, project, tags, rate) = split_task_input(&state.task_input);
-    db_insert_task(&FurTask::new(
-        name,
-        state.timer_start_time,
-        stop_time,
-        tags,
-        project,
-        rate,
-        String::new(),
+fn non_billable_marker_regex() -> Regex {
+    Regex::new(&format!(
+        r"(^|\s){}(\s|$)",
+        regex::escape(NON_BILLABLE_MARKER)
     ))
-    .expect("Couldn't write task to database.");
-
-    delete_autosave();
-    reset_timer(state);
+    .unwrap()
 }
 
-pub fn start_timer(state: &mut Furtherance) {
-    state.timer_start_time = Local::now();
-    state.displayed_task_start_time = convert_datetime_to_iced_time(state.timer_start_time);
-    state.timer_is_running = true;
-    if state.fur_settings.pomodoro && !state.pomodoro.on_break {
-        state.pomodoro.sessions += 1;
+pub fn import_csv_to_database(
+    file: &mut File,
+    localization: &Localization,
+) -> Result<CsvImportSummary, Box<dyn std::error::Error>> {
+    // Seek back to the start of the file after verification
+    file.seek(std::io::SeekFrom::Start(0))?;
+
+    let CsvImport {
+        tasks,
+        notes,
+        mut summary,
+    } = read_csv(&*file, localization)?;
+
+    // All tasks go in one transaction, so a failure leaves the database untouched
+    db_insert_tasks(&tasks)?;
+    summary.imported = tasks.len();
+
+    for note in notes {
+        if let Err(e) = db_save_day_note(&note) {
+            eprintln!("Failed to import day note: {}", e);
+        }
     }
-}
 
-pub async fn get_timer_duration() {
-    time::sleep(Duration::from_secs(1)).await;
+    Ok(summary)
 }
 
-pub fn split_task_input(input: &str) -> (String, String, String, f32) {
-    let re_name = Regex::new(r"^[^@#$]+").unwrap();
-    let re_project = Regex::new(r"@([^#\$]+)").unwrap();
-    let re_tags = Regex::new(r"#([^@#$]+)").unwrap();
-    let re_rate = Regex::new(r"\$([^@#$]+)").unwrap();
+/// Read every row of a Furtherance CSV. Rows that can't be imported are collected
+/// as errors with their line number instead of stopping the import.
+pub fn read_csv<R: std::io::Read>(
+    reader: R,
+    localization: &Localization,
+) -> Result<CsvImport, Box<dyn std::error::Error>> {
+    let mut reader = BufReader::new(reader);
+    // Keep row numbers matching the lines of the file
+    let skipped_lines = u64::from(skip_export_description(&mut reader)?);
+    let mut rdr = ReaderBuilder::new().flexible(true).from_reader(reader);
+    let device_column = rdr
+        .headers()
+        .ok()
+        .and_then(|headers| headers.iter().position(|header| header == "Device"));
+    let mut import = CsvImport::default();
+    let mut reading_notes = false;
+    let mut seen_tasks: HashSet<(String, i64, i64)> = HashSet::new();
 
-    let name = re_name
-        .find(input)
-        .map_or("", |m| m.as_str().trim())
-        .to_string();
+    for result in rdr.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => match e.kind() {
+                csv::ErrorKind::Utf8 { pos, .. } => {
+                    import.summary.errors.push(CsvRowError {
+                        row: pos.as_ref().map_or(0, |pos| pos.line() + skipped_lines),
+                        problem: localization.get_message("csv-invalid-text", None),
+                    });
+                    continue;
+                }
+                _ => return Err(e.into()),
+            },
+        };
+        let row = record
+            .position()
+            .map_or(0, |position| position.line() + skipped_lines);
 
-    let project = re_project
-        .captures(input)
-        .and_then(|cap| cap.get(1).map(|m| m.as_str().trim().to_string()))
-        .unwrap_or(String::new());
+        // Day notes are written after the tasks under their own header
+        if record.iter().eq(DAY_NOTES_CSV_HEADER) {
+            reading_notes = true;
+            continue;
+        }
+        if reading_notes {
+            if let Some(date) = record
+                .get(0)
+                .and_then(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok())
+            {
+                // Keep any note already written for that day
+                if let Ok(None) = db_retrieve_day_note(&date) {
+                    import.notes.push(FurDayNote::new(
+                        date,
+                        record.get(1).unwrap_or("").trim().to_string(),
+                    ));
+                }
+            }
+            continue;
+        }
 
-    let tags = re_tags
-        .captures_iter(input)
-        .map(|cap| cap.get(1).unwrap().as_str().trim().to_lowercase())
-        .filter(|s| !s.is_empty())
-        .sorted()
-        .unique()
-        .collect::<Vec<String>>()
-        .join(" #");
+        match parse_csv_task(&record, device_column, localization) {
+            Ok(task) => {
+                let already_exists =
+                    db_task_with_times_exists(&task.name, &task.start_time, &task.stop_time)
+                        .unwrap_or(false);
+                let key = (
+                    task.name.clone(),
+                    task.start_time.timestamp(),
+                    task.stop_time.timestamp(),
+                );
+                if already_exists || !seen_tasks.insert(key) {
+                    import.summary.duplicates += 1;
+                } else {
+                    import.tasks.push(task);
+                }
+            }
+            Err(problem) => import.summary.errors.push(CsvRowError { row, problem }),
+        }
+    }
 
-    let rate_string = re_rate
-        .captures(input)
-        .and_then(|cap| cap.get(1).map(|m| m.as_str().trim().to_string()))
-        .unwrap_or("0.0".to_string());
-    let rate: f32 = rate_string.parse().unwrap_or(0.0);
+    Ok(import)
+}
 
-    (name, project, tags, rate)
+/// Report exports start with a line describing their selection, which isn't part of the
+/// CSV. Only that first line is skipped, since task names may also start with `#`.
+fn skip_export_description<R: BufRead>(reader: &mut R) -> std::io::Result<bool> {
+    if reader.fill_buf()?.first() == Some(&b'#') {
+        reader.read_line(&mut String::new())?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
 }
 
-pub fn import_csv_to_database(file: &mut File, localization: &Localization) {
-    // Seek back to the start of the file after verification
-    if let Err(e) = file.seek(std::io::SeekFrom::Start(0)) {
-        eprintln!("Failed to seek to start of file: {}", e);
-        return;
+/// Parse and validate one task row in any of the CSV layouts Furtherance has exported.
+/// `device_column` is where the header put the device the task was tracked on, if anywhere.
+pub fn parse_csv_task(
+    record: &StringRecord,
+    device_column: Option<usize>,
+    localization: &Localization,
+) -> Result<FurTask, String> {
+    let field = |i: usize| record.get(i).unwrap_or("").trim();
+
+    let (name, start, stop, tags, project, rate, currency, is_billable) = match record.len() {
+        // v4 - Iced with billable flag, followed by any net earnings, which are
+        // recalculated, and device
+        10..=12 => (
+            field(0),
+            field(1),
+            field(2),
+            field(3).to_string(),
+            field(4),
+            field(5),
+            field(6),
+            field(7).parse::<bool>().ok(),
+        ),
+        // v3 - Iced
+        9 => (
+            field(0),
+            field(1),
+            field(2),
+            field(3).to_string(),
+            field(4),
+            field(5),
+            field(6),
+            None,
+        ),
+        // v2 - macOS SwiftUI
+        7 => (
+            field(0),
+            field(4),
+            field(5),
+            normalize_tags(field(2)),
+            field(1),
+            field(3),
+            "",
+            None,
+        ),
+        // v1 - GTK
+        6 => (
+            field(1),
+            field(2),
+            field(3),
+            normalize_tags(field(4)),
+            "",
+            "",
+            "",
+            None,
+        ),
+        _ => return Err(localization.get_message("csv-wrong-column-count", None)),
+    };
+
+    if name.is_empty() {
+        return Err(localization.get_message("csv-missing-name", None));
     }
+    let start_time = parse_csv_datetime(start)
+        .ok_or_else(|| localization.get_message("csv-invalid-start-time", None))?;
+    let stop_time = parse_csv_datetime(stop)
+        .ok_or_else(|| localization.get_message("csv-invalid-stop-time", None))?;
+    if stop_time <= start_time {
+        return Err(localization.get_message("csv-stop-before-start", None));
+    }
+    let rate =
+        parse_csv_rate(rate).ok_or_else(|| localization.get_message("csv-invalid-rate", None))?;
 
-    match read_csv(file, localization) {
-        Ok(tasks_to_import) => {
-            if let Err(e) = db_insert_tasks(&tasks_to_import) {
-                eprintln!("Failed to import tasks: {}", e);
-            }
-        }
-        Err(e) => eprintln!("Failed to read the CSV file: {}", e),
+    let mut task = FurTask::new_with_last_updated(
+        name.to_string(),
+        start_time,
+        stop_time,
+        tags,
+        project.to_string(),
+        rate,
+        currency.to_string(),
+        0,
+    );
+    if let Some(is_billable) = is_billable {
+        task.is_billable = is_billable;
+    }
+    if let Some(device_name) = device_column
+        .map(field)
+        .filter(|device_name| !device_name.is_empty())
+    {
+        task.device_name = device_name.to_string();
     }
+
+    Ok(task)
 }
 
-pub fn read_csv(
-    file: &File,
-    localization: &Localization,
-) -> Result<Vec<FurTask>, Box<dyn std::error::Error>> {
-    let mut rdr = ReaderBuilder::new().flexible(true).from_reader(file);
-    let mut tasks = Vec::new();
+/// RFC 3339 as written by Furtherance, or a plain local date and time as written by
+/// older versions and most spreadsheets, on either a 24-hour or 12-hour clock
+pub fn parse_csv_datetime(value: &str) -> Option<DateTime<Local>> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Local));
+    }
+    if let Ok(date_time) = value.parse::<DateTime<Local>>() {
+        return Some(date_time);
+    }
 
-    for result in rdr.records() {
-        let record = result?;
-
-        let task = match record.len() {
-            9 => {
-                // v3 - Iced
-                FurTask::new_with_last_updated(
-                    record.get(0).unwrap_or("").to_string(),
-                    record.get(1).unwrap_or("").parse().unwrap_or_default(),
-                    record.get(2).unwrap_or("").parse().unwrap_or_default(),
-                    record.get(3).unwrap_or("").trim().to_string(),
-                    record.get(4).unwrap_or("").trim().to_string(),
-                    record.get(5).unwrap_or("0").trim().parse().unwrap_or(0.0),
-                    record.get(6).unwrap_or("").trim().to_string(),
-                    0,
-                )
-            }
-            7 => {
-                // v2 - macOS SwiftUI
-                let date_format = "%Y-%m-%d %H:%M:%S";
-                FurTask::new_with_last_updated(
-                    record.get(0).unwrap_or("").to_string(),
-                    Local
-                        .from_local_datetime(
-                            &NaiveDateTime::parse_from_str(
-                                record.get(4).unwrap_or(""),
-                                date_format,
-                            )
-                            .unwrap_or_default(),
-                        )
-                        .single()
-                        .unwrap_or_default(),
-                    Local
-                        .from_local_datetime(
-                            &NaiveDateTime::parse_from_str(
-                                record.get(5).unwrap_or(""),
-                                date_format,
-                            )
-                            .unwrap_or_default(),
-                        )
-                        .single()
-                        .unwrap_or_default(),
-                    record
-                        .get(2)
-                        .unwrap_or("")
-                        .split('#')
-                        .map(|t| t.trim().to_lowercase())
-                        .filter(|t| !t.is_empty())
-                        .sorted()
-                        .unique()
-                        .collect::<Vec<String>>()
-                        .join(" #"),
-                    record.get(1).unwrap_or("").trim().to_string(),
-                    record.get(3).unwrap_or("0").trim().parse().unwrap_or(0.0),
-                    String::new(),
-                    0,
-                )
-            }
-            6 => FurTask::new_with_last_updated(
-                // v1 - GTK
-                record.get(1).unwrap_or("").to_string(),
-                record.get(2).unwrap_or("").parse().unwrap_or_default(),
-                record.get(3).unwrap_or("").parse().unwrap_or_default(),
-                record
-                    .get(4)
-                    .unwrap_or("")
-                    .split('#')
-                    .map(|t| t.trim().to_lowercase())
-                    .filter(|t| !t.is_empty())
-                    .sorted()
-                    .unique()
-                    .collect::<Vec<String>>()
-                    .join(" #"),
-                String::new(),
-                0.0,
-                String::new(),
-                0,
-            ),
-
-            _ => return Err(localization.get_message("invalid-csv", None).into()),
-        };
+    let (date, time) = value.trim().split_once([' ', 'T'])?;
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let time = parse_time(time)?;
+    Local.from_local_datetime(&date.and_time(time)).earliest()
+}
 
-        if let Ok(exists) = db_task_exists(&task) {
-            if !exists {
-                tasks.push(task);
-            }
-        }
+fn parse_csv_rate(value: &str) -> Option<f32> {
+    let value = value.trim_start_matches('$').trim();
+    if value.is_empty() {
+        return Some(0.0);
     }
-
-    Ok(tasks)
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|rate| rate.is_finite() && *rate >= 0.0)
 }
 
 pub fn reset_timer(state: &mut Furtherance) {
+    state.timer_paused = false;
     state.task_input = "".to_string();
+    state.running_task_input = String::new();
+    state.running_task_notes = widget::text_editor::Content::new();
+    state.show_running_task_notes = false;
+    state.timer_start_error = None;
     state.timer_text = get_timer_text(state, 0);
     state.idle = FurIdle::new();
+    state.planned_time_notified = None;
+    update_status_file(state);
+    refresh_status_snapshot(state);
+    refresh_report_running_time(state);
 }
 
 fn convert_datetime_to_iced_time(dt: DateTime<Local>) -> time_picker::Time {
@@ -319,35 +1892,32 @@ pub fn get_timer_text(state: &Furtherance, seconds_elapsed: i64) -> String {
 
 fn get_running_timer_text(state: &Furtherance, seconds_elapsed: i64) -> String {
     if state.fur_settings.pomodoro {
-        let stop_time = if state.pomodoro.on_break {
-            if state.fur_settings.pomodoro_extended_breaks
-                && state.pomodoro.sessions % state.fur_settings.pomodoro_extended_break_interval
-                    == 0
-            {
-                state.timer_start_time
-                    + TimeDelta::minutes(state.fur_settings.pomodoro_extended_break_length)
-            } else {
-                state.timer_start_time
-                    + TimeDelta::minutes(state.fur_settings.pomodoro_break_length)
-            }
-        } else {
-            if state.pomodoro.snoozed {
-                state.pomodoro.snoozed_at
-                    + TimeDelta::minutes(state.fur_settings.pomodoro_snooze_length)
-            } else {
-                state.timer_start_time + TimeDelta::minutes(state.fur_settings.pomodoro_length)
-            }
-        };
-
         let seconds_until_end =
-            (stop_time - state.timer_start_time).num_seconds() - seconds_elapsed;
+            (pomodoro_end_time(state) - state.timer_start_time).num_seconds() - seconds_elapsed;
         if seconds_until_end > 0 {
             seconds_to_formatted_duration(seconds_until_end, true)
         } else {
             "0:00:00".to_string()
         }
     } else {
-        seconds_to_formatted_duration(seconds_elapsed, true)
+        seconds_to_formatted_duration(seconds_elapsed, state.fur_settings.show_seconds)
+    }
+}
+
+fn pomodoro_end_time(state: &Furtherance) -> DateTime<Local> {
+    if state.pomodoro.on_break {
+        if state.fur_settings.pomodoro_extended_breaks
+            && state.pomodoro.sessions % state.fur_settings.pomodoro_extended_break_interval == 0
+        {
+            state.timer_start_time
+                + TimeDelta::minutes(state.fur_settings.pomodoro_extended_break_length)
+        } else {
+            state.timer_start_time + TimeDelta::minutes(state.fur_settings.pomodoro_break_length)
+        }
+    } else if state.pomodoro.snoozed {
+        state.pomodoro.snoozed_at + TimeDelta::minutes(state.fur_settings.pomodoro_snooze_length)
+    } else {
+        state.timer_start_time + TimeDelta::minutes(state.fur_settings.pomodoro_length)
     }
 }
 
@@ -371,10 +1941,24 @@ pub fn get_stopped_timer_text(state: &Furtherance) -> String {
             seconds_to_formatted_duration(state.fur_settings.pomodoro_length * 60, true)
         }
     } else {
-        "0:00:00".to_string()
+        seconds_to_formatted_duration(0, state.fur_settings.show_seconds)
     }
 }
 
+/// Window title for a running timer, e.g. "0:42:17 – Write report — Furtherance"
+pub fn window_title(timer_text: &str, task_name: &str) -> String {
+    let task_name = if task_name.chars().count() > TITLE_TASK_NAME_MAX_CHARS {
+        let truncated: String = task_name
+            .chars()
+            .take(TITLE_TASK_NAME_MAX_CHARS - 1)
+            .collect();
+        format!("{}…", truncated.trim_end())
+    } else {
+        task_name.to_string()
+    };
+    format!("{} – {} — Furtherance", timer_text, task_name)
+}
+
 pub fn seconds_to_formatted_duration(total_seconds: i64, show_seconds: bool) -> String {
     if show_seconds {
         seconds_to_hms(total_seconds)
@@ -383,6 +1967,73 @@ pub fn seconds_to_formatted_duration(total_seconds: i64, show_seconds: bool) ->
     }
 }
 
+/// Round a duration for display and export per the rounding settings.
+/// Stored start and stop times are never rounded.
+pub fn round_seconds(total_seconds: i64, settings: &FurSettings) -> i64 {
+    if !settings.rounding_enabled || settings.rounding_minutes <= 0 {
+        return total_seconds;
+    }
+
+    let increment = settings.rounding_minutes * 60;
+    let increments = match settings.rounding_direction {
+        FurRoundingDirection::Nearest => (total_seconds + increment / 2).div_euclid(increment),
+        FurRoundingDirection::Up => (total_seconds + increment - 1).div_euclid(increment),
+        FurRoundingDirection::Down => total_seconds.div_euclid(increment),
+    };
+    increments * increment
+}
+
+/// Parse a typed duration like "1h30m", "1.5h", "45m", "45" (minutes) or "1:30" into seconds.
+/// Empty input is no duration.
+pub fn parse_duration_input(input: &str) -> Option<i64> {
+    let input = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    if input.is_empty() {
+        return Some(0);
+    }
+
+    if let Some((hours, minutes)) = input.split_once(':') {
+        let hours = hours.parse::<u32>().ok()? as i64;
+        let minutes = minutes.parse::<u32>().ok()? as i64;
+        return (minutes < 60).then_some(hours * 3600 + minutes * 60);
+    }
+
+    let (hours, rest) = match input.split_once('h') {
+        Some((hours, rest)) => (hours.parse::<f64>().ok()?, rest),
+        None => (0.0, input.as_str()),
+    };
+    let minutes = rest.strip_suffix('m').unwrap_or(rest);
+    let minutes = if minutes.is_empty() {
+        // "m" on its own isn't a duration
+        if rest == input {
+            return None;
+        }
+        0
+    } else {
+        minutes.parse::<u32>().ok()? as i64
+    };
+    if !hours.is_finite() || hours < 0.0 {
+        return None;
+    }
+
+    Some((hours * 3600.0).round() as i64 + minutes * 60)
+}
+
+/// Format seconds the way durations are typed, e.g. "1h30m"
+pub fn format_duration_input(total_seconds: i64) -> String {
+    let h = total_seconds / 3600;
+    let m = total_seconds % 3600 / 60;
+    match (h, m) {
+        (0, 0) => String::new(),
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
 fn seconds_to_hms(total_seconds: i64) -> String {
     let h = total_seconds / 3600;
     let m = total_seconds % 3600 / 60;
@@ -396,6 +2047,59 @@ fn seconds_to_hm(total_seconds: i64) -> String {
     format!("{}:{:02}", h, m)
 }
 
+/// Keep a restored window on the display it opened on, which may be smaller than the one
+/// the position was saved on. Windows larger than the display stay at its corner.
+pub fn clamp_window_position(position: Point, window_size: Size, monitor_size: Size) -> Point {
+    let max_x = (monitor_size.width - window_size.width).max(0.0);
+    let max_y = (monitor_size.height - window_size.height).max(0.0);
+    Point::new(position.x.clamp(0.0, max_x), position.y.clamp(0.0, max_y))
+}
+
+/// Save the window's size, position and maximized state to come back to next launch
+pub fn save_window_geometry(state: &mut Furtherance) {
+    state.window_geometry_changed = false;
+    // A maximized or minimized window's size isn't the one to come back to
+    if !state.window_maximized && !state.window_minimized {
+        let position = state.window_position.unwrap_or(Point::new(
+            state.fur_settings.window_x,
+            state.fur_settings.window_y,
+        ));
+        if let Err(e) = state.fur_settings.change_window_geometry(
+            (state.window_width, state.window_height),
+            (position.x, position.y),
+        ) {
+            log_error(
+                &mut state.errors,
+                format!("Failed to change window geometry in settings: {}", e),
+            );
+        }
+    }
+    if state.fur_settings.window_maximized != state.window_maximized {
+        if let Err(e) = state
+            .fur_settings
+            .change_window_maximized(&state.window_maximized)
+        {
+            log_error(
+                &mut state.errors,
+                format!("Failed to change window_maximized in settings: {}", e),
+            );
+        }
+    }
+}
+
+/// The inspector error for a task whose stop doesn't come after its start, or empty when valid
+pub fn task_times_error(
+    start_time: DateTime<Local>,
+    stop_time: DateTime<Local>,
+    localization: &Localization,
+) -> String {
+    if stop_time > start_time {
+        String::new()
+    } else {
+        localization.get_message("stop-before-start", None)
+    }
+}
+
 pub fn combine_chosen_date_with_time(
     old_date_time: DateTime<Local>,
     new_date: date_picker::Date,
@@ -410,25 +2114,27 @@ pub fn combine_chosen_date_with_time(
     )
 }
 
+/// Puts the chosen time on the old date. A time without seconds keeps the old seconds
+/// as long as the hour and minute are unchanged, so re-submitting a time doesn't move it.
 pub fn combine_chosen_time_with_date(
     old_date_time: DateTime<Local>,
     new_time: time_picker::Time,
 ) -> LocalResult<DateTime<Local>> {
-    let (hour, minute, _) = match new_time {
-        time_picker::Time::Hm {
-            hour,
-            minute,
-            period,
-        } => (hour, minute, period),
-        _ => (1, 1, time_picker::Period::H24),
+    let chosen = NaiveTime::from(new_time);
+    let second = match new_time {
+        time_picker::Time::Hms { .. } => chosen.second(),
+        _ if chosen.hour() == old_date_time.hour() && chosen.minute() == old_date_time.minute() => {
+            old_date_time.second()
+        }
+        _ => 0,
     };
     Local.with_ymd_and_hms(
         old_date_time.year(),
         old_date_time.month(),
         old_date_time.day(),
-        hour,
-        minute,
-        0,
+        chosen.hour(),
+        chosen.minute(),
+        second,
     )
 }
 
@@ -436,8 +2142,23 @@ pub fn verify_csv(
     file: &std::fs::File,
     localization: &Localization,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut rdr = Reader::from_reader(file);
+    let mut reader = BufReader::new(file);
+    skip_export_description(&mut reader)?;
+    let mut rdr = ReaderBuilder::new().from_reader(reader);
 
+    // v4 - Iced with billable flag
+    let v4_headers = vec![
+        "Name",
+        "Start Time",
+        "Stop Time",
+        "Tags",
+        "Project",
+        "Rate",
+        "Currency",
+        "Billable",
+        "Total Time",
+        "Total Earnings",
+    ];
     // v3 - Iced
     let v3_headers = vec![
         "Name",
@@ -470,11 +2191,15 @@ pub fn verify_csv(
         "seconds",
     ];
 
+    // Columns newer exports add after the v4 ones
+    let v4_optional_headers = ["Net Earnings", "Device"];
+
     if let Ok(headers) = rdr.headers() {
-        if verify_headers(headers, &v3_headers, localization).is_err() {
-            if verify_headers(headers, &v2_headers, localization).is_err() {
-                verify_headers(headers, &v1_headers, localization)?;
-            }
+        if verify_headers(headers, &v4_headers, &v4_optional_headers, localization).is_err()
+            && verify_headers(headers, &v3_headers, &[], localization).is_err()
+            && verify_headers(headers, &v2_headers, &[], localization).is_err()
+        {
+            verify_headers(headers, &v1_headers, &[], localization)?;
         }
     } else {
         return Err(localization
@@ -485,9 +2210,12 @@ pub fn verify_csv(
     Ok(())
 }
 
+/// Checks that `headers` start with `expected`. Any columns after those have to be
+/// among `optional`, in the same order.
 fn verify_headers(
     headers: &StringRecord,
     expected: &[&str],
+    optional: &[&str],
     localization: &Localization,
 ) -> Result<(), Box<dyn std::error::Error>> {
     for (i, expected_header) in expected.iter().enumerate() {
@@ -501,14 +2229,63 @@ fn verify_headers(
             }
         }
     }
+
+    let mut optional = optional.iter();
+    for header in headers.iter().skip(expected.len()) {
+        if !optional.any(|optional_header| header == *optional_header) {
+            return Err(localization.get_message("wrong-column-order", None).into());
+        }
+    }
     Ok(())
 }
 
+/// Asks the notification server to identify itself. Without a notification daemon
+/// this fails right away, so it tells whether desktop notifications can be shown.
+pub fn check_notification_support() -> Result<(), String> {
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        notify_rust::get_server_information()
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    {
+        Ok(())
+    }
+}
+
+/// Shows a desktop notification while they're available. Otherwise the window
+/// asks for attention instead, and the failure is only logged the first time.
+pub fn notify(state: &mut Furtherance, notification_type: NotificationType) -> Task<Message> {
+    if !matches!(state.notification_status, Some(Err(_))) {
+        match show_notification(
+            notification_type,
+            &state.localization,
+            state.fur_settings.pomodoro_notification_alarm_sound,
+        ) {
+            Ok(_) => {
+                state.notification_status = Some(Ok(()));
+                return Task::none();
+            }
+            Err(e) => {
+                eprintln!("Desktop notifications unavailable: {e}");
+                state.notification_status = Some(Err(e));
+            }
+        }
+    }
+    request_window_attention()
+}
+
+pub fn request_window_attention() -> Task<Message> {
+    window::latest()
+        .and_then(|id| window::request_user_attention(id, Some(window::UserAttention::Critical)))
+}
+
 pub fn show_notification(
     notification_type: NotificationType,
     localization: &Localization,
     notification_alarm_sound: bool,
-) {
+) -> Result<(), String> {
     let heading: String;
     let details: String;
     let has_sound: bool;
@@ -529,14 +2306,30 @@ pub fn show_notification(
             details = localization.get_message("idle-notification-body", None);
             has_sound = false;
         }
+        NotificationType::PlannedTimeReached => {
+            heading = localization.get_message("planned-time-reached-title", None);
+            details = localization.get_message("planned-time-reached-body", None);
+            has_sound = false;
+        }
+        NotificationType::AutoStopped => {
+            heading = localization.get_message("timer-auto-stopped-title", None);
+            details = localization.get_message("timer-auto-stopped-body", None);
+            has_sound = false;
+        }
         NotificationType::Reminder => {
             heading = localization.get_message("track-your-time", None);
             details = localization.get_message("did-you-forget", None);
             has_sound = false;
         }
+        NotificationType::Test => {
+            heading = localization.get_message("test-notification-title", None);
+            details = localization.get_message("test-notification-body", None);
+            has_sound = false;
+        }
     }
 
-    match Notification::new()
+    let mut notification = Notification::new();
+    notification
         .summary(&heading)
         .body(&details)
         .appname("Furtherance")
@@ -545,30 +2338,58 @@ pub fn show_notification(
         } else {
             ""
         })
-        .timeout(Timeout::Milliseconds(6000))
-        .show()
-    {
-        Ok(_) => {}
-        Err(e) => eprintln!("Failed to show notification: {e}"),
+        .timeout(Timeout::Milliseconds(6000));
+
+    // Only the XDG notification spec lets us attach actions and wait for the response
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let has_actions = matches!(notification_type, NotificationType::PomodoroOver);
+    #[cfg(all(unix, not(target_os = "macos")))]
+    if has_actions {
+        for action in PomodoroNotificationAction::ALL {
+            notification.action(
+                action.id(),
+                &localization.get_message(action.label_key(), None),
+            );
+        }
+    }
+
+    match notification.show() {
+        Ok(handle) => {
+            #[cfg(all(unix, not(target_os = "macos")))]
+            if has_actions {
+                std::thread::spawn(move || handle.wait_for_action(send_notification_action));
+            }
+            #[cfg(not(all(unix, not(target_os = "macos"))))]
+            let _ = handle;
+            Ok(())
+        }
+        Err(e) => Err(e.to_string()),
     }
 }
 
 pub fn convert_iced_time_to_chrono_local(
     iced_time: time_picker::Time,
 ) -> LocalResult<DateTime<Local>> {
-    let (hour, minute, _) = match iced_time {
-        time_picker::Time::Hm {
-            hour,
-            minute,
-            period,
-        } => (hour, minute, period),
-        _ => (1, 1, time_picker::Period::H24),
-    };
+    Local::now().with_time(NaiveTime::from(iced_time))
+}
 
-    if let Some(time) = NaiveTime::from_hms_opt(hour, minute, 0) {
-        Local::now().with_time(time)
-    } else {
-        LocalResult::None
+pub fn get_day_notes() -> BTreeMap<NaiveDate, FurDayNote> {
+    match db_retrieve_all_day_notes() {
+        Ok(notes) => notes.into_iter().map(|note| (note.date, note)).collect(),
+        Err(e) => {
+            eprintln!("Failed to retrieve day notes: {}", e);
+            BTreeMap::new()
+        }
+    }
+}
+
+pub fn get_days_off() -> BTreeSet<NaiveDate> {
+    match db_retrieve_days_off() {
+        Ok(days_off) => days_off,
+        Err(e) => {
+            eprintln!("Failed to retrieve days off: {}", e);
+            BTreeSet::new()
+        }
     }
 }
 
@@ -579,14 +2400,3 @@ pub fn reset_fur_user(user: &mut Option<FurUser>) {
         Err(e) => eprintln!("Error deleting user credentials: {}", e),
     }
 }
-
-pub fn detect_wayland() -> bool {
-    #[cfg(target_os = "linux")]
-    if let Ok(true) = env::var("XDG_SESSION_TYPE").map(|v| v == "wayland") {
-        return true;
-    } else if let Ok(_) = env::var("WAYLAND_DISPLAY") {
-        return Path::new(&format!("/run/user/{}/wayland-0", get_current_uid())).exists();
-    }
-
-    false
-}
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::HashMap;
+
 use crate::localization::Localization;
+use fluent::FluentValue;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +27,7 @@ pub enum FurView {
     Timer,
     Todo,
     Report,
+    Search,
     Settings,
 }
 
@@ -48,6 +52,7 @@ impl std::fmt::Display for FurView {
                 FurView::Timer => localization.get_message("timer", None),
                 FurView::Todo => localization.get_message("todo", None),
                 FurView::Report => localization.get_message("report", None),
+                FurView::Search => localization.get_message("search", None),
                 FurView::Settings => localization.get_message("settings", None),
             }
         )
@@ -56,18 +61,33 @@ impl std::fmt::Display for FurView {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FurAlert {
+    AutosaveCorrupt,
     AutosaveRestored,
+    CsvImportErrors,
+    DeleteArchivedTodosConfirmation,
     DeleteEverythingConfirmation,
     DeleteGroupConfirmation,
     DeleteShortcutConfirmation,
     DeleteTaskConfirmation,
     DeleteTodoConfirmation,
+    DiscardChangesConfirmation,
+    DiscardStoppedTaskConfirmation,
+    DroppedDatabase,
+    ErrorLog,
     Idle,
     ImportMacDatabase,
+    LongTaskConfirmation,
+    MergeDuplicatesConfirmation,
     NotifyOfSync,
+    Onboarding,
     PomodoroBreakOver,
     PomodoroOver,
+    SettingsRecovered,
     ShortcutExists,
+    SwitchToShortcutConfirmation,
+    SyncHistory,
+    TimerStartOverlap,
+    YearInReview,
 }
 
 #[derive(Debug)]
@@ -76,10 +96,12 @@ pub enum FurInspectorView {
     AddNewTodo,
     AddShortcut,
     AddTaskToGroup,
+    EditDayNote,
     EditGroup,
     EditShortcut,
     EditTask,
     EditTodo,
+    GenerateInvoice,
 }
 
 #[derive(Debug, Clone)]
@@ -94,6 +116,14 @@ pub enum EditTaskProperty {
     StopDate,
 }
 
+#[derive(Debug, Clone)]
+pub enum InvoiceField {
+    From,
+    Client,
+    Number,
+    Notes,
+}
+
 #[derive(Debug, Clone)]
 pub enum EditTodoProperty {
     Task,
@@ -101,6 +131,7 @@ pub enum EditTodoProperty {
     Tags,
     Rate,
     Date,
+    Estimate,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -119,7 +150,19 @@ pub enum NotificationType {
     PomodoroOver,
     BreakOver,
     Idle,
+    PlannedTimeReached,
+    AutoStopped,
     Reminder,
+    Test,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnboardingStep {
+    TaskSyntax,
+    Settings,
+    IdleAndPomodoro,
+    FirstShortcut,
+    Sync,
 }
 
 #[derive(Debug, Clone)]
@@ -128,6 +171,89 @@ pub enum ChangeDB {
     New,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilterChip {
+    Project(String),
+    Tag(String),
+}
+
+impl std::fmt::Display for FilterChip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilterChip::Project(project) => write!(f, "@{}", project),
+            FilterChip::Tag(tag) => write!(f, "#{}", tag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FurBillableFilter {
+    All,
+    Billable,
+    NonBillable,
+}
+
+impl FurBillableFilter {
+    pub const ALL: [FurBillableFilter; 3] = [
+        FurBillableFilter::All,
+        FurBillableFilter::Billable,
+        FurBillableFilter::NonBillable,
+    ];
+
+    pub fn includes(&self, is_billable: bool) -> bool {
+        match self {
+            FurBillableFilter::All => true,
+            FurBillableFilter::Billable => is_billable,
+            FurBillableFilter::NonBillable => !is_billable,
+        }
+    }
+}
+
+impl std::fmt::Display for FurBillableFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurBillableFilter::All => localization.get_message("all-tasks", None),
+                FurBillableFilter::Billable => localization.get_message("billable", None),
+                FurBillableFilter::NonBillable => localization.get_message("non-billable", None),
+            }
+        )
+    }
+}
+
+/// The period a report range is compared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FurComparisonPeriod {
+    PreviousPeriod,
+    PreviousYear,
+}
+
+impl FurComparisonPeriod {
+    pub const ALL: [FurComparisonPeriod; 2] = [
+        FurComparisonPeriod::PreviousPeriod,
+        FurComparisonPeriod::PreviousYear,
+    ];
+}
+
+impl std::fmt::Display for FurComparisonPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurComparisonPeriod::PreviousPeriod =>
+                    localization.get_message("previous-period", None),
+                FurComparisonPeriod::PreviousYear =>
+                    localization.get_message("previous-year", None),
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum FurDateRange {
     PastWeek,
@@ -170,20 +296,345 @@ impl std::fmt::Display for FurDateRange {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurHistoryGrouping {
+    Day,
+    Week,
+    Month,
+}
+
+impl FurHistoryGrouping {
+    pub const ALL: [FurHistoryGrouping; 3] = [
+        FurHistoryGrouping::Day,
+        FurHistoryGrouping::Week,
+        FurHistoryGrouping::Month,
+    ];
+}
+
+impl std::fmt::Display for FurHistoryGrouping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurHistoryGrouping::Day => localization.get_message("group-by-day", None),
+                FurHistoryGrouping::Week => localization.get_message("group-by-week", None),
+                FurHistoryGrouping::Month => localization.get_message("group-by-month", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurIdleBackend {
+    Auto,
+    WaylandIdleNotify,
+    ScreenSaverDbus,
+    XScreenSaver,
+    System,
+}
+
+impl FurIdleBackend {
+    /// Backends that can be chosen manually in settings
+    #[cfg(target_os = "linux")]
+    pub const ALL: [FurIdleBackend; 4] = [
+        FurIdleBackend::Auto,
+        FurIdleBackend::WaylandIdleNotify,
+        FurIdleBackend::ScreenSaverDbus,
+        FurIdleBackend::XScreenSaver,
+    ];
+    #[cfg(not(target_os = "linux"))]
+    pub const ALL: [FurIdleBackend; 1] = [FurIdleBackend::Auto];
+
+    /// The order `Auto` tries backends in
+    #[cfg(target_os = "linux")]
+    pub const FALLBACK_ORDER: [FurIdleBackend; 3] = [
+        FurIdleBackend::WaylandIdleNotify,
+        FurIdleBackend::ScreenSaverDbus,
+        FurIdleBackend::XScreenSaver,
+    ];
+    #[cfg(not(target_os = "linux"))]
+    pub const FALLBACK_ORDER: [FurIdleBackend; 1] = [FurIdleBackend::System];
+}
+
+impl std::fmt::Display for FurIdleBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurIdleBackend::Auto => localization.get_message("idle-backend-auto", None),
+                FurIdleBackend::WaylandIdleNotify => "Wayland ext-idle-notify".to_string(),
+                FurIdleBackend::ScreenSaverDbus => "D-Bus ScreenSaver".to_string(),
+                FurIdleBackend::XScreenSaver => "X11 XScreenSaver".to_string(),
+                FurIdleBackend::System => localization.get_message("idle-backend-system", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FurRenameField {
+    Project,
+    Tag,
+    TaskName,
+}
+
+impl FurRenameField {
+    pub const ALL: [FurRenameField; 3] = [
+        FurRenameField::Project,
+        FurRenameField::Tag,
+        FurRenameField::TaskName,
+    ];
+}
+
+impl std::fmt::Display for FurRenameField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurRenameField::Project => localization.get_message("project", None),
+                FurRenameField::Tag => localization.get_message("tag", None),
+                FurRenameField::TaskName => localization.get_message("task-name", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurRoundingDirection {
+    Nearest,
+    Up,
+    Down,
+}
+
+impl FurRoundingDirection {
+    pub const ALL: [FurRoundingDirection; 3] = [
+        FurRoundingDirection::Nearest,
+        FurRoundingDirection::Up,
+        FurRoundingDirection::Down,
+    ];
+}
+
+impl std::fmt::Display for FurRoundingDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurRoundingDirection::Nearest => localization.get_message("round-nearest", None),
+                FurRoundingDirection::Up => localization.get_message("round-up", None),
+                FurRoundingDirection::Down => localization.get_message("round-down", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurShortcutSort {
+    Manual,
+    Alphabetical,
+    MostUsed,
+    RecentlyUsed,
+}
+
+impl FurShortcutSort {
+    pub const ALL: [FurShortcutSort; 4] = [
+        FurShortcutSort::Manual,
+        FurShortcutSort::Alphabetical,
+        FurShortcutSort::MostUsed,
+        FurShortcutSort::RecentlyUsed,
+    ];
+}
+
+impl std::fmt::Display for FurShortcutSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurShortcutSort::Manual => localization.get_message("sort-manual", None),
+                FurShortcutSort::Alphabetical =>
+                    localization.get_message("sort-alphabetical", None),
+                FurShortcutSort::MostUsed => localization.get_message("sort-most-used", None),
+                FurShortcutSort::RecentlyUsed =>
+                    localization.get_message("sort-recently-used", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurSummaryFormat {
+    Markdown,
+    Org,
+    Html,
+}
+
+impl FurSummaryFormat {
+    pub const ALL: [FurSummaryFormat; 3] = [
+        FurSummaryFormat::Markdown,
+        FurSummaryFormat::Org,
+        FurSummaryFormat::Html,
+    ];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FurSummaryFormat::Markdown => "md",
+            FurSummaryFormat::Org => "org",
+            FurSummaryFormat::Html => "html",
+        }
+    }
+}
+
+impl std::fmt::Display for FurSummaryFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FurSummaryFormat::Markdown => "Markdown",
+                FurSummaryFormat::Org => "Org",
+                FurSummaryFormat::Html => "HTML",
+            }
+        )
+    }
+}
+
+/// How often a summary file is written on its own
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurSummarySchedule {
+    Off,
+    Daily,
+    Weekly,
+}
+
+impl FurSummarySchedule {
+    pub const ALL: [FurSummarySchedule; 3] = [
+        FurSummarySchedule::Off,
+        FurSummarySchedule::Daily,
+        FurSummarySchedule::Weekly,
+    ];
+}
+
+impl std::fmt::Display for FurSummarySchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurSummarySchedule::Off => localization.get_message("schedule-off", None),
+                FurSummarySchedule::Daily => localization.get_message("schedule-daily", None),
+                FurSummarySchedule::Weekly => localization.get_message("schedule-weekly", None),
+            }
+        )
+    }
+}
+
+/// How often to sync, stored in settings as minutes with 0 meaning manual only
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FurSyncInterval {
+    Manual,
+    Minutes(u16),
+}
+
+impl FurSyncInterval {
+    pub const ALL: [FurSyncInterval; 7] = [
+        FurSyncInterval::Minutes(5),
+        FurSyncInterval::Minutes(10),
+        FurSyncInterval::Minutes(15),
+        FurSyncInterval::Minutes(30),
+        FurSyncInterval::Minutes(60),
+        FurSyncInterval::Minutes(120),
+        FurSyncInterval::Manual,
+    ];
+
+    pub fn from_minutes(minutes: u16) -> Self {
+        if minutes == 0 {
+            FurSyncInterval::Manual
+        } else {
+            FurSyncInterval::Minutes(minutes.clamp(5, 120))
+        }
+    }
+
+    pub fn minutes(&self) -> u16 {
+        match self {
+            FurSyncInterval::Manual => 0,
+            FurSyncInterval::Minutes(minutes) => *minutes,
+        }
+    }
+}
+
+impl std::fmt::Display for FurSyncInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurSyncInterval::Manual => localization.get_message("manual-only", None),
+                FurSyncInterval::Minutes(minutes) => localization.get_message(
+                    "every-x-minutes",
+                    Some(&HashMap::from([("count", FluentValue::from(*minutes))])),
+                ),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FurThemePreference {
+    System,
+    Light,
+    Dark,
+}
+
+impl FurThemePreference {
+    pub const ALL: [FurThemePreference; 3] = [
+        FurThemePreference::System,
+        FurThemePreference::Light,
+        FurThemePreference::Dark,
+    ];
+}
+
+impl std::fmt::Display for FurThemePreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let localization = Localization::new();
+        write!(
+            f,
+            "{}",
+            match self {
+                FurThemePreference::System => localization.get_message("auto", None),
+                FurThemePreference::Light => localization.get_message("light", None),
+                FurThemePreference::Dark => localization.get_message("dark", None),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FurTaskProperty {
     Title,
     Project,
     Tags,
     Rate,
+    Device,
 }
 
 impl FurTaskProperty {
-    pub const ALL: [FurTaskProperty; 4] = [
+    pub const ALL: [FurTaskProperty; 5] = [
         FurTaskProperty::Title,
         FurTaskProperty::Project,
         FurTaskProperty::Tags,
         FurTaskProperty::Rate,
+        FurTaskProperty::Device,
     ];
 }
 
@@ -198,6 +649,7 @@ impl std::fmt::Display for FurTaskProperty {
                 FurTaskProperty::Project => localization.get_message("project", None),
                 FurTaskProperty::Tags => localization.get_message("tags", None),
                 FurTaskProperty::Rate => localization.get_message("rate", None),
+                FurTaskProperty::Device => localization.get_message("device", None),
             }
         )
     }